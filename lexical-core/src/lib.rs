@@ -84,6 +84,8 @@
 #![cfg_attr(feature = "write", doc = " - [`write_unchecked`]")]
 #![cfg_attr(feature = "write", doc = " - [`write_with_options`]")]
 #![cfg_attr(feature = "write", doc = " - [`write_with_options_unchecked`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_with_options_dyn`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_with_options_unchecked_dyn`]")]
 //!
 #![cfg_attr(feature = "write", doc = " **From String**")]
 #![cfg_attr(feature = "write", doc = "")]
@@ -91,6 +93,12 @@
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_with_options`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_with_options_dyn`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_options_dyn`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_units`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_units`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_units_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_units_with_options`]")]
 //!
 //! # Features
 //!
@@ -196,6 +204,14 @@
 //! safe feature enabled and disabled, with the tests verified by Miri
 //! and Valgrind.
 //!
+//! ### saturating
+//!
+//! Enable conversions to and from [`Saturating`] integers. `Saturating`
+//! was stabilized in Rust 1.74.0, so this requires a newer compiler than
+//! the minimum supported version.
+//!
+//! [`Saturating`]: core::num::Saturating
+//!
 //! # Configuration API
 //!
 //! Lexical provides two main levels of configuration:
@@ -224,6 +240,14 @@
 //! - JSON, XML, TOML, YAML, SQLite, and many more.
 //! - Rust, Python, C#, FORTRAN, COBOL literals and strings, and many more.
 //!
+//! The number format is normally a compile-time constant. For number
+//! formats only known at runtime, such as formats read from configuration
+//! files, a [`DynamicFormat`] may be created from the [`NumberFormatBuilder`]
+//! and used with the `_dyn` variants of the conversion functions. Any valid
+//! format is supported, since the parsers and writers are generic over the
+//! [`Format`] trait, which is implemented by both [`DynamicFormat`] and the
+//! compile-time [`NumberFormat`].
+//!
 //! ## Options API
 //!
 //! The Options API provides high-level options to specify number parsing
@@ -315,12 +339,23 @@
 //! [`write_unchecked`]: crate::write_unchecked
 //! [`write_with_options`]: crate::write_with_options
 //! [`write_with_options_unchecked`]: crate::write_with_options_unchecked
+//! [`write_with_options_dyn`]: crate::write_with_options_dyn
+//! [`write_with_options_unchecked_dyn`]: crate::write_with_options_unchecked_dyn
 //! [`parse`]: crate::parse
 //! [`parse_partial`]: crate::parse_partial
 //! [`parse_with_options`]: crate::parse_with_options
 //! [`parse_partial_with_options`]: crate::parse_partial_with_options
+//! [`parse_with_options_dyn`]: crate::parse_with_options_dyn
+//! [`parse_partial_with_options_dyn`]: crate::parse_partial_with_options_dyn
+//! [`parse_units`]: crate::parse_units
+//! [`parse_partial_units`]: crate::parse_partial_units
+//! [`parse_units_with_options`]: crate::parse_units_with_options
+//! [`parse_partial_units_with_options`]: crate::parse_partial_units_with_options
 //!
 //! [`NumberFormatBuilder`]: crate::NumberFormatBuilder
+//! [`DynamicFormat`]: crate::DynamicFormat
+//! [`Format`]: crate::Format
+//! [`NumberFormat`]: crate::NumberFormat
 //! [`ParseFloatOptions`]: crate::ParseFloatOptions
 //! [`ParseIntegerOptions`]: crate::ParseIntegerOptions
 //! [`WriteFloatOptions`]: crate::WriteFloatOptions
@@ -332,24 +367,58 @@
 #![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(all(feature = "integers", feature = "saturating"))]
+use core::num::Saturating;
+#[cfg(feature = "integers")]
+use core::num::{
+    NonZeroI128,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI8,
+    NonZeroIsize,
+    NonZeroU128,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU8,
+    NonZeroUsize,
+    Wrapping,
+};
+
 #[cfg(feature = "parse-floats")]
 use lexical_parse_float::{
     FromLexical as FromFloat,
+    FromLexicalWithFormat as FromFloatWithFormat,
     FromLexicalWithOptions as FromFloatWithOptions,
 };
 #[cfg(feature = "parse-integers")]
 use lexical_parse_integer::{
     FromLexical as FromInteger,
+    FromLexicalWithFormat as FromIntegerWithFormat,
     FromLexicalWithOptions as FromIntegerWithOptions,
+    OverflowMode,
 };
+#[cfg(feature = "parse-integers")]
+use lexical_util::format::STANDARD;
+#[cfg(feature = "parse-integers")]
+use lexical_util::unicode::unicode_digit;
 #[cfg(feature = "parse")]
-use lexical_util::{from_lexical, from_lexical_with_options};
+use lexical_util::{from_lexical, from_lexical_with_format, from_lexical_with_options};
 #[cfg(feature = "write")]
-use lexical_util::{to_lexical, to_lexical_with_options};
+use lexical_util::{to_lexical, to_lexical_with_format, to_lexical_with_options};
 #[cfg(feature = "write-floats")]
-use lexical_write_float::{ToLexical as ToFloat, ToLexicalWithOptions as ToFloatWithOptions};
+use lexical_write_float::{
+    ToLexical as ToFloat,
+    ToLexicalWithFormat as ToFloatWithFormat,
+    ToLexicalWithOptions as ToFloatWithOptions,
+};
 #[cfg(feature = "write-integers")]
-use lexical_write_integer::{ToLexical as ToInteger, ToLexicalWithOptions as ToIntegerWithOptions};
+use lexical_write_integer::{
+    ToLexical as ToInteger,
+    ToLexicalWithFormat as ToIntegerWithFormat,
+    ToLexicalWithOptions as ToIntegerWithOptions,
+};
 
 // Re-exports
 #[cfg(feature = "parse-floats")]
@@ -366,17 +435,44 @@ pub use lexical_parse_integer::{
 };
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
+#[cfg(feature = "parse")]
+pub use lexical_util::code_unit::CodeUnit;
+#[cfg(feature = "complex")]
+pub use lexical_util::complex::Complex;
 #[cfg(feature = "write")]
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
+#[cfg(feature = "custom-float")]
+pub use lexical_util::custom_float::{self, f8e4m3, f8e5m2, tf32, CustomFloat};
+#[cfg(feature = "decimal")]
+pub use lexical_util::decimal::{decimal128, decimal32, decimal64, DecimalFloat};
+#[cfg(feature = "double-double")]
+pub use lexical_util::double_double::DoubleDouble;
 #[cfg(feature = "parse")]
 pub use lexical_util::error::Error;
+#[cfg(feature = "f80")]
+pub use lexical_util::f80::f80;
+#[cfg(feature = "f128")]
+pub use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
-pub use lexical_util::format::{self, format_error, format_is_valid, NumberFormatBuilder};
+pub use lexical_util::format::{
+    self,
+    format_error,
+    format_is_valid,
+    DynamicFormat,
+    Format,
+    NumberFormat,
+    NumberFormatBuilder,
+};
+pub use lexical_util::grouping::DigitGrouping;
 #[cfg(feature = "parse")]
 pub use lexical_util::options::ParseOptions;
+#[cfg(feature = "parse")]
+pub use lexical_util::punctuation::{self, Punctuation};
 #[cfg(feature = "write")]
 pub use lexical_util::options::WriteOptions;
+#[cfg(feature = "rational")]
+pub use lexical_util::rational::Rational;
 #[cfg(feature = "parse")]
 pub use lexical_util::result::Result;
 #[cfg(feature = "write-floats")]
@@ -385,6 +481,8 @@ pub use lexical_write_float::{
     Options as WriteFloatOptions,
     OptionsBuilder as WriteFloatOptionsBuilder,
 };
+#[cfg(all(feature = "write-floats", feature = "rational"))]
+pub use lexical_write_float::write_rational;
 #[cfg(feature = "write-integers")]
 pub use lexical_write_integer::{
     options as write_integer_options,
@@ -399,16 +497,29 @@ pub use lexical_write_integer::{
 from_lexical!();
 #[cfg(feature = "parse")]
 from_lexical_with_options!();
+#[cfg(feature = "parse")]
+from_lexical_with_format!();
 #[cfg(feature = "write")]
 to_lexical!();
 #[cfg(feature = "write")]
 to_lexical_with_options!();
+#[cfg(feature = "write")]
+to_lexical_with_format!();
 
-/// Implement `FromLexical` and `FromLexicalWithOptions` for numeric type.
+/// Implement `FromLexical`, `FromLexicalWithOptions` and `FromLexicalWithFormat` for numeric type.
 #[cfg(feature = "parse")]
 macro_rules! from_lexical_impl {
-    ($t:ident, $from:ident, $from_options:ident, $options:ident) => {
-        impl FromLexical for $t {
+    ($t:ident, $from:ident, $from_options:ident, $from_format:ident, $options:ident) => {
+        from_lexical_impl!([] $t, $from, $from_options, $from_format, $options);
+    };
+    (
+        [$($generics:tt)*] $t:ty,
+        $from:ident,
+        $from_options:ident,
+        $from_format:ident,
+        $options:ident
+    ) => {
+        impl<$($generics)*> FromLexical for $t {
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical(bytes: &[u8]) -> Result<Self> {
                 <Self as $from>::from_lexical(bytes)
@@ -418,9 +529,19 @@ macro_rules! from_lexical_impl {
             fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)> {
                 <Self as $from>::from_lexical_partial(bytes)
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_units<U: CodeUnit>(units: &[U]) -> Result<Self> {
+                <Self as $from>::from_lexical_units(units)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_units<U: CodeUnit>(units: &[U]) -> Result<(Self, usize)> {
+                <Self as $from>::from_lexical_partial_units(units)
+            }
         }
 
-        impl FromLexicalWithOptions for $t {
+        impl<$($generics)*> FromLexicalWithOptions for $t {
             type Options = $options;
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -438,37 +559,425 @@ macro_rules! from_lexical_impl {
             ) -> Result<(Self, usize)> {
                 <Self as $from_options>::from_lexical_partial_with_options::<FORMAT>(bytes, options)
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_units_with_options<U: CodeUnit, const FORMAT: u128>(
+                units: &[U],
+                options: &Self::Options,
+            ) -> Result<Self> {
+                <Self as $from_options>::from_lexical_units_with_options::<U, FORMAT>(
+                    units, options,
+                )
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_units_with_options<U: CodeUnit, const FORMAT: u128>(
+                units: &[U],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                <Self as $from_options>::from_lexical_partial_units_with_options::<U, FORMAT>(
+                    units, options,
+                )
+            }
+        }
+
+        impl<$($generics)*> FromLexicalWithFormat for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_format<Fmt: Format>(
+                bytes: &[u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> Result<Self> {
+                <Self as $from_format>::from_lexical_with_format(bytes, format, options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_format<Fmt: Format>(
+                bytes: &[u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                <Self as $from_format>::from_lexical_partial_with_format(bytes, format, options)
+            }
         }
     };
 }
 
-/// Implement `FromLexical` and `FromLexicalWithOptions` for integers.
+/// Implement `FromLexical`, `FromLexicalWithOptions` and `FromLexicalWithFormat` for integers.
 #[cfg(feature = "parse-integers")]
 macro_rules! integer_from_lexical {
     ($($t:ident)*) => ($(
-        from_lexical_impl!($t, FromInteger, FromIntegerWithOptions, ParseIntegerOptions);
+        from_lexical_impl!(
+            $t,
+            FromInteger,
+            FromIntegerWithOptions,
+            FromIntegerWithFormat,
+            ParseIntegerOptions
+        );
     )*);
 }
 
 #[cfg(feature = "parse-integers")]
 integer_from_lexical! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
 
-/// Implement `FromLexical` and `FromLexicalWithOptions` for floats.
+#[cfg(feature = "parse-integers")]
+const STANDARD_FORMAT: NumberFormat<STANDARD> = NumberFormat::<{ STANDARD }> {};
+
+/// Get the index of the first zero digit of a number, for [`Error::Zero`].
+///
+/// This skips any whitespace, sign, punctuation and base prefix before
+/// the digits. Non-ASCII zero digits are only found in UTF-8 bytes.
+#[cfg(feature = "parse-integers")]
+#[inline]
+fn zero_index<U: CodeUnit, F: Format>(units: &[U], format: F) -> usize {
+    let bytes = U::as_bytes(units);
+    let is_zero = |i: usize| match bytes {
+        Some(bytes) => bytes[i] == b'0' || matches!(unicode_digit(&bytes[i..]), Some((0, _, _))),
+        None => units[i].to_code_point() == b'0' as u32,
+    };
+    let base_prefix = format.base_prefix() as u32;
+    let is_prefix = |i: usize| match units.get(i + 1).map(|c| c.to_code_point()) {
+        Some(_) if base_prefix == 0 => false,
+        Some(c) if format.case_sensitive_base_prefix() => c == base_prefix,
+        Some(c) if c < 0x80 => (c as u8).eq_ignore_ascii_case(&(base_prefix as u8)),
+        _ => false,
+    };
+    let index = match (0..units.len()).find(|&i| is_zero(i)) {
+        // The leading zero of a base prefix is not a digit of the number.
+        Some(index) if is_prefix(index) => (index + 2..units.len()).find(|&i| is_zero(i)),
+        index => index,
+    };
+    index.unwrap_or(0)
+}
+
+/// Implement `FromLexical`, `FromLexicalWithOptions` and `FromLexicalWithFormat` for non-zero
+/// integers.
+///
+/// Parsing zero returns [`Error::Zero`], with the index of the first
+/// zero digit of the number.
+#[cfg(feature = "parse-integers")]
+macro_rules! nonzero_from_lexical {
+    ($($t:ident $inner:ident ; )*) => ($(
+        impl FromLexical for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical(bytes: &[u8]) -> Result<Self> {
+                let value = <$inner as FromInteger>::from_lexical(bytes)?;
+                Self::new(value).ok_or_else(|| Error::Zero(zero_index(bytes, STANDARD_FORMAT)))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)> {
+                let (value, count) = <$inner as FromInteger>::from_lexical_partial(bytes)?;
+                let error = || Error::Zero(zero_index(&bytes[..count], STANDARD_FORMAT));
+                let value = Self::new(value).ok_or_else(error)?;
+                Ok((value, count))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_units<U: CodeUnit>(units: &[U]) -> Result<Self> {
+                let value = <$inner as FromInteger>::from_lexical_units(units)?;
+                Self::new(value).ok_or_else(|| Error::Zero(zero_index(units, STANDARD_FORMAT)))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_units<U: CodeUnit>(units: &[U]) -> Result<(Self, usize)> {
+                let (value, count) = <$inner as FromInteger>::from_lexical_partial_units(units)?;
+                let error = || Error::Zero(zero_index(&units[..count], STANDARD_FORMAT));
+                let value = Self::new(value).ok_or_else(error)?;
+                Ok((value, count))
+            }
+        }
+
+        impl FromLexicalWithOptions for $t {
+            type Options = ParseIntegerOptions;
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<Self> {
+                let value = <$inner as FromIntegerWithOptions>::from_lexical_with_options::<
+                    FORMAT,
+                >(bytes, options)?;
+                let format = NumberFormat::<{ FORMAT }> {};
+                Self::new(value).ok_or_else(|| Error::Zero(zero_index(bytes, format)))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                let (value, count) = <$inner as FromIntegerWithOptions>::
+                    from_lexical_partial_with_options::<FORMAT>(bytes, options)?;
+                let format = NumberFormat::<{ FORMAT }> {};
+                let error = || Error::Zero(zero_index(&bytes[..count], format));
+                let value = Self::new(value).ok_or_else(error)?;
+                Ok((value, count))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_units_with_options<U: CodeUnit, const FORMAT: u128>(
+                units: &[U],
+                options: &Self::Options,
+            ) -> Result<Self> {
+                let value = <$inner as FromIntegerWithOptions>::
+                    from_lexical_units_with_options::<U, FORMAT>(units, options)?;
+                let format = NumberFormat::<{ FORMAT }> {};
+                Self::new(value).ok_or_else(|| Error::Zero(zero_index(units, format)))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_units_with_options<U: CodeUnit, const FORMAT: u128>(
+                units: &[U],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                let (value, count) = <$inner as FromIntegerWithOptions>::
+                    from_lexical_partial_units_with_options::<U, FORMAT>(units, options)?;
+                let format = NumberFormat::<{ FORMAT }> {};
+                let error = || Error::Zero(zero_index(&units[..count], format));
+                let value = Self::new(value).ok_or_else(error)?;
+                Ok((value, count))
+            }
+        }
+
+        impl FromLexicalWithFormat for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_format<Fmt: Format>(
+                bytes: &[u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> Result<Self> {
+                let value = <$inner as FromIntegerWithFormat>::from_lexical_with_format(
+                    bytes, format, options,
+                )?;
+                Self::new(value).ok_or_else(|| Error::Zero(zero_index(bytes, format)))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_format<Fmt: Format>(
+                bytes: &[u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                let (value, count) = <$inner as FromIntegerWithFormat>::
+                    from_lexical_partial_with_format(bytes, format, options)?;
+                let error = || Error::Zero(zero_index(&bytes[..count], format));
+                let value = Self::new(value).ok_or_else(error)?;
+                Ok((value, count))
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "parse-integers")]
+nonzero_from_lexical! {
+    NonZeroU8 u8 ;
+    NonZeroU16 u16 ;
+    NonZeroU32 u32 ;
+    NonZeroU64 u64 ;
+    NonZeroU128 u128 ;
+    NonZeroUsize usize ;
+    NonZeroI8 i8 ;
+    NonZeroI16 i16 ;
+    NonZeroI32 i32 ;
+    NonZeroI64 i64 ;
+    NonZeroI128 i128 ;
+    NonZeroIsize isize ;
+}
+
+/// Set the overflow mode of integer parse options.
+#[cfg(feature = "parse-integers")]
+#[inline(always)]
+fn with_overflow_mode(options: &ParseIntegerOptions, mode: OverflowMode) -> ParseIntegerOptions {
+    let mut options = options.clone();
+    // SAFETY: always safe, the overflow mode is not validated.
+    unsafe { options.set_overflow_mode(mode) };
+    options
+}
+
+/// Implement `FromLexical`, `FromLexicalWithOptions` and `FromLexicalWithFormat` for integer
+/// wrappers.
+///
+/// Values that overflow the wrapped integer use the overflow mode of the
+/// wrapper, so `Wrapping` wraps and `Saturating` saturates, regardless of
+/// [`ParseIntegerOptions::overflow_mode`].
+#[cfg(feature = "parse-integers")]
+macro_rules! wrapper_from_lexical {
+    ($($t:ident $mode:ident ; )*) => ($(
+        impl<T> FromLexical for $t<T>
+        where
+            T: FromIntegerWithFormat<Options = ParseIntegerOptions>,
+        {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical(bytes: &[u8]) -> Result<Self> {
+                let options = ParseIntegerOptions::new();
+                Self::from_lexical_with_options::<STANDARD>(bytes, &options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)> {
+                let options = ParseIntegerOptions::new();
+                Self::from_lexical_partial_with_options::<STANDARD>(bytes, &options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_units<U: CodeUnit>(units: &[U]) -> Result<Self> {
+                let options = ParseIntegerOptions::new();
+                Self::from_lexical_units_with_options::<U, STANDARD>(units, &options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_units<U: CodeUnit>(units: &[U]) -> Result<(Self, usize)> {
+                let options = ParseIntegerOptions::new();
+                Self::from_lexical_partial_units_with_options::<U, STANDARD>(units, &options)
+            }
+        }
+
+        impl<T> FromLexicalWithOptions for $t<T>
+        where
+            T: FromIntegerWithFormat<Options = ParseIntegerOptions>,
+        {
+            type Options = ParseIntegerOptions;
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<Self> {
+                let options = with_overflow_mode(options, OverflowMode::$mode);
+                T::from_lexical_with_options::<FORMAT>(bytes, &options).map($t)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                let options = with_overflow_mode(options, OverflowMode::$mode);
+                let result = T::from_lexical_partial_with_options::<FORMAT>(bytes, &options);
+                result.map(|(value, count)| ($t(value), count))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_units_with_options<U: CodeUnit, const FORMAT: u128>(
+                units: &[U],
+                options: &Self::Options,
+            ) -> Result<Self> {
+                let options = with_overflow_mode(options, OverflowMode::$mode);
+                T::from_lexical_units_with_options::<U, FORMAT>(units, &options).map($t)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_units_with_options<U: CodeUnit, const FORMAT: u128>(
+                units: &[U],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                let options = with_overflow_mode(options, OverflowMode::$mode);
+                let result =
+                    T::from_lexical_partial_units_with_options::<U, FORMAT>(units, &options);
+                result.map(|(value, count)| ($t(value), count))
+            }
+        }
+
+        impl<T> FromLexicalWithFormat for $t<T>
+        where
+            T: FromIntegerWithFormat<Options = ParseIntegerOptions>,
+        {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_format<Fmt: Format>(
+                bytes: &[u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> Result<Self> {
+                let options = with_overflow_mode(options, OverflowMode::$mode);
+                T::from_lexical_with_format(bytes, format, &options).map($t)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_format<Fmt: Format>(
+                bytes: &[u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                let options = with_overflow_mode(options, OverflowMode::$mode);
+                let result = T::from_lexical_partial_with_format(bytes, format, &options);
+                result.map(|(value, count)| ($t(value), count))
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "parse-integers")]
+wrapper_from_lexical! { Wrapping Wrap ; }
+
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+wrapper_from_lexical! { Saturating Saturate ; }
+
+/// Implement `FromLexical`, `FromLexicalWithOptions` and `FromLexicalWithFormat` for floats.
 #[cfg(feature = "parse-floats")]
 macro_rules! float_from_lexical {
     ($($t:ident)*) => ($(
-        from_lexical_impl!($t, FromFloat, FromFloatWithOptions, ParseFloatOptions);
+        from_lexical_impl!(
+            $t,
+            FromFloat,
+            FromFloatWithOptions,
+            FromFloatWithFormat,
+            ParseFloatOptions
+        );
     )*);
 }
 
 #[cfg(feature = "parse-floats")]
 float_from_lexical! { f32 f64 }
+#[cfg(all(feature = "parse-floats", feature = "f80"))]
+float_from_lexical! { f80 }
+#[cfg(all(feature = "parse-floats", feature = "f128"))]
+float_from_lexical! { f128 }
+#[cfg(all(feature = "parse-floats", feature = "custom-float"))]
+from_lexical_impl!(
+    [const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8]
+        CustomFloat<EXPONENT, MANTISSA, SPECIAL>,
+    FromFloat,
+    FromFloatWithOptions,
+    FromFloatWithFormat,
+    ParseFloatOptions
+);
+#[cfg(all(feature = "parse-floats", feature = "double-double"))]
+float_from_lexical! { DoubleDouble }
+#[cfg(all(feature = "parse-floats", feature = "complex"))]
+from_lexical_impl!(
+    [F: FromFloatWithFormat<Options = ParseFloatOptions> + Default] Complex<F>,
+    FromFloat,
+    FromFloatWithOptions,
+    FromFloatWithFormat,
+    ParseFloatOptions
+);
+#[cfg(all(feature = "parse-floats", feature = "rational"))]
+from_lexical_impl!(
+    Rational,
+    FromFloat,
+    FromFloatWithOptions,
+    FromFloatWithFormat,
+    ParseFloatOptions
+);
+#[cfg(all(feature = "parse-floats", feature = "decimal"))]
+float_from_lexical! { decimal32 decimal64 decimal128 }
 
 // Implement ToLexical for numeric type.
 #[cfg(feature = "write")]
 macro_rules! to_lexical_impl {
-    ($t:ident, $to:ident, $to_options:ident, $options:ident) => {
-        impl ToLexical for $t {
+    ($t:ident, $to:ident, $to_options:ident, $to_format:ident, $options:ident) => {
+        to_lexical_impl!([] $t, $to, $to_options, $to_format, $options);
+    };
+    (
+        [$($generics:tt)*] $t:ty,
+        $to:ident,
+        $to_options:ident,
+        $to_format:ident,
+        $options:ident
+    ) => {
+        impl<$($generics)*> ToLexical for $t {
             #[cfg_attr(not(feature = "compact"), inline)]
             unsafe fn to_lexical_unchecked<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8] {
                 // SAFETY: safe as long as `bytes` is large enough to hold the significant digits.
@@ -481,7 +990,7 @@ macro_rules! to_lexical_impl {
             }
         }
 
-        impl ToLexicalWithOptions for $t {
+        impl<$($generics)*> ToLexicalWithOptions for $t {
             type Options = $options;
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -507,30 +1016,214 @@ macro_rules! to_lexical_impl {
                 <Self as $to_options>::to_lexical_with_options::<FORMAT>(self, bytes, options)
             }
         }
+
+        impl<$($generics)*> ToLexicalWithFormat for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            unsafe fn to_lexical_with_format_unchecked<'a, Fmt: Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                // SAFETY: safe as long as `bytes` is large enough to hold the significant digits.
+                unsafe {
+                    <Self as $to_format>::to_lexical_with_format_unchecked(
+                        self, bytes, format, options,
+                    )
+                }
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn to_lexical_with_format<'a, Fmt: Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                <Self as $to_format>::to_lexical_with_format(self, bytes, format, options)
+            }
+        }
     };
 }
 
-/// Implement `ToLexical` and `ToLexicalWithOptions` for integers.
+/// Implement `ToLexical`, `ToLexicalWithOptions` and `ToLexicalWithFormat` for integers.
 #[cfg(feature = "write-integers")]
 macro_rules! integer_to_lexical {
     ($($t:ident)*) => ($(
-        to_lexical_impl!($t, ToInteger, ToIntegerWithOptions, WriteIntegerOptions);
+        to_lexical_impl!(
+            $t,
+            ToInteger,
+            ToIntegerWithOptions,
+            ToIntegerWithFormat,
+            WriteIntegerOptions
+        );
     )*);
 }
 
 #[cfg(feature = "write-integers")]
 integer_to_lexical! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
 
-/// Implement `ToLexical` and `ToLexicalWithOptions` for floats.
+/// Implement `ToLexical`, `ToLexicalWithOptions` and `ToLexicalWithFormat` for integer newtypes.
+///
+/// The generics and bounds are passed in brackets, and the wrapped
+/// integer is extracted by the `$get` closure.
+#[cfg(feature = "write-integers")]
+macro_rules! wrapper_to_lexical {
+    ($([$($generics:tt)*] $t:ty, $inner:ty, $get:expr ; )*) => ($(
+        impl<$($generics)*> ToLexical for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            unsafe fn to_lexical_unchecked(self, bytes: &mut [u8]) -> &mut [u8] {
+                // SAFETY: safe as long as `bytes` is large enough to hold the significant digits.
+                unsafe { <$inner as ToInteger>::to_lexical_unchecked($get(self), bytes) }
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn to_lexical(self, bytes: &mut [u8]) -> &mut [u8] {
+                <$inner as ToInteger>::to_lexical($get(self), bytes)
+            }
+        }
+
+        impl<$($generics)*> ToLexicalWithOptions for $t {
+            type Options = WriteIntegerOptions;
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                // SAFETY: safe as long as `bytes` is large enough to hold the significant digits.
+                unsafe {
+                    <$inner as ToIntegerWithOptions>::to_lexical_with_options_unchecked::<FORMAT>(
+                        $get(self),
+                        bytes,
+                        options,
+                    )
+                }
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                <$inner as ToIntegerWithOptions>::to_lexical_with_options::<FORMAT>(
+                    $get(self),
+                    bytes,
+                    options,
+                )
+            }
+        }
+
+        impl<$($generics)*> ToLexicalWithFormat for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            unsafe fn to_lexical_with_format_unchecked<'a, Fmt: Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                // SAFETY: safe as long as `bytes` is large enough to hold the significant digits.
+                unsafe {
+                    <$inner as ToIntegerWithFormat>::to_lexical_with_format_unchecked(
+                        $get(self),
+                        bytes,
+                        format,
+                        options,
+                    )
+                }
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn to_lexical_with_format<'a, Fmt: Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                <$inner as ToIntegerWithFormat>::to_lexical_with_format(
+                    $get(self),
+                    bytes,
+                    format,
+                    options,
+                )
+            }
+        }
+    )*);
+}
+
+/// Implement `ToLexical`, `ToLexicalWithOptions` and `ToLexicalWithFormat` for non-zero integers.
+#[cfg(feature = "write-integers")]
+macro_rules! nonzero_to_lexical {
+    ($($t:ident $inner:ident ; )*) => ($(
+        wrapper_to_lexical! { [] $t, $inner, |x: $t| x.get() ; }
+    )*);
+}
+
+#[cfg(feature = "write-integers")]
+nonzero_to_lexical! {
+    NonZeroU8 u8 ;
+    NonZeroU16 u16 ;
+    NonZeroU32 u32 ;
+    NonZeroU64 u64 ;
+    NonZeroU128 u128 ;
+    NonZeroUsize usize ;
+    NonZeroI8 i8 ;
+    NonZeroI16 i16 ;
+    NonZeroI32 i32 ;
+    NonZeroI64 i64 ;
+    NonZeroI128 i128 ;
+    NonZeroIsize isize ;
+}
+
+#[cfg(feature = "write-integers")]
+wrapper_to_lexical! {
+    [T: ToIntegerWithFormat<Options = WriteIntegerOptions> + ToInteger]
+        Wrapping<T>, T, |x: Wrapping<T>| x.0 ;
+}
+
+#[cfg(all(feature = "write-integers", feature = "saturating"))]
+wrapper_to_lexical! {
+    [T: ToIntegerWithFormat<Options = WriteIntegerOptions> + ToInteger]
+        Saturating<T>, T, |x: Saturating<T>| x.0 ;
+}
+
+/// Implement `ToLexical`, `ToLexicalWithOptions` and `ToLexicalWithFormat` for floats.
 #[cfg(feature = "write-floats")]
 macro_rules! float_to_lexical {
     ($($t:ident)*) => ($(
-        to_lexical_impl!($t, ToFloat, ToFloatWithOptions, WriteFloatOptions);
+        to_lexical_impl!($t, ToFloat, ToFloatWithOptions, ToFloatWithFormat, WriteFloatOptions);
     )*);
 }
 
 #[cfg(feature = "write-floats")]
 float_to_lexical! { f32 f64 }
+#[cfg(all(feature = "write-floats", feature = "f80"))]
+float_to_lexical! { f80 }
+#[cfg(all(feature = "write-floats", feature = "f128"))]
+float_to_lexical! { f128 }
+#[cfg(all(feature = "write-floats", feature = "custom-float"))]
+to_lexical_impl!(
+    [const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8]
+        CustomFloat<EXPONENT, MANTISSA, SPECIAL>,
+    ToFloat,
+    ToFloatWithOptions,
+    ToFloatWithFormat,
+    WriteFloatOptions
+);
+#[cfg(all(feature = "write-floats", feature = "double-double"))]
+float_to_lexical! { DoubleDouble }
+#[cfg(all(feature = "write-floats", feature = "complex"))]
+to_lexical_impl!(
+    [F: ToFloatWithFormat<Options = WriteFloatOptions> + FormattedSize] Complex<F>,
+    ToFloat,
+    ToFloatWithOptions,
+    ToFloatWithFormat,
+    WriteFloatOptions
+);
+#[cfg(all(feature = "write-floats", feature = "decimal"))]
+float_to_lexical! { decimal32 decimal64 decimal128 }
 
 /// Write number to string.
 ///
@@ -756,6 +1449,111 @@ pub unsafe fn write_with_options_unchecked<'a, N: ToLexicalWithOptions, const FO
     unsafe { n.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
 }
 
+/// Write number to string with custom options and a runtime number format.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `format`  - Number format validated at runtime.
+/// * `options` - Options to customize number parsing.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least `{integer}::FORMATTED_SIZE` elements. If you
+/// are using custom digit precision control or exponent break points
+/// for writing floats, these constants may be insufficient to store
+/// the serialized number, and up to 1200 bytes may be required with
+/// radix support.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// // import `BUFFER_SIZE` to get the maximum bytes written by the number.
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let float = 3.14159265359_f32;
+///
+/// let format = lexical_core::NumberFormatBuilder::new().build_dynamic().unwrap();
+/// let options = lexical_core::WriteFloatOptions::new();
+/// lexical_core::write_with_options_dyn(float, &mut buffer, format, &options);
+///
+/// assert_eq!(&buffer[0..9], b"3.1415927");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_with_options_dyn<'a, N: ToLexicalWithFormat>(
+    n: N,
+    bytes: &'a mut [u8],
+    format: DynamicFormat,
+    options: &N::Options,
+) -> &'a mut [u8] {
+    n.to_lexical_with_format(bytes, format, options)
+}
+
+/// Write number to string with custom options and a runtime number format.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `format`  - Number format validated at runtime.
+/// * `options` - Options to customize number parsing.
+///
+/// # Safety
+///
+/// If the buffer is not be large enough to hold the serialized number,
+/// it will overflow the buffer unless the `safe` feature is enabled.
+/// Buffer overflows are severe security vulnerabilities, and therefore
+/// to ensure the function will not overwrite the buffer, provide a
+/// buffer with at least `{integer}::FORMATTED_SIZE` elements. If you
+/// are using custom digit precision control or exponent break points
+/// for writing floats, these constants may be insufficient to store
+/// the serialized number, and up to 1200 bytes may be required with
+/// radix support.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// // import `BUFFER_SIZE` to get the maximum bytes written by the number.
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let float = 3.14159265359_f32;
+///
+/// let format = lexical_core::NumberFormatBuilder::new().build_dynamic().unwrap();
+/// let options = lexical_core::WriteFloatOptions::new();
+/// unsafe {
+///     lexical_core::write_with_options_unchecked_dyn(float, &mut buffer, format, &options);
+/// }
+///
+/// assert_eq!(&buffer[0..9], b"3.1415927");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub unsafe fn write_with_options_unchecked_dyn<'a, N: ToLexicalWithFormat>(
+    n: N,
+    bytes: &'a mut [u8],
+    format: DynamicFormat,
+    options: &N::Options,
+) -> &'a mut [u8] {
+    // SAFETY: safe if the provided buffer is large enough for the numerical string
+    unsafe { n.to_lexical_with_format_unchecked(bytes, format, options) }
+}
+
 /// Parse complete number from string.
 ///
 /// This method parses the entire string, returning an error if
@@ -867,3 +1665,187 @@ pub fn parse_partial_with_options<N: FromLexicalWithOptions, const FORMAT: u128>
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options::<FORMAT>(bytes, options)
 }
+
+/// Parse complete number from string with custom parsing options and a runtime number format.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Number format validated at runtime.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// let format = lexical_core::DynamicFormat::new(lexical_core::format::JSON).unwrap();
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let string = "3.14159265359";
+/// let result = lexical_core::parse_with_options_dyn::<f32>(string.as_bytes(), format, &options);
+/// assert_eq!(result, Ok(3.14159265359_f32));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_with_options_dyn<N: FromLexicalWithFormat>(
+    bytes: &[u8],
+    format: DynamicFormat,
+    options: &N::Options,
+) -> Result<N> {
+    N::from_lexical_with_format(bytes, format, options)
+}
+
+/// Parse partial number from string with custom parsing options and a runtime number format.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Number format validated at runtime.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// let format = lexical_core::DynamicFormat::new(lexical_core::format::JSON).unwrap();
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let string = "3.14159265359 hello";
+/// let bytes = string.as_bytes();
+/// let result = lexical_core::parse_partial_with_options_dyn::<f32>(bytes, format, &options);
+/// assert_eq!(result, Ok((3.14159265359_f32, 13)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_with_options_dyn<N: FromLexicalWithFormat>(
+    bytes: &[u8],
+    format: DynamicFormat,
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    N::from_lexical_partial_with_format(bytes, format, options)
+}
+
+/// Parse complete number from a slice of code units.
+///
+/// This is the same as [`parse`], except it accepts `&[u16]`, `&[char]`,
+/// or any other [`CodeUnit`] slice. Non-ASCII code units are invalid digits.
+///
+/// * `units`   - Code unit slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// let string: Vec<u16> = "3.14159265359".encode_utf16().collect();
+/// let result = lexical_core::parse_units::<f32, u16>(&string);
+/// assert_eq!(result, Ok(3.14159265359_f32));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_units<N: FromLexical, U: CodeUnit>(units: &[U]) -> Result<N> {
+    N::from_lexical_units(units)
+}
+
+/// Parse partial number from a slice of code units.
+///
+/// This is the same as [`parse_partial`], except it accepts `&[u16]`,
+/// `&[char]`, or any other [`CodeUnit`] slice, and returns the number
+/// of processed code units. Non-ASCII code units are invalid digits.
+///
+/// * `units`   - Code unit slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// let string: Vec<char> = "3.14159265359 héllo".chars().collect();
+/// let result = lexical_core::parse_partial_units::<f32, char>(&string);
+/// assert_eq!(result, Ok((3.14159265359_f32, 13)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_units<N: FromLexical, U: CodeUnit>(units: &[U]) -> Result<(N, usize)> {
+    N::from_lexical_partial_units(units)
+}
+
+/// Parse complete number from a slice of code units with custom parsing options.
+///
+/// This is the same as [`parse_with_options`], except it accepts `&[u16]`,
+/// `&[char]`, or any other [`CodeUnit`] slice. Non-ASCII code units are
+/// invalid digits.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `units`   - Code unit slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// const JSON: u128 = lexical_core::format::JSON;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let string: Vec<u16> = "3.14159265359".encode_utf16().collect();
+/// let result = lexical_core::parse_units_with_options::<f32, u16, JSON>(&string, &options);
+/// assert_eq!(result, Ok(3.14159265359_f32));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_units_with_options<N: FromLexicalWithOptions, U: CodeUnit, const FORMAT: u128>(
+    units: &[U],
+    options: &N::Options,
+) -> Result<N> {
+    N::from_lexical_units_with_options::<U, FORMAT>(units, options)
+}
+
+/// Parse partial number from a slice of code units with custom parsing options.
+///
+/// This is the same as [`parse_partial_with_options`], except it accepts
+/// `&[u16]`, `&[char]`, or any other [`CodeUnit`] slice, and returns the
+/// number of processed code units. Non-ASCII code units are invalid digits.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `units`   - Code unit slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// const JSON: u128 = lexical_core::format::JSON;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let string: Vec<u16> = "3.14159265359 hello".encode_utf16().collect();
+/// let result =
+///     lexical_core::parse_partial_units_with_options::<f32, u16, JSON>(&string, &options);
+/// assert_eq!(result, Ok((3.14159265359_f32, 13)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_units_with_options<
+    N: FromLexicalWithOptions,
+    U: CodeUnit,
+    const FORMAT: u128,
+>(
+    units: &[U],
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    N::from_lexical_partial_units_with_options::<U, FORMAT>(units, options)
+}
@@ -53,3 +53,440 @@ fn string_to_float_test() {
         Ok((12345.0f32, 7))
     );
 }
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_to_string_dyn_test() {
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    let options = lexical_core::WriteIntegerOptions::new();
+    let format = lexical_core::DynamicFormat::default();
+    assert_eq!(
+        lexical_core::write_with_options_dyn(12345u32, &mut buffer, format, &options),
+        b"12345"
+    );
+    #[cfg(feature = "power-of-two")]
+    {
+        let format = lexical_core::NumberFormatBuilder::new().radix(16).build_dynamic().unwrap();
+        assert_eq!(
+            lexical_core::write_with_options_dyn(12345u32, &mut buffer, format, &options),
+            b"3039"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_to_string_dyn_test() {
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    let options = lexical_core::WriteFloatOptions::new();
+    let format = lexical_core::DynamicFormat::default();
+    assert_eq!(
+        lexical_core::write_with_options_dyn(12345.0f32, &mut buffer, format, &options),
+        b"12345.0"
+    );
+    #[cfg(feature = "power-of-two")]
+    {
+        let format = lexical_core::NumberFormatBuilder::new().radix(2).build_dynamic().unwrap();
+        assert_eq!(
+            lexical_core::write_with_options_dyn(12.5f32, &mut buffer, format, &options),
+            b"1100.1"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn string_to_integer_dyn_test() {
+    let options = lexical_core::ParseIntegerOptions::new();
+    let format = lexical_core::DynamicFormat::default();
+    assert_eq!(lexical_core::parse_with_options_dyn(b"12345", format, &options), Ok(12345u32));
+    assert_eq!(
+        lexical_core::parse_partial_with_options_dyn(b"12345a", format, &options),
+        Ok((12345u32, 5))
+    );
+    #[cfg(feature = "power-of-two")]
+    {
+        let format = lexical_core::NumberFormatBuilder::new().radix(16).build_dynamic().unwrap();
+        assert_eq!(lexical_core::parse_with_options_dyn(b"3039", format, &options), Ok(12345u32));
+    }
+    #[cfg(feature = "format")]
+    {
+        const FORMAT: u128 = lexical_core::format::RUST_LITERAL;
+        let format = lexical_core::DynamicFormat::new(FORMAT).unwrap();
+        for &input in &[&b"1_234"[..], b"_1", b"1__2", b"1_"] {
+            assert_eq!(
+                lexical_core::parse_with_options_dyn::<i32>(input, format, &options),
+                lexical_core::parse_with_options::<i32, FORMAT>(input, &options)
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn string_to_float_dyn_test() {
+    let options = lexical_core::ParseFloatOptions::new();
+    let format = lexical_core::DynamicFormat::default();
+    assert_eq!(lexical_core::parse_with_options_dyn(b"12345.0", format, &options), Ok(12345.0f32));
+    assert_eq!(
+        lexical_core::parse_partial_with_options_dyn(b"12345.0", format, &options),
+        Ok((12345.0f32, 7))
+    );
+    #[cfg(feature = "format")]
+    {
+        const FORMAT: u128 = lexical_core::format::JSON;
+        let format = lexical_core::DynamicFormat::new(FORMAT).unwrap();
+        for &input in &[&b"1.5e3"[..], b"1.", b".5", b"+1", b"NaN", b"01"] {
+            assert_eq!(
+                lexical_core::parse_with_options_dyn::<f64>(input, format, &options),
+                lexical_core::parse_with_options::<f64, FORMAT>(input, &options)
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "format"))]
+fn string_to_integer_runtime_format_test() {
+    use core::num;
+
+    // Formats without a pre-defined constant are supported at runtime.
+    let options = lexical_core::ParseIntegerOptions::new();
+    let format = lexical_core::NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'!'))
+        .internal_digit_separator(true)
+        .build_dynamic()
+        .unwrap();
+    assert_eq!(lexical_core::parse_with_options_dyn(b"1!234!567", format, &options), Ok(1234567));
+    assert_eq!(
+        lexical_core::parse_with_options_dyn::<i32>(b"!1", format, &options),
+        Err(lexical_core::Error::InvalidDigit(0))
+    );
+
+    #[cfg(feature = "power-of-two")]
+    {
+        let format = lexical_core::NumberFormatBuilder::new()
+            .base_prefix(num::NonZeroU8::new(b'd'))
+            .build_dynamic()
+            .unwrap();
+        assert_eq!(lexical_core::parse_with_options_dyn(b"0d123", format, &options), Ok(123));
+        assert_eq!(lexical_core::parse_with_options_dyn(b"-0d123", format, &options), Ok(-123));
+        assert_eq!(lexical_core::parse_with_options_dyn(b"123", format, &options), Ok(123));
+
+        let format = lexical_core::NumberFormatBuilder::new()
+            .radix(16)
+            .base_prefix(num::NonZeroU8::new(b'x'))
+            .build_dynamic()
+            .unwrap();
+        assert_eq!(lexical_core::parse_with_options_dyn(b"0xFF", format, &options), Ok(255));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats", feature = "format"))]
+fn float_runtime_format_test() {
+    use core::num;
+
+    // Formats without a pre-defined constant are supported at runtime.
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    let parse_options = lexical_core::ParseFloatOptions::new();
+    let write_options = lexical_core::WriteFloatOptions::new();
+    let format = lexical_core::NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'!'))
+        .internal_digit_separator(true)
+        .build_dynamic()
+        .unwrap();
+    assert_eq!(
+        lexical_core::parse_with_options_dyn(b"1!234.5!6", format, &parse_options),
+        Ok(1234.56f64)
+    );
+    assert_eq!(
+        lexical_core::write_with_options_dyn(1234.56f64, &mut buffer, format, &write_options),
+        b"1234.56"
+    );
+
+    #[cfg(feature = "power-of-two")]
+    {
+        let format = lexical_core::NumberFormatBuilder::new()
+            .mantissa_radix(2)
+            .exponent_base(num::NonZeroU8::new(2))
+            .exponent_radix(num::NonZeroU8::new(2))
+            .build_dynamic()
+            .unwrap();
+        let parse_options =
+            lexical_core::ParseFloatOptions::builder().exponent(b'p').build().unwrap();
+        let write_options =
+            lexical_core::WriteFloatOptions::builder().exponent(b'p').build().unwrap();
+        assert_eq!(
+            lexical_core::parse_with_options_dyn(b"1.1p11", format, &parse_options),
+            Ok(12.0f64)
+        );
+        assert_eq!(
+            lexical_core::write_with_options_dyn(12.0f64, &mut buffer, format, &write_options),
+            b"1100.0"
+        );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "parse-integers"))]
+fn string_to_number_units_test() {
+    let units: Vec<u16> = "3.5".encode_utf16().collect();
+    assert_eq!(Ok(3.5), lexical_core::parse_units::<f32, _>(&units));
+    let units: Vec<char> = "15\u{a0}".chars().collect();
+    assert_eq!(Err(lexical_core::Error::InvalidDigit(2)), lexical_core::parse_units::<i32, _>(&units));
+    assert_eq!(Ok((15, 2)), lexical_core::parse_partial_units::<i32, _>(&units));
+
+    const STANDARD: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::ParseFloatOptions::new();
+    let units: Vec<u16> = "1e5 ".encode_utf16().collect();
+    assert_eq!(
+        Ok((1e5, 3)),
+        lexical_core::parse_partial_units_with_options::<f64, _, STANDARD>(&units, &options)
+    );
+    let options = lexical_core::ParseIntegerOptions::new();
+    assert_eq!(
+        Err(lexical_core::Error::InvalidDigit(1)),
+        lexical_core::parse_units_with_options::<u8, _, STANDARD>(&units, &options)
+    );
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn string_to_nonzero_test() {
+    use core::num::{NonZeroI32, NonZeroU8};
+
+    assert_eq!(lexical_core::parse(b"12"), Ok(NonZeroU8::new(12).unwrap()));
+    assert_eq!(lexical_core::parse(b"-5"), Ok(NonZeroI32::new(-5).unwrap()));
+    assert_eq!(lexical_core::parse::<NonZeroU8>(b"0"), Err(lexical_core::Error::Zero(0)));
+    assert_eq!(lexical_core::parse::<NonZeroI32>(b"-000"), Err(lexical_core::Error::Zero(1)));
+    assert_eq!(lexical_core::parse::<NonZeroU8>(b"256"), Err(lexical_core::Error::Overflow(2)));
+    assert_eq!(lexical_core::parse::<NonZeroU8>(b"1a"), Err(lexical_core::Error::InvalidDigit(1)));
+    assert_eq!(lexical_core::parse_partial(b"7,"), Ok((NonZeroU8::new(7).unwrap(), 1)));
+    assert_eq!(lexical_core::parse_partial::<NonZeroU8>(b"00,"), Err(lexical_core::Error::Zero(0)));
+    assert!(lexical_core::Error::Zero(0).is_zero());
+
+    let units: Vec<u16> = "0".encode_utf16().collect();
+    let result = lexical_core::parse_units::<NonZeroU8, _>(&units);
+    assert_eq!(result, Err(lexical_core::Error::Zero(0)));
+    let units: Vec<u16> = "-00".encode_utf16().collect();
+    let result = lexical_core::parse_units::<NonZeroI32, _>(&units);
+    assert_eq!(result, Err(lexical_core::Error::Zero(1)));
+
+    const STANDARD: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::ParseIntegerOptions::new();
+    assert_eq!(
+        lexical_core::parse_with_options::<NonZeroI32, STANDARD>(b"+0", &options),
+        Err(lexical_core::Error::Zero(1))
+    );
+    let format = lexical_core::DynamicFormat::default();
+    assert_eq!(
+        lexical_core::parse_partial_with_options_dyn::<NonZeroI32>(b"10 ", format, &options),
+        Ok((NonZeroI32::new(10).unwrap(), 2))
+    );
+
+    // The zero of a base prefix is not a zero digit.
+    #[cfg(feature = "power-of-two")]
+    {
+        let format = lexical_core::NumberFormatBuilder::new()
+            .radix(16)
+            .base_prefix(core::num::NonZeroU8::new(b'x'))
+            .build_dynamic()
+            .unwrap();
+        let parse =
+            |x: &[u8]| lexical_core::parse_with_options_dyn::<NonZeroI32>(x, format, &options);
+        assert_eq!(parse(b"0x0"), Err(lexical_core::Error::Zero(2)));
+        assert_eq!(parse(b"0X00"), Err(lexical_core::Error::Zero(2)));
+        assert_eq!(parse(b"-0x0"), Err(lexical_core::Error::Zero(3)));
+        assert_eq!(parse(b"00"), Err(lexical_core::Error::Zero(0)));
+    }
+
+    // Only non-ASCII digits with a value of zero are zero digits.
+    #[cfg(feature = "format")]
+    {
+        use lexical_core::parse_integer_options::OverflowMode;
+
+        const UNICODE: u128 = lexical_core::NumberFormatBuilder::new().unicode_digits(true).build();
+        let options = lexical_core::ParseIntegerOptions::builder()
+            .overflow_mode(OverflowMode::Wrap)
+            .build()
+            .unwrap();
+        let parse = |x: &str| {
+            lexical_core::parse_with_options::<NonZeroU8, UNICODE>(x.as_bytes(), &options)
+        };
+        assert_eq!(parse("٠"), Err(lexical_core::Error::Zero(0)));
+        assert_eq!(parse("٢٥٦٠"), Err(lexical_core::Error::Zero(6)));
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn string_to_wrapper_test() {
+    use core::num::Wrapping;
+
+    assert_eq!(lexical_core::parse(b"255"), Ok(Wrapping(255u8)));
+    assert_eq!(lexical_core::parse_partial(b"-12,"), Ok((Wrapping(-12i16), 3)));
+    assert_eq!(lexical_core::parse(b"256"), Ok(Wrapping(0u8)));
+    assert_eq!(lexical_core::parse(b"-129"), Ok(Wrapping(127i8)));
+    assert_eq!(lexical_core::parse_partial(b"65537,"), Ok((Wrapping(1u16), 5)));
+
+    // The wrapper sets the overflow mode, whatever the options.
+    const STANDARD: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::ParseIntegerOptions::builder()
+        .overflow_mode(lexical_core::parse_integer_options::OverflowMode::Error)
+        .build()
+        .unwrap();
+    assert_eq!(
+        lexical_core::parse_with_options::<Wrapping<u8>, STANDARD>(b"257", &options),
+        Ok(Wrapping(1))
+    );
+    let units: Vec<u16> = "300".encode_utf16().collect();
+    assert_eq!(lexical_core::parse_units(&units), Ok(Wrapping(44u8)));
+
+    #[cfg(feature = "saturating")]
+    {
+        use core::num::Saturating;
+
+        assert_eq!(lexical_core::parse(b"-128"), Ok(Saturating(-128i8)));
+        assert_eq!(lexical_core::parse(b"-129"), Ok(Saturating(-128i8)));
+        assert_eq!(lexical_core::parse(b"99999"), Ok(Saturating(255u8)));
+        assert_eq!(
+            lexical_core::parse_with_options::<Saturating<u8>, STANDARD>(b"256", &options),
+            Ok(Saturating(255))
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn wrapper_to_string_test() {
+    use core::num::{NonZeroI64, NonZeroU128, Wrapping};
+    use lexical_core::FormattedSize;
+
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    let value = NonZeroU128::new(u128::MAX).unwrap();
+    let mut large = [b'0'; NonZeroU128::FORMATTED_SIZE_DECIMAL];
+    assert_eq!(lexical_core::write(value, &mut large), u128::MAX.to_string().as_bytes());
+    assert_eq!(lexical_core::write(NonZeroI64::new(-42).unwrap(), &mut buffer), b"-42");
+    assert_eq!(lexical_core::write(Wrapping(12345u32), &mut buffer), b"12345");
+    assert_eq!(Wrapping::<i8>::FORMATTED_SIZE_DECIMAL, i8::FORMATTED_SIZE_DECIMAL);
+
+    const STANDARD: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteIntegerOptions::new();
+    assert_eq!(
+        lexical_core::write_with_options::<_, STANDARD>(Wrapping(-7i8), &mut buffer, &options),
+        b"-7"
+    );
+
+    #[cfg(feature = "saturating")]
+    {
+        use core::num::Saturating;
+
+        assert_eq!(lexical_core::write(Saturating(u64::MAX), &mut buffer), b"18446744073709551615");
+    }
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "f128"))]
+fn f128_roundtrip_test() {
+    use lexical_core::{f128, FormattedSize};
+
+    let mut buffer = [b'0'; f128::FORMATTED_SIZE_DECIMAL];
+    let value: f128 = lexical_core::parse(b"3.1415926535897932384626433832795028").unwrap();
+    assert_eq!(value.to_bits(), 0x4000921FB54442D18469898CC51701B8);
+    assert_eq!(lexical_core::write(value, &mut buffer), b"3.1415926535897932384626433832795028");
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "f80"))]
+fn f80_roundtrip_test() {
+    use lexical_core::{f80, FormattedSize};
+
+    let mut buffer = [b'0'; f80::FORMATTED_SIZE_DECIMAL];
+    let value: f80 = lexical_core::parse(b"3.14159265358979323846").unwrap();
+    assert_eq!(value.to_bits(), 0x4000C90FDAA22168C235);
+    assert_eq!(lexical_core::write(value, &mut buffer), b"3.1415926535897932385");
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "custom-float"))]
+fn custom_float_roundtrip_test() {
+    use lexical_core::{f8e4m3, f8e5m2, FormattedSize};
+
+    let mut buffer = [b'0'; f8e4m3::FORMATTED_SIZE_DECIMAL];
+    let value: f8e4m3 = lexical_core::parse(b"0.1").unwrap();
+    assert_eq!(value.to_bits(), 0x1D);
+    assert_eq!(lexical_core::write(value, &mut buffer), b"0.1");
+    let value: f8e4m3 = lexical_core::parse(b"1e10").unwrap();
+    assert_eq!(lexical_core::write(value, &mut buffer), b"448.0");
+    let value: f8e5m2 = lexical_core::parse(b"1e10").unwrap();
+    assert_eq!(lexical_core::write(value, &mut buffer), b"inf");
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "decimal"))]
+fn decimal_roundtrip_test() {
+    use lexical_core::{decimal128, DecimalFloat, FormattedSize};
+
+    let mut buffer = [b'0'; decimal128::FORMATTED_SIZE_DECIMAL];
+    let value: decimal128 = lexical_core::parse(b"1.50").unwrap();
+    assert_eq!(value.to_bits(), 0x303C0000000000000000000000000096);
+    assert_eq!(lexical_core::write(value, &mut buffer), b"1.50");
+    assert_eq!(lexical_core::write(value.normalize(), &mut buffer), b"1.5");
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "double-double"))]
+fn double_double_roundtrip_test() {
+    use lexical_core::{DoubleDouble, FormattedSize};
+
+    let mut buffer = [b'0'; DoubleDouble::FORMATTED_SIZE_DECIMAL];
+    let value: DoubleDouble = lexical_core::parse(b"0.1").unwrap();
+    assert_eq!(value, DoubleDouble::new(0.1, -5.551115123125783e-18));
+    assert_eq!(lexical_core::write(value, &mut buffer), b"0.1");
+
+    let strings: [&[u8]; 5] = [
+        b"3.14159265358979323846264338327950288",
+        b"-2.718281828459045235360287471352662",
+        b"1.00000000000000000000000000001",
+        b"6.02214076e-300",
+        b"1.7976931348623157e308",
+    ];
+    for &string in strings.iter() {
+        let value: DoubleDouble = lexical_core::parse(string).unwrap();
+        let written = lexical_core::write(value, &mut buffer);
+        assert_eq!(lexical_core::parse::<DoubleDouble>(written), Ok(value));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "complex"))]
+fn complex_roundtrip_test() {
+    use lexical_core::{Complex, FormattedSize};
+
+    let mut buffer = [b'0'; Complex::<f64>::FORMATTED_SIZE_DECIMAL];
+    let value: Complex<f64> = lexical_core::parse(b"1.5-2e-3i").unwrap();
+    assert_eq!(value, Complex::new(1.5, -2e-3));
+    assert_eq!(lexical_core::write(value, &mut buffer), b"1.5-0.002i");
+
+    let strings: [&[u8]; 4] = [b"1e300+1e-300i", b"-0.1-infi", b"2.5i", b"(1,-2)"];
+    for &string in strings.iter() {
+        let value: Complex<f64> = lexical_core::parse(string).unwrap();
+        let written = lexical_core::write(value, &mut buffer);
+        assert_eq!(lexical_core::parse::<Complex<f64>>(written), Ok(value));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "rational"))]
+fn rational_roundtrip_test() {
+    use lexical_core::format::STANDARD;
+    use lexical_core::Rational;
+
+    let value: Rational = lexical_core::parse(b"0.1").unwrap();
+    assert_eq!(lexical_core::write_rational::<STANDARD>(&value), b"1/10");
+    let value = Rational::from_float(0.1f64).unwrap();
+    let written = lexical_core::write_rational::<STANDARD>(&value);
+    assert_eq!(written, b"3602879701896397/36028797018963968");
+    assert_eq!(lexical_core::parse::<Rational>(&written), Ok(value));
+}
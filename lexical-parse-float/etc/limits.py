@@ -100,3 +100,5 @@ def all_limits(mantissa_size, exponent_size, type_name):
 
 all_limits(23, 8, 'f32')
 all_limits(52, 11, 'f64')
+all_limits(10, 5, 'f16')
+all_limits(7, 8, 'bf16')
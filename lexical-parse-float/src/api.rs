@@ -2,19 +2,65 @@
 
 #![doc(hidden)]
 
+#[cfg(feature = "complex")]
+use crate::complex::is_complex_token;
 use crate::options::Options;
+#[cfg(feature = "complex")]
+use crate::parse::ParseComplex;
+#[cfg(feature = "decimal")]
+use crate::parse::ParseDecimalFloat;
+#[cfg(feature = "double-double")]
+use crate::parse::ParseDoubleDouble;
+#[cfg(any(feature = "f80", feature = "f128", feature = "custom-float"))]
+use crate::parse::ParseExactFloat;
 use crate::parse::ParseFloat;
+#[cfg(feature = "rational")]
+use crate::parse::ParseRational;
+#[cfg(feature = "rational")]
+use crate::rational::is_rational_token;
+use crate::stream::is_token;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+use lexical_util::code_unit::{narrow, CodeUnit};
+#[cfg(feature = "complex")]
+use lexical_util::complex::Complex;
+#[cfg(feature = "custom-float")]
+use lexical_util::custom_float::CustomFloat;
+#[cfg(feature = "decimal")]
+use lexical_util::decimal::{decimal128, decimal32, decimal64};
+#[cfg(feature = "double-double")]
+use lexical_util::double_double::DoubleDouble;
 use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
-use lexical_util::{from_lexical, from_lexical_with_options};
+#[cfg(feature = "f80")]
+use lexical_util::f80::f80;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
+use lexical_util::format::{is_valid_options_punctuation, Format, NumberFormat, STANDARD};
+use lexical_util::grouping::check_grouping;
+#[cfg(feature = "rational")]
+use lexical_util::rational::Rational;
+use lexical_util::unicode::{parse_partial_unicode, parse_unicode, Replacements};
+use lexical_util::whitespace::{parse_partial_whitespace, parse_whitespace};
+use lexical_util::{from_lexical, from_lexical_with_format, from_lexical_with_options};
 
 // API
 
 const DEFAULT_OPTIONS: Options = Options::new();
+const STANDARD_FORMAT: NumberFormat<STANDARD> = NumberFormat::<{ STANDARD }> {};
+
+/// Get the non-ASCII digits and alternative punctuation to replace.
+#[inline(always)]
+fn replacements<F: Format>(format: F, options: &Options) -> Replacements {
+    Replacements::new(
+        format.unicode_digits(),
+        options.punctuation(),
+        format.digit_separator(),
+        options.decimal_point(),
+        options.exponent(),
+    )
+}
 
 /// Implement FromLexical for numeric type.
 ///
@@ -24,11 +70,17 @@ const DEFAULT_OPTIONS: Options = Options::new();
 /// issues.
 macro_rules! float_from_lexical {
     ($($t:ident)*) => ($(
-        impl FromLexical for $t {
+        float_from_lexical!(@impl [] $t);
+    )*);
+    (@impl [$($generics:tt)*] $t:ty) => {
+        float_from_lexical!(@impl [$($generics)*] $t, is_token);
+    };
+    (@impl [$($generics:tt)*] $t:ty, $is_token:ident) => {
+        impl<$($generics)*> FromLexical for $t {
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical(bytes: &[u8]) -> lexical_util::result::Result<Self>
             {
-                Self::parse_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+                Self::parse_complete(bytes, STANDARD_FORMAT, &DEFAULT_OPTIONS)
             }
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -36,11 +88,29 @@ macro_rules! float_from_lexical {
                 bytes: &[u8],
             ) -> lexical_util::result::Result<(Self, usize)>
             {
-                Self::parse_partial::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+                Self::parse_partial(bytes, STANDARD_FORMAT, &DEFAULT_OPTIONS)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_units<U: CodeUnit>(
+                units: &[U],
+            ) -> lexical_util::result::Result<Self>
+            {
+                let is_token = |c| $is_token(c, STANDARD_FORMAT, &DEFAULT_OPTIONS);
+                narrow(units, is_token, Self::from_lexical)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_units<U: CodeUnit>(
+                units: &[U],
+            ) -> lexical_util::result::Result<(Self, usize)>
+            {
+                let is_token = |c| $is_token(c, STANDARD_FORMAT, &DEFAULT_OPTIONS);
+                narrow(units, is_token, Self::from_lexical_partial)
             }
         }
 
-        impl FromLexicalWithOptions for $t {
+        impl<$($generics)*> FromLexicalWithOptions for $t {
             type Options = Options;
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -48,31 +118,136 @@ macro_rules! float_from_lexical {
                 bytes: &[u8],
                 options: &Self::Options,
             ) -> lexical_util::result::Result<Self>
+            {
+                Self::from_lexical_with_format(bytes, NumberFormat::<{ FORMAT }> {}, options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)>
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                Self::from_lexical_partial_with_format(bytes, format, options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_units_with_options<U: CodeUnit, const FORMAT: u128>(
+                units: &[U],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self>
             {
                 let format = NumberFormat::<{ FORMAT }> {};
+                parse_whitespace(units, format, |units| {
+                    narrow(
+                        units,
+                        |c| $is_token(c, format, options),
+                        |bytes| Self::from_lexical_with_format(bytes, format, options),
+                    )
+                })
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_units_with_options<U: CodeUnit, const FORMAT: u128>(
+                units: &[U],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)>
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                parse_partial_whitespace(units, format, |units| {
+                    narrow(
+                        units,
+                        |c| $is_token(c, format, options),
+                        |bytes| Self::from_lexical_partial_with_format(bytes, format, options),
+                    )
+                })
+            }
+        }
+
+        impl<$($generics)*> FromLexicalWithFormat for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_format<Fmt: Format>(
+                bytes: &[u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self>
+            {
+                let replacements = replacements(format, options);
                 if !format.is_valid() {
                     return Err(format.error());
-                } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+                } else if !is_valid_options_punctuation(
+                    format.packed(),
+                    options.exponent(),
+                    options.decimal_point(),
+                ) {
                     return Err(Error::InvalidPunctuation);
                 }
-                Self::parse_complete::<FORMAT>(bytes, options)
+                parse_whitespace(bytes, format, |bytes| {
+                    if !replacements.is_empty() {
+                        let is_token = |c| $is_token(c, format, options);
+                        return parse_unicode(bytes, &replacements, is_token, |bytes| {
+                            check_grouping(bytes, format)?;
+                            Self::parse_complete(bytes, format, options)
+                        });
+                    }
+                    check_grouping(bytes, format)?;
+                    Self::parse_complete(bytes, format, options)
+                })
             }
 
             #[cfg_attr(not(feature = "compact"), inline)]
-            fn from_lexical_partial_with_options<const FORMAT: u128>(
+            fn from_lexical_partial_with_format<Fmt: Format>(
                 bytes: &[u8],
+                format: Fmt,
                 options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>
             {
-                Self::parse_partial::<FORMAT>(bytes, options)
+                let replacements = replacements(format, options);
+                parse_partial_whitespace(bytes, format, |bytes| {
+                    if !replacements.is_empty() {
+                        let is_token = |c| $is_token(c, format, options);
+                        return parse_partial_unicode(bytes, &replacements, is_token, |bytes| {
+                            check_grouping(bytes, format)?;
+                            Self::parse_partial(bytes, format, options)
+                        });
+                    }
+                    check_grouping(bytes, format)?;
+                    Self::parse_partial(bytes, format, options)
+                })
             }
         }
-    )*)
+    };
 }
 
 from_lexical! {}
 from_lexical_with_options! {}
+from_lexical_with_format! {}
 float_from_lexical! { f32 f64 }
 
 #[cfg(feature = "f16")]
 float_from_lexical! { bf16 f16 }
+
+#[cfg(feature = "f80")]
+float_from_lexical! { f80 }
+#[cfg(feature = "f128")]
+float_from_lexical! { f128 }
+#[cfg(feature = "custom-float")]
+float_from_lexical! {
+    @impl [const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8]
+        CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+}
+
+#[cfg(feature = "double-double")]
+float_from_lexical! { DoubleDouble }
+
+#[cfg(feature = "complex")]
+float_from_lexical! {
+    @impl [F: FromLexicalWithFormat<Options = Options> + Default] Complex<F>, is_complex_token
+}
+
+#[cfg(feature = "rational")]
+float_from_lexical! { @impl [] Rational, is_rational_token }
+
+#[cfg(feature = "decimal")]
+float_from_lexical! { decimal32 decimal64 decimal128 }
@@ -20,9 +20,9 @@
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::mask::{lower_n_halfway, lower_n_mask};
 use crate::number::Number;
-use crate::shared;
+use crate::shared::{self, Rounding};
 use crate::table::bellerophon_powers;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 
 // ALGORITHM
 // ---------
@@ -36,8 +36,12 @@ use lexical_util::format::NumberFormat;
 /// unable to unambiguously round the significant digits.
 ///
 /// This has been modified to return a biased, rather than unbiased exponent.
-pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> ExtendedFloat80 {
-    let format = NumberFormat::<{ FORMAT }> {};
+pub fn bellerophon<F: RawFloat, Fmt: Format>(
+    num: &Number,
+    format: Fmt,
+    lossy: bool,
+    rounding: Rounding,
+) -> ExtendedFloat80 {
     debug_assert!(!matches!(format.radix(), 2 | 4 | 8 | 16 | 32));
     debug_assert!(format.mantissa_radix() == format.exponent_base());
 
@@ -91,18 +95,21 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
         mant: num.mantissa,
         exp: 0,
     };
+    // The errors are in units of the last place, so must be normalized too,
+    // and we cannot directly multiply if the digits were truncated, since
+    // that would also multiply the errors.
     match fp.mant.overflowing_mul(powers.get_small_int(small_index as usize)) {
-        // Overflow, multiplication unsuccessful, go slow path.
-        (_, true) => {
-            normalize(&mut fp);
-            fp = mul(&fp, &powers.get_small(small_index as usize));
-            errors += error_halfscale();
-        },
         // No overflow, multiplication successful.
-        (mant, false) => {
+        (mant, false) if errors == 0 => {
             fp.mant = mant;
             normalize(&mut fp);
         },
+        // Overflow, multiplication unsuccessful, go slow path.
+        _ => {
+            errors <<= normalize(&mut fp);
+            fp = mul(&fp, &powers.get_small(small_index as usize));
+            errors += error_halfscale();
+        },
     }
 
     // Multiply by the large power.
@@ -123,7 +130,7 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
     }
 
     // Too many errors accumulated, return an error.
-    if !lossy && !error_is_accurate::<F>(errors, &fp) {
+    if !lossy && !error_is_accurate::<F>(errors, &fp, rounding) {
         // Bias the exponent so we know it's invalid.
         fp.exp += shared::INVALID_FP;
         return fp;
@@ -137,11 +144,7 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
         return fp_zero;
     }
 
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
-            is_above || (is_odd && is_halfway)
-        });
-    });
+    shared::round::<F, _>(&mut fp, |f, s| shared::round_bits(f, s, rounding, false));
     fp
 }
 
@@ -178,7 +181,7 @@ const fn error_halfscale() -> u32 {
 
 /// Determine if the number of errors is tolerable for float precision.
 #[cfg_attr(not(feature = "compact"), inline)]
-fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80) -> bool {
+fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80, rounding: Rounding) -> bool {
     // Check we can't have a literal 0 denormal float.
     debug_assert!(fp.exp >= -64);
 
@@ -250,7 +253,6 @@ fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80) -> bool {
     let maskbits = extrabits as u64;
     let errors = errors as u64;
 
-    // Round-to-nearest, need to use the halfway point.
     if extrabits > 64 {
         // Underflow, we have a shift larger than the mantissa.
         // Representation is valid **only** if the value is close enough
@@ -261,6 +263,14 @@ fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80) -> bool {
         let mask = lower_n_mask(maskbits);
         let extra = fp.mant & mask;
 
+        // Round-toward, need to check if we're close to 0 or to carrying.
+        // IE, b10100 | 000000 or b10011 | 111111.
+        if !rounding.is_nearest() {
+            let cmp1 = extra <= errors;
+            let cmp2 = mask.saturating_sub(errors) < extra;
+            return !(cmp1 || cmp2);
+        }
+
         // Round-to-nearest, need to check if we're close to halfway.
         // IE, b10100 | 100000, where `|` signifies the truncation point.
         let halfway = lower_n_halfway(maskbits);
@@ -9,11 +9,11 @@
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::mask::lower_n_halfway;
 use crate::number::Number;
-use crate::shared;
+use crate::shared::{self, Rounding};
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
 use lexical_util::digit::char_to_valid_digit_const;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_util::iterator::{AsBytes, BytesIter};
 use lexical_util::step::u64_step;
 
@@ -22,8 +22,12 @@ use lexical_util::step::u64_step;
 
 /// Algorithm specialized for radixes of powers-of-two.
 #[inline]
-pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> ExtendedFloat80 {
-    let format = NumberFormat::<{ FORMAT }> {};
+pub fn binary<F: RawFloat, Fmt: Format>(
+    num: &Number,
+    format: Fmt,
+    lossy: bool,
+    rounding: Rounding,
+) -> ExtendedFloat80 {
     debug_assert!(matches!(format.radix(), 2 | 4 | 8 | 16 | 32));
 
     let fp_zero = ExtendedFloat80 {
@@ -46,7 +50,12 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
     // if it's above that, always round-up. If it's odd, we can always
     // disambiguate the float. If it's even, and exactly halfway, this
     // step fails.
-    let power2 = shared::calculate_power2::<F, FORMAT>(num.exponent, ctlz);
+    //
+    // When rounding toward a direction, we can only fail if all the
+    // truncated bits are 0, since the truncated digits could be non-zero.
+    // Rounding down always truncates, and rounding to nearest with ties
+    // away from zero always rounds up at halfway, so these never fail.
+    let power2 = shared::calculate_power2::<F, _>(num.exponent, format, ctlz);
     if -power2 + 1 >= 64 {
         // Have more than 63 bits below the minimum exponent, must be 0.
         // Since we can't have partial digit rounding, this is true always
@@ -65,8 +74,13 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
     let halfway = lower_n_halfway(shift as u64);
     let is_even = mantissa & last_bit == 0;
     let is_halfway = mantissa & truncated == halfway;
-    if !lossy && is_even && is_halfway && num.many_digits {
-        // Exactly halfway and even, cannot safely determine our representation.
+    let is_ambiguous = match rounding {
+        Rounding::NearestEven => is_even && is_halfway,
+        Rounding::NearestAway | Rounding::Down => false,
+        Rounding::Up => mantissa & truncated == 0,
+    };
+    if !lossy && is_ambiguous && num.many_digits {
+        // Cannot safely determine our representation.
         // Bias the exponent so we know it's invalid.
         return ExtendedFloat80 {
             mant: mantissa,
@@ -75,24 +89,21 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
     }
 
     // Shift our digits into place, and round up if needed.
-    let is_above = mantissa & truncated > halfway;
-    let round_up = is_above || (!is_even && is_halfway);
     let mut fp = ExtendedFloat80 {
         mant: mantissa,
         exp: power2,
     };
 
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |_, _, _| round_up);
-    });
+    shared::round::<F, _>(&mut fp, |f, s| shared::round_bits(f, s, rounding, false));
     fp
 }
 
 /// Iteratively parse and consume digits without overflowing.
 #[inline]
 #[allow(unused_mut)]
-pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
+pub fn parse_u64_digits<'a, Iter, Fmt: Format>(
     mut iter: Iter,
+    format: Fmt,
     mantissa: &mut u64,
     step: &mut usize,
     overflowed: &mut bool,
@@ -100,7 +111,6 @@ pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
 ) where
     Iter: BytesIter<'a>,
 {
-    let format = NumberFormat::<{ FORMAT }> {};
     let radix = format.radix() as u64;
 
     // Try to parse 8 digits at a time, if we can.
@@ -110,7 +120,7 @@ pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
         let radix4 = radix2.wrapping_mul(radix2);
         let radix8 = radix4.wrapping_mul(radix4);
         while *step > 8 {
-            if let Some(v) = algorithm::try_parse_8digits::<u64, _, FORMAT>(&mut iter) {
+            if let Some(v) = algorithm::try_parse_8digits::<u64, _, _>(&mut iter, format) {
                 *mantissa = mantissa.wrapping_mul(radix8).wrapping_add(v);
                 *step -= 8;
             } else {
@@ -140,10 +150,14 @@ pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
 /// Fallback, slow algorithm optimized for powers-of-two.
 ///
 /// This avoids the need for arbitrary-precision arithmetic, since the result
-/// will always be a near-halfway representation where rounded-down it's even.
+/// will always be a near-halfway representation where rounded-down it's even,
+/// or when rounding up, a representation with truncated bits of 0.
 #[inline]
-pub fn slow_binary<F: RawFloat, const FORMAT: u128>(num: Number) -> ExtendedFloat80 {
-    let format = NumberFormat::<{ FORMAT }> {};
+pub fn slow_binary<F: RawFloat, Fmt: Format>(
+    num: Number,
+    format: Fmt,
+    rounding: Rounding,
+) -> ExtendedFloat80 {
     let radix = format.radix();
     debug_assert!(matches!(radix, 2 | 4 | 8 | 16 | 32));
 
@@ -161,10 +175,11 @@ pub fn slow_binary<F: RawFloat, const FORMAT: u128>(num: Number) -> ExtendedFloa
 
     // Parse the integer digits.
     let mut step = u64_step(radix);
-    let mut integer = num.integer.bytes::<FORMAT>();
+    let mut integer = num.integer.bytes(format);
     integer.integer_iter().skip_zeros();
-    parse_u64_digits::<_, FORMAT>(
+    parse_u64_digits(
         integer.integer_iter(),
+        format,
         &mut mantissa,
         &mut step,
         &mut overflow,
@@ -173,12 +188,13 @@ pub fn slow_binary<F: RawFloat, const FORMAT: u128>(num: Number) -> ExtendedFloa
 
     // Parse the fraction digits.
     if let Some(fraction) = num.fraction {
-        let mut fraction = fraction.bytes::<FORMAT>();
+        let mut fraction = fraction.bytes(format);
         if mantissa == 0 {
             fraction.fraction_iter().skip_zeros();
         }
-        parse_u64_digits::<_, FORMAT>(
+        parse_u64_digits(
             fraction.fraction_iter(),
+            format,
             &mut mantissa,
             &mut step,
             &mut overflow,
@@ -193,15 +209,13 @@ pub fn slow_binary<F: RawFloat, const FORMAT: u128>(num: Number) -> ExtendedFloa
     // Normalize our mantissa for simpler results.
     let ctlz = mantissa.leading_zeros();
     mantissa <<= ctlz;
-    let power2 = shared::calculate_power2::<F, FORMAT>(num.exponent, ctlz);
+    let power2 = shared::calculate_power2::<F, _>(num.exponent, format, ctlz);
 
     let mut fp = ExtendedFloat80 {
         mant: mantissa,
         exp: power2,
     };
 
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |_, _, _| !zero);
-    });
+    shared::round::<F, _>(&mut fp, |f, s| shared::round_bits(f, s, rounding, !zero));
     fp
 }
@@ -0,0 +1,165 @@
+//! Parse complex numbers on top of the float parsers.
+//!
+//! Complex numbers are either written as `a+bi`, with a configurable
+//! imaginary unit, or as an `(a,b)` tuple. The real or the imaginary part
+//! may be omitted in the `a+bi` form, so `1.5` and `2e-3i` are valid
+//! complex numbers. The sign between both parts is the sign of the
+//! imaginary part, so it follows the same rules as the real part, and
+//! special values, such as `1-infi`, are supported.
+//!
+//! If the imaginary unit is `None`, only the tuple form is parsed.
+
+#![cfg(feature = "complex")]
+#![doc(hidden)]
+
+use crate::api::FromLexicalWithFormat;
+use crate::options::Options;
+use crate::stream::is_token;
+use lexical_util::complex::Complex;
+use lexical_util::error::Error;
+use lexical_util::format::Format;
+use lexical_util::result::Result;
+
+/// Determine if a byte may be part of a complex number.
+#[inline]
+pub fn is_complex_token<Fmt: Format>(c: u8, format: Fmt, options: &Options) -> bool {
+    is_token(c, format, options)
+        || matches!(c, b'(' | b',' | b')')
+        || match options.imaginary_unit() {
+            Some(unit) => unit.contains(&c),
+            None => false,
+        }
+}
+
+/// Parse a float part of the complex number, starting at the index.
+///
+/// Returns the value and the index past the float, and offsets the
+/// index of any errors by the start of the float.
+#[inline]
+fn parse_part<F, Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    index: usize,
+    options: &Options,
+) -> Result<(F, usize)>
+where
+    F: FromLexicalWithFormat<Options = Options>,
+{
+    match F::from_lexical_partial_with_format(&bytes[index..], format, options) {
+        Ok((value, count)) => Ok((value, index + count)),
+        Err(mut error) => {
+            if let Some(position) = error.index_mut() {
+                *position += index;
+            }
+            Err(error)
+        },
+    }
+}
+
+/// Parse an expected byte, returning the index past the byte.
+#[inline]
+fn parse_byte(bytes: &[u8], index: usize, expected: u8) -> Result<usize> {
+    match bytes.get(index) {
+        Some(&c) if c == expected => Ok(index + 1),
+        Some(_) => Err(Error::InvalidDigit(index)),
+        None => Err(Error::Empty(index)),
+    }
+}
+
+/// Parse the imaginary unit, returning the index past the unit.
+#[inline]
+fn parse_unit(bytes: &[u8], index: usize, unit: &[u8]) -> Option<usize> {
+    match bytes[index..].starts_with(unit) {
+        true => Some(index + unit.len()),
+        false => None,
+    }
+}
+
+/// Parse a complex number in the `(a,b)` tuple form.
+#[inline]
+fn parse_tuple<F, Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<(Complex<F>, usize)>
+where
+    F: FromLexicalWithFormat<Options = Options>,
+{
+    let index = parse_byte(bytes, 0, b'(')?;
+    let (re, index) = parse_part::<F, _>(bytes, format, index, options)?;
+    let index = parse_byte(bytes, index, b',')?;
+    let (im, index) = parse_part::<F, _>(bytes, format, index, options)?;
+    let index = parse_byte(bytes, index, b')')?;
+    Ok((Complex::new(re, im), index))
+}
+
+/// Check the parser consumed every byte.
+#[inline(always)]
+fn check_complete<T>(value: T, index: usize, bytes: &[u8]) -> Result<T> {
+    match index == bytes.len() {
+        true => Ok(value),
+        false => Err(Error::InvalidDigit(index)),
+    }
+}
+
+/// Parse a complex number from bytes using a complete parser.
+pub fn parse_complete<F, Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<Complex<F>>
+where
+    F: FromLexicalWithFormat<Options = Options> + Default,
+{
+    let unit = match options.imaginary_unit() {
+        Some(unit) if bytes.first() != Some(&b'(') => unit,
+        _ => {
+            let (value, index) = parse_tuple::<F, _>(bytes, format, options)?;
+            return check_complete(value, index, bytes);
+        },
+    };
+
+    let (value, index) = parse_part::<F, _>(bytes, format, 0, options)?;
+    if let Some(index) = parse_unit(bytes, index, unit) {
+        return check_complete(Complex::new(F::default(), value), index, bytes);
+    } else if !matches!(bytes.get(index), Some(b'+') | Some(b'-')) {
+        return check_complete(Complex::new(value, F::default()), index, bytes);
+    }
+
+    let (im, index) = parse_part::<F, _>(bytes, format, index, options)?;
+    match parse_unit(bytes, index, unit) {
+        Some(index) => check_complete(Complex::new(value, im), index, bytes),
+        None if index == bytes.len() => Err(Error::Empty(index)),
+        None => Err(Error::InvalidDigit(index)),
+    }
+}
+
+/// Parse a complex number from bytes using a partial parser.
+///
+/// The imaginary part after the real part is only consumed if it is
+/// complete, so `1+2` parses the real part and stops before the sign.
+pub fn parse_partial<F, Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<(Complex<F>, usize)>
+where
+    F: FromLexicalWithFormat<Options = Options> + Default,
+{
+    let unit = match options.imaginary_unit() {
+        Some(unit) if bytes.first() != Some(&b'(') => unit,
+        _ => return parse_tuple::<F, _>(bytes, format, options),
+    };
+
+    let (value, index) = parse_part::<F, _>(bytes, format, 0, options)?;
+    if let Some(index) = parse_unit(bytes, index, unit) {
+        return Ok((Complex::new(F::default(), value), index));
+    } else if matches!(bytes.get(index), Some(b'+') | Some(b'-')) {
+        if let Ok((im, end)) = parse_part::<F, _>(bytes, format, index, options) {
+            if let Some(end) = parse_unit(bytes, end, unit) {
+                return Ok((Complex::new(value, im), end));
+            }
+        }
+    }
+    Ok((Complex::new(value, F::default()), index))
+}
@@ -0,0 +1,223 @@
+//! Parsing of IEEE-754 decimal floats.
+//!
+//! Decimal floats are parsed exactly, and the quantum of the input is
+//! preserved, so `1.50` is parsed with a coefficient of `150` and an
+//! exponent of `-2`. If the number has more significant digits than the
+//! precision of the format, or is smaller than the smallest quantum, the
+//! trailing digits are rounded using the rounding mode. If the exponent
+//! is too large, the coefficient is padded with trailing zeros if it
+//! fits, otherwise the value overflows.
+
+#![cfg(feature = "decimal")]
+#![doc(hidden)]
+
+use crate::number::Number;
+use crate::options::Options;
+use crate::parse::match_positive_special;
+use crate::shared::Rounding;
+use lexical_util::decimal::DecimalFloat;
+use lexical_util::digit::char_to_valid_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::Format;
+use lexical_util::iterator::{AsBytes, Bytes};
+use lexical_util::result::Result;
+
+/// The significant digits of the number, truncated to the precision.
+struct Digits {
+    /// The leading significant digits.
+    coefficient: u128,
+    /// The number of significant digits in the coefficient.
+    count: usize,
+    /// The number of digits after the coefficient.
+    truncated: i64,
+    /// The first truncated digit.
+    round_digit: u32,
+    /// If any truncated digit after the first is not 0.
+    is_sticky: bool,
+}
+
+impl Digits {
+    /// Add a digit, truncating any digits past the precision.
+    #[inline(always)]
+    fn push(&mut self, digit: u32, precision: usize) {
+        if self.count == 0 && digit == 0 {
+            // Leading zeros are not significant.
+        } else if self.count < precision {
+            self.coefficient = self.coefficient * 10 + digit as u128;
+            self.count += 1;
+        } else {
+            if self.truncated == 0 {
+                self.round_digit = digit;
+            } else {
+                self.is_sticky |= digit != 0;
+            }
+            self.truncated += 1;
+        }
+    }
+
+    /// Truncate `shift` more digits from the coefficient.
+    #[inline]
+    fn shift(&mut self, shift: i64) {
+        let is_sticky = self.is_sticky || self.round_digit != 0;
+        if shift > self.count as i64 {
+            self.is_sticky = is_sticky || self.coefficient != 0;
+            self.round_digit = 0;
+            self.coefficient = 0;
+        } else {
+            let pow = 10u128.pow(shift as u32 - 1);
+            let truncated = self.coefficient % (pow * 10);
+            let rest = truncated % pow;
+            self.is_sticky = is_sticky || rest != 0;
+            self.round_digit = (truncated / pow) as u32;
+            self.coefficient /= pow * 10;
+        }
+        self.count = self.count.saturating_sub(shift as usize);
+    }
+
+    /// Determine if the coefficient should be rounded up.
+    #[inline]
+    fn is_above(&self, rounding: Rounding) -> bool {
+        match rounding {
+            Rounding::NearestEven => {
+                let is_odd = self.coefficient % 2 == 1;
+                self.round_digit > 5 || (self.round_digit == 5 && (self.is_sticky || is_odd))
+            },
+            Rounding::NearestAway => self.round_digit >= 5,
+            Rounding::Down => false,
+            Rounding::Up => self.round_digit != 0 || self.is_sticky,
+        }
+    }
+}
+
+/// Convert the parsed number to a decimal float.
+///
+/// The exponent is the explicit exponent of the number, so the quantum
+/// is calculated from the number of fraction digits.
+pub fn to_decimal<D: DecimalFloat, Fmt: Format>(
+    num: &Number,
+    format: Fmt,
+    exponent: i64,
+    rounding: Rounding,
+) -> D {
+    let mut digits = Digits {
+        coefficient: 0,
+        count: 0,
+        truncated: 0,
+        round_digit: 0,
+        is_sticky: false,
+    };
+    let mut integer = num.integer.bytes(format);
+    for &c in integer.integer_iter() {
+        digits.push(char_to_valid_digit_const(c, 10), D::PRECISION);
+    }
+    let mut fraction_digits = 0_i64;
+    if let Some(fraction) = num.fraction {
+        let mut fraction = fraction.bytes(format);
+        for &c in fraction.fraction_iter() {
+            digits.push(char_to_valid_digit_const(c, 10), D::PRECISION);
+            fraction_digits += 1;
+        }
+    }
+
+    let min_exponent = D::MIN_EXPONENT as i64;
+    let max_exponent = D::MAX_EXPONENT as i64;
+    let mut quantum = exponent - fraction_digits + digits.truncated;
+    if digits.count == 0 {
+        // Zeros have no significant digits, so the quantum is clamped.
+        let quantum = quantum.max(min_exponent).min(max_exponent);
+        return from_parts(num.is_negative, 0, quantum);
+    } else if quantum < min_exponent {
+        // Subnormal, round the digits below the smallest quantum.
+        digits.shift(min_exponent - quantum);
+        quantum = min_exponent;
+    }
+
+    let mut coefficient = digits.coefficient;
+    if digits.is_above(rounding) {
+        coefficient += 1;
+        if coefficient > D::MAX_COEFFICIENT {
+            coefficient /= 10;
+            quantum += 1;
+        }
+    }
+
+    // Pad the coefficient with zeros if the exponent is too large.
+    while quantum > max_exponent && coefficient * 10 <= D::MAX_COEFFICIENT {
+        coefficient *= 10;
+        quantum -= 1;
+    }
+    if quantum > max_exponent {
+        // Overflow, which rounds to the largest finite value if rounding down.
+        return match rounding {
+            Rounding::Down => from_parts(num.is_negative, D::MAX_COEFFICIENT, max_exponent),
+            _ if num.is_negative => D::NEG_INFINITY,
+            _ => D::INFINITY,
+        };
+    }
+    from_parts(num.is_negative, coefficient, quantum)
+}
+
+/// Create the decimal float from parts that are known to be in range.
+#[inline(always)]
+fn from_parts<D: DecimalFloat>(is_negative: bool, coefficient: u128, exponent: i64) -> D {
+    match D::from_parts(is_negative, coefficient, exponent as i32) {
+        Some(value) => value,
+        None => unreachable!("the coefficient and exponent are always in range"),
+    }
+}
+
+/// Check if the decimal overflowed to infinity or underflowed to zero, if those are errors.
+///
+/// Zero and special values are never out of range. The error index is
+/// the start of the number, like for binary floats.
+#[inline(always)]
+pub fn check_range<D: DecimalFloat>(value: D, is_zero: bool, options: &Options) -> Result<D> {
+    if !options.error_on_out_of_range() || is_zero {
+        Ok(value)
+    } else if value.is_inf() {
+        Err(Error::Overflow(0))
+    } else if value.coefficient() == 0 {
+        Err(Error::Underflow(0))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Parse a partial representation of a special, non-finite decimal float.
+#[inline]
+pub fn parse_partial_decimal_special<D, Fmt: Format>(
+    byte: Bytes<Fmt>,
+    is_negative: bool,
+    options: &Options,
+) -> Option<(D, usize)>
+where
+    D: DecimalFloat,
+{
+    let (is_nan, count) = match_positive_special(byte, options)?;
+    let mut bits = match is_nan {
+        true => D::NAN.to_raw_bits(),
+        false => D::INFINITY.to_raw_bits(),
+    };
+    if is_negative {
+        bits |= D::sign_mask();
+    }
+    Some((D::from_raw_bits(bits), count))
+}
+
+/// Try to parse a special, non-finite decimal float.
+#[inline]
+pub fn parse_decimal_special<D, Fmt: Format>(
+    byte: Bytes<Fmt>,
+    is_negative: bool,
+    options: &Options,
+) -> Option<D>
+where
+    D: DecimalFloat,
+{
+    let length = byte.length();
+    let (value, count) = parse_partial_decimal_special::<D, _>(byte, is_negative, options)?;
+    match count == length {
+        true => Some(value),
+        false => None,
+    }
+}
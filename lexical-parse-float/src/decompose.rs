@@ -0,0 +1,163 @@
+//! Parse numbers into their components without converting to a float.
+//!
+//! This validates the number against the float grammar of a packed
+//! number format, including digit separators, base prefixes and suffixes,
+//! and exponent syntax, and returns the sign, the significant digits, and
+//! the explicit exponent. This can be used to build other numeric types,
+//! such as decimal types, on top of the float grammar.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_float::decompose::decompose;
+//! use lexical_parse_float::format::STANDARD;
+//! use lexical_parse_float::Options;
+//!
+//! # pub fn main() {
+//! let options = Options::new();
+//! let number = decompose::<STANDARD>(b"-12.50e3", &options).unwrap();
+//! assert!(number.is_negative());
+//! assert_eq!(number.integer(), b"12");
+//! assert_eq!(number.fraction(), Some(&b"50"[..]));
+//! assert_eq!(number.exponent(), 3);
+//! # }
+//! ```
+
+use crate::options::Options;
+use crate::parse::parse_partial_decomposed;
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, Format, NumberFormat};
+use lexical_util::result::Result;
+
+/// Components of a parsed number.
+///
+/// The value of the number is the integer and fraction digits, in the
+/// mantissa radix, multiplied by the exponent base raised to the
+/// explicit exponent. The digit slices are borrowed from the input, so
+/// they may contain digit separators: use [`integer_digits`] and
+/// [`fraction_digits`] to iterate over only the digits.
+///
+/// [`integer_digits`]: Self::integer_digits
+/// [`fraction_digits`]: Self::fraction_digits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecomposedNumber<'a> {
+    /// If the number is negative.
+    is_negative: bool,
+    /// The integer digits, including any digit separators.
+    integer: &'a [u8],
+    /// The fraction digits, including any digit separators.
+    fraction: Option<&'a [u8]>,
+    /// The explicit exponent.
+    exponent: i64,
+    /// The digit separator, or 0 if digit separators are disabled.
+    digit_separator: u8,
+}
+
+impl<'a> DecomposedNumber<'a> {
+    /// Get if the number is negative.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Get the integer digits, including any digit separators.
+    ///
+    /// This excludes the sign and the base prefix.
+    #[inline]
+    pub const fn integer(&self) -> &'a [u8] {
+        self.integer
+    }
+
+    /// Get the fraction digits, including any digit separators.
+    ///
+    /// This is `None` if the number has no decimal point, and empty if
+    /// the decimal point has no digits after it.
+    #[inline]
+    pub const fn fraction(&self) -> Option<&'a [u8]> {
+        self.fraction
+    }
+
+    /// Get the explicit exponent, or 0 if the number has no exponent.
+    ///
+    /// This does not include the digits after the decimal point. Like
+    /// the float parsers, the magnitude of very large exponents is
+    /// saturated, which has no effect on any float value.
+    #[inline]
+    pub const fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Iterate over the integer digits, skipping digit separators.
+    #[inline]
+    pub fn integer_digits(&self) -> impl Iterator<Item = u8> + 'a {
+        let digit_separator = self.digit_separator;
+        self.integer.iter().copied().filter(move |&c| c != digit_separator)
+    }
+
+    /// Iterate over the fraction digits, skipping digit separators.
+    #[inline]
+    pub fn fraction_digits(&self) -> impl Iterator<Item = u8> + 'a {
+        let digit_separator = self.digit_separator;
+        let fraction = self.fraction.unwrap_or(&[]);
+        fraction.iter().copied().filter(move |&c| c != digit_separator)
+    }
+}
+
+/// Parse the components of a number from the entire input.
+///
+/// Special values, such as NaN and infinity, are not numbers and
+/// return an error.
+#[inline]
+pub fn decompose<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<DecomposedNumber<'a>> {
+    let (number, count) = decompose_partial::<FORMAT>(bytes, options)?;
+    if count == bytes.len() {
+        Ok(number)
+    } else {
+        Err(Error::InvalidDigit(count))
+    }
+}
+
+/// Parse the components of a number from the start of the input.
+///
+/// Returns the components and the number of processed bytes. Special
+/// values, such as NaN and infinity, are not numbers and return an error.
+#[inline]
+pub fn decompose_partial<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<(DecomposedNumber<'a>, usize)> {
+    decompose_partial_with_format(bytes, NumberFormat::<{ FORMAT }> {}, options)
+}
+
+/// Parse the components of a number from the start of the input, using a format value.
+///
+/// This is the same as [`decompose_partial`], except the number format
+/// may be selected at runtime, such as a [`DynamicFormat`].
+///
+/// [`DynamicFormat`]: lexical_util::format::DynamicFormat
+#[inline]
+pub fn decompose_partial_with_format<'a, F: Format>(
+    bytes: &'a [u8],
+    format: F,
+    options: &Options,
+) -> Result<(DecomposedNumber<'a>, usize)> {
+    let exponent = options.exponent();
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(format.packed(), exponent, options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+
+    let (number, exponent, count) = parse_partial_decomposed(bytes, format, options)?;
+    let decomposed = DecomposedNumber {
+        is_negative: number.is_negative,
+        integer: number.integer,
+        fraction: number.fraction,
+        exponent,
+        digit_separator: format.digit_separator(),
+    };
+    Ok((decomposed, count))
+}
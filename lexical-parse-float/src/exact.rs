@@ -0,0 +1,615 @@
+//! Correctly rounded parsing of extended and quadruple-precision floats.
+//!
+//! The extended-float algorithms need guard bits beyond the significand,
+//! so floats with 64-bit or wider significands, such as f80 and f128, are
+//! parsed using big integers. The leading
+//! significant digits are scaled by the power of the radix, and the top
+//! 128 bits of the scaled value are rounded to the float, with the
+//! remaining bits as a sticky bit.
+//!
+//! If the significant digits are truncated, the value is between the
+//! truncated digits and the next value, and both bounds are rounded. If
+//! the bounds round to different floats, the digits are compared to the
+//! boundary between the two floats, like in the slow path algorithm.
+//!
+//! Double-doubles are parsed as the value rounded to the nearest f64, and
+//! the exact residual is calculated using the same big integers, and then
+//! rounded to the low f64.
+
+#![cfg(any(feature = "f80", feature = "f128", feature = "custom-float", feature = "double-double"))]
+#![doc(hidden)]
+
+use crate::number::Number;
+#[cfg(feature = "double-double")]
+use crate::options::RoundMode;
+use crate::shared::Rounding;
+use core::cmp::Ordering;
+use lexical_util::bignum;
+use lexical_util::digit::char_to_valid_digit_const;
+use lexical_util::format::Format;
+use lexical_util::iterator::AsBytes;
+use lexical_util::num::{AsCast, AsPrimitive, Float};
+
+/// The number of limbs to store the significant digits.
+///
+/// Any digits after the first `DIGIT_LIMBS - 1` limbs are truncated.
+const DIGIT_LIMBS: usize = 64;
+
+/// The number of limbs to store the scaled significant digits.
+///
+/// The powers of the radix are only calculated until the scaled value
+/// is out of the range of an f128, so this bounds the value.
+const LIMBS: usize = 340;
+
+// LIMBS
+
+/// A big integer with a fixed capacity, as little-endian `u64` limbs.
+///
+/// The limbs after the length are always 0.
+#[derive(Clone)]
+struct Limbs<const N: usize> {
+    data: [u64; N],
+    len: usize,
+}
+
+impl<const N: usize> Limbs<N> {
+    /// Create a big integer from limbs.
+    #[inline]
+    fn from_slice(x: &[u64]) -> Self {
+        let mut data = [0; N];
+        data[..x.len()].copy_from_slice(x);
+        Self {
+            data,
+            len: bignum::normalized_len(x),
+        }
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[u64] {
+        &self.data[..self.len]
+    }
+
+    /// Get the number of bits in the value.
+    #[inline]
+    fn bit_len(&self) -> i64 {
+        match self.len {
+            0 => 0,
+            len => 64 * len as i64 - self.data[len - 1].leading_zeros() as i64,
+        }
+    }
+
+    /// Multiply by a small value and add a carry.
+    #[inline]
+    fn mul_small(&mut self, y: u64, carry: u64) {
+        let carry = bignum::mul_small(&mut self.data[..self.len], y, carry);
+        if carry != 0 {
+            self.data[self.len] = carry;
+            self.len += 1;
+        }
+    }
+
+    /// Multiply by `odd^exp`, stopping early if the value exceeds `max_bits`.
+    ///
+    /// Returns if the value does not exceed `max_bits`.
+    fn mul_pow(&mut self, odd: u32, mut exp: i64, max_bits: i64) -> bool {
+        if self.bit_len() > max_bits {
+            return false;
+        } else if odd == 1 {
+            return true;
+        }
+        let (base, step) = bignum::max_power(odd);
+        while exp > 0 {
+            let power = match exp >= step as i64 {
+                true => base,
+                false => (odd as u64).pow(exp as u32),
+            };
+            self.mul_small(power, 0);
+            exp -= step as i64;
+            if self.bit_len() > max_bits {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Shift the value left by `shift` bits.
+    fn shl(&self, shift: usize) -> Self {
+        let mut result = Self::from_slice(&[]);
+        let end = self.len + shift / 64 + 1;
+        debug_assert!(end <= N || self.bit_len() + (shift as i64) <= 64 * N as i64);
+        let end = end.min(N);
+        bignum::shl(&mut result.data[..end], self.as_slice(), shift);
+        result.len = bignum::normalized_len(&result.data[..end]);
+        result
+    }
+
+    /// Get the top 128 bits of the value, which must not be 0.
+    ///
+    /// Returns the bits, with the most-significant bit set, the number
+    /// of bits shifted out, which may be negative, and if any bit that
+    /// was shifted out is set.
+    fn hi128(&self) -> (u128, i64, bool) {
+        let shift = self.bit_len() - 128;
+        let limb = |index: usize| self.data.get(index).copied().unwrap_or(0) as u128;
+        if shift <= 0 {
+            let value = limb(0) | (limb(1) << 64);
+            return (value << -shift, shift, false);
+        }
+
+        let index = (shift / 64) as usize;
+        let bits = (shift % 64) as u32;
+        let low = limb(index) | (limb(index + 1) << 64);
+        let value = match bits {
+            0 => low,
+            _ => (low >> bits) | (limb(index + 2) << (128 - bits)),
+        };
+        let is_truncated = self.data[..index].iter().any(|&x| x != 0)
+            || (limb(index) & ((1 << bits) - 1)) != 0;
+        (value, shift, is_truncated)
+    }
+}
+
+// SCALING
+
+/// The significant digits scaled by the power of the radix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scaled {
+    /// The value is below half the smallest denormal float.
+    Zero,
+    /// The value is above the largest finite float.
+    Infinite,
+    /// The value is `mant * 2^exp`, and above it if the sticky bit is set.
+    Finite(u128, i64, bool),
+}
+
+/// Scale the significant digits to `digits * 2^pow2 * odd^exp`.
+fn scale_digits<F: Float>(digits: &[u64], odd: u32, pow2: i64, exp: i64) -> Scaled {
+    // The value rounds to infinity if it is at least `2^max_exp`, and
+    // to zero if it is below `2^min_exp`, for any rounding mode.
+    let max_exp = (F::MAX_EXPONENT + F::MANTISSA_SIZE) as i64 + 1;
+    let min_exp = F::DENORMAL_EXPONENT as i64 - 2;
+    let mut x = Limbs::<LIMBS>::from_slice(digits);
+    if exp >= 0 || odd == 1 {
+        // The value is at least `2^(bits - 1 + pow2)`, and below `2^(bits + pow2)`.
+        if !x.mul_pow(odd, exp, max_exp - pow2) {
+            return Scaled::Infinite;
+        } else if x.bit_len() + pow2 <= min_exp {
+            return Scaled::Zero;
+        }
+        let (mant, shift, is_truncated) = x.hi128();
+        return Scaled::Finite(mant, pow2 + shift, is_truncated);
+    }
+
+    // The value is below `2^(x_bits + pow2 - y_bits + 1)`.
+    let x_bits = x.bit_len();
+    let mut y = Limbs::<LIMBS>::from_slice(&[1]);
+    if !y.mul_pow(odd, -exp, x_bits + pow2 - min_exp) {
+        return Scaled::Zero;
+    }
+
+    // Shift so the quotient has 127 or 128 bits, and normalize the divisor.
+    let shift = 127 + y.bit_len() - x_bits;
+    let (x_shift, y_shift) = match shift >= 0 {
+        true => (shift as usize, 0),
+        false => (0, -shift as usize),
+    };
+    let y = y.shl(y_shift);
+    let n = y.len;
+    let norm = y.data[n - 1].leading_zeros() as usize;
+    let y = y.shl(norm);
+    x = x.shl(x_shift + norm);
+
+    let mut quotient = [0_u64; 2];
+    bignum::divrem_normalized(&mut x.data[..n + 2], y.as_slice(), &mut quotient);
+    let mant = (quotient[0] as u128) | ((quotient[1] as u128) << 64);
+    let is_truncated = x.data[..n].iter().any(|&x| x != 0);
+    Scaled::Finite(mant, pow2 - shift, is_truncated)
+}
+
+// ROUNDING
+
+/// The rounded bits for values that round above the largest finite float.
+///
+/// This is infinity, or the largest finite float for floats without
+/// infinity, and is kept distinct so the overflow can be reported.
+const OVERFLOW: u128 = u128::MAX;
+
+/// Round the scaled digits to the bits of a positive float.
+fn round<F: Float>(scaled: Scaled, rounding: Rounding) -> u128 {
+    let (mant, exp, is_truncated) = match scaled {
+        Scaled::Zero if rounding == Rounding::Up => return 1,
+        Scaled::Zero => return 0,
+        Scaled::Infinite if rounding == Rounding::Down => return F::MAX.to_bits().as_u128(),
+        Scaled::Infinite => return OVERFLOW,
+        Scaled::Finite(mant, exp, is_truncated) => (mant, exp, is_truncated),
+    };
+
+    // Get the exponent of the least-significant bit of the float.
+    let top = exp + 127 - mant.leading_zeros() as i64;
+    let mut ulp = (top - F::MANTISSA_SIZE as i64).max(F::DENORMAL_EXPONENT as i64);
+    let shift = ulp - exp;
+    debug_assert!(shift > 0);
+
+    // Values below half the smallest float are not halfway, or exact.
+    let (mut result, is_above, is_halfway, is_exact) = if shift > 128 {
+        (0, false, false, false)
+    } else {
+        let mask = match shift {
+            128 => u128::MAX,
+            _ => (1 << shift) - 1,
+        };
+        let halfway = 1_u128 << (shift - 1);
+        let truncated_bits = mant & mask;
+        let result = match shift {
+            128 => 0,
+            _ => mant >> shift,
+        };
+        (
+            result,
+            truncated_bits > halfway || (truncated_bits == halfway && is_truncated),
+            truncated_bits == halfway && !is_truncated,
+            truncated_bits == 0 && !is_truncated,
+        )
+    };
+
+    let is_odd = result & 1 == 1;
+    let is_roundup = match rounding {
+        Rounding::NearestEven => is_above || (is_odd && is_halfway),
+        Rounding::NearestAway => is_above || is_halfway,
+        Rounding::Down => false,
+        Rounding::Up => !is_exact,
+    };
+    result += is_roundup as u128;
+
+    // Rounding up may carry into the next exponent.
+    let hidden = F::HIDDEN_BIT_MASK.as_u128();
+    if result == hidden << 1 {
+        result >>= 1;
+        ulp += 1;
+    }
+    if ulp >= F::MAX_EXPONENT as i64 {
+        return round::<F>(Scaled::Infinite, rounding);
+    }
+    let biased = match result & hidden {
+        0 => 0,
+        _ => (ulp - F::DENORMAL_EXPONENT as i64 + 1) as u128,
+    };
+    let bits = if hidden & F::EXPONENT_MASK.as_u128() == 0 {
+        // The hidden bit is explicit, like the x87 extended-precision float.
+        (biased << (F::MANTISSA_SIZE + 1)) | result
+    } else {
+        (biased << F::MANTISSA_SIZE) | (result & F::MANTISSA_MASK.as_u128())
+    };
+    if bits > F::MAX.to_bits().as_u128() {
+        // Floats without infinity use the largest exponent, except for NaN.
+        return round::<F>(Scaled::Infinite, rounding);
+    }
+    bits
+}
+
+/// Get the float from the bits as `mant * 2^exp`.
+#[inline]
+fn decompose<F: Float>(bits: u128) -> (u128, i64) {
+    let float = F::from_bits(<F::Unsigned>::as_cast(bits));
+    (float.mantissa().as_u128(), float.exponent() as i64)
+}
+
+// DIGITS
+
+/// Call `cb` with each digit, and if it is in the integer, until it returns false.
+fn visit_digits<Cb, Fmt: Format>(num: &Number, format: Fmt, mut cb: Cb)
+where
+    Cb: FnMut(u32, bool) -> bool,
+{
+    let radix = format.radix();
+    let mut integer = num.integer.bytes(format);
+    for &c in integer.integer_iter() {
+        if !cb(char_to_valid_digit_const(c, radix), true) {
+            return;
+        }
+    }
+    if let Some(fraction) = num.fraction {
+        let mut fraction = fraction.bytes(format);
+        for &c in fraction.fraction_iter() {
+            if !cb(char_to_valid_digit_const(c, radix), false) {
+                return;
+            }
+        }
+    }
+}
+
+/// Compare the significant digits to the boundary `mant * 2^exp`.
+///
+/// The digits are `0.d1d2d3... * radix^scale`, where `d1` is not 0,
+/// and the radix must be the exponent base. This generates the digits
+/// of the boundary, and compares them to the significant digits.
+fn compare_digits<Fmt: Format>(
+    num: &Number,
+    format: Fmt,
+    scale: i64,
+    mant: u128,
+    exp: i64,
+) -> Ordering {
+    let radix = format.radix();
+    let pow2 = radix.trailing_zeros() as i64;
+    let odd = radix >> pow2;
+
+    // Get the boundary divided by `radix^scale` as `num / den`.
+    let mut numerator = Limbs::<LIMBS>::from_slice(&[mant as u64, (mant >> 64) as u64]);
+    let mut denominator = Limbs::<LIMBS>::from_slice(&[1]);
+    if scale > 0 {
+        denominator.mul_pow(odd, scale, i64::MAX);
+    } else {
+        numerator.mul_pow(odd, -scale, i64::MAX);
+    }
+    let shift = exp - pow2 * scale;
+    if shift > 0 {
+        numerator = numerator.shl(shift as usize);
+    } else {
+        denominator = denominator.shl(-shift as usize);
+    }
+
+    // Normalize the denominator, so the digits can be divided out.
+    let norm = denominator.data[denominator.len - 1].leading_zeros() as usize;
+    let mut numerator = numerator.shl(norm);
+    let denominator = denominator.shl(norm);
+    let n = denominator.len;
+    if bignum::compare(numerator.as_slice(), denominator.as_slice()) != Ordering::Less {
+        // The digits are below 1, and the boundary is not.
+        return Ordering::Less;
+    }
+
+    let mut ordering = Ordering::Equal;
+    let mut is_leading = true;
+    visit_digits(num, format, |digit, _| {
+        if is_leading && digit == 0 {
+            return true;
+        }
+        is_leading = false;
+        numerator.mul_small(radix as u64, 0);
+        let mut quotient = [0_u64; 1];
+        bignum::divrem_normalized(
+            &mut numerator.data[..n + 1],
+            denominator.as_slice(),
+            &mut quotient,
+        );
+        numerator.len = bignum::normalized_len(&numerator.data[..n + 1]);
+        ordering = digit.cmp(&(quotient[0] as u32));
+        ordering == Ordering::Equal
+    });
+    match ordering == Ordering::Equal && numerator.len != 0 {
+        // The boundary has more digits.
+        true => Ordering::Less,
+        false => ordering,
+    }
+}
+
+// ALGORITHM
+
+/// The significant digits of the number, without leading zeros.
+struct Digits {
+    /// The leading significant digits, as a big integer.
+    value: Limbs<DIGIT_LIMBS>,
+    /// The number of significant digits in the value.
+    count: usize,
+    /// The scale, so the number is `0.d1d2d3... * radix^scale`.
+    scale: i64,
+    /// If any digit after the leading digits is not 0.
+    is_truncated: bool,
+}
+
+/// Parse the significant digits, without leading zeros.
+fn parse_digits<Fmt: Format>(num: &Number, format: Fmt) -> Digits {
+    let radix = format.radix();
+    let (base, step) = bignum::max_power(radix);
+    let max_digits = step * (DIGIT_LIMBS - 1);
+    let mut value = Limbs::<DIGIT_LIMBS>::from_slice(&[]);
+    let mut chunk = 0_u64;
+    let mut chunk_digits = 0;
+    let mut count = 0;
+    let mut scale = 0_i64;
+    let mut is_truncated = false;
+    visit_digits(num, format, |digit, is_integer| {
+        if count == 0 && digit == 0 {
+            if !is_integer {
+                scale -= 1;
+            }
+            return true;
+        } else if is_integer {
+            scale += 1;
+        }
+        if count < max_digits {
+            chunk = chunk * radix as u64 + digit as u64;
+            chunk_digits += 1;
+            count += 1;
+            if chunk_digits == step {
+                value.mul_small(base, chunk);
+                chunk = 0;
+                chunk_digits = 0;
+            }
+        } else {
+            is_truncated |= digit != 0;
+        }
+        is_integer || !is_truncated
+    });
+    if chunk_digits != 0 {
+        value.mul_small((radix as u64).pow(chunk_digits as u32), chunk);
+    }
+
+    Digits {
+        value,
+        count,
+        scale,
+        is_truncated,
+    }
+}
+
+/// Get the exponents so the number is `digits * 2^pow2 * odd^exp`.
+fn value_exponents<Fmt: Format>(digits: &Digits, format: Fmt, exponent: i64) -> (i64, i64) {
+    let pow2 = format.radix().trailing_zeros() as i64;
+    let digits_exp = digits.scale - digits.count as i64;
+    if format.radix() == format.exponent_base() {
+        let exp = digits_exp + exponent;
+        (pow2 * exp, exp)
+    } else {
+        let bits_per_base = format.exponent_base().trailing_zeros() as i64;
+        (pow2 * digits_exp + bits_per_base * exponent, 0)
+    }
+}
+
+/// Convert the parsed number to a float, using big-integer arithmetic.
+///
+/// `exponent` is the explicit exponent, in the exponent base. Returns the
+/// float, and if it rounded above the largest finite float, which is
+/// infinity, unless the float has no infinity and saturates.
+pub fn to_float<F: Float, Fmt: Format>(
+    num: &Number,
+    format: Fmt,
+    exponent: i64,
+    lossy: bool,
+    rounding: Rounding,
+) -> (F, bool) {
+    let radix = format.radix();
+    let pow2 = radix.trailing_zeros() as i64;
+    let odd = radix >> pow2;
+
+    // Parse the significant digits, and get the value as `digits * 2^pow2 * odd^exp`.
+    let parsed = parse_digits(num, format);
+    let (value_pow2, value_exp) = value_exponents(&parsed, format, exponent);
+    let Digits {
+        value: digits,
+        scale,
+        is_truncated,
+        ..
+    } = parsed;
+
+    let sign = match num.is_negative {
+        true => F::SIGN_MASK.as_u128(),
+        false => 0,
+    };
+    let to_native = |bits: u128| match bits {
+        OVERFLOW if num.is_negative => (F::NEG_INFINITY, true),
+        OVERFLOW => (F::INFINITY, true),
+        _ => (F::from_bits(<F::Unsigned>::as_cast(bits | sign)), false),
+    };
+    if digits.len == 0 {
+        return to_native(0);
+    }
+
+    let scaled = scale_digits::<F>(digits.as_slice(), odd, value_pow2, value_exp);
+    if !is_truncated {
+        return to_native(round::<F>(scaled, rounding));
+    }
+
+    // Round the bounds of the truncated value, which is above the
+    // truncated digits, and below the next value.
+    let lower = match scaled {
+        Scaled::Finite(mant, exp, _) => Scaled::Finite(mant, exp, true),
+        scaled => scaled,
+    };
+    let mut next = digits.clone();
+    next.len += 1;
+    bignum::add_assign(&mut next.data[..next.len], &[1]);
+    next.len = bignum::normalized_len(next.as_slice());
+    let upper = match scale_digits::<F>(next.as_slice(), odd, value_pow2, value_exp) {
+        Scaled::Finite(mant, exp, false) => Scaled::Finite(mant - 1, exp, true),
+        scaled => scaled,
+    };
+    let lower = round::<F>(lower, rounding);
+    let upper = round::<F>(upper, rounding);
+    if lower == upper || lossy {
+        return to_native(lower);
+    }
+
+    // The bounds are adjacent floats: compare the digits to the boundary
+    // between them. This only occurs if the radix has an odd factor.
+    let (mant, exp) = match rounding {
+        Rounding::NearestEven | Rounding::NearestAway => {
+            let (mant, exp) = decompose::<F>(lower);
+            (2 * mant + 1, exp - 1)
+        },
+        Rounding::Down => decompose::<F>(upper),
+        Rounding::Up => decompose::<F>(lower),
+    };
+    let ordering = compare_digits(num, format, scale + exponent, mant, exp);
+    let is_upper = match rounding {
+        Rounding::NearestEven => {
+            ordering == Ordering::Greater || (ordering == Ordering::Equal && lower & 1 == 1)
+        },
+        Rounding::NearestAway => ordering != Ordering::Less,
+        Rounding::Down => ordering != Ordering::Less,
+        Rounding::Up => ordering == Ordering::Greater,
+    };
+    match is_upper {
+        true => to_native(upper),
+        false => to_native(lower),
+    }
+}
+
+/// Convert the parsed number to a double-double, using big-integer arithmetic.
+///
+/// The high float is the number rounded to the nearest f64, and the low
+/// float is the exact residual, rounded using the rounding mode. If the
+/// digits are truncated, a digit between the truncated digits and the
+/// next value is added, which only changes the residual if it is within
+/// the truncated digits of a boundary. Returns the high and low floats.
+#[cfg(feature = "double-double")]
+pub fn to_double_double<Fmt: Format>(
+    num: &Number,
+    format: Fmt,
+    exponent: i64,
+    lossy: bool,
+    round_mode: RoundMode,
+) -> (f64, f64) {
+    let rounding = Rounding::new(round_mode, num.is_negative);
+    let (hi, _) = to_float::<f64, _>(num, format, exponent, lossy, Rounding::NearestEven);
+    if hi == 0.0 {
+        // The residual is the number.
+        let (lo, _) = to_float::<f64, _>(num, format, exponent, lossy, rounding);
+        return (hi, lo);
+    } else if hi.is_inf() {
+        return (hi, 0.0);
+    }
+
+    let radix = format.radix();
+    let pow2 = radix.trailing_zeros() as i64;
+    let odd = radix >> pow2;
+    let mut digits = parse_digits(num, format);
+    if digits.is_truncated {
+        digits.value.mul_small(radix as u64, 1);
+        digits.count += 1;
+    }
+    let (value_pow2, value_exp) = value_exponents(&digits, format, exponent);
+
+    // Scale the number `digits * 2^pow2 * odd^exp` and the high float
+    // `mant * 2^exp2` to integers, with a common power of 2 and odd.
+    let (mant, exp2) = (hi.mantissa(), hi.exponent() as i64);
+    let mut x = Limbs::<LIMBS>::from_slice(digits.value.as_slice());
+    let mut y = Limbs::<LIMBS>::from_slice(&[mant]);
+    if value_exp >= 0 {
+        x.mul_pow(odd, value_exp, i64::MAX);
+    } else {
+        y.mul_pow(odd, -value_exp, i64::MAX);
+    }
+    let shift = value_pow2.min(exp2);
+    let x = x.shl((value_pow2 - shift) as usize);
+    let y = y.shl((exp2 - shift) as usize);
+
+    // Get the magnitude of the residual, and round it to the low float.
+    let ordering = bignum::compare(x.as_slice(), y.as_slice());
+    let (mut residual, subtrahend) = match ordering {
+        Ordering::Equal => return (hi, 0.0),
+        Ordering::Less => (y, x),
+        Ordering::Greater => (x, y),
+    };
+    bignum::sub_assign(&mut residual.data[..residual.len], subtrahend.as_slice());
+    residual.len = bignum::normalized_len(residual.as_slice());
+    let is_negative = num.is_negative != (ordering == Ordering::Less);
+    let rounding = Rounding::new(round_mode, is_negative);
+    let scaled = scale_digits::<f64>(residual.as_slice(), odd, shift, value_exp.min(0));
+    let lo = f64::from_bits(round::<f64>(scaled, rounding) as u64);
+    match is_negative {
+        true => (hi, -lo),
+        false => (hi, lo),
+    }
+}
@@ -101,16 +101,22 @@ impl RawFloat for f64 {
 #[cfg(feature = "f16")]
 impl RawFloat for f16 {
     #[inline(always)]
-    unsafe fn pow_fast_path(_: usize, _: u32) -> Self {
-        unimplemented!()
+    unsafe fn pow_fast_path(exponent: usize, radix: u32) -> Self {
+        // The powers are exact in the narrower float, and the `f32`
+        // arithmetic has enough precision that rounding twice is exact.
+        // SAFETY: safe as long as the exponent is smaller than the radix table.
+        Self::from_f32(unsafe { f32::pow_fast_path(exponent, radix) })
     }
 }
 
 #[cfg(feature = "f16")]
 impl RawFloat for bf16 {
     #[inline(always)]
-    unsafe fn pow_fast_path(_: usize, _: u32) -> Self {
-        unimplemented!()
+    unsafe fn pow_fast_path(exponent: usize, radix: u32) -> Self {
+        // The powers are exact in the narrower float, and the `f32`
+        // arithmetic has enough precision that rounding twice is exact.
+        // SAFETY: safe as long as the exponent is smaller than the radix table.
+        Self::from_f32(unsafe { f32::pow_fast_path(exponent, radix) })
     }
 }
 
@@ -163,22 +169,29 @@ impl LemireFloat for f64 {
     const LARGEST_POWER_OF_TEN: i32 = 308;
 }
 
+// For the 16-bit floats, we have 2m+1 ≤ 2^12 (f16) or 2^9 (bf16) for
+// positive exponents, so q ≤ 5 or q ≤ 3, and 2^11×5^−q < 2^64 (f16) or
+// 2^8×5^−q < 2^64 (bf16) for negative exponents, so q ≥ −22 or q ≥ −24.
+// Unlike the wider floats, the halfway points between the f16 subnormals
+// can have fewer than 20 significant digits, so we need 5^−q < 2^64,
+// or q ≥ −27, for f16.
+
 #[cfg(feature = "f16")]
 impl LemireFloat for f16 {
-    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = 0;
-    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 0;
-    const MINIMUM_EXPONENT: i32 = 0;
-    const SMALLEST_POWER_OF_TEN: i32 = 0;
-    const LARGEST_POWER_OF_TEN: i32 = 0;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -27;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 5;
+    const MINIMUM_EXPONENT: i32 = -15;
+    const SMALLEST_POWER_OF_TEN: i32 = -27;
+    const LARGEST_POWER_OF_TEN: i32 = 4;
 }
 
 #[cfg(feature = "f16")]
 impl LemireFloat for bf16 {
-    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = 0;
-    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 0;
-    const MINIMUM_EXPONENT: i32 = 0;
-    const SMALLEST_POWER_OF_TEN: i32 = 0;
-    const LARGEST_POWER_OF_TEN: i32 = 0;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -24;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 3;
+    const MINIMUM_EXPONENT: i32 = -127;
+    const SMALLEST_POWER_OF_TEN: i32 = -60;
+    const LARGEST_POWER_OF_TEN: i32 = 38;
 }
 
 #[inline(always)]
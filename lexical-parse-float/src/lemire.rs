@@ -7,22 +7,23 @@
 #![doc(hidden)]
 
 use crate::float::{ExtendedFloat80, LemireFloat};
+use crate::mask::lower_n_mask;
 use crate::number::Number;
-use crate::shared;
+use crate::shared::{self, Rounding};
 use crate::table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
 
 /// Ensure truncation of digits doesn't affect our computation, by doing 2 passes.
 #[inline]
-pub fn lemire<F: LemireFloat>(num: &Number, lossy: bool) -> ExtendedFloat80 {
+pub fn lemire<F: LemireFloat>(num: &Number, lossy: bool, rounding: Rounding) -> ExtendedFloat80 {
     // If significant digits were truncated, then we can have rounding error
     // only if `mantissa + 1` produces a different result. We also avoid
     // redundantly using the Eisel-Lemire algorithm if it was unable to
     // correctly round on the first pass.
-    let mut fp = compute_float::<F>(num.exponent, num.mantissa, lossy);
+    let mut fp = compute_float::<F>(num.exponent, num.mantissa, lossy, rounding);
     if !lossy
         && num.many_digits
         && fp.exp >= 0
-        && fp != compute_float::<F>(num.exponent, num.mantissa + 1, false)
+        && fp != compute_float::<F>(num.exponent, num.mantissa + 1, false, rounding)
     {
         // Need to re-calculate, since the previous values are rounded
         // when the slow path algorithm expects a normalized extended float.
@@ -49,7 +50,12 @@ pub fn lemire<F: LemireFloat>(num: &Number, lossy: bool) -> ExtendedFloat80 {
 /// at a Gigabyte per Second" in section 5, "Fast Algorithm", and
 /// section 6, "Exact Numbers And Ties", available online:
 /// <https://arxiv.org/abs/2101.11408.pdf>.
-pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> ExtendedFloat80 {
+pub fn compute_float<F: LemireFloat>(
+    q: i64,
+    mut w: u64,
+    lossy: bool,
+    rounding: Rounding,
+) -> ExtendedFloat80 {
     let fp_zero = ExtendedFloat80 {
         mant: 0,
         exp: 0,
@@ -93,6 +99,11 @@ pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> Extende
     let upperbit = (hi >> 63) as i32;
     let mut mantissa = hi >> (upperbit + 64 - F::MANTISSA_SIZE - 3);
     let mut power2 = power(q as i32) + upperbit - lz - F::MINIMUM_EXPONENT;
+    if !rounding.is_nearest() {
+        let shift = upperbit + 64 - F::MANTISSA_SIZE - 2;
+        let is_up = rounding == Rounding::Up;
+        return compute_float_directed::<F>(q, lo, hi, lz, shift, power2, lossy, is_up);
+    }
     if power2 <= 0 {
         if -power2 + 1 >= 64 {
             // Have more than 64 bits below the minimum exponent, must be 0.
@@ -100,6 +111,15 @@ pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> Extende
         }
         // Have a subnormal value.
         mantissa >>= -power2 + 1;
+        // Round ties to even, which can only occur for the narrow floats.
+        if rounding == Rounding::NearestEven
+            && lo <= 1
+            && q >= F::MIN_EXPONENT_ROUND_TO_EVEN as i64
+            && mantissa & 3 == 1
+            && (mantissa << (upperbit + 64 - F::MANTISSA_SIZE - 3 - power2 + 1)) == hi
+        {
+            mantissa &= !1_u64;
+        }
         mantissa += mantissa & 1;
         mantissa >>= 1;
         power2 = (mantissa >= (1_u64 << F::MANTISSA_SIZE)) as i32;
@@ -119,7 +139,8 @@ pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> Extende
     //  3. All the bits truncated when shifting to mantissa bits + 1 are 0.
     //
     // Or, we may fall between two floats: we are exactly halfway.
-    if lo <= 1
+    if rounding == Rounding::NearestEven
+        && lo <= 1
         && q >= F::MIN_EXPONENT_ROUND_TO_EVEN as i64
         && q <= F::MAX_EXPONENT_ROUND_TO_EVEN as i64
         && mantissa & 3 == 1
@@ -150,6 +171,64 @@ pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> Extende
     }
 }
 
+/// Round the 128-bit product toward or away from zero.
+///
+/// The truncated bits only determine if the value is exact, and
+/// therefore the direction to round, unless they are all 0 or all 1,
+/// since the product may be slightly below the exact value. The
+/// product is exact for q ∈ [0, 27], since 5^q < 2^64, otherwise,
+/// these cases are represented by a negative, biased binary exponent,
+/// unless parsing is lossy.
+#[allow(clippy::too_many_arguments)]
+fn compute_float_directed<F: LemireFloat>(
+    q: i64,
+    lo: u64,
+    hi: u64,
+    lz: i32,
+    mut shift: i32,
+    mut power2: i32,
+    lossy: bool,
+    is_up: bool,
+) -> ExtendedFloat80 {
+    if power2 <= 0 {
+        // Have a subnormal value, also truncate the bits below the minimum exponent.
+        shift += -power2 + 1;
+    }
+    let (mut mantissa, mask) = match shift >= 64 {
+        true => (0, u64::MAX),
+        false => (hi >> shift, lower_n_mask(shift as u64)),
+    };
+    let truncated_bits = hi & mask;
+    let is_inexact = if truncated_bits != 0 && truncated_bits != mask {
+        true
+    } else if lossy || (0..=27).contains(&q) {
+        truncated_bits != 0 || lo != 0
+    } else {
+        return compute_error_scaled::<F>(q, hi, lz);
+    };
+    mantissa += (is_up && is_inexact) as u64;
+    if power2 <= 0 {
+        // Check if rounding up carried to the hidden bit.
+        power2 = (mantissa >= (1_u64 << F::MANTISSA_SIZE)) as i32;
+    } else if mantissa >= (2_u64 << F::MANTISSA_SIZE) {
+        mantissa = 1_u64 << F::MANTISSA_SIZE;
+        power2 += 1;
+    }
+    // Zero out the hidden bit.
+    mantissa &= !(1_u64 << F::MANTISSA_SIZE);
+    if power2 >= F::INFINITE_POWER {
+        // Exponent is above largest normal value, must be infinite.
+        return ExtendedFloat80 {
+            mant: 0,
+            exp: F::INFINITE_POWER,
+        };
+    }
+    ExtendedFloat80 {
+        mant: mantissa,
+        exp: power2,
+    }
+}
+
 /// Fallback algorithm to calculate the non-rounded representation.
 /// This calculates the extended representation, and then normalizes
 /// the resulting representation, so the high bit is set.
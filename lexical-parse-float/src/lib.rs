@@ -61,11 +61,12 @@
 //!
 //! ```rust
 //! use lexical_parse_float::Options;
-//! use lexical_parse_float::format::STANDARD;
+//! use lexical_parse_float::format::{NumberFormat, STANDARD};
 //! use lexical_parse_float::parse::ParseFloat;
 //!
 //! let options = Options::new();
-//! let result = f64::fast_path_complete::<{ STANDARD }>(b"1.34000", &options);
+//! let format = NumberFormat::<{ STANDARD }> {};
+//! let result = f64::fast_path_complete(b"1.34000", format, &options);
 //! assert_eq!(result, Ok(1.34000));
 //! ```
 //!
@@ -98,6 +99,10 @@ pub mod shared;
 pub mod bellerophon;
 pub mod bigint;
 pub mod binary;
+pub mod complex;
+pub mod decimal;
+pub mod decompose;
+pub mod exact;
 pub mod float;
 pub mod fpu;
 pub mod lemire;
@@ -107,7 +112,10 @@ pub mod mask;
 pub mod number;
 pub mod options;
 pub mod parse;
+pub mod rational;
+pub mod stream;
 pub mod slow;
+pub mod status;
 pub mod table;
 
 mod api;
@@ -121,14 +129,33 @@ mod table_radix;
 mod table_small;
 
 // Re-exports
-pub use self::api::{FromLexical, FromLexicalWithOptions};
+pub use self::api::{FromLexical, FromLexicalWithFormat, FromLexicalWithOptions};
+pub use self::decompose::{decompose, decompose_partial, DecomposedNumber};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{Options, OptionsBuilder, RoundMode};
+pub use self::status::{parse_partial_with_status, parse_with_status, Status};
+pub use self::stream::{FloatStream, Progress};
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
+#[cfg(feature = "complex")]
+pub use lexical_util::complex::Complex;
+#[cfg(feature = "custom-float")]
+pub use lexical_util::custom_float::{self, f8e4m3, f8e5m2, tf32, CustomFloat};
+#[cfg(feature = "rational")]
+pub use lexical_util::rational::Rational;
+#[cfg(feature = "decimal")]
+pub use lexical_util::decimal::{decimal128, decimal32, decimal64, DecimalFloat};
+#[cfg(feature = "double-double")]
+pub use lexical_util::double_double::DoubleDouble;
 pub use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
+#[cfg(feature = "f80")]
+pub use lexical_util::f80::f80;
+#[cfg(feature = "f128")]
+pub use lexical_util::f128::f128;
 pub use lexical_util::format::{self, NumberFormatBuilder};
+pub use lexical_util::grouping::DigitGrouping;
 pub use lexical_util::options::ParseOptions;
+pub use lexical_util::punctuation::{self, Punctuation};
 pub use lexical_util::result::Result;
@@ -7,6 +7,8 @@ use lexical_util::assert::debug_assert_radix;
 use lexical_util::bf16::bf16;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 
 // EXACT EXPONENT
 // --------------
@@ -73,119 +75,123 @@ impl ExactFloat for f64 {
 #[cfg(feature = "f16")]
 impl ExactFloat for f16 {
     #[inline(always)]
-    fn exponent_limit(_: u32) -> (i64, i64) {
-        unimplemented!()
+    fn exponent_limit(radix: u32) -> (i64, i64) {
+        debug_assert_radix(radix);
+        f16_exponent_limit(radix)
     }
 
     #[inline(always)]
-    fn mantissa_limit(_: u32) -> i64 {
-        unimplemented!()
+    fn mantissa_limit(radix: u32) -> i64 {
+        debug_assert_radix(radix);
+        f16_mantissa_limit(radix)
     }
 }
 
 #[cfg(feature = "f16")]
 impl ExactFloat for bf16 {
     #[inline(always)]
-    fn exponent_limit(_: u32) -> (i64, i64) {
-        unimplemented!()
+    fn exponent_limit(radix: u32) -> (i64, i64) {
+        debug_assert_radix(radix);
+        bf16_exponent_limit(radix)
     }
 
     #[inline(always)]
-    fn mantissa_limit(_: u32) -> i64 {
-        unimplemented!()
+    fn mantissa_limit(radix: u32) -> i64 {
+        debug_assert_radix(radix);
+        bf16_mantissa_limit(radix)
     }
 }
 
-//#[cfg(feature = "f128")]
-//impl ExactFloat for f128 {
-//    #[inline(always)]
-//    fn exponent_limit(radix: u32) -> (i64, i64) {
-//        debug_assert_radix(radix);
-//        match radix {
-//            2 if cfg!(feature = "power-of-two") => (-16494, 16383),
-//            3 if cfg!(feature = "radix") => (-71, 71),
-//            4 if cfg!(feature = "power-of-two") => (-8247, 8191),
-//            5 if cfg!(feature = "radix") => (-48, 48),
-//            6 if cfg!(feature = "radix") => (-71, 71),
-//            7 if cfg!(feature = "radix") => (-40, 40),
-//            8 if cfg!(feature = "power-of-two") => (-5498, 5461),
-//            9 if cfg!(feature = "radix") => (-35, 35),
-//            10 => (-48, 48),
-//            11 if cfg!(feature = "radix") => (-32, 32),
-//            12 if cfg!(feature = "radix") => (-71, 71),
-//            13 if cfg!(feature = "radix") => (-30, 30),
-//            14 if cfg!(feature = "radix") => (-40, 40),
-//            15 if cfg!(feature = "radix") => (-28, 28),
-//            16 if cfg!(feature = "power-of-two") => (-4123, 4095),
-//            17 if cfg!(feature = "radix") => (-27, 27),
-//            18 if cfg!(feature = "radix") => (-35, 35),
-//            19 if cfg!(feature = "radix") => (-26, 26),
-//            20 if cfg!(feature = "radix") => (-48, 48),
-//            21 if cfg!(feature = "radix") => (-25, 25),
-//            22 if cfg!(feature = "radix") => (-32, 32),
-//            23 if cfg!(feature = "radix") => (-24, 24),
-//            24 if cfg!(feature = "radix") => (-71, 71),
-//            25 if cfg!(feature = "radix") => (-24, 24),
-//            26 if cfg!(feature = "radix") => (-30, 30),
-//            27 if cfg!(feature = "radix") => (-23, 23),
-//            28 if cfg!(feature = "radix") => (-40, 40),
-//            29 if cfg!(feature = "radix") => (-23, 23),
-//            30 if cfg!(feature = "radix") => (-28, 28),
-//            31 if cfg!(feature = "radix") => (-22, 22),
-//            32 if cfg!(feature = "power-of-two") => (-3298, 3276),
-//            33 if cfg!(feature = "radix") => (-22, 22),
-//            34 if cfg!(feature = "radix") => (-27, 27),
-//            35 if cfg!(feature = "radix") => (-22, 22),
-//            36 if cfg!(feature = "radix") => (-35, 35),
-//            // Invalid radix
-//            _ => unreachable!(),
-//        }
-//    }
-//
-//    #[inline(always)]
-//    fn mantissa_limit(radix: u32) -> i64 {
-//        debug_assert_radix(radix);
-//        match radix {
-//            2 if cfg!(feature = "power-of-two") => 113,
-//            3 if cfg!(feature = "radix") => 71,
-//            4 if cfg!(feature = "power-of-two") => 56,
-//            5 if cfg!(feature = "radix") => 48,
-//            6 if cfg!(feature = "radix") => 43,
-//            7 if cfg!(feature = "radix") => 40,
-//            8 if cfg!(feature = "power-of-two") => 37,
-//            9 if cfg!(feature = "radix") => 35,
-//            10 => 34,
-//            11 if cfg!(feature = "radix") => 32,
-//            12 if cfg!(feature = "radix") => 31,
-//            13 if cfg!(feature = "radix") => 30,
-//            14 if cfg!(feature = "radix") => 29,
-//            15 if cfg!(feature = "radix") => 28,
-//            16 if cfg!(feature = "power-of-two") => 28,
-//            17 if cfg!(feature = "radix") => 27,
-//            18 if cfg!(feature = "radix") => 27,
-//            19 if cfg!(feature = "radix") => 26,
-//            20 if cfg!(feature = "radix") => 26,
-//            21 if cfg!(feature = "radix") => 25,
-//            22 if cfg!(feature = "radix") => 25,
-//            23 if cfg!(feature = "radix") => 24,
-//            24 if cfg!(feature = "radix") => 24,
-//            25 if cfg!(feature = "radix") => 24,
-//            26 if cfg!(feature = "radix") => 24,
-//            27 if cfg!(feature = "radix") => 23,
-//            28 if cfg!(feature = "radix") => 23,
-//            29 if cfg!(feature = "radix") => 23,
-//            30 if cfg!(feature = "radix") => 23,
-//            31 if cfg!(feature = "radix") => 22,
-//            32 if cfg!(feature = "power-of-two") => 22,
-//            33 if cfg!(feature = "radix") => 22,
-//            34 if cfg!(feature = "radix") => 22,
-//            35 if cfg!(feature = "radix") => 22,
-//            36 if cfg!(feature = "radix") => 21,
-//            // Invalid radix
-//            _ => unreachable!(),
-//        }
-//    }
-//}
+#[cfg(feature = "f128")]
+impl ExactFloat for f128 {
+    #[inline(always)]
+    fn exponent_limit(radix: u32) -> (i64, i64) {
+        debug_assert_radix(radix);
+        match radix {
+            2 if cfg!(feature = "power-of-two") => (-16494, 16383),
+            3 if cfg!(feature = "radix") => (-71, 71),
+            4 if cfg!(feature = "power-of-two") => (-8247, 8191),
+            5 if cfg!(feature = "radix") => (-48, 48),
+            6 if cfg!(feature = "radix") => (-71, 71),
+            7 if cfg!(feature = "radix") => (-40, 40),
+            8 if cfg!(feature = "power-of-two") => (-5498, 5461),
+            9 if cfg!(feature = "radix") => (-35, 35),
+            10 => (-48, 48),
+            11 if cfg!(feature = "radix") => (-32, 32),
+            12 if cfg!(feature = "radix") => (-71, 71),
+            13 if cfg!(feature = "radix") => (-30, 30),
+            14 if cfg!(feature = "radix") => (-40, 40),
+            15 if cfg!(feature = "radix") => (-28, 28),
+            16 if cfg!(feature = "power-of-two") => (-4123, 4095),
+            17 if cfg!(feature = "radix") => (-27, 27),
+            18 if cfg!(feature = "radix") => (-35, 35),
+            19 if cfg!(feature = "radix") => (-26, 26),
+            20 if cfg!(feature = "radix") => (-48, 48),
+            21 if cfg!(feature = "radix") => (-25, 25),
+            22 if cfg!(feature = "radix") => (-32, 32),
+            23 if cfg!(feature = "radix") => (-24, 24),
+            24 if cfg!(feature = "radix") => (-71, 71),
+            25 if cfg!(feature = "radix") => (-24, 24),
+            26 if cfg!(feature = "radix") => (-30, 30),
+            27 if cfg!(feature = "radix") => (-23, 23),
+            28 if cfg!(feature = "radix") => (-40, 40),
+            29 if cfg!(feature = "radix") => (-23, 23),
+            30 if cfg!(feature = "radix") => (-28, 28),
+            31 if cfg!(feature = "radix") => (-22, 22),
+            32 if cfg!(feature = "power-of-two") => (-3298, 3276),
+            33 if cfg!(feature = "radix") => (-22, 22),
+            34 if cfg!(feature = "radix") => (-27, 27),
+            35 if cfg!(feature = "radix") => (-22, 22),
+            36 if cfg!(feature = "radix") => (-35, 35),
+            // Invalid radix
+            _ => unreachable!(),
+        }
+    }
+
+    #[inline(always)]
+    fn mantissa_limit(radix: u32) -> i64 {
+        debug_assert_radix(radix);
+        match radix {
+            2 if cfg!(feature = "power-of-two") => 113,
+            3 if cfg!(feature = "radix") => 71,
+            4 if cfg!(feature = "power-of-two") => 56,
+            5 if cfg!(feature = "radix") => 48,
+            6 if cfg!(feature = "radix") => 43,
+            7 if cfg!(feature = "radix") => 40,
+            8 if cfg!(feature = "power-of-two") => 37,
+            9 if cfg!(feature = "radix") => 35,
+            10 => 34,
+            11 if cfg!(feature = "radix") => 32,
+            12 if cfg!(feature = "radix") => 31,
+            13 if cfg!(feature = "radix") => 30,
+            14 if cfg!(feature = "radix") => 29,
+            15 if cfg!(feature = "radix") => 28,
+            16 if cfg!(feature = "power-of-two") => 28,
+            17 if cfg!(feature = "radix") => 27,
+            18 if cfg!(feature = "radix") => 27,
+            19 if cfg!(feature = "radix") => 26,
+            20 if cfg!(feature = "radix") => 26,
+            21 if cfg!(feature = "radix") => 25,
+            22 if cfg!(feature = "radix") => 25,
+            23 if cfg!(feature = "radix") => 24,
+            24 if cfg!(feature = "radix") => 24,
+            25 if cfg!(feature = "radix") => 24,
+            26 if cfg!(feature = "radix") => 24,
+            27 if cfg!(feature = "radix") => 23,
+            28 if cfg!(feature = "radix") => 23,
+            29 if cfg!(feature = "radix") => 23,
+            30 if cfg!(feature = "radix") => 23,
+            31 if cfg!(feature = "radix") => 22,
+            32 if cfg!(feature = "power-of-two") => 22,
+            33 if cfg!(feature = "radix") => 22,
+            34 if cfg!(feature = "radix") => 22,
+            35 if cfg!(feature = "radix") => 22,
+            36 if cfg!(feature = "radix") => 21,
+            // Invalid radix
+            _ => unreachable!(),
+        }
+    }
+}
 
 // CONST FN
 // --------
@@ -362,6 +368,182 @@ pub const fn f64_mantissa_limit(radix: u32) -> i64 {
     }
 }
 
+/// Get the exponent limit as a const fn.
+#[inline(always)]
+#[cfg(feature = "f16")]
+pub const fn f16_exponent_limit(radix: u32) -> (i64, i64) {
+    match radix {
+        2 if cfg!(feature = "power-of-two") => (-15, 15),
+        3 if cfg!(feature = "radix") => (-6, 6),
+        4 if cfg!(feature = "power-of-two") => (-7, 7),
+        5 if cfg!(feature = "radix") => (-4, 4),
+        6 if cfg!(feature = "radix") => (-6, 6),
+        7 if cfg!(feature = "radix") => (-3, 3),
+        8 if cfg!(feature = "power-of-two") => (-5, 5),
+        9 if cfg!(feature = "radix") => (-3, 3),
+        10 => (-4, 4),
+        11 if cfg!(feature = "radix") => (-3, 3),
+        12 if cfg!(feature = "radix") => (-6, 6),
+        13 if cfg!(feature = "radix") => (-2, 2),
+        14 if cfg!(feature = "radix") => (-3, 3),
+        15 if cfg!(feature = "radix") => (-2, 2),
+        16 if cfg!(feature = "power-of-two") => (-3, 3),
+        17 if cfg!(feature = "radix") => (-2, 2),
+        18 if cfg!(feature = "radix") => (-3, 3),
+        19 if cfg!(feature = "radix") => (-2, 2),
+        20 if cfg!(feature = "radix") => (-4, 4),
+        21 if cfg!(feature = "radix") => (-2, 2),
+        22 if cfg!(feature = "radix") => (-3, 3),
+        23 if cfg!(feature = "radix") => (-2, 2),
+        24 if cfg!(feature = "radix") => (-6, 6),
+        25 if cfg!(feature = "radix") => (-2, 2),
+        26 if cfg!(feature = "radix") => (-2, 2),
+        27 if cfg!(feature = "radix") => (-2, 2),
+        28 if cfg!(feature = "radix") => (-3, 3),
+        29 if cfg!(feature = "radix") => (-2, 2),
+        30 if cfg!(feature = "radix") => (-2, 2),
+        31 if cfg!(feature = "radix") => (-2, 2),
+        32 if cfg!(feature = "power-of-two") => (-3, 3),
+        33 if cfg!(feature = "radix") => (-2, 2),
+        34 if cfg!(feature = "radix") => (-2, 2),
+        35 if cfg!(feature = "radix") => (-2, 2),
+        36 if cfg!(feature = "radix") => (-3, 3),
+        _ => (0, 0),
+    }
+}
+
+/// Get the mantissa limit as a const fn.
+#[inline(always)]
+#[cfg(feature = "f16")]
+pub const fn f16_mantissa_limit(radix: u32) -> i64 {
+    match radix {
+        2 if cfg!(feature = "power-of-two") => 11,
+        3 if cfg!(feature = "radix") => 6,
+        4 if cfg!(feature = "power-of-two") => 5,
+        5 if cfg!(feature = "radix") => 4,
+        6 if cfg!(feature = "radix") => 4,
+        7 if cfg!(feature = "radix") => 3,
+        8 if cfg!(feature = "power-of-two") => 3,
+        9 if cfg!(feature = "radix") => 3,
+        10 => 3,
+        11 if cfg!(feature = "radix") => 3,
+        12 if cfg!(feature = "radix") => 3,
+        13 if cfg!(feature = "radix") => 2,
+        14 if cfg!(feature = "radix") => 2,
+        15 if cfg!(feature = "radix") => 2,
+        16 if cfg!(feature = "power-of-two") => 2,
+        17 if cfg!(feature = "radix") => 2,
+        18 if cfg!(feature = "radix") => 2,
+        19 if cfg!(feature = "radix") => 2,
+        20 if cfg!(feature = "radix") => 2,
+        21 if cfg!(feature = "radix") => 2,
+        22 if cfg!(feature = "radix") => 2,
+        23 if cfg!(feature = "radix") => 2,
+        24 if cfg!(feature = "radix") => 2,
+        25 if cfg!(feature = "radix") => 2,
+        26 if cfg!(feature = "radix") => 2,
+        27 if cfg!(feature = "radix") => 2,
+        28 if cfg!(feature = "radix") => 2,
+        29 if cfg!(feature = "radix") => 2,
+        30 if cfg!(feature = "radix") => 2,
+        31 if cfg!(feature = "radix") => 2,
+        32 if cfg!(feature = "power-of-two") => 2,
+        33 if cfg!(feature = "radix") => 2,
+        34 if cfg!(feature = "radix") => 2,
+        35 if cfg!(feature = "radix") => 2,
+        36 if cfg!(feature = "radix") => 2,
+        _ => 0,
+    }
+}
+
+/// Get the exponent limit as a const fn.
+#[inline(always)]
+#[cfg(feature = "f16")]
+pub const fn bf16_exponent_limit(radix: u32) -> (i64, i64) {
+    match radix {
+        2 if cfg!(feature = "power-of-two") => (-127, 127),
+        3 if cfg!(feature = "radix") => (-5, 5),
+        4 if cfg!(feature = "power-of-two") => (-63, 63),
+        5 if cfg!(feature = "radix") => (-3, 3),
+        6 if cfg!(feature = "radix") => (-5, 5),
+        7 if cfg!(feature = "radix") => (-2, 2),
+        8 if cfg!(feature = "power-of-two") => (-42, 42),
+        9 if cfg!(feature = "radix") => (-2, 2),
+        10 => (-3, 3),
+        11 if cfg!(feature = "radix") => (-2, 2),
+        12 if cfg!(feature = "radix") => (-5, 5),
+        13 if cfg!(feature = "radix") => (-2, 2),
+        14 if cfg!(feature = "radix") => (-2, 2),
+        15 if cfg!(feature = "radix") => (-2, 2),
+        16 if cfg!(feature = "power-of-two") => (-31, 31),
+        17 if cfg!(feature = "radix") => (-1, 1),
+        18 if cfg!(feature = "radix") => (-2, 2),
+        19 if cfg!(feature = "radix") => (-1, 1),
+        20 if cfg!(feature = "radix") => (-3, 3),
+        21 if cfg!(feature = "radix") => (-1, 1),
+        22 if cfg!(feature = "radix") => (-2, 2),
+        23 if cfg!(feature = "radix") => (-1, 1),
+        24 if cfg!(feature = "radix") => (-5, 5),
+        25 if cfg!(feature = "radix") => (-1, 1),
+        26 if cfg!(feature = "radix") => (-2, 2),
+        27 if cfg!(feature = "radix") => (-1, 1),
+        28 if cfg!(feature = "radix") => (-2, 2),
+        29 if cfg!(feature = "radix") => (-1, 1),
+        30 if cfg!(feature = "radix") => (-2, 2),
+        31 if cfg!(feature = "radix") => (-1, 1),
+        32 if cfg!(feature = "power-of-two") => (-25, 25),
+        33 if cfg!(feature = "radix") => (-1, 1),
+        34 if cfg!(feature = "radix") => (-1, 1),
+        35 if cfg!(feature = "radix") => (-1, 1),
+        36 if cfg!(feature = "radix") => (-2, 2),
+        _ => (0, 0),
+    }
+}
+
+/// Get the mantissa limit as a const fn.
+#[inline(always)]
+#[cfg(feature = "f16")]
+pub const fn bf16_mantissa_limit(radix: u32) -> i64 {
+    match radix {
+        2 if cfg!(feature = "power-of-two") => 8,
+        3 if cfg!(feature = "radix") => 5,
+        4 if cfg!(feature = "power-of-two") => 4,
+        5 if cfg!(feature = "radix") => 3,
+        6 if cfg!(feature = "radix") => 3,
+        7 if cfg!(feature = "radix") => 2,
+        8 if cfg!(feature = "power-of-two") => 2,
+        9 if cfg!(feature = "radix") => 2,
+        10 => 2,
+        11 if cfg!(feature = "radix") => 2,
+        12 if cfg!(feature = "radix") => 2,
+        13 if cfg!(feature = "radix") => 2,
+        14 if cfg!(feature = "radix") => 2,
+        15 if cfg!(feature = "radix") => 2,
+        16 if cfg!(feature = "power-of-two") => 2,
+        17 if cfg!(feature = "radix") => 1,
+        18 if cfg!(feature = "radix") => 1,
+        19 if cfg!(feature = "radix") => 1,
+        20 if cfg!(feature = "radix") => 1,
+        21 if cfg!(feature = "radix") => 1,
+        22 if cfg!(feature = "radix") => 1,
+        23 if cfg!(feature = "radix") => 1,
+        24 if cfg!(feature = "radix") => 1,
+        25 if cfg!(feature = "radix") => 1,
+        26 if cfg!(feature = "radix") => 1,
+        27 if cfg!(feature = "radix") => 1,
+        28 if cfg!(feature = "radix") => 1,
+        29 if cfg!(feature = "radix") => 1,
+        30 if cfg!(feature = "radix") => 1,
+        31 if cfg!(feature = "radix") => 1,
+        32 if cfg!(feature = "power-of-two") => 1,
+        33 if cfg!(feature = "radix") => 1,
+        34 if cfg!(feature = "radix") => 1,
+        35 if cfg!(feature = "radix") => 1,
+        36 if cfg!(feature = "radix") => 1,
+        _ => 0,
+    }
+}
+
 // POWER LIMITS
 // ------------
 
@@ -619,48 +801,55 @@ impl MaxDigits for f64 {
     }
 }
 
+/// emin = -14
+/// p2 = 11
 #[cfg(feature = "f16")]
 impl MaxDigits for f16 {
     #[inline(always)]
-    fn max_digits(_: u32) -> Option<usize> {
-        unimplemented!()
+    fn max_digits(radix: u32) -> Option<usize> {
+        debug_assert_radix(radix);
+        f16_max_digits(radix)
     }
 }
 
+/// emin = -126
+/// p2 = 8
 #[cfg(feature = "f16")]
 impl MaxDigits for bf16 {
     #[inline(always)]
-    fn max_digits(_: u32) -> Option<usize> {
-        unimplemented!()
+    fn max_digits(radix: u32) -> Option<usize> {
+        debug_assert_radix(radix);
+        bf16_max_digits(radix)
     }
 }
 
-///// emin = -16382
-///// p2 = 113
-//#[cfg(feature = "f128")]
-//impl MaxDigits for f128 {
-//    #[inline(always)]
-//    fn max_digits(radix: u32) -> Option<usize> {
-//        match radix {
-//            6 => Some(10159),
-//            10 => Some(11565),
-//            12 => Some(11927),
-//            14 => Some(12194),
-//            18 => Some(12568),
-//            20 => Some(12706),
-//            22 => Some(12823),
-//            24 => Some(12924),
-//            26 => Some(13012),
-//            28 => Some(13089),
-//            30 => Some(13158),
-//            34 => Some(13277),
-//            36 => Some(13328),
-//            // Powers of two should be unreachable.
-//            // Odd numbers will have infinite digits.
-//            _ => None,
-//        }
-//    }
-//}
+/// emin = -16382
+/// p2 = 113
+#[cfg(feature = "f128")]
+impl MaxDigits for f128 {
+    #[inline(always)]
+    fn max_digits(radix: u32) -> Option<usize> {
+        debug_assert_radix(radix);
+        match radix {
+            6 => Some(10159),
+            10 => Some(11565),
+            12 => Some(11927),
+            14 => Some(12194),
+            18 => Some(12568),
+            20 => Some(12706),
+            22 => Some(12823),
+            24 => Some(12924),
+            26 => Some(13012),
+            28 => Some(13089),
+            30 => Some(13158),
+            34 => Some(13277),
+            36 => Some(13328),
+            // Powers of two should be unreachable.
+            // Odd numbers will have infinite digits.
+            _ => None,
+        }
+    }
+}
 
 // CONST FN
 // --------
@@ -710,3 +899,51 @@ pub const fn f64_max_digits(radix: u32) -> Option<usize> {
         _ => None,
     }
 }
+
+/// Get the maximum number of significant digits as a const fn.
+#[inline(always)]
+#[cfg(feature = "f16")]
+pub const fn f16_max_digits(radix: u32) -> Option<usize> {
+    match radix {
+        6 => Some(21),
+        10 => Some(23),
+        12 => Some(23),
+        14 => Some(23),
+        18 => Some(23),
+        20 => Some(23),
+        22 => Some(24),
+        24 => Some(24),
+        26 => Some(24),
+        28 => Some(24),
+        30 => Some(24),
+        34 => Some(24),
+        36 => Some(24),
+        // Powers of two should be unreachable.
+        // Odd numbers will have infinite digits.
+        _ => None,
+    }
+}
+
+/// Get the maximum number of significant digits as a const fn.
+#[inline(always)]
+#[cfg(feature = "f16")]
+pub const fn bf16_max_digits(radix: u32) -> Option<usize> {
+    match radix {
+        6 => Some(87),
+        10 => Some(98),
+        12 => Some(101),
+        14 => Some(103),
+        18 => Some(106),
+        20 => Some(107),
+        22 => Some(107),
+        24 => Some(108),
+        26 => Some(109),
+        28 => Some(109),
+        30 => Some(110),
+        34 => Some(111),
+        36 => Some(111),
+        // Powers of two should be unreachable.
+        // Odd numbers will have infinite digits.
+        _ => None,
+    }
+}
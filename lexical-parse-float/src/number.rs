@@ -9,7 +9,7 @@
 use crate::float::RawFloat;
 #[cfg(feature = "nightly")]
 use crate::fpu::set_precision;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 
 /// Representation of a number as the significant digits and exponent.
 ///
@@ -35,8 +35,7 @@ pub struct Number<'a> {
 impl<'a> Number<'a> {
     /// Detect if the float can be accurately reconstructed from native floats.
     #[inline]
-    pub fn is_fast_path<F: RawFloat, const FORMAT: u128>(&self) -> bool {
-        let format = NumberFormat::<FORMAT> {};
+    pub fn is_fast_path<F: RawFloat, Fmt: Format>(&self, format: Fmt) -> bool {
         debug_assert!(format.mantissa_radix() == format.exponent_base());
         F::min_exponent_fast_path(format.radix()) <= self.exponent
             && self.exponent <= F::max_exponent_disguised_fast_path(format.radix())
@@ -44,6 +43,28 @@ impl<'a> Number<'a> {
             && !self.many_digits
     }
 
+    /// Detect if the fast path is exact, and therefore valid for any rounding.
+    ///
+    /// The value is exact if the mantissa times the odd factor of the
+    /// radix power fits in the float mantissa, or is divisible by the
+    /// odd factor of the radix power, since powers-of-two are exact.
+    #[inline]
+    pub fn is_exact_fast_path<F: RawFloat, Fmt: Format>(&self, format: Fmt) -> bool {
+        if !self.is_fast_path::<F, _>(format) {
+            return false;
+        }
+        let radix = format.radix() as u64;
+        let odd = radix >> radix.trailing_zeros();
+        match odd.checked_pow(self.exponent.unsigned_abs() as u32) {
+            Some(power) if self.exponent < 0 => self.mantissa.wrapping_rem(power) == 0,
+            Some(power) => match self.mantissa.checked_mul(power) {
+                Some(mantissa) => mantissa <= F::MAX_MANTISSA_FAST_PATH,
+                None => false,
+            },
+            None => false,
+        }
+    }
+
     /// The fast path algorithmn using machine-sized integers and floats.
     ///
     /// This is extracted into a separate function so that it can be attempted before constructing
@@ -55,8 +76,7 @@ impl<'a> Number<'a> {
     /// powers-of-10 from the exponent to the significant digits.
     // `set_precision` doesn't return a unit value on x87 FPUs.
     #[allow(clippy::let_unit_value)]
-    pub fn try_fast_path<F: RawFloat, const FORMAT: u128>(&self) -> Option<F> {
-        let format = NumberFormat::<FORMAT> {};
+    pub fn try_fast_path<F: RawFloat, Fmt: Format>(&self, format: Fmt) -> Option<F> {
         debug_assert!(format.mantissa_radix() == format.exponent_base());
         // The fast path crucially depends on arithmetic being rounded to the correct number of bits
         // without any intermediate rounding. On x86 (without SSE or SSE2) this requires the precision
@@ -66,7 +86,7 @@ impl<'a> Number<'a> {
         #[cfg(feature = "nightly")]
         let _cw = set_precision::<F>();
 
-        if self.is_fast_path::<F, FORMAT>() {
+        if self.is_fast_path::<F, _>(format) {
             let radix = format.radix();
             let max_exponent = F::max_exponent_fast_path(radix);
             let mut value = if self.exponent <= max_exponent {
@@ -103,8 +123,7 @@ impl<'a> Number<'a> {
     /// Force a fast-path algorithm, even when it may not be accurate.
     // `set_precision` doesn't return a unit value on x87 FPUs.
     #[allow(clippy::let_unit_value)]
-    pub fn force_fast_path<F: RawFloat, const FORMAT: u128>(&self) -> F {
-        let format = NumberFormat::<FORMAT> {};
+    pub fn force_fast_path<F: RawFloat, Fmt: Format>(&self, format: Fmt) -> F {
         debug_assert!(format.mantissa_radix() == format.exponent_base());
 
         #[cfg(feature = "nightly")]
@@ -3,12 +3,31 @@
 use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
 use lexical_util::error::Error;
 use lexical_util::options::{self, ParseOptions};
+use lexical_util::punctuation::Punctuation;
 use lexical_util::result::Result;
 use static_assertions::const_assert;
 
 /// Maximum length for a special string.
 const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 
+/// Maximum length for the imaginary unit.
+const MAX_IMAGINARY_UNIT_LENGTH: usize = 4;
+
+/// Enumeration for how to round floats that cannot be exactly represented.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RoundMode {
+    /// Round to the nearest float, with ties to the float with an even mantissa.
+    NearestTiesEven,
+    /// Round to the nearest float, with ties away from zero.
+    NearestTiesAway,
+    /// Round toward zero, or truncate the float.
+    TowardZero,
+    /// Round toward positive infinity.
+    TowardPositive,
+    /// Round toward negative infinity.
+    TowardNegative,
+}
+
 /// Builder for `Options`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct OptionsBuilder {
@@ -25,6 +44,14 @@ pub struct OptionsBuilder {
     inf_string: Option<&'static [u8]>,
     /// Long string representation of `Infinity`.
     infinity_string: Option<&'static [u8]>,
+    /// Rounding mode for floats that cannot be exactly represented.
+    round_mode: RoundMode,
+    /// Return an error if a float overflows to infinity or underflows to zero.
+    error_on_out_of_range: bool,
+    /// Suffix for the imaginary part of complex numbers.
+    imaginary_unit: Option<&'static [u8]>,
+    /// Alternatives for the digit separator, decimal point and signs.
+    punctuation: Punctuation,
 }
 
 impl OptionsBuilder {
@@ -38,6 +65,10 @@ impl OptionsBuilder {
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
             infinity_string: Some(b"infinity"),
+            round_mode: RoundMode::NearestTiesEven,
+            error_on_out_of_range: false,
+            imaginary_unit: Some(b"i"),
+            punctuation: Punctuation::new(),
         }
     }
 
@@ -79,6 +110,30 @@ impl OptionsBuilder {
         self.infinity_string
     }
 
+    /// Get the rounding mode for floats that cannot be exactly represented.
+    #[inline(always)]
+    pub const fn get_round_mode(&self) -> RoundMode {
+        self.round_mode
+    }
+
+    /// Get if we return an error if a float overflows to infinity or underflows to zero.
+    #[inline(always)]
+    pub const fn get_error_on_out_of_range(&self) -> bool {
+        self.error_on_out_of_range
+    }
+
+    /// Get the suffix for the imaginary part of complex numbers.
+    #[inline(always)]
+    pub const fn get_imaginary_unit(&self) -> Option<&'static [u8]> {
+        self.imaginary_unit
+    }
+
+    /// Get the alternatives for the digit separator, decimal point and signs.
+    #[inline(always)]
+    pub const fn get_punctuation(&self) -> Punctuation {
+        self.punctuation
+    }
+
     // SETTERS
 
     /// Set if we disable the use of arbitrary-precision arithmetic.
@@ -123,6 +178,47 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the rounding mode for floats that cannot be exactly represented.
+    #[inline(always)]
+    pub const fn round_mode(mut self, round_mode: RoundMode) -> Self {
+        self.round_mode = round_mode;
+        self
+    }
+
+    /// Set if we return an error if a float overflows to infinity or underflows to zero.
+    #[inline(always)]
+    pub const fn error_on_out_of_range(mut self, error_on_out_of_range: bool) -> Self {
+        self.error_on_out_of_range = error_on_out_of_range;
+        self
+    }
+
+    /// Set the suffix for the imaginary part of complex numbers.
+    ///
+    /// If `None`, complex numbers can only be parsed as an `(a,b)` tuple.
+    #[inline(always)]
+    pub const fn imaginary_unit(mut self, imaginary_unit: Option<&'static [u8]>) -> Self {
+        self.imaginary_unit = imaginary_unit;
+        self
+    }
+
+    /// Set the alternatives for the digit separator, decimal point and signs.
+    ///
+    /// Each alternative is replaced by the punctuation it stands for
+    /// before parsing, so the digit separator rules of the number format
+    /// still apply.
+    ///
+    /// The options do not know the number format, so alternatives are
+    /// only checked against the decimal point, exponent and signs when
+    /// the options are built. An alternative that is the digit separator
+    /// of the number format, but does not stand for it, is only detected
+    /// when parsing, which returns [`Error::InvalidAlternativePunctuation`]
+    /// for any input.
+    #[inline(always)]
+    pub const fn punctuation(mut self, punctuation: Punctuation) -> Self {
+        self.punctuation = punctuation;
+        self
+    }
+
     // BUILDERS
 
     /// Determine if `nan_str` is valid.
@@ -197,6 +293,27 @@ impl OptionsBuilder {
         }
     }
 
+    /// Determine if `imaginary_unit` is valid.
+    #[inline(always)]
+    #[allow(clippy::if_same_then_else, clippy::needless_bool)]
+    pub const fn imaginary_unit_is_valid(&self) -> bool {
+        if self.imaginary_unit.is_none() {
+            return true;
+        }
+
+        let unit = unwrap_str(self.imaginary_unit);
+        let length = unit.len();
+        if length == 0 || length > MAX_IMAGINARY_UNIT_LENGTH {
+            false
+        } else if !is_valid_letter_slice(unit) {
+            false
+        } else if is_exponent_letter(unit[0], self.exponent) {
+            false
+        } else {
+            true
+        }
+    }
+
     /// Check if the builder state is valid.
     #[inline(always)]
     #[allow(clippy::if_same_then_else, clippy::needless_bool)]
@@ -211,6 +328,10 @@ impl OptionsBuilder {
             false
         } else if !self.infinity_string_is_valid() {
             false
+        } else if !self.imaginary_unit_is_valid() {
+            false
+        } else if !self.punctuation.is_valid_for(0, self.decimal_point, self.exponent) {
+            false
         } else {
             true
         }
@@ -231,6 +352,10 @@ impl OptionsBuilder {
             nan_string: self.nan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
+            round_mode: self.round_mode,
+            error_on_out_of_range: self.error_on_out_of_range,
+            imaginary_unit: self.imaginary_unit,
+            punctuation: self.punctuation,
         }
     }
 
@@ -284,6 +409,21 @@ impl OptionsBuilder {
             }
         }
 
+        if self.imaginary_unit.is_some() {
+            let unit = unwrap_str(self.imaginary_unit);
+            if unit.is_empty() || !is_valid_letter_slice(unit) {
+                return Err(Error::InvalidImaginaryUnit);
+            } else if is_exponent_letter(unit[0], self.exponent) {
+                return Err(Error::InvalidImaginaryUnit);
+            } else if unit.len() > MAX_IMAGINARY_UNIT_LENGTH {
+                return Err(Error::ImaginaryUnitTooLong);
+            }
+        }
+
+        if !self.punctuation.is_valid_for(0, self.decimal_point, self.exponent) {
+            return Err(Error::InvalidAlternativePunctuation);
+        }
+
         // SAFETY: always safe, since it must be valid.
         Ok(unsafe { self.build_unchecked() })
     }
@@ -328,6 +468,14 @@ pub struct Options {
     inf_string: Option<&'static [u8]>,
     /// Long string representation of `Infinity`.
     infinity_string: Option<&'static [u8]>,
+    /// Rounding mode for floats that cannot be exactly represented.
+    round_mode: RoundMode,
+    /// Return an error if a float overflows to infinity or underflows to zero.
+    error_on_out_of_range: bool,
+    /// Suffix for the imaginary part of complex numbers.
+    imaginary_unit: Option<&'static [u8]>,
+    /// Alternatives for the digit separator, decimal point and signs.
+    punctuation: Punctuation,
 }
 
 impl Options {
@@ -399,6 +547,30 @@ impl Options {
         self.infinity_string
     }
 
+    /// Get the rounding mode for floats that cannot be exactly represented.
+    #[inline(always)]
+    pub const fn round_mode(&self) -> RoundMode {
+        self.round_mode
+    }
+
+    /// Get if we return an error if a float overflows to infinity or underflows to zero.
+    #[inline(always)]
+    pub const fn error_on_out_of_range(&self) -> bool {
+        self.error_on_out_of_range
+    }
+
+    /// Get the suffix for the imaginary part of complex numbers.
+    #[inline(always)]
+    pub const fn imaginary_unit(&self) -> Option<&'static [u8]> {
+        self.imaginary_unit
+    }
+
+    /// Get the alternatives for the digit separator, decimal point and signs.
+    #[inline(always)]
+    pub const fn punctuation(&self) -> Punctuation {
+        self.punctuation
+    }
+
     // SETTERS
 
     /// Set if we disable the use of arbitrary-precision arithmetic.
@@ -466,6 +638,48 @@ impl Options {
         self.infinity_string = infinity_string
     }
 
+    /// Set the rounding mode for floats that cannot be exactly represented.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_round_mode(&mut self, round_mode: RoundMode) {
+        self.round_mode = round_mode
+    }
+
+    /// Set if we return an error if a float overflows to infinity or underflows to zero.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_error_on_out_of_range(&mut self, error_on_out_of_range: bool) {
+        self.error_on_out_of_range = error_on_out_of_range
+    }
+
+    /// Set the suffix for the imaginary part of complex numbers.
+    /// Unsafe, use the builder API for option validation.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_imaginary_unit(&mut self, imaginary_unit: Option<&'static [u8]>) {
+        self.imaginary_unit = imaginary_unit
+    }
+
+    /// Set the alternatives for the digit separator, decimal point and signs.
+    /// Unsafe, use the builder API for option validation.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_punctuation(&mut self, punctuation: Punctuation) {
+        self.punctuation = punctuation
+    }
+
     // BUILDERS
 
     /// Get OptionsBuilder as a static function.
@@ -484,6 +698,10 @@ impl Options {
             nan_string: self.nan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
+            round_mode: self.round_mode,
+            error_on_out_of_range: self.error_on_out_of_range,
+            imaginary_unit: self.imaginary_unit,
+            punctuation: self.punctuation,
         }
     }
 }
@@ -502,6 +720,12 @@ impl ParseOptions for Options {
     }
 }
 
+/// Determine if a letter is the exponent character, ignoring case.
+#[inline(always)]
+const fn is_exponent_letter(c: u8, exponent: u8) -> bool {
+    c | 0x20 == exponent | 0x20
+}
+
 /// Unwrap `Option` as a const fn.
 #[inline(always)]
 const fn unwrap_str(option: Option<&'static [u8]>) -> &'static [u8] {
@@ -572,6 +796,7 @@ pub const PYTHON_LITERAL: Options = unsafe {
         .nan_string(options::PYTHON_LITERAL)
         .inf_string(options::PYTHON_LITERAL)
         .infinity_string(options::PYTHON_LITERAL)
+        .imaginary_unit(options::PYTHON_LITERAL_IMAGINARY)
         .build_unchecked()
 };
 const_assert!(PYTHON_LITERAL.is_valid());
@@ -763,6 +988,7 @@ pub const JULIA_LITERAL: Options = unsafe {
     Options::builder()
         .inf_string(options::JULIA_LITERAL_INF)
         .infinity_string(options::JULIA_LITERAL_INFINITY)
+        .imaginary_unit(options::JULIA_LITERAL_IMAGINARY)
         .build_unchecked()
 };
 const_assert!(JULIA_LITERAL.is_valid());
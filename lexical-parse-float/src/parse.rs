@@ -5,27 +5,54 @@
 
 #![doc(hidden)]
 
+#[cfg(feature = "complex")]
+use crate::api::FromLexicalWithFormat;
 #[cfg(any(feature = "compact", feature = "radix"))]
 use crate::bellerophon::bellerophon;
 #[cfg(feature = "power-of-two")]
 use crate::binary::{binary, slow_binary};
+#[cfg(feature = "complex")]
+use crate::complex;
+#[cfg(feature = "decimal")]
+use crate::decimal::{self, parse_decimal_special, parse_partial_decimal_special};
+#[cfg(any(feature = "f80", feature = "f128", feature = "custom-float", feature = "double-double"))]
+use crate::exact;
 use crate::float::{extended_to_float, ExtendedFloat80, LemireFloat};
 #[cfg(not(feature = "compact"))]
 use crate::lemire::lemire;
 use crate::number::Number;
 use crate::options::Options;
-use crate::shared;
-use crate::slow::slow_radix;
+#[cfg(feature = "rational")]
+use crate::rational;
+use crate::shared::{self, Rounding};
+use crate::slow::{compare_float, slow_radix};
+use crate::status::Status;
+use core::cmp;
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+#[cfg(feature = "complex")]
+use lexical_util::complex::Complex;
+#[cfg(feature = "custom-float")]
+use lexical_util::custom_float::CustomFloat;
+#[cfg(feature = "decimal")]
+use lexical_util::decimal::{decimal128, decimal32, decimal64, DecimalFloat};
 use lexical_util::digit::{char_to_digit_const, char_to_valid_digit_const};
+#[cfg(feature = "double-double")]
+use lexical_util::double_double::DoubleDouble;
 use lexical_util::error::Error;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::NumberFormat;
+#[cfg(feature = "f80")]
+use lexical_util::f80::f80;
+use lexical_util::format::Format;
 use lexical_util::iterator::{AsBytes, Bytes, BytesIter};
+use lexical_util::num::{AsPrimitive, Float};
+#[cfg(feature = "rational")]
+use lexical_util::rational::Rational;
 use lexical_util::result::Result;
 use lexical_util::step::u64_step;
 
@@ -43,7 +70,7 @@ macro_rules! is_power_two {
 /// Check if the radix is valid and error otherwise
 macro_rules! check_radix {
     ($format:ident) => {{
-        let format = NumberFormat::<{ $format }> {};
+        let format = $format;
         #[cfg(feature = "power-of-two")]
         {
             if format.radix() != format.exponent_base() {
@@ -66,37 +93,57 @@ macro_rules! check_radix {
     }};
 }
 
+/// Check if the radix is decimal, which is required for decimal floats.
+#[cfg(feature = "decimal")]
+macro_rules! check_decimal_radix {
+    ($format:ident) => {{
+        let format = $format;
+        if format.radix() != 10 || format.exponent_base() != 10 {
+            return Err(Error::InvalidRadix);
+        }
+    }};
+}
+
 /// Parse integer trait, implemented in terms of the optimized back-end.
 pub trait ParseFloat: LemireFloat {
     /// Forward complete parser parameters to the backend.
     #[cfg_attr(not(feature = "compact"), inline(always))]
-    fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<Self> {
-        check_radix!(FORMAT);
-        parse_complete::<Self, FORMAT>(bytes, options)
+    fn parse_complete<Fmt: Format>(bytes: &[u8], format: Fmt, options: &Options) -> Result<Self> {
+        check_radix!(format);
+        parse_complete::<Self, _>(bytes, format, options)
     }
 
     /// Forward partial parser parameters to the backend.
     #[cfg_attr(not(feature = "compact"), inline(always))]
-    fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<(Self, usize)> {
-        check_radix!(FORMAT);
-        parse_partial::<Self, FORMAT>(bytes, options)
+    fn parse_partial<Fmt: Format>(
+        bytes: &[u8],
+        format: Fmt,
+        options: &Options,
+    ) -> Result<(Self, usize)> {
+        check_radix!(format);
+        parse_partial::<Self, _>(bytes, format, options)
     }
 
     /// Forward complete parser parameters to the backend, using only the fast path.
     #[cfg_attr(not(feature = "compact"), inline(always))]
-    fn fast_path_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<Self> {
-        check_radix!(FORMAT);
-        fast_path_complete::<Self, FORMAT>(bytes, options)
+    fn fast_path_complete<Fmt: Format>(
+        bytes: &[u8],
+        format: Fmt,
+        options: &Options,
+    ) -> Result<Self> {
+        check_radix!(format);
+        fast_path_complete::<Self, _>(bytes, format, options)
     }
 
     /// Forward partial parser parameters to the backend, using only the fast path.
     #[cfg_attr(not(feature = "compact"), inline(always))]
-    fn fast_path_partial<const FORMAT: u128>(
+    fn fast_path_partial<Fmt: Format>(
         bytes: &[u8],
+        format: Fmt,
         options: &Options,
     ) -> Result<(Self, usize)> {
-        check_radix!(FORMAT);
-        fast_path_partial::<Self, FORMAT>(bytes, options)
+        check_radix!(format);
+        fast_path_partial::<Self, _>(bytes, format, options)
     }
 }
 
@@ -109,44 +156,175 @@ macro_rules! parse_float_impl {
 parse_float_impl! { f32 f64 }
 
 #[cfg(feature = "f16")]
-macro_rules! parse_float_as_f32 {
-    ($($t:ty)*) => ($(
-        impl ParseFloat for $t {
-            #[cfg_attr(not(feature = "compact"), inline(always))]
-            fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options)
-                -> Result<Self>
-            {
-                Ok(Self::from_f32(parse_complete::<f32, FORMAT>(bytes, options)?))
-            }
+parse_float_impl! { bf16 f16 }
 
-            #[cfg_attr(not(feature = "compact"), inline(always))]
-            fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options)
-                -> Result<(Self, usize)>
-            {
-                let (float, count) = parse_partial::<f32, FORMAT>(bytes, options)?;
-                Ok((Self::from_f32(float), count))
-            }
+/// Parse float trait for floats wider than 64 bits, using the exact algorithm.
+#[cfg(any(feature = "f80", feature = "f128", feature = "custom-float"))]
+pub trait ParseExactFloat: Float {
+    /// Forward complete parser parameters to the backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete<Fmt: Format>(bytes: &[u8], format: Fmt, options: &Options) -> Result<Self> {
+        check_radix!(format);
+        parse_exact_complete::<Self, _>(bytes, format, options)
+    }
 
-            #[cfg_attr(not(feature = "compact"), inline(always))]
-            fn fast_path_complete<const FORMAT: u128>(bytes: &[u8], options: &Options)
-                -> Result<Self>
-            {
-                Ok(Self::from_f32(fast_path_complete::<f32, FORMAT>(bytes, options)?))
-            }
+    /// Forward partial parser parameters to the backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial<Fmt: Format>(
+        bytes: &[u8],
+        format: Fmt,
+        options: &Options,
+    ) -> Result<(Self, usize)> {
+        check_radix!(format);
+        parse_exact_partial::<Self, _>(bytes, format, options)
+    }
+}
 
-            #[cfg_attr(not(feature = "compact"), inline(always))]
-            fn fast_path_partial<const FORMAT: u128>(bytes: &[u8], options: &Options)
-                -> Result<(Self, usize)>
-            {
-                let (float, count) = fast_path_partial::<f32, FORMAT>(bytes, options)?;
-                Ok((Self::from_f32(float), count))
-            }
-        }
-    )*)
+#[cfg(feature = "f80")]
+impl ParseExactFloat for f80 {
 }
 
-#[cfg(feature = "f16")]
-parse_float_as_f32! { bf16 f16 }
+#[cfg(feature = "f128")]
+impl ParseExactFloat for f128 {
+}
+
+#[cfg(feature = "custom-float")]
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> ParseExactFloat
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+}
+
+/// Parse double-double trait, implemented in terms of the exact algorithm.
+#[cfg(feature = "double-double")]
+pub trait ParseDoubleDouble: Sized {
+    /// Forward complete parser parameters to the backend.
+    fn parse_complete<Fmt: Format>(bytes: &[u8], format: Fmt, options: &Options) -> Result<Self>;
+
+    /// Forward partial parser parameters to the backend.
+    fn parse_partial<Fmt: Format>(
+        bytes: &[u8],
+        format: Fmt,
+        options: &Options,
+    ) -> Result<(Self, usize)>;
+}
+
+#[cfg(feature = "double-double")]
+impl ParseDoubleDouble for DoubleDouble {
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete<Fmt: Format>(bytes: &[u8], format: Fmt, options: &Options) -> Result<Self> {
+        check_radix!(format);
+        parse_double_double_complete(bytes, format, options)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial<Fmt: Format>(
+        bytes: &[u8],
+        format: Fmt,
+        options: &Options,
+    ) -> Result<(Self, usize)> {
+        check_radix!(format);
+        parse_double_double_partial(bytes, format, options)
+    }
+}
+
+/// Parse complex trait, implemented in terms of the parser for each part.
+#[cfg(feature = "complex")]
+pub trait ParseComplex: Sized {
+    /// Forward complete parser parameters to the backend.
+    fn parse_complete<Fmt: Format>(bytes: &[u8], format: Fmt, options: &Options) -> Result<Self>;
+
+    /// Forward partial parser parameters to the backend.
+    fn parse_partial<Fmt: Format>(
+        bytes: &[u8],
+        format: Fmt,
+        options: &Options,
+    ) -> Result<(Self, usize)>;
+}
+
+#[cfg(feature = "complex")]
+impl<F> ParseComplex for Complex<F>
+where
+    F: FromLexicalWithFormat<Options = Options> + Default,
+{
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete<Fmt: Format>(bytes: &[u8], format: Fmt, options: &Options) -> Result<Self> {
+        complex::parse_complete::<F, _>(bytes, format, options)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial<Fmt: Format>(
+        bytes: &[u8],
+        format: Fmt,
+        options: &Options,
+    ) -> Result<(Self, usize)> {
+        complex::parse_partial::<F, _>(bytes, format, options)
+    }
+}
+
+/// Parse rational trait, implemented in terms of the decomposed number.
+#[cfg(feature = "rational")]
+pub trait ParseRational: Sized {
+    /// Forward complete parser parameters to the backend.
+    fn parse_complete<Fmt: Format>(bytes: &[u8], format: Fmt, options: &Options) -> Result<Self>;
+
+    /// Forward partial parser parameters to the backend.
+    fn parse_partial<Fmt: Format>(
+        bytes: &[u8],
+        format: Fmt,
+        options: &Options,
+    ) -> Result<(Self, usize)>;
+}
+
+#[cfg(feature = "rational")]
+impl ParseRational for Rational {
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete<Fmt: Format>(bytes: &[u8], format: Fmt, options: &Options) -> Result<Self> {
+        rational::parse_complete(bytes, format, options)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial<Fmt: Format>(
+        bytes: &[u8],
+        format: Fmt,
+        options: &Options,
+    ) -> Result<(Self, usize)> {
+        rational::parse_partial(bytes, format, options)
+    }
+}
+
+/// Parse decimal float trait, implemented in terms of the decimal algorithm.
+#[cfg(feature = "decimal")]
+pub trait ParseDecimalFloat: DecimalFloat {
+    /// Forward complete parser parameters to the backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete<Fmt: Format>(bytes: &[u8], format: Fmt, options: &Options) -> Result<Self> {
+        check_decimal_radix!(format);
+        parse_decimal_complete::<Self, _>(bytes, format, options)
+    }
+
+    /// Forward partial parser parameters to the backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial<Fmt: Format>(
+        bytes: &[u8],
+        format: Fmt,
+        options: &Options,
+    ) -> Result<(Self, usize)> {
+        check_decimal_radix!(format);
+        parse_decimal_partial::<Self, _>(bytes, format, options)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl ParseDecimalFloat for decimal32 {
+}
+
+#[cfg(feature = "decimal")]
+impl ParseDecimalFloat for decimal64 {
+}
+
+#[cfg(feature = "decimal")]
+impl ParseDecimalFloat for decimal128 {
+}
 
 // PARSE
 // -----
@@ -192,19 +370,16 @@ macro_rules! parse_exponent_sign {
 /// Utility to extract the result and handle any errors from parsing a `Number`.
 macro_rules! parse_number {
     (
-        $format:ident,
         $byte:ident,
         $is_negative:ident,
         $options:ident,
         $parse_normal:ident,
         $parse_special:ident
     ) => {{
-        match $parse_normal::<$format>($byte.clone(), $is_negative, $options) {
+        match $parse_normal($byte.clone(), $is_negative, $options) {
             Ok(n) => n,
             Err(e) => {
-                if let Some(value) =
-                    $parse_special::<_, $format>($byte.clone(), $is_negative, $options)
-                {
+                if let Some(value) = $parse_special($byte.clone(), $is_negative, $options) {
                     return Ok(value);
                 } else {
                     return Err(e);
@@ -226,12 +401,12 @@ macro_rules! to_native {
 }
 
 /// Parse a float from bytes using a complete parser.
-pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
+pub fn parse_complete<F: LemireFloat, Fmt: Format>(
     bytes: &[u8],
+    format: Fmt,
     options: &Options,
 ) -> Result<F> {
-    let format = NumberFormat::<{ FORMAT }> {};
-    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let mut byte = bytes.bytes(format);
     let (is_negative, shift) = parse_mantissa_sign!(byte, format);
     // SAFETY: safe since we shift at most one for a parsed sign byte.
     unsafe { byte.step_by_unchecked(shift) };
@@ -240,35 +415,19 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
     }
 
     // Parse our a small representation of our number.
-    let num = parse_number!(FORMAT, byte, is_negative, options, parse_number, parse_special);
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok(value);
-    }
-    // Now try the moderate path algorithm.
-    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
-
-    // Unable to correctly round the float using the fast or moderate algorithms.
-    // Fallback to a slower, but always correct algorithm. If we have
-    // lossy, we can't be here.
-    if fp.exp < 0 {
-        debug_assert!(!options.lossy());
-        // Undo the invalid extended float biasing.
-        fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
-    }
-
-    // Convert to native float and return result.
-    Ok(to_native!(F, fp, is_negative))
+    let num = parse_number!(byte, is_negative, options, parse_number, parse_special);
+    let rounding = Rounding::new(options.round_mode(), is_negative);
+    let value = to_float::<F, _>(num, format, options.lossy(), rounding);
+    check_range(value, num.mantissa == 0, options)
 }
 
 /// Parse a float using only the fast path as a complete parser.
-pub fn fast_path_complete<F: LemireFloat, const FORMAT: u128>(
+pub fn fast_path_complete<F: LemireFloat, Fmt: Format>(
     bytes: &[u8],
+    format: Fmt,
     options: &Options,
 ) -> Result<F> {
-    let format = NumberFormat::<{ FORMAT }> {};
-    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let mut byte = bytes.bytes(format);
     let (is_negative, shift) = parse_mantissa_sign!(byte, format);
     // SAFETY: safe since we shift at most one for a parsed sign byte.
     unsafe { byte.step_by_unchecked(shift) };
@@ -277,17 +436,17 @@ pub fn fast_path_complete<F: LemireFloat, const FORMAT: u128>(
     }
 
     // Parse our a small representation of our number.
-    let num = parse_number!(FORMAT, byte, is_negative, options, parse_number, parse_special);
-    Ok(num.force_fast_path::<_, FORMAT>())
+    let num = parse_number!(byte, is_negative, options, parse_number, parse_special);
+    Ok(num.force_fast_path(format))
 }
 
 /// Parse a float from bytes using a partial parser.
-pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
+pub fn parse_partial<F: LemireFloat, Fmt: Format>(
     bytes: &[u8],
+    format: Fmt,
     options: &Options,
 ) -> Result<(F, usize)> {
-    let format = NumberFormat::<{ FORMAT }> {};
-    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let mut byte = bytes.bytes(format);
     let (is_negative, shift) = parse_mantissa_sign!(byte, format);
     // SAFETY: safe since we shift at most one for a parsed sign byte.
     unsafe { byte.step_by_unchecked(shift) };
@@ -297,41 +456,128 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
 
     // Parse our a small representation of our number.
     let (num, count) = parse_number!(
-        FORMAT,
         byte,
         is_negative,
         options,
         parse_partial_number,
         parse_partial_special
     );
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok((value, count));
+    let rounding = Rounding::new(options.round_mode(), is_negative);
+    let value = to_float::<F, _>(num, format, options.lossy(), rounding);
+    Ok((check_range(value, num.mantissa == 0, options)?, count))
+}
+
+/// Parse a float using only the fast path as a partial parser.
+pub fn fast_path_partial<F: LemireFloat, Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<(F, usize)> {
+    let mut byte = bytes.bytes(format);
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
     }
-    // Now try the moderate path algorithm.
-    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
 
-    // Unable to correctly round the float using the fast or moderate algorithms.
-    // Fallback to a slower, but always correct algorithm. If we have
-    // lossy, we can't be here.
-    if fp.exp < 0 {
-        debug_assert!(!options.lossy());
-        // Undo the invalid extended float biasing.
-        fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+    // Parse our a small representation of our number.
+    let (num, count) = parse_number!(
+        byte,
+        is_negative,
+        options,
+        parse_partial_number,
+        parse_partial_special
+    );
+    Ok((num.force_fast_path(format), count))
+}
+
+/// Parse a float from bytes using a complete parser, with the status of the conversion.
+pub fn parse_complete_status<F: LemireFloat, Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<(F, Status)> {
+    check_radix!(format);
+    let mut byte = bytes.bytes(format);
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
     }
 
-    // Convert to native float and return result.
-    Ok((to_native!(F, fp, is_negative), count))
+    // Special values are always exact.
+    let num = match parse_number(byte.clone(), is_negative, options) {
+        Ok(num) => num,
+        Err(e) => match parse_special(byte.clone(), is_negative, options) {
+            Some(value) => return Ok((value, Status::Exact)),
+            None => return Err(e),
+        },
+    };
+    let (value, status) = to_float_status::<F, _>(num, format, options);
+    Ok((check_range(value, num.mantissa == 0, options)?, status))
 }
 
-/// Parse a float using only the fast path as a partial parser.
-pub fn fast_path_partial<F: LemireFloat, const FORMAT: u128>(
+/// Parse a float from bytes using a partial parser, with the status of the conversion.
+pub fn parse_partial_status<F: LemireFloat, Fmt: Format>(
     bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<(F, Status, usize)> {
+    check_radix!(format);
+    let mut byte = bytes.bytes(format);
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // Special values are always exact.
+    let (num, count) = match parse_partial_number(byte.clone(), is_negative, options) {
+        Ok(result) => result,
+        Err(e) => match parse_partial_special(byte.clone(), is_negative, options) {
+            Some((value, count)) => return Ok((value, Status::Exact, count)),
+            None => return Err(e),
+        },
+    };
+    let (value, status) = to_float_status::<F, _>(num, format, options);
+    Ok((check_range(value, num.mantissa == 0, options)?, status, count))
+}
+
+/// Parse a float from bytes using a complete parser and the exact algorithm.
+#[cfg(any(feature = "f80", feature = "f128", feature = "custom-float"))]
+pub fn parse_exact_complete<F: Float, Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<F> {
+    let mut byte = bytes.bytes(format);
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // Parse our a small representation of our number.
+    let (num, exponent) =
+        parse_number!(byte, is_negative, options, parse_number_components, parse_special);
+    let rounding = Rounding::new(options.round_mode(), is_negative);
+    let (value, is_overflow) =
+        exact::to_float::<F, _>(&num, format, exponent, options.lossy(), rounding);
+    check_exact_range(value, is_overflow, num.mantissa == 0, options)
+}
+
+/// Parse a float from bytes using a partial parser and the exact algorithm.
+#[cfg(any(feature = "f80", feature = "f128", feature = "custom-float"))]
+pub fn parse_exact_partial<F: Float, Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
     options: &Options,
 ) -> Result<(F, usize)> {
-    let format = NumberFormat::<{ FORMAT }> {};
-    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let mut byte = bytes.bytes(format);
     let (is_negative, shift) = parse_mantissa_sign!(byte, format);
     // SAFETY: safe since we shift at most one for a parsed sign byte.
     unsafe { byte.step_by_unchecked(shift) };
@@ -340,43 +586,341 @@ pub fn fast_path_partial<F: LemireFloat, const FORMAT: u128>(
     }
 
     // Parse our a small representation of our number.
-    let (num, count) = parse_number!(
-        FORMAT,
+    let (num, exponent, count) = parse_number!(
         byte,
         is_negative,
         options,
-        parse_partial_number,
+        parse_partial_components,
         parse_partial_special
     );
-    Ok((num.force_fast_path::<_, FORMAT>(), count))
+    let rounding = Rounding::new(options.round_mode(), is_negative);
+    let (value, is_overflow) =
+        exact::to_float::<F, _>(&num, format, exponent, options.lossy(), rounding);
+    Ok((check_exact_range(value, is_overflow, num.mantissa == 0, options)?, count))
+}
+
+/// Parse a double-double from bytes using a complete parser.
+#[cfg(feature = "double-double")]
+pub fn parse_double_double_complete<Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<DoubleDouble> {
+    let mut byte = bytes.bytes(format);
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // Special values have a low float of 0.
+    let (num, exponent) = match parse_number_components(byte.clone(), is_negative, options)
+    {
+        Ok(result) => result,
+        Err(e) => match parse_special::<f64, _>(byte.clone(), is_negative, options) {
+            Some(value) => return Ok(DoubleDouble::from(value)),
+            None => return Err(e),
+        },
+    };
+    let (hi, lo) =
+        exact::to_double_double(&num, format, exponent, options.lossy(), options.round_mode());
+    let hi = check_range(hi, num.mantissa == 0 || lo != 0.0, options)?;
+    Ok(DoubleDouble::new(hi, lo))
+}
+
+/// Parse a double-double from bytes using a partial parser.
+#[cfg(feature = "double-double")]
+pub fn parse_double_double_partial<Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<(DoubleDouble, usize)> {
+    let mut byte = bytes.bytes(format);
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // Special values have a low float of 0.
+    let (num, exponent, count) =
+        match parse_partial_components(byte.clone(), is_negative, options) {
+            Ok(result) => result,
+            Err(e) => match parse_partial_special::<f64, _>(byte.clone(), is_negative, options)
+            {
+                Some((value, count)) => return Ok((DoubleDouble::from(value), count)),
+                None => return Err(e),
+            },
+        };
+    let (hi, lo) =
+        exact::to_double_double(&num, format, exponent, options.lossy(), options.round_mode());
+    let hi = check_range(hi, num.mantissa == 0 || lo != 0.0, options)?;
+    Ok((DoubleDouble::new(hi, lo), count))
+}
+
+/// Parse a decimal float from bytes using a complete parser.
+#[cfg(feature = "decimal")]
+pub fn parse_decimal_complete<D: DecimalFloat, Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<D> {
+    let mut byte = bytes.bytes(format);
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // Parse our a small representation of our number.
+    let (num, exponent) = parse_number!(
+        byte,
+        is_negative,
+        options,
+        parse_number_components,
+        parse_decimal_special
+    );
+    let rounding = Rounding::new(options.round_mode(), is_negative);
+    let value = decimal::to_decimal::<D, _>(&num, format, exponent, rounding);
+    decimal::check_range(value, num.mantissa == 0, options)
+}
+
+/// Parse a decimal float from bytes using a partial parser.
+#[cfg(feature = "decimal")]
+pub fn parse_decimal_partial<D: DecimalFloat, Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<(D, usize)> {
+    let mut byte = bytes.bytes(format);
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // Parse our a small representation of our number.
+    let (num, exponent, count) = parse_number!(
+        byte,
+        is_negative,
+        options,
+        parse_partial_components,
+        parse_partial_decimal_special
+    );
+    let rounding = Rounding::new(options.round_mode(), is_negative);
+    let value = decimal::to_decimal::<D, _>(&num, format, exponent, rounding);
+    Ok((decimal::check_range(value, num.mantissa == 0, options)?, count))
+}
+
+/// Check if the float overflowed to infinity or underflowed to zero, if those are errors.
+///
+/// Zero and special values are never out of range. The error index is
+/// the start of the number, since the value as a whole is out of range:
+/// either the significant digits or the exponent can cause it.
+#[inline(always)]
+pub fn check_range<F: Float>(value: F, is_special: bool, options: &Options) -> Result<F> {
+    if !options.error_on_out_of_range() || is_special {
+        Ok(value)
+    } else if value.is_inf() {
+        Err(Error::Overflow(0))
+    } else if value == F::ZERO {
+        Err(Error::Underflow(0))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Check if the float from the exact algorithm is out of range, if that is an error.
+///
+/// Floats without infinity saturate to the largest finite float, so the
+/// overflow is reported by the algorithm.
+#[cfg(any(feature = "f80", feature = "f128", feature = "custom-float"))]
+#[inline(always)]
+fn check_exact_range<F: Float>(
+    value: F,
+    is_overflow: bool,
+    is_zero: bool,
+    options: &Options,
+) -> Result<F> {
+    if is_overflow && options.error_on_out_of_range() {
+        Err(Error::Overflow(0))
+    } else {
+        check_range(value, is_zero, options)
+    }
 }
 
 // PATHS
 // -----
 
+/// Convert the parsed number to a native float, using the fastest valid algorithm.
+#[inline(always)]
+pub fn to_float<F: LemireFloat, Fmt: Format>(
+    num: Number,
+    format: Fmt,
+    lossy: bool,
+    rounding: Rounding,
+) -> F {
+    // Try the fast-path algorithm, which rounds to nearest, tie even.
+    if rounding == Rounding::NearestEven || num.is_exact_fast_path::<F, _>(format) {
+        if let Some(value) = num.try_fast_path(format) {
+            return value;
+        }
+    }
+    // Now try the moderate path algorithm.
+    let mut fp = moderate_path::<F, _>(&num, format, lossy, rounding);
+
+    // Unable to correctly round the float using the fast or moderate algorithms.
+    // Fallback to a slower, but always correct algorithm. If we have
+    // lossy, we can't be here.
+    if fp.exp < 0 {
+        debug_assert!(!lossy);
+        // Undo the invalid extended float biasing.
+        fp.exp -= shared::INVALID_FP;
+        fp = slow_path::<F, _>(num, format, fp, rounding);
+    }
+    // The algorithms overflow to infinity and underflow to 0.
+    shared::round_limits::<F>(&mut fp, rounding, num.mantissa == 0);
+
+    // Convert to native float and return result.
+    let is_negative = num.is_negative;
+    to_native!(F, fp, is_negative)
+}
+
+/// Convert the parsed number to a native float, and determine if it was rounded.
+///
+/// The float is converted once, and then compared to the significant
+/// digits. This only requires big integers for numbers with more
+/// significant digits than fit in the mantissa.
+#[inline]
+pub fn to_float_status<F: LemireFloat, Fmt: Format>(
+    num: Number,
+    format: Fmt,
+    options: &Options,
+) -> (F, Status) {
+    let rounding = Rounding::new(options.round_mode(), num.is_negative);
+    let value = to_float::<F, _>(num, format, options.lossy(), rounding);
+    let status = if num.mantissa == 0 || is_exact::<F, _>(num, format, value) {
+        Status::Exact
+    } else if value.is_inf() {
+        Status::Overflow
+    } else if value == F::ZERO {
+        Status::Underflow
+    } else if value.is_denormal() {
+        Status::Subnormal
+    } else {
+        Status::Inexact
+    };
+    (value, status)
+}
+
+/// Determine if the float is exactly equal to the parsed number.
+#[inline]
+fn is_exact<F: LemireFloat, Fmt: Format>(num: Number, format: Fmt, value: F) -> bool {
+    if value.is_inf() || value == F::ZERO {
+        return false;
+    }
+    if num.many_digits && is_truncated(&num, format) {
+        // The truncated mantissa has more significant bits than any
+        // float for powers-of-two, so compare the digits otherwise.
+        #[cfg(feature = "power-of-two")]
+        if is_power_two!(format.mantissa_radix()) {
+            return false;
+        }
+        return compare_float::<F, _>(num, format, value) == cmp::Ordering::Equal;
+    }
+
+    // Get the odd part and the binary exponent of `mantissa * base^exponent`,
+    // which is an integer if the odd factor of the power divides it.
+    let base = format.exponent_base();
+    let shift = base.trailing_zeros();
+    let odd = (base >> shift) as u64;
+    // Any odd factor of the power overflows for exponents above 64.
+    let power = odd.checked_pow(num.exponent.unsigned_abs().min(64) as u32);
+    // Remove the factors of two first, so only an odd part that
+    // can't fit in the float's mantissa overflows.
+    let mantissa_zeros = num.mantissa.trailing_zeros();
+    let odd_mantissa = num.mantissa >> mantissa_zeros;
+    let mantissa = match power {
+        Some(power) if num.exponent < 0 => match odd_mantissa % power {
+            0 => odd_mantissa / power,
+            _ => return false,
+        },
+        Some(power) => match odd_mantissa.checked_mul(power) {
+            Some(mantissa) => mantissa,
+            None => return false,
+        },
+        None => return false,
+    };
+    let exponent = num.exponent * shift as i64 + mantissa_zeros as i64;
+
+    // Compare it to the odd part and the binary exponent of the float.
+    let float_mantissa = value.mantissa().as_u64();
+    let float_exponent = value.exponent() as i64;
+    let (zeros, float_zeros) = (mantissa.trailing_zeros(), float_mantissa.trailing_zeros());
+    mantissa >> zeros == float_mantissa >> float_zeros
+        && exponent + zeros as i64 == float_exponent + float_zeros as i64
+}
+
+/// Determine if any non-zero significant digits were truncated from the mantissa.
+#[inline]
+fn is_truncated<Fmt: Format>(num: &Number, format: Fmt) -> bool {
+    let mut step = u64_step(format.radix());
+    let mut is_truncated = |&c: &u8| match step {
+        0 => c != b'0',
+        _ => {
+            step -= 1;
+            false
+        },
+    };
+
+    // Skip leading zeros, like when parsing the mantissa.
+    let mut integer = num.integer.bytes(format);
+    let mut integer_iter = integer.integer_iter();
+    integer_iter.skip_zeros();
+    let is_zero = integer_iter.is_done();
+    if integer_iter.any(&mut is_truncated) {
+        return true;
+    }
+    if let Some(fraction) = num.fraction {
+        let mut fraction = fraction.bytes(format);
+        let mut fraction_iter = fraction.fraction_iter();
+        if is_zero {
+            fraction_iter.skip_zeros();
+        }
+        return fraction_iter.any(is_truncated);
+    }
+    false
+}
+
 /// Wrapper for different moderate-path algorithms.
 /// A return exponent of `-1` indicates an invalid value.
 #[inline]
-pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
+#[cfg_attr(not(feature = "power-of-two"), allow(unused_variables))]
+pub fn moderate_path<F: LemireFloat, Fmt: Format>(
     num: &Number,
+    format: Fmt,
     lossy: bool,
+    rounding: Rounding,
 ) -> ExtendedFloat80 {
     #[cfg(feature = "compact")]
     {
         #[cfg(feature = "power-of-two")]
         {
-            let format = NumberFormat::<{ FORMAT }> {};
             if is_power_two!(format.mantissa_radix()) {
                 // Implement the power-of-two backends.
-                binary::<F, FORMAT>(num, lossy)
+                binary::<F, _>(num, format, lossy, rounding)
             } else {
-                bellerophon::<F, FORMAT>(num, lossy)
+                bellerophon::<F, _>(num, format, lossy, rounding)
             }
         }
 
         #[cfg(not(feature = "power-of-two"))]
         {
-            bellerophon::<F, FORMAT>(num, lossy)
+            bellerophon::<F, _>(num, format, lossy, rounding)
         }
     }
 
@@ -384,34 +928,32 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
     {
         #[cfg(feature = "radix")]
         {
-            let format = NumberFormat::<{ FORMAT }> {};
             let radix = format.mantissa_radix();
             if radix == 10 {
-                lemire::<F>(num, lossy)
+                lemire::<F>(num, lossy, rounding)
             } else if is_power_two!(radix) {
                 // Implement the power-of-two backends.
-                binary::<F, FORMAT>(num, lossy)
+                binary::<F, _>(num, format, lossy, rounding)
             } else {
-                bellerophon::<F, FORMAT>(num, lossy)
+                bellerophon::<F, _>(num, format, lossy, rounding)
             }
         }
 
         #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
         {
-            let format = NumberFormat::<{ FORMAT }> {};
             let radix = format.mantissa_radix();
             debug_assert!(matches!(radix, 2 | 4 | 8 | 10 | 16 | 32));
             if radix == 10 {
-                lemire::<F>(num, lossy)
+                lemire::<F>(num, lossy, rounding)
             } else {
                 // Implement the power-of-two backends.
-                binary::<F, FORMAT>(num, lossy)
+                binary::<F, _>(num, format, lossy, rounding)
             }
         }
 
         #[cfg(not(feature = "power-of-two"))]
         {
-            lemire::<F>(num, lossy)
+            lemire::<F>(num, lossy, rounding)
         }
     }
 }
@@ -419,22 +961,23 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
 /// Invoke the slow path.
 /// At this point, the float string has already been validated.
 #[inline]
-pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
+pub fn slow_path<F: LemireFloat, Fmt: Format>(
     num: Number,
+    format: Fmt,
     fp: ExtendedFloat80,
+    rounding: Rounding,
 ) -> ExtendedFloat80 {
     #[cfg(not(feature = "power-of-two"))]
     {
-        slow_radix::<F, FORMAT>(num, fp)
+        slow_radix::<F, _>(num, format, fp, rounding)
     }
 
     #[cfg(feature = "power-of-two")]
     {
-        let format = NumberFormat::<{ FORMAT }> {};
         if is_power_two!(format.mantissa_radix()) {
-            slow_binary::<F, FORMAT>(num)
+            slow_binary::<F, _>(num, format, rounding)
         } else {
-            slow_radix::<F, FORMAT>(num, fp)
+            slow_radix::<F, _>(num, format, fp, rounding)
         }
     }
 }
@@ -447,12 +990,48 @@ pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
 /// This creates a representation of the float as the
 /// significant digits and the decimal exponent.
 #[inline]
-#[allow(clippy::collapsible_if)]
-pub fn parse_partial_number<'a, const FORMAT: u128>(
-    mut byte: Bytes<'a, FORMAT>,
+pub fn parse_partial_number<'a, Fmt: Format>(
+    byte: Bytes<'a, Fmt>,
     is_negative: bool,
     options: &Options,
 ) -> Result<(Number<'a>, usize)> {
+    let (number, _, count) = parse_partial_components(byte, is_negative, options)?;
+    Ok((number, count))
+}
+
+/// Parse the sign and components of a partial, non-special number.
+///
+/// This does not convert the number to a float, and returns the
+/// number, the explicit exponent, and the number of processed bytes.
+#[inline]
+pub fn parse_partial_decomposed<'a, Fmt: Format>(
+    bytes: &'a [u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<(Number<'a>, i64, usize)> {
+    check_radix!(format);
+    let mut byte = bytes.bytes(format);
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    parse_partial_components(byte, is_negative, options)
+}
+
+/// Parse a partial, non-special floating point number.
+///
+/// This returns the number, the explicit exponent, and the number
+/// of processed bytes.
+#[inline(always)]
+#[allow(clippy::collapsible_if)]
+pub fn parse_partial_components<'a, Fmt: Format>(
+    mut byte: Bytes<'a, Fmt>,
+    is_negative: bool,
+    options: &Options,
+) -> Result<(Number<'a>, i64, usize)> {
     //  NOTE:
     //      There are no satisfactory optimizations to reduce the number
     //      of multiplications for very long input strings, but this will
@@ -480,7 +1059,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     //      be made.
 
     // Config options
-    let format = NumberFormat::<{ FORMAT }> {};
+    let format = byte.format();
     let decimal_point = options.decimal_point();
     let exponent_character = options.exponent();
     debug_assert!(format.is_valid());
@@ -519,8 +1098,8 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     let mut mantissa = 0_u64;
     let start = byte.clone();
     #[cfg(not(feature = "compact"))]
-    parse_8digits::<_, FORMAT>(byte.integer_iter(), &mut mantissa);
-    parse_digits::<_, _, FORMAT>(byte.integer_iter(), |digit| {
+    parse_8digits(byte.integer_iter(), format, &mut mantissa);
+    parse_digits(byte.integer_iter(), format, |digit| {
         mantissa = mantissa.wrapping_mul(format.radix() as _).wrapping_add(digit as _);
     });
     let mut n_digits = byte.current_count() - start.current_count();
@@ -529,9 +1108,11 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     }
 
     // Store the integer digits for slow-path algorithms.
-    // SAFETY: safe, since `n_digits <= start.as_slice().len()`.
-    debug_assert!(n_digits <= start.as_slice().len());
-    let integer_digits = unsafe { start.as_slice().get_unchecked(..n_digits) };
+    // This must use the cursor, since the digit count skips digit separators.
+    let n_bytes = byte.cursor() - start.cursor();
+    // SAFETY: safe, since `n_bytes <= start.as_slice().len()`.
+    debug_assert!(n_bytes <= start.as_slice().len());
+    let integer_digits = unsafe { start.as_slice().get_unchecked(..n_bytes) };
 
     // Check if integer leading zeros are disabled.
     if cfg!(feature = "format") && !is_prefix && format.no_float_leading_zeros() {
@@ -553,16 +1134,17 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
         unsafe { byte.step_unchecked() };
         let before = byte.clone();
         #[cfg(not(feature = "compact"))]
-        parse_8digits::<_, FORMAT>(byte.fraction_iter(), &mut mantissa);
-        parse_digits::<_, _, FORMAT>(byte.fraction_iter(), |digit| {
+        parse_8digits(byte.fraction_iter(), format, &mut mantissa);
+        parse_digits(byte.fraction_iter(), format, |digit| {
             mantissa = mantissa.wrapping_mul(format.radix() as _).wrapping_add(digit as _);
         });
         n_after_dot = byte.current_count() - before.current_count();
 
         // Store the fraction digits for slow-path algorithms.
-        // SAFETY: safe, since `n_after_dot <= before.as_slice().len()`.
-        debug_assert!(n_after_dot <= before.as_slice().len());
-        fraction_digits = Some(unsafe { before.as_slice().get_unchecked(..n_after_dot) });
+        let n_bytes = byte.cursor() - before.cursor();
+        // SAFETY: safe, since `n_bytes <= before.as_slice().len()`.
+        debug_assert!(n_bytes <= before.as_slice().len());
+        fraction_digits = Some(unsafe { before.as_slice().get_unchecked(..n_bytes) });
 
         // Calculate the implicit exponent: the number of digits after the dot.
         implicit_exponent = -(n_after_dot as i64);
@@ -613,7 +1195,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
         }
 
         let before = byte.current_count();
-        parse_digits::<_, _, FORMAT>(byte.exponent_iter(), |digit| {
+        parse_digits(byte.exponent_iter(), format, |digit| {
             if explicit_exponent < 0x10000000 {
                 explicit_exponent *= format.radix() as i64;
                 explicit_exponent += digit as i64;
@@ -668,6 +1250,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
                 integer: integer_digits,
                 fraction: fraction_digits,
             },
+            explicit_exponent,
             end,
         ));
     }
@@ -699,11 +1282,11 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
         // Have more than 19 significant digits, so we overflowed.
         many_digits = true;
         mantissa = 0;
-        let mut integer = integer_digits.bytes::<{ FORMAT }>();
+        let mut integer = integer_digits.bytes(format);
         // Skip leading zeros, so we can use the step properly.
         let mut integer_iter = integer.integer_iter();
         integer_iter.skip_zeros();
-        parse_u64_digits::<_, FORMAT>(integer_iter, &mut mantissa, &mut step);
+        parse_u64_digits(integer_iter, format, &mut mantissa, &mut step);
         implicit_exponent = if step == 0 {
             // Filled our mantissa with just the integer.
             int_end - integer.current_count() as i64
@@ -713,13 +1296,13 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
             // but parsing only the integral digits produced less
             // than 19 digits. That means we must have a decimal
             // point, and at least 1 fractional digit.
-            let mut fraction = fraction_digits.unwrap().bytes::<{ FORMAT }>();
+            let mut fraction = fraction_digits.unwrap().bytes(format);
             let mut fraction_iter = fraction.fraction_iter();
             // Skip leading zeros, so we can use the step properly.
             if mantissa == 0 {
                 fraction_iter.skip_zeros();
             }
-            parse_u64_digits::<_, FORMAT>(fraction_iter, &mut mantissa, &mut step);
+            parse_u64_digits(fraction_iter, format, &mut mantissa, &mut step);
             -(fraction.current_count() as i64)
         };
         if format.mantissa_radix() == format.exponent_base() {
@@ -741,19 +1324,20 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
             integer: integer_digits,
             fraction: fraction_digits,
         },
+        explicit_exponent,
         end,
     ))
 }
 
 /// Try to parse a non-special floating point number.
 #[inline]
-pub fn parse_number<'a, const FORMAT: u128>(
-    byte: Bytes<'a, FORMAT>,
+pub fn parse_number<'a, Fmt: Format>(
+    byte: Bytes<'a, Fmt>,
     is_negative: bool,
     options: &Options,
 ) -> Result<Number<'a>> {
     let length = byte.length();
-    let (float, count) = parse_partial_number::<FORMAT>(byte, is_negative, options)?;
+    let (float, count) = parse_partial_number(byte, is_negative, options)?;
     if count == length {
         Ok(float)
     } else {
@@ -761,17 +1345,32 @@ pub fn parse_number<'a, const FORMAT: u128>(
     }
 }
 
+/// Try to parse a non-special floating point number, with the explicit exponent.
+#[inline]
+pub fn parse_number_components<'a, Fmt: Format>(
+    byte: Bytes<'a, Fmt>,
+    is_negative: bool,
+    options: &Options,
+) -> Result<(Number<'a>, i64)> {
+    let length = byte.length();
+    let (float, exponent, count) = parse_partial_components(byte, is_negative, options)?;
+    if count == length {
+        Ok((float, exponent))
+    } else {
+        Err(Error::InvalidDigit(count))
+    }
+}
+
 // DIGITS
 // ------
 
 /// Iteratively parse and consume digits from bytes.
 #[inline]
-pub fn parse_digits<'a, Iter, Cb, const FORMAT: u128>(mut iter: Iter, mut cb: Cb)
+pub fn parse_digits<'a, Iter, Cb, Fmt: Format>(mut iter: Iter, format: Fmt, mut cb: Cb)
 where
     Iter: BytesIter<'a>,
     Cb: FnMut(u32),
 {
-    let format = NumberFormat::<{ FORMAT }> {};
     let radix = format.radix();
     while let Some(&c) = iter.peek() {
         match char_to_digit_const(c, radix) {
@@ -786,11 +1385,10 @@ where
 /// Iteratively parse and consume digits in intervals of 8.
 #[inline]
 #[cfg(not(feature = "compact"))]
-pub fn parse_8digits<'a, Iter, const FORMAT: u128>(mut iter: Iter, mantissa: &mut u64)
+pub fn parse_8digits<'a, Iter, Fmt: Format>(mut iter: Iter, format: Fmt, mantissa: &mut u64)
 where
     Iter: BytesIter<'a>,
 {
-    let format = NumberFormat::<{ FORMAT }> {};
     let radix: u64 = format.radix() as u64;
     if can_try_parse_8digits!(iter, radix) {
         let radix2 = radix.wrapping_mul(radix);
@@ -798,7 +1396,7 @@ where
         let radix8 = radix4.wrapping_mul(radix4);
         // Can do up to 2 iterations without overflowing, however, for large
         // inputs, this is much faster than any other alternative.
-        while let Some(v) = algorithm::try_parse_8digits::<u64, _, FORMAT>(&mut iter) {
+        while let Some(v) = algorithm::try_parse_8digits::<u64, _, _>(&mut iter, format) {
             *mantissa = mantissa.wrapping_mul(radix8).wrapping_add(v);
         }
     }
@@ -810,14 +1408,14 @@ where
 ///
 /// There must be at least `step` digits left in iterator.
 #[inline]
-pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
+pub fn parse_u64_digits<'a, Iter, Fmt: Format>(
     mut iter: Iter,
+    format: Fmt,
     mantissa: &mut u64,
     step: &mut usize,
 ) where
     Iter: BytesIter<'a>,
 {
-    let format = NumberFormat::<{ FORMAT }> {};
     let radix = format.radix() as u64;
 
     // Try to parse 8 digits at a time, if we can.
@@ -827,7 +1425,7 @@ pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
         let radix4 = radix2.wrapping_mul(radix2);
         let radix8 = radix4.wrapping_mul(radix4);
         while *step > 8 {
-            if let Some(v) = algorithm::try_parse_8digits::<u64, _, FORMAT>(&mut iter) {
+            if let Some(v) = algorithm::try_parse_8digits::<u64, _, _>(&mut iter, format) {
                 *mantissa = mantissa.wrapping_mul(radix8).wrapping_add(v);
                 *step -= 8;
             } else {
@@ -856,8 +1454,8 @@ pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
 /// Determine if the input data matches the special string.
 /// If there's no match, returns 0. Otherwise, returns the byte's cursor.
 #[inline]
-pub fn is_special_eq<const FORMAT: u128>(mut byte: Bytes<FORMAT>, string: &'static [u8]) -> usize {
-    let format = NumberFormat::<{ FORMAT }> {};
+pub fn is_special_eq<Fmt: Format>(mut byte: Bytes<Fmt>, string: &'static [u8]) -> usize {
+    let format = byte.format();
     if cfg!(feature = "format") && format.case_sensitive_special() {
         if shared::starts_with(byte.special_iter(), string.iter()) {
             // Trim the iterator afterwards.
@@ -872,16 +1470,15 @@ pub fn is_special_eq<const FORMAT: u128>(mut byte: Bytes<FORMAT>, string: &'stat
     0
 }
 
-/// Parse a positive representation of a special, non-finite float.
+/// Determine if the input data matches a special, non-finite float.
+///
+/// Returns if the special value is NaN, and the number of processed bytes.
 #[inline]
-pub fn parse_positive_special<F, const FORMAT: u128>(
-    byte: Bytes<FORMAT>,
+pub fn match_positive_special<Fmt: Format>(
+    byte: Bytes<Fmt>,
     options: &Options,
-) -> Option<(F, usize)>
-where
-    F: LemireFloat,
-{
-    let format = NumberFormat::<{ FORMAT }> {};
+) -> Option<(bool, usize)> {
+    let format = byte.format();
     if cfg!(feature = "format") && format.no_special() {
         return None;
     }
@@ -890,25 +1487,25 @@ where
     let length = byte.length() - cursor;
     if let Some(nan_string) = options.nan_string() {
         if length >= nan_string.len() {
-            let count = is_special_eq::<FORMAT>(byte.clone(), nan_string);
+            let count = is_special_eq(byte.clone(), nan_string);
             if count != 0 {
-                return Some((F::NAN, count));
+                return Some((true, count));
             }
         }
     }
     if let Some(infinity_string) = options.infinity_string() {
         if length >= infinity_string.len() {
-            let count = is_special_eq::<FORMAT>(byte.clone(), infinity_string);
+            let count = is_special_eq(byte.clone(), infinity_string);
             if count != 0 {
-                return Some((F::INFINITY, count));
+                return Some((false, count));
             }
         }
     }
     if let Some(inf_string) = options.inf_string() {
         if length >= inf_string.len() {
-            let count = is_special_eq::<FORMAT>(byte.clone(), inf_string);
+            let count = is_special_eq(byte.clone(), inf_string);
             if count != 0 {
-                return Some((F::INFINITY, count));
+                return Some((false, count));
             }
         }
     }
@@ -916,17 +1513,33 @@ where
     None
 }
 
+/// Parse a positive representation of a special, non-finite float.
+#[inline]
+pub fn parse_positive_special<F, Fmt: Format>(
+    byte: Bytes<Fmt>,
+    options: &Options,
+) -> Option<(F, usize)>
+where
+    F: Float,
+{
+    let (is_nan, count) = match_positive_special(byte, options)?;
+    match is_nan {
+        true => Some((F::NAN, count)),
+        false => Some((F::INFINITY, count)),
+    }
+}
+
 /// Parse a partial representation of a special, non-finite float.
 #[inline]
-pub fn parse_partial_special<F, const FORMAT: u128>(
-    byte: Bytes<FORMAT>,
+pub fn parse_partial_special<F, Fmt: Format>(
+    byte: Bytes<Fmt>,
     is_negative: bool,
     options: &Options,
 ) -> Option<(F, usize)>
 where
-    F: LemireFloat,
+    F: Float,
 {
-    let (mut float, count) = parse_positive_special::<F, FORMAT>(byte, options)?;
+    let (mut float, count) = parse_positive_special::<F, _>(byte, options)?;
     if is_negative {
         float = -float;
     }
@@ -935,16 +1548,16 @@ where
 
 /// Try to parse a special, non-finite float.
 #[inline]
-pub fn parse_special<F, const FORMAT: u128>(
-    byte: Bytes<FORMAT>,
+pub fn parse_special<F, Fmt: Format>(
+    byte: Bytes<Fmt>,
     is_negative: bool,
     options: &Options,
 ) -> Option<F>
 where
-    F: LemireFloat,
+    F: Float,
 {
     let length = byte.length();
-    if let Some((float, count)) = parse_partial_special::<F, FORMAT>(byte, is_negative, options) {
+    if let Some((float, count)) = parse_partial_special::<F, _>(byte, is_negative, options) {
         if count == length {
             return Some(float);
         }
@@ -0,0 +1,192 @@
+//! Parse exact rationals on top of the float grammar.
+//!
+//! Numbers, such as `0.1` or `1.5e-3`, are parsed to their exact value in
+//! lowest terms, so `0.1` is `1/10`. Rational literals, such as `3/4`, are
+//! parsed as an integer numerator and denominator, without a decimal
+//! point or exponent, and reduced to lowest terms. Both follow the number
+//! format, including the sign, digit separators, and base prefixes. The
+//! sign of a literal is the sign of the numerator, so the denominator must
+//! not have a sign.
+//!
+//! Special values, such as NaN and infinity, are not rationals and return
+//! an error.
+
+#![cfg(feature = "rational")]
+#![doc(hidden)]
+
+use crate::decompose::{decompose_partial_with_format, DecomposedNumber};
+use crate::options::Options;
+use crate::stream::is_token;
+use lexical_parse_integer::bignum::parse_limbs_vec;
+use lexical_util::error::Error;
+use lexical_util::format::Format;
+use lexical_util::rational::Rational;
+use lexical_util::result::Result;
+
+/// The largest magnitude of the exponent of a number.
+///
+/// Larger exponents return an error, rather than allocating huge integers.
+pub const MAX_EXPONENT: i64 = 0x10000;
+
+/// Determine if a byte may be part of a rational.
+#[inline]
+pub fn is_rational_token<Fmt: Format>(c: u8, format: Fmt, options: &Options) -> bool {
+    is_token(c, format, options) || c == b'/'
+}
+
+/// Offset the index of an error by the start of the number.
+#[inline]
+fn offset(mut error: Error, index: usize) -> Error {
+    if let Some(position) = error.index_mut() {
+        *position += index;
+    }
+    error
+}
+
+/// Get if the number is an integer, without a decimal point or exponent.
+///
+/// The count is the number of bytes processed in the number, from `bytes`.
+#[inline]
+fn is_integer(number: &DecomposedNumber, bytes: &[u8], count: usize) -> bool {
+    number.fraction().is_none() && integer_end(number, bytes) == count
+}
+
+/// Get the index past the integer digits of the number.
+#[inline]
+fn integer_end(number: &DecomposedNumber, bytes: &[u8]) -> usize {
+    let integer = number.integer();
+    integer.as_ptr() as usize - bytes.as_ptr() as usize + integer.len()
+}
+
+/// Parse the integer digits of a number as limbs.
+#[inline]
+fn parse_integer<Fmt: Format>(number: &DecomposedNumber, format: Fmt) -> Vec<u64> {
+    let digits: Vec<u8> = number.integer_digits().collect();
+    match digits.is_empty() {
+        true => Vec::new(),
+        // The digits are valid, so this can only fail if the radix is invalid.
+        false => parse_limbs_vec(&digits, format.mantissa_radix()).expect("digits must be valid"),
+    }
+}
+
+/// Parse the digits as limbs, after removing any trailing zeros.
+///
+/// Returns the limbs and the number of trailing zeros.
+fn parse_digits(digits: impl Iterator<Item = u8>, radix: u32) -> (Vec<u64>, usize) {
+    let mut digits: Vec<u8> = digits.collect();
+    let zeros = digits.iter().rev().take_while(|&&c| c == b'0').count();
+    digits.truncate(digits.len() - zeros);
+    match digits.is_empty() {
+        true => (Vec::new(), zeros),
+        // The digits are valid, so this can only fail if the radix is invalid.
+        false => (parse_limbs_vec(&digits, radix).expect("digits must be valid"), zeros),
+    }
+}
+
+/// Convert the components of a number to an exact rational.
+///
+/// Out-of-range errors point to the start of the number, like for floats.
+fn to_rational<Fmt: Format>(number: &DecomposedNumber, format: Fmt) -> Result<Rational> {
+    let radix = format.mantissa_radix();
+    let digits = number.integer_digits().chain(number.fraction_digits());
+    let (limbs, zeros) = parse_digits(digits, radix);
+
+    // Zero is never out of range, for any exponent.
+    let exponent = number.exponent();
+    if limbs.is_empty() {
+        return Ok(Rational::zero());
+    } else if exponent > MAX_EXPONENT {
+        return Err(Error::Overflow(0));
+    } else if exponent < -MAX_EXPONENT {
+        return Err(Error::Underflow(0));
+    }
+
+    // The value is `digits * radix^(zeros - fraction) * base^exponent`.
+    let fraction = number.fraction_digits().count();
+    let powers = [(radix, zeros as i64 - fraction as i64), (format.exponent_base(), exponent)];
+    Ok(Rational::from_powers(number.is_negative(), limbs, &powers))
+}
+
+/// Parse the denominator of a rational literal, starting at the index.
+///
+/// Returns the denominator and the index past the denominator.
+fn parse_denominator<Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    index: usize,
+    options: &Options,
+) -> Result<(Vec<u64>, usize)> {
+    if matches!(bytes.get(index), Some(b'+') | Some(b'-')) {
+        return Err(Error::InvalidDigit(index));
+    }
+    let (number, count) = decompose_partial_with_format(&bytes[index..], format, options)
+        .map_err(|e| offset(e, index))?;
+    if !is_integer(&number, &bytes[index..], count) {
+        return Err(Error::InvalidDigit(integer_end(&number, bytes)));
+    }
+    let limbs = parse_integer(&number, format);
+    match limbs.is_empty() {
+        true => Err(Error::Zero(index)),
+        false => Ok((limbs, index + count)),
+    }
+}
+
+/// Parse the start of a rational, stopping before any `/`.
+///
+/// Returns the components, the number of processed bytes, and if the
+/// number may be the numerator of a rational literal.
+#[inline]
+fn parse_number<'a, Fmt: Format>(
+    bytes: &'a [u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<(DecomposedNumber<'a>, usize, bool)> {
+    let (number, count) = decompose_partial_with_format(bytes, format, options)?;
+    let is_literal = bytes.get(count) == Some(&b'/') && is_integer(&number, bytes, count);
+    Ok((number, count, is_literal))
+}
+
+/// Parse a rational from bytes using a complete parser.
+pub fn parse_complete<Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<Rational> {
+    let (number, count, is_literal) = parse_number(bytes, format, options)?;
+    if is_literal {
+        let (denominator, index) = parse_denominator(bytes, format, count + 1, options)?;
+        if index != bytes.len() {
+            return Err(Error::InvalidDigit(index));
+        }
+        let numerator = parse_integer(&number, format);
+        Ok(Rational::new(number.is_negative(), numerator, denominator))
+    } else if count != bytes.len() {
+        Err(Error::InvalidDigit(count))
+    } else {
+        to_rational(&number, format)
+    }
+}
+
+/// Parse a rational from bytes using a partial parser.
+///
+/// The denominator of a rational literal is only consumed if it is
+/// valid, so `3/x` parses the numerator and stops before the `/`. A zero
+/// denominator is always an error.
+pub fn parse_partial<Fmt: Format>(
+    bytes: &[u8],
+    format: Fmt,
+    options: &Options,
+) -> Result<(Rational, usize)> {
+    let (number, count, is_literal) = parse_number(bytes, format, options)?;
+    if is_literal {
+        match parse_denominator(bytes, format, count + 1, options) {
+            Ok((denominator, index)) => {
+                let numerator = parse_integer(&number, format);
+                return Ok((Rational::new(number.is_negative(), numerator, denominator), index));
+            },
+            Err(error @ Error::Zero(_)) => return Err(error),
+            Err(_) => (),
+        }
+    }
+    Ok((to_rational(&number, format)?, count))
+}
@@ -4,8 +4,9 @@
 
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::mask::{lower_n_halfway, lower_n_mask};
+use crate::options::RoundMode;
 #[cfg(feature = "power-of-two")]
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_util::num::AsPrimitive;
 
 // 8 DIGIT
@@ -36,8 +37,7 @@ pub fn calculate_shift<F: RawFloat>(power2: i32) -> i32 {
 /// Calculate the biased, binary exponent from the mantissa shift and exponent.
 #[inline(always)]
 #[cfg(feature = "power-of-two")]
-pub fn calculate_power2<F: RawFloat, const FORMAT: u128>(exponent: i64, ctlz: u32) -> i32 {
-    let format = NumberFormat::<{ FORMAT }> {};
+pub fn calculate_power2<F: RawFloat, Fmt: Format>(exponent: i64, format: Fmt, ctlz: u32) -> i32 {
     exponent as i32 * log2(format.exponent_base()) + F::EXPONENT_BIAS - ctlz as i32
 }
 
@@ -162,6 +162,42 @@ where
 // ROUNDING
 // --------
 
+/// Direction to round the magnitude of a float.
+///
+/// The algorithms work on the absolute value of the float, so the
+/// rounding mode is converted to a direction from the sign of the float.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round to the nearest float, with ties to even.
+    NearestEven,
+    /// Round to the nearest float, with ties away from zero.
+    NearestAway,
+    /// Round the magnitude down, toward zero.
+    Down,
+    /// Round the magnitude up, away from zero.
+    Up,
+}
+
+impl Rounding {
+    /// Get the direction to round the magnitude from the mode and sign.
+    #[inline(always)]
+    pub const fn new(mode: RoundMode, is_negative: bool) -> Self {
+        match (mode, is_negative) {
+            (RoundMode::NearestTiesEven, _) => Self::NearestEven,
+            (RoundMode::NearestTiesAway, _) => Self::NearestAway,
+            (RoundMode::TowardZero, _) => Self::Down,
+            (RoundMode::TowardPositive, false) | (RoundMode::TowardNegative, true) => Self::Up,
+            (RoundMode::TowardPositive, true) | (RoundMode::TowardNegative, false) => Self::Down,
+        }
+    }
+
+    /// Determine if the rounding is to the nearest float.
+    #[inline(always)]
+    pub const fn is_nearest(self) -> bool {
+        matches!(self, Self::NearestEven | Self::NearestAway)
+    }
+}
+
 /// Round an extended-precision float to the nearest machine float.
 ///
 /// Shifts the significant digits into place, adjusts the exponent,
@@ -272,6 +308,69 @@ where
     fp.mant += cb(is_odd, is_halfway, is_above) as u64;
 }
 
+/// Shift right N-bytes and round with the rounding direction.
+///
+/// `is_truncated` is if any non-zero bits are below the extended float,
+/// which are not part of the mantissa.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn round_bits(fp: &mut ExtendedFloat80, shift: i32, rounding: Rounding, is_truncated: bool) {
+    // Ensure we've already handled denormal values that underflow.
+    debug_assert!(shift <= 64);
+
+    let mask = lower_n_mask(shift as u64);
+    let halfway = lower_n_halfway(shift as u64);
+    let truncated_bits = fp.mant & mask;
+    let is_above = truncated_bits > halfway || (truncated_bits == halfway && is_truncated);
+    let is_halfway = truncated_bits == halfway && !is_truncated;
+    let is_exact = truncated_bits == 0 && !is_truncated;
+
+    fp.mant = match shift == 64 {
+        true => 0,
+        false => fp.mant >> shift,
+    };
+    fp.exp += shift;
+
+    let is_odd = fp.mant & 1 == 1;
+    let is_roundup = match rounding {
+        Rounding::NearestEven => is_above || (is_odd && is_halfway),
+        Rounding::NearestAway => is_above || is_halfway,
+        Rounding::Down => false,
+        Rounding::Up => !is_exact,
+    };
+    fp.mant += is_roundup as u64;
+}
+
+/// Step a rounded extended float to the next or previous float.
+///
+/// Since the bits of floats are ordered like their magnitudes, this
+/// steps between the largest normal float and infinity, and between
+/// denormal and normal floats.
+#[inline(always)]
+pub fn step_float<F: RawFloat>(fp: &mut ExtendedFloat80, is_next: bool) {
+    let bits = ((fp.exp as u64) << F::MANTISSA_SIZE) | fp.mant;
+    let bits = match is_next {
+        true => bits + 1,
+        false => bits.saturating_sub(1),
+    };
+    fp.mant = bits & F::MANTISSA_MASK.as_u64();
+    fp.exp = (bits >> F::MANTISSA_SIZE) as i32;
+}
+
+/// Round values that overflow or underflow, which cannot be directed.
+///
+/// The algorithms round values that are too large to infinity, and
+/// values that are too small to zero. This rounds infinity down to the
+/// largest float, and non-zero values up to the smallest float.
+#[inline(always)]
+pub fn round_limits<F: RawFloat>(fp: &mut ExtendedFloat80, rounding: Rounding, is_zero: bool) {
+    if rounding == Rounding::Down && fp.exp >= F::INFINITE_POWER {
+        fp.mant = F::MANTISSA_MASK.as_u64();
+        fp.exp = F::INFINITE_POWER - 1;
+    } else if rounding == Rounding::Up && fp.mant == 0 && fp.exp == 0 && !is_zero {
+        fp.mant = 1;
+    }
+}
+
 /// Round our significant digits into place, truncating them.
 #[cfg_attr(not(feature = "compact"), inline)]
 pub fn round_down(fp: &mut ExtendedFloat80, shift: i32) {
@@ -12,14 +12,14 @@ use crate::bigint::{Bigint, Limb, LIMB_BITS};
 use crate::float::{extended_to_float, ExtendedFloat80, RawFloat};
 use crate::limits::{u32_power_limit, u64_power_limit};
 use crate::number::Number;
-use crate::shared;
+use crate::shared::{self, Rounding};
 use core::cmp;
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
 use lexical_util::digit::char_to_valid_digit_const;
 #[cfg(feature = "radix")]
 use lexical_util::digit::digit_to_char_const;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_util::iterator::{AsBytes, BytesIter};
 use lexical_util::num::{AsPrimitive, Integer};
 
@@ -42,21 +42,25 @@ use lexical_util::num::{AsPrimitive, Integer};
 /// any value before or equal to `16777217.0` must be rounded down
 /// to `16777216.0`. These near-halfway conversions therefore may require
 /// a large number of digits to unambiguously determine how to round.
+///
+/// When rounding toward a direction, the float may instead be exactly
+/// representable, or near it, which is handled the same way.
 #[inline]
-pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
+pub fn slow_radix<F: RawFloat, Fmt: Format>(
     num: Number,
+    format: Fmt,
     fp: ExtendedFloat80,
+    rounding: Rounding,
 ) -> ExtendedFloat80 {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
     debug_assert!(fp.mant & (1 << 63) != 0);
 
-    let format = NumberFormat::<{ FORMAT }> {};
 
     // This assumes the sign bit has already been parsed, and we're
     // starting with the integer digits, and the float format has been
     // correctly validated.
-    let sci_exp = scientific_exponent::<FORMAT>(&num);
+    let sci_exp = scientific_exponent(&num, format);
 
     // We have 3 major algorithms we use for this:
     //  1. An algorithm with a finite number of digits and a positive exponent.
@@ -71,10 +75,10 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         if let Some(max_digits) = F::max_digits(format.radix()) {
             // Can use our finite number of digit algorithm.
-            digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
+            digit_comp::<F, _>(num, format, fp, sci_exp, max_digits, rounding)
         } else {
             // Fallback to infinite digits.
-            byte_comp::<F, FORMAT>(num, fp, sci_exp)
+            byte_comp::<F, _>(num, format, fp, sci_exp, rounding)
         }
     }
 
@@ -82,8 +86,62 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         // Can use our finite number of digit algorithm.
         let max_digits = F::max_digits(format.radix()).unwrap();
-        digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
+        digit_comp::<F, _>(num, format, fp, sci_exp, max_digits, rounding)
+    }
+}
+
+/// Compare the significant digits to a float.
+///
+/// This is used to determine if a float exactly represents the digits.
+/// The digits are first compared by magnitude, so the big integers
+/// cannot overflow for digits far outside the range of the float.
+pub fn compare_float<F: RawFloat, Fmt: Format>(
+    num: Number,
+    format: Fmt,
+    float: F,
+) -> cmp::Ordering {
+    let radix = format.radix();
+    let theor = b(float);
+    let sci_exp = scientific_exponent(&num, format);
+
+    // The digits are in `[radix^sci_exp, radix^(sci_exp + 1))`, so get
+    // the bounds of the binary exponent from the floor and ceiling of
+    // `log2(radix)`, and compare it to the exponent of the float.
+    let log2_floor = 31 - radix.leading_zeros() as i32;
+    let log2_ceil = log2_floor + !radix.is_power_of_two() as i32;
+    let (lower, upper) = match sci_exp >= 0 {
+        true => (sci_exp * log2_floor, (sci_exp + 1) * log2_ceil),
+        false => (sci_exp * log2_ceil, (sci_exp + 1) * log2_floor),
+    };
+    let float_exp = theor.exp + 63 - theor.mant.leading_zeros() as i32;
+    if float_exp >= upper {
+        return cmp::Ordering::Less;
+    } else if float_exp < lower {
+        return cmp::Ordering::Greater;
+    }
+
+    #[cfg(feature = "radix")]
+    if F::max_digits(radix).is_none() {
+        return byte_ord(num, format, theor, sci_exp);
     }
+
+    let max_digits = F::max_digits(radix).unwrap();
+    let (mut real_digits, digits) = parse_mantissa(num, format, max_digits);
+    // This can't underflow, since `digits` is at most `max_digits`.
+    let exponent = sci_exp + 1 - digits as i32;
+    if exponent < 0 {
+        return negative_digit_ord(real_digits, format, exponent, theor);
+    }
+
+    // Both values are integers scaled by powers-of-two.
+    let mut theor_digits = Bigint::from_u64(theor.mant);
+    real_digits.pow(radix, exponent as u32).unwrap();
+    if theor.exp > 0 {
+        theor_digits.pow(2, theor.exp as u32).unwrap();
+    } else if theor.exp < 0 {
+        real_digits.pow(2, (-theor.exp) as u32).unwrap();
+    }
+    real_digits.data.cmp(&theor_digits.data)
 }
 
 /// Algorithm that generates the mantissa for a finite representation.
@@ -94,28 +152,31 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
 /// digits to the theoretical digits for `b` and determine if we
 /// need to round-up.
 #[inline]
-pub fn digit_comp<F: RawFloat, const FORMAT: u128>(
+pub fn digit_comp<F: RawFloat, Fmt: Format>(
     num: Number,
+    format: Fmt,
     fp: ExtendedFloat80,
     sci_exp: i32,
     max_digits: usize,
+    rounding: Rounding,
 ) -> ExtendedFloat80 {
-    let (bigmant, digits) = parse_mantissa::<FORMAT>(num, max_digits);
+    let (bigmant, digits) = parse_mantissa(num, format, max_digits);
     // This can't underflow, since `digits` is at most `max_digits`.
     let exponent = sci_exp + 1 - digits as i32;
     if exponent >= 0 {
-        positive_digit_comp::<F, FORMAT>(bigmant, exponent)
+        positive_digit_comp::<F, _>(bigmant, format, exponent, rounding)
     } else {
-        negative_digit_comp::<F, FORMAT>(bigmant, fp, exponent)
+        negative_digit_comp::<F, _>(bigmant, format, fp, exponent, rounding)
     }
 }
 
 /// Generate the significant digits with a positive exponent relative to mantissa.
-pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
+pub fn positive_digit_comp<F: RawFloat, Fmt: Format>(
     mut bigmant: Bigint,
+    format: Fmt,
     exponent: i32,
+    rounding: Rounding,
 ) -> ExtendedFloat80 {
-    let format = NumberFormat::<{ FORMAT }> {};
 
     // Simple, we just need to multiply by the power of the radix.
     // Now, we can calculate the mantissa and the exponent from this.
@@ -134,16 +195,36 @@ pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
     };
 
     // Shift the digits into position and determine if we need to round-up.
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
-            is_above || (is_halfway && is_truncated) || (is_odd && is_halfway)
-        });
-    });
+    shared::round::<F, _>(&mut fp, |f, s| shared::round_bits(f, s, rounding, is_truncated));
     fp
 }
 
 /// Generate the significant digits with a negative exponent relative to mantissa.
 ///
+/// When rounding toward a direction, we instead compare to the nearest
+/// float `c`, and round to the float before or after `c`.
+pub fn negative_digit_comp<F: RawFloat, Fmt: Format>(
+    bigmant: Bigint,
+    format: Fmt,
+    mut fp: ExtendedFloat80,
+    exponent: i32,
+    rounding: Rounding,
+) -> ExtendedFloat80 {
+    // Ensure our preconditions are valid:
+    //  1. The significant digits are not shifted into place.
+    debug_assert!(fp.mant & (1 << 63) != 0);
+
+    // Get the significant digits and the binary exponent for `b+h`, or `c`.
+    let (theor, candidate) = theoretical::<F>(fp, rounding);
+
+    // Compare our theoretical and real digits and round.
+    let ord = negative_digit_ord(bigmant, format, exponent, theor);
+    round_compared::<F>(&mut fp, candidate, ord, rounding);
+    fp
+}
+
+/// Compare the significant digits with a negative exponent to the theoretical digits.
+///
 /// This algorithm is quite simple: we have the significant digits `m1 * b^N1`,
 /// where `m1` is the bigint mantissa, `b` is the radix, and `N1` is the radix
 /// exponent. We then calculate the theoretical representation of `b+h`, which
@@ -162,17 +243,12 @@ pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
 ///
 /// This allows us to compare both floats using integers efficiently
 /// without any loss of precision.
-#[allow(clippy::comparison_chain)]
-pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
+pub fn negative_digit_ord<Fmt: Format>(
     bigmant: Bigint,
-    mut fp: ExtendedFloat80,
+    format: Fmt,
     exponent: i32,
-) -> ExtendedFloat80 {
-    // Ensure our preconditions are valid:
-    //  1. The significant digits are not shifted into place.
-    debug_assert!(fp.mant & (1 << 63) != 0);
-
-    let format = NumberFormat::<FORMAT> {};
+    theor: ExtendedFloat80,
+) -> cmp::Ordering {
     let radix = format.radix();
 
     // Get the significant digits and radix exponent for the real digits.
@@ -180,13 +256,7 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     let real_exp = exponent;
     debug_assert!(real_exp < 0);
 
-    // Round down our extended-precision float and calculate `b`.
-    let mut b = fp;
-    shared::round::<F, _>(&mut b, shared::round_down);
-    let b = extended_to_float::<F>(b);
-
-    // Get the significant digits and the binary exponent for `b+h`.
-    let theor = bh(b);
+    // Get the significant digits and the binary exponent for the theoretical digits.
     let mut theor_digits = Bigint::from_u64(theor.mant);
     let theor_exp = theor.exp;
 
@@ -223,21 +293,7 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
         real_digits.pow(2, (-binary_exp) as u32).unwrap();
     }
 
-    // Compare our theoretical and real digits and round nearest, tie even.
-    let ord = real_digits.data.cmp(&theor_digits.data);
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
-            // Can ignore `is_halfway` and `is_above`, since those were
-            // calculates using less significant digits.
-            match ord {
-                cmp::Ordering::Greater => true,
-                cmp::Ordering::Less => false,
-                cmp::Ordering::Equal if is_odd => true,
-                cmp::Ordering::Equal => false,
-            }
-        });
-    });
-    fp
+    real_digits.data.cmp(&theor_digits.data)
 }
 
 /// Try to parse 8 digits at a time.
@@ -252,7 +308,7 @@ macro_rules! try_parse_8digits {
         $step:ident,
         $max_digits:ident
     ) => {{
-        let format = NumberFormat::<$format> {};
+        let format = $format;
         let radix = format.radix() as Limb;
 
         // Try 8-digit optimizations.
@@ -262,7 +318,7 @@ macro_rules! try_parse_8digits {
             let radix8 = radix4.wrapping_mul(radix4);
 
             while $step - $counter >= 8 && $max_digits - $count >= 8 {
-                if let Some(v) = algorithm::try_parse_8digits::<Limb, _, FORMAT>(&mut $iter) {
+                if let Some(v) = algorithm::try_parse_8digits::<Limb, _, _>(&mut $iter, format) {
                     $value = $value.wrapping_mul(radix8).wrapping_add(v);
                     $counter += 8;
                     $count += 8;
@@ -359,8 +415,7 @@ macro_rules! round_up_nonzero {
 ///
 /// Returns the parsed mantissa and the number of digits in the mantissa.
 /// The max digits is the maximum number of digits plus one.
-pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bigint, usize) {
-    let format = NumberFormat::<FORMAT> {};
+pub fn parse_mantissa<Fmt: Format>(num: Number, format: Fmt, max_digits: usize) -> (Bigint, usize) {
     let radix = format.radix();
 
     // Iteratively process all the data in the mantissa.
@@ -381,12 +436,12 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
     let max_native = (format.radix() as Limb).pow(step as u32);
 
     // Process the integer digits.
-    let mut integer = num.integer.bytes::<FORMAT>();
+    let mut integer = num.integer.bytes(format);
     let mut integer_iter = integer.integer_iter();
     integer_iter.skip_zeros();
     'integer: loop {
         #[cfg(not(feature = "compact"))]
-        try_parse_8digits!(FORMAT, integer_iter, value, count, counter, step, max_digits);
+        try_parse_8digits!(format, integer_iter, value, count, counter, step, max_digits);
 
         // Parse a digit at a time, until we reach step.
         while counter < step && count < max_digits {
@@ -404,7 +459,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
             add_temporary!(@end format, result, counter, value);
             round_up_nonzero!(format, integer_iter, result, count);
             if let Some(fraction) = num.fraction {
-                let mut fraction = fraction.bytes::<FORMAT>();
+                let mut fraction = fraction.bytes(format);
                 round_up_nonzero!(format, fraction.fraction_iter(), result, count)
             }
             return (result, count);
@@ -417,7 +472,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
 
     // Process the fraction digits.
     if let Some(fraction) = num.fraction {
-        let mut fraction = fraction.bytes::<FORMAT>();
+        let mut fraction = fraction.bytes(format);
         let mut fraction_iter = fraction.integer_iter();
         if count == 0 {
             // No digits added yet, can skip leading fraction zeros too.
@@ -425,7 +480,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
         }
         'fraction: loop {
             #[cfg(not(feature = "compact"))]
-            try_parse_8digits!(FORMAT, fraction_iter, value, count, counter, step, max_digits);
+            try_parse_8digits!(format, fraction_iter, value, count, counter, step, max_digits);
 
             // Parse a digit at a time, until we reach step.
             while counter < step && count < max_digits {
@@ -537,25 +592,36 @@ macro_rules! fraction_compare {
 /// Adapted from "Bigcomp: Deciding Truncated, Near Halfway Conversions",
 /// available [here](https://www.exploringbinary.com/bigcomp-deciding-truncated-near-halfway-conversions/).
 #[cfg(feature = "radix")]
-#[allow(clippy::comparison_chain)]
-pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
+pub fn byte_comp<F: RawFloat, Fmt: Format>(
     number: Number,
+    format: Fmt,
     mut fp: ExtendedFloat80,
     sci_exp: i32,
+    rounding: Rounding,
 ) -> ExtendedFloat80 {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
     debug_assert!(fp.mant & (1 << 63) != 0);
 
-    let format = NumberFormat::<FORMAT> {};
+    // Calculate `b+h`, or `c`, to create a ratio for our theoretical digits.
+    let (theor, candidate) = theoretical::<F>(fp, rounding);
 
-    // Round down our extended-precision float and calculate `b`.
-    let mut b = fp;
-    shared::round::<F, _>(&mut b, shared::round_down);
-    let b = extended_to_float::<F>(b);
+    // Compare our theoretical and real digits and round.
+    let ord = byte_ord(number, format, theor, sci_exp);
+    round_compared::<F>(&mut fp, candidate, ord, rounding);
+    fp
+}
 
-    // Calculate `b+h` to create a ratio for our theoretical digits.
-    let theor = Bigfloat::from_float(bh::<F>(b));
+/// Compare the digits to the theoretical digits generated from a ratio.
+#[cfg(feature = "radix")]
+#[allow(clippy::comparison_chain)]
+pub fn byte_ord<Fmt: Format>(
+    number: Number,
+    format: Fmt,
+    theor: ExtendedFloat80,
+    sci_exp: i32,
+) -> cmp::Ordering {
+    let theor = Bigfloat::from_float(theor);
 
     // Now, create a scaling factor for the digit count.
     let mut factor = Bigfloat::from_u32(1);
@@ -610,48 +676,34 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
         }
     }
 
-    // Compare our theoretical and real digits and round nearest, tie even.
-    let ord = compare_bytes::<FORMAT>(number, num, den);
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
-            // Can ignore `is_halfway` and `is_above`, since those were
-            // calculates using less significant digits.
-            match ord {
-                cmp::Ordering::Greater => true,
-                cmp::Ordering::Less => false,
-                cmp::Ordering::Equal if is_odd => true,
-                cmp::Ordering::Equal => false,
-            }
-        });
-    });
-    fp
+    compare_bytes(number, format, num, den)
 }
 
 /// Compare digits between the generated values the ratio and the actual view.
 #[cfg(feature = "radix")]
-pub fn compare_bytes<const FORMAT: u128>(
+pub fn compare_bytes<Fmt: Format>(
     number: Number,
+    format: Fmt,
     mut num: Bigfloat,
     den: Bigfloat,
 ) -> cmp::Ordering {
-    let format = NumberFormat::<FORMAT> {};
     let radix = format.radix();
 
     // Now need to compare the theoretical digits. First, I need to trim
     // any leading zeros, and will also need to ignore trailing ones.
-    let mut integer = number.integer.bytes::<{ FORMAT }>();
+    let mut integer = number.integer.bytes(format);
     let mut integer_iter = integer.integer_iter();
     integer_iter.skip_zeros();
     if integer_iter.is_done() {
         // Cannot be empty, since we must have at least **some** significant digits.
-        let mut fraction = number.fraction.unwrap().bytes::<{ FORMAT }>();
+        let mut fraction = number.fraction.unwrap().bytes(format);
         let mut fraction_iter = fraction.fraction_iter();
         fraction_iter.skip_zeros();
         fraction_compare!(fraction_iter, num, den, radix);
     } else {
         integer_compare!(integer_iter, num, den, radix);
         if let Some(fraction) = number.fraction {
-            let mut fraction = fraction.bytes::<{ FORMAT }>();
+            let mut fraction = fraction.bytes(format);
             let mut fraction_iter = fraction.fraction_iter();
             fraction_compare!(fraction_iter, num, den, radix);
         } else if !num.data.is_empty() {
@@ -670,11 +722,10 @@ pub fn compare_bytes<const FORMAT: u128>(
 /// Calculate the scientific exponent from a `Number` value.
 /// Any other attempts would require slowdowns for faster algorithms.
 #[inline]
-pub fn scientific_exponent<const FORMAT: u128>(num: &Number) -> i32 {
+pub fn scientific_exponent<Fmt: Format>(num: &Number, format: Fmt) -> i32 {
     // This has the significant digits and exponent relative to those
     // digits: therefore, we just need to scale to mantissa to `[1, radix)`.
     // This doesn't need to be very fast.
-    let format = NumberFormat::<FORMAT> {};
 
     // Use power reduction to make this faster: we need at least
     // F::MANTISSA_SIZE bits, so we must have at least radix^4 digits.
@@ -719,6 +770,64 @@ pub fn bh<F: RawFloat>(float: F) -> ExtendedFloat80 {
     }
 }
 
+/// Calculate the theoretical digits to compare to the real digits.
+///
+/// When rounding to nearest, this is `b+h`, where `b` is the extended-precision
+/// float rounded down. Otherwise, this is the nearest finite float `c`, which is
+/// also returned, since the real digits are between the floats before and
+/// after `c`.
+#[inline]
+pub fn theoretical<F: RawFloat>(
+    fp: ExtendedFloat80,
+    rounding: Rounding,
+) -> (ExtendedFloat80, Option<ExtendedFloat80>) {
+    if rounding.is_nearest() {
+        let mut b = fp;
+        shared::round::<F, _>(&mut b, shared::round_down);
+        (bh(extended_to_float::<F>(b)), None)
+    } else {
+        let mut c = fp;
+        shared::round::<F, _>(&mut c, |f, s| {
+            shared::round_bits(f, s, Rounding::NearestEven, false)
+        });
+        if c.exp >= F::INFINITE_POWER {
+            shared::step_float::<F>(&mut c, false);
+        }
+        (b(extended_to_float::<F>(c)), Some(c))
+    }
+}
+
+/// Round the extended-precision float from the comparison to the theoretical digits.
+#[inline]
+pub fn round_compared<F: RawFloat>(
+    fp: &mut ExtendedFloat80,
+    candidate: Option<ExtendedFloat80>,
+    ord: cmp::Ordering,
+    rounding: Rounding,
+) {
+    if let Some(c) = candidate {
+        *fp = c;
+        match (ord, rounding) {
+            (cmp::Ordering::Greater, Rounding::Up) => shared::step_float::<F>(fp, true),
+            (cmp::Ordering::Less, Rounding::Down) => shared::step_float::<F>(fp, false),
+            _ => (),
+        }
+        return;
+    }
+
+    shared::round::<F, _>(fp, |f, s| {
+        shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
+            // Can ignore `is_halfway` and `is_above`, since those were
+            // calculates using less significant digits.
+            match ord {
+                cmp::Ordering::Greater => true,
+                cmp::Ordering::Less => false,
+                cmp::Ordering::Equal => is_odd || rounding == Rounding::NearestAway,
+            }
+        });
+    });
+}
+
 /// Calculate the integral ceiling of the binary factor from a basen number.
 #[inline]
 pub const fn integral_binary_factor(radix: u32) -> u32 {
@@ -0,0 +1,97 @@
+//! Parse floats and report if the conversion was lossy.
+//!
+//! The float parsers round to the nearest representable float, which
+//! silently loses precision, overflows to infinity, or underflows to 0.
+//! These parsers also return a [`Status`], describing if the returned
+//! float exactly represents the input, or how it was rounded.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_float::format::STANDARD;
+//! use lexical_parse_float::status::{parse_with_status, Status};
+//! use lexical_parse_float::Options;
+//!
+//! # pub fn main() {
+//! let options = Options::new();
+//! let parse = |bytes| parse_with_status::<f64, STANDARD>(bytes, &options);
+//! assert_eq!(parse(b"0.5"), Ok((0.5, Status::Exact)));
+//! assert_eq!(parse(b"0.1"), Ok((0.1, Status::Inexact)));
+//! assert_eq!(parse(b"1e400"), Ok((f64::INFINITY, Status::Overflow)));
+//! assert_eq!(parse(b"1e-400"), Ok((0.0, Status::Underflow)));
+//! assert_eq!(parse(b"5e-324"), Ok((5e-324, Status::Subnormal)));
+//! # }
+//! ```
+
+use crate::float::LemireFloat;
+use crate::options::Options;
+use crate::parse::{parse_complete_status, parse_partial_status};
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, Format, NumberFormat};
+use lexical_util::result::Result;
+
+/// How the parsed float represents the input.
+///
+/// Special values, such as NaN and infinity, and zero are always exact.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The float exactly represents the input.
+    Exact,
+    /// The input was rounded to a normal float.
+    Inexact,
+    /// The input was rounded to a subnormal float, with reduced precision.
+    Subnormal,
+    /// The input was rounded to infinity.
+    ///
+    /// Inputs larger than the largest finite float that round to it,
+    /// such as when rounding toward zero, are inexact.
+    Overflow,
+    /// The non-zero input was rounded to zero.
+    Underflow,
+}
+
+impl Status {
+    /// Get if the float exactly represents the input.
+    #[inline]
+    pub const fn is_exact(&self) -> bool {
+        matches!(self, Self::Exact)
+    }
+}
+
+/// Validate the format and options.
+macro_rules! check_format {
+    ($format:ident, $options:ident) => {{
+        if !$format.is_valid() {
+            return Err($format.error());
+        }
+        let exponent = $options.exponent();
+        let decimal_point = $options.decimal_point();
+        if !is_valid_options_punctuation($format.packed(), exponent, decimal_point) {
+            return Err(Error::InvalidPunctuation);
+        }
+    }};
+}
+
+/// Parse a float from the entire input, and report if it was rounded.
+#[inline]
+pub fn parse_with_status<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, Status)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    check_format!(format, options);
+    parse_complete_status::<F, _>(bytes, format, options)
+}
+
+/// Parse a float from the start of the input, and report if it was rounded.
+///
+/// Returns the float, its status, and the number of processed bytes.
+#[inline]
+pub fn parse_partial_with_status<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, Status, usize)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    check_format!(format, options);
+    parse_partial_status::<F, _>(bytes, format, options)
+}
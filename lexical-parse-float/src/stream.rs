@@ -0,0 +1,143 @@
+//! Resumable parser for floats split across chunks of input.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_float::format::STANDARD;
+//! use lexical_parse_float::stream::{FloatStream, Progress};
+//!
+//! # pub fn main() {
+//! let mut stream = FloatStream::<f64, STANDARD, 64>::new();
+//! assert_eq!(stream.feed(b"1.2"), Ok(Progress::Incomplete));
+//! assert_eq!(stream.feed(b"5e"), Ok(Progress::Incomplete));
+//! assert_eq!(stream.feed(b"-1]"), Ok(Progress::Complete(0.125, 7, 2)));
+//! # }
+//! ```
+
+use crate::api::FromLexicalWithOptions;
+use crate::options::Options;
+use core::marker::PhantomData;
+use lexical_util::digit::char_is_digit_const;
+use lexical_util::format::{Format, NumberFormat};
+use lexical_util::result::Result;
+use lexical_util::stream::StreamBuffer;
+use lexical_util::whitespace::is_whitespace_byte;
+
+pub use lexical_util::stream::Progress;
+
+/// Determine if a byte is contained in a special string.
+#[inline]
+fn is_special_token(c: u8, string: Option<&'static [u8]>) -> bool {
+    match string {
+        Some(string) => string.iter().any(|x| c.eq_ignore_ascii_case(x)),
+        None => false,
+    }
+}
+
+/// Determine if a byte may be part of a float.
+///
+/// Any non-ASCII byte may be part of a Unicode digit, if the format allows
+/// them, and any byte of an alternative punctuation may be part of it.
+#[inline]
+pub fn is_token<F: Format>(c: u8, format: F, options: &Options) -> bool {
+    char_is_digit_const(c, format.mantissa_radix())
+        || char_is_digit_const(c, format.exponent_radix())
+        || c == b'+'
+        || c == b'-'
+        || c == options.decimal_point()
+        || c.eq_ignore_ascii_case(&options.exponent())
+        || (c != 0
+            && (c == format.digit_separator()
+                || c.eq_ignore_ascii_case(&format.base_prefix())
+                || c.eq_ignore_ascii_case(&format.base_suffix())))
+        || (!format.no_special()
+            && (is_special_token(c, options.nan_string())
+                || is_special_token(c, options.inf_string())
+                || is_special_token(c, options.infinity_string())))
+        || (c >= 0x80 && format.unicode_digits())
+        || options.punctuation().contains_byte(c)
+}
+
+/// Resumable float parser, which accepts the input in chunks.
+///
+/// Each call to [`feed`](Self::feed) either requires more input, or returns
+/// the parsed value, the number of bytes processed since the start of the
+/// number, which includes bytes from previous chunks, and the number of
+/// bytes used from the chunk. Both the value and the count are identical
+/// to the partial parser on the contiguous input. At the end of the input,
+/// [`finish`](Self::finish) parses any buffered bytes. After each number
+/// completes, the next number starts with the unused bytes of the chunk.
+///
+/// Up to `SIZE` bytes of a number that crosses a chunk boundary are
+/// buffered inline, and longer numbers on the heap.
+#[derive(Clone, Debug)]
+pub struct FloatStream<T, const FORMAT: u128, const SIZE: usize> {
+    /// Bytes of the number from previous chunks.
+    buffer: StreamBuffer<SIZE>,
+    /// Options to parse the number.
+    options: Options,
+    /// Type of the float to parse.
+    _marker: PhantomData<T>,
+}
+
+impl<T, const FORMAT: u128, const SIZE: usize> FloatStream<T, FORMAT, SIZE> {
+    /// Create a stream with the default options.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_options(Options::new())
+    }
+
+    /// Create a stream with custom options.
+    #[inline]
+    pub const fn with_options(options: Options) -> Self {
+        Self {
+            buffer: StreamBuffer::new(),
+            options,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the number of bytes buffered from previous chunks.
+    #[inline]
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Discard the buffered bytes to start a new number.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+impl<T, const FORMAT: u128, const SIZE: usize> FloatStream<T, FORMAT, SIZE>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    /// Feed the next chunk of input.
+    #[inline]
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Progress<T>> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        let options = &self.options;
+        // Whitespace allowed by the format may surround the number.
+        self.buffer.feed(
+            chunk,
+            |c| is_token(c, format, options) || is_whitespace_byte(c, format),
+            |bytes| T::from_lexical_partial_with_options::<FORMAT>(bytes, options),
+        )
+    }
+
+    /// Parse the buffered bytes at the end of the input.
+    #[inline]
+    pub fn finish(&mut self) -> Result<(T, usize)> {
+        let options = &self.options;
+        self.buffer.finish(|bytes| T::from_lexical_partial_with_options::<FORMAT>(bytes, options))
+    }
+}
+
+impl<T, const FORMAT: u128, const SIZE: usize> Default for FloatStream<T, FORMAT, SIZE> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
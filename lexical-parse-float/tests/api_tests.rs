@@ -1,5 +1,9 @@
 #[cfg(feature = "format")]
 use core::num;
+#[cfg(feature = "format")]
+use lexical_parse_float::punctuation::{self, Punctuation};
+#[cfg(feature = "format")]
+use lexical_parse_float::DigitGrouping;
 use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
@@ -676,6 +680,52 @@ fn parse_f64_lossy_test() {
     assert_eq!(Ok((1.2345e10, 9)), parse(b"1.2345e10"));
 }
 
+#[test]
+fn out_of_range_test() {
+    const FORMAT: u128 = STANDARD;
+    let options = Options::builder().error_on_out_of_range(true).build().unwrap();
+    let parse = |x| f64::from_lexical_with_options::<FORMAT>(x, &options);
+    let parse_partial = |x| f64::from_lexical_partial_with_options::<FORMAT>(x, &options);
+
+    assert_eq!(Err(Error::Overflow(0)), parse(b"1e999"));
+    assert_eq!(Err(Error::Overflow(0)), parse(b"-1e999"));
+    assert_eq!(Err(Error::Underflow(0)), parse(b"1e-999"));
+    assert_eq!(Err(Error::Underflow(0)), parse(b"-2e-324"));
+    assert_eq!(Err(Error::Overflow(0)), parse_partial(b"1e999,"));
+    assert_eq!(Err(Error::Underflow(0)), parse_partial(b"1e-999]"));
+    assert_eq!(Ok(1.7976931348623157e308), parse(b"1.7976931348623158e308"));
+    assert_eq!(Ok(5e-324), parse(b"3e-324"));
+    assert_eq!(Ok(0.0), parse(b"0e999"));
+    assert_eq!(Ok((-0.0, 8)), parse_partial(b"-0.0e-99,"));
+    assert_eq!(Ok(f64::INFINITY), parse(b"inf"));
+    let parse_f32 = |x| f32::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(Err(Error::Overflow(0)), parse_f32(b"1e39"));
+    assert_eq!(Ok(f64::INFINITY), f64::from_lexical(b"1e999"));
+
+    let options = Options::builder()
+        .error_on_out_of_range(true)
+        .round_mode(lexical_parse_float::RoundMode::TowardZero)
+        .build()
+        .unwrap();
+    let parse = |x| f64::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(Ok(f64::MAX), parse(b"1e999"));
+    assert_eq!(Err(Error::Underflow(0)), parse(b"1e-999"));
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn f16_out_of_range_test() {
+    const FORMAT: u128 = STANDARD;
+    let options = Options::builder().error_on_out_of_range(true).build().unwrap();
+    let parse_f16 = |x| f16::from_lexical_with_options::<FORMAT>(x, &options);
+    let parse_bf16 = |x| bf16::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(Err(Error::Overflow(0)), parse_f16(b"1e5"));
+    assert_eq!(Err(Error::Underflow(0)), parse_f16(b"1e-9"));
+    assert_eq!(Ok(f16::from_f32(1.0)), parse_f16(b"1e0"));
+    assert!(parse_f16(b"inf").unwrap().is_inf());
+    assert_eq!(Err(Error::Overflow(0)), parse_bf16(b"1e39"));
+}
+
 #[test]
 fn f32_lossy_decimal_test() {
     const FORMAT: u128 = STANDARD;
@@ -984,6 +1034,24 @@ fn f64_exponent_internal_digit_separator_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"31.01e71_", &options).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn f64_many_digits_digit_separator_test() {
+    const FORMAT: u128 = rebuild(format::PERMISSIVE)
+        .internal_digit_separator(true)
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .build();
+    let options = Options::new();
+    let value =
+        f64::from_lexical_with_options::<FORMAT>(b"9_0_0_7_1_9_9_2_5_4_7_4_0_9_9_3.0_0_1", &options);
+    assert_eq!(value, Ok(9007199254740994.0));
+    let value = f64::from_lexical_with_options::<FORMAT>(
+        b"1_2_3_4_5_6_7_8_9_0.1_2_3_4_5_6_7_8_9_0_1_2_3_4_5",
+        &options,
+    );
+    assert_eq!(value, Ok(1234567890.1234567890123456789012345));
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn f64_integer_leading_digit_separator_test() {
@@ -1242,6 +1310,35 @@ fn issue68_test() {
     assert_eq!(f64::INFINITY, f64::from_lexical_with_options::<FORMAT>(hex, &options).unwrap());
 }
 
+#[test]
+fn code_unit_test() {
+    let units: Vec<u16> = "1.5e3".encode_utf16().collect();
+    assert_eq!(Ok(1500.0), f64::from_lexical_units(&units));
+    let units: Vec<u16> = "1.5\u{e9}3".encode_utf16().collect();
+    assert_eq!(Err(Error::InvalidDigit(3)), f64::from_lexical_units(&units));
+    assert_eq!(Ok((1.5, 3)), f64::from_lexical_partial_units(&units));
+    let units: Vec<char> = "\u{661}.5".chars().collect();
+    assert_eq!(Err(Error::EmptyMantissa(0)), f32::from_lexical_units(&units));
+    let units: Vec<char> = "-Infinity\u{2026}".chars().collect();
+    assert_eq!(Ok((f64::NEG_INFINITY, 9)), f64::from_lexical_partial_units(&units));
+    assert_eq!(Ok(1.5), f64::from_lexical_units(b"1.5"));
+
+    let digits = "1".repeat(1000);
+    let units: Vec<u16> = digits.encode_utf16().collect();
+    assert_eq!(f64::from_lexical(digits.as_bytes()), f64::from_lexical_units(&units));
+
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    let units: Vec<u32> = "1,5 e".chars().map(|c| c as u32).collect();
+    assert_eq!(
+        Err(Error::InvalidDigit(3)),
+        f64::from_lexical_units_with_options::<_, STANDARD>(&units, &options)
+    );
+    assert_eq!(
+        Ok((1.5, 3)),
+        f64::from_lexical_partial_units_with_options::<_, STANDARD>(&units, &options)
+    );
+}
+
 fn float_equal<F: Float>(x: F, y: F) -> bool {
     if x.is_nan() {
         y.is_nan()
@@ -1348,6 +1445,137 @@ quickcheck! {
     }
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn f64_unicode_digits_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().unicode_digits(true).build();
+    let parse = |x: &str| f64::from_lexical_with_options::<FORMAT>(x.as_bytes(), &options);
+    assert_eq!(parse("١.٥"), Ok(1.5));
+    assert_eq!(parse("-۱۲.۵e۳"), Ok(-12500.0));
+    assert_eq!(parse("९.९e-१"), Ok(0.99));
+    assert_eq!(parse("０.１"), Ok(0.1));
+    assert_eq!(parse("1.5"), Ok(1.5));
+
+    // Errors are indexed in bytes, and scripts cannot be mixed.
+    assert_eq!(parse("١.٥x"), Err(Error::InvalidDigit(5)));
+    assert_eq!(parse("١.5"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse("١.٥e3"), Err(Error::InvalidDigit(6)));
+    assert_eq!(parse("١.٥e"), Err(Error::EmptyExponent(6)));
+
+    let partial =
+        |x: &str| f64::from_lexical_partial_with_options::<FORMAT>(x.as_bytes(), &options);
+    assert_eq!(partial("١.٥ 3"), Ok((1.5, 5)));
+    assert_eq!(partial("０.５，"), Ok((0.5, 7)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_punctuation_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+    let options = Options::builder().punctuation(punctuation::TYPOGRAPHIC).build().unwrap();
+    let parse = |x: &str| f64::from_lexical_with_options::<FORMAT>(x.as_bytes(), &options);
+    assert_eq!(parse("\u{2212}1\u{202F}234\u{066B}5"), Ok(-1234.5));
+    assert_eq!(parse("1\u{00A0}000'000.25e\u{2212}2"), Ok(10000.0025));
+    assert_eq!(parse("1_000.5"), Ok(1000.5));
+    assert_eq!(parse("1.5e-1"), Ok(0.15));
+
+    // The digit separator rules of the format still apply.
+    assert_eq!(parse("1\u{202F}.5"), Err(Error::InvalidDigit(1)));
+
+    // Long numbers are replaced on the heap, with or without `std`.
+    let long = format!("1{}\u{066B}5", "\u{202F}000".repeat(100));
+    assert_eq!(parse(&long), Ok(1e300));
+    assert_eq!(parse(&format!("{}\u{202F}", long)), Err(Error::InvalidDigit(long.len())));
+
+    // Alternatives cannot overlap the punctuation of the format.
+    const QUOTE: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'\''))
+        .internal_digit_separator(true)
+        .build();
+    let options = Options::builder()
+        .punctuation(Punctuation::new().decimal_points(&[b"'"]))
+        .build()
+        .unwrap();
+    let result = f64::from_lexical_with_options::<QUOTE>(b"1", &options);
+    assert_eq!(result, Err(Error::InvalidAlternativePunctuation));
+    let result = f64::from_lexical_partial_with_options::<QUOTE>(b"1", &options);
+    assert_eq!(result, Err(Error::InvalidAlternativePunctuation));
+
+    // Non-ASCII digits and punctuation can be combined.
+    const ARABIC: u128 = NumberFormatBuilder::new().unicode_digits(true).build();
+    let options = Options::builder()
+        .punctuation(Punctuation::new().decimal_points(&[punctuation::ARABIC_DECIMAL_SEPARATOR]))
+        .build()
+        .unwrap();
+    let parse = |x: &str| f64::from_lexical_with_options::<ARABIC>(x.as_bytes(), &options);
+    assert_eq!(parse("١٢٫٥"), Ok(12.5));
+    let partial =
+        |x: &str| f64::from_lexical_partial_with_options::<ARABIC>(x.as_bytes(), &options);
+    assert_eq!(partial("١٢٫٥ 3"), Ok((12.5, 8)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_digit_grouping_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .digit_grouping(DigitGrouping::Thousands)
+        .build();
+    let options = Options::new();
+    let parse = |x: &[u8]| f64::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b"1_234_567.25"), Ok(1234567.25));
+    assert_eq!(parse(b"-12_345e2"), Ok(-1234500.0));
+    assert_eq!(parse(b"1_234.567_8"), Ok(1234.5678));
+    assert_eq!(parse(b"1_23_456.5"), Err(Error::InvalidDigitGroup(4)));
+    assert_eq!(parse(b"12_3456.5"), Err(Error::InvalidDigitGroup(6)));
+
+    let partial = |x: &[u8]| f64::from_lexical_partial_with_options::<FORMAT>(x, &options);
+    assert_eq!(partial(b"1_000.5 2"), Ok((1000.5, 7)));
+    assert_eq!(partial(b"10_00.5 2"), Err(Error::InvalidDigitGroup(5)));
+
+    // Groups are checked after replacing alternative punctuation.
+    let options = Options::builder().punctuation(punctuation::TYPOGRAPHIC).build().unwrap();
+    let parse = |x: &str| f64::from_lexical_with_options::<FORMAT>(x.as_bytes(), &options);
+    assert_eq!(parse("1\u{202F}234\u{066B}5"), Ok(1234.5));
+    assert_eq!(parse("12\u{202F}34"), Err(Error::InvalidDigitGroup(7)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_whitespace_test() {
+    const FORMAT: u128 =
+        NumberFormatBuilder::new().leading_whitespace(true).trailing_whitespace(true).build();
+    let options = Options::new();
+    let parse = |x: &[u8]| f64::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b" 1.5e3\n"), Ok(1500.0));
+    assert_eq!(parse(b"\t-0.25 "), Ok(-0.25));
+    assert!(parse(b" inf ").unwrap().is_infinite());
+    assert_eq!(parse(b"  1.5x"), Err(Error::InvalidDigit(5)));
+    assert_eq!(parse(b"1. 5"), Err(Error::InvalidDigit(2)));
+
+    let partial = |x: &[u8]| f64::from_lexical_partial_with_options::<FORMAT>(x, &options);
+    assert_eq!(partial(b" 1.5 x"), Ok((1.5, 5)));
+
+    // Unicode whitespace is skipped before replacing alternative punctuation.
+    const UNICODE: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .leading_whitespace(true)
+        .trailing_whitespace(true)
+        .unicode_whitespace(true)
+        .build();
+    let options = Options::builder().punctuation(punctuation::TYPOGRAPHIC).build().unwrap();
+    let parse = |x: &str| f64::from_lexical_with_options::<UNICODE>(x.as_bytes(), &options);
+    assert_eq!(parse("\u{A0}1\u{202F}234.5\u{A0}"), Ok(1234.5));
+    let units: Vec<u16> = "\u{2003}2.5\u{2003}".encode_utf16().collect();
+    assert_eq!(f64::from_lexical_units_with_options::<_, UNICODE>(&units, &options), Ok(2.5));
+}
+
 proptest! {
     #[test]
     #[cfg_attr(miri, ignore)]
@@ -1482,4 +1710,18 @@ proptest! {
         let input: String = format!("{:e}", i);
         prop_assert_eq!(i, f64::from_lexical(input.as_bytes()).unwrap());
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_code_unit_proptest(i in r"[+-]?[0-9]{0,20}(\.[0-9]{0,20})?([eE][+-]?[0-9]{0,3})?.?") {
+        let units: Vec<char> = i.chars().collect();
+        let bytes: Vec<u8> = i.chars().map(|c| if c.is_ascii() { c as u8 } else { 0xFF }).collect();
+        let bits = |r: Result<f64, Error>| r.map(f64::to_bits);
+        let partial_bits = |r: Result<(f64, usize), Error>| r.map(|(x, n)| (x.to_bits(), n));
+        prop_assert_eq!(bits(f64::from_lexical_units(&units)), bits(f64::from_lexical(&bytes)));
+        prop_assert_eq!(
+            partial_bits(f64::from_lexical_partial_units(&units)),
+            partial_bits(f64::from_lexical_partial(&bytes))
+        );
+    }
 }
@@ -4,7 +4,8 @@
 use lexical_parse_float::bellerophon::bellerophon;
 use lexical_parse_float::float::{extended_to_float, ExtendedFloat80, RawFloat};
 use lexical_parse_float::number::Number;
-use lexical_util::format::STANDARD;
+use lexical_parse_float::shared::Rounding;
+use lexical_util::format::{NumberFormat, STANDARD};
 
 pub fn bellerophon_test<F: RawFloat, const FORMAT: u128>(
     xmant: u64,
@@ -21,7 +22,8 @@ pub fn bellerophon_test<F: RawFloat, const FORMAT: u128>(
         integer: &[],
         fraction: None,
     };
-    let xfp = bellerophon::<F, FORMAT>(&num, false);
+    let format = NumberFormat::<{ FORMAT }> {};
+    let xfp = bellerophon::<F, _>(&num, format, false, Rounding::NearestEven);
     let yfp = ExtendedFloat80 {
         mant: ymant,
         exp: yexp,
@@ -50,7 +52,8 @@ pub fn compute_float32(q: i64, w: u64) -> (i32, u64) {
         integer: &[],
         fraction: None,
     };
-    let fp = bellerophon::<f32, { STANDARD }>(&num, false);
+    let format = NumberFormat::<{ STANDARD }> {};
+    let fp = bellerophon::<f32, _>(&num, format, false, Rounding::NearestEven);
     (fp.exp, fp.mant)
 }
 
@@ -63,6 +66,7 @@ pub fn compute_float64(q: i64, w: u64) -> (i32, u64) {
         integer: &[],
         fraction: None,
     };
-    let fp = bellerophon::<f64, { STANDARD }>(&num, false);
+    let format = NumberFormat::<{ STANDARD }> {};
+    let fp = bellerophon::<f64, _>(&num, format, false, Rounding::NearestEven);
     (fp.exp, fp.mant)
 }
@@ -2,7 +2,8 @@
 
 use lexical_parse_float::binary::{binary, slow_binary};
 use lexical_parse_float::number::Number;
-use lexical_util::format::NumberFormatBuilder;
+use lexical_parse_float::shared::Rounding;
+use lexical_util::format::{NumberFormat, NumberFormatBuilder};
 
 const BINARY: u128 = NumberFormatBuilder::from_radix(2);
 const BASE4: u128 = NumberFormatBuilder::from_radix(4);
@@ -19,7 +20,7 @@ fn compute_float32<const FORMAT: u128>(q: i64, w: u64, many_digits: bool) -> (i3
         integer: &[],
         fraction: None,
     };
-    let fp = binary::<f32, FORMAT>(&num, false);
+    let fp = binary::<f32, _>(&num, NumberFormat::<{ FORMAT }> {}, false, Rounding::NearestEven);
     (fp.exp, fp.mant)
 }
 
@@ -32,7 +33,7 @@ fn compute_float64<const FORMAT: u128>(q: i64, w: u64, many_digits: bool) -> (i3
         integer: &[],
         fraction: None,
     };
-    let fp = binary::<f64, FORMAT>(&num, false);
+    let fp = binary::<f64, _>(&num, NumberFormat::<{ FORMAT }> {}, false, Rounding::NearestEven);
     (fp.exp, fp.mant)
 }
 
@@ -141,7 +142,7 @@ fn compute_float64_slow<const FORMAT: u128>(
         integer,
         fraction,
     };
-    let fp = slow_binary::<f64, FORMAT>(num);
+    let fp = slow_binary::<f64, _>(num, NumberFormat::<{ FORMAT }> {}, Rounding::NearestEven);
     (fp.exp, fp.mant)
 }
 
@@ -0,0 +1,113 @@
+#![cfg(feature = "complex")]
+
+use lexical_parse_float::{options, Complex, FromLexical, FromLexicalWithOptions, Options};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+
+const FORMAT: u128 = STANDARD;
+
+fn parse(bytes: &[u8]) -> Result<Complex<f64>, Error> {
+    Complex::<f64>::from_lexical(bytes)
+}
+
+fn parse_partial(bytes: &[u8]) -> Result<(Complex<f64>, usize), Error> {
+    Complex::<f64>::from_lexical_partial(bytes)
+}
+
+#[test]
+fn parse_test() {
+    assert_eq!(parse(b"1.5+2e-3i"), Ok(Complex::new(1.5, 2e-3)));
+    assert_eq!(parse(b"1.5-2e-3i"), Ok(Complex::new(1.5, -2e-3)));
+    assert_eq!(parse(b"-1-2i"), Ok(Complex::new(-1.0, -2.0)));
+    assert_eq!(parse(b"1e+5+1e-5i"), Ok(Complex::new(1e5, 1e-5)));
+    assert_eq!(parse(b"1.5"), Ok(Complex::new(1.5, 0.0)));
+    assert_eq!(parse(b"2e-3i"), Ok(Complex::new(0.0, 2e-3)));
+    assert_eq!(parse(b"-2i"), Ok(Complex::new(0.0, -2.0)));
+    assert_eq!(parse(b"(1,2)"), Ok(Complex::new(1.0, 2.0)));
+    assert_eq!(parse(b"(-1.5,-2e-3)"), Ok(Complex::new(-1.5, -2e-3)));
+}
+
+#[test]
+fn parse_special_test() {
+    assert_eq!(parse(b"1+infi"), Ok(Complex::new(1.0, f64::INFINITY)));
+    assert_eq!(parse(b"-inf-infi"), Ok(Complex::new(f64::NEG_INFINITY, f64::NEG_INFINITY)));
+    assert_eq!(parse(b"infinityi"), Ok(Complex::new(0.0, f64::INFINITY)));
+    let value = parse(b"1-NaNi").unwrap();
+    assert_eq!(value.re, 1.0);
+    assert!(value.im.is_nan());
+    let value = parse(b"(NaN,inf)").unwrap();
+    assert!(value.re.is_nan());
+    assert_eq!(value.im, f64::INFINITY);
+}
+
+#[test]
+fn parse_error_test() {
+    assert_eq!(parse(b""), Err(Error::Empty(0)));
+    assert_eq!(parse(b"i"), Err(Error::EmptyMantissa(0)));
+    assert_eq!(parse(b"1+2"), Err(Error::Empty(3)));
+    assert_eq!(parse(b"1+2j"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1+i"), Err(Error::EmptyMantissa(2)));
+    assert_eq!(parse(b"1+2ix"), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse(b"1x"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse(b"2i+1"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse(b"1+2e+i"), Err(Error::EmptyExponent(5)));
+    assert_eq!(parse(b"(1,2"), Err(Error::Empty(4)));
+    assert_eq!(parse(b"(1;2)"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse(b"(1, 2)"), Err(Error::EmptyMantissa(3)));
+    assert_eq!(parse(b"(1,2))"), Err(Error::InvalidDigit(5)));
+}
+
+#[test]
+fn parse_partial_test() {
+    assert_eq!(parse_partial(b"1.5+2e-3i"), Ok((Complex::new(1.5, 2e-3), 9)));
+    assert_eq!(parse_partial(b"1+2i,3"), Ok((Complex::new(1.0, 2.0), 4)));
+    assert_eq!(parse_partial(b"2i 3"), Ok((Complex::new(0.0, 2.0), 2)));
+    assert_eq!(parse_partial(b"(1,2)x"), Ok((Complex::new(1.0, 2.0), 5)));
+
+    // Incomplete imaginary parts are not consumed.
+    assert_eq!(parse_partial(b"1+2"), Ok((Complex::new(1.0, 0.0), 1)));
+    assert_eq!(parse_partial(b"1+2j"), Ok((Complex::new(1.0, 0.0), 1)));
+    assert_eq!(parse_partial(b"1-x"), Ok((Complex::new(1.0, 0.0), 1)));
+    assert_eq!(parse_partial(b"(1,2"), Err(Error::Empty(4)));
+}
+
+#[test]
+fn parse_imaginary_unit_test() {
+    let options = Options::builder().imaginary_unit(Some(b"im")).build().unwrap();
+    let parse = |x| Complex::<f64>::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b"1+2im"), Ok(Complex::new(1.0, 2.0)));
+    assert_eq!(parse(b"2im"), Ok(Complex::new(0.0, 2.0)));
+    assert_eq!(parse(b"1+2i"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1+2iM"), Err(Error::InvalidDigit(3)));
+
+    let python = &options::PYTHON_LITERAL;
+    let parse = |x| Complex::<f64>::from_lexical_with_options::<FORMAT>(x, python);
+    assert_eq!(parse(b"1.5-2j"), Ok(Complex::new(1.5, -2.0)));
+    let julia = &options::JULIA_LITERAL;
+    let parse = |x| Complex::<f64>::from_lexical_with_options::<FORMAT>(x, julia);
+    assert_eq!(parse(b"1.5-Infim"), Ok(Complex::new(1.5, f64::NEG_INFINITY)));
+
+    // Without an imaginary unit, only tuples are parsed.
+    let options = Options::builder().imaginary_unit(None).build().unwrap();
+    let parse = |x| Complex::<f64>::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b"(1,2)"), Ok(Complex::new(1.0, 2.0)));
+    assert_eq!(parse(b"1"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse(b"1+2i"), Err(Error::InvalidDigit(0)));
+}
+
+#[test]
+fn parse_f32_test() {
+    let parse = |x| Complex::<f32>::from_lexical(x);
+    assert_eq!(parse(b"0.1+0.2i"), Ok(Complex::new(0.1f32, 0.2f32)));
+    assert_eq!(parse(b"(1e39,1e-46)"), Ok(Complex::new(f32::INFINITY, 0.0)));
+}
+
+#[test]
+fn parse_units_test() {
+    let units: Vec<u16> = "1.5-2e-3i".encode_utf16().collect();
+    assert_eq!(Complex::<f64>::from_lexical_units(&units), Ok(Complex::new(1.5, -2e-3)));
+    let units: Vec<char> = "(1,2)".chars().collect();
+    assert_eq!(Complex::<f64>::from_lexical_units(&units), Ok(Complex::new(1.0, 2.0)));
+    let units: Vec<u16> = "1+2i\u{2003}".encode_utf16().collect();
+    assert_eq!(Complex::<f64>::from_lexical_partial_units(&units), Ok((Complex::new(1.0, 2.0), 4)));
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "custom-float")]
+
+use lexical_parse_float::{
+    f8e4m3,
+    f8e5m2,
+    tf32,
+    FromLexical,
+    FromLexicalWithOptions,
+    Options,
+    RoundMode,
+};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+
+const FORMAT: u128 = STANDARD;
+
+/// Get the exact value of the float bits as an `f64`.
+///
+/// The bits past the largest finite float are treated as a normal float,
+/// so the next bits give the value that overflows.
+fn to_f64<F: Float>(bits: u32) -> f64 {
+    let mantissa = bits as u64 & ((1 << F::MANTISSA_SIZE) - 1);
+    let biased = (bits >> F::MANTISSA_SIZE) as i32;
+    let (mantissa, exponent) = match biased {
+        0 => (mantissa, F::DENORMAL_EXPONENT),
+        _ => (mantissa | (1 << F::MANTISSA_SIZE), biased - F::EXPONENT_BIAS),
+    };
+    mantissa as f64 * 2f64.powi(exponent)
+}
+
+fn parse_bits<F>(bytes: &[u8], round_mode: RoundMode) -> u32
+where
+    F: Float<Unsigned = u32> + FromLexicalWithOptions<Options = Options>,
+{
+    let options = Options::builder().round_mode(round_mode).build().unwrap();
+    F::from_lexical_with_options::<FORMAT>(bytes, &options).unwrap().to_bits()
+}
+
+/// Subtract 1 from the last digit of the halfway point.
+fn below(mut digits: Vec<u8>) -> Vec<u8> {
+    for digit in digits.iter_mut().rev().filter(|c| **c != b'.') {
+        if *digit == b'0' {
+            *digit = b'9';
+        } else {
+            *digit -= 1;
+            break;
+        }
+    }
+    digits
+}
+
+/// Check the exact halfway point between two floats, and the values next to it.
+///
+/// The halfway point is exact as an `f64`, and is written exactly, with
+/// enough trailing zeros to add or subtract a digit past the halfway point.
+fn check_halfway<F>(bits: u32)
+where
+    F: Float<Unsigned = u32> + FromLexicalWithOptions<Options = Options>,
+{
+    let halfway = (to_f64::<F>(bits) + to_f64::<F>(bits + 1)) / 2.0;
+    let string = format!("{:.200e}", halfway);
+    let index = string.find('e').unwrap();
+    let (digits, exponent) = (string.as_bytes()[..index].to_vec(), &string[index..]);
+    let parse = |digits: &[u8], round_mode| {
+        let mut bytes = digits.to_vec();
+        bytes.extend_from_slice(exponent.as_bytes());
+        parse_bits::<F>(&bytes, round_mode)
+    };
+
+    let even = bits + (bits & 1);
+    let message = format!("{:#X}", bits);
+    assert_eq!(parse(&digits, RoundMode::NearestTiesEven), even, "{}", message);
+    assert_eq!(parse(&digits, RoundMode::NearestTiesAway), bits + 1, "{}", message);
+    assert_eq!(parse(&digits, RoundMode::TowardZero), bits, "{}", message);
+    assert_eq!(parse(&digits, RoundMode::TowardPositive), bits + 1, "{}", message);
+
+    // Values just below and above the halfway point are not ties.
+    let below = below(digits.clone());
+    assert_eq!(parse(&below, RoundMode::NearestTiesEven), bits, "{}", message);
+    let mut above = digits;
+    *above.last_mut().unwrap() += 1;
+    assert_eq!(parse(&above, RoundMode::NearestTiesEven), bits + 1, "{}", message);
+}
+
+#[test]
+fn f8e4m3_parse_test() {
+    let parse = |x: &[u8]| f8e4m3::from_lexical(x).unwrap().to_bits();
+    assert_eq!(parse(b"0"), 0);
+    assert_eq!(parse(b"-0.0"), 0x80);
+    assert_eq!(parse(b"1"), 0x38);
+    assert_eq!(parse(b"0.1"), 0x1D);
+    assert_eq!(parse(b"-3.5"), 0xC6);
+    assert_eq!(parse(b"0.001953125"), 0x01);
+    assert_eq!(parse(b"0.0009765625"), 0);
+    assert_eq!(parse(b"0.00097656251"), 0x01);
+    assert!(f8e4m3::from_lexical(b"NaN").unwrap().is_nan());
+}
+
+#[test]
+fn f8e4m3_saturate_test() {
+    // There is no infinity, so large values round to the largest float.
+    let max = 0x7E;
+    for mode in [RoundMode::NearestTiesEven, RoundMode::TowardZero, RoundMode::TowardPositive] {
+        assert_eq!(parse_bits::<f8e4m3>(b"448", mode), max);
+        assert_eq!(parse_bits::<f8e4m3>(b"464", mode), max);
+        assert_eq!(parse_bits::<f8e4m3>(b"1e10", mode), max);
+        assert_eq!(parse_bits::<f8e4m3>(b"inf", mode), max);
+        assert_eq!(parse_bits::<f8e4m3>(b"-1e10", mode), max | 0x80);
+    }
+
+    let options = Options::builder().error_on_out_of_range(true).build().unwrap();
+    let parse = |x| f8e4m3::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b"460").map(|x| x.to_bits()), Ok(max));
+    assert_eq!(parse(b"464").map(|x| x.to_bits()), Ok(max));
+    assert_eq!(parse(b"465").map(|x| x.to_bits()), Err(Error::Overflow(0)));
+    assert_eq!(parse(b"500").map(|x| x.to_bits()), Err(Error::Overflow(0)));
+    assert_eq!(parse(b"1e-5").map(|x| x.to_bits()), Err(Error::Underflow(0)));
+}
+
+#[test]
+fn f8e5m2_parse_test() {
+    let parse = |x: &[u8]| f8e5m2::from_lexical(x).unwrap().to_bits();
+    assert_eq!(parse(b"1"), 0x3C);
+    assert_eq!(parse(b"0.1"), 0x2E);
+    assert_eq!(parse(b"57344"), 0x7B);
+    assert_eq!(parse(b"61439"), 0x7B);
+    assert_eq!(parse(b"61440"), 0x7C);
+    assert_eq!(parse(b"inf"), 0x7C);
+    assert_eq!(parse(b"1e-5"), 0x01);
+}
+
+#[test]
+fn tf32_parse_test() {
+    let parse = |x: &[u8]| tf32::from_lexical(x).unwrap().to_bits();
+    assert_eq!(parse(b"1"), 0x1FC00);
+    assert_eq!(parse(b"3.14159"), 0x20248);
+    assert_eq!(parse(b"3.401e38"), 0x3FBFF);
+    assert_eq!(parse(b"3.5e38"), 0x3FC00);
+    assert_eq!(parse(b"1e-45"), 0);
+    assert_eq!(parse(b"1e-41"), 0x00001);
+}
+
+#[test]
+fn f8e4m3_halfway_test() {
+    // Every halfway point below the largest float.
+    for bits in 0..f8e4m3::MAX.to_bits() {
+        check_halfway::<f8e4m3>(bits);
+    }
+}
+
+#[test]
+fn f8e5m2_halfway_test() {
+    // Includes the halfway point between the largest float and infinity.
+    for bits in 0..f8e5m2::INFINITY.to_bits() {
+        check_halfway::<f8e5m2>(bits);
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn tf32_halfway_test() {
+    for bits in (0..tf32::INFINITY.to_bits()).step_by(7) {
+        check_halfway::<tf32>(bits);
+    }
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "decimal")]
+
+use lexical_parse_float::{
+    decimal128,
+    decimal32,
+    decimal64,
+    DecimalFloat,
+    FromLexical,
+    FromLexicalWithOptions,
+    Options,
+    RoundMode,
+};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+
+const FORMAT: u128 = STANDARD;
+
+fn parse_bits<D>(bytes: &[u8], round_mode: RoundMode) -> u128
+where
+    D: DecimalFloat + FromLexicalWithOptions<Options = Options>,
+{
+    let options = Options::builder().round_mode(round_mode).build().unwrap();
+    D::from_lexical_with_options::<FORMAT>(bytes, &options).unwrap().to_raw_bits()
+}
+
+fn check_modes<D>(bytes: &[u8], even: u128, away: u128, down: u128, up: u128)
+where
+    D: DecimalFloat + FromLexicalWithOptions<Options = Options>,
+{
+    assert_eq!(parse_bits::<D>(bytes, RoundMode::NearestTiesEven), even);
+    assert_eq!(parse_bits::<D>(bytes, RoundMode::NearestTiesAway), away);
+    assert_eq!(parse_bits::<D>(bytes, RoundMode::TowardZero), down);
+    assert_eq!(parse_bits::<D>(bytes, RoundMode::TowardPositive), up);
+    assert_eq!(parse_bits::<D>(bytes, RoundMode::TowardNegative), down);
+
+    // Negative values round in the opposite direction.
+    let mut negative = b"-".to_vec();
+    negative.extend_from_slice(bytes);
+    let sign = D::sign_mask();
+    assert_eq!(parse_bits::<D>(&negative, RoundMode::NearestTiesEven), even | sign);
+    assert_eq!(parse_bits::<D>(&negative, RoundMode::TowardNegative), up | sign);
+    assert_eq!(parse_bits::<D>(&negative, RoundMode::TowardPositive), down | sign);
+}
+
+#[test]
+fn parse_quantum_test() {
+    let parse = |x: &[u8]| {
+        let value = decimal64::from_lexical(x).unwrap();
+        (value.is_sign_negative(), value.coefficient(), value.exponent())
+    };
+    assert_eq!(parse(b"1"), (false, 1, 0));
+    assert_eq!(parse(b"1.50"), (false, 150, -2));
+    assert_eq!(parse(b"-1.5"), (true, 15, -1));
+    assert_eq!(parse(b"1.5e3"), (false, 15, 2));
+    assert_eq!(parse(b"0.00150"), (false, 150, -5));
+    assert_eq!(parse(b"-0.000"), (true, 0, -3));
+    assert_eq!(parse(b"1200"), (false, 1200, 0));
+    assert_eq!(parse(b"0012.3400"), (false, 123400, -4));
+
+    // Zeros are clamped, and large exponents are padded with zeros.
+    assert_eq!(parse(b"0e-1000"), (false, 0, -398));
+    assert_eq!(parse(b"0e1000"), (false, 0, 369));
+    assert_eq!(parse(b"1e384"), (false, 1_000_000_000_000_000, 369));
+}
+
+#[test]
+fn parse_bits_test() {
+    let parse = |x: &[u8]| decimal128::from_lexical(x).unwrap().to_bits();
+    assert_eq!(parse(b"1"), 0x30400000000000000000000000000001);
+    assert_eq!(parse(b"-1.50"), 0xB03C0000000000000000000000000096);
+    assert_eq!(parse(b"1e-6176"), 1);
+    assert_eq!(parse(b"1234567890123456789012345678901234"), 0x30403CDE6FFF9732DE825CD07E96AFF2);
+    let max = b"9999999999999999999999999999999999e6111";
+    assert_eq!(parse(max), 0x5FFFED09BEAD87C0378D8E63FFFFFFFF);
+    assert_eq!(decimal32::from_lexical(b"1.000").unwrap().to_bits(), 0x310003E8);
+    assert_eq!(decimal32::from_lexical(b"1e96").unwrap().to_bits(), 0x5F8F4240);
+}
+
+#[test]
+fn parse_special_test() {
+    assert!(decimal32::from_lexical(b"NaN").unwrap().is_nan());
+    assert!(decimal64::from_lexical(b"-NaN").unwrap().is_sign_negative());
+    assert_eq!(decimal128::from_lexical(b"inf").unwrap().to_bits(), decimal128::INFINITY.to_bits());
+    let value = decimal128::from_lexical(b"-Infinity").unwrap();
+    assert_eq!(value.to_bits(), decimal128::NEG_INFINITY.to_bits());
+}
+
+#[test]
+fn parse_error_test() {
+    assert_eq!(decimal64::from_lexical(b"").map(|x| x.to_bits()), Err(Error::Empty(0)));
+    let result = decimal64::from_lexical(b"1.0x").map(|x| x.to_bits());
+    assert_eq!(result, Err(Error::InvalidDigit(3)));
+    let (value, count) = decimal64::from_lexical_partial(b"1.50x").unwrap();
+    assert_eq!((value.coefficient(), value.exponent(), count), (150, -2, 4));
+
+    let options = Options::builder().error_on_out_of_range(true).build().unwrap();
+    let parse = |x| {
+        let result = decimal32::from_lexical_with_options::<FORMAT>(x, &options);
+        result.map(|x| x.to_bits())
+    };
+    assert_eq!(parse(b"1e97"), Err(Error::Overflow(0)));
+    assert_eq!(parse(b"1e-102"), Err(Error::Underflow(0)));
+    assert_eq!(parse(b"0e-102"), Ok(0));
+}
+
+#[test]
+fn parse_round_mode_test() {
+    // Digits beyond the precision are rounded.
+    let (low, high) = (0x31E462D53C8ABAC0, 0x31E462D53C8ABAC1);
+    check_modes::<decimal64>(b"12345678901234567", high, high, low, high);
+    check_modes::<decimal64>(b"12345678901234565", low, high, low, high);
+    let (low, high) = (0x332462D53C8ABAC0, 0x332462D53C8ABAC1);
+    check_modes::<decimal64>(b"123456789012345650000000001", high, high, low, high);
+
+    // Overflow rounds to infinity, or the largest finite value.
+    let (max, inf) = (0x77F8967F, 0x78000000);
+    check_modes::<decimal32>(b"1e97", inf, inf, max, inf);
+    check_modes::<decimal32>(b"9999999e90", max, max, max, max);
+    check_modes::<decimal32>(b"99999995e89", inf, inf, max, inf);
+
+    // Subnormals are rounded to the smallest quantum.
+    check_modes::<decimal32>(b"5e-102", 0, 1, 0, 1);
+    check_modes::<decimal32>(b"6e-102", 1, 1, 0, 1);
+    check_modes::<decimal32>(b"1e-102", 0, 0, 0, 1);
+    check_modes::<decimal32>(b"15e-102", 2, 2, 1, 2);
+}
@@ -0,0 +1,141 @@
+use lexical_parse_float::decompose::{decompose, decompose_partial};
+use lexical_parse_float::{FromLexicalWithOptions, Options};
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use proptest::prelude::*;
+
+#[test]
+fn decompose_test() {
+    let options = Options::new();
+    let number = decompose::<STANDARD>(b"-12.50e3", &options).unwrap();
+    assert!(number.is_negative());
+    assert_eq!(number.integer(), b"12");
+    assert_eq!(number.fraction(), Some(&b"50"[..]));
+    assert_eq!(number.exponent(), 3);
+
+    let number = decompose::<STANDARD>(b"+7", &options).unwrap();
+    assert!(!number.is_negative());
+    assert_eq!(number.integer(), b"7");
+    assert_eq!(number.fraction(), None);
+    assert_eq!(number.exponent(), 0);
+
+    let number = decompose::<STANDARD>(b".5E-10", &options).unwrap();
+    assert_eq!(number.integer(), b"");
+    assert_eq!(number.fraction(), Some(&b"5"[..]));
+    assert_eq!(number.exponent(), -10);
+
+    let number = decompose::<STANDARD>(b"1.", &options).unwrap();
+    assert_eq!(number.fraction(), Some(&b""[..]));
+
+    // Many digits keep all the digits.
+    let digits = b"123456789012345678901234567890.123456789012345678901234567890";
+    let number = decompose::<STANDARD>(digits, &options).unwrap();
+    assert_eq!(number.integer(), &digits[..30]);
+    assert_eq!(number.fraction(), Some(&digits[31..]));
+
+    assert_eq!(decompose::<STANDARD>(b"", &options), Err(Error::Empty(0)));
+    assert_eq!(decompose::<STANDARD>(b"-", &options), Err(Error::Empty(1)));
+    assert_eq!(decompose::<STANDARD>(b"1.5 ", &options), Err(Error::InvalidDigit(3)));
+    assert_eq!(decompose::<STANDARD>(b"1e", &options), Err(Error::EmptyExponent(2)));
+    assert_eq!(decompose::<STANDARD>(b".", &options), Err(Error::EmptyMantissa(1)));
+    assert!(decompose::<STANDARD>(b"NaN", &options).is_err());
+    assert!(decompose::<STANDARD>(b"inf", &options).is_err());
+}
+
+#[test]
+fn decompose_partial_test() {
+    let options = Options::new();
+    let (number, count) = decompose_partial::<STANDARD>(b"1.5e+3,2", &options).unwrap();
+    assert_eq!(count, 6);
+    assert_eq!(number.integer(), b"1");
+    assert_eq!(number.fraction(), Some(&b"5"[..]));
+    assert_eq!(number.exponent(), 3);
+
+    assert_eq!(decompose_partial::<STANDARD>(b"-3e", &options), Err(Error::EmptyExponent(3)));
+}
+
+#[test]
+fn options_test() {
+    let options = Options::builder().decimal_point(b',').exponent(b'^').build().unwrap();
+    let number = decompose::<STANDARD>(b"1,25^-2", &options).unwrap();
+    assert_eq!(number.integer(), b"1");
+    assert_eq!(number.fraction(), Some(&b"25"[..]));
+    assert_eq!(number.exponent(), -2);
+
+    // SAFETY: safe, since the invalid options are never used to parse.
+    let options = unsafe { Options::builder().decimal_point(b'e').build_unchecked() };
+    assert_eq!(decompose::<STANDARD>(b"1", &options), Err(Error::InvalidPunctuation));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_separator_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+
+    let options = Options::new();
+    let number = decompose::<FORMAT>(b"1_000.2_5e1_0", &options).unwrap();
+    assert_eq!(number.integer(), b"1_000");
+    assert_eq!(number.fraction(), Some(&b"2_5"[..]));
+    assert_eq!(number.exponent(), 10);
+    assert_eq!(number.integer_digits().collect::<Vec<_>>(), b"1000");
+    assert_eq!(number.fraction_digits().collect::<Vec<_>>(), b"25");
+
+    let digits = b"1_2_3_4_5_6_7_8_9_0_1_2_3_4_5_6_7_8_9_0_1_2_3_4_5";
+    let number = decompose::<FORMAT>(digits, &options).unwrap();
+    assert_eq!(number.integer(), &digits[..]);
+    assert_eq!(number.integer_digits().count(), 25);
+
+    assert!(decompose::<FORMAT>(b"10_", &options).is_err());
+    assert_eq!(decompose::<FORMAT>(b"_10", &options), Err(Error::EmptyMantissa(0)));
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn base_prefix_suffix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .base_prefix(std::num::NonZeroU8::new(b'x'))
+        .base_suffix(std::num::NonZeroU8::new(b'h'))
+        .build();
+
+    let options = Options::new();
+    let number = decompose::<FORMAT>(b"-0x12.5e3h", &options).unwrap();
+    assert!(number.is_negative());
+    assert_eq!(number.integer(), b"12");
+    assert_eq!(number.fraction(), Some(&b"5"[..]));
+    assert_eq!(number.exponent(), 3);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn invalid_format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'1'))
+        .internal_digit_separator(true)
+        .build();
+    let options = Options::new();
+    assert_eq!(decompose::<FORMAT>(b"1", &options), Err(Error::InvalidDigitSeparator));
+}
+
+/// Rebuild the float from the decomposed number.
+fn recompose(input: &[u8]) -> Option<f64> {
+    let number = decompose::<STANDARD>(input, &Options::new()).ok()?;
+    let integer: String = number.integer_digits().map(char::from).collect();
+    let fraction: String = number.fraction_digits().map(char::from).collect();
+    let sign = if number.is_negative() { "-" } else { "" };
+    let string = format!("{}{}.{}e{}", sign, integer, fraction, number.exponent());
+    string.parse().ok()
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn decompose_proptest(i in r"[+-]?[0-9]{0,30}(\.[0-9]{0,30})?([eE][+-]?[0-9]{1,3})?") {
+        let expected = f64::from_lexical_with_options::<STANDARD>(i.as_bytes(), &Options::new());
+        prop_assert_eq!(recompose(i.as_bytes()), expected.ok());
+    }
+}
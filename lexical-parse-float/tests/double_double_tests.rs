@@ -0,0 +1,123 @@
+#![cfg(feature = "double-double")]
+
+use lexical_parse_float::{
+    DoubleDouble,
+    FromLexical,
+    FromLexicalWithOptions,
+    Options,
+    RoundMode,
+};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+
+const FORMAT: u128 = STANDARD;
+
+fn parse(bytes: &[u8]) -> (u64, u64) {
+    let value = DoubleDouble::from_lexical(bytes).unwrap();
+    (value.hi().to_bits(), value.lo().to_bits())
+}
+
+fn parse_mode(bytes: &[u8], round_mode: RoundMode) -> (u64, u64) {
+    let options = Options::builder().round_mode(round_mode).build().unwrap();
+    let value = DoubleDouble::from_lexical_with_options::<FORMAT>(bytes, &options).unwrap();
+    (value.hi().to_bits(), value.lo().to_bits())
+}
+
+#[test]
+fn parse_test() {
+    assert_eq!(parse(b"1"), (0x3FF0000000000000, 0));
+    assert_eq!(parse(b"0.1"), (0x3FB999999999999A, 0xBC5999999999999A));
+    assert_eq!(parse(b"-0.1"), (0xBFB999999999999A, 0x3C5999999999999A));
+    assert_eq!(parse(b"0.3"), (0x3FD3333333333333, 0x3C6999999999999A));
+    assert_eq!(
+        parse(b"3.141592653589793238462643383279502884"),
+        (0x400921FB54442D18, 0x3CA1A62633145C07)
+    );
+    assert_eq!(parse(b"1e23"), (0x44B52D02C7E14AF6, 0x4160000000000000));
+    assert_eq!(
+        parse(b"123456789012345678901234567890"),
+        (0x45F8EE90FF6C373E, 0x426DC9C7E15A4000)
+    );
+    assert_eq!(parse(b"1e300"), (0x7E37E43C8800759C, 0xFAD698FDC7ACE0CA));
+    assert_eq!(parse(b"1.7976931348623157e308"), (0x7FEFFFFFFFFFFFFF, 0xFC54E53663A912B6));
+}
+
+#[test]
+fn parse_denormal_test() {
+    // The residual of denormal floats is below the smallest float.
+    assert_eq!(parse(b"2.2250738585072014e-308"), (0x10000000000000, 0));
+    assert_eq!(parse(b"1e-310").0, 0x12688B70E62B);
+    assert_eq!(DoubleDouble::from_lexical(b"1e-310").unwrap().lo(), 0.0);
+    assert_eq!(parse(b"4.9e-324").0, 1);
+    assert_eq!(DoubleDouble::from_lexical(b"4.9e-324").unwrap().lo(), 0.0);
+    assert_eq!(parse(b"1e-400"), (0, 0));
+}
+
+#[test]
+fn parse_special_test() {
+    let parse = |x: &[u8]| DoubleDouble::from_lexical(x).unwrap();
+    assert!(parse(b"NaN").hi().is_nan());
+    assert_eq!(parse(b"NaN").lo(), 0.0);
+    assert_eq!(parse(b"inf"), DoubleDouble::new(f64::INFINITY, 0.0));
+    assert_eq!(parse(b"-inf"), DoubleDouble::new(f64::NEG_INFINITY, 0.0));
+    assert_eq!(parse(b"1e400"), DoubleDouble::new(f64::INFINITY, 0.0));
+    assert_eq!(parse(b"-0.0").hi().to_bits(), 0x8000000000000000);
+
+    let options = Options::builder().error_on_out_of_range(true).build().unwrap();
+    let parse = |x| DoubleDouble::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b"1e400"), Err(Error::Overflow(0)));
+    assert_eq!(parse(b"1e-400"), Err(Error::Underflow(0)));
+    assert_eq!(parse(b"1e-330"), Err(Error::Underflow(0)));
+    assert_eq!(parse(b"0e-400"), Ok(DoubleDouble::new(0.0, 0.0)));
+    assert_eq!(parse(b"1e-320").map(|x| x.hi().to_bits()), Ok(0x7E8));
+}
+
+#[test]
+fn parse_partial_test() {
+    let parse = |x: &[u8]| DoubleDouble::from_lexical_partial(x).unwrap();
+    let value = DoubleDouble::new(0.1, -5.551115123125783e-18);
+    assert_eq!(parse(b"0.1"), (value, 3));
+    assert_eq!(parse(b"0.1 + 2"), (value, 3));
+    assert_eq!(parse(b"-inf,"), (DoubleDouble::new(f64::NEG_INFINITY, 0.0), 4));
+    assert_eq!(DoubleDouble::from_lexical(b"0.1 "), Err(Error::InvalidDigit(3)));
+    assert_eq!(DoubleDouble::from_lexical(b""), Err(Error::Empty(0)));
+}
+
+#[test]
+fn parse_round_mode_test() {
+    // The high float is always the nearest, and the residual is rounded.
+    let nearest = (0x3FB999999999999A, 0xBC5999999999999A);
+    let truncated = (0x3FB999999999999A, 0xBC59999999999999);
+    assert_eq!(parse_mode(b"0.1", RoundMode::NearestTiesEven), nearest);
+    assert_eq!(parse_mode(b"0.1", RoundMode::NearestTiesAway), nearest);
+    assert_eq!(parse_mode(b"0.1", RoundMode::TowardZero), truncated);
+    assert_eq!(parse_mode(b"0.1", RoundMode::TowardPositive), truncated);
+
+    // The exact halfway point between `2^-60` and the next float.
+    let halfway = b"1.00000000000000000086736173798840364350245946005774602193952212924636592690508241076940976199693977832794189453125";
+    let lower = (0x3FF0000000000000, 0x3C30000000000000);
+    let upper = (0x3FF0000000000000, 0x3C30000000000001);
+    assert_eq!(parse_mode(halfway, RoundMode::NearestTiesEven), lower);
+    assert_eq!(parse_mode(halfway, RoundMode::NearestTiesAway), upper);
+    assert_eq!(parse_mode(halfway, RoundMode::TowardZero), lower);
+    assert_eq!(parse_mode(halfway, RoundMode::TowardPositive), upper);
+    let mut above = halfway.to_vec();
+    above.push(b'1');
+    assert_eq!(parse_mode(&above, RoundMode::NearestTiesEven), upper);
+}
+
+#[test]
+fn parse_truncated_test() {
+    // Digits past the maximum are truncated, but still round the residual.
+    let mut digits = b"1.".to_vec();
+    digits.extend_from_slice(&[b'0'; 1500]);
+    digits.push(b'1');
+    assert_eq!(parse_mode(&digits, RoundMode::NearestTiesEven), (0x3FF0000000000000, 0));
+    assert_eq!(parse_mode(&digits, RoundMode::TowardZero), (0x3FF0000000000000, 0));
+    assert_eq!(parse_mode(&digits, RoundMode::TowardPositive), (0x3FF0000000000000, 1));
+
+    let mut digits = b"0.1".to_vec();
+    digits.extend_from_slice(&[b'0'; 1500]);
+    digits.push(b'1');
+    assert_eq!(parse(&digits), (0x3FB999999999999A, 0xBC5999999999999A));
+}
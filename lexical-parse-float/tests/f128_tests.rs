@@ -0,0 +1,150 @@
+#![cfg(feature = "f128")]
+
+use lexical_parse_float::{f128, FromLexical, FromLexicalWithOptions, Options, RoundMode};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+use proptest::prelude::*;
+
+const FORMAT: u128 = STANDARD;
+
+fn parse_bits(bytes: &[u8], round_mode: RoundMode) -> u128 {
+    let options = Options::builder().round_mode(round_mode).build().unwrap();
+    f128::from_lexical_with_options::<FORMAT>(bytes, &options).unwrap().to_bits()
+}
+
+fn check_modes(bytes: &[u8], even: u128, away: u128, down: u128, up: u128) {
+    assert_eq!(parse_bits(bytes, RoundMode::NearestTiesEven), even);
+    assert_eq!(parse_bits(bytes, RoundMode::NearestTiesAway), away);
+    assert_eq!(parse_bits(bytes, RoundMode::TowardZero), down);
+    assert_eq!(parse_bits(bytes, RoundMode::TowardPositive), up);
+    assert_eq!(parse_bits(bytes, RoundMode::TowardNegative), down);
+
+    // Negative values round in the opposite direction.
+    let mut negative = b"-".to_vec();
+    negative.extend_from_slice(bytes);
+    let sign = f128::SIGN_MASK;
+    assert_eq!(parse_bits(&negative, RoundMode::NearestTiesEven), even | sign);
+    assert_eq!(parse_bits(&negative, RoundMode::TowardNegative), up | sign);
+    assert_eq!(parse_bits(&negative, RoundMode::TowardPositive), down | sign);
+}
+
+#[test]
+fn parse_simple_test() {
+    let parse = |x: &[u8]| f128::from_lexical(x).unwrap().to_bits();
+    assert_eq!(parse(b"0"), 0);
+    assert_eq!(parse(b"-0.0"), 1 << 127);
+    assert_eq!(parse(b"1"), 0x3FFF << 112);
+    assert_eq!(parse(b"1.0e0"), 0x3FFF << 112);
+    assert_eq!(parse(b"2"), 0x4000 << 112);
+    assert_eq!(parse(b"-0.5"), (1 << 127) | (0x3FFE << 112));
+    assert_eq!(parse(b"0.1"), 0x3FFB999999999999999999999999999A);
+    assert_eq!(parse(b"123456789012345678901234567890"), 0x405F8EE90FF6C373E0EE4E3F0AD20000);
+    let pi = b"3.141592653589793238462643383279502884197";
+    assert_eq!(parse(pi), 0x4000921FB54442D18469898CC51701B8);
+    assert_eq!(parse(b"1e4932"), 0x7FFEAE596552B8FDED99D037E3D04B75);
+    assert_eq!(parse(b"1e-4000"), 0x0C17387AE70C9E700B8049732D11A23D);
+
+    // Extremes.
+    let max = 0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF;
+    assert_eq!(parse(b"1.18973149535723176508575932662800702e4932"), max);
+    assert_eq!(parse(b"1.2e4932"), f128::INFINITY_BITS);
+    assert_eq!(parse(b"6.475175119438025110924438958227646552e-4966"), 1);
+    assert_eq!(parse(b"3.3e-4966"), 1);
+    assert_eq!(parse(b"3.2e-4966"), 0);
+    assert_eq!(parse(b"1e-5000"), 0);
+}
+
+#[test]
+fn parse_special_test() {
+    assert!(f128::from_lexical(b"NaN").unwrap().is_nan());
+    assert!(f128::from_lexical(b"inf").unwrap().is_inf());
+    assert!(f128::from_lexical(b"-Infinity").unwrap().is_inf());
+    assert_eq!(f128::from_lexical(b"-inf").unwrap().to_bits(), f128::NEG_INFINITY.to_bits());
+}
+
+#[test]
+fn parse_error_test() {
+    assert_eq!(f128::from_lexical(b""), Err(Error::Empty(0)));
+    assert_eq!(f128::from_lexical(b"1.0x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(f128::from_lexical(b"1e"), Err(Error::EmptyExponent(2)));
+    let (value, count) = f128::from_lexical_partial(b"1.5x").unwrap();
+    assert_eq!((value.to_bits(), count), ((0x3FFF << 112) | (1 << 111), 3));
+
+    let options = Options::builder().error_on_out_of_range(true).build().unwrap();
+    let parse = |x| f128::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b"1e5000"), Err(Error::Overflow(0)));
+    assert_eq!(parse(b"1e-5000"), Err(Error::Underflow(0)));
+    assert!(parse(b"inf").unwrap().is_inf());
+    assert_eq!(parse(b"0").unwrap().to_bits(), 0);
+}
+
+#[test]
+fn parse_round_mode_test() {
+    let max = 0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF;
+    let inf = f128::INFINITY_BITS;
+    let tenth = 0x3FFB999999999999999999999999999A;
+    check_modes(b"0.1", tenth, tenth, tenth - 1, tenth);
+    let large = 0x7FFEAE596552B8FDED99D037E3D04B75;
+    check_modes(b"1e4932", large, large, large - 1, large);
+    check_modes(b"1.18973149535723176508575932662800702e4932", max, max, max, inf);
+    check_modes(b"1.2e4932", inf, inf, max, inf);
+    check_modes(b"1e-5000", 0, 0, 0, 1);
+}
+
+#[test]
+fn parse_halfway_test() {
+    // Halfway between 1 and the next float, and just above it.
+    let one = 0x3FFF << 112;
+    let halfway = "1.0000000000000000000000000000000000962964972193617926527988971292463659\
+                   2690508241076940976199693977832794189453125";
+    check_modes(halfway.as_bytes(), one, one + 1, one, one + 1);
+    let above = format!("{}0000000001", halfway);
+    check_modes(above.as_bytes(), one + 1, one + 1, one, one + 1);
+    let below = format!("{}4999999999", &halfway[..halfway.len() - 1]);
+    check_modes(below.as_bytes(), one, one, one, one + 1);
+
+    // Halfway between 1 + 1 ulp and 1 + 2 ulp rounds up to the even float.
+    let halfway = "1.0000000000000000000000000000000002888894916580853779583966913877390977\
+                   8071524723230822928599081933498382568359375";
+    check_modes(halfway.as_bytes(), one + 2, one + 2, one + 1, one + 2);
+
+    // Just above halfway to the smallest subnormal, differing after 200 digits.
+    let above = "3.2375875597190125554622194791138232762497846690173405048449421945985197\
+                 7006205968550883574563832497012793907073842405983829360994319127102334\
+                 25550359863089915213963553756674672083673128192358701197243e-4966";
+    check_modes(above.as_bytes(), 1, 1, 0, 1);
+    check_modes(b"3.2375875597190125554622194791138232762e-4966", 0, 0, 0, 1);
+    check_modes(b"3.2375875597190125554622194791138232763e-4966", 1, 1, 0, 1);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn parse_radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+    let options = Options::builder().exponent(b'^').build().unwrap();
+    let parse_binary = |x| f128::from_lexical_with_options::<BINARY>(x, &options).unwrap();
+    let parse_base36 = |x| f128::from_lexical_with_options::<BASE36>(x, &options).unwrap();
+    assert_eq!(parse_binary(b"1.1").to_bits(), (0x3FFF << 112) | (1 << 111));
+    assert_eq!(parse_binary(b"1^-100000001101110").to_bits(), 1);
+    assert_eq!(parse_base36(b"YA").to_bits(), 0x40093480000000000000000000000000);
+    assert_eq!(parse_base36(b"0.I").to_bits(), 0x3FFE << 112);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_exact_proptest(x in u64::MIN..u64::MAX) {
+        // The exact decimal representation of every f64 is an f128.
+        let f = f64::from_bits(x);
+        if !f.is_nan() && !f.is_inf() {
+            let string = format!("{:.800e}", f);
+            let value = f128::from_lexical(string.as_bytes()).unwrap();
+            prop_assert_eq!(value.to_bits(), f128::from_f64(f).to_bits());
+        }
+    }
+
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "f16")]
+
+use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options, RoundMode};
+use lexical_util::bf16::bf16;
+use lexical_util::f16::f16;
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+
+const FORMAT: u128 = STANDARD;
+
+/// Get the exact value of the float bits as an `f64`.
+///
+/// The bits past the largest finite float are treated as a normal float,
+/// so the infinity bits give the value that overflows.
+fn to_f64<F: Float>(bits: u16) -> f64 {
+    let mantissa = bits as u64 & ((1 << F::MANTISSA_SIZE) - 1);
+    let biased = (bits >> F::MANTISSA_SIZE) as i32;
+    let (mantissa, exponent) = match biased {
+        0 => (mantissa, F::DENORMAL_EXPONENT),
+        _ => (mantissa | (1 << F::MANTISSA_SIZE), biased - F::EXPONENT_BIAS),
+    };
+    mantissa as f64 * 2f64.powi(exponent)
+}
+
+/// Get the digits and exponent of the exact halfway point between two floats.
+///
+/// The halfway point is exact as an `f64`, and is written exactly, with
+/// enough trailing zeros to add or subtract a digit past the halfway point.
+fn halfway<F: Float>(bits: u16) -> (Vec<u8>, String) {
+    let halfway = (to_f64::<F>(bits) + to_f64::<F>(bits + 1)) / 2.0;
+    let string = format!("{:.200e}", halfway);
+    let index = string.find('e').unwrap();
+    (string.as_bytes()[..index].to_vec(), string[index..].to_string())
+}
+
+/// Subtract 1 from the last digit of the halfway point.
+fn below(mut digits: Vec<u8>) -> Vec<u8> {
+    for digit in digits.iter_mut().rev().filter(|c| **c != b'.') {
+        if *digit == b'0' {
+            *digit = b'9';
+        } else {
+            *digit -= 1;
+            break;
+        }
+    }
+    digits
+}
+
+/// Add 1 to the last digit of the halfway point.
+fn above(mut digits: Vec<u8>) -> Vec<u8> {
+    *digits.last_mut().unwrap() += 1;
+    digits
+}
+
+fn check_halfway<F>(bits: u16)
+where
+    F: Float<Unsigned = u16> + FromLexicalWithOptions<Options = Options>,
+{
+    let parse = |digits: &[u8], exponent: &str, round_mode: RoundMode| {
+        let mut bytes = digits.to_vec();
+        bytes.extend_from_slice(exponent.as_bytes());
+        let options = Options::builder().round_mode(round_mode).build().unwrap();
+        let float = F::from_lexical_with_options::<FORMAT>(&bytes, &options).unwrap();
+        float.to_bits()
+    };
+    let (digits, exponent) = halfway::<F>(bits);
+    let even = bits + (bits & 1);
+    let message = format!("{:#X}", bits);
+    assert_eq!(parse(&digits, &exponent, RoundMode::NearestTiesEven), even, "{}", message);
+    assert_eq!(parse(&digits, &exponent, RoundMode::NearestTiesAway), bits + 1, "{}", message);
+    assert_eq!(parse(&digits, &exponent, RoundMode::TowardZero), bits, "{}", message);
+    assert_eq!(parse(&digits, &exponent, RoundMode::TowardPositive), bits + 1, "{}", message);
+
+    // Values just below and above the halfway point are not ties.
+    let below = below(digits.clone());
+    assert_eq!(parse(&below, &exponent, RoundMode::NearestTiesEven), bits, "{}", message);
+    let above = above(digits);
+    assert_eq!(parse(&above, &exponent, RoundMode::NearestTiesEven), bits + 1, "{}", message);
+}
+
+#[test]
+fn f16_parse_test() {
+    let parse = |x: &[u8]| f16::from_lexical(x).unwrap().to_bits();
+    assert_eq!(parse(b"0"), 0);
+    assert_eq!(parse(b"-0.0"), 0x8000);
+    assert_eq!(parse(b"1"), 0x3C00);
+    assert_eq!(parse(b"0.1"), 0x2E66);
+    assert_eq!(parse(b"3.14"), 0x4248);
+    assert_eq!(parse(b"65504"), 0x7BFF);
+    assert_eq!(parse(b"65519.99"), 0x7BFF);
+    assert_eq!(parse(b"65520"), 0x7C00);
+    assert_eq!(parse(b"6e-8"), 0x0001);
+    assert_eq!(parse(b"2.98e-8"), 0);
+    assert_eq!(parse(b"2.99e-8"), 0x0001);
+    assert_eq!(parse(b"6.104e-5"), 0x0400);
+}
+
+#[test]
+fn bf16_parse_test() {
+    let parse = |x: &[u8]| bf16::from_lexical(x).unwrap().to_bits();
+    assert_eq!(parse(b"0"), 0);
+    assert_eq!(parse(b"1"), 0x3F80);
+    assert_eq!(parse(b"0.1"), 0x3DCD);
+    assert_eq!(parse(b"3.14"), 0x4049);
+    assert_eq!(parse(b"3.39e38"), 0x7F7F);
+    assert_eq!(parse(b"3.4e38"), 0x7F80);
+    assert_eq!(parse(b"1e-40"), 0x0001);
+    assert_eq!(parse(b"1.18e-38"), 0x0080);
+}
+
+#[test]
+fn double_rounding_test() {
+    // Rounding to f32 first gives the halfway point, which rounds down to even.
+    assert_eq!(f16::from_lexical(b"2049.0000001").unwrap().to_bits(), 0x6801);
+    assert_eq!(f16::from_lexical(b"1.00048828126").unwrap().to_bits(), 0x3C01);
+    assert_eq!(bf16::from_lexical(b"257.000001").unwrap().to_bits(), 0x4381);
+    assert_eq!(bf16::from_lexical(b"1.00390625001").unwrap().to_bits(), 0x3F81);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn f16_halfway_test() {
+    // Includes the halfway point between the largest float and infinity.
+    for bits in 0..f16::INFINITY.to_bits() {
+        check_halfway::<f16>(bits);
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn bf16_halfway_test() {
+    for bits in 0..bf16::INFINITY.to_bits() {
+        check_halfway::<bf16>(bits);
+    }
+}
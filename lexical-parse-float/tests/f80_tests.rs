@@ -0,0 +1,113 @@
+#![cfg(feature = "f80")]
+
+use lexical_parse_float::{f80, FromLexical, FromLexicalWithOptions, Options, RoundMode};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+use proptest::prelude::*;
+
+const FORMAT: u128 = STANDARD;
+
+fn parse_bits(bytes: &[u8], round_mode: RoundMode) -> u128 {
+    let options = Options::builder().round_mode(round_mode).build().unwrap();
+    f80::from_lexical_with_options::<FORMAT>(bytes, &options).unwrap().to_bits()
+}
+
+fn check_modes(bytes: &[u8], even: u128, away: u128, down: u128, up: u128) {
+    assert_eq!(parse_bits(bytes, RoundMode::NearestTiesEven), even);
+    assert_eq!(parse_bits(bytes, RoundMode::NearestTiesAway), away);
+    assert_eq!(parse_bits(bytes, RoundMode::TowardZero), down);
+    assert_eq!(parse_bits(bytes, RoundMode::TowardPositive), up);
+    assert_eq!(parse_bits(bytes, RoundMode::TowardNegative), down);
+
+    // Negative values round in the opposite direction.
+    let mut negative = b"-".to_vec();
+    negative.extend_from_slice(bytes);
+    let sign = f80::SIGN_MASK;
+    assert_eq!(parse_bits(&negative, RoundMode::NearestTiesEven), even | sign);
+    assert_eq!(parse_bits(&negative, RoundMode::TowardNegative), up | sign);
+    assert_eq!(parse_bits(&negative, RoundMode::TowardPositive), down | sign);
+}
+
+#[test]
+fn parse_simple_test() {
+    let parse = |x: &[u8]| f80::from_lexical(x).unwrap().to_bits();
+    assert_eq!(parse(b"0"), 0);
+    assert_eq!(parse(b"-0.0"), 1 << 79);
+    assert_eq!(parse(b"1"), 0x3FFF8000000000000000);
+    assert_eq!(parse(b"2"), 0x40008000000000000000);
+    assert_eq!(parse(b"-0.5"), 0xBFFE8000000000000000);
+    assert_eq!(parse(b"0.1"), 0x3FFBCCCCCCCCCCCCCCCD);
+    assert_eq!(parse(b"123456789012345678901234567890"), 0x405FC77487FB61B9F077);
+    assert_eq!(parse(b"3.14159265358979323846264338327950288"), 0x4000C90FDAA22168C235);
+    assert_eq!(parse(b"1e4932"), 0x7FFED72CB2A95C7EF6CD);
+    assert_eq!(parse(b"1e-4000"), 0x0C179C3D73864F3805C0);
+
+    // Extremes.
+    assert_eq!(parse(b"1.189731495357231765e4932"), 0x7FFEFFFFFFFFFFFFFFFF);
+    assert_eq!(parse(b"1.19e4932"), f80::INFINITY_BITS);
+    assert_eq!(parse(b"3.3621031431120935063e-4932"), 0x00018000000000000000);
+    assert_eq!(parse(b"3.6451995318824746025e-4951"), 1);
+    assert_eq!(parse(b"1.9e-4951"), 1);
+    assert_eq!(parse(b"1.8e-4951"), 0);
+}
+
+#[test]
+fn parse_special_test() {
+    assert!(f80::from_lexical(b"NaN").unwrap().is_nan());
+    assert!(f80::from_lexical(b"inf").unwrap().is_inf());
+    assert_eq!(f80::from_lexical(b"inf").unwrap().to_bits(), 0x7FFF8000000000000000);
+    assert_eq!(f80::from_lexical(b"-inf").unwrap().to_bits(), f80::NEG_INFINITY.to_bits());
+}
+
+#[test]
+fn parse_error_test() {
+    assert_eq!(f80::from_lexical(b""), Err(Error::Empty(0)));
+    assert_eq!(f80::from_lexical(b"1.0x"), Err(Error::InvalidDigit(3)));
+    let (value, count) = f80::from_lexical_partial(b"1.5x").unwrap();
+    assert_eq!((value.to_bits(), count), (0x3FFFC000000000000000, 3));
+
+    let options = Options::builder().error_on_out_of_range(true).build().unwrap();
+    let parse = |x| f80::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b"1e5000"), Err(Error::Overflow(0)));
+    assert_eq!(parse(b"1e-5000"), Err(Error::Underflow(0)));
+}
+
+#[test]
+fn parse_round_mode_test() {
+    let max = 0x7FFEFFFFFFFFFFFFFFFF;
+    let inf = f80::INFINITY_BITS;
+    let tenth = 0x3FFBCCCCCCCCCCCCCCCD;
+    check_modes(b"0.1", tenth, tenth, tenth - 1, tenth);
+    check_modes(b"1.189731495357231765e4932", max, max, max - 1, max);
+    check_modes(b"1.19e4932", inf, inf, max, inf);
+    check_modes(b"1e-5000", 0, 0, 0, 1);
+
+    // Rounding down to the largest denormal, or up to the smallest normal,
+    // which sets the explicit integer bit.
+    let (denormal, normal) = (0x00007FFFFFFFFFFFFFFF, 0x00018000000000000000);
+    check_modes(b"3.36210314311209350626e-4932", normal, normal, denormal, normal);
+}
+
+#[test]
+fn parse_halfway_test() {
+    // Halfway between 1 and the next float, and the next float and the one after.
+    let one = 0x3FFF8000000000000000;
+    let halfway = b"1.0000000000000000000542101086242752217003726400434970855712890625";
+    check_modes(halfway, one, one + 1, one, one + 1);
+    let halfway = b"1.0000000000000000001626303258728256651011179201304912567138671875";
+    check_modes(halfway, one + 2, one + 2, one + 1, one + 2);
+    let above = b"1.00000000000000000005421010862427522170037264004349708557128906250001";
+    check_modes(above, one + 1, one + 1, one, one + 1);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_exact_proptest(f in f64::MIN..f64::MAX) {
+        // Every f64 is exact as an f80, so the exact decimal digits parse to the same value.
+        let string = format!("{:.800e}", f);
+        let value = f80::from_lexical(string.as_bytes()).unwrap();
+        prop_assert_eq!(value.to_bits(), f80::from_f64(f).to_bits());
+    }
+}
@@ -1,4 +1,6 @@
-use lexical_parse_float::options::{Options, OptionsBuilder};
+use lexical_parse_float::options::{Options, OptionsBuilder, RoundMode};
+use lexical_parse_float::punctuation::{Punctuation, TYPOGRAPHIC};
+use lexical_parse_float::Error;
 
 #[test]
 fn invalid_exponent_test() {
@@ -88,6 +90,45 @@ fn invalid_infinity_test() {
     assert!(builder.is_valid());
 }
 
+#[test]
+fn invalid_imaginary_unit_test() {
+    let mut builder = OptionsBuilder::default();
+    assert_eq!(builder.get_imaginary_unit(), Some("i".as_bytes()));
+    builder = builder.imaginary_unit(Some(b"imag0"));
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_unit(Some(b"i0"));
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_unit(Some(b"E"));
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_unit(Some(b""));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.imaginary_unit(Some(b"im"));
+    assert!(builder.is_valid());
+    builder = builder.imaginary_unit(Some(b"j"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+    builder = builder.imaginary_unit(None);
+    assert!(builder.is_valid());
+}
+
+#[test]
+fn invalid_punctuation_test() {
+    let mut builder = OptionsBuilder::default();
+    assert_eq!(builder.get_punctuation(), Punctuation::new());
+    builder = builder.punctuation(TYPOGRAPHIC);
+    assert!(builder.is_valid());
+    assert_eq!(builder.build().unwrap().punctuation(), TYPOGRAPHIC);
+    builder = builder.punctuation(Punctuation::new().decimal_points(&[b"e"]));
+    assert!(!builder.is_valid());
+    builder = builder.punctuation(Punctuation::new().digit_separators(&[b"."]));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidAlternativePunctuation));
+    builder = builder.decimal_point(b',');
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
+
 #[test]
 fn builder_test() {
     let mut builder = OptionsBuilder::default();
@@ -98,6 +139,8 @@ fn builder_test() {
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
     builder = builder.infinity_string(Some(b"Infiniiiiiity"));
+    builder = builder.round_mode(RoundMode::TowardZero);
+    builder = builder.error_on_out_of_range(true);
 
     assert_eq!(builder.get_lossy(), true);
     assert_eq!(builder.get_exponent(), b'^');
@@ -105,6 +148,8 @@ fn builder_test() {
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
     assert_eq!(builder.get_infinity_string(), Some("Infiniiiiiity".as_bytes()));
+    assert_eq!(builder.get_round_mode(), RoundMode::TowardZero);
+    assert_eq!(builder.get_error_on_out_of_range(), true);
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(unsafe { builder.build_unchecked() }));
@@ -121,6 +166,8 @@ fn options_test() {
         opts.set_nan_string(Some(b"nan"));
         opts.set_inf_string(Some(b"Infinity"));
         opts.set_infinity_string(Some(b"Infiniiiiiity"));
+        opts.set_round_mode(RoundMode::TowardNegative);
+        opts.set_error_on_out_of_range(true);
     }
 
     assert_eq!(opts.lossy(), true);
@@ -129,6 +176,8 @@ fn options_test() {
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
     assert_eq!(opts.inf_string(), Some("Infinity".as_bytes()));
     assert_eq!(opts.infinity_string(), Some("Infiniiiiiity".as_bytes()));
+    assert_eq!(opts.round_mode(), RoundMode::TowardNegative);
+    assert_eq!(opts.error_on_out_of_range(), true);
     assert!(opts.is_valid());
 
     assert_eq!(Options::builder(), OptionsBuilder::new());
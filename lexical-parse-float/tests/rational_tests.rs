@@ -0,0 +1,125 @@
+#![cfg(feature = "rational")]
+
+use lexical_parse_float::rational::MAX_EXPONENT;
+use lexical_parse_float::{FromLexical, Rational};
+#[cfg(feature = "format")]
+use lexical_parse_float::{FromLexicalWithOptions, Options};
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+
+fn parse(bytes: &[u8]) -> Result<Rational, Error> {
+    Rational::from_lexical(bytes)
+}
+
+fn parse_partial(bytes: &[u8]) -> Result<(Rational, usize), Error> {
+    Rational::from_lexical_partial(bytes)
+}
+
+fn rational(is_negative: bool, numerator: u64, denominator: u64) -> Rational {
+    Rational::new(is_negative, vec![numerator], vec![denominator])
+}
+
+#[test]
+fn parse_decimal_test() {
+    assert_eq!(parse(b"0.1"), Ok(rational(false, 1, 10)));
+    assert_eq!(parse(b"0.50"), Ok(rational(false, 1, 2)));
+    assert_eq!(parse(b"1.5e-3"), Ok(rational(false, 3, 2000)));
+    assert_eq!(parse(b"-2"), Ok(rational(true, 2, 1)));
+    assert_eq!(parse(b"-0.0e10"), Ok(Rational::zero()));
+    assert_eq!(parse(b"0e99999"), Ok(Rational::zero()));
+    assert_eq!(parse(b"0.0e-99999"), Ok(Rational::zero()));
+    assert_eq!(parse(b".25"), Ok(rational(false, 1, 4)));
+    assert_eq!(parse(b"12.5e2"), Ok(rational(false, 1250, 1)));
+
+    // Values past the float range are exact.
+    let value = parse(b"1e30").unwrap();
+    assert_eq!(value.numerator(), &[0x4674edea40000000, 0xc9f2c9cd0]);
+    let value = parse(b"1e-400").unwrap();
+    assert_eq!(value.numerator(), &[1]);
+    assert_eq!(value.denominator().len(), 21);
+}
+
+#[test]
+fn parse_literal_test() {
+    assert_eq!(parse(b"3/4"), Ok(rational(false, 3, 4)));
+    assert_eq!(parse(b"-6/8"), Ok(rational(true, 3, 4)));
+    assert_eq!(parse(b"+10/5"), Ok(rational(false, 2, 1)));
+    assert_eq!(parse(b"0/7"), Ok(Rational::zero()));
+    assert_eq!(parse(b"007/010"), Ok(rational(false, 7, 10)));
+    let value = parse(b"36893488147419103232/3").unwrap();
+    assert_eq!(value.numerator(), &[0, 2]);
+    assert_eq!(value.denominator(), &[3]);
+}
+
+#[test]
+fn parse_error_test() {
+    assert_eq!(parse(b""), Err(Error::Empty(0)));
+    assert_eq!(parse(b"3/0"), Err(Error::Zero(2)));
+    assert_eq!(parse(b"3/-4"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse(b"3/+4"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse(b"3/"), Err(Error::Empty(2)));
+    assert_eq!(parse(b"3/4.5"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"3/4e1"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"3/4/5"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1.5/2"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1e2/3"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1x"), Err(Error::InvalidDigit(1)));
+    assert!(parse(b"NaN").is_err());
+    assert!(parse(b"inf").is_err());
+}
+
+#[test]
+fn parse_exponent_test() {
+    let value = format!("1e{}", MAX_EXPONENT);
+    assert!(parse(value.as_bytes()).is_ok());
+    let value = format!("1e{}", MAX_EXPONENT + 1);
+    assert_eq!(parse(value.as_bytes()), Err(Error::Overflow(0)));
+    let value = format!("1e-{}", MAX_EXPONENT + 1);
+    assert_eq!(parse(value.as_bytes()), Err(Error::Underflow(0)));
+}
+
+#[test]
+fn parse_partial_test() {
+    assert_eq!(parse_partial(b"3/4 "), Ok((rational(false, 3, 4), 3)));
+    assert_eq!(parse_partial(b"0.5,1"), Ok((rational(false, 1, 2), 3)));
+
+    // Invalid denominators are not consumed, except for zero.
+    assert_eq!(parse_partial(b"3/x"), Ok((rational(false, 3, 1), 1)));
+    assert_eq!(parse_partial(b"3/-4"), Ok((rational(false, 3, 1), 1)));
+    assert_eq!(parse_partial(b"3/4.5"), Ok((rational(false, 3, 1), 1)));
+    assert_eq!(parse_partial(b"1.5/2"), Ok((rational(false, 3, 2), 3)));
+    assert_eq!(parse_partial(b"3/0"), Err(Error::Zero(2)));
+}
+
+#[test]
+fn parse_units_test() {
+    let units: Vec<u16> = "-6/8".encode_utf16().collect();
+    assert_eq!(Rational::from_lexical_units(&units), Ok(rational(true, 3, 4)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_digit_separator_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+
+    let options = Options::new();
+    let parse = |x| Rational::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b"1_000/2_000"), Ok(rational(false, 1, 2)));
+    assert_eq!(parse(b"-0.2_5"), Ok(rational(true, 1, 4)));
+    assert_eq!(parse(b"1_0/_2"), Err(Error::EmptyMantissa(4)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_sign_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().no_positive_mantissa_sign(true).build();
+
+    let options = Options::new();
+    let parse = |x| Rational::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b"-3/4"), Ok(rational(true, 3, 4)));
+    assert_eq!(parse(b"+3/4"), Err(Error::InvalidPositiveSign(0)));
+}
@@ -0,0 +1,305 @@
+use lexical_parse_float::{FromLexicalWithOptions, Options, RoundMode};
+#[cfg(feature = "power-of-two")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use proptest::prelude::*;
+
+const MODES: [RoundMode; 5] = [
+    RoundMode::NearestTiesEven,
+    RoundMode::NearestTiesAway,
+    RoundMode::TowardZero,
+    RoundMode::TowardPositive,
+    RoundMode::TowardNegative,
+];
+
+fn parse<T, const FORMAT: u128>(string: &str, round_mode: RoundMode) -> T
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    let options = Options::builder().round_mode(round_mode).build().unwrap();
+    T::from_lexical_with_options::<FORMAT>(string.as_bytes(), &options).unwrap()
+}
+
+fn parse_f32(string: &str, round_mode: RoundMode) -> f32 {
+    parse::<f32, STANDARD>(string, round_mode)
+}
+
+fn parse_f64(string: &str, round_mode: RoundMode) -> f64 {
+    parse::<f64, STANDARD>(string, round_mode)
+}
+
+fn parse_lossy_f64(string: &str, round_mode: RoundMode) -> f64 {
+    let options = Options::builder().lossy(true).round_mode(round_mode).build().unwrap();
+    f64::from_lexical_with_options::<STANDARD>(string.as_bytes(), &options).unwrap()
+}
+
+fn next_f32(x: f32) -> f32 {
+    f32::from_bits(x.to_bits() + 1)
+}
+
+fn next_f64(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() + 1)
+}
+
+fn prev_f64(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() - 1)
+}
+
+/// Get the exact decimal representation of a float.
+fn exact(x: f64) -> String {
+    format!("{:.1100e}", x)
+}
+
+/// Decrement the last mantissa digit of the exact representation.
+fn decrement(string: &str) -> String {
+    let (mantissa, exponent) = string.split_at(string.find('e').unwrap());
+    let mut digits = mantissa.as_bytes().to_vec();
+    for digit in digits.iter_mut().rev().filter(|&&mut c| c != b'.') {
+        if *digit == b'0' {
+            *digit = b'9';
+        } else {
+            *digit -= 1;
+            break;
+        }
+    }
+    format!("{}{}", String::from_utf8(digits).unwrap(), exponent)
+}
+
+/// Check the value is between `down` and `up`, and rounds to nearest correctly.
+fn check_f64(string: &str, down: f64, up: f64, nearest: f64) {
+    assert_eq!(parse_f64(string, RoundMode::NearestTiesEven), nearest, "{}", string);
+    assert_eq!(parse_f64(string, RoundMode::NearestTiesAway), nearest, "{}", string);
+    assert_eq!(parse_f64(string, RoundMode::TowardZero), down, "{}", string);
+    assert_eq!(parse_f64(string, RoundMode::TowardNegative), down, "{}", string);
+    assert_eq!(parse_f64(string, RoundMode::TowardPositive), up, "{}", string);
+
+    let negative = format!("-{}", string);
+    assert_eq!(parse_f64(&negative, RoundMode::NearestTiesEven), -nearest);
+    assert_eq!(parse_f64(&negative, RoundMode::NearestTiesAway), -nearest);
+    assert_eq!(parse_f64(&negative, RoundMode::TowardZero), -down);
+    assert_eq!(parse_f64(&negative, RoundMode::TowardNegative), -up);
+    assert_eq!(parse_f64(&negative, RoundMode::TowardPositive), -down);
+}
+
+#[test]
+fn inexact_test() {
+    let tenth = 0.1f64;
+    check_f64("0.1", prev_f64(tenth), tenth, tenth);
+    check_f64("1.1", prev_f64(1.1), 1.1, 1.1);
+    check_f64("1.3e23", 1.3e23, next_f64(1.3e23), 1.3e23);
+    check_f64("4.9e-324", 0.0, 5e-324, 5e-324);
+    check_f64("1.0000000000000000000000000001", 1.0, next_f64(1.0), 1.0);
+    check_f64("0.99999999999999999999999999999", prev_f64(1.0), 1.0, 1.0);
+
+    assert_eq!(parse_f32("0.1", RoundMode::TowardZero), 0.099999994);
+    assert_eq!(parse_f32("0.1", RoundMode::TowardPositive), 0.1);
+    assert_eq!(parse_f32("-0.1", RoundMode::TowardPositive), -0.099999994);
+    assert_eq!(parse_f32("-0.1", RoundMode::TowardNegative), -0.1);
+}
+
+#[test]
+fn exact_test() {
+    for &string in ["0", "0.5", "1.25", "3", "2.5e-1", "16777216", "1e10"].iter() {
+        let expected: f64 = string.parse().unwrap();
+        for &mode in MODES.iter() {
+            assert_eq!(parse_f64(string, mode), expected);
+            assert_eq!(parse_f32(string, mode), expected as f32);
+        }
+    }
+    let strings = [
+        "1e22",
+        "9007199254740992",
+        "1.000000000000000000000000000000",
+        "0.0000152587890625",
+    ];
+    for &string in strings.iter() {
+        let expected: f64 = string.parse().unwrap();
+        for &mode in MODES.iter() {
+            assert_eq!(parse_f64(string, mode), expected);
+        }
+    }
+}
+
+#[test]
+fn halfway_test() {
+    assert_eq!(parse_f64("9007199254740993", RoundMode::NearestTiesEven), 9007199254740992.0);
+    assert_eq!(parse_f64("9007199254740993", RoundMode::NearestTiesAway), 9007199254740994.0);
+    assert_eq!(parse_f64("9007199254740993", RoundMode::TowardZero), 9007199254740992.0);
+    assert_eq!(parse_f64("9007199254740993", RoundMode::TowardPositive), 9007199254740994.0);
+    assert_eq!(parse_f64("-9007199254740993", RoundMode::NearestTiesAway), -9007199254740994.0);
+    assert_eq!(parse_f64("-9007199254740993", RoundMode::TowardPositive), -9007199254740992.0);
+    assert_eq!(parse_f64("1e23", RoundMode::NearestTiesEven), 1e23);
+    assert_eq!(parse_f64("1e23", RoundMode::NearestTiesAway), next_f64(1e23));
+    assert_eq!(parse_f32("16777217", RoundMode::NearestTiesEven), 16777216.0);
+    assert_eq!(parse_f32("16777217", RoundMode::NearestTiesAway), 16777218.0);
+    assert_eq!(parse_f32("16777219", RoundMode::NearestTiesEven), 16777220.0);
+    assert_eq!(parse_f32("16777219", RoundMode::NearestTiesAway), 16777220.0);
+
+    // Half of the smallest denormal float.
+    let half = exact(2f64.powi(-150));
+    assert_eq!(parse_f32(&half, RoundMode::NearestTiesEven), 0.0);
+    assert_eq!(parse_f32(&half, RoundMode::NearestTiesAway), 1e-45);
+    assert_eq!(parse_f32(&half, RoundMode::TowardZero), 0.0);
+    assert_eq!(parse_f32(&half, RoundMode::TowardPositive), 1e-45);
+
+    // Halfway case with many digits, which was previously misrounded.
+    let x = f32::from_bits(202239009);
+    let y = f32::from_bits(202239010);
+    let half = exact((x as f64 + y as f64) / 2.0);
+    assert_eq!(parse_f32(&half, RoundMode::NearestTiesEven), y);
+    assert_eq!(parse_f32(&half, RoundMode::TowardZero), x);
+}
+
+#[test]
+fn overflow_test() {
+    assert_eq!(parse_f64("1e400", RoundMode::NearestTiesEven), f64::INFINITY);
+    assert_eq!(parse_f64("1e400", RoundMode::NearestTiesAway), f64::INFINITY);
+    assert_eq!(parse_f64("1e400", RoundMode::TowardZero), f64::MAX);
+    assert_eq!(parse_f64("1e400", RoundMode::TowardNegative), f64::MAX);
+    assert_eq!(parse_f64("1e400", RoundMode::TowardPositive), f64::INFINITY);
+    assert_eq!(parse_f64("-1e400", RoundMode::TowardZero), -f64::MAX);
+    assert_eq!(parse_f64("-1e400", RoundMode::TowardPositive), -f64::MAX);
+    assert_eq!(parse_f64("-1e400", RoundMode::TowardNegative), f64::NEG_INFINITY);
+    assert_eq!(parse_f64("1.7976931348623158e308", RoundMode::TowardZero), f64::MAX);
+    assert_eq!(parse_f64("1.7976931348623158e308", RoundMode::TowardPositive), f64::INFINITY);
+    assert_eq!(parse_f64("1.7976931348623157e308", RoundMode::TowardPositive), f64::MAX);
+    assert_eq!(parse_f32("3.5e38", RoundMode::TowardZero), f32::MAX);
+    assert_eq!(parse_f32("3.5e38", RoundMode::TowardPositive), f32::INFINITY);
+
+    // Special values are never rounded.
+    assert_eq!(parse_f64("inf", RoundMode::TowardZero), f64::INFINITY);
+}
+
+#[test]
+fn underflow_test() {
+    assert_eq!(parse_f64("1e-400", RoundMode::NearestTiesEven), 0.0);
+    assert_eq!(parse_f64("1e-400", RoundMode::NearestTiesAway), 0.0);
+    assert_eq!(parse_f64("1e-400", RoundMode::TowardZero), 0.0);
+    assert_eq!(parse_f64("1e-400", RoundMode::TowardPositive), 5e-324);
+    assert_eq!(parse_f64("-1e-400", RoundMode::TowardPositive), -0.0);
+    assert_eq!(parse_f64("-1e-400", RoundMode::TowardNegative), -5e-324);
+    assert_eq!(parse_f64("1e-100000", RoundMode::TowardPositive), 5e-324);
+    assert_eq!(parse_f64("0e-100000", RoundMode::TowardPositive), 0.0);
+    assert_eq!(parse_f32("1e-46", RoundMode::TowardPositive), 1e-45);
+    assert_eq!(parse_f32("1e-46", RoundMode::TowardZero), 0.0);
+}
+
+#[test]
+fn lossy_test() {
+    for &mode in MODES.iter() {
+        assert_eq!(parse_lossy_f64("0.5", mode), 0.5);
+        assert_eq!(parse_lossy_f64("1e22", mode), 1e22);
+    }
+
+    let tenth = 0.1f64;
+    assert_eq!(parse_lossy_f64("0.1", RoundMode::TowardZero), prev_f64(tenth));
+    assert_eq!(parse_lossy_f64("0.1", RoundMode::TowardPositive), tenth);
+    assert_eq!(parse_lossy_f64("-0.1", RoundMode::TowardPositive), -prev_f64(tenth));
+    assert_eq!(parse_lossy_f64("-0.1", RoundMode::TowardNegative), -tenth);
+    assert_eq!(parse_lossy_f64("1.3e23", RoundMode::TowardPositive), next_f64(1.3e23));
+    assert_eq!(parse_lossy_f64("1e400", RoundMode::TowardZero), f64::MAX);
+    assert_eq!(parse_lossy_f64("1e400", RoundMode::TowardPositive), f64::INFINITY);
+    assert_eq!(parse_lossy_f64("1e-400", RoundMode::TowardZero), 0.0);
+    assert_eq!(parse_lossy_f64("1e-400", RoundMode::TowardPositive), 5e-324);
+
+    // Without the slow path, truncated digits may round to the adjacent float.
+    let truncated = "1.0000000000000000000000000001";
+    let up = parse_lossy_f64(truncated, RoundMode::TowardPositive);
+    assert!(up == 1.0 || up == next_f64(1.0));
+    assert_eq!(parse_lossy_f64(truncated, RoundMode::TowardZero), 1.0);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn binary_test() {
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let exact = "1.1";
+    let truncated = format!("1.{}1", "0".repeat(80));
+    let halfway = format!("1.{}1", "0".repeat(52));
+    let above = format!("1.{}1{}1", "0".repeat(52), "0".repeat(30));
+    let parse = |string: &str, mode| parse::<f64, BINARY>(string, mode);
+
+    for &mode in MODES.iter() {
+        assert_eq!(parse(exact, mode), 1.5);
+        assert_eq!(parse(&format!("{}{}", exact, "0".repeat(80)), mode), 1.5);
+    }
+    assert_eq!(parse(&truncated, RoundMode::NearestTiesEven), 1.0);
+    assert_eq!(parse(&truncated, RoundMode::TowardZero), 1.0);
+    assert_eq!(parse(&truncated, RoundMode::TowardPositive), next_f64(1.0));
+    assert_eq!(parse(&halfway, RoundMode::NearestTiesEven), 1.0);
+    assert_eq!(parse(&halfway, RoundMode::NearestTiesAway), next_f64(1.0));
+    assert_eq!(parse(&above, RoundMode::NearestTiesEven), next_f64(1.0));
+    assert_eq!(parse(&above, RoundMode::TowardZero), 1.0);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix_test() {
+    const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+    let third = 1.0f64 / 3.0;
+    assert_eq!(parse::<f64, BASE3>("0.1", RoundMode::NearestTiesEven), third);
+    assert_eq!(parse::<f64, BASE3>("0.1", RoundMode::TowardZero), third);
+    assert_eq!(parse::<f64, BASE3>("0.1", RoundMode::TowardPositive), next_f64(third));
+    assert_eq!(parse::<f64, BASE3>("-0.1", RoundMode::TowardNegative), -next_f64(third));
+    assert_eq!(parse::<f64, BASE3>("0.1e1", RoundMode::TowardPositive), 1.0);
+    assert_eq!(parse::<f64, BASE3>("1e1000000000", RoundMode::TowardZero), f64::MAX);
+    assert_eq!(parse::<f64, BASE3>("1e-1000000000", RoundMode::TowardPositive), 5e-324);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f32_proptest(i in r"[0-9]{1,20}(\.[0-9]{1,20})?([eE][+-]?[0-9]{1,2})?") {
+        // Use the correctly rounded double-precision float to bound the value.
+        let x: f64 = i.parse().unwrap();
+        let down = parse_f32(&i, RoundMode::TowardZero);
+        let up = parse_f32(&i, RoundMode::TowardPositive);
+        let nearest = parse_f32(&i, RoundMode::NearestTiesEven);
+        let away = parse_f32(&i, RoundMode::NearestTiesAway);
+        prop_assert!(down as f64 <= x && x <= up as f64);
+        prop_assert_eq!(down, parse_f32(&i, RoundMode::TowardNegative));
+        if x as f32 as f64 != x {
+            prop_assert!(down != up);
+        }
+        if down != up && up.is_finite() {
+            prop_assert_eq!(up, next_f32(down));
+        }
+        prop_assert!(nearest == down || nearest == up);
+        prop_assert!(away == down || away == up);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f32_halfway_proptest(bits in 0u32..0x7F7F_FFFF) {
+        let x = f32::from_bits(bits);
+        let y = next_f32(x);
+        let even = if bits % 2 == 0 { x } else { y };
+        let halfway = exact((x as f64 + y as f64) / 2.0);
+        prop_assert_eq!(parse_f32(&halfway, RoundMode::NearestTiesEven), even);
+        prop_assert_eq!(parse_f32(&halfway, RoundMode::NearestTiesAway), y);
+        prop_assert_eq!(parse_f32(&halfway, RoundMode::TowardZero), x);
+        prop_assert_eq!(parse_f32(&halfway, RoundMode::TowardPositive), y);
+
+        let exact = exact(x as f64);
+        for &mode in MODES.iter() {
+            prop_assert_eq!(parse_f32(&exact, mode), x);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_exact_proptest(bits in 0u64..0x7FEF_FFFF_FFFF_FFFF) {
+        let x = f64::from_bits(bits);
+        let y = next_f64(x);
+        let string = exact(x);
+        for &mode in MODES.iter() {
+            prop_assert_eq!(parse_f64(&string, mode), x);
+        }
+
+        // Slightly above `x`, and slightly below `y`.
+        let above = string.replacen('e', "1e", 1);
+        check_f64(&above, x, y, x);
+        let below = decrement(&exact(y));
+        check_f64(&below, x, y, y);
+    }
+}
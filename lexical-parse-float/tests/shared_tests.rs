@@ -1,7 +1,7 @@
 use lexical_parse_float::float::ExtendedFloat80;
 use lexical_parse_float::shared;
 #[cfg(feature = "power-of-two")]
-use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{NumberFormat, NumberFormatBuilder};
 
 #[test]
 fn calculate_shift_test() {
@@ -16,12 +16,13 @@ fn calculate_shift_test() {
 #[cfg(feature = "power-of-two")]
 fn calculate_power2_test() {
     const BASE4: u128 = NumberFormatBuilder::from_radix(4);
-    assert_eq!(shared::calculate_power2::<f64, BASE4>(-63, 5), 944);
-    assert_eq!(shared::calculate_power2::<f64, BASE4>(-15, 5), 1040);
-    assert_eq!(shared::calculate_power2::<f64, BASE4>(-8, 0), 1059);
-    assert_eq!(shared::calculate_power2::<f64, BASE4>(-8, 5), 1054);
-    assert_eq!(shared::calculate_power2::<f64, BASE4>(0, 5), 1070);
-    assert_eq!(shared::calculate_power2::<f64, BASE4>(50, 5), 1170);
+    let format = NumberFormat::<{ BASE4 }> {};
+    assert_eq!(shared::calculate_power2::<f64, _>(-63, format, 5), 944);
+    assert_eq!(shared::calculate_power2::<f64, _>(-15, format, 5), 1040);
+    assert_eq!(shared::calculate_power2::<f64, _>(-8, format, 0), 1059);
+    assert_eq!(shared::calculate_power2::<f64, _>(-8, format, 5), 1054);
+    assert_eq!(shared::calculate_power2::<f64, _>(0, format, 5), 1070);
+    assert_eq!(shared::calculate_power2::<f64, _>(50, format, 5), 1170);
 }
 
 #[test]
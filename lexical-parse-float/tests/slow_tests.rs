@@ -8,8 +8,9 @@ use lexical_parse_float::bigint::Bigint;
 use lexical_parse_float::float::{ExtendedFloat80, RawFloat};
 use lexical_parse_float::limits::MaxDigits;
 use lexical_parse_float::number::Number;
+use lexical_parse_float::shared::Rounding;
 use lexical_parse_float::slow;
-use lexical_util::format::STANDARD;
+use lexical_util::format::{NumberFormat, STANDARD};
 use stackvec::vec_from_u32;
 
 fn b<F: RawFloat>(float: F) -> (u64, i32) {
@@ -54,6 +55,7 @@ fn bh_test() {
 #[test]
 fn slow_radix_test() {
     const FORMAT: u128 = STANDARD;
+    let format = NumberFormat::<{ FORMAT }> {};
 
     // 5e-324, round-down.
     let mut num = Number {
@@ -68,13 +70,13 @@ fn slow_radix_test() {
         mant: 1 << 63,
         exp: -63,
     };
-    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp);
+    let result = slow::slow_radix::<f64, _>(num, format, fp, Rounding::NearestEven);
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 0);
 
     // 5e-324, round-up.
     num.fraction = Some(b"47032822920623272088284396434110686182529901307162382212792841250337753635104375932649918180817996189898282347722858865463328355177969898199387398005390939063150356595155702263922908583924491051844359318028499365361525003193704576782492193656236698636584807570015857692699037063119282795585513329278343384093519780155312465972635795746227664652728272200563740064854999770965994704540208281662262378573934507363390079677619305775067401763246736009689513405355374585166611342237666786041621596804619144672918403005300575308490487653917113865916462395249126236538818796362393732804238910186723484976682350898633885879256283027559956575244555072551893136908362547791869486679949683240497058210285131854513962138377228261454376934125320985913276672363281251");
-    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp);
+    let result = slow::slow_radix::<f64, _>(num, format, fp, Rounding::NearestEven);
     assert_eq!(result.mant, 1);
     assert_eq!(result.exp, 0);
 
@@ -91,13 +93,13 @@ fn slow_radix_test() {
         mant: 9223372036854776832,
         exp: 2035,
     };
-    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp);
+    let result = slow::slow_radix::<f64, _>(num, format, fp, Rounding::NearestEven);
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 2046);
 
     // 8.988465674311582e+307
     num.fraction = Some(b"98846567431158053656668072130502949627624141313081589739713427561540454154866937524136980060240969353498844031142021255416291053696845311086136572877053658847429381365898442381794745560514296474151486978574387976858590638908514073910088308747655630259515975825139366555781573480200663642101543165321617080321");
-    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp);
+    let result = slow::slow_radix::<f64, _>(num, format, fp, Rounding::NearestEven);
     assert_eq!(result.mant, 1);
     assert_eq!(result.exp, 2046);
 }
@@ -105,6 +107,7 @@ fn slow_radix_test() {
 #[test]
 fn digit_comp_test() {
     const FORMAT: u128 = STANDARD;
+    let format = NumberFormat::<{ FORMAT }> {};
     let max_digits = f64::max_digits(10).unwrap();
 
     // 5e-324, round-down.
@@ -120,7 +123,14 @@ fn digit_comp_test() {
         mant: 1 << 63,
         exp: -63,
     };
-    let result = slow::digit_comp::<f64, FORMAT>(num.clone(), fp, -324, max_digits);
+    let result = slow::digit_comp::<f64, _>(
+        num,
+        format,
+        fp,
+        -324,
+        max_digits,
+        Rounding::NearestEven,
+    );
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 0);
 
@@ -137,7 +147,14 @@ fn digit_comp_test() {
         mant: 1 << 63,
         exp: -62,
     };
-    let result = slow::digit_comp::<f64, FORMAT>(num.clone(), fp, -324, max_digits);
+    let result = slow::digit_comp::<f64, _>(
+        num,
+        format,
+        fp,
+        -324,
+        max_digits,
+        Rounding::NearestEven,
+    );
     assert_eq!(result.mant, 2);
     assert_eq!(result.exp, 0);
 
@@ -154,13 +171,27 @@ fn digit_comp_test() {
         mant: 9223372036854776832,
         exp: 2035,
     };
-    let result = slow::digit_comp::<f64, FORMAT>(num.clone(), fp, 307, max_digits);
+    let result = slow::digit_comp::<f64, _>(
+        num,
+        format,
+        fp,
+        307,
+        max_digits,
+        Rounding::NearestEven,
+    );
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 2046);
 
     // 8.988465674311582e+307
     num.fraction = Some(b"98846567431158053656668072130502949627624141313081589739713427561540454154866937524136980060240969353498844031142021255416291053696845311086136572877053658847429381365898442381794745560514296474151486978574387976858590638908514073910088308747655630259515975825139366555781573480200663642101543165321617080321");
-    let result = slow::digit_comp::<f64, FORMAT>(num.clone(), fp, 307, max_digits);
+    let result = slow::digit_comp::<f64, _>(
+        num,
+        format,
+        fp,
+        307,
+        max_digits,
+        Rounding::NearestEven,
+    );
     assert_eq!(result.mant, 1);
     assert_eq!(result.exp, 2046);
 }
@@ -168,6 +199,7 @@ fn digit_comp_test() {
 #[test]
 fn positive_digit_comp_test() {
     const FORMAT: u128 = STANDARD;
+    let format = NumberFormat::<{ FORMAT }> {};
 
     // 8.98846567431158e+307
     let bigmant = Bigint {
@@ -177,7 +209,8 @@ fn positive_digit_comp_test() {
         ]),
     };
     let exponent = 307 + 1 - 308;
-    let result = slow::positive_digit_comp::<f64, FORMAT>(bigmant, exponent);
+    let result =
+        slow::positive_digit_comp::<f64, _>(bigmant, format, exponent, Rounding::NearestEven);
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 2046);
 
@@ -189,7 +222,8 @@ fn positive_digit_comp_test() {
         ]),
     };
     let exponent = 307 + 1 - 308;
-    let result = slow::positive_digit_comp::<f64, FORMAT>(bigmant, exponent);
+    let result =
+        slow::positive_digit_comp::<f64, _>(bigmant, format, exponent, Rounding::NearestEven);
     assert_eq!(result.mant, 1);
     assert_eq!(result.exp, 2046);
 }
@@ -197,6 +231,7 @@ fn positive_digit_comp_test() {
 #[test]
 fn negative_digit_comp_test() {
     const FORMAT: u128 = STANDARD;
+    let format = NumberFormat::<{ FORMAT }> {};
 
     // 5e-324, below halfway, round-down to 0.0.
     let bigmant = Bigint {
@@ -220,7 +255,13 @@ fn negative_digit_comp_test() {
         exp: -63,
     };
     let exponent = -324 + 1 - 755;
-    let result = slow::negative_digit_comp::<f64, FORMAT>(bigmant, fp, exponent);
+    let result = slow::negative_digit_comp::<f64, _>(
+        bigmant,
+        format,
+        fp,
+        exponent,
+        Rounding::NearestEven,
+    );
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 0);
 
@@ -242,7 +283,13 @@ fn negative_digit_comp_test() {
         ]),
     };
     let exponent = -324 + 1 - 752;
-    let result = slow::negative_digit_comp::<f64, FORMAT>(bigmant, fp, exponent);
+    let result = slow::negative_digit_comp::<f64, _>(
+        bigmant,
+        format,
+        fp,
+        exponent,
+        Rounding::NearestEven,
+    );
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 0);
 
@@ -264,7 +311,13 @@ fn negative_digit_comp_test() {
         ]),
     };
     let exponent = -324 + 1 - 753;
-    let result = slow::negative_digit_comp::<f64, FORMAT>(bigmant, fp, exponent);
+    let result = slow::negative_digit_comp::<f64, _>(
+        bigmant,
+        format,
+        fp,
+        exponent,
+        Rounding::NearestEven,
+    );
     assert_eq!(result.mant, 1);
     assert_eq!(result.exp, 0);
 
@@ -290,7 +343,13 @@ fn negative_digit_comp_test() {
         exp: -62,
     };
     let exponent = -324 + 1 - 755;
-    let result = slow::negative_digit_comp::<f64, FORMAT>(bigmant, fp, exponent);
+    let result = slow::negative_digit_comp::<f64, _>(
+        bigmant,
+        format,
+        fp,
+        exponent,
+        Rounding::NearestEven,
+    );
     assert_eq!(result.mant, 1);
     assert_eq!(result.exp, 0);
 
@@ -312,7 +371,13 @@ fn negative_digit_comp_test() {
         ]),
     };
     let exponent = -324 + 1 - 752;
-    let result = slow::negative_digit_comp::<f64, FORMAT>(bigmant, fp, exponent);
+    let result = slow::negative_digit_comp::<f64, _>(
+        bigmant,
+        format,
+        fp,
+        exponent,
+        Rounding::NearestEven,
+    );
     assert_eq!(result.mant, 2);
     assert_eq!(result.exp, 0);
 
@@ -334,7 +399,13 @@ fn negative_digit_comp_test() {
         ]),
     };
     let exponent = -324 + 1 - 753;
-    let result = slow::negative_digit_comp::<f64, FORMAT>(bigmant, fp, exponent);
+    let result = slow::negative_digit_comp::<f64, _>(
+        bigmant,
+        format,
+        fp,
+        exponent,
+        Rounding::NearestEven,
+    );
     assert_eq!(result.mant, 2);
     assert_eq!(result.exp, 0);
 }
@@ -342,6 +413,7 @@ fn negative_digit_comp_test() {
 #[test]
 fn parse_mantissa_test() {
     const FORMAT: u128 = STANDARD;
+    let format = NumberFormat::<{ FORMAT }> {};
     let max_digits = f64::max_digits(10).unwrap();
 
     // Large number of digits.
@@ -353,7 +425,7 @@ fn parse_mantissa_test() {
         integer: b"2",
         fraction: Some(b"4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328124999"),
     };
-    let (bigmant, count) = slow::parse_mantissa::<FORMAT>(num.clone(), max_digits);
+    let (bigmant, count) = slow::parse_mantissa(num, format, max_digits);
     let expected = vec_from_u32::<100>(&[
         1727738439, 330069557, 3509095598, 686205316, 156923684, 750687444, 2688855918, 28211928,
         1887482096, 3222998811, 913348873, 1652282845, 1600735541, 1664240266, 84454144,
@@ -372,7 +444,7 @@ fn parse_mantissa_test() {
 
     // Leading zeros
     num.integer = b"0000000002";
-    let (bigmant, count) = slow::parse_mantissa::<FORMAT>(num.clone(), max_digits);
+    let (bigmant, count) = slow::parse_mantissa(num, format, max_digits);
     assert_eq!(&*bigmant.data, &*expected);
     assert_eq!(count, 755);
 
@@ -385,7 +457,7 @@ fn parse_mantissa_test() {
         integer: b"7",
         fraction: Some(b"4109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984375332669816033062329967789262837"),
     };
-    let (bigmant, count) = slow::parse_mantissa::<FORMAT>(num.clone(), max_digits);
+    let (bigmant, count) = slow::parse_mantissa(num, format, max_digits);
     let expected = vec_from_u32::<100>(&[
         983641521, 2202462645, 4170685875, 1591772364, 529830014, 803977727, 126733331, 1695971390,
         4089590927, 1532849076, 2705586665, 4046282448, 4076195232, 3230469892, 3059053929,
@@ -405,14 +477,14 @@ fn parse_mantissa_test() {
     // No fraction digits.
     num.integer = b"74109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984375332669816033062329967789262837";
     num.fraction = None;
-    let (bigmant, count) = slow::parse_mantissa::<FORMAT>(num.clone(), max_digits);
+    let (bigmant, count) = slow::parse_mantissa(num, format, max_digits);
     assert_eq!(&*bigmant.data, &*expected);
     assert_eq!(count, max_digits + 1);
 
     // Multiple of step (check we add our temporary correctly).
     num.integer = b"7410984687618698162648531893023320585475897039214871466383785237510132609053131277979497545424539885696948470431685765963899850655339096945981621940161728171894510697854671067917687257517734731555330779540854980960845750095811137303474765809687100959097544227100475730780971111893578483867565399878350301522805593404659373979179073872386829939581848166016912201945649993128979841136206248449867871357218035220901702390328579173252022052897402080290685402160661237554998340267130003581248647904138574340187552090159017259254714629617513415977493871857473787096164563890871811984127167305601704549300470526959016576377688490826798697257336652176556794107250876433756084600398490497214911746308553955635418864151316847843631308023759629577398300170898437533266981";
     num.fraction = None;
-    let (bigmant, count) = slow::parse_mantissa::<FORMAT>(num.clone(), max_digits);
+    let (bigmant, count) = slow::parse_mantissa(num, format, max_digits);
     let expected = vec_from_u32::<100>(&[
         617018405, 396211401, 2130402383, 3812547827, 4263683770, 3918012496, 1787721490,
         2493014694, 435464626, 3720854431, 2928509507, 2677932436, 369049650, 3606588290,
@@ -435,6 +507,7 @@ fn parse_mantissa_test() {
 #[cfg(feature = "radix")]
 fn byte_comp_test() {
     const FORMAT: u128 = STANDARD;
+    let format = NumberFormat::<{ FORMAT }> {};
 
     // 5e-324
     let mut num = Number {
@@ -449,25 +522,25 @@ fn byte_comp_test() {
         mant: 1 << 63,
         exp: -63,
     };
-    let result = slow::byte_comp::<f64, FORMAT>(num.clone(), fp, -324);
+    let result = slow::byte_comp::<f64, _>(num, format, fp, -324, Rounding::NearestEven);
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 0);
 
     // 5e-324, equal, round-down
     num.fraction = Some(b"4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125");
-    let result = slow::byte_comp::<f64, FORMAT>(num.clone(), fp, -324);
+    let result = slow::byte_comp::<f64, _>(num, format, fp, -324, Rounding::NearestEven);
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 0);
 
     // 5e-324, equal, round-down, many 0s
     num.fraction = Some(b"47032822920623272088284396434110686182529901307162382212792841250337753635104375932649918180817996189898282347722858865463328355177969898199387398005390939063150356595155702263922908583924491051844359318028499365361525003193704576782492193656236698636584807570015857692699037063119282795585513329278343384093519780155312465972635795746227664652728272200563740064854999770965994704540208281662262378573934507363390079677619305775067401763246736009689513405355374585166611342237666786041621596804619144672918403005300575308490487653917113865916462395249126236538818796362393732804238910186723484976682350898633885879256283027559956575244555072551893136908362547791869486679949683240497058210285131854513962138377228261454376934125320985913276672363281250000000");
-    let result = slow::byte_comp::<f64, FORMAT>(num.clone(), fp, -324);
+    let result = slow::byte_comp::<f64, _>(num, format, fp, -324, Rounding::NearestEven);
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 0);
 
     // 5e-324, above, round-up
     num.fraction = Some(b"47032822920623272088284396434110686182529901307162382212792841250337753635104375932649918180817996189898282347722858865463328355177969898199387398005390939063150356595155702263922908583924491051844359318028499365361525003193704576782492193656236698636584807570015857692699037063119282795585513329278343384093519780155312465972635795746227664652728272200563740064854999770965994704540208281662262378573934507363390079677619305775067401763246736009689513405355374585166611342237666786041621596804619144672918403005300575308490487653917113865916462395249126236538818796362393732804238910186723484976682350898633885879256283027559956575244555072551893136908362547791869486679949683240497058210285131854513962138377228261454376934125320985913276672363281251");
-    let result = slow::byte_comp::<f64, FORMAT>(num.clone(), fp, -324);
+    let result = slow::byte_comp::<f64, _>(num, format, fp, -324, Rounding::NearestEven);
     assert_eq!(result.mant, 1);
     assert_eq!(result.exp, 0);
 
@@ -484,13 +557,13 @@ fn byte_comp_test() {
         mant: 9223372036854776832,
         exp: 960 + 1075,
     };
-    let result = slow::byte_comp::<f64, FORMAT>(num.clone(), fp, 307);
+    let result = slow::byte_comp::<f64, _>(num, format, fp, 307, Rounding::NearestEven);
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 2046);
 
     // 8.988465674311582e+307
     num.fraction = Some(b"98846567431158053656668072130502949627624141313081589739713427561540454154866937524136980060240969353498844031142021255416291053696845311086136572877053658847429381365898442381794745560514296474151486978574387976858590638908514073910088308747655630259515975825139366555781573480200663642101543165321617080321");
-    let result = slow::byte_comp::<f64, FORMAT>(num.clone(), fp, 307);
+    let result = slow::byte_comp::<f64, _>(num, format, fp, 307, Rounding::NearestEven);
     assert_eq!(result.mant, 1);
     assert_eq!(result.exp, 2046);
 }
@@ -499,6 +572,7 @@ fn byte_comp_test() {
 #[cfg(feature = "radix")]
 fn compare_bytes_test() {
     const FORMAT: u128 = STANDARD;
+    let format = NumberFormat::<{ FORMAT }> {};
 
     // 2^-1074
     let num = Bigfloat {
@@ -527,21 +601,21 @@ fn compare_bytes_test() {
         fraction: Some(b"4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328124999"),
     };
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Less
     );
 
     // Exactly halfway.
     number.fraction = Some(b"4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125");
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Equal
     );
 
     // Above halfway.
     number.fraction = Some(b"4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125001");
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Greater
     );
 
@@ -572,21 +646,21 @@ fn compare_bytes_test() {
         fraction: Some(b"4109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984374999"),
     };
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Less
     );
 
     // Exactly halfway.
     number.fraction = Some(b"4109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984375");
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Equal
     );
 
     // Above halfway.
     number.fraction = Some(b"4109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984375001");
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Greater
     );
 
@@ -617,21 +691,21 @@ fn compare_bytes_test() {
         fraction: Some(b"9884656743115805365666807213050294962762414131308158973971342756154045415486693752413698006024096935349884403114202125541629105369684531108613657287705365884742938136589844238179474556051429647415148697857438797685859063890851407391008830874765563025951597582513936655578157348020066364210154316532161708031999"),
     };
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Less
     );
 
     // Exactly halfway.
     number.fraction = Some(b"9884656743115805365666807213050294962762414131308158973971342756154045415486693752413698006024096935349884403114202125541629105369684531108613657287705365884742938136589844238179474556051429647415148697857438797685859063890851407391008830874765563025951597582513936655578157348020066364210154316532161708032");
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Equal
     );
 
     // Above halfway.
     number.fraction = Some(b"9884656743115805365666807213050294962762414131308158973971342756154045415486693752413698006024096935349884403114202125541629105369684531108613657287705365884742938136589844238179474556051429648741514697857438797685859063890851407391008830874765563025951597582513936655578157348020066364210154316532161708032001");
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Greater
     );
 
@@ -641,7 +715,7 @@ fn compare_bytes_test() {
     number.integer = b"000008";
     number.fraction = Some(b"98846567431158053656668072130502949627624141313081589739713427561540454154866937524136980060240969353498844031142021255416291053696845311086136572877053658847429381365898442381794745560514296474151486978574387976858590638908514073910088308747655630259515975825139366555781573480200663642101543165321617080319990000");
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Less
     );
 
@@ -649,7 +723,7 @@ fn compare_bytes_test() {
     number.integer = b"000008";
     number.fraction = Some(b"98846567431158053656668072130502949627624141313081589739713427561540454154866937524136980060240969353498844031142021255416291053696845311086136572877053658847429381365898442381794745560514296474151486978574387976858590638908514073910088308747655630259515975825139366555781573480200663642101543165321617080320000");
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Equal
     );
 
@@ -657,13 +731,14 @@ fn compare_bytes_test() {
     number.integer = b"000008";
     number.fraction = Some(b"98846567431158053656668072130502949627624141313081589739713427561540454154866937524136980060240969353498844031142021255416291053696845311086136572877053658847429381365898442381794745560514296487415146978574387976858590638908514073910088308747655630259515975825139366555781573480200663642101543165321617080320010000");
     assert_eq!(
-        slow::compare_bytes::<FORMAT>(number.clone(), num.clone(), den.clone()),
+        slow::compare_bytes(number, format, num.clone(), den.clone()),
         cmp::Ordering::Greater
     );
 }
 
 #[test]
 fn scientific_exponent_test() {
+    let format = NumberFormat::<{ STANDARD }> {};
     let mut number = Number {
         exponent: -4,
         mantissa: 12345,
@@ -672,13 +747,13 @@ fn scientific_exponent_test() {
         integer: &[],
         fraction: None,
     };
-    assert_eq!(slow::scientific_exponent::<{ STANDARD }>(&number), 0);
+    assert_eq!(slow::scientific_exponent(&number, format), 0);
 
     number.exponent = -15;
-    assert_eq!(slow::scientific_exponent::<{ STANDARD }>(&number), -11);
+    assert_eq!(slow::scientific_exponent(&number, format), -11);
 
     number.mantissa = 1234567890123456;
-    assert_eq!(slow::scientific_exponent::<{ STANDARD }>(&number), 0);
+    assert_eq!(slow::scientific_exponent(&number, format), 0);
 }
 
 #[test]
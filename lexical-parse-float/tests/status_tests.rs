@@ -0,0 +1,214 @@
+use lexical_parse_float::{
+    parse_partial_with_status,
+    parse_with_status,
+    Error,
+    Options,
+    RoundMode,
+    Status,
+};
+#[cfg(feature = "power-of-two")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use proptest::prelude::*;
+
+fn parse_f32(string: &str) -> (f32, Status) {
+    parse_with_status::<f32, STANDARD>(string.as_bytes(), &Options::new()).unwrap()
+}
+
+fn parse_f64(string: &str) -> (f64, Status) {
+    parse_with_status::<f64, STANDARD>(string.as_bytes(), &Options::new()).unwrap()
+}
+
+fn parse_f64_mode(string: &str, round_mode: RoundMode) -> (f64, Status) {
+    let options = Options::builder().round_mode(round_mode).build().unwrap();
+    parse_with_status::<f64, STANDARD>(string.as_bytes(), &options).unwrap()
+}
+
+/// Get the exact decimal representation of a float.
+fn exact(x: f64) -> String {
+    format!("{:.1100e}", x)
+}
+
+#[test]
+fn exact_test() {
+    assert_eq!(parse_f64("0"), (0.0, Status::Exact));
+    assert_eq!(parse_f64("-0.0e-500"), (-0.0, Status::Exact));
+    assert_eq!(parse_f64("1"), (1.0, Status::Exact));
+    assert_eq!(parse_f64("-2.5"), (-2.5, Status::Exact));
+    assert_eq!(parse_f64("1e22"), (1e22, Status::Exact));
+    assert_eq!(parse_f64("9007199254740992"), (9007199254740992.0, Status::Exact));
+    assert_eq!(parse_f64("0.0000152587890625"), (0.0000152587890625, Status::Exact));
+    assert_eq!(parse_f64("1.000000000000000000000000000000"), (1.0, Status::Exact));
+    assert_eq!(parse_f64(&exact(f64::MAX)), (f64::MAX, Status::Exact));
+    assert_eq!(parse_f64(&exact(f64::MIN_POSITIVE)), (f64::MIN_POSITIVE, Status::Exact));
+    assert_eq!(parse_f64(&exact(5e-324)), (5e-324, Status::Exact));
+    // Trailing zeros past the mantissa digits, with a large power-of-two factor.
+    assert_eq!(parse_f64(&exact(5.469798877447152e19)), (5.469798877447152e19, Status::Exact));
+    assert_eq!(parse_f32("16777216"), (16777216.0, Status::Exact));
+    assert_eq!(parse_f32(&exact(f32::MAX as f64)), (f32::MAX, Status::Exact));
+    assert_eq!(parse_f32(&exact(1e-45f32 as f64)), (1e-45, Status::Exact));
+
+    assert_eq!(parse_f64("inf"), (f64::INFINITY, Status::Exact));
+    assert_eq!(parse_f64("-infinity"), (f64::NEG_INFINITY, Status::Exact));
+    let (nan, status) = parse_f64("NaN");
+    assert!(nan.is_nan());
+    assert!(status.is_exact());
+}
+
+#[test]
+fn inexact_test() {
+    assert_eq!(parse_f64("0.1"), (0.1, Status::Inexact));
+    assert_eq!(parse_f64("-1.1"), (-1.1, Status::Inexact));
+    assert_eq!(parse_f64("9007199254740993"), (9007199254740992.0, Status::Inexact));
+    assert_eq!(parse_f64("1e23"), (1e23, Status::Inexact));
+    assert_eq!(parse_f64("1.0000000000000000000000000001"), (1.0, Status::Inexact));
+    assert_eq!(parse_f64("2.2250738585072014e-308"), (f64::MIN_POSITIVE, Status::Inexact));
+    assert_eq!(parse_f32("16777217"), (16777216.0, Status::Inexact));
+    assert_eq!(parse_f32("1e22"), (1e22, Status::Inexact));
+
+    let above = exact(1.0).replacen('e', "1e", 1);
+    assert_eq!(parse_f64(&above), (1.0, Status::Inexact));
+    assert!(!Status::Inexact.is_exact());
+}
+
+#[test]
+fn overflow_test() {
+    assert_eq!(parse_f64("1e400"), (f64::INFINITY, Status::Overflow));
+    assert_eq!(parse_f64("-1e400"), (f64::NEG_INFINITY, Status::Overflow));
+    assert_eq!(parse_f64("1.7976931348623159e308"), (f64::INFINITY, Status::Overflow));
+    assert_eq!(parse_f64("1.7976931348623158e308"), (f64::MAX, Status::Inexact));
+    assert_eq!(parse_f64("1.7976931348623157e308"), (f64::MAX, Status::Inexact));
+    assert_eq!(parse_f32("1e39"), (f32::INFINITY, Status::Overflow));
+    assert_eq!(parse_f64_mode("1e400", RoundMode::TowardZero), (f64::MAX, Status::Inexact));
+    assert_eq!(parse_f64_mode("-1e400", RoundMode::TowardPositive), (-f64::MAX, Status::Inexact));
+    assert_eq!(parse_f64_mode("1e400", RoundMode::TowardPositive), (f64::INFINITY, Status::Overflow));
+}
+
+#[test]
+fn underflow_test() {
+    assert_eq!(parse_f64("1e-400"), (0.0, Status::Underflow));
+    assert_eq!(parse_f64("-1e-400"), (-0.0, Status::Underflow));
+    assert_eq!(parse_f64("2e-324"), (0.0, Status::Underflow));
+    assert_eq!(parse_f32("1e-50"), (0.0, Status::Underflow));
+    assert_eq!(parse_f64_mode("1e-400", RoundMode::TowardPositive), (5e-324, Status::Subnormal));
+}
+
+#[test]
+fn subnormal_test() {
+    assert_eq!(parse_f64("5e-324"), (5e-324, Status::Subnormal));
+    assert_eq!(parse_f64("3e-324"), (5e-324, Status::Subnormal));
+    assert_eq!(parse_f64("1e-310"), (1e-310, Status::Subnormal));
+    assert_eq!(parse_f32("1e-40"), (1e-40, Status::Subnormal));
+    let half = exact(f64::MIN_POSITIVE / 2.0);
+    assert_eq!(parse_f64(&half), (f64::MIN_POSITIVE / 2.0, Status::Exact));
+}
+
+#[test]
+fn partial_test() {
+    let options = Options::new();
+    let parse = |bytes| parse_partial_with_status::<f64, STANDARD>(bytes, &options);
+    assert_eq!(parse(b"0.5,"), Ok((0.5, Status::Exact, 3)));
+    assert_eq!(parse(b"0.1 "), Ok((0.1, Status::Inexact, 3)));
+    assert_eq!(parse(b"1e400x"), Ok((f64::INFINITY, Status::Overflow, 5)));
+    assert_eq!(parse(b"infx"), Ok((f64::INFINITY, Status::Exact, 3)));
+    assert_eq!(parse(b"x"), Err(Error::EmptyMantissa(0)));
+}
+
+#[test]
+fn error_test() {
+    let options = Options::new();
+    let parse = |bytes| parse_with_status::<f64, STANDARD>(bytes, &options);
+    assert_eq!(parse(b""), Err(Error::Empty(0)));
+    assert_eq!(parse(b"1.5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"-"), Err(Error::Empty(1)));
+}
+
+#[test]
+fn lossy_test() {
+    let options = Options::builder().lossy(true).build().unwrap();
+    let parse = |bytes| parse_with_status::<f64, STANDARD>(bytes, &options);
+    assert_eq!(parse(b"0.5"), Ok((0.5, Status::Exact)));
+    assert_eq!(parse(b"1e400"), Ok((f64::INFINITY, Status::Overflow)));
+    assert_eq!(parse(b"0.1").map(|x| x.1), Ok(Status::Inexact));
+}
+
+#[test]
+fn out_of_range_test() {
+    let options = Options::builder().error_on_out_of_range(true).build().unwrap();
+    let parse = |bytes| parse_with_status::<f64, STANDARD>(bytes, &options);
+    let parse_partial = |bytes| parse_partial_with_status::<f64, STANDARD>(bytes, &options);
+    assert_eq!(parse(b"1e400"), Err(Error::Overflow(0)));
+    assert_eq!(parse(b"1e-400"), Err(Error::Underflow(0)));
+    assert_eq!(parse(b"5e-324"), Ok((5e-324, Status::Subnormal)));
+    assert_eq!(parse_partial(b"1e400,"), Err(Error::Overflow(0)));
+    assert_eq!(parse_partial(b"0.1,"), Ok((0.1, Status::Inexact, 3)));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn binary_test() {
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let options = Options::new();
+    let parse = |bytes| parse_with_status::<f64, BINARY>(bytes, &options).unwrap();
+    assert_eq!(parse(b"1.1"), (1.5, Status::Exact));
+    let exact = b"1.0000000000000000000000000000000000000000000000000001";
+    assert_eq!(parse(exact), (1.0000000000000002, Status::Exact));
+    let inexact = b"1.00000000000000000000000000000000000000000000000000001";
+    assert_eq!(parse(inexact), (1.0, Status::Inexact));
+
+    // More significant digits than fit in the mantissa.
+    let exact = format!("1{}", "0".repeat(70));
+    assert_eq!(parse(exact.as_bytes()), (2f64.powi(70), Status::Exact));
+    let inexact = format!("{}1", exact);
+    assert_eq!(parse(inexact.as_bytes()), (2f64.powi(71), Status::Inexact));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix_test() {
+    const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+    let options = Options::new();
+    let parse = |string: &str| parse_with_status::<f64, BASE3>(string.as_bytes(), &options).unwrap();
+    let to_base3 = |mut value: u128| {
+        let mut digits = Vec::new();
+        while value != 0 {
+            digits.push(b'0' + (value % 3) as u8);
+            value /= 3;
+        }
+        digits.reverse();
+        String::from_utf8(digits).unwrap()
+    };
+
+    // Odd radixes compare digits with more significant digits than fit in the mantissa.
+    assert_eq!(parse(&to_base3(1 << 70)), (2f64.powi(70), Status::Exact));
+    assert_eq!(parse(&to_base3((1 << 70) + 1)), (2f64.powi(70), Status::Inexact));
+    assert_eq!(parse("0.1"), (1.0 / 3.0, Status::Inexact));
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_proptest(bits in 0u64..0x7FEF_FFFF_FFFF_FFFF) {
+        let x = f64::from_bits(bits);
+        let string = exact(x);
+        prop_assert_eq!(parse_f64(&string), (x, Status::Exact));
+
+        // Slightly above `x`.
+        let above = string.replacen('e', "1e", 1);
+        let expected = match bits {
+            0 => Status::Underflow,
+            _ if x < f64::MIN_POSITIVE => Status::Subnormal,
+            _ => Status::Inexact,
+        };
+        prop_assert_eq!(parse_f64(&above), (x, expected));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f32_proptest(i in r"[+-]?[0-9]{1,20}\.[0-9]{1,20}(e[+-]?[0-9]{1,2})?") {
+        // Every single-precision float is exactly representable as a double.
+        let (x, status64) = parse_f64(&i);
+        let (y, status) = parse_f32(&i);
+        prop_assert_eq!(status.is_exact(), status64.is_exact() && y as f64 == x);
+    }
+}
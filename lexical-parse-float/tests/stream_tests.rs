@@ -0,0 +1,191 @@
+use core::fmt::Debug;
+use lexical_parse_float::stream::{FloatStream, Progress};
+use lexical_parse_float::{FromLexicalWithOptions, Options};
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+#[cfg(feature = "format")]
+use lexical_util::punctuation;
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+use lexical_util::result::Result;
+use proptest::prelude::*;
+
+/// Feed the input in the given chunks, finishing at the end of the input.
+fn stream<T, const FORMAT: u128>(chunks: &[&[u8]], options: &Options) -> Result<(T, usize)>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    let mut stream = FloatStream::<T, FORMAT, 4>::with_options(options.clone());
+    for chunk in chunks {
+        if let Progress::Complete(value, count, _) = stream.feed(chunk)? {
+            return Ok((value, count));
+        }
+    }
+    stream.finish()
+}
+
+/// Check every split of the input matches the contiguous partial parser.
+fn check_stream<T, const FORMAT: u128>(input: &[u8], options: &Options)
+where
+    T: FromLexicalWithOptions<Options = Options> + Float,
+    T::Unsigned: Debug,
+{
+    // Compare the bits, since NaN is not equal to itself.
+    let to_bits =
+        |result: Result<(T, usize)>| result.map(|(value, count)| (value.to_bits(), count));
+    let expected = to_bits(T::from_lexical_partial_with_options::<FORMAT>(input, options));
+    for index in 0..=input.len() {
+        let (first, second) = input.split_at(index);
+        assert_eq!(to_bits(stream::<T, FORMAT>(&[first, second], options)), expected);
+    }
+    let bytes: Vec<&[u8]> = input.chunks(1).collect();
+    assert_eq!(to_bits(stream::<T, FORMAT>(&bytes, options)), expected);
+}
+
+#[test]
+fn feed_test() {
+    let mut stream = FloatStream::<f64, STANDARD, 64>::new();
+    assert_eq!(stream.feed(b"1.2"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"5e"), Ok(Progress::Incomplete));
+    assert_eq!(stream.buffered(), 5);
+    assert_eq!(stream.feed(b"-1]"), Ok(Progress::Complete(0.125, 7, 2)));
+    assert_eq!(stream.feed(b"in"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"f,"), Ok(Progress::Complete(f64::INFINITY, 3, 1)));
+    assert_eq!(stream.feed(b"1e"), Ok(Progress::Incomplete));
+    assert_eq!(stream.finish(), Err(Error::EmptyExponent(2)));
+    assert_eq!(stream.feed(b". "), Err(Error::EmptyMantissa(1)));
+}
+
+#[test]
+fn long_test() {
+    let mut stream = FloatStream::<f64, STANDARD, 4>::new();
+    assert_eq!(stream.feed(b"1.25"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b" "), Ok(Progress::Complete(1.25, 4, 0)));
+    assert_eq!(stream.feed(b"1.25"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"0"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"00e1 "), Ok(Progress::Complete(12.5, 9, 4)));
+}
+
+#[test]
+fn remainder_test() {
+    // Buffered bytes after the number are the start of the next number.
+    let mut stream = FloatStream::<f64, STANDARD, 4>::new();
+    assert_eq!(stream.feed(b"1.5-"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"2"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b" 3"), Ok(Progress::Complete(1.5, 3, 0)));
+    assert_eq!(stream.feed(b" 3"), Ok(Progress::Complete(-2.0, 2, 0)));
+    assert_eq!(stream.feed(b"3"), Ok(Progress::Incomplete));
+    assert_eq!(stream.finish(), Ok((3.0, 1)));
+}
+
+#[test]
+fn decimal_test() {
+    let options = Options::new();
+    check_stream::<f64, STANDARD>(b"", &options);
+    check_stream::<f64, STANDARD>(b"1.5e", &options);
+    check_stream::<f64, STANDARD>(b"1.5e+", &options);
+    check_stream::<f64, STANDARD>(b"-1.5e+3,", &options);
+    check_stream::<f64, STANDARD>(b"1.5E-3x", &options);
+    check_stream::<f32, STANDARD>(b"3.4028236e38 ", &options);
+    check_stream::<f64, STANDARD>(b"2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324", &options);
+    check_stream::<f64, STANDARD>(b"infinity]", &options);
+    check_stream::<f64, STANDARD>(b"-infin", &options);
+    check_stream::<f64, STANDARD>(b"NaN", &options);
+
+    let options = Options::builder()
+        .decimal_point(b',')
+        .exponent(b'^')
+        .nan_string(Some(b"nan"))
+        .inf_string(Some(b"inf"))
+        .infinity_string(Some(b"infinite"))
+        .build()
+        .unwrap();
+    check_stream::<f64, STANDARD>(b"1,5^3.", &options);
+    check_stream::<f64, STANDARD>(b"infinity", &options);
+    check_stream::<f64, STANDARD>(b"infinite,", &options);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .consecutive_digit_separator(true)
+        .special_digit_separator(true)
+        .build();
+
+    let options = Options::new();
+    check_stream::<f64, FORMAT>(b"1__2.3_4e5_6 ", &options);
+    check_stream::<f64, FORMAT>(b"1__2.3_4e5__", &options);
+    check_stream::<f64, FORMAT>(b"n_a_n]", &options);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn unicode_digits_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().unicode_digits(true).build();
+
+    let options = Options::new();
+    let mut stream = FloatStream::<f64, FORMAT, 4>::new();
+    assert_eq!(stream.feed("١٢".as_bytes()), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed("٣,".as_bytes()), Ok(Progress::Complete(123.0, 6, 2)));
+
+    check_stream::<f64, FORMAT>("١٢٣,".as_bytes(), &options);
+    check_stream::<f64, FORMAT>("-١.٥e٣ ".as_bytes(), &options);
+    check_stream::<f64, FORMAT>("１２.５".as_bytes(), &options);
+    check_stream::<f64, FORMAT>("١٢é".as_bytes(), &options);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn punctuation_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+
+    let options = Options::builder().punctuation(punctuation::TYPOGRAPHIC).build().unwrap();
+    let mut stream = FloatStream::<f64, FORMAT, 4>::with_options(options.clone());
+    assert_eq!(stream.feed("\u{2212}1\u{066B}".as_bytes()), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed("5,".as_bytes()), Ok(Progress::Complete(-1.5, 7, 1)));
+
+    check_stream::<f64, FORMAT>("\u{2212}1\u{066B}5,".as_bytes(), &options);
+    check_stream::<f64, FORMAT>("1\u{202F}234,".as_bytes(), &options);
+    check_stream::<f64, FORMAT>("1\u{00A0}000'000.25e\u{2212}2 ".as_bytes(), &options);
+    check_stream::<f64, FORMAT>("1\u{202F}.5".as_bytes(), &options);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn whitespace_test() {
+    const FORMAT: u128 =
+        NumberFormatBuilder::new().leading_whitespace(true).trailing_whitespace(true).build();
+
+    let options = Options::new();
+    let mut stream = FloatStream::<f64, FORMAT, 4>::new();
+    assert_eq!(stream.feed(b" 1.5"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b","), Ok(Progress::Complete(1.5, 4, 0)));
+
+    check_stream::<f64, FORMAT>(b" 1.5,", &options);
+    check_stream::<f64, FORMAT>(b"\t-2.5e3 \n1", &options);
+    check_stream::<f64, FORMAT>(b" inf ", &options);
+
+    const UNICODE: u128 = NumberFormatBuilder::rebuild(FORMAT).unicode_whitespace(true).build();
+    check_stream::<f64, UNICODE>("\u{2003}2.5\u{2003},".as_bytes(), &options);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_stream_proptest(i in r"[+-]?[0-9]{0,20}(\.[0-9]{0,20})?([eE][+-]?[0-9]{0,3})?[^0-9]?") {
+        check_stream::<f64, STANDARD>(i.as_bytes(), &Options::new());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f32_special_stream_proptest(i in r"[+-]?(nan|NaN|inf|infinity|Infinity|infin)[a-z ]?") {
+        check_stream::<f32, STANDARD>(i.as_bytes(), &Options::new());
+    }
+}
@@ -11,9 +11,10 @@
 #![cfg(not(feature = "compact"))]
 #![doc(hidden)]
 
-use crate::shared::is_overflow;
+use crate::options::Options;
+use crate::shared::{is_overflow, overflow_value};
 use lexical_util::digit::char_to_digit_const;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_util::iterator::{AsBytes, BytesIter};
 use lexical_util::num::{as_cast, Integer, UnsignedInteger};
 use lexical_util::result::Result;
@@ -37,19 +38,22 @@ macro_rules! can_try_parse_multidigits {
 macro_rules! parse_8digits {
     (
         $value:ident,
+        $overflowed:ident,
         $iter:ident,
         $format:ident,
         $t:ident
     ) => {{
-        let radix: $t = as_cast(NumberFormat::<{ $format }>::MANTISSA_RADIX);
+        let radix: $t = as_cast($format.mantissa_radix());
         let radix2: $t = radix.wrapping_mul(radix);
         let radix4: $t = radix2.wrapping_mul(radix2);
         let radix8: $t = radix4.wrapping_mul(radix4);
 
         // Try our fast, 8-digit at a time optimizations.
-        while let Some(val8) = try_parse_8digits::<$t, _, $format>(&mut $iter) {
-            $value = $value.wrapping_mul(radix8);
-            $value = $value.wrapping_add(val8);
+        while let Some(val8) = try_parse_8digits::<$t, _, _>(&mut $iter, $format) {
+            let (value, mul_overflowed) = $value.overflowing_mul(radix8);
+            let (value, add_overflowed) = value.overflowing_add(val8);
+            $value = value;
+            $overflowed |= mul_overflowed | add_overflowed;
         }
     }};
 }
@@ -63,18 +67,21 @@ macro_rules! parse_8digits {
 macro_rules! parse_4digits {
     (
         $value:ident,
+        $overflowed:ident,
         $iter:ident,
         $format:ident,
         $t:ident
     ) => {{
-        let radix: $t = as_cast(NumberFormat::<{ $format }>::MANTISSA_RADIX);
+        let radix: $t = as_cast($format.mantissa_radix());
         let radix2: $t = radix.wrapping_mul(radix);
         let radix4: $t = radix2.wrapping_mul(radix2);
 
         // Try our fast, 4-digit at a time optimizations.
-        while let Some(val4) = try_parse_4digits::<$t, _, $format>(&mut $iter) {
-            $value = $value.wrapping_mul(radix4);
-            $value = $value.wrapping_add(val4);
+        while let Some(val4) = try_parse_4digits::<$t, _, _>(&mut $iter, $format) {
+            let (value, mul_overflowed) = $value.overflowing_mul(radix4);
+            let (value, add_overflowed) = value.overflowing_add(val4);
+            $value = value;
+            $overflowed |= mul_overflowed | add_overflowed;
         }
     }};
 }
@@ -85,8 +92,10 @@ macro_rules! parse_4digits {
 macro_rules! parse_digits {
     (
         $value:ident,
+        $overflowed:ident,
         $iter:ident,
         $format:ident,
+        $options:ident,
         $is_negative:ident,
         $start_index:ident,
         $t:ident,
@@ -115,55 +124,92 @@ macro_rules! parse_digits {
         //
         //  DO NOT MAKE CHANGES without monitoring the resulting benchmarks,
         //  or performance could greatly be impacted.
-        let radix = NumberFormat::<{ $format }>::MANTISSA_RADIX;
+        let radix = $format.mantissa_radix();
 
         // Optimizations for reading 8-digits at a time.
         // Makes no sense to do 8 digits at a time for 32-bit values,
         // since it can only hold 8 digits for base 10.
         if <$t>::BITS == 128 && can_try_parse_multidigits!($iter, radix) {
-            parse_8digits!($value, $iter, $format, $u);
+            parse_8digits!($value, $overflowed, $iter, $format, $u);
         }
         if <$t>::BITS == 64 && can_try_parse_multidigits!($iter, radix) && !<$t>::IS_SIGNED {
-            parse_8digits!($value, $iter, $format, $u);
+            parse_8digits!($value, $overflowed, $iter, $format, $u);
         }
 
         // Optimizations for reading 4-digits at a time.
         // 36^4 is larger than a 16-bit integer. Likewise, 10^4 is almost
         // the limit of u16, so it's not worth it.
         if <$t>::BITS == 32 && can_try_parse_multidigits!($iter, radix) && !<$t>::IS_SIGNED {
-            parse_4digits!($value, $iter, $format, $u);
+            parse_4digits!($value, $overflowed, $iter, $format, $u);
         }
 
-        parse_1digit!($value, $iter, $format, $is_negative, $start_index, $t, $u, $invalid_digit)
+        parse_1digit!(
+            $value,
+            $overflowed,
+            $iter,
+            $format,
+            $options,
+            $is_negative,
+            $start_index,
+            $t,
+            $u,
+            $invalid_digit
+        )
     }};
 }
 
 /// Algorithm for the complete parser.
 #[inline]
-pub fn algorithm_complete<T, Unsigned, const FORMAT: u128>(bytes: &[u8]) -> Result<T>
+pub fn algorithm_complete<T, Unsigned, F: Format>(
+    bytes: &[u8],
+    format: F,
+    options: &Options,
+) -> Result<T>
 where
     T: Integer,
     Unsigned: UnsignedInteger,
 {
-    algorithm!(bytes, FORMAT, T, Unsigned, parse_digits, invalid_digit_complete, into_ok_complete)
+    algorithm!(
+        bytes,
+        format,
+        options,
+        T,
+        Unsigned,
+        parse_digits,
+        invalid_digit_complete,
+        into_ok_complete
+    )
 }
 
 /// Algorithm for the partial parser.
 #[inline]
-pub fn algorithm_partial<T, Unsigned, const FORMAT: u128>(bytes: &[u8]) -> Result<(T, usize)>
+pub fn algorithm_partial<T, Unsigned, F: Format>(
+    bytes: &[u8],
+    format: F,
+    options: &Options,
+) -> Result<(T, usize)>
 where
     T: Integer,
     Unsigned: UnsignedInteger,
 {
-    algorithm!(bytes, FORMAT, T, Unsigned, parse_digits, invalid_digit_partial, into_ok_partial)
+    algorithm!(
+        bytes,
+        format,
+        options,
+        T,
+        Unsigned,
+        parse_digits,
+        invalid_digit_partial,
+        into_ok_partial
+    )
 }
 
 // DIGIT OPTIMIZATIONS
 
 /// Determine if 4 bytes, read raw from bytes, are 4 digits for the radix.
 #[inline]
-pub fn is_4digits<const FORMAT: u128>(v: u32) -> bool {
-    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+pub fn is_4digits<F: Format>(v: u32, format: F) -> bool {
+    let radix = format.mantissa_radix();
     debug_assert!(radix <= 10);
 
     // We want to have a wrapping add and sub such that only values from the
@@ -185,8 +231,8 @@ pub fn is_4digits<const FORMAT: u128>(v: u32) -> bool {
 
 /// Parse 4 bytes read from bytes into 4 digits.
 #[inline]
-pub fn parse_4digits<const FORMAT: u128>(mut v: u32) -> u32 {
-    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+pub fn parse_4digits<F: Format>(mut v: u32, format: F) -> u32 {
+    let radix = format.mantissa_radix();
     debug_assert!(radix <= 10);
 
     // Normalize our digits to the range `[0, 9]`.
@@ -205,23 +251,24 @@ pub fn parse_4digits<const FORMAT: u128>(mut v: u32) -> u32 {
 /// This approach is described in full here:
 /// <https://johnnylee-sde.github.io/Fast-numeric-string-to-int/>
 #[inline]
-pub fn try_parse_4digits<'a, T, Iter, const FORMAT: u128>(iter: &mut Iter) -> Option<T>
+pub fn try_parse_4digits<'a, T, Iter, F>(iter: &mut Iter, format: F) -> Option<T>
 where
     T: Integer,
     Iter: BytesIter<'a>,
+    F: Format,
 {
     // Can't do fast optimizations with radixes larger than 10, since
     // we no longer have a contiguous ASCII block. Likewise, cannot
     // use non-contiguous iterators.
-    debug_assert!(NumberFormat::<{ FORMAT }>::MANTISSA_RADIX <= 10);
-    debug_assert!(Iter::IS_CONTIGUOUS);
+    debug_assert!(format.mantissa_radix() <= 10);
+    debug_assert!(iter.is_contiguous());
 
     // Read our digits, validate the input, and check from there.
     let bytes = u32::from_le(iter.read::<u32>()?);
-    if is_4digits::<FORMAT>(bytes) {
+    if is_4digits(bytes, format) {
         // SAFETY: safe since we have at least 4 bytes in the buffer.
         unsafe { iter.step_by_unchecked(4) };
-        Some(T::as_cast(parse_4digits::<FORMAT>(bytes)))
+        Some(T::as_cast(parse_4digits(bytes, format)))
     } else {
         None
     }
@@ -230,8 +277,8 @@ where
 /// Determine if 8 bytes, read raw from bytes, are 8 digits for the radix.
 /// See `is_4digits` for the algorithm description.
 #[inline]
-pub fn is_8digits<const FORMAT: u128>(v: u64) -> bool {
-    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+pub fn is_8digits<F: Format>(v: u64, format: F) -> bool {
+    let radix = format.mantissa_radix();
     debug_assert!(radix <= 10);
 
     let add = 0x46 + 10 - radix;
@@ -250,8 +297,8 @@ pub fn is_8digits<const FORMAT: u128>(v: u64) -> bool {
 /// Credit for this goes to @aqrit, which further optimizes the
 /// optimization described by Johnny Lee above.
 #[inline]
-pub fn parse_8digits<const FORMAT: u128>(mut v: u64) -> u64 {
-    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX as u64;
+pub fn parse_8digits<F: Format>(mut v: u64, format: F) -> u64 {
+    let radix = format.mantissa_radix() as u64;
     debug_assert!(radix <= 10);
 
     // Create our masks. Assume the optimizer will do this at compile time.
@@ -277,23 +324,24 @@ pub fn parse_8digits<const FORMAT: u128>(mut v: u64) -> u64 {
 /// Use a fast-path optimization, where we attempt to parse 8 digits at a time.
 /// This reduces the number of multiplications necessary to 3, instead of 8.
 #[inline]
-pub fn try_parse_8digits<'a, T, Iter, const FORMAT: u128>(iter: &mut Iter) -> Option<T>
+pub fn try_parse_8digits<'a, T, Iter, F>(iter: &mut Iter, format: F) -> Option<T>
 where
     T: Integer,
     Iter: BytesIter<'a>,
+    F: Format,
 {
     // Can't do fast optimizations with radixes larger than 10, since
     // we no longer have a contiguous ASCII block. Likewise, cannot
     // use non-contiguous iterators.
-    debug_assert!(NumberFormat::<{ FORMAT }>::MANTISSA_RADIX <= 10);
-    debug_assert!(Iter::IS_CONTIGUOUS);
+    debug_assert!(format.mantissa_radix() <= 10);
+    debug_assert!(iter.is_contiguous());
 
     // Read our digits, validate the input, and check from there.
     let bytes = u64::from_le(iter.read::<u64>()?);
-    if is_8digits::<FORMAT>(bytes) {
+    if is_8digits(bytes, format) {
         // SAFETY: safe since we have at least 8 bytes in the buffer.
         unsafe { iter.step_by_unchecked(8) };
-        Some(T::as_cast(parse_8digits::<FORMAT>(bytes)))
+        Some(T::as_cast(parse_8digits(bytes, format)))
     } else {
         None
     }
@@ -4,8 +4,30 @@
 
 use crate::options::Options;
 use crate::parse::ParseInteger;
-use lexical_util::format::{NumberFormat, STANDARD};
-use lexical_util::{from_lexical, from_lexical_with_options};
+use crate::stream::is_token;
+use lexical_util::code_unit::{narrow, CodeUnit};
+use lexical_util::format::{Format, NumberFormat, STANDARD};
+use lexical_util::grouping::check_grouping;
+use lexical_util::unicode::{parse_partial_unicode, parse_unicode, Replacements};
+use lexical_util::whitespace::{parse_partial_whitespace, parse_whitespace};
+use lexical_util::{from_lexical, from_lexical_with_format, from_lexical_with_options};
+
+// API
+
+const DEFAULT_OPTIONS: Options = Options::new();
+const STANDARD_FORMAT: NumberFormat<STANDARD> = NumberFormat::<{ STANDARD }> {};
+
+/// Get the non-ASCII digits and alternative punctuation to replace.
+#[inline(always)]
+fn replacements<F: Format>(format: F, options: &Options) -> Replacements {
+    Replacements::new(
+        format.unicode_digits(),
+        options.punctuation(),
+        format.digit_separator(),
+        options.decimal_point(),
+        options.exponent(),
+    )
+}
 
 /// Implement FromLexical for numeric type.
 ///
@@ -20,7 +42,7 @@ macro_rules! integer_from_lexical {
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical(bytes: &[u8]) -> lexical_util::result::Result<Self>
             {
-                Self::parse_complete::<$unsigned, STANDARD>(bytes)
+                Self::parse_complete::<$unsigned, _>(bytes, STANDARD_FORMAT, &DEFAULT_OPTIONS)
             }
 
             $(#[$meta:meta])?
@@ -29,7 +51,27 @@ macro_rules! integer_from_lexical {
                 bytes: &[u8],
             ) -> lexical_util::result::Result<(Self, usize)>
             {
-                Self::parse_partial::<$unsigned, STANDARD>(bytes)
+                Self::parse_partial::<$unsigned, _>(bytes, STANDARD_FORMAT, &DEFAULT_OPTIONS)
+            }
+
+            $(#[$meta:meta])?
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_units<U: CodeUnit>(
+                units: &[U],
+            ) -> lexical_util::result::Result<Self>
+            {
+                let is_token = |c| is_token(c, STANDARD_FORMAT, &DEFAULT_OPTIONS);
+                narrow(units, is_token, Self::from_lexical)
+            }
+
+            $(#[$meta:meta])?
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_units<U: CodeUnit>(
+                units: &[U],
+            ) -> lexical_util::result::Result<(Self, usize)>
+            {
+                let is_token = |c| is_token(c, STANDARD_FORMAT, &DEFAULT_OPTIONS);
+                narrow(units, is_token, Self::from_lexical_partial)
             }
         }
 
@@ -40,28 +82,107 @@ macro_rules! integer_from_lexical {
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical_with_options<const FORMAT: u128>(
                 bytes: &[u8],
-                _: &Self::Options,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self>
+            {
+                Self::from_lexical_with_format(bytes, NumberFormat::<{ FORMAT }> {}, options)
+            }
+
+            $(#[$meta:meta])?
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)>
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                Self::from_lexical_partial_with_format(bytes, format, options)
+            }
+
+            $(#[$meta:meta])?
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_units_with_options<U: CodeUnit, const FORMAT: u128>(
+                units: &[U],
+                options: &Self::Options,
             ) -> lexical_util::result::Result<Self>
             {
                 let format = NumberFormat::<{ FORMAT }> {};
+                parse_whitespace(units, format, |units| {
+                    narrow(
+                        units,
+                        |c| is_token(c, format, options),
+                        |bytes| Self::from_lexical_with_format(bytes, format, options),
+                    )
+                })
+            }
+
+            $(#[$meta:meta])?
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_units_with_options<U: CodeUnit, const FORMAT: u128>(
+                units: &[U],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)>
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                parse_partial_whitespace(units, format, |units| {
+                    narrow(
+                        units,
+                        |c| is_token(c, format, options),
+                        |bytes| Self::from_lexical_partial_with_format(bytes, format, options),
+                    )
+                })
+            }
+        }
+
+        impl FromLexicalWithFormat for $t {
+            $(#[$meta:meta])?
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_format<F: Format>(
+                bytes: &[u8],
+                format: F,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self>
+            {
+                let replacements = replacements(format, options);
                 if !format.is_valid() {
                     return Err(format.error());
                 }
-                Self::parse_complete::<$unsigned, FORMAT>(bytes)
+                parse_whitespace(bytes, format, |bytes| {
+                    if !replacements.is_empty() {
+                        let is_token = |c| is_token(c, format, options);
+                        return parse_unicode(bytes, &replacements, is_token, |bytes| {
+                            check_grouping(bytes, format)?;
+                            Self::parse_complete::<$unsigned, _>(bytes, format, options)
+                        });
+                    }
+                    check_grouping(bytes, format)?;
+                    Self::parse_complete::<$unsigned, _>(bytes, format, options)
+                })
             }
 
             $(#[$meta:meta])?
             #[cfg_attr(not(feature = "compact"), inline)]
-            fn from_lexical_partial_with_options<const FORMAT: u128>(
+            fn from_lexical_partial_with_format<F: Format>(
                 bytes: &[u8],
-                _: &Self::Options,
+                format: F,
+                options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>
             {
-                let format = NumberFormat::<{ FORMAT }> {};
+                let replacements = replacements(format, options);
                 if !format.is_valid() {
                     return Err(format.error());
                 }
-                Self::parse_partial::<$unsigned, FORMAT>(bytes)
+                parse_partial_whitespace(bytes, format, |bytes| {
+                    if !replacements.is_empty() {
+                        let is_token = |c| is_token(c, format, options);
+                        return parse_partial_unicode(bytes, &replacements, is_token, |bytes| {
+                            check_grouping(bytes, format)?;
+                            Self::parse_partial::<$unsigned, _>(bytes, format, options)
+                        });
+                    }
+                    check_grouping(bytes, format)?;
+                    Self::parse_partial::<$unsigned, _>(bytes, format, options)
+                })
             }
         }
     )*)
@@ -69,6 +190,7 @@ macro_rules! integer_from_lexical {
 
 from_lexical! {}
 from_lexical_with_options! {}
+from_lexical_with_format! {}
 integer_from_lexical! {
     u8 u8 ;
     u16 u16 ;
@@ -0,0 +1,282 @@
+//! Parse very long digit strings to little-endian `u64` limbs.
+//!
+//! These parsers convert strings of any length, such as thousands of
+//! digits, to an unsigned value stored as limbs from least to most
+//! significant. The input must only contain digits in the radix: signs,
+//! digit separators, and other number syntax are not supported.
+//!
+//! All buffers are provided by the caller, so no allocation is required:
+//! [`limbs_len`] and [`scratch_len`] give the size of the buffers for a
+//! given number of digits. Power-of-two radixes are parsed in linear time,
+//! and other radixes use a subquadratic divide-and-conquer algorithm for
+//! large inputs, which splits the digits in half and combines the halves
+//! with a precomputed power of the radix.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_integer::bignum::{limbs_len, parse_limbs, scratch_len};
+//!
+//! # pub fn main() {
+//! let digits = b"340282366920938463463374607431768211456";
+//! let mut limbs = vec![0u64; limbs_len(digits.len(), 10)];
+//! let mut scratch = vec![0u64; scratch_len(digits.len(), 10)];
+//! let len = parse_limbs(digits, 10, &mut limbs, &mut scratch).unwrap();
+//! assert_eq!(&limbs[..len], &[0, 0, 1]);
+//! # }
+//! ```
+
+use lexical_util::bignum;
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::is_valid_radix;
+use lexical_util::result::Result;
+
+/// The number of words of digits below which the schoolbook algorithm is used.
+const BASE_WORDS: usize = 32;
+
+/// Get the index of the smallest power `base^(2^t)` where `2^(t + 1) >= words`.
+#[inline]
+const fn top_level(words: usize) -> usize {
+    let mut level = 0;
+    while (1 << (level + 1)) < words {
+        level += 1;
+    }
+    level
+}
+
+/// Get the number of limbs required to store a value with the given number of digits.
+///
+/// This is an upper bound, and the value may require fewer limbs. The
+/// radix must be valid.
+#[inline]
+pub const fn limbs_len(digits: usize, radix: u32) -> usize {
+    let (_, step) = bignum::max_power(radix);
+    match digits % step {
+        0 => digits / step,
+        _ => digits / step + 1,
+    }
+}
+
+/// Get the number of scratch limbs required to parse a value with the given number of digits.
+///
+/// This is an upper bound, which increases with the number of digits.
+/// The radix must be valid.
+pub const fn scratch_len(digits: usize, radix: u32) -> usize {
+    let words = limbs_len(digits, radix);
+    if radix.is_power_of_two() {
+        return 0;
+    } else if words <= BASE_WORDS {
+        return words;
+    }
+
+    // Result, powers table, and the scratch to create the powers or recurse.
+    let level = top_level(words);
+    let mut recurse = 0;
+    let mut j = 0;
+    while j <= level {
+        if (1 << (j + 1)) > BASE_WORDS {
+            let mul = bignum::mul_scratch_len(1 << j, 1 << j);
+            let inner = if recurse > mul {
+                recurse
+            } else {
+                mul
+            };
+            recurse = (1 << (j + 1)) + inner;
+        }
+        j += 1;
+    }
+    let square = bignum::mul_scratch_len(1 << level, 1 << level);
+    let inner = if recurse > square {
+        recurse
+    } else {
+        square
+    };
+    (1 << (level + 1)) + bignum::powers_len(level) + inner
+}
+
+/// Parse the digits into `out` using the schoolbook algorithm.
+///
+/// `out` must be able to store the value, and the digits must be valid.
+fn parse_base(digits: &[u8], radix: u32, out: &mut [u64]) {
+    for limb in out.iter_mut() {
+        *limb = 0;
+    }
+
+    // Accumulate as many digits as fit in a `u64` before multiplying.
+    let mut len = 0;
+    let mut chunk = 0_u64;
+    let mut power = 1_u64;
+    for &c in digits {
+        if power > u64::MAX / radix as u64 {
+            let carry = bignum::mul_small(&mut out[..len], power, chunk);
+            if carry != 0 {
+                out[len] = carry;
+                len += 1;
+            }
+            chunk = 0;
+            power = 1;
+        }
+        let digit = char_to_digit_const(c, radix).unwrap_or(0);
+        chunk = chunk * radix as u64 + digit as u64;
+        power *= radix as u64;
+    }
+    let carry = bignum::mul_small(&mut out[..len], power, chunk);
+    if carry != 0 {
+        out[len] = carry;
+    }
+}
+
+/// Parse up to `step * 2^(level + 1)` digits into `out`, with `2^(level + 1)` limbs.
+///
+/// The value is `high * base^(2^level) + low`, where `low` is the last
+/// `step * 2^level` digits, and both halves are parsed recursively.
+fn parse_level(
+    digits: &[u8],
+    radix: u32,
+    level: usize,
+    out: &mut [u64],
+    powers: &[u64],
+    scratch: &mut [u64],
+) {
+    let size = 1 << level;
+    if 2 * size <= BASE_WORDS {
+        return parse_base(digits, radix, &mut out[..2 * size]);
+    }
+
+    let (_, step) = bignum::max_power(radix);
+    let split = digits.len().saturating_sub(step * size);
+    let (high, low) = digits.split_at(split);
+    if high.is_empty() {
+        for limb in out[size..2 * size].iter_mut() {
+            *limb = 0;
+        }
+        return parse_level(low, radix, level - 1, out, powers, scratch);
+    }
+
+    let (hi, scratch) = scratch.split_at_mut(size);
+    let (lo, scratch) = scratch.split_at_mut(size);
+    parse_level(high, radix, level - 1, hi, powers, scratch);
+    parse_level(low, radix, level - 1, lo, powers, scratch);
+    let hi = &hi[..bignum::normalized_len(hi)];
+    let lo = &lo[..bignum::normalized_len(lo)];
+    let power = bignum::power(powers, level);
+    let out = &mut out[..2 * size];
+    for limb in out.iter_mut() {
+        *limb = 0;
+    }
+    bignum::mul(out, hi, power, scratch);
+    bignum::add_assign(out, lo);
+}
+
+/// Parse digits in a power-of-two radix by packing the bits of each digit.
+fn parse_power_of_two(digits: &[u8], radix: u32, limbs: &mut [u64]) {
+    let log2 = radix.trailing_zeros() as usize;
+    for (index, &c) in digits.iter().rev().enumerate() {
+        let digit = char_to_digit_const(c, radix).unwrap_or(0) as u64;
+        let bit = index * log2;
+        let shift = bit % 64;
+        if let Some(limb) = limbs.get_mut(bit / 64) {
+            *limb |= digit << shift;
+        }
+        if shift + log2 > 64 {
+            if let Some(limb) = limbs.get_mut(bit / 64 + 1) {
+                *limb |= digit >> (64 - shift);
+            }
+        }
+    }
+}
+
+/// Parse a string of digits to little-endian `u64` limbs.
+///
+/// Returns the number of limbs in the value, without the most-significant
+/// zero limbs, and all remaining limbs are set to 0. `limbs` must be
+/// large enough to store the value, which is guaranteed if it has at
+/// least [`limbs_len`] limbs, and `scratch` must have at least
+/// [`scratch_len`] limbs.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidRadix`] if the radix is not valid for the
+/// enabled features, [`Error::Empty`] if there are no digits,
+/// [`Error::InvalidDigit`] for any byte that is not a digit in the radix,
+/// and [`Error::Overflow`] if the value does not fit in `limbs`.
+///
+/// # Panics
+///
+/// Panics if `scratch` is smaller than [`scratch_len`].
+pub fn parse_limbs(
+    bytes: &[u8],
+    radix: u32,
+    limbs: &mut [u64],
+    scratch: &mut [u64],
+) -> Result<usize> {
+    if !is_valid_radix(radix) {
+        return Err(Error::InvalidRadix);
+    } else if bytes.is_empty() {
+        return Err(Error::Empty(0));
+    }
+    if let Some(index) = bytes.iter().position(|&c| char_to_digit_const(c, radix).is_none()) {
+        return Err(Error::InvalidDigit(index));
+    }
+    for limb in limbs.iter_mut() {
+        *limb = 0;
+    }
+
+    // Ignore leading zeros, so they do not affect the buffer sizes.
+    let start = bytes.iter().position(|&c| c != b'0').unwrap_or(bytes.len());
+    let digits = &bytes[start..];
+    let (_, step) = bignum::max_power(radix);
+    let overflow = Error::Overflow((bytes.len() - 1).min(start + step * limbs.len()));
+    if digits.is_empty() {
+        return Ok(0);
+    } else if radix.is_power_of_two() {
+        // Check the number of bits before packing the digits.
+        let log2 = radix.trailing_zeros() as usize;
+        let first = char_to_digit_const(digits[0], radix).unwrap_or(0);
+        let bits = (digits.len() - 1) * log2 + (32 - first.leading_zeros() as usize);
+        if bits > 64 * limbs.len() {
+            return Err(overflow);
+        }
+        parse_power_of_two(digits, radix, limbs);
+        return Ok(bignum::normalized_len(limbs));
+    }
+
+    let words = limbs_len(digits.len(), radix);
+    let value = if words <= BASE_WORDS {
+        let (value, _) = scratch.split_at_mut(words);
+        parse_base(digits, radix, value);
+        value
+    } else {
+        let level = top_level(words);
+        let (value, scratch) = scratch.split_at_mut(2 << level);
+        let (powers, scratch) = scratch.split_at_mut(bignum::powers_len(level));
+        let (base, _) = bignum::max_power(radix);
+        bignum::powers(base, level, powers, scratch);
+        parse_level(digits, radix, level, value, powers, scratch);
+        value
+    };
+
+    let len = bignum::normalized_len(value);
+    if len > limbs.len() {
+        return Err(overflow);
+    }
+    limbs[..len].copy_from_slice(&value[..len]);
+    Ok(len)
+}
+
+/// Parse a string of digits to little-endian `u64` limbs, allocating the buffers.
+///
+/// The returned limbs do not have any most-significant zero limbs. See
+/// [`parse_limbs`] for the errors.
+#[cfg(feature = "std")]
+pub fn parse_limbs_vec(bytes: &[u8], radix: u32) -> Result<Vec<u64>> {
+    if !is_valid_radix(radix) {
+        return Err(Error::InvalidRadix);
+    }
+    let mut limbs = vec![0; limbs_len(bytes.len(), radix)];
+    let mut scratch = vec![0; scratch_len(bytes.len(), radix)];
+    let len = parse_limbs(bytes, radix, &mut limbs, &mut scratch)?;
+    limbs.truncate(len);
+    Ok(limbs)
+}
@@ -5,28 +5,55 @@
 #![cfg(feature = "compact")]
 #![doc(hidden)]
 
-use crate::shared::is_overflow;
+use crate::options::Options;
+use crate::shared::{is_overflow, overflow_value};
 use lexical_util::digit::char_to_digit_const;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_util::iterator::{AsBytes, BytesIter};
 use lexical_util::num::{as_cast, Integer, UnsignedInteger};
 use lexical_util::result::Result;
 use lexical_util::step::min_step;
 
 /// Algorithm for the complete parser.
-pub fn algorithm_complete<T, Unsigned, const FORMAT: u128>(bytes: &[u8]) -> Result<T>
+pub fn algorithm_complete<T, Unsigned, F: Format>(
+    bytes: &[u8],
+    format: F,
+    options: &Options,
+) -> Result<T>
 where
     T: Integer,
     Unsigned: UnsignedInteger,
 {
-    algorithm!(bytes, FORMAT, T, Unsigned, parse_1digit, invalid_digit_complete, into_ok_complete)
+    algorithm!(
+        bytes,
+        format,
+        options,
+        T,
+        Unsigned,
+        parse_1digit,
+        invalid_digit_complete,
+        into_ok_complete
+    )
 }
 
 /// Algorithm for the partial parser.
-pub fn algorithm_partial<T, Unsigned, const FORMAT: u128>(bytes: &[u8]) -> Result<(T, usize)>
+pub fn algorithm_partial<T, Unsigned, F: Format>(
+    bytes: &[u8],
+    format: F,
+    options: &Options,
+) -> Result<(T, usize)>
 where
     T: Integer,
     Unsigned: UnsignedInteger,
 {
-    algorithm!(bytes, FORMAT, T, Unsigned, parse_1digit, invalid_digit_partial, into_ok_partial)
+    algorithm!(
+        bytes,
+        format,
+        options,
+        T,
+        Unsigned,
+        parse_1digit,
+        invalid_digit_partial,
+        into_ok_partial
+    )
 }
@@ -64,17 +64,24 @@
 mod shared;
 
 pub mod algorithm;
+pub mod bignum;
 pub mod compact;
+pub mod notation;
 pub mod options;
 pub mod parse;
+pub mod stream;
+pub mod wide;
 
 mod api;
 
 // Re-exports
-pub use self::api::{FromLexical, FromLexicalWithOptions};
+pub use self::api::{FromLexical, FromLexicalWithFormat, FromLexicalWithOptions};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{Options, OptionsBuilder, OverflowMode};
+pub use self::stream::{IntegerStream, Progress};
 pub use lexical_util::error::Error;
 pub use lexical_util::format::{self, NumberFormatBuilder};
+pub use lexical_util::grouping::DigitGrouping;
 pub use lexical_util::options::ParseOptions;
+pub use lexical_util::punctuation::{self, Punctuation};
 pub use lexical_util::result::Result;
@@ -0,0 +1,551 @@
+//! Parse integers written in exponent or fraction notation.
+//!
+//! These parsers validate the input using the float grammar of the
+//! number format, and accept the input only if the value is exactly
+//! integral, such as `1.5e3` or `12.0`. The value is calculated from
+//! the digits with integer arithmetic, so it is always exact.
+//!
+//! Special values are accepted if the format allows them, but are never
+//! integral: NaN is a non-integral error, and infinity overflows the
+//! integer, so it is an error unless the overflow mode saturates.
+//!
+//! Errors point to the offending byte: the last non-zero digit for
+//! non-integral values, and the digit that overflowed for out-of-range
+//! values. If only scaling by the exponent overflows, the error points
+//! to the exponent character, or to the last integer digit if the
+//! overflow is from trailing zeros.
+
+#![doc(hidden)]
+
+use crate::options::{Options, OverflowMode};
+use crate::shared::overflow_value;
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, Format};
+use lexical_util::iterator::{AsBytes, Bytes, BytesIter};
+use lexical_util::num::{as_cast, Integer, UnsignedInteger};
+use lexical_util::result::Result;
+
+/// A special, non-finite value.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Special {
+    /// Not a number.
+    NaN,
+    /// Positive or negative infinity.
+    Infinity,
+}
+
+/// The special strings, which are the defaults of the float parsers.
+///
+/// The long form of infinity must be checked before the short form.
+const SPECIAL_STRINGS: [(&[u8], Special); 3] =
+    [(b"NaN", Special::NaN), (b"infinity", Special::Infinity), (b"inf", Special::Infinity)];
+
+/// Components of a number in exponent or fraction notation.
+struct Components<'a> {
+    /// If the number is negative.
+    is_negative: bool,
+    /// The integer digits, including any digit separators.
+    integer: &'a [u8],
+    /// The index of the integer digits in the input.
+    integer_index: usize,
+    /// The fraction digits, including any digit separators.
+    fraction: &'a [u8],
+    /// The index of the fraction digits in the input.
+    fraction_index: usize,
+    /// The number of fraction digits.
+    fraction_count: usize,
+    /// The explicit exponent.
+    exponent: i64,
+    /// The index of the exponent character, if there is an exponent.
+    exponent_index: usize,
+    /// The special value, if the number isn't finite.
+    special: Option<Special>,
+    /// The number of processed bytes.
+    count: usize,
+}
+
+/// Get the number of bits per digit for a power-of-two radix.
+///
+/// This is only valid for powers of two, where it's the trailing zeros.
+#[inline]
+const fn log2(radix: u32) -> i64 {
+    radix.trailing_zeros() as i64
+}
+
+/// Consume digits from the iterator until an invalid digit.
+#[inline]
+fn skip_digits<'a, Iter>(mut iter: Iter, radix: u32)
+where
+    Iter: BytesIter<'a>,
+{
+    while let Some(&c) = iter.peek() {
+        if char_to_digit_const(c, radix).is_none() {
+            break;
+        }
+        // SAFETY: safe, since `iter` cannot be empty due to `iter.peek()`.
+        unsafe { iter.step_unchecked() };
+    }
+}
+
+/// Call `cb` with each digit and its index, for the integer then fraction digits.
+#[inline]
+fn for_each_digit<F: Format>(number: &Components, format: F, mut cb: impl FnMut(u32, usize)) {
+    let radix = format.mantissa_radix();
+    let mut integer = number.integer.bytes(format);
+    let mut iter = integer.integer_iter();
+    while let Some(&c) = iter.next() {
+        if let Some(digit) = char_to_digit_const(c, radix) {
+            cb(digit, number.integer_index + iter.cursor() - 1);
+        }
+    }
+
+    let mut fraction = number.fraction.bytes(format);
+    let mut iter = fraction.fraction_iter();
+    while let Some(&c) = iter.next() {
+        if let Some(digit) = char_to_digit_const(c, radix) {
+            cb(digit, number.fraction_index + iter.cursor() - 1);
+        }
+    }
+}
+
+/// Determine if the bytes start with the special string, and get the processed bytes.
+#[inline]
+fn is_special_eq<F: Format>(mut byte: Bytes<F>, format: F, string: &[u8]) -> Option<usize> {
+    let is_case_sensitive = cfg!(feature = "format") && format.case_sensitive_special();
+    let mut iter = byte.special_iter();
+    for &expected in string {
+        match iter.next() {
+            Some(&c) if c == expected => (),
+            Some(&c) if !is_case_sensitive && c.eq_ignore_ascii_case(&expected) => (),
+            _ => return None,
+        }
+    }
+    // Trim any trailing digit separators, like the float parsers.
+    byte.special_iter().peek();
+    Some(byte.cursor())
+}
+
+/// Determine if the bytes start with a special value allowed by the format.
+///
+/// Returns the special value and the number of processed bytes.
+#[inline]
+fn match_special<F: Format>(byte: Bytes<F>, format: F) -> Option<(Special, usize)> {
+    if cfg!(feature = "format") && format.no_special() {
+        return None;
+    }
+    SPECIAL_STRINGS.iter().find_map(|&(string, special)| {
+        is_special_eq(byte.clone(), format, string).map(|count| (special, count))
+    })
+}
+
+/// Calculate `base^exp`, and if the result overflowed.
+///
+/// Like the other wrapping operations, the result is modulo `2^BITS`.
+#[inline]
+fn overflowing_pow<U: UnsignedInteger>(mut base: U, mut exp: u64) -> (U, bool) {
+    let mut value = U::ONE;
+    let mut overflowed = false;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            let (result, mul_overflowed) = value.overflowing_mul(base);
+            value = result;
+            overflowed |= mul_overflowed;
+        }
+        exp >>= 1;
+        if exp != 0 {
+            let (result, mul_overflowed) = base.overflowing_mul(base);
+            base = result;
+            overflowed |= mul_overflowed;
+        }
+    }
+    (value, overflowed)
+}
+
+/// Parse the components of the number, using the float grammar.
+#[allow(clippy::collapsible_if)]
+fn parse_components<'a, T: Integer, F: Format>(
+    bytes: &'a [u8],
+    format: F,
+    options: &Options,
+) -> Result<Components<'a>> {
+    let radix = format.mantissa_radix();
+    let decimal_point = options.decimal_point();
+    let exponent_character = options.exponent();
+    let mut byte = bytes.bytes(format);
+
+    // SIGN
+
+    let (is_negative, shift) = match byte.integer_iter().peek() {
+        Some(&b'+') if format.no_positive_mantissa_sign() => {
+            return Err(Error::InvalidPositiveSign(0));
+        },
+        Some(&b'+') => (false, 1),
+        Some(&b'-') if T::IS_SIGNED => (true, 1),
+        Some(_) if format.required_mantissa_sign() => return Err(Error::MissingSign(0)),
+        // Like the plain parsers, a negative sign is an invalid digit for
+        // unsigned integers, so the number is empty.
+        Some(&b'-') => {
+            return Ok(Components {
+                is_negative: false,
+                integer: &[],
+                integer_index: 0,
+                fraction: &[],
+                fraction_index: 0,
+                fraction_count: 0,
+                exponent: 0,
+                exponent_index: 0,
+                special: None,
+                count: 0,
+            });
+        },
+        _ => (false, 0),
+    };
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.is_done() {
+        return Err(Error::Empty(shift));
+    }
+
+    // SPECIAL
+
+    // Like the float parsers, digits take precedence over special values.
+    let is_digit = byte.integer_iter().peek().map(|&c| char_to_digit_const(c, radix).is_some());
+    if is_digit == Some(false) {
+        if let Some((special, count)) = match_special(byte.clone(), format) {
+            return Ok(Components {
+                is_negative,
+                integer: &[],
+                integer_index: shift,
+                fraction: &[],
+                fraction_index: shift,
+                fraction_count: 0,
+                exponent: 0,
+                exponent_index: count,
+                special: Some(special),
+                count,
+            });
+        }
+    }
+
+    // INTEGER
+
+    // Check to see if we have a valid base prefix.
+    let base_prefix = format.base_prefix();
+    let mut is_prefix = false;
+    let mut start = byte.clone();
+    let mut iter = byte.integer_iter();
+    if cfg!(feature = "format") && base_prefix != 0 && iter.peek() == Some(&b'0') {
+        // SAFETY: safe since `byte.len() >= 1`.
+        unsafe { iter.step_unchecked() };
+        // Check to see if the next character is the base prefix.
+        if let Some(&c) = iter.peek() {
+            is_prefix = if format.case_sensitive_base_prefix() {
+                c == base_prefix
+            } else {
+                c.eq_ignore_ascii_case(&base_prefix)
+            };
+            if is_prefix {
+                // SAFETY: safe since `byte.len() >= 1`.
+                unsafe { iter.step_unchecked() };
+                if iter.is_done() {
+                    return Err(Error::Empty(iter.cursor()));
+                }
+            }
+        }
+    }
+
+    if is_prefix {
+        start = byte.clone();
+    }
+    skip_digits(byte.integer_iter(), radix);
+    let integer_count = byte.current_count() - start.current_count();
+    if cfg!(feature = "format") && format.required_integer_digits() && integer_count == 0 {
+        return Err(Error::EmptyInteger(byte.cursor()));
+    }
+    // This must use the cursor, since the digit count skips digit separators.
+    let integer = &start.as_slice()[..byte.cursor() - start.cursor()];
+    let no_leading_zeros = format.no_float_leading_zeros() || format.no_integer_leading_zeros();
+    if cfg!(feature = "format") && !is_prefix && no_leading_zeros {
+        if integer.len() > 1 && integer.first() == Some(&b'0') {
+            return Err(Error::InvalidLeadingZeros(start.cursor()));
+        }
+    }
+
+    // FRACTION
+
+    let mut fraction: &[u8] = &[];
+    let mut fraction_index = byte.cursor();
+    let mut fraction_count = 0;
+    let has_fraction = byte.first_is(decimal_point);
+    if has_fraction {
+        // SAFETY: safe, since `byte` cannot be empty due to `first_is`.
+        unsafe { byte.step_unchecked() };
+        let before = byte.clone();
+        skip_digits(byte.fraction_iter(), radix);
+        fraction_count = byte.current_count() - before.current_count();
+        fraction = &before.as_slice()[..byte.cursor() - before.cursor()];
+        fraction_index = before.cursor();
+        if cfg!(feature = "format") && format.required_fraction_digits() && fraction_count == 0 {
+            return Err(Error::EmptyFraction(byte.cursor()));
+        }
+    }
+    if format.required_mantissa_digits() && integer_count + fraction_count == 0 {
+        return Err(Error::EmptyMantissa(byte.cursor()));
+    }
+
+    // EXPONENT
+
+    let mut exponent = 0_i64;
+    let mut exponent_index = byte.cursor();
+    let is_exponent = if cfg!(feature = "format") && format.case_sensitive_exponent() {
+        byte.first_is(exponent_character)
+    } else {
+        byte.case_insensitive_first_is(exponent_character)
+    };
+    if is_exponent {
+        if cfg!(feature = "format") {
+            if format.no_exponent_notation() {
+                return Err(Error::InvalidExponent(byte.cursor()));
+            }
+            if format.no_exponent_without_fraction() && !has_fraction {
+                return Err(Error::ExponentWithoutFraction(byte.cursor()));
+            }
+        }
+
+        exponent_index = byte.cursor();
+        // SAFETY: safe, since `byte` cannot be empty due to `first_is`.
+        unsafe { byte.step_unchecked() };
+        let (is_negative, shift) = match byte.integer_iter().peek() {
+            Some(&b'+') if format.no_positive_exponent_sign() => {
+                return Err(Error::InvalidPositiveExponentSign(byte.cursor()));
+            },
+            Some(&b'+') => (false, 1),
+            Some(&b'-') => (true, 1),
+            _ if format.required_exponent_sign() => {
+                return Err(Error::MissingExponentSign(byte.cursor()));
+            },
+            _ => (false, 0),
+        };
+        // SAFETY: safe since we shift at most one for a parsed sign byte.
+        unsafe { byte.step_by_unchecked(shift) };
+
+        // Saturate the exponent, like the float parsers: any larger exponent
+        // overflows every integer type, or is not integral.
+        let exponent_radix = format.exponent_radix();
+        let before = byte.current_count();
+        let mut iter = byte.exponent_iter();
+        while let Some(digit) = iter.peek().and_then(|&c| char_to_digit_const(c, exponent_radix)) {
+            if exponent < 0x10000000 {
+                exponent = exponent * exponent_radix as i64 + digit as i64;
+            }
+            // SAFETY: safe, since `iter` cannot be empty due to `iter.peek()`.
+            unsafe { iter.step_unchecked() };
+        }
+        if format.required_exponent_digits() && byte.current_count() - before == 0 {
+            return Err(Error::EmptyExponent(byte.cursor()));
+        }
+        if is_negative {
+            exponent = -exponent;
+        }
+    } else if cfg!(feature = "format") && format.required_exponent_notation() {
+        return Err(Error::MissingExponent(byte.cursor()));
+    }
+
+    // Check to see if we have a valid base suffix.
+    let base_suffix = format.base_suffix();
+    if cfg!(feature = "format") && base_suffix != 0 {
+        let is_suffix = if format.case_sensitive_base_suffix() {
+            byte.first_is(base_suffix)
+        } else {
+            byte.case_insensitive_first_is(base_suffix)
+        };
+        if is_suffix {
+            // SAFETY: safe since `byte.len() >= 1`.
+            unsafe { byte.step_unchecked() };
+        }
+    }
+
+    Ok(Components {
+        is_negative,
+        integer,
+        integer_index: start.cursor(),
+        fraction,
+        fraction_index,
+        fraction_count,
+        exponent,
+        exponent_index,
+        special: None,
+        count: byte.cursor(),
+    })
+}
+
+/// Get the integer for infinity, which overflows any integer.
+///
+/// Infinity has no wrapped value, so it's an error unless the overflow
+/// mode saturates.
+#[inline]
+fn infinity_value<T: Integer>(number: &Components, options: &Options) -> Result<T> {
+    let index = number.integer_index;
+    match options.overflow_mode() {
+        OverflowMode::Saturate if T::IS_SIGNED && number.is_negative => Ok(T::MIN),
+        OverflowMode::Saturate => Ok(T::MAX),
+        _ if T::IS_SIGNED && number.is_negative => Err(Error::Underflow(index)),
+        _ => Err(Error::Overflow(index)),
+    }
+}
+
+/// Convert the components of the number to an integer.
+///
+/// The value is the significant digits, without trailing zeros, scaled
+/// by a power of the exponent base, or by a power of 2 if the mantissa
+/// radix and exponent base differ, and must be exactly integral.
+fn to_integer<T, U, F>(number: &Components, format: F, options: &Options) -> Result<T>
+where
+    T: Integer,
+    U: UnsignedInteger,
+    F: Format,
+{
+    let radix = format.mantissa_radix();
+    let base = format.exponent_base();
+    match number.special {
+        Some(Special::NaN) => return Err(Error::NonIntegral(number.integer_index)),
+        Some(Special::Infinity) => return infinity_value(number, options),
+        None => (),
+    }
+
+    // Find the last non-zero digit, since trailing zeros only scale the value.
+    let mut digit_count = 0;
+    let mut last = None;
+    let mut last_integer_index = number.integer_index;
+    for_each_digit(number, format, |digit, index| {
+        if digit != 0 {
+            last = Some((digit_count, digit, index));
+        }
+        if index < number.fraction_index {
+            last_integer_index = index;
+        }
+        digit_count += 1;
+    });
+    let (last_position, last_digit, last_index) = match last {
+        Some(last) => last,
+        None => return Ok(T::ZERO),
+    };
+    let trailing_zeros = (digit_count - last_position - 1) as i64;
+    let implicit_exponent = trailing_zeros - number.fraction_count as i64;
+
+    // The value is `digits * scale^shift`, where the last digit is non-zero.
+    let (scale, shift) = if radix == base {
+        (radix, number.exponent + implicit_exponent)
+    } else {
+        // Different radixes and bases are only valid for powers of two.
+        debug_assert!(radix.is_power_of_two() && base.is_power_of_two());
+        (2, log2(base) * number.exponent + log2(radix) * implicit_exponent)
+    };
+    // Only a power-of-two scale can divide the last digit.
+    if shift < 0 && (scale != 2 || -shift > last_digit.trailing_zeros() as i64) {
+        return Err(Error::NonIntegral(last_index));
+    }
+
+    let mut value = U::ZERO;
+    let mut overflow_index = None;
+    let mut position = 0;
+    for_each_digit(number, format, |mut digit, index| {
+        if position > last_position {
+            return;
+        }
+        let mut multiplier: U = as_cast(radix);
+        if position == last_position && shift < 0 {
+            // Remove the trailing zero bits of the last digit.
+            multiplier = as_cast(1_u32 << (log2(radix) + shift));
+            digit >>= -shift;
+        }
+        let (result, mul_overflowed) = value.overflowing_mul(multiplier);
+        let (result, add_overflowed) = result.overflowing_add(as_cast(digit));
+        value = result;
+        if overflow_index.is_none() && (mul_overflowed || add_overflowed) {
+            overflow_index = Some(index);
+        }
+        position += 1;
+    });
+
+    let is_negative = number.is_negative;
+    let is_out_of_range = |value: U, overflowed: bool| {
+        if T::IS_SIGNED {
+            // Signed type: have to deal with 2's complement.
+            let max_value: U = as_cast::<U, _>(T::MAX) + U::ONE;
+            overflowed || value > max_value || (!is_negative && value == max_value)
+        } else {
+            overflowed
+        }
+    };
+    // Point to the digit that overflowed, or what scaled the digits out of range.
+    let mut overflowed = overflow_index.is_some();
+    let mut index = overflow_index.unwrap_or(last_index);
+    if shift > 0 {
+        if !is_out_of_range(value, overflowed) {
+            index = match number.exponent > 0 {
+                true => number.exponent_index,
+                false => last_integer_index,
+            };
+        }
+        let (power, pow_overflowed) = overflowing_pow::<U>(as_cast(scale), shift as u64);
+        let (result, mul_overflowed) = value.overflowing_mul(power);
+        value = result;
+        overflowed |= pow_overflowed | mul_overflowed;
+    }
+
+    if is_out_of_range(value, overflowed) {
+        match overflow_value::<T, U>(value, is_negative, options.overflow_mode()) {
+            Some(value) => Ok(value),
+            None if T::IS_SIGNED && is_negative => Err(Error::Underflow(index)),
+            None => Err(Error::Overflow(index)),
+        }
+    } else if T::IS_SIGNED && is_negative {
+        Ok(as_cast::<T, _>(value.wrapping_neg()))
+    } else {
+        Ok(as_cast(value))
+    }
+}
+
+/// Validate the punctuation of the format and options.
+#[inline]
+fn check_punctuation<F: Format>(format: F, options: &Options) -> Result<()> {
+    if is_valid_options_punctuation(format.packed(), options.exponent(), options.decimal_point()) {
+        Ok(())
+    } else {
+        Err(Error::InvalidPunctuation)
+    }
+}
+
+/// Parse an integer in exponent or fraction notation from the entire input.
+pub fn parse_complete<T, U, F>(bytes: &[u8], format: F, options: &Options) -> Result<T>
+where
+    T: Integer,
+    U: UnsignedInteger,
+    F: Format,
+{
+    check_punctuation(format, options)?;
+    let number = parse_components::<T, _>(bytes, format, options)?;
+    if number.count != bytes.len() {
+        return Err(Error::InvalidDigit(number.count));
+    }
+    to_integer::<T, U, _>(&number, format, options)
+}
+
+/// Parse an integer in exponent or fraction notation from the start of the input.
+pub fn parse_partial<T, U, F>(
+    bytes: &[u8],
+    format: F,
+    options: &Options,
+) -> Result<(T, usize)>
+where
+    T: Integer,
+    U: UnsignedInteger,
+    F: Format,
+{
+    check_punctuation(format, options)?;
+    let number = parse_components::<T, _>(bytes, format, options)?;
+    let value = to_integer::<T, U, _>(&number, format, options)?;
+    Ok((value, number.count))
+}
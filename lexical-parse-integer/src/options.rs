@@ -1,18 +1,133 @@
 //! Configuration options for parsing integers.
 
+use lexical_util::ascii::is_valid_ascii;
+use lexical_util::error::Error;
 use lexical_util::options::ParseOptions;
+use lexical_util::punctuation::Punctuation;
 use lexical_util::result::Result;
 use static_assertions::const_assert;
 
+/// Enumeration for how to handle integers that overflow the type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OverflowMode {
+    /// Return `Error::Overflow` or `Error::Underflow`.
+    Error,
+    /// Clamp the value to the maximum or minimum value of the type.
+    Saturate,
+    /// Wrap the value modulo `2^BITS`, like two's complement arithmetic.
+    Wrap,
+}
+
 /// Builder for `Options`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct OptionsBuilder {}
+pub struct OptionsBuilder {
+    /// How to handle integers that overflow the type.
+    overflow_mode: OverflowMode,
+    /// Accept exponent and fraction notation for integral values.
+    float_notation: bool,
+    /// Character to designate the exponent component.
+    exponent: u8,
+    /// Character to separate the integer from the fraction components.
+    decimal_point: u8,
+    /// Alternatives for the digit separator, decimal point and signs.
+    punctuation: Punctuation,
+}
 
 impl OptionsBuilder {
     /// Create new options builder with default options.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            overflow_mode: OverflowMode::Error,
+            float_notation: false,
+            exponent: b'e',
+            decimal_point: b'.',
+            punctuation: Punctuation::new(),
+        }
+    }
+
+    // GETTERS
+
+    /// Get how to handle integers that overflow the type.
+    #[inline(always)]
+    pub const fn get_overflow_mode(&self) -> OverflowMode {
+        self.overflow_mode
+    }
+
+    /// Get if exponent and fraction notation is accepted for integral values.
+    #[inline(always)]
+    pub const fn get_float_notation(&self) -> bool {
+        self.float_notation
+    }
+
+    /// Get the character to designate the exponent component.
+    #[inline(always)]
+    pub const fn get_exponent(&self) -> u8 {
+        self.exponent
+    }
+
+    /// Get the character to separate the integer from the fraction components.
+    #[inline(always)]
+    pub const fn get_decimal_point(&self) -> u8 {
+        self.decimal_point
+    }
+
+    /// Get the alternatives for the digit separator, decimal point and signs.
+    #[inline(always)]
+    pub const fn get_punctuation(&self) -> Punctuation {
+        self.punctuation
+    }
+
+    // SETTERS
+
+    /// Set how to handle integers that overflow the type.
+    #[inline(always)]
+    pub const fn overflow_mode(mut self, overflow_mode: OverflowMode) -> Self {
+        self.overflow_mode = overflow_mode;
+        self
+    }
+
+    /// Set if exponent and fraction notation is accepted for integral values.
+    ///
+    /// If set, integers are parsed using the float grammar of the number
+    /// format, such as `1.5e3`, and values that are not exactly integral,
+    /// such as `1.25`, return `Error::NonIntegral`.
+    #[inline(always)]
+    pub const fn float_notation(mut self, float_notation: bool) -> Self {
+        self.float_notation = float_notation;
+        self
+    }
+
+    /// Set the character to designate the exponent component.
+    #[inline(always)]
+    pub const fn exponent(mut self, exponent: u8) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    /// Set the character to separate the integer from the fraction components.
+    #[inline(always)]
+    pub const fn decimal_point(mut self, decimal_point: u8) -> Self {
+        self.decimal_point = decimal_point;
+        self
+    }
+
+    /// Set the alternatives for the digit separator, decimal point and signs.
+    ///
+    /// Each alternative is replaced by the punctuation it stands for
+    /// before parsing, so the digit separator rules of the number format
+    /// still apply.
+    ///
+    /// The options do not know the number format, so alternatives are
+    /// only checked against the decimal point, exponent and signs when
+    /// the options are built. An alternative that is the digit separator
+    /// of the number format, but does not stand for it, is only detected
+    /// when parsing, which returns [`Error::InvalidAlternativePunctuation`]
+    /// for any input.
+    #[inline(always)]
+    pub const fn punctuation(mut self, punctuation: Punctuation) -> Self {
+        self.punctuation = punctuation;
+        self
     }
 
     // BUILDERS
@@ -20,7 +135,9 @@ impl OptionsBuilder {
     /// Check if the builder state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        is_valid_ascii(self.exponent)
+            && is_valid_ascii(self.decimal_point)
+            && self.punctuation.is_valid_for(0, self.decimal_point, self.exponent)
     }
 
     /// Build the Options struct with bounds validation.
@@ -30,12 +147,25 @@ impl OptionsBuilder {
     /// Safe as long as`is_valid` is true.
     #[inline(always)]
     pub const unsafe fn build_unchecked(&self) -> Options {
-        Options {}
+        Options {
+            overflow_mode: self.overflow_mode,
+            float_notation: self.float_notation,
+            exponent: self.exponent,
+            decimal_point: self.decimal_point,
+            punctuation: self.punctuation,
+        }
     }
 
     /// Build the Options struct.
     #[inline(always)]
     pub const fn build(&self) -> Result<Options> {
+        if !is_valid_ascii(self.exponent) {
+            return Err(Error::InvalidExponentSymbol);
+        } else if !is_valid_ascii(self.decimal_point) {
+            return Err(Error::InvalidDecimalPoint);
+        } else if !self.punctuation.is_valid_for(0, self.decimal_point, self.exponent) {
+            return Err(Error::InvalidAlternativePunctuation);
+        }
         // SAFETY: always safe, since it must be valid.
         Ok(unsafe { self.build_unchecked() })
     }
@@ -53,28 +183,126 @@ impl Default for OptionsBuilder {
 /// # Examples
 ///
 /// ```rust
-/// use lexical_parse_integer::options::Options;
+/// use lexical_parse_integer::options::{Options, OverflowMode};
 ///
 /// # pub fn main() {
 /// let options = Options::builder()
+///     .overflow_mode(OverflowMode::Saturate)
 ///     .build()
 ///     .unwrap();
 /// # }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Options {}
+pub struct Options {
+    /// How to handle integers that overflow the type.
+    overflow_mode: OverflowMode,
+    /// Accept exponent and fraction notation for integral values.
+    float_notation: bool,
+    /// Character to designate the exponent component.
+    exponent: u8,
+    /// Character to separate the integer from the fraction components.
+    decimal_point: u8,
+    /// Alternatives for the digit separator, decimal point and signs.
+    punctuation: Punctuation,
+}
 
 impl Options {
     /// Create options with default values.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        // SAFETY: always safe since it uses the default arguments.
+        unsafe { Self::builder().build_unchecked() }
     }
 
     /// Check if the options state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        self.rebuild().is_valid()
+    }
+
+    /// Get how to handle integers that overflow the type.
+    #[inline(always)]
+    pub const fn overflow_mode(&self) -> OverflowMode {
+        self.overflow_mode
+    }
+
+    /// Get if exponent and fraction notation is accepted for integral values.
+    #[inline(always)]
+    pub const fn float_notation(&self) -> bool {
+        self.float_notation
+    }
+
+    /// Get the character to designate the exponent component.
+    #[inline(always)]
+    pub const fn exponent(&self) -> u8 {
+        self.exponent
+    }
+
+    /// Get the character to separate the integer from the fraction components.
+    #[inline(always)]
+    pub const fn decimal_point(&self) -> u8 {
+        self.decimal_point
+    }
+
+    /// Get the alternatives for the digit separator, decimal point and signs.
+    #[inline(always)]
+    pub const fn punctuation(&self) -> Punctuation {
+        self.punctuation
+    }
+
+    // SETTERS
+
+    /// Set how to handle integers that overflow the type.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_overflow_mode(&mut self, overflow_mode: OverflowMode) {
+        self.overflow_mode = overflow_mode
+    }
+
+    /// Set if exponent and fraction notation is accepted for integral values.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_float_notation(&mut self, float_notation: bool) {
+        self.float_notation = float_notation
+    }
+
+    /// Set the character to designate the exponent component.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, but may produce invalid output if the exponent
+    /// is not a valid ASCII character.
+    #[inline(always)]
+    pub unsafe fn set_exponent(&mut self, exponent: u8) {
+        self.exponent = exponent;
+    }
+
+    /// Set the character to separate the integer from the fraction components.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, but may produce invalid output if the decimal point
+    /// is not a valid ASCII character.
+    #[inline(always)]
+    pub unsafe fn set_decimal_point(&mut self, decimal_point: u8) {
+        self.decimal_point = decimal_point;
+    }
+
+    /// Set the alternatives for the digit separator, decimal point and signs.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, but may produce invalid output if the alternatives
+    /// are not valid.
+    #[inline(always)]
+    pub unsafe fn set_punctuation(&mut self, punctuation: Punctuation) {
+        self.punctuation = punctuation;
     }
 
     // BUILDERS
@@ -88,7 +316,13 @@ impl Options {
     /// Create OptionsBuilder using existing values.
     #[inline(always)]
     pub const fn rebuild(&self) -> OptionsBuilder {
-        OptionsBuilder {}
+        OptionsBuilder {
+            overflow_mode: self.overflow_mode,
+            float_notation: self.float_notation,
+            exponent: self.exponent,
+            decimal_point: self.decimal_point,
+            punctuation: self.punctuation,
+        }
     }
 }
 
@@ -8,6 +8,9 @@ use crate::algorithm::{algorithm_complete, algorithm_partial};
 #[cfg(feature = "compact")]
 use crate::compact::{algorithm_complete, algorithm_partial};
 
+use crate::notation;
+use crate::options::Options;
+use lexical_util::format::Format;
 use lexical_util::num::{Integer, UnsignedInteger};
 use lexical_util::result::Result;
 
@@ -15,16 +18,28 @@ use lexical_util::result::Result;
 pub trait ParseInteger: Integer {
     /// Forward complete parser parameters to the backend.
     #[cfg_attr(not(feature = "compact"), inline(always))]
-    fn parse_complete<Unsigned: UnsignedInteger, const FORMAT: u128>(bytes: &[u8]) -> Result<Self> {
-        algorithm_complete::<_, Unsigned, { FORMAT }>(bytes)
+    fn parse_complete<Unsigned: UnsignedInteger, F: Format>(
+        bytes: &[u8],
+        format: F,
+        options: &Options,
+    ) -> Result<Self> {
+        if options.float_notation() {
+            return notation::parse_complete::<_, Unsigned, _>(bytes, format, options);
+        }
+        algorithm_complete::<_, Unsigned, _>(bytes, format, options)
     }
 
     /// Forward partial parser parameters to the backend.
     #[cfg_attr(not(feature = "compact"), inline(always))]
-    fn parse_partial<Unsigned: UnsignedInteger, const FORMAT: u128>(
+    fn parse_partial<Unsigned: UnsignedInteger, F: Format>(
         bytes: &[u8],
+        format: F,
+        options: &Options,
     ) -> Result<(Self, usize)> {
-        algorithm_partial::<_, Unsigned, { FORMAT }>(bytes)
+        if options.float_notation() {
+            return notation::parse_partial::<_, Unsigned, _>(bytes, format, options);
+        }
+        algorithm_partial::<_, Unsigned, _>(bytes, format, options)
     }
 }
 
@@ -13,9 +13,10 @@
 //! 2. Overflow checking on invalid digits for partial parsers, while
 //!     just returning invalid digits for complete parsers.
 //! 3. A format-aware sign parser.
-//! 4. Digit parsing algorithms which explicitly wrap on overflow, for no
-//!     additional overhead. This has major performance wins for **most**
-//!     real-world integers, so most valid input will be substantially faster.
+//! 4. Digit parsing algorithms which explicitly wrap on overflow, and
+//!     record if they wrapped without branching, for minimal overhead. This
+//!     has major performance wins for **most** real-world integers, so most
+//!     valid input will be substantially faster.
 //! 5. An algorithm to detect if overflow occurred. This is comprehensive,
 //!     and short-circuits for common cases.
 //! 6. A parsing algorithm for unsigned integers, always producing positive
@@ -24,7 +25,8 @@
 
 #![doc(hidden)]
 
-use lexical_util::format::NumberFormat;
+use crate::options::OverflowMode;
+use lexical_util::format::Format;
 use lexical_util::num::{as_cast, Integer, UnsignedInteger};
 use lexical_util::step::max_step;
 
@@ -53,8 +55,10 @@ macro_rules! into_ok_partial {
 macro_rules! invalid_digit_complete {
     (
         $value:ident,
+        $overflowed:ident,
         $iter:ident,
         $format:ident,
+        $options:ident,
         $is_negative:ident,
         $start_index:ident,
         $t:ident,
@@ -70,21 +74,28 @@ macro_rules! invalid_digit_complete {
 macro_rules! invalid_digit_partial {
     (
         $value:ident,
+        $overflowed:ident,
         $iter:ident,
         $format:ident,
+        $options:ident,
         $is_negative:ident,
         $start_index:ident,
         $t:ident,
         $u:ident
     ) => {{
-        let radix = NumberFormat::<{ $format }>::MANTISSA_RADIX;
+        let radix = $format.mantissa_radix();
         let count = $iter.current_count() - $start_index - 1;
-        if is_overflow::<$t, $u, $format>($value, count, $is_negative) {
-            let min = min_step(radix, <$t as Integer>::BITS, <$t>::IS_SIGNED);
-            if <$t>::IS_SIGNED && $is_negative {
-                into_error!(Underflow, (count - 1).min(min + 1))
-            } else {
-                into_error!(Overflow, (count - 1).min(min + 1))
+        if is_overflow::<$t, $u, _>($value, $overflowed, count, $is_negative, $format) {
+            match overflow_value::<$t, $u>($value, $is_negative, $options.overflow_mode()) {
+                Some(value) => into_ok_partial!(value, $iter.cursor() - 1),
+                None => {
+                    let min = min_step(radix, <$t as Integer>::BITS, <$t>::IS_SIGNED);
+                    if <$t>::IS_SIGNED && $is_negative {
+                        into_error!(Underflow, (count - 1).min(min + 1))
+                    } else {
+                        into_error!(Overflow, (count - 1).min(min + 1))
+                    }
+                },
             }
         } else if <$t>::IS_SIGNED && $is_negative {
             into_ok_partial!($value.wrapping_neg(), $iter.cursor() - 1)
@@ -142,26 +153,25 @@ macro_rules! parse_sign {
 
 /// Determine if the value has overflowed.
 #[cfg_attr(not(feature = "compact"), inline)]
-pub(super) fn is_overflow<T, U, const FORMAT: u128>(
+pub(super) fn is_overflow<T, U, F>(
     value: U,
+    overflowed: bool,
     count: usize,
     is_negative: bool,
+    format: F,
 ) -> bool
 where
     T: Integer,
     U: UnsignedInteger,
+    F: Format,
 {
-    let format = NumberFormat::<{ FORMAT }> {};
-
     let max = max_step(format.radix(), T::BITS, T::IS_SIGNED);
-    let radix: U = as_cast(format.radix());
-    let min_value: U = radix.pow(max as u32 - 1);
     if T::IS_SIGNED {
         // Signed type: have to deal with 2's complement.
         let max_value: U = as_cast::<U, _>(T::MAX) + U::ONE;
         if count > max
             || (count == max
-                && (value < min_value || value > max_value || (!is_negative && value == max_value)))
+                && (overflowed || value > max_value || (!is_negative && value == max_value)))
         {
             // Must have overflowed, or wrapped.
             // 1. Guaranteed overflow due to too many digits.
@@ -170,19 +180,39 @@ where
             // 4. Guaranteed overflow due to 2's complement.
             return true;
         }
-    } else if count > max || (count == max && value < min_value) {
+    } else if count > max || (count == max && overflowed) {
         // Must have overflowed: too many digits or wrapped.
         return true;
     }
     false
 }
 
+/// Get the value of an integer that overflowed, or `None` if it is an error.
+///
+/// The digits are parsed with wrapping arithmetic, so the value is
+/// already the result modulo `2^BITS`.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub(super) fn overflow_value<T, U>(value: U, is_negative: bool, mode: OverflowMode) -> Option<T>
+where
+    T: Integer,
+    U: UnsignedInteger,
+{
+    match mode {
+        OverflowMode::Error => None,
+        OverflowMode::Saturate if T::IS_SIGNED && is_negative => Some(T::MIN),
+        OverflowMode::Saturate => Some(T::MAX),
+        OverflowMode::Wrap if T::IS_SIGNED && is_negative => Some(as_cast(value.wrapping_neg())),
+        OverflowMode::Wrap => Some(as_cast(value)),
+    }
+}
+
 /// Parse the value for the given type.
 macro_rules! parse_value {
     (
         $iter:ident,
         $is_negative:ident,
         $format:ident,
+        $options:ident,
         $start_index:ident,
         $t:ident,
         $u:ident,
@@ -196,16 +226,33 @@ macro_rules! parse_value {
         // and cast the value over, which is fast. Leads to substantial
         // improvements due to decreased branching for all but `i8`.
         let mut value = <$u>::ZERO;
-        let format = NumberFormat::<{ $format }> {};
-        $parser!(value, $iter, $format, $is_negative, $start_index, $t, $u, $invalid_digit);
+        let mut overflowed = false;
+        let format = $format;
+        $parser!(
+            value,
+            overflowed,
+            $iter,
+            $format,
+            $options,
+            $is_negative,
+            $start_index,
+            $t,
+            $u,
+            $invalid_digit
+        );
         let count = $iter.current_count() - $start_index;
 
-        if is_overflow::<$t, $u, $format>(value, count, $is_negative) {
-            let min = min_step(format.radix(), <$t as Integer>::BITS, <$t>::IS_SIGNED);
-            if <$t>::IS_SIGNED && $is_negative {
-                into_error!(Underflow, (count - 1).min(min + 1))
-            } else {
-                into_error!(Overflow, (count - 1).min(min + 1))
+        if is_overflow::<$t, $u, _>(value, overflowed, count, $is_negative, $format) {
+            match overflow_value::<$t, $u>(value, $is_negative, $options.overflow_mode()) {
+                Some(value) => $into_ok!(value, $iter.length()),
+                None => {
+                    let min = min_step(format.radix(), <$t as Integer>::BITS, <$t>::IS_SIGNED);
+                    if <$t>::IS_SIGNED && $is_negative {
+                        into_error!(Underflow, (count - 1).min(min + 1))
+                    } else {
+                        into_error!(Overflow, (count - 1).min(min + 1))
+                    }
+                },
             }
         } else if <$t>::IS_SIGNED && $is_negative {
             // Need to cast it to the signed type first, so we don't
@@ -223,16 +270,18 @@ macro_rules! parse_value {
 macro_rules! parse_1digit {
     (
         $value:ident,
+        $overflowed:ident,
         $iter:ident,
         $format:ident,
+        $options:ident,
         $is_negative:ident,
         $start_index:ident,
         $t:ident,
         $u:ident,
         $invalid_digit:ident
     ) => {{
-        let format = NumberFormat::<{ $format }>;
-        let radix = NumberFormat::<{ $format }>::MANTISSA_RADIX;
+        let format = $format;
+        let radix = $format.mantissa_radix();
 
         // Do our slow parsing algorithm: 1 digit at a time.
         while let Some(&c) = $iter.next() {
@@ -264,8 +313,10 @@ macro_rules! parse_1digit {
                     // Might have handled our base-prefix here.
                     return $invalid_digit!(
                         $value,
+                        $overflowed,
                         $iter,
                         $format,
+                        $options,
                         $is_negative,
                         $start_index,
                         $t,
@@ -273,8 +324,10 @@ macro_rules! parse_1digit {
                     );
                 },
             };
-            $value = $value.wrapping_mul(as_cast(radix));
-            $value = $value.wrapping_add(as_cast(digit));
+            let (value, mul_overflowed) = $value.overflowing_mul(as_cast(radix));
+            let (value, add_overflowed) = value.overflowing_add(as_cast(digit));
+            $value = value;
+            $overflowed |= mul_overflowed | add_overflowed;
         }
     }};
 }
@@ -288,13 +341,14 @@ macro_rules! algorithm {
     (
         $bytes:ident,
         $format:ident,
+        $options:ident,
         $t:ident,
         $u:ident,
         $parser:ident,
         $invalid_digit:ident,
         $into_ok:ident
     ) => {{
-        let format = NumberFormat::<{ $format }> {};
+        let format = $format;
 
         // WARNING:
         // --------
@@ -318,7 +372,7 @@ macro_rules! algorithm {
         // Removes conditional paths, to, which simplifies maintenance.
         // The skip version of the iterator automatically coalesces to
         // the noskip iterator.
-        let mut byte = $bytes.bytes::<{ $format }>();
+        let mut byte = $bytes.bytes(format);
 
         let mut iter = byte.integer_iter();
         let (is_negative, shift) = parse_sign!(iter, format);
@@ -384,6 +438,7 @@ macro_rules! algorithm {
             iter,
             is_negative,
             $format,
+            $options,
             start_index,
             $t,
             $u,
@@ -0,0 +1,307 @@
+//! Parse user-defined, fixed-width wide integers.
+//!
+//! These parsers support any type implementing [`WideInteger`], such as
+//! 256- or 512-bit integers, using the same integer grammar, radix, digit
+//! separators, digit grouping and whitespace as the primitive parsers.
+//! Overflow is detected exactly, and is handled by
+//! [`Options::overflow_mode`]. The other options only apply to the
+//! primitive integers.
+//!
+//! Wide integers are defined outside of lexical, so they cannot implement
+//! [`FromLexical`] in lexical, however, they may implement it using these
+//! parsers.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_integer::format::STANDARD;
+//! use lexical_parse_integer::wide::{parse_complete, WideInteger};
+//! use lexical_parse_integer::{Error, Options};
+//!
+//! #[derive(Clone, Debug, PartialEq)]
+//! struct U256([u64; 4]);
+//!
+//! impl WideInteger for U256 {
+//!     const IS_SIGNED: bool = false;
+//!     const ZERO: Self = U256([0; 4]);
+//!
+//!     fn limbs(&self) -> &[u64] {
+//!         &self.0
+//!     }
+//!
+//!     fn limbs_mut(&mut self) -> &mut [u64] {
+//!         &mut self.0
+//!     }
+//! }
+//!
+//! # pub fn main() {
+//! let options = Options::new();
+//! let parse = |bytes| parse_complete::<U256, STANDARD>(bytes, &options);
+//! assert_eq!(parse(b"18446744073709551616"), Ok(U256([0, 1, 0, 0])));
+//! let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+//! assert_eq!(parse(max.as_bytes()), Ok(U256([u64::MAX; 4])));
+//! let overflow = max.replace("935", "936");
+//! assert_eq!(parse(overflow.as_bytes()), Err(Error::Overflow(77)));
+//! # }
+//! ```
+//!
+//! [`FromLexical`]: crate::FromLexical
+
+use crate::options::{Options, OverflowMode};
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::{Format, NumberFormat};
+use lexical_util::grouping::check_grouping;
+use lexical_util::iterator::{AsBytes, BytesIter};
+use lexical_util::result::Result;
+use lexical_util::whitespace::{parse_partial_whitespace, parse_whitespace};
+#[doc(inline)]
+pub use lexical_util::wide::WideInteger;
+
+/// Set the value to the largest value of the type.
+#[inline]
+fn set_max<T: WideInteger>(value: &mut T) {
+    for limb in value.limbs_mut() {
+        *limb = u64::MAX;
+    }
+    if T::IS_SIGNED {
+        if let Some(last) = value.limbs_mut().last_mut() {
+            *last >>= 1;
+        }
+    }
+}
+
+/// Set the value to the smallest value of a signed type.
+#[inline]
+fn set_min<T: WideInteger>(value: &mut T) {
+    for limb in value.limbs_mut() {
+        *limb = 0;
+    }
+    if let Some(last) = value.limbs_mut().last_mut() {
+        *last = 1 << 63;
+    }
+}
+
+/// Get if the unsigned magnitude is the smallest value of a signed type.
+#[inline]
+fn is_min<T: WideInteger>(value: &T) -> bool {
+    match value.limbs().split_last() {
+        Some((&last, rest)) => last == 1 << 63 && rest.iter().all(|&x| x == 0),
+        None => false,
+    }
+}
+
+/// Calculate the maximum number of digits that can always be parsed without overflow.
+///
+/// This matches `min_step` for the primitive integers.
+#[cold]
+fn min_step<T: WideInteger>(radix: u32) -> usize {
+    let mut value = T::ZERO;
+    match value.limbs_mut().first_mut() {
+        Some(first) => *first = 1,
+        // Without limbs, only zero can be parsed.
+        None => return 0,
+    }
+    let mut step = 0;
+    while value.mul_small(radix as u64, 0) == 0 && !value.is_negative() {
+        step += 1;
+    }
+    step
+}
+
+/// Get the result of a value that overflowed, or the error.
+#[cold]
+fn overflow_value<T: WideInteger>(
+    mut value: T,
+    is_negative: bool,
+    count: usize,
+    radix: u32,
+    mode: OverflowMode,
+) -> Result<T> {
+    match mode {
+        OverflowMode::Error => {
+            let index = (count - 1).min(min_step::<T>(radix) + 1);
+            if T::IS_SIGNED && is_negative {
+                Err(Error::Underflow(index))
+            } else {
+                Err(Error::Overflow(index))
+            }
+        },
+        OverflowMode::Saturate if T::IS_SIGNED && is_negative => {
+            set_min(&mut value);
+            Ok(value)
+        },
+        OverflowMode::Saturate => {
+            set_max(&mut value);
+            Ok(value)
+        },
+        OverflowMode::Wrap => {
+            if is_negative {
+                value.wrapping_neg();
+            }
+            Ok(value)
+        },
+    }
+}
+
+/// Parse a wide integer, returning the value and the number of processed bytes.
+fn parse_wide<T, F>(
+    bytes: &[u8],
+    format: F,
+    options: &Options,
+    is_partial: bool,
+) -> Result<(T, usize)>
+where
+    T: WideInteger,
+    F: Format,
+{
+    let radix = format.mantissa_radix();
+    let mut byte = bytes.bytes(format);
+    let mut iter = byte.integer_iter();
+
+    // SIGN
+
+    let (is_negative, shift) = match iter.peek() {
+        Some(&b'+') if format.no_positive_mantissa_sign() => {
+            return Err(Error::InvalidPositiveSign(0));
+        },
+        Some(&b'+') => (false, 1),
+        Some(&b'-') if T::IS_SIGNED => (true, 1),
+        Some(_) if format.required_mantissa_sign() => return Err(Error::MissingSign(0)),
+        _ => (false, 0),
+    };
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { iter.step_by_unchecked(shift) };
+    if iter.is_done() {
+        return Err(Error::Empty(shift));
+    }
+    let zeros = iter.skip_zeros();
+
+    // Check to see if we have a valid base prefix.
+    let base_prefix = format.base_prefix();
+    let mut is_prefix = false;
+    if cfg!(feature = "format") && base_prefix != 0 && zeros == 1 {
+        if let Some(&c) = iter.peek() {
+            is_prefix = if format.case_sensitive_base_prefix() {
+                c == base_prefix
+            } else {
+                c.eq_ignore_ascii_case(&base_prefix)
+            };
+            if is_prefix {
+                // SAFETY: safe since `iter.peek()` is not empty.
+                unsafe { iter.step_unchecked() };
+                if iter.is_done() {
+                    return Err(Error::Empty(iter.cursor()));
+                }
+            }
+        }
+    }
+
+    // Check for invalid leading zeros.
+    if cfg!(feature = "format") && !is_prefix && format.no_integer_leading_zeros() && zeros != 0 {
+        let index = iter.cursor() - zeros;
+        if zeros > 1 {
+            return Err(Error::InvalidLeadingZeros(index));
+        }
+        return match iter.peek().map(|&c| char_to_digit_const(c, radix)) {
+            Some(Some(_)) => Err(Error::InvalidLeadingZeros(index)),
+            _ if is_partial => Ok((T::ZERO, index)),
+            _ => Ok((T::ZERO, bytes.len())),
+        };
+    }
+
+    // DIGITS
+
+    // Accumulate as many digits as fit in a `u64` before multiplying
+    // the wide integer, which is much faster than a digit at a time.
+    let mut value = T::ZERO;
+    let mut overflowed = false;
+    let mut chunk = 0_u64;
+    let mut power = 1_u64;
+    let mut count = 0;
+    let mut end = bytes.len();
+    while let Some(&c) = iter.next() {
+        let digit = match char_to_digit_const(c, radix) {
+            Some(digit) => digit,
+            None => {
+                // Need to check for a base suffix, which must follow at least 1 digit.
+                let base_suffix = format.base_suffix();
+                if cfg!(feature = "format") && base_suffix != 0 && count > 0 {
+                    let is_suffix = if format.case_sensitive_base_suffix() {
+                        c == base_suffix
+                    } else {
+                        c.eq_ignore_ascii_case(&base_suffix)
+                    };
+                    if is_suffix && iter.is_done() {
+                        break;
+                    } else if is_suffix {
+                        // SAFETY: safe since the iterator is not empty.
+                        unsafe { iter.step_unchecked() };
+                    }
+                }
+                if !is_partial {
+                    return Err(Error::InvalidDigit(iter.cursor() - 1));
+                }
+                end = iter.cursor() - 1;
+                break;
+            },
+        };
+        if power > u64::MAX / radix as u64 {
+            overflowed |= value.mul_small(power, chunk) != 0;
+            chunk = 0;
+            power = 1;
+        }
+        chunk = chunk * radix as u64 + digit as u64;
+        power *= radix as u64;
+        count += 1;
+    }
+    overflowed |= value.mul_small(power, chunk) != 0;
+
+    // Signed types can hold 1 more negative value than positive values.
+    if T::IS_SIGNED && value.is_negative() && !(is_negative && is_min(&value)) {
+        overflowed = true;
+    }
+    if overflowed {
+        overflow_value(value, is_negative, count, radix, options.overflow_mode())
+            .map(|value| (value, end))
+    } else {
+        if is_negative {
+            value.wrapping_neg();
+        }
+        Ok((value, end))
+    }
+}
+
+/// Parse a wide integer from the entire input.
+#[inline]
+pub fn parse_complete<T, const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<T>
+where
+    T: WideInteger,
+{
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    }
+    parse_whitespace(bytes, format, |bytes| {
+        check_grouping(bytes, format)?;
+        parse_wide::<T, _>(bytes, format, options, false).map(|(value, _)| value)
+    })
+}
+
+/// Parse a wide integer from the start of the input.
+///
+/// Returns the value and the number of processed bytes.
+#[inline]
+pub fn parse_partial<T, const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<(T, usize)>
+where
+    T: WideInteger,
+{
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    }
+    parse_partial_whitespace(bytes, format, |bytes| {
+        check_grouping(bytes, format)?;
+        parse_wide::<T, _>(bytes, format, options, true)
+    })
+}
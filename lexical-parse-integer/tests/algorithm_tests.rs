@@ -4,46 +4,50 @@
 mod util;
 
 use lexical_parse_integer::algorithm;
-use lexical_util::format::STANDARD;
+use lexical_parse_integer::options::Options;
+use lexical_util::format::{NumberFormat, STANDARD};
 use lexical_util::iterator::AsBytes;
 use proptest::prelude::*;
 #[cfg(feature = "power-of-two")]
 use util::from_radix;
 
+const OPTIONS: Options = Options::new();
+const STANDARD_FORMAT: NumberFormat<STANDARD> = NumberFormat::<{ STANDARD }> {};
+
 #[test]
 fn test_is_4digits() {
     let value: u32 = 0x31_32_33_34;
     #[cfg(feature = "power-of-two")]
-    assert!(!algorithm::is_4digits::<{ from_radix(4) }>(value));
+    assert!(!algorithm::is_4digits(value, NumberFormat::<{ from_radix(4) }> {}));
     #[cfg(feature = "radix")]
-    assert!(algorithm::is_4digits::<{ from_radix(5) }>(value));
-    assert!(algorithm::is_4digits::<{ STANDARD }>(value));
+    assert!(algorithm::is_4digits(value, NumberFormat::<{ from_radix(5) }> {}));
+    assert!(algorithm::is_4digits(value, STANDARD_FORMAT));
 
     let value: u32 = 0x29_30_39_38;
-    assert!(!algorithm::is_4digits::<{ STANDARD }>(value));
+    assert!(!algorithm::is_4digits(value, STANDARD_FORMAT));
 
     let value: u32 = 0x31_32_33_40;
-    assert!(!algorithm::is_4digits::<{ STANDARD }>(value));
+    assert!(!algorithm::is_4digits(value, STANDARD_FORMAT));
 
     let value: u32 = 0x31_32_33_39;
     #[cfg(feature = "radix")]
-    assert!(!algorithm::is_4digits::<{ from_radix(9) }>(value));
-    assert!(algorithm::is_4digits::<{ STANDARD }>(value));
+    assert!(!algorithm::is_4digits(value, NumberFormat::<{ from_radix(9) }> {}));
+    assert!(algorithm::is_4digits(value, STANDARD_FORMAT));
 }
 
 #[test]
 fn test_parse_4digits() {
-    assert_eq!(algorithm::parse_4digits::<{ STANDARD }>(0x31_32_33_34), 4321);
+    assert_eq!(algorithm::parse_4digits(0x31_32_33_34, STANDARD_FORMAT), 4321);
     #[cfg(feature = "radix")]
-    assert_eq!(algorithm::parse_4digits::<{ from_radix(5) }>(0x31_32_33_34), 586);
-    assert_eq!(algorithm::parse_4digits::<{ STANDARD }>(0x36_37_38_39), 9876);
+    assert_eq!(algorithm::parse_4digits(0x31_32_33_34, NumberFormat::<{ from_radix(5) }> {}), 586);
+    assert_eq!(algorithm::parse_4digits(0x36_37_38_39, STANDARD_FORMAT), 9876);
 }
 
 #[test]
 fn test_try_parse_4digits() {
     let parse = |bytes: &[u8]| {
-        let mut digits = bytes.bytes::<{ STANDARD }>();
-        algorithm::try_parse_4digits::<u32, _, STANDARD>(&mut digits.integer_iter())
+        let mut digits = bytes.bytes(STANDARD_FORMAT);
+        algorithm::try_parse_4digits::<u32, _, _>(&mut digits.integer_iter(), STANDARD_FORMAT)
     };
     assert_eq!(parse(b"1234"), Some(1234));
     assert_eq!(parse(b"123"), None);
@@ -57,57 +61,57 @@ fn test_try_parse_4digits() {
 fn test_is_8digits() {
     let value: u64 = 0x31_32_33_34_35_36_37_38;
     #[cfg(feature = "power-of-two")]
-    assert!(!algorithm::is_8digits::<{ from_radix(4) }>(value));
+    assert!(!algorithm::is_8digits(value, NumberFormat::<{ from_radix(4) }> {}));
     #[cfg(feature = "radix")]
-    assert!(!algorithm::is_8digits::<{ from_radix(5) }>(value));
-    assert!(algorithm::is_8digits::<{ STANDARD }>(value));
+    assert!(!algorithm::is_8digits(value, NumberFormat::<{ from_radix(5) }> {}));
+    assert!(algorithm::is_8digits(value, STANDARD_FORMAT));
 
     let value: u64 = 0x29_30_31_32_33_34_35_36;
-    assert!(!algorithm::is_8digits::<{ STANDARD }>(value));
+    assert!(!algorithm::is_8digits(value, STANDARD_FORMAT));
 
     let value: u64 = 0x30_31_32_33_34_35_36_40;
-    assert!(!algorithm::is_8digits::<{ STANDARD }>(value));
+    assert!(!algorithm::is_8digits(value, STANDARD_FORMAT));
 
     let value: u64 = 0x31_32_33_34_35_36_37_39;
     #[cfg(feature = "radix")]
-    assert!(!algorithm::is_8digits::<{ from_radix(9) }>(value));
-    assert!(algorithm::is_8digits::<{ STANDARD }>(value));
+    assert!(!algorithm::is_8digits(value, NumberFormat::<{ from_radix(9) }> {}));
+    assert!(algorithm::is_8digits(value, STANDARD_FORMAT));
 }
 
 #[test]
 fn test_parse_8digits() {
     // 10000000
     let value: u64 = 0x30_30_30_30_30_30_30_31;
-    assert_eq!(algorithm::parse_8digits::<{ STANDARD }>(value), 10000000);
+    assert_eq!(algorithm::parse_8digits(value, STANDARD_FORMAT), 10000000);
     #[cfg(feature = "radix")]
-    assert_eq!(algorithm::parse_8digits::<{ from_radix(5) }>(value), 78125);
+    assert_eq!(algorithm::parse_8digits(value, NumberFormat::<{ from_radix(5) }> {}), 78125);
 
     // 00000010
     let value: u64 = 0x30_31_30_30_30_30_30_30;
-    assert_eq!(algorithm::parse_8digits::<{ STANDARD }>(value), 10);
+    assert_eq!(algorithm::parse_8digits(value, STANDARD_FORMAT), 10);
     #[cfg(feature = "radix")]
-    assert_eq!(algorithm::parse_8digits::<{ from_radix(5) }>(value), 5);
+    assert_eq!(algorithm::parse_8digits(value, NumberFormat::<{ from_radix(5) }> {}), 5);
 
     // 12344321
     let value: u64 = 0x31_32_33_34_34_33_32_31;
-    assert_eq!(algorithm::parse_8digits::<{ STANDARD }>(value), 12344321);
+    assert_eq!(algorithm::parse_8digits(value, STANDARD_FORMAT), 12344321);
     #[cfg(feature = "power-of-two")]
-    assert_eq!(algorithm::parse_8digits::<{ from_radix(8) }>(value), 2738385);
+    assert_eq!(algorithm::parse_8digits(value, NumberFormat::<{ from_radix(8) }> {}), 2738385);
 
     #[cfg(feature = "radix")]
     {
-        assert_eq!(algorithm::parse_8digits::<{ from_radix(9) }>(value), 6052420);
-        assert_eq!(algorithm::parse_8digits::<{ from_radix(7) }>(value), 1120400);
-        assert_eq!(algorithm::parse_8digits::<{ from_radix(6) }>(value), 402745);
-        assert_eq!(algorithm::parse_8digits::<{ from_radix(5) }>(value), 121836);
+        assert_eq!(algorithm::parse_8digits(value, NumberFormat::<{ from_radix(9) }> {}), 6052420);
+        assert_eq!(algorithm::parse_8digits(value, NumberFormat::<{ from_radix(7) }> {}), 1120400);
+        assert_eq!(algorithm::parse_8digits(value, NumberFormat::<{ from_radix(6) }> {}), 402745);
+        assert_eq!(algorithm::parse_8digits(value, NumberFormat::<{ from_radix(5) }> {}), 121836);
     }
 }
 
 #[test]
 fn test_try_parse_8digits() {
     let parse = |bytes: &[u8]| {
-        let mut digits = bytes.bytes::<{ STANDARD }>();
-        algorithm::try_parse_8digits::<u64, _, STANDARD>(&mut digits.integer_iter())
+        let mut digits = bytes.bytes(STANDARD_FORMAT);
+        algorithm::try_parse_8digits::<u64, _, _>(&mut digits.integer_iter(), STANDARD_FORMAT)
     };
 
     assert_eq!(parse(b"12345678"), Some(12345678));
@@ -121,14 +125,21 @@ fn test_try_parse_8digits() {
 #[cfg(feature = "power-of-two")]
 macro_rules! parse_radix {
     ($i:literal) => {
-        |bytes: &[u8]| algorithm::algorithm_partial::<u32, u32, { from_radix($i) }>(bytes)
+        |bytes: &[u8]| {
+            let format = NumberFormat::<{ from_radix($i) }> {};
+            algorithm::algorithm_partial::<u32, u32, _>(bytes, format, &OPTIONS)
+        }
     };
 }
 
 #[test]
 fn algorithm_test() {
-    let parse_u32 = |bytes: &[u8]| algorithm::algorithm_partial::<u32, u32, STANDARD>(bytes);
-    let parse_i32 = |bytes: &[u8]| algorithm::algorithm_partial::<i32, u32, STANDARD>(bytes);
+    let parse_u32 = |bytes: &[u8]| {
+        algorithm::algorithm_partial::<u32, u32, _>(bytes, STANDARD_FORMAT, &OPTIONS)
+    };
+    let parse_i32 = |bytes: &[u8]| {
+        algorithm::algorithm_partial::<i32, u32, _>(bytes, STANDARD_FORMAT, &OPTIONS)
+    };
 
     assert_eq!(parse_u32(b"12345"), Ok((12345, 5)));
     assert_eq!(parse_u32(b"+12345"), Ok((12345, 6)));
@@ -160,8 +171,12 @@ fn algorithm_test() {
 
 #[test]
 fn algorithm_128_test() {
-    let parse_u128 = |bytes: &[u8]| algorithm::algorithm_partial::<u128, u128, STANDARD>(bytes);
-    let parse_i128 = |bytes: &[u8]| algorithm::algorithm_partial::<i128, u128, STANDARD>(bytes);
+    let parse_u128 = |bytes: &[u8]| {
+        algorithm::algorithm_partial::<u128, u128, _>(bytes, STANDARD_FORMAT, &OPTIONS)
+    };
+    let parse_i128 = |bytes: &[u8]| {
+        algorithm::algorithm_partial::<i128, u128, _>(bytes, STANDARD_FORMAT, &OPTIONS)
+    };
 
     assert_eq!(parse_u128(b"12345"), Ok((12345, 5)));
     assert_eq!(parse_u128(b"+12345"), Ok((12345, 6)));
@@ -183,7 +198,7 @@ proptest! {
     )
     {
         let v = (a << 24) | (b << 16) | (c << 8) | d;
-        let actual = algorithm::parse_4digits::<{ STANDARD }>(v);
+        let actual = algorithm::parse_4digits(v, STANDARD_FORMAT);
         let expected = (a - 0x30) + 10 * (b - 0x30) + 100 * (c - 0x30) + 1000 * (d - 0x30);
         prop_assert_eq!(actual, expected);
     }
@@ -204,7 +219,7 @@ proptest! {
         let v1 = (a << 24) | (b << 16) | (c << 8) | d;
         let v2 = (e << 24) | (f << 16) | (g << 8) | h;
         let v = (v1 << 32) | v2;
-        let actual = algorithm::parse_8digits::<{ STANDARD }>(v);
+        let actual = algorithm::parse_8digits(v, STANDARD_FORMAT);
         let e1 = (a - 0x30) + 10 * (b - 0x30) + 100 * (c - 0x30) + 1000 * (d - 0x30);
         let e2 = (e - 0x30) + 10 * (f - 0x30) + 100 * (g - 0x30) + 1000 * (h - 0x30);
         let expected = e1 + 10000 * e2;
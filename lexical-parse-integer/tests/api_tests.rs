@@ -1,7 +1,13 @@
 #[cfg(feature = "power-of-two")]
 mod util;
 
-use lexical_parse_integer::{FromLexical, FromLexicalWithOptions, Options};
+#[cfg(feature = "format")]
+use lexical_parse_integer::punctuation::{Punctuation, MINUS_SIGN, NARROW_NO_BREAK_SPACE};
+#[cfg(feature = "format")]
+use lexical_parse_integer::DigitGrouping;
+#[cfg(feature = "format")]
+use lexical_parse_integer::FromLexicalWithFormat;
+use lexical_parse_integer::{FromLexical, FromLexicalWithOptions, Options, OverflowMode};
 use lexical_util::error::Error;
 #[cfg(feature = "format")]
 use lexical_util::format::NumberFormatBuilder;
@@ -147,6 +153,67 @@ fn options_test() {
     assert_eq!(Ok(0), i128::from_lexical_with_options::<STANDARD>(b"0", &options));
 }
 
+fn parse_with_mode<T>(bytes: &[u8], overflow_mode: OverflowMode) -> Result<T, Error>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    let options = Options::builder().overflow_mode(overflow_mode).build().unwrap();
+    T::from_lexical_with_options::<STANDARD>(bytes, &options)
+}
+
+#[test]
+fn saturate_test() {
+    let parse_u8 = |bytes| parse_with_mode::<u8>(bytes, OverflowMode::Saturate);
+    let parse_i8 = |bytes| parse_with_mode::<i8>(bytes, OverflowMode::Saturate);
+    assert_eq!(Ok(255), parse_u8(b"256"));
+    assert_eq!(Ok(255), parse_u8(b"99999999999"));
+    assert_eq!(Ok(200), parse_u8(b"200"));
+    assert_eq!(Err(Error::InvalidDigit(3)), parse_u8(b"256a"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse_u8(b"-1"));
+    assert_eq!(Ok(127), parse_i8(b"128"));
+    assert_eq!(Ok(-128), parse_i8(b"-129"));
+    assert_eq!(Ok(-128), parse_i8(b"-128"));
+
+    let mode = OverflowMode::Saturate;
+    assert_eq!(Ok(65535), parse_with_mode::<u16>(b"70000", mode));
+    assert_eq!(Ok(u64::MAX), parse_with_mode::<u64>(b"18446744073709551616", mode));
+    assert_eq!(Ok(i64::MIN), parse_with_mode::<i64>(b"-9223372036854775809", mode));
+    let bytes = b"170141183460469231731687303715884105728";
+    assert_eq!(Ok(i128::MAX), parse_with_mode::<i128>(bytes, mode));
+    let bytes = b"1000000000000000000000000000000000000000";
+    assert_eq!(Ok(u128::MAX), parse_with_mode::<u128>(bytes, mode));
+}
+
+#[test]
+fn wrap_test() {
+    let parse_u8 = |bytes| parse_with_mode::<u8>(bytes, OverflowMode::Wrap);
+    let parse_i8 = |bytes| parse_with_mode::<i8>(bytes, OverflowMode::Wrap);
+    assert_eq!(Ok(0), parse_u8(b"256"));
+    assert_eq!(Ok(44), parse_u8(b"300"));
+    assert_eq!(Err(Error::InvalidDigit(3)), parse_u8(b"256a"));
+    assert_eq!(Ok(-128), parse_i8(b"128"));
+    assert_eq!(Ok(127), parse_i8(b"-129"));
+
+    let mode = OverflowMode::Wrap;
+    assert_eq!(Ok(4464), parse_with_mode::<u16>(b"70000", mode));
+    assert_eq!(Ok(0), parse_with_mode::<u64>(b"18446744073709551616", mode));
+    assert_eq!(Ok(i64::MAX), parse_with_mode::<i64>(b"-9223372036854775809", mode));
+    let bytes = b"170141183460469231731687303715884105728";
+    assert_eq!(Ok(i128::MIN), parse_with_mode::<i128>(bytes, mode));
+}
+
+#[test]
+fn wrapped_overflow_test() {
+    // These have the maximum number of digits, and wrap past the minimum value.
+    assert_eq!(Err(Error::Overflow(2)), u8::from_lexical(b"356"));
+    assert_eq!(Err(Error::Overflow(2)), i8::from_lexical(b"356"));
+    assert_eq!(Err(Error::Underflow(2)), i8::from_lexical(b"-356"));
+    assert_eq!(Err(Error::Overflow(4)), u16::from_lexical(b"76000"));
+    assert_eq!(Err(Error::Overflow(4)), i16::from_lexical(b"76000"));
+    assert_eq!(Err(Error::Overflow(19)), u64::from_lexical(b"50000000000000000000"));
+    assert_eq!(Ok(4464), parse_with_mode::<u16>(b"135536", OverflowMode::Wrap));
+}
+
 #[test]
 #[cfg(feature = "power-of-two")]
 fn i32_binary_test() {
@@ -352,6 +419,34 @@ fn base_prefix_and_suffix_test() {
     assert!(i32::from_lexical_with_options::<FORMAT>(b"+0x", &options).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn from_lexical_with_format_test() {
+    use core::num;
+
+    let options = Options::new();
+    let format = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'!'))
+        .integer_internal_digit_separator(true)
+        .build_dynamic()
+        .unwrap();
+    assert_eq!(i32::from_lexical_with_format(b"1!234", format, &options), Ok(1234));
+    let result = i32::from_lexical_with_format(b"!1234", format, &options);
+    assert_eq!(result, Err(Error::InvalidDigit(0)));
+    assert_eq!(i32::from_lexical_partial_with_format(b"1!2,3", format, &options), Ok((12, 3)));
+
+    #[cfg(feature = "power-of-two")]
+    {
+        let format = NumberFormatBuilder::new()
+            .radix(16)
+            .base_prefix(num::NonZeroU8::new(b'x'))
+            .build_dynamic()
+            .unwrap();
+        assert_eq!(i32::from_lexical_with_format(b"-0xFF", format, &options), Ok(-255));
+        assert_eq!(i32::from_lexical_with_format(b"0x", format, &options), Err(Error::Empty(2)));
+    }
+}
+
 macro_rules! is_error {
     ($result:expr, $check:ident) => {{
         let result = $result;
@@ -395,6 +490,197 @@ macro_rules! is_invalid_digit_match {
     }};
 }
 
+#[test]
+fn code_unit_test() {
+    let units: Vec<u16> = "1234".encode_utf16().collect();
+    assert_eq!(Ok(1234), u32::from_lexical_units(&units));
+    let units: Vec<u16> = "-12\u{e9}4".encode_utf16().collect();
+    assert_eq!(Err(Error::InvalidDigit(3)), i32::from_lexical_units(&units));
+    assert_eq!(Ok((-12, 3)), i32::from_lexical_partial_units(&units));
+    let units: Vec<char> = "\u{661}\u{662}".chars().collect();
+    assert_eq!(Err(Error::InvalidDigit(0)), u8::from_lexical_units(&units));
+    let units: Vec<char> = "256".chars().collect();
+    assert_eq!(Err(Error::Overflow(2)), u8::from_lexical_units(&units));
+    assert_eq!(Ok(255), u8::from_lexical_units(b"255"));
+
+    let options = Options::new();
+    let units: Vec<u32> = "12 34".chars().map(|c| c as u32).collect();
+    assert_eq!(
+        Err(Error::InvalidDigit(2)),
+        u64::from_lexical_units_with_options::<_, STANDARD>(&units, &options)
+    );
+    assert_eq!(
+        Ok((12, 2)),
+        u64::from_lexical_partial_units_with_options::<_, STANDARD>(&units, &options)
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn code_unit_format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build();
+    let options = Options::new();
+    let units: Vec<u16> = "1_234".encode_utf16().collect();
+    assert_eq!(Ok(1234), u32::from_lexical_units_with_options::<_, FORMAT>(&units, &options));
+    let units: Vec<u16> = "1_2\u{2009}34".encode_utf16().collect();
+    assert_eq!(
+        Ok((12, 3)),
+        u32::from_lexical_partial_units_with_options::<_, FORMAT>(&units, &options)
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i32_unicode_digits_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().unicode_digits(true).build();
+    let parse = |x: &str| i32::from_lexical_with_options::<FORMAT>(x.as_bytes(), &options);
+    assert_eq!(parse("١٢٣"), Ok(123));
+    assert_eq!(parse("-۴۵"), Ok(-45));
+    assert_eq!(parse("+९०"), Ok(90));
+    assert_eq!(parse("１２"), Ok(12));
+    assert_eq!(parse("42"), Ok(42));
+
+    // Errors are indexed in bytes, and scripts cannot be mixed.
+    assert_eq!(parse("١٢x"), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse("١٢é"), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse("١٢3"), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse("１٢"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse("-"), Err(Error::Empty(1)));
+    assert_eq!(parse("٣٠٠٠٠٠٠٠٠٠٠"), Err(Error::Overflow(20)));
+
+    let partial =
+        |x: &str| i32::from_lexical_partial_with_options::<FORMAT>(x.as_bytes(), &options);
+    assert_eq!(partial("١٢ 3"), Ok((12, 4)));
+    assert_eq!(partial("७x"), Ok((7, 3)));
+
+    // Non-ASCII digits are only valid with the flag.
+    assert_eq!(i32::from_lexical("١٢".as_bytes()), Err(Error::InvalidDigit(0)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i32_punctuation_test() {
+    const PUNCTUATION: Punctuation = Punctuation::new()
+        .digit_separators(&[NARROW_NO_BREAK_SPACE, b"'"])
+        .minus_signs(&[MINUS_SIGN]);
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+    let options = Options::builder().punctuation(PUNCTUATION).build().unwrap();
+    let parse = |x: &str| i32::from_lexical_with_options::<FORMAT>(x.as_bytes(), &options);
+    assert_eq!(parse("\u{2212}1\u{202F}000"), Ok(-1000));
+    assert_eq!(parse("1'000'000"), Ok(1000000));
+    assert_eq!(parse("-1_000"), Ok(-1000));
+
+    // The digit separator rules of the format still apply.
+    assert_eq!(parse("1\u{202F}"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse("'1"), Err(Error::InvalidDigit(0)));
+
+    let partial =
+        |x: &str| i32::from_lexical_partial_with_options::<FORMAT>(x.as_bytes(), &options);
+    assert_eq!(partial("\u{2212}12 3"), Ok((-12, 5)));
+
+    // Without a digit separator in the format, separators are not replaced.
+    let parse = |x: &str| i32::from_lexical_with_options::<STANDARD>(x.as_bytes(), &options);
+    assert_eq!(parse("\u{2212}12"), Ok(-12));
+    assert_eq!(parse("1'0"), Err(Error::InvalidDigit(1)));
+
+    // Alternatives are checked against the digit separator of the format
+    // when parsing, since the options do not know the format.
+    const QUOTE: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'\''))
+        .internal_digit_separator(true)
+        .build();
+    let options =
+        Options::builder().punctuation(Punctuation::new().minus_signs(&[b"'"])).build().unwrap();
+    let result = i32::from_lexical_with_options::<QUOTE>(b"1", &options);
+    assert_eq!(result, Err(Error::InvalidAlternativePunctuation));
+    let result = i32::from_lexical_partial_with_options::<QUOTE>(b"1", &options);
+    assert_eq!(result, Err(Error::InvalidAlternativePunctuation));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"'1", &options), Ok(-1));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i64_digit_grouping_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b','))
+        .integer_internal_digit_separator(true)
+        .digit_grouping(DigitGrouping::Thousands)
+        .build();
+    let options = Options::new();
+    let parse = |x: &[u8]| i64::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b"1,234,567"), Ok(1234567));
+    assert_eq!(parse(b"-123,456"), Ok(-123456));
+    assert_eq!(parse(b"1234567"), Ok(1234567));
+    assert_eq!(parse(b"1,23,4567"), Err(Error::InvalidDigitGroup(4)));
+    assert_eq!(parse(b"-1,2345"), Err(Error::InvalidDigitGroup(6)));
+
+    let partial = |x: &[u8]| i64::from_lexical_partial_with_options::<FORMAT>(x, &options);
+    assert_eq!(partial(b"1,234 5"), Ok((1234, 5)));
+    assert_eq!(partial(b"12,34 5"), Err(Error::InvalidDigitGroup(5)));
+
+    const INDIAN: u128 =
+        NumberFormatBuilder::rebuild(FORMAT).digit_grouping(DigitGrouping::Indian).build();
+    let parse = |x: &[u8]| i64::from_lexical_with_options::<INDIAN>(x, &options);
+    assert_eq!(parse(b"1,23,45,678"), Ok(12345678));
+    assert_eq!(parse(b"12,345,678"), Err(Error::InvalidDigitGroup(5)));
+
+    const MYRIAD: u128 =
+        NumberFormatBuilder::rebuild(FORMAT).digit_grouping(DigitGrouping::Myriad).build();
+    let parse = |x: &[u8]| i64::from_lexical_with_options::<MYRIAD>(x, &options);
+    assert_eq!(parse(b"1,2345,6789"), Ok(123456789));
+    assert_eq!(parse(b"1,234,567"), Err(Error::InvalidDigitGroup(5)));
+
+    // Digit grouping requires integer internal digit separators.
+    const INVALID: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b','))
+        .integer_leading_digit_separator(true)
+        .digit_grouping(DigitGrouping::Thousands)
+        .build();
+    let result = i64::from_lexical_with_options::<INVALID>(b"1", &options);
+    assert_eq!(result, Err(Error::InvalidDigitGrouping));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i32_whitespace_test() {
+    const FORMAT: u128 =
+        NumberFormatBuilder::new().leading_whitespace(true).trailing_whitespace(true).build();
+    let options = Options::new();
+    let parse = |x: &[u8]| i32::from_lexical_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse(b" 12\n"), Ok(12));
+    assert_eq!(parse(b"\t-7\r\n"), Ok(-7));
+    assert_eq!(parse(b"1 2"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse(b"  1x "), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse("\u{3000}12".as_bytes()), Err(Error::InvalidDigit(0)));
+
+    let partial = |x: &[u8]| i32::from_lexical_partial_with_options::<FORMAT>(x, &options);
+    assert_eq!(partial(b" 12 \n3"), Ok((12, 5)));
+    assert_eq!(partial(b"12"), Ok((12, 2)));
+
+    // Whitespace is only skipped where the format allows it.
+    const LEADING: u128 = NumberFormatBuilder::new().leading_whitespace(true).build();
+    let parse = |x: &[u8]| i32::from_lexical_with_options::<LEADING>(x, &options);
+    assert_eq!(parse(b" 12"), Ok(12));
+    assert_eq!(parse(b"12 "), Err(Error::InvalidDigit(2)));
+    let partial = |x: &[u8]| i32::from_lexical_partial_with_options::<LEADING>(x, &options);
+    assert_eq!(partial(b" 12 3"), Ok((12, 3)));
+
+    const UNICODE: u128 = NumberFormatBuilder::rebuild(FORMAT).unicode_whitespace(true).build();
+    let parse = |x: &str| i32::from_lexical_with_options::<UNICODE>(x.as_bytes(), &options);
+    assert_eq!(parse("\u{3000}12\u{A0}"), Ok(12));
+    let units: Vec<u16> = " 12\u{3000}".encode_utf16().collect();
+    assert_eq!(i32::from_lexical_units_with_options::<_, UNICODE>(&units, &options), Ok(12));
+    let result = i32::from_lexical_partial_units_with_options::<_, UNICODE>(&units, &options);
+    assert_eq!(result, Ok((12, 4)));
+}
+
 proptest! {
     #[test]
     #[cfg_attr(miri, ignore)]
@@ -411,6 +697,24 @@ proptest! {
         prop_assert_eq!(i, result.unwrap());
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i16_saturate_proptest(i in r"[+-]?[0-9]{1,30}") {
+        let value: i128 = i.parse().unwrap();
+        let expected = value.max(i16::MIN as i128).min(i16::MAX as i128) as i16;
+        prop_assert_eq!(parse_with_mode::<i16>(i.as_bytes(), OverflowMode::Saturate), Ok(expected));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn u16_wrap_proptest(i in r"[+-]?[0-9]{1,30}") {
+        let value: i128 = i.parse().unwrap();
+        prop_assert_eq!(parse_with_mode::<i16>(i.as_bytes(), OverflowMode::Wrap), Ok(value as i16));
+        let unsigned = i.trim_start_matches('-').as_bytes();
+        let expected = value.unsigned_abs() as u16;
+        prop_assert_eq!(parse_with_mode::<u16>(unsigned, OverflowMode::Wrap), Ok(expected));
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn u8_invalid_proptest(i in r"[+]?[0-9]{2}\D") {
@@ -770,4 +1074,13 @@ proptest! {
     fn i128_trailing_digits_proptest(i in r"[+-]?[0-9]{38}\D[0-9]{2}") {
         is_invalid_digit_match!(i128::from_lexical(i.as_bytes()), 38 | 39);
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i64_code_unit_proptest(i in r"[+-]?[0-9]{0,21}.?[0-9]{0,3}") {
+        let units: Vec<u16> = i.encode_utf16().collect();
+        let bytes: Vec<u8> = i.chars().map(|c| if c.is_ascii() { c as u8 } else { 0xFF }).collect();
+        prop_assert_eq!(i64::from_lexical_units(&units), i64::from_lexical(&bytes));
+        prop_assert_eq!(i64::from_lexical_partial_units(&units), i64::from_lexical_partial(&bytes));
+    }
 }
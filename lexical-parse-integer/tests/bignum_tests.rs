@@ -0,0 +1,132 @@
+#![cfg(feature = "std")]
+
+use lexical_parse_integer::bignum::{limbs_len, parse_limbs, parse_limbs_vec, scratch_len};
+use lexical_parse_integer::FromLexical;
+use lexical_util::bignum::{mul_small, normalized_len};
+use lexical_util::error::Error;
+use proptest::prelude::*;
+
+const DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Get `len` scrambled digits in the radix.
+fn digits(len: usize, radix: u32) -> String {
+    (0..len as u64)
+        .map(|i| DIGITS[(((i * 2654435761) >> 7) % radix as u64) as usize] as char)
+        .collect()
+}
+
+/// Parse the digits using only small multiplications, as a reference.
+fn reference_parse(digits: &str, radix: u32) -> Vec<u64> {
+    let mut limbs = vec![0; digits.len() * 6 / 64 + 1];
+    for c in digits.chars() {
+        let digit = c.to_digit(radix).unwrap() as u64;
+        assert_eq!(mul_small(&mut limbs, radix as u64, digit), 0);
+    }
+    let len = normalized_len(&limbs);
+    limbs.truncate(len);
+    limbs
+}
+
+fn trim(limbs: &[u64]) -> &[u64] {
+    &limbs[..normalized_len(limbs)]
+}
+
+#[test]
+fn parse_limbs_test() {
+    assert_eq!(parse_limbs_vec(b"0", 10), Ok(vec![]));
+    assert_eq!(parse_limbs_vec(b"000", 10), Ok(vec![]));
+    assert_eq!(parse_limbs_vec(b"0001", 10), Ok(vec![1]));
+    assert_eq!(parse_limbs_vec(b"18446744073709551616", 10), Ok(vec![0, 1]));
+    let max = u128::MAX.to_string();
+    assert_eq!(parse_limbs_vec(max.as_bytes(), 10), Ok(vec![u64::MAX, u64::MAX]));
+    assert_eq!(parse_limbs_vec(b"", 10), Err(Error::Empty(0)));
+    assert_eq!(parse_limbs_vec(b"12a", 10), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse_limbs_vec(b"-1", 10), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse_limbs_vec(b"1", 1), Err(Error::InvalidRadix));
+    assert_eq!(parse_limbs_vec(b"1", 37), Err(Error::InvalidRadix));
+}
+
+#[test]
+fn buffer_test() {
+    assert_eq!(limbs_len(39, 10), 3);
+    assert_eq!(scratch_len(39, 10), 3);
+
+    // Leading zeros do not require more limbs.
+    let mut limbs = [0; 2];
+    let digits = format!("{}{}", "0".repeat(100), u128::MAX);
+    let len = parse_limbs(digits.as_bytes(), 10, &mut limbs, &mut [0; 3]);
+    assert_eq!((len, limbs), (Ok(2), [u64::MAX, u64::MAX]));
+
+    let mut limbs = [0; 1];
+    let mut scratch = [0; 2];
+    let result = parse_limbs(b"18446744073709551616", 10, &mut limbs, &mut scratch);
+    assert_eq!(result, Err(Error::Overflow(19)));
+}
+
+#[test]
+fn large_test() {
+    // Large values use the divide-and-conquer algorithm.
+    for &len in &[600, 620, 640, 1250, 1260, 1950, 5000, 20000, 40000] {
+        let digits = digits(len, 10);
+        let expected = reference_parse(&digits, 10);
+        assert_eq!(parse_limbs_vec(digits.as_bytes(), 10).unwrap(), expected, "{}", len);
+    }
+
+    let nines = "9".repeat(10000);
+    let mut limbs = parse_limbs_vec(nines.as_bytes(), 10).unwrap();
+    assert_eq!(limbs, reference_parse(&nines, 10));
+    limbs.push(0);
+    assert_eq!(mul_small(&mut limbs, 1, 1), 0);
+    assert_eq!(trim(&limbs), reference_parse(&format!("1{}", "0".repeat(10000)), 10));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn power_of_two_test() {
+    for &radix in &[2_u32, 4, 8, 16, 32] {
+        let digits = digits(19200 / radix.trailing_zeros() as usize, radix);
+        let expected = reference_parse(&digits, radix);
+        assert_eq!(parse_limbs_vec(digits.as_bytes(), radix).unwrap(), expected);
+        let lowercase = digits.to_ascii_lowercase();
+        assert_eq!(parse_limbs_vec(lowercase.as_bytes(), radix).unwrap(), expected);
+    }
+    assert_eq!(parse_limbs_vec(b"12", 2), Err(Error::InvalidDigit(1)));
+
+    let mut limbs = [0; 1];
+    let max = "F".repeat(16);
+    assert_eq!(parse_limbs(max.as_bytes(), 16, &mut limbs, &mut []), Ok(1));
+    assert_eq!(limbs, [u64::MAX]);
+    let overflow = format!("0{}", "V".repeat(13));
+    let result = parse_limbs(overflow.as_bytes(), 32, &mut limbs, &mut []);
+    assert_eq!(result, Err(Error::Overflow(13)));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix_test() {
+    for &radix in &[3, 7, 12, 31, 36] {
+        for &len in &[60, 800, 4000, 14000] {
+            let digits = digits(len, radix);
+            let expected = reference_parse(&digits, radix);
+            assert_eq!(parse_limbs_vec(digits.as_bytes(), radix).unwrap(), expected);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn u128_proptest(i in r"[0-9]{1,39}") {
+        if let Ok(expected) = u128::from_lexical(i.as_bytes()) {
+            let expected = [expected as u64, (expected >> 64) as u64];
+            prop_assert_eq!(parse_limbs_vec(i.as_bytes(), 10).unwrap(), trim(&expected));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn large_proptest(i in r"[0-9]{1,3000}") {
+        let limbs = parse_limbs_vec(i.as_bytes(), 10).unwrap();
+        prop_assert_eq!(limbs, reference_parse(&i, 10));
+    }
+}
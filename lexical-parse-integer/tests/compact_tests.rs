@@ -1,12 +1,20 @@
 #![cfg(feature = "compact")]
 
 use lexical_parse_integer::compact;
-use lexical_util::format::STANDARD;
+use lexical_parse_integer::options::Options;
+use lexical_util::format::{NumberFormat, STANDARD};
+
+const OPTIONS: Options = Options::new();
+const STANDARD_FORMAT: NumberFormat<STANDARD> = NumberFormat::<{ STANDARD }> {};
 
 #[test]
 fn algorithm_test() {
-    let parse_u32 = |digits: &[u8]| compact::algorithm_partial::<u32, u32, STANDARD>(digits);
-    let parse_i32 = |digits: &[u8]| compact::algorithm_partial::<i32, u32, STANDARD>(digits);
+    let parse_u32 = |digits: &[u8]| {
+        compact::algorithm_partial::<u32, u32, _>(digits, STANDARD_FORMAT, &OPTIONS)
+    };
+    let parse_i32 = |digits: &[u8]| {
+        compact::algorithm_partial::<i32, u32, _>(digits, STANDARD_FORMAT, &OPTIONS)
+    };
 
     assert_eq!(parse_u32(b"12345"), Ok((12345, 5)));
     assert_eq!(parse_u32(b"+12345"), Ok((12345, 6)));
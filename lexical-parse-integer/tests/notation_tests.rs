@@ -0,0 +1,258 @@
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use core::num;
+use lexical_parse_integer::{FromLexical, FromLexicalWithOptions, Options, OverflowMode};
+use lexical_util::error::Error;
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use proptest::prelude::*;
+
+fn options() -> Options {
+    Options::builder().float_notation(true).build().unwrap()
+}
+
+fn parse<T>(bytes: &[u8]) -> Result<T, Error>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    T::from_lexical_with_options::<STANDARD>(bytes, &options())
+}
+
+#[test]
+fn integral_test() {
+    assert_eq!(parse::<u32>(b"1e6"), Ok(1000000));
+    assert_eq!(parse::<u32>(b"12.0"), Ok(12));
+    assert_eq!(parse::<u32>(b"1.5E3"), Ok(1500));
+    assert_eq!(parse::<u32>(b"2.50e1"), Ok(25));
+    assert_eq!(parse::<u32>(b"1500e-2"), Ok(15));
+    assert_eq!(parse::<u32>(b"123"), Ok(123));
+    assert_eq!(parse::<u32>(b"+7."), Ok(7));
+    assert_eq!(parse::<u32>(b".5e1"), Ok(5));
+    assert_eq!(parse::<i32>(b"-1.5e3"), Ok(-1500));
+    assert_eq!(parse::<i32>(b"-0.0e-5"), Ok(0));
+    assert_eq!(parse::<u8>(b"0e999999999999"), Ok(0));
+    assert_eq!(parse::<u8>(b"1500000000000000000000000000000e-28"), Ok(150));
+    assert_eq!(parse::<u64>(b"0.000000000000000000001e21"), Ok(1));
+}
+
+#[test]
+fn non_integral_test() {
+    assert_eq!(parse::<u32>(b"1.25"), Err(Error::NonIntegral(3)));
+    assert_eq!(parse::<u32>(b"15e-1"), Err(Error::NonIntegral(1)));
+    assert_eq!(parse::<u32>(b"1.2500e1"), Err(Error::NonIntegral(3)));
+    assert_eq!(parse::<i32>(b"-0.5"), Err(Error::NonIntegral(3)));
+    assert_eq!(parse::<u64>(b"1e-100"), Err(Error::NonIntegral(0)));
+    assert!(Error::NonIntegral(0).is_non_integral());
+}
+
+#[test]
+fn error_test() {
+    assert_eq!(parse::<u32>(b""), Err(Error::Empty(0)));
+    assert_eq!(parse::<i32>(b"-"), Err(Error::Empty(1)));
+    assert_eq!(parse::<u32>(b"."), Err(Error::EmptyMantissa(1)));
+    assert_eq!(parse::<u32>(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(parse::<u32>(b"1.5e3x"), Err(Error::InvalidDigit(5)));
+    assert_eq!(parse::<u32>(b"-1"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse::<u32>(b"-1.5e3"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse::<u32>(b"in"), Err(Error::EmptyMantissa(0)));
+
+    // Exponent notation is disabled by default.
+    assert_eq!(u32::from_lexical(b"1e3"), Err(Error::InvalidDigit(1)));
+    assert_eq!(u32::from_lexical(b"12.0"), Err(Error::InvalidDigit(2)));
+}
+
+#[test]
+fn overflow_test() {
+    assert_eq!(parse::<u8>(b"2.55e2"), Ok(255));
+    assert_eq!(parse::<u8>(b"2.56e2"), Err(Error::Overflow(3)));
+    assert_eq!(parse::<i8>(b"1.27e2"), Ok(127));
+    assert_eq!(parse::<i8>(b"1.28e2"), Err(Error::Overflow(3)));
+    assert_eq!(parse::<i8>(b"-1.28e2"), Ok(-128));
+    assert_eq!(parse::<i8>(b"-1.29e2"), Err(Error::Underflow(4)));
+    assert_eq!(parse::<u64>(b"1.8446744073709551615e19"), Ok(u64::MAX));
+    assert_eq!(parse::<u64>(b"1.8446744073709551616e19"), Err(Error::Overflow(20)));
+
+    // Scaling the digits out of range points to the exponent or trailing zeros.
+    assert_eq!(parse::<u64>(b"1e100"), Err(Error::Overflow(1)));
+    assert_eq!(parse::<i8>(b"-1.3e2"), Err(Error::Underflow(4)));
+    assert_eq!(parse::<u8>(b"1000"), Err(Error::Overflow(3)));
+    assert_eq!(parse::<u8>(b"1000.0e-1"), Ok(100));
+    assert_eq!(parse::<u8>(b"10000.0e-1"), Err(Error::Overflow(4)));
+    let bytes = b"1.70141183460469231731687303715884105727e38";
+    assert_eq!(parse::<i128>(bytes), Ok(i128::MAX));
+
+    let saturate = options().rebuild().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    let parse_u8 = |bytes| u8::from_lexical_with_options::<STANDARD>(bytes, &saturate);
+    assert_eq!(parse_u8(b"2.56e2"), Ok(255));
+    assert_eq!(parse_u8(b"1e999999999999"), Ok(255));
+
+    let wrap = options().rebuild().overflow_mode(OverflowMode::Wrap).build().unwrap();
+    let parse_u8 = |bytes| u8::from_lexical_with_options::<STANDARD>(bytes, &wrap);
+    let parse_i8 = |bytes| i8::from_lexical_with_options::<STANDARD>(bytes, &wrap);
+    assert_eq!(parse_u8(b"2.56e2"), Ok(0));
+    assert_eq!(parse_u8(b"3e2"), Ok(44));
+    assert_eq!(parse_u8(b"1e999999999999"), Ok(0));
+    assert_eq!(parse_i8(b"-1.29e2"), Ok(127));
+}
+
+#[test]
+fn special_test() {
+    assert_eq!(parse::<u32>(b"NaN"), Err(Error::NonIntegral(0)));
+    assert_eq!(parse::<i32>(b"-nan"), Err(Error::NonIntegral(1)));
+    assert_eq!(parse::<u32>(b"inf"), Err(Error::Overflow(0)));
+    assert_eq!(parse::<u32>(b"Infinity"), Err(Error::Overflow(0)));
+    assert_eq!(parse::<i32>(b"-inf"), Err(Error::Underflow(1)));
+    assert_eq!(parse::<u32>(b"-inf"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse::<u32>(b"infx"), Err(Error::InvalidDigit(3)));
+
+    // Only saturating gives a value for infinity.
+    let saturate = options().rebuild().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    let parse_i8 = |bytes| i8::from_lexical_with_options::<STANDARD>(bytes, &saturate);
+    assert_eq!(parse_i8(b"inf"), Ok(127));
+    assert_eq!(parse_i8(b"-infinity"), Ok(-128));
+    assert_eq!(parse_i8(b"nan"), Err(Error::NonIntegral(0)));
+    let result = i8::from_lexical_partial_with_options::<STANDARD>(b"inf,", &saturate);
+    assert_eq!(result, Ok((127, 3)));
+
+    let wrap = options().rebuild().overflow_mode(OverflowMode::Wrap).build().unwrap();
+    assert_eq!(u8::from_lexical_with_options::<STANDARD>(b"inf", &wrap), Err(Error::Overflow(0)));
+}
+
+#[test]
+fn partial_test() {
+    let options = options();
+    let parse = |bytes| i32::from_lexical_partial_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(parse(b"1.5e3,"), Ok((1500, 5)));
+    assert_eq!(parse(b"-12.0 "), Ok((-12, 5)));
+    assert_eq!(parse(b"1.25,"), Err(Error::NonIntegral(3)));
+    assert_eq!(parse(b"5e"), Err(Error::EmptyExponent(2)));
+
+    // Negative numbers are not part of an unsigned integer, like without
+    // float notation.
+    let parse = |bytes| u32::from_lexical_partial_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(parse(b"-1.5e3"), u32::from_lexical_partial(b"-1.5e3"));
+    assert_eq!(parse(b"-1.5e3"), Ok((0, 0)));
+}
+
+#[test]
+fn punctuation_test() {
+    let options = Options::builder()
+        .float_notation(true)
+        .exponent(b'^')
+        .decimal_point(b',')
+        .build()
+        .unwrap();
+    let parse = |bytes| u32::from_lexical_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(parse(b"1,5^3"), Ok(1500));
+    assert_eq!(parse(b"1.5e3"), Err(Error::InvalidDigit(1)));
+
+    let options = options.rebuild().exponent(b'1').build().unwrap();
+    let parse = |bytes| u32::from_lexical_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(parse(b"1"), Err(Error::InvalidPunctuation));
+}
+
+#[test]
+fn units_test() {
+    let options = options();
+    let units: Vec<u16> = "2.5e2".encode_utf16().collect();
+    assert_eq!(u32::from_lexical_units_with_options::<_, STANDARD>(&units, &options), Ok(250));
+    let units: Vec<u16> = "2.5e2 ".encode_utf16().collect();
+    let result = u32::from_lexical_partial_units_with_options::<_, STANDARD>(&units, &options);
+    assert_eq!(result, Ok((250, 5)));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn binary_exponent_test() {
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build();
+    let options = options().rebuild().exponent(b'p').build().unwrap();
+    let parse = |bytes| u32::from_lexical_with_options::<HEX>(bytes, &options);
+    assert_eq!(parse(b"1.8p3"), Ok(12));
+    assert_eq!(parse(b"1.8p1"), Ok(3));
+    assert_eq!(parse(b"A.0p0"), Ok(10));
+    assert_eq!(parse(b"1p31"), Ok(0x80000000));
+    assert_eq!(parse(b"1.8p0"), Err(Error::NonIntegral(2)));
+    assert_eq!(parse(b"1p-1"), Err(Error::NonIntegral(0)));
+    assert_eq!(parse(b"1p32"), Err(Error::Overflow(1)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .fraction_internal_digit_separator(true)
+        .build();
+    let parse = |bytes| u32::from_lexical_with_options::<FORMAT>(bytes, &options());
+    assert_eq!(parse(b"1_000.5e1"), Ok(10005));
+    assert_eq!(parse(b"1_000.2_5e2"), Ok(100025));
+    assert_eq!(parse(b"1_000.2_5e1"), Err(Error::NonIntegral(8)));
+
+    const NO_EXPONENT: u128 = NumberFormatBuilder::new().no_exponent_notation(true).build();
+    let parse = |bytes| u32::from_lexical_with_options::<NO_EXPONENT>(bytes, &options());
+    assert_eq!(parse(b"12.0"), Ok(12));
+    assert_eq!(parse(b"1e3"), Err(Error::InvalidExponent(1)));
+
+    const REQUIRED_FRACTION: u128 =
+        NumberFormatBuilder::new().required_fraction_digits(true).build();
+    let parse = |bytes| u32::from_lexical_with_options::<REQUIRED_FRACTION>(bytes, &options());
+    assert_eq!(parse(b"1.e3"), Err(Error::EmptyFraction(2)));
+
+    const NO_LEADING_ZEROS: u128 =
+        NumberFormatBuilder::new().no_integer_leading_zeros(true).build();
+    let parse = |bytes| u32::from_lexical_with_options::<NO_LEADING_ZEROS>(bytes, &options());
+    assert_eq!(parse(b"0.5e1"), Ok(5));
+    assert_eq!(parse(b"01.5e1"), Err(Error::InvalidLeadingZeros(0)));
+
+    const NO_SPECIAL: u128 = NumberFormatBuilder::new().no_special(true).build();
+    let parse = |bytes| u32::from_lexical_with_options::<NO_SPECIAL>(bytes, &options());
+    assert_eq!(parse(b"inf"), Err(Error::EmptyMantissa(0)));
+
+    const CASE_SENSITIVE: u128 = NumberFormatBuilder::new().case_sensitive_special(true).build();
+    let parse = |bytes| u32::from_lexical_with_options::<CASE_SENSITIVE>(bytes, &options());
+    assert_eq!(parse(b"inf"), Err(Error::Overflow(0)));
+    assert_eq!(parse(b"INF"), Err(Error::EmptyMantissa(0)));
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn exponent_proptest(i in 0u32..u32::MAX, exp in 0u32..10) {
+        let expected = i as u64 * 10u64.pow(exp);
+        prop_assert_eq!(parse::<u64>(format!("{}e{}", i, exp).as_bytes()), Ok(expected));
+        let string = format!("{}.{}e{}", i, "0".repeat(exp as usize), exp);
+        prop_assert_eq!(parse::<u64>(string.as_bytes()), Ok(expected));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn fraction_proptest(i in 0i64..i64::MAX, digits in r"[0-9]{0,5}[1-9]") {
+        let string = format!("-{}.{}", i, digits);
+        prop_assert_eq!(parse::<i64>(string.as_bytes()), Err(Error::NonIntegral(string.len() - 1)));
+        let string = format!("-{}{}e-{}", i, digits, digits.len());
+        let index = string.len() - 3 - digits.len().to_string().len();
+        prop_assert_eq!(parse::<i64>(string.as_bytes()), Err(Error::NonIntegral(index)));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i16_proptest(i in r"[+-]?[0-9]{1,6}(\.[0-9]{0,6})?(e[+-]?[0-9]{1,2})?") {
+        // Only integral values are accepted, and they must exactly match.
+        let value: f64 = i.parse().unwrap();
+        let result = parse::<i16>(i.as_bytes());
+        if value.fract() != 0.0 {
+            prop_assert!(matches!(result, Err(Error::NonIntegral(_))));
+        } else if value > i16::MAX as f64 {
+            prop_assert!(matches!(result, Err(Error::Overflow(_))));
+        } else if value < i16::MIN as f64 {
+            prop_assert!(matches!(result, Err(Error::Underflow(_))));
+        } else {
+            prop_assert_eq!(result, Ok(value as i16));
+        }
+    }
+}
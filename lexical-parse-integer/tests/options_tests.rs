@@ -1,4 +1,6 @@
-use lexical_parse_integer::options::{Options, OptionsBuilder};
+use lexical_parse_integer::options::{Options, OptionsBuilder, OverflowMode};
+use lexical_parse_integer::punctuation::{Punctuation, MINUS_SIGN};
+use lexical_parse_integer::Error;
 
 #[test]
 fn options_tests() {
@@ -15,3 +17,60 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(options.rebuild(), Options::builder());
 }
+
+#[test]
+fn builder_test() {
+    let builder = OptionsBuilder::new().overflow_mode(OverflowMode::Saturate);
+    assert_eq!(builder.get_overflow_mode(), OverflowMode::Saturate);
+    assert!(builder.is_valid());
+    assert_eq!(builder.build(), Ok(unsafe { builder.build_unchecked() }));
+
+    let mut options = builder.build().unwrap();
+    assert_eq!(options.overflow_mode(), OverflowMode::Saturate);
+    unsafe { options.set_overflow_mode(OverflowMode::Wrap) };
+    assert_eq!(options.overflow_mode(), OverflowMode::Wrap);
+    assert_eq!(options.rebuild().build(), Ok(options));
+    assert_eq!(Options::new().overflow_mode(), OverflowMode::Error);
+}
+
+#[test]
+fn float_notation_test() {
+    let builder = OptionsBuilder::new().float_notation(true).exponent(b'^').decimal_point(b',');
+    assert!(builder.get_float_notation());
+    assert_eq!(builder.get_exponent(), b'^');
+    assert_eq!(builder.get_decimal_point(), b',');
+    assert!(builder.is_valid());
+
+    let mut options = builder.build().unwrap();
+    assert!(options.float_notation());
+    assert_eq!(options.exponent(), b'^');
+    assert_eq!(options.decimal_point(), b',');
+    unsafe { options.set_float_notation(false) };
+    unsafe { options.set_exponent(b'e') };
+    unsafe { options.set_decimal_point(b'.') };
+    assert_eq!(options, Options::new());
+
+    let builder = OptionsBuilder::new().exponent(b'\x00');
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidExponentSymbol));
+    let builder = OptionsBuilder::new().decimal_point(b'\x00');
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidDecimalPoint));
+}
+
+#[test]
+fn punctuation_test() {
+    let punctuation = Punctuation::new().minus_signs(&[MINUS_SIGN]);
+    let builder = OptionsBuilder::new().punctuation(punctuation);
+    assert_eq!(builder.get_punctuation(), punctuation);
+    assert!(builder.is_valid());
+
+    let mut options = builder.build().unwrap();
+    assert_eq!(options.punctuation(), punctuation);
+    unsafe { options.set_punctuation(Punctuation::new()) };
+    assert_eq!(options, Options::new());
+
+    let builder = OptionsBuilder::new().punctuation(Punctuation::new().plus_signs(&[b"-"]));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidAlternativePunctuation));
+}
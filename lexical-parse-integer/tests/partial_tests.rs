@@ -1,4 +1,4 @@
-use lexical_parse_integer::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_parse_integer::{FromLexical, FromLexicalWithOptions, Options, OverflowMode};
 use lexical_util::error::Error;
 #[cfg(all(feature = "format", feature = "power-of-two"))]
 use lexical_util::format::NumberFormatBuilder;
@@ -17,6 +17,25 @@ fn u8_decimal_test() {
     assert_eq!(Ok((0, 1)), u8::from_lexical_partial_with_options::<{ STANDARD }>(b"0", &options));
 }
 
+#[test]
+fn overflow_mode_test() {
+    let options = Options::builder().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    let parse_u8 = |bytes| u8::from_lexical_partial_with_options::<STANDARD>(bytes, &options);
+    let parse_i8 = |bytes| i8::from_lexical_partial_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(Ok((255, 3)), parse_u8(b"256,"));
+    assert_eq!(Ok((255, 4)), parse_u8(b"1000"));
+    assert_eq!(Ok((-128, 4)), parse_i8(b"-200a"));
+    assert_eq!(Ok((127, 4)), parse_i8(b"+200"));
+
+    let options = Options::builder().overflow_mode(OverflowMode::Wrap).build().unwrap();
+    let parse_u8 = |bytes| u8::from_lexical_partial_with_options::<STANDARD>(bytes, &options);
+    let parse_i8 = |bytes| i8::from_lexical_partial_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(Ok((0, 3)), parse_u8(b"256,"));
+    assert_eq!(Ok((232, 4)), parse_u8(b"1000"));
+    assert_eq!(Ok((56, 4)), parse_i8(b"-200a"));
+    assert_eq!(Ok((-56, 4)), parse_i8(b"+200"));
+}
+
 #[test]
 #[cfg(all(feature = "format", feature = "power-of-two"))]
 fn u8_decimal_format_test() {
@@ -0,0 +1,210 @@
+use core::fmt::Debug;
+use lexical_parse_integer::stream::{IntegerStream, Progress};
+use lexical_parse_integer::{FromLexicalWithOptions, Options};
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+#[cfg(feature = "format")]
+use lexical_util::punctuation;
+use lexical_util::format::STANDARD;
+use lexical_util::result::Result;
+use proptest::prelude::*;
+
+/// Feed the input in the given chunks, finishing at the end of the input.
+fn stream<T, const FORMAT: u128>(chunks: &[&[u8]], options: &Options) -> Result<(T, usize)>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    let mut stream = IntegerStream::<T, FORMAT, 4>::with_options(options.clone());
+    for chunk in chunks {
+        if let Progress::Complete(value, count, _) = stream.feed(chunk)? {
+            return Ok((value, count));
+        }
+    }
+    stream.finish()
+}
+
+/// Check every split of the input matches the contiguous partial parser.
+fn check_stream<T, const FORMAT: u128>(input: &[u8])
+where
+    T: FromLexicalWithOptions<Options = Options> + Debug + PartialEq,
+{
+    check_stream_with_options::<T, FORMAT>(input, &Options::new());
+}
+
+/// Check every split of the input matches the contiguous partial parser, with custom options.
+fn check_stream_with_options<T, const FORMAT: u128>(input: &[u8], options: &Options)
+where
+    T: FromLexicalWithOptions<Options = Options> + Debug + PartialEq,
+{
+    let expected = T::from_lexical_partial_with_options::<FORMAT>(input, options);
+    for index in 0..=input.len() {
+        let (first, second) = input.split_at(index);
+        assert_eq!(stream::<T, FORMAT>(&[first, second], options), expected);
+    }
+    let bytes: Vec<&[u8]> = input.chunks(1).collect();
+    assert_eq!(stream::<T, FORMAT>(&bytes, options), expected);
+}
+
+#[test]
+fn feed_test() {
+    let mut stream = IntegerStream::<u32, STANDARD, 64>::new();
+    assert_eq!(stream.feed(b""), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"12"), Ok(Progress::Incomplete));
+    assert_eq!(stream.buffered(), 2);
+    assert_eq!(stream.feed(b"34,5"), Ok(Progress::Complete(1234, 4, 2)));
+    assert_eq!(stream.buffered(), 0);
+    assert_eq!(stream.feed(b"56 "), Ok(Progress::Complete(56, 2, 2)));
+    assert_eq!(stream.feed(b"7"), Ok(Progress::Incomplete));
+    assert_eq!(stream.finish(), Ok((7, 1)));
+    assert_eq!(stream.finish(), Err(Error::Empty(0)));
+    assert_eq!(stream.feed(b"4294967"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"296 "), Err(Error::Overflow(9)));
+
+    stream.feed(b"12").unwrap();
+    stream.reset();
+    assert_eq!(stream.feed(b"3 "), Ok(Progress::Complete(3, 1, 1)));
+}
+
+#[test]
+fn long_test() {
+    let mut stream = IntegerStream::<u32, STANDARD, 4>::new();
+    assert_eq!(stream.feed(b"0001"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b" "), Ok(Progress::Complete(1, 4, 0)));
+    assert_eq!(stream.feed(b"0000"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"1"), Ok(Progress::Incomplete));
+    assert_eq!(stream.buffered(), 5);
+    assert_eq!(stream.feed(b"00000001 "), Ok(Progress::Complete(100000001, 13, 8)));
+    assert_eq!(stream.feed(b"000"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"4294967296 "), Err(Error::Overflow(9)));
+    assert_eq!(stream.buffered(), 0);
+}
+
+#[test]
+fn remainder_test() {
+    // Buffered bytes after the number are the start of the next number.
+    let mut stream = IntegerStream::<i32, STANDARD, 4>::new();
+    assert_eq!(stream.feed(b"12+"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"34,"), Ok(Progress::Complete(12, 2, 0)));
+    assert_eq!(stream.buffered(), 1);
+    assert_eq!(stream.feed(b"34,"), Ok(Progress::Complete(34, 3, 2)));
+    assert_eq!(stream.feed(b"1-2"), Ok(Progress::Incomplete));
+    assert_eq!(stream.finish(), Ok((1, 1)));
+    assert_eq!(stream.finish(), Ok((-2, 2)));
+    assert_eq!(stream.buffered(), 0);
+}
+
+#[test]
+fn decimal_test() {
+    check_stream::<u8, STANDARD>(b"255");
+    check_stream::<u8, STANDARD>(b"256");
+    check_stream::<u8, STANDARD>(b"-1");
+    check_stream::<i8, STANDARD>(b"-128,");
+    check_stream::<i8, STANDARD>(b"+12-3");
+    check_stream::<u32, STANDARD>(b"");
+    check_stream::<u32, STANDARD>(b"+");
+    check_stream::<u32, STANDARD>(b"0012345abc");
+    check_stream::<i64, STANDARD>(b"-9223372036854775808 1");
+    check_stream::<u128, STANDARD>(b"340282366920938463463374607431768211455]");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .integer_consecutive_digit_separator(true)
+        .build();
+
+    check_stream::<u32, FORMAT>(b"1__2_3 ");
+    check_stream::<u32, FORMAT>(b"1__2_3__");
+    check_stream::<u32, FORMAT>(b"_12");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn unicode_digits_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().unicode_digits(true).build();
+
+    let mut stream = IntegerStream::<u32, FORMAT, 4>::new();
+    assert_eq!(stream.feed("١٢".as_bytes()), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed("٣,".as_bytes()), Ok(Progress::Complete(123, 6, 2)));
+
+    check_stream::<u32, FORMAT>("١٢٣,".as_bytes());
+    check_stream::<i32, FORMAT>("-۴۵ ".as_bytes());
+    check_stream::<u32, FORMAT>("１２".as_bytes());
+    check_stream::<u32, FORMAT>("١٢é".as_bytes());
+    check_stream::<u32, FORMAT>("١٢3".as_bytes());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn punctuation_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+
+    let options = Options::builder().punctuation(punctuation::TYPOGRAPHIC).build().unwrap();
+    let mut stream = IntegerStream::<i32, FORMAT, 4>::with_options(options.clone());
+    assert_eq!(stream.feed("\u{2212}1\u{202F}".as_bytes()), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed("234,".as_bytes()), Ok(Progress::Complete(-1234, 10, 3)));
+
+    check_stream_with_options::<i32, FORMAT>("\u{2212}1\u{202F}234,".as_bytes(), &options);
+    check_stream_with_options::<i32, FORMAT>("1\u{00A0}000'000 ".as_bytes(), &options);
+    check_stream_with_options::<i32, FORMAT>("1\u{202F}".as_bytes(), &options);
+    check_stream_with_options::<u32, FORMAT>("\u{2212}1".as_bytes(), &options);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn whitespace_test() {
+    const FORMAT: u128 =
+        NumberFormatBuilder::new().leading_whitespace(true).trailing_whitespace(true).build();
+
+    let mut stream = IntegerStream::<i32, FORMAT, 4>::new();
+    assert_eq!(stream.feed(b" 1"), Ok(Progress::Incomplete));
+    assert_eq!(stream.feed(b"2 ,"), Ok(Progress::Complete(12, 4, 2)));
+
+    check_stream::<i32, FORMAT>(b" 12\n,");
+    check_stream::<i32, FORMAT>(b"\t-7 3");
+    check_stream::<i32, FORMAT>(b"  ");
+
+    const LEADING: u128 = NumberFormatBuilder::new().leading_whitespace(true).build();
+    check_stream::<i32, LEADING>(b" 12 3");
+
+    const UNICODE: u128 = NumberFormatBuilder::rebuild(FORMAT).unicode_whitespace(true).build();
+    check_stream::<i32, UNICODE>("\u{3000}12\u{A0},".as_bytes());
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn base_prefix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .base_prefix(std::num::NonZeroU8::new(b'x'))
+        .base_suffix(std::num::NonZeroU8::new(b'h'))
+        .build();
+
+    check_stream::<u32, FORMAT>(b"0x1_2h,");
+    check_stream::<u32, FORMAT>(b"0XaBh");
+    check_stream::<u32, FORMAT>(b"0x_1 ");
+    check_stream::<u32, FORMAT>(b"0xfg");
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn u64_stream_proptest(i in r"[+-]?[0-9]{0,25}[^0-9]?[0-9]{0,3}") {
+        check_stream::<u64, STANDARD>(i.as_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i32_stream_proptest(i in r"[+-]?[0-9]{0,12}[^0-9]?") {
+        check_stream::<i32, STANDARD>(i.as_bytes());
+    }
+}
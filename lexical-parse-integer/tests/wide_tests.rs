@@ -0,0 +1,259 @@
+use lexical_parse_integer::wide::{parse_complete, parse_partial, WideInteger};
+#[cfg(feature = "format")]
+use lexical_parse_integer::DigitGrouping;
+use lexical_parse_integer::{FromLexicalWithOptions, Options, OverflowMode};
+use lexical_util::error::Error;
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use proptest::prelude::*;
+
+macro_rules! wide_integer {
+    ($($t:ident $limbs:literal $is_signed:literal ;)*) => ($(
+        #[derive(Clone, Debug, PartialEq)]
+        struct $t([u64; $limbs]);
+
+        impl WideInteger for $t {
+            const IS_SIGNED: bool = $is_signed;
+            const ZERO: Self = $t([0; $limbs]);
+
+            fn limbs(&self) -> &[u64] {
+                &self.0
+            }
+
+            fn limbs_mut(&mut self) -> &mut [u64] {
+                &mut self.0
+            }
+        }
+    )*);
+}
+
+wide_integer! {
+    U128 2 false ;
+    I128 2 true ;
+    U256 4 false ;
+    I256 4 true ;
+    U512 8 false ;
+    U0 0 false ;
+}
+
+impl U128 {
+    fn new(x: u128) -> Self {
+        Self([x as u64, (x >> 64) as u64])
+    }
+}
+
+impl I128 {
+    fn new(x: i128) -> Self {
+        Self([x as u64, (x >> 64) as u64])
+    }
+}
+
+impl I256 {
+    fn new_small(x: i64) -> Self {
+        let sign = if x < 0 { u64::MAX } else { 0 };
+        Self([x as u64, sign, sign, sign])
+    }
+}
+
+fn parse<T: WideInteger>(bytes: &[u8]) -> Result<T, Error> {
+    parse_complete::<T, STANDARD>(bytes, &Options::new())
+}
+
+const U256_MAX: &str =
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+const I256_MAX: &str =
+    "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+const I256_MIN: &str =
+    "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+
+#[test]
+fn u256_test() {
+    assert_eq!(parse::<U256>(b"0"), Ok(U256([0; 4])));
+    assert_eq!(parse::<U256>(b"+0001"), Ok(U256([1, 0, 0, 0])));
+    let bytes = b"340282366920938463463374607431768211456";
+    assert_eq!(parse::<U256>(bytes), Ok(U256([0, 0, 1, 0])));
+    assert_eq!(parse::<U256>(U256_MAX.as_bytes()), Ok(U256([u64::MAX; 4])));
+    assert_eq!(parse::<U256>(b"-1"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse::<U256>(b""), Err(Error::Empty(0)));
+    assert_eq!(parse::<U256>(b"+"), Err(Error::Empty(1)));
+    assert_eq!(parse::<U256>(b"12a"), Err(Error::InvalidDigit(2)));
+}
+
+#[test]
+fn i256_test() {
+    assert_eq!(parse::<I256>(b"-1"), Ok(I256([u64::MAX; 4])));
+    let min = I256([0, u64::MAX, u64::MAX, u64::MAX]);
+    assert_eq!(parse::<I256>(b"-18446744073709551616"), Ok(min));
+    let max = I256([u64::MAX, u64::MAX, u64::MAX, i64::MAX as u64]);
+    assert_eq!(parse::<I256>(I256_MAX.as_bytes()), Ok(max));
+    assert_eq!(parse::<I256>(I256_MIN.as_bytes()), Ok(I256([0, 0, 0, 1 << 63])));
+    assert_eq!(parse::<I256>(b"-"), Err(Error::Empty(1)));
+}
+
+#[test]
+fn overflow_test() {
+    // Like the primitive parsers, the index is at most the maximum number of digits.
+    assert_eq!(parse::<U256>(format!("{}0", U256_MAX).as_bytes()), Err(Error::Overflow(78)));
+    assert_eq!(parse::<U256>(format!("{}00", U256_MAX).as_bytes()), Err(Error::Overflow(78)));
+    let above_max = U256_MAX.replace("935", "936");
+    assert_eq!(parse::<U256>(above_max.as_bytes()), Err(Error::Overflow(77)));
+    let above_max = I256_MAX.replace("967", "968");
+    assert_eq!(parse::<I256>(above_max.as_bytes()), Err(Error::Overflow(76)));
+    let below_min = I256_MIN.replace("968", "969");
+    assert_eq!(parse::<I256>(below_min.as_bytes()), Err(Error::Underflow(76)));
+    assert_eq!(parse::<U512>(U256_MAX.as_bytes()).map(|x| x.0[4]), Ok(0));
+
+    let options = Options::builder().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    let parse_i256 = |bytes| parse_complete::<I256, STANDARD>(bytes, &options);
+    let max = I256([u64::MAX, u64::MAX, u64::MAX, i64::MAX as u64]);
+    assert_eq!(parse_i256(above_max.as_bytes()), Ok(max));
+    assert_eq!(parse_i256(below_min.as_bytes()), Ok(I256([0, 0, 0, 1 << 63])));
+
+    let options = Options::builder().overflow_mode(OverflowMode::Wrap).build().unwrap();
+    let parse_u256 = |bytes| parse_complete::<U256, STANDARD>(bytes, &options);
+    let above_max = U256_MAX.replace("935", "937");
+    assert_eq!(parse_u256(above_max.as_bytes()), Ok(U256([1, 0, 0, 0])));
+
+    // Integers without limbs can only hold 0.
+    assert_eq!(parse::<U0>(b"0"), Ok(U0([])));
+    assert_eq!(parse::<U0>(b"1"), Err(Error::Overflow(0)));
+}
+
+#[test]
+fn partial_test() {
+    let options = Options::new();
+    let parse = |bytes| parse_partial::<I256, STANDARD>(bytes, &options);
+    assert_eq!(parse(b"-12,"), Ok((I256::new_small(-12), 3)));
+    assert_eq!(parse(b"12"), Ok((I256::new_small(12), 2)));
+    assert_eq!(parse(b"x"), Ok((I256::ZERO, 0)));
+    let bytes = format!("{}0,", I256_MAX);
+    assert_eq!(parse(bytes.as_bytes()), Err(Error::Overflow(77)));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn radix_test() {
+    const HEX: u128 = NumberFormatBuilder::from_radix(16);
+    let parse = |bytes| parse_complete::<U256, HEX>(bytes, &Options::new());
+    let bytes = format!("1{}", "0".repeat(48));
+    assert_eq!(parse(bytes.as_bytes()), Ok(U256([0, 0, 0, 1])));
+    assert_eq!(parse(b"fFfFfFfFfFfFfFfF"), Ok(U256([u64::MAX, 0, 0, 0])));
+    let max = "F".repeat(64);
+    assert_eq!(parse(max.as_bytes()), Ok(U256([u64::MAX; 4])));
+    assert_eq!(parse(format!("{}0", max).as_bytes()), Err(Error::Overflow(64)));
+
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let parse = |bytes| parse_complete::<U256, BINARY>(bytes, &Options::new());
+    let bytes = format!("1{}", "0".repeat(255));
+    assert_eq!(parse(bytes.as_bytes()), Ok(U256([0, 0, 0, 1 << 63])));
+    assert_eq!(parse(b"12"), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .required_mantissa_sign(true)
+        .build();
+    let parse = |bytes| parse_complete::<U256, FORMAT>(bytes, &Options::new());
+    assert_eq!(parse(b"+18_446_744_073_709_551_616"), Ok(U256([0, 1, 0, 0])));
+    assert_eq!(parse(b"18446744073709551616"), Err(Error::MissingSign(0)));
+    for &input in &[&b"+1__0"[..], b"+_1", b"+1_", b"+1_x"] {
+        let expected = u64::from_lexical_with_options::<FORMAT>(input, &Options::new());
+        assert_eq!(parse(input), expected.map(|x| U256([x, 0, 0, 0])));
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_grouping_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b','))
+        .integer_internal_digit_separator(true)
+        .digit_grouping(DigitGrouping::Thousands)
+        .build();
+    let parse = |bytes| parse_complete::<I256, FORMAT>(bytes, &Options::new());
+    assert_eq!(parse(b"-1,234,567"), Ok(I256::new_small(-1234567)));
+    assert_eq!(parse(b"1234567"), Ok(I256::new_small(1234567)));
+    assert_eq!(parse(b"1,23,4567"), Err(Error::InvalidDigitGroup(4)));
+    let parse = |bytes| parse_partial::<I256, FORMAT>(bytes, &Options::new());
+    assert_eq!(parse(b"1,234 5"), Ok((I256::new_small(1234), 5)));
+    assert_eq!(parse(b"12,34 5"), Err(Error::InvalidDigitGroup(5)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn whitespace_test() {
+    const FORMAT: u128 =
+        NumberFormatBuilder::new().leading_whitespace(true).trailing_whitespace(true).build();
+    let parse = |bytes| parse_complete::<I256, FORMAT>(bytes, &Options::new());
+    assert_eq!(parse(b" 12 "), Ok(I256::new_small(12)));
+    assert_eq!(parse(b"\t-7\r\n"), Ok(I256::new_small(-7)));
+    assert_eq!(parse(b" 1 2"), Err(Error::InvalidDigit(2)));
+    let parse = |bytes| parse_partial::<I256, FORMAT>(bytes, &Options::new());
+    assert_eq!(parse(b" 12 \n3"), Ok((I256::new_small(12), 5)));
+
+    // Whitespace is only skipped where the format allows it.
+    const LEADING: u128 = NumberFormatBuilder::new().leading_whitespace(true).build();
+    let parse = |bytes| parse_complete::<I256, LEADING>(bytes, &Options::new());
+    assert_eq!(parse(b" 12"), Ok(I256::new_small(12)));
+    assert_eq!(parse(b"12 "), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse(b" 12 "), Err(Error::InvalidDigit(3)));
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn base_prefix_test() {
+    const PREFIX: u128 = NumberFormatBuilder::new()
+        .base_prefix(core::num::NonZeroU8::new(b'd'))
+        .base_suffix(core::num::NonZeroU8::new(b'h'))
+        .build();
+    let parse = |bytes| parse_complete::<U256, PREFIX>(bytes, &Options::new());
+    assert_eq!(parse(b"0d12"), Ok(U256([12, 0, 0, 0])));
+    assert_eq!(parse(b"12h"), Ok(U256([12, 0, 0, 0])));
+    assert_eq!(parse(b"0d"), Err(Error::Empty(2)));
+    let parse = |bytes| parse_partial::<U256, PREFIX>(bytes, &Options::new());
+    assert_eq!(parse(b"12h "), Ok((U256([12, 0, 0, 0]), 3)));
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn u128_proptest(i in r"[+-]?[0-9]{1,42}[a0-9]?") {
+        // A 2-limb wide integer must match the primitive integer.
+        let options = Options::new();
+        let expected = u128::from_lexical_with_options::<STANDARD>(i.as_bytes(), &options);
+        prop_assert_eq!(parse::<U128>(i.as_bytes()), expected.map(U128::new));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i128_proptest(i in r"[+-]?[0-9]{1,42}[a0-9]?") {
+        let options = Options::new();
+        let expected = i128::from_lexical_with_options::<STANDARD>(i.as_bytes(), &options);
+        prop_assert_eq!(parse::<I128>(i.as_bytes()), expected.map(I128::new));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i128_partial_proptest(i in r"[+-]?[0-9]{1,42}[a, ]?") {
+        let options = Options::new();
+        let expected = i128::from_lexical_partial_with_options::<STANDARD>(i.as_bytes(), &options);
+        let result = parse_partial::<I128, STANDARD>(i.as_bytes(), &options);
+        prop_assert_eq!(result, expected.map(|(x, n)| (I128::new(x), n)));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn overflow_mode_proptest(i in r"-?[0-9]{1,45}") {
+        for &mode in &[OverflowMode::Saturate, OverflowMode::Wrap] {
+            let options = Options::builder().overflow_mode(mode).build().unwrap();
+            let expected = i128::from_lexical_with_options::<STANDARD>(i.as_bytes(), &options);
+            let result = parse_complete::<I128, STANDARD>(i.as_bytes(), &options);
+            prop_assert_eq!(result, expected.map(I128::new));
+        }
+    }
+}
@@ -13,7 +13,7 @@
 macro_rules! from_lexical {
     () => {
         /// Trait for numerical types that can be parsed from bytes.
-        pub trait FromLexical: lexical_util::num::Number {
+        pub trait FromLexical: Sized {
             /// Checked parser for a string-to-number conversion.
             ///
             /// This method parses the entire string, returning an error if
@@ -35,6 +35,35 @@ macro_rules! from_lexical {
             ///
             /// * `bytes`   - Slice containing a numeric string.
             fn from_lexical_partial(bytes: &[u8]) -> lexical_util::result::Result<(Self, usize)>;
+
+            /// Checked parser for a string-to-number conversion from code units.
+            ///
+            /// This is the same as [`from_lexical`](Self::from_lexical), except
+            /// any non-ASCII code units are invalid digits.
+            ///
+            /// * `units`   - Slice of code units containing a numeric string.
+            #[inline]
+            fn from_lexical_units<U: lexical_util::code_unit::CodeUnit>(
+                units: &[U],
+            ) -> lexical_util::result::Result<Self> {
+                use lexical_util::code_unit::{is_ascii, narrow};
+                narrow(units, is_ascii, Self::from_lexical)
+            }
+
+            /// Checked parser for a string-to-number conversion from code units.
+            ///
+            /// This is the same as [`from_lexical_partial`](Self::from_lexical_partial),
+            /// except any non-ASCII code units are invalid digits, and the number
+            /// of processed code units is returned.
+            ///
+            /// * `units`   - Slice of code units containing a numeric string.
+            #[inline]
+            fn from_lexical_partial_units<U: lexical_util::code_unit::CodeUnit>(
+                units: &[U],
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                use lexical_util::code_unit::{is_ascii, narrow};
+                narrow(units, is_ascii, Self::from_lexical_partial)
+            }
         }
     };
 }
@@ -47,7 +76,7 @@ macro_rules! from_lexical_with_options {
         /// Trait for numerical types that can be parsed from bytes with custom options.
         ///
         /// The `Options` type specifies the configurable options to provide.
-        pub trait FromLexicalWithOptions: lexical_util::num::Number {
+        pub trait FromLexicalWithOptions: Sized {
             /// Custom formatting options for parsing a number.
             type Options: lexical_util::options::ParseOptions;
 
@@ -101,6 +130,106 @@ macro_rules! from_lexical_with_options {
                 bytes: &[u8],
                 options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>;
+
+            /// Checked parser for a string-to-number conversion from code units.
+            ///
+            /// This is the same as
+            /// [`from_lexical_with_options`](Self::from_lexical_with_options),
+            /// except any non-ASCII code units are invalid digits.
+            ///
+            /// * `U`       - Type of the code units.
+            /// * `FORMAT`  - Flags and characters designating the number grammar.
+            /// * `units`   - Slice of code units containing a numeric string.
+            /// * `options` - Options to dictate number parsing.
+            #[inline]
+            fn from_lexical_units_with_options<
+                U: lexical_util::code_unit::CodeUnit,
+                const FORMAT: u128,
+            >(
+                units: &[U],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self> {
+                use lexical_util::code_unit::{is_ascii, narrow};
+                narrow(units, is_ascii, |bytes| {
+                    Self::from_lexical_with_options::<FORMAT>(bytes, options)
+                })
+            }
+
+            /// Checked parser for a string-to-number conversion from code units.
+            ///
+            /// This is the same as
+            /// [`from_lexical_partial_with_options`](Self::from_lexical_partial_with_options),
+            /// except any non-ASCII code units are invalid digits, and the number
+            /// of processed code units is returned.
+            ///
+            /// * `U`       - Type of the code units.
+            /// * `FORMAT`  - Flags and characters designating the number grammar.
+            /// * `units`   - Slice of code units containing a numeric string.
+            /// * `options` - Options to dictate number parsing.
+            #[inline]
+            fn from_lexical_partial_units_with_options<
+                U: lexical_util::code_unit::CodeUnit,
+                const FORMAT: u128,
+            >(
+                units: &[U],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                use lexical_util::code_unit::{is_ascii, narrow};
+                narrow(units, is_ascii, |bytes| {
+                    Self::from_lexical_partial_with_options::<FORMAT>(bytes, options)
+                })
+            }
+        }
+    };
+}
+
+/// Define FromLexicalWithFormat trait.
+#[macro_export]
+#[cfg(feature = "parse")]
+macro_rules! from_lexical_with_format {
+    () => {
+        /// Trait for numerical types that can be parsed from bytes with a number format value.
+        ///
+        /// This is the same as `FromLexicalWithOptions`, except the number
+        /// format is a value implementing [`Format`], such as a
+        /// [`DynamicFormat`] selected at runtime, rather than a `FORMAT`
+        /// const generic parameter.
+        ///
+        /// [`Format`]: lexical_util::format::Format
+        /// [`DynamicFormat`]: lexical_util::format::DynamicFormat
+        pub trait FromLexicalWithFormat: FromLexicalWithOptions {
+            /// Checked parser for a string-to-number conversion.
+            ///
+            /// This method parses the entire string, returning an error if
+            /// any invalid digits are found during parsing. Any invalid
+            /// number format will prevent parsing, returning the appropriate
+            /// format error.
+            ///
+            /// * `bytes`   - Slice containing a numeric string.
+            /// * `format`  - Number format designating the number grammar.
+            /// * `options` - Options to dictate number parsing.
+            fn from_lexical_with_format<F: lexical_util::format::Format>(
+                bytes: &[u8],
+                format: F,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self>;
+
+            /// Checked parser for a string-to-number conversion.
+            ///
+            /// This method parses until an invalid digit is found (or the end
+            /// of the string), returning the number of processed digits
+            /// and the parsed value until that point. Any invalid number
+            /// format will prevent parsing, returning the appropriate
+            /// format error.
+            ///
+            /// * `bytes`   - Slice containing a numeric string.
+            /// * `format`  - Number format designating the number grammar.
+            /// * `options` - Options to dictate number parsing.
+            fn from_lexical_partial_with_format<F: lexical_util::format::Format>(
+                bytes: &[u8],
+                format: F,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)>;
         }
     };
 }
@@ -121,9 +250,7 @@ macro_rules! to_lexical {
         ///
         /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
         /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
-        pub trait ToLexical:
-            lexical_util::constants::FormattedSize + lexical_util::num::Number
-        {
+        pub trait ToLexical: lexical_util::constants::FormattedSize + Sized {
             /// Serializer for a number-to-string conversion.
             ///
             /// Returns a subslice of the input buffer containing the written bytes,
@@ -178,9 +305,7 @@ macro_rules! to_lexical_with_options {
         ///
         /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
         /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
-        pub trait ToLexicalWithOptions:
-            lexical_util::constants::FormattedSize + lexical_util::num::Number
-        {
+        pub trait ToLexicalWithOptions: lexical_util::constants::FormattedSize + Sized {
             /// Custom formatting options for writing a number.
             type Options: lexical_util::options::WriteOptions;
 
@@ -291,3 +416,68 @@ macro_rules! to_lexical_with_options {
         }
     };
 }
+
+/// Define ToLexicalWithFormat trait.
+#[macro_export]
+#[cfg(feature = "write")]
+macro_rules! to_lexical_with_format {
+    () => {
+        /// Trait for numerical types that can be serialized to bytes with a number format value.
+        ///
+        /// This is the same as `ToLexicalWithOptions`, except the number
+        /// format is a value implementing [`Format`], such as a
+        /// [`DynamicFormat`] selected at runtime, rather than a `FORMAT`
+        /// const generic parameter.
+        ///
+        /// [`Format`]: lexical_util::format::Format
+        /// [`DynamicFormat`]: lexical_util::format::DynamicFormat
+        pub trait ToLexicalWithFormat: ToLexicalWithOptions {
+            /// Serializer for a number-to-string conversion.
+            ///
+            /// Returns a subslice of the input buffer containing the written bytes,
+            /// starting from the same address in memory as the input slice.
+            ///
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            /// * `format`  - Number format designating the number grammar.
+            /// * `options` - Options for number formatting.
+            ///
+            /// # Safety
+            ///
+            /// Safe as long as the caller has provided a buffer large enough
+            /// to hold the serialized number, like for
+            /// `to_lexical_with_options_unchecked`.
+            ///
+            /// # Panics
+            ///
+            /// Panics in the same cases as `to_lexical_with_options_unchecked`.
+            unsafe fn to_lexical_with_format_unchecked<'a, F: lexical_util::format::Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: F,
+                options: &Self::Options,
+            ) -> &'a mut [u8];
+
+            /// Serializer for a number-to-string conversion.
+            ///
+            /// Returns a subslice of the input buffer containing the written bytes,
+            /// starting from the same address in memory as the input slice.
+            ///
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            /// * `format`  - Number format designating the number grammar.
+            /// * `options` - Options for number formatting.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the buffer is not of sufficient size, or in the same
+            /// cases as `to_lexical_with_options`.
+            fn to_lexical_with_format<'a, F: lexical_util::format::Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: F,
+                options: &Self::Options,
+            ) -> &'a mut [u8];
+        }
+    };
+}
@@ -2,7 +2,7 @@
 
 #[cfg(feature = "write")]
 use crate::constants::FormattedSize;
-use crate::format::NumberFormat;
+use crate::format::Format;
 
 // RADIX
 
@@ -30,19 +30,16 @@ pub fn debug_assert_radix(radix: u32) {
 /// Assert radix is in range `[2, 36]`.
 #[inline]
 #[cfg(feature = "radix")]
-pub fn assert_radix<const FORMAT: u128>() {
-    assert!(
-        (2..=36).contains(&NumberFormat::<{ FORMAT }>::RADIX),
-        "Numerical base must be from 2-36."
-    );
+pub fn assert_radix<F: Format>(format: F) {
+    assert!((2..=36).contains(&format.radix()), "Numerical base must be from 2-36.");
 }
 
 /// Check radix is is 10 or a power of 2.
 #[inline]
 #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
-pub fn assert_radix<const FORMAT: u128>() {
+pub fn assert_radix<F: Format>(format: F) {
     assert!(
-        matches!(NumberFormat::<{ FORMAT }>::RADIX, 2 | 4 | 8 | 10 | 16 | 32),
+        matches!(format.radix(), 2 | 4 | 8 | 10 | 16 | 32),
         "Numerical base must be from 2, 4, 8, 10, 16, or 32."
     );
 }
@@ -50,8 +47,8 @@ pub fn assert_radix<const FORMAT: u128>() {
 /// Check radix is equal to 10.
 #[inline]
 #[cfg(not(feature = "power-of-two"))]
-pub fn assert_radix<const FORMAT: u128>() {
-    assert!(NumberFormat::<{ FORMAT }>::RADIX == 10, "Numerical base must be 10.");
+pub fn assert_radix<F: Format>(format: F) {
+    assert!(format.radix() == 10, "Numerical base must be 10.");
 }
 
 // BUFFER
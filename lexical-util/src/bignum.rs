@@ -0,0 +1,502 @@
+//! Arbitrary-precision arithmetic over little-endian `u64` limb slices.
+//!
+//! These routines support converting very long digit strings to and from
+//! limbs, in `lexical-parse-integer` and `lexical-write-integer`. All
+//! buffers are provided by the caller, so no allocation is required:
+//! the `*_scratch_len` functions give the size of the scratch buffers.
+//!
+//! Multiplication uses Karatsuba's algorithm for large inputs, and
+//! division uses the recursive algorithm by Burnikel and Ziegler, "Fast
+//! Recursive Division" (1998), so both are subquadratic. The base cases
+//! use the schoolbook algorithms, and Knuth's Algorithm D for division.
+
+#![cfg(feature = "integers")]
+
+use core::cmp;
+
+/// The number of limbs below which schoolbook multiplication is used.
+pub const KARATSUBA_CUTOFF: usize = 32;
+
+/// The number of limbs below which schoolbook division is used.
+pub const BURNIKEL_ZIEGLER_CUTOFF: usize = 48;
+
+/// Get the largest power of the radix that fits in a `u64`, and its exponent.
+#[inline]
+pub const fn max_power(radix: u32) -> (u64, usize) {
+    let mut power = radix as u64;
+    let mut step = 1;
+    while power <= u64::MAX / radix as u64 {
+        power *= radix as u64;
+        step += 1;
+    }
+    (power, step)
+}
+
+/// Get the number of limbs without the most-significant zero limbs.
+#[inline]
+pub fn normalized_len(x: &[u64]) -> usize {
+    x.iter().rposition(|&v| v != 0).map_or(0, |index| index + 1)
+}
+
+/// Compare two values, which may have most-significant zero limbs.
+#[inline]
+pub fn compare(x: &[u64], y: &[u64]) -> cmp::Ordering {
+    let x = &x[..normalized_len(x)];
+    let y = &y[..normalized_len(y)];
+    x.len().cmp(&y.len()).then_with(|| x.iter().rev().cmp(y.iter().rev()))
+}
+
+/// Add `y` to `x` in place, propagating the carry through `x`.
+///
+/// Returns if the addition overflowed `x`. `x` must be at least as
+/// long as `y`.
+#[inline]
+pub fn add_assign(x: &mut [u64], y: &[u64]) -> bool {
+    debug_assert!(x.len() >= y.len());
+    let mut carry = false;
+    for (xi, &yi) in x.iter_mut().zip(y.iter()) {
+        let (v, c1) = xi.overflowing_add(yi);
+        let (v, c2) = v.overflowing_add(carry as u64);
+        *xi = v;
+        carry = c1 | c2;
+    }
+    for xi in x[y.len()..].iter_mut() {
+        if !carry {
+            break;
+        }
+        let (v, c) = xi.overflowing_add(1);
+        *xi = v;
+        carry = c;
+    }
+    carry
+}
+
+/// Subtract `y` from `x` in place, propagating the borrow through `x`.
+///
+/// Returns if the subtraction underflowed `x`. `x` must be at least as
+/// long as `y`.
+#[inline]
+pub fn sub_assign(x: &mut [u64], y: &[u64]) -> bool {
+    debug_assert!(x.len() >= y.len());
+    let mut borrow = false;
+    for (xi, &yi) in x.iter_mut().zip(y.iter()) {
+        let (v, b1) = xi.overflowing_sub(yi);
+        let (v, b2) = v.overflowing_sub(borrow as u64);
+        *xi = v;
+        borrow = b1 | b2;
+    }
+    for xi in x[y.len()..].iter_mut() {
+        if !borrow {
+            break;
+        }
+        let (v, b) = xi.overflowing_sub(1);
+        *xi = v;
+        borrow = b;
+    }
+    borrow
+}
+
+/// Multiply by a small value and add a carry in place.
+///
+/// Returns the carry out of the most-significant limb.
+#[inline]
+pub fn mul_small(x: &mut [u64], y: u64, mut carry: u64) -> u64 {
+    for xi in x.iter_mut() {
+        let full = (*xi as u128) * (y as u128) + (carry as u128);
+        *xi = full as u64;
+        carry = (full >> 64) as u64;
+    }
+    carry
+}
+
+/// Divide by a small value in place, and return the remainder.
+///
+/// The divisor must not be 0.
+#[inline]
+pub fn divrem_small(x: &mut [u64], y: u64) -> u64 {
+    debug_assert!(y != 0, "attempt to divide by zero");
+    let mut rem = 0_u64;
+    for xi in x.iter_mut().rev() {
+        let full = ((rem as u128) << 64) | (*xi as u128);
+        *xi = (full / y as u128) as u64;
+        rem = (full % y as u128) as u64;
+    }
+    rem
+}
+
+// MULTIPLICATION
+
+/// Get the number of scratch limbs required to multiply values with `x_len` and `y_len` limbs.
+///
+/// This is an upper bound, which increases with the length of the
+/// shorter value.
+#[inline]
+pub const fn mul_scratch_len(x_len: usize, y_len: usize) -> usize {
+    let short = if x_len < y_len {
+        x_len
+    } else {
+        y_len
+    };
+    if short < KARATSUBA_CUTOFF {
+        0
+    } else {
+        12 * short + 768
+    }
+}
+
+/// Multiply `x` and `y` using the schoolbook algorithm.
+fn schoolbook_mul(out: &mut [u64], x: &[u64], y: &[u64]) {
+    for limb in out.iter_mut() {
+        *limb = 0;
+    }
+    for (i, &yi) in y.iter().enumerate() {
+        if yi == 0 {
+            continue;
+        }
+        let mut carry = 0_u64;
+        for (j, &xj) in x.iter().enumerate() {
+            let full = (xj as u128) * (yi as u128) + (out[i + j] as u128) + (carry as u128);
+            out[i + j] = full as u64;
+            carry = (full >> 64) as u64;
+        }
+        out[i + x.len()] = carry;
+    }
+}
+
+/// Multiply `x` and `y` of the same length using Karatsuba's algorithm.
+///
+/// With `x = x1*B^m + x0` and `y = y1*B^m + y0`, the product is
+/// `z2*B^2m + z1*B^m + z0`, where `z0 = x0*y0`, `z2 = x1*y1`, and
+/// `z1 = (x0 + x1)*(y0 + y1) - z0 - z2`, requiring 3 multiplications.
+fn karatsuba_mul(out: &mut [u64], x: &[u64], y: &[u64], scratch: &mut [u64]) {
+    let n = x.len();
+    let m = n / 2;
+    let hi = n - m;
+    let (x0, x1) = x.split_at(m);
+    let (y0, y1) = y.split_at(m);
+    {
+        let (z0, z2) = out.split_at_mut(2 * m);
+        mul(z0, x0, y0, scratch);
+        mul(&mut z2[..2 * hi], x1, y1, scratch);
+    }
+
+    let (sx, scratch) = scratch.split_at_mut(hi + 1);
+    let (sy, scratch) = scratch.split_at_mut(hi + 1);
+    let (z1, scratch) = scratch.split_at_mut(2 * hi + 2);
+    sx[..hi].copy_from_slice(x1);
+    sx[hi] = add_assign(&mut sx[..hi], x0) as u64;
+    sy[..hi].copy_from_slice(y1);
+    sy[hi] = add_assign(&mut sy[..hi], y0) as u64;
+    mul(z1, sx, sy, scratch);
+    sub_assign(z1, &out[..2 * m]);
+    sub_assign(z1, &out[2 * m..2 * n]);
+    let len = normalized_len(z1);
+    add_assign(&mut out[m..2 * n], &z1[..len]);
+}
+
+/// Multiply `x` and `y`, storing the product in `out`.
+///
+/// `out` must have at least `x.len() + y.len()` limbs, and those limbs
+/// are overwritten. `scratch` must have at least [`mul_scratch_len`]
+/// limbs. Uses Karatsuba's algorithm if both values have at least
+/// [`KARATSUBA_CUTOFF`] limbs.
+pub fn mul(out: &mut [u64], x: &[u64], y: &[u64], scratch: &mut [u64]) {
+    let (x, y) = if x.len() >= y.len() {
+        (x, y)
+    } else {
+        (y, x)
+    };
+    let out = &mut out[..x.len() + y.len()];
+    let n = y.len();
+    if n < KARATSUBA_CUTOFF {
+        schoolbook_mul(out, x, y);
+    } else if x.len() == n {
+        karatsuba_mul(out, x, y, scratch);
+    } else {
+        // Split the longer value into chunks as long as the shorter one,
+        // and add each partial product.
+        for limb in out.iter_mut() {
+            *limb = 0;
+        }
+        let (product, scratch) = scratch.split_at_mut(2 * n);
+        for (index, chunk) in x.chunks(n).enumerate() {
+            let product = &mut product[..chunk.len() + n];
+            mul(product, chunk, y, scratch);
+            let len = normalized_len(product);
+            add_assign(&mut out[index * n..], &product[..len]);
+        }
+    }
+}
+
+// DIVISION
+
+/// Get the number of limbs a divisor is padded to for division.
+///
+/// The padded length is `m * 2^k` with `m` less than the cutoff, so the
+/// recursive division always splits the divisor evenly.
+#[inline]
+const fn padded_len(n: usize) -> usize {
+    let mut k = 0;
+    while (n + (1 << k) - 1) >> k >= BURNIKEL_ZIEGLER_CUTOFF {
+        k += 1;
+    }
+    ((n + (1 << k) - 1) >> k) << k
+}
+
+/// Get the number of scratch limbs required to divide by a value with `y_len` limbs.
+///
+/// This is an upper bound, which increases with the length of the divisor.
+#[inline]
+pub const fn divrem_scratch_len(y_len: usize) -> usize {
+    8 * padded_len(y_len) + 768
+}
+
+/// Divide `a` by `b` using Knuth's Algorithm D, from TAOCP Vol. 2, 4.3.1.
+///
+/// `b` must be normalized, so the most-significant bit is set, and the
+/// top `b.len()` limbs of `a` must be less than `b`. Stores the quotient
+/// in `q`, with `a.len() - b.len()` limbs, and the remainder in the low
+/// limbs of `a`.
+#[allow(clippy::many_single_char_names, clippy::needless_range_loop)]
+fn knuth_divrem(a: &mut [u64], b: &[u64], q: &mut [u64]) {
+    const BASE: u128 = 1 << 64;
+    let n = b.len();
+    let m = a.len() - n;
+    let bn1 = b[n - 1] as u128;
+    for j in (0..m).rev() {
+        // Estimate the quotient digit from the top 2 limbs, which is
+        // at most 2 too large, and refine it with the next limb.
+        let top = ((a[j + n] as u128) << 64) | (a[j + n - 1] as u128);
+        let mut qhat = top / bn1;
+        let mut rhat = top % bn1;
+        while qhat >= BASE
+            || (n >= 2 && qhat * b[n - 2] as u128 > ((rhat << 64) | a[j + n - 2] as u128))
+        {
+            qhat -= 1;
+            rhat += bn1;
+            if rhat >= BASE {
+                break;
+            }
+        }
+
+        // Multiply and subtract, adding back if the estimate was 1 too large.
+        let mut carry = 0_u64;
+        let mut borrow = false;
+        for i in 0..n {
+            let full = qhat * b[i] as u128 + carry as u128;
+            carry = (full >> 64) as u64;
+            let (v, b1) = a[j + i].overflowing_sub(full as u64);
+            let (v, b2) = v.overflowing_sub(borrow as u64);
+            a[j + i] = v;
+            borrow = b1 | b2;
+        }
+        let (v, b1) = a[j + n].overflowing_sub(carry);
+        let (v, b2) = v.overflowing_sub(borrow as u64);
+        a[j + n] = v;
+        if b1 | b2 {
+            qhat -= 1;
+            let carry = add_assign(&mut a[j..j + n], b);
+            a[j + n] = a[j + n].wrapping_add(carry as u64);
+        }
+        q[j] = qhat as u64;
+    }
+}
+
+/// Divide `a`, with `2n` limbs, by `b`, with `n` limbs.
+///
+/// `b` must be normalized, and the top half of `a` must be less than `b`.
+/// Stores the quotient in `q`, with `n` limbs, and the remainder in the
+/// low half of `a`.
+fn div2n1n(a: &mut [u64], b: &[u64], q: &mut [u64], scratch: &mut [u64]) {
+    let n = b.len();
+    if n & 1 != 0 || n < BURNIKEL_ZIEGLER_CUTOFF {
+        return knuth_divrem(a, b, q);
+    }
+    let h = n / 2;
+    let (q0, q1) = q.split_at_mut(h);
+    div3n2n(&mut a[h..4 * h], b, q1, scratch);
+    div3n2n(&mut a[..3 * h], b, q0, scratch);
+}
+
+/// Divide `a`, with `3h` limbs, by `b`, with `2h` limbs.
+///
+/// `b` must be normalized, and the top `2h` limbs of `a` must be less
+/// than `b`. Stores the quotient in `q`, with `h` limbs, and the remainder
+/// in the low `2h` limbs of `a`.
+fn div3n2n(a: &mut [u64], b: &[u64], q: &mut [u64], scratch: &mut [u64]) {
+    let h = q.len();
+    let (b2, b1) = b.split_at(h);
+    if compare(&a[2 * h..], b1) == cmp::Ordering::Less {
+        div2n1n(&mut a[h..], b1, q, scratch);
+    } else {
+        // The top limbs of `a` and `b` are equal, so the quotient is
+        // `B^h - 1`, and `[a1, a2] - q*b1` is `[a1, a2] - b1*B^h + b1`.
+        for limb in q.iter_mut() {
+            *limb = u64::MAX;
+        }
+        sub_assign(&mut a[2 * h..], b1);
+        add_assign(&mut a[h..], b1);
+    }
+
+    // Subtract `q*b2`, and correct the quotient, which is at most 2 too large.
+    let (product, scratch) = scratch.split_at_mut(2 * h);
+    mul(product, q, b2, scratch);
+    let mut borrow = sub_assign(a, product);
+    while borrow {
+        sub_assign(q, &[1]);
+        borrow = !add_assign(a, b);
+    }
+}
+
+/// Shift `src` left by `shift` bits into `dst`, which must be at least as long as `src`.
+///
+/// The shift must be less than 64, and any bits shifted out of `dst` are lost.
+#[inline]
+fn shl_into(dst: &mut [u64], src: &[u64], shift: u32) {
+    let mut prev = 0_u64;
+    for (index, limb) in dst.iter_mut().enumerate() {
+        let value = src.get(index).copied().unwrap_or(0);
+        *limb = match shift {
+            0 => value,
+            _ => (value << shift) | (prev >> (64 - shift)),
+        };
+        prev = value;
+    }
+}
+
+/// Shift `src` left by `shift` bits into `dst`.
+///
+/// The low limbs of `dst` are set to 0, and any bits shifted out of
+/// `dst` are lost.
+#[inline]
+pub fn shl(dst: &mut [u64], src: &[u64], shift: usize) {
+    let limbs = cmp::min(shift / 64, dst.len());
+    for limb in dst[..limbs].iter_mut() {
+        *limb = 0;
+    }
+    shl_into(&mut dst[limbs..], src, (shift % 64) as u32);
+}
+
+/// Divide `a` by a normalized `b`, storing the quotient in `q`.
+///
+/// This is faster than [`divrem`] for short quotients, such as when
+/// generating one digit at a time. The most-significant bit of `b` must
+/// be set, and the top `b.len()` limbs of `a` must be less than `b`, so
+/// the quotient fits in `a.len() - b.len()` limbs. On return, the low
+/// `b.len()` limbs of `a` hold the remainder.
+///
+/// # Panics
+///
+/// Panics if `b` is not normalized, or the quotient does not fit in
+/// `a.len() - b.len()` limbs.
+#[inline]
+pub fn divrem_normalized(a: &mut [u64], b: &[u64], q: &mut [u64]) {
+    let n = b.len();
+    assert!(n != 0 && b[n - 1] >> 63 == 1, "divisor must be normalized");
+    assert!(compare(&a[a.len() - n..], b) == cmp::Ordering::Less, "quotient is too large");
+    knuth_divrem(a, b, q);
+}
+
+/// Divide `x` by `y` in place, storing the quotient in `q`.
+///
+/// `y` must not be 0, and the quotient must fit in `y.len()` limbs, that
+/// is, `x < y * 2^(64 * y.len())`, which holds if `x < y^2`. On return,
+/// `x` holds the remainder, and `q` holds the quotient. `q` must have at
+/// least `y.len()` limbs, and `scratch` must have at least
+/// [`divrem_scratch_len`] limbs.
+pub fn divrem(x: &mut [u64], y: &[u64], q: &mut [u64], scratch: &mut [u64]) {
+    let y = &y[..normalized_len(y)];
+    assert!(!y.is_empty(), "attempt to divide by zero");
+    let n = y.len();
+    let x_len = normalized_len(x);
+    for limb in q.iter_mut() {
+        *limb = 0;
+    }
+    if compare(x, y) == cmp::Ordering::Less {
+        return;
+    } else if n == 1 {
+        let rem = divrem_small(&mut x[..x_len], y[0]);
+        q[0] = x[0];
+        for limb in x.iter_mut() {
+            *limb = 0;
+        }
+        x[0] = rem;
+        return;
+    }
+
+    // Normalize the divisor so the most-significant bit is set, and pad
+    // it with low limbs so it can be split evenly in the recursion.
+    let padded = padded_len(n);
+    let pad = padded - n;
+    let shift = y[n - 1].leading_zeros();
+    let (b, scratch) = scratch.split_at_mut(padded);
+    let (a, scratch) = scratch.split_at_mut(2 * padded);
+    let (quotient, scratch) = scratch.split_at_mut(padded);
+    for limb in b[..pad].iter_mut() {
+        *limb = 0;
+    }
+    shl_into(&mut b[pad..], y, shift);
+    for limb in a[..pad].iter_mut() {
+        *limb = 0;
+    }
+    let end = cmp::min(pad + x_len + 1, 2 * padded);
+    shl_into(&mut a[pad..end], &x[..x_len], shift);
+    for limb in a[end..].iter_mut() {
+        *limb = 0;
+    }
+
+    div2n1n(a, b, quotient, scratch);
+    q[..n].copy_from_slice(&quotient[..n]);
+    for limb in x.iter_mut() {
+        *limb = 0;
+    }
+    for (index, limb) in x[..n].iter_mut().enumerate() {
+        let value = a[pad + index];
+        *limb = match shift {
+            0 => value,
+            _ => (value >> shift) | (a[pad + index + 1] << (64 - shift)),
+        };
+    }
+}
+
+// POWERS
+
+/// Get the offset of the power `base^(2^index)` in a table of powers.
+#[inline]
+const fn power_offset(index: usize) -> usize {
+    (1 << index) - 1
+}
+
+/// Get the number of limbs in a table of the powers `base^(2^i)` for `i <= index`.
+///
+/// Each power `base^(2^i)` is stored in `2^i` limbs.
+#[inline]
+pub const fn powers_len(index: usize) -> usize {
+    power_offset(index + 1)
+}
+
+/// Fill a table with the powers `base^(2^i)` for `i <= index`, by repeated squaring.
+///
+/// `table` must have at least [`powers_len`] limbs, and `scratch` must
+/// have at least [`mul_scratch_len`] limbs for values with `2^(index-1)`
+/// limbs.
+pub fn powers(base: u64, index: usize, table: &mut [u64], scratch: &mut [u64]) {
+    table[0] = base;
+    for i in 1..=index {
+        let (prev, next) = table.split_at_mut(power_offset(i));
+        let prev = &prev[power_offset(i - 1)..];
+        let prev = &prev[..normalized_len(prev)];
+        let next = &mut next[..1 << i];
+        for limb in next.iter_mut() {
+            *limb = 0;
+        }
+        mul(next, prev, prev, scratch);
+    }
+}
+
+/// Get the power `base^(2^index)` from a table of powers, without the most-significant zero limbs.
+#[inline]
+pub fn power(table: &[u64], index: usize) -> &[u64] {
+    let power = &table[power_offset(index)..power_offset(index + 1)];
+    &power[..normalized_len(power)]
+}
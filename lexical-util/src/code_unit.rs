@@ -0,0 +1,150 @@
+//! Parse from slices of code units other than bytes.
+//!
+//! The parsers only work over contiguous bytes, however, numbers are
+//! only made of ASCII characters, so any code unit can be narrowed to a
+//! byte without changing the result. Non-ASCII code units are narrowed
+//! to a byte that is never valid in a number, so they are rejected at
+//! the same index as an invalid byte.
+//!
+//! The parsers never read past a byte that cannot be part of the number,
+//! so only the code units of the number and the first code unit after it
+//! are narrowed, into a small buffer on the stack, or on the heap for
+//! long numbers. Byte slices are parsed directly, so they keep all
+//! optimizations.
+
+#![cfg(feature = "parse")]
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::result::Result;
+
+/// Byte that non-ASCII code units are narrowed to.
+///
+/// This is never valid ASCII or UTF-8, so it is never part of a number.
+pub const NON_ASCII: u8 = 0xFF;
+
+/// Maximum number of code units narrowed on the stack.
+///
+/// Longer numbers are narrowed on the heap.
+pub const NARROW_SIZE: usize = 256;
+
+/// A unit of text that can be narrowed to a byte.
+pub trait CodeUnit: Copy {
+    /// Convert the code unit to an ASCII byte, or [`NON_ASCII`].
+    fn to_byte(self) -> u8;
+
+    /// Convert the code unit to a code point, or a value above `char::MAX`.
+    ///
+    /// By default, only ASCII code units are converted. Surrogates are
+    /// not decoded, and are returned unchanged.
+    #[inline(always)]
+    fn to_code_point(self) -> u32 {
+        match self.to_byte() {
+            byte @ 0..=0x7F => byte as u32,
+            _ => u32::MAX,
+        }
+    }
+
+    /// Get the code units as bytes, if they are bytes.
+    #[inline(always)]
+    fn as_bytes(_: &[Self]) -> Option<&[u8]> {
+        None
+    }
+}
+
+impl CodeUnit for u8 {
+    #[inline(always)]
+    fn to_byte(self) -> u8 {
+        self
+    }
+
+    #[inline(always)]
+    fn as_bytes(units: &[Self]) -> Option<&[u8]> {
+        Some(units)
+    }
+}
+
+impl CodeUnit for u16 {
+    #[inline(always)]
+    fn to_byte(self) -> u8 {
+        if self < 0x80 {
+            self as u8
+        } else {
+            NON_ASCII
+        }
+    }
+
+    #[inline(always)]
+    fn to_code_point(self) -> u32 {
+        self as u32
+    }
+}
+
+impl CodeUnit for u32 {
+    #[inline(always)]
+    fn to_byte(self) -> u8 {
+        if self < 0x80 {
+            self as u8
+        } else {
+            NON_ASCII
+        }
+    }
+
+    #[inline(always)]
+    fn to_code_point(self) -> u32 {
+        self
+    }
+}
+
+impl CodeUnit for char {
+    #[inline(always)]
+    fn to_byte(self) -> u8 {
+        (self as u32).to_byte()
+    }
+
+    #[inline(always)]
+    fn to_code_point(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Narrow the code units of a number to bytes, and parse them.
+///
+/// `is_token` must return true for every byte that may be part of a
+/// number, and `parse` is the parser for the bytes. Indexes and counts
+/// are the same for code units and bytes.
+#[inline]
+pub fn narrow<U, T, Token, Parse>(units: &[U], is_token: Token, parse: Parse) -> Result<T>
+where
+    U: CodeUnit,
+    Token: Fn(u8) -> bool,
+    Parse: FnOnce(&[u8]) -> Result<T>,
+{
+    if let Some(bytes) = U::as_bytes(units) {
+        return parse(bytes);
+    }
+
+    // Include the first byte after the number, if present.
+    let count = units.iter().take_while(|&&c| is_token(c.to_byte())).count();
+    let units = &units[..units.len().min(count + 1)];
+    if units.len() <= NARROW_SIZE {
+        let mut buffer = [0u8; NARROW_SIZE];
+        for (byte, &unit) in buffer.iter_mut().zip(units.iter()) {
+            *byte = unit.to_byte();
+        }
+        return parse(&buffer[..units.len()]);
+    }
+
+    let buffer: Vec<u8> = units.iter().map(|&unit| unit.to_byte()).collect();
+    parse(&buffer)
+}
+
+/// Get if a narrowed byte may be part of any number.
+///
+/// This is the token check for parsers without a narrower check: only
+/// non-ASCII code units are never part of a number.
+#[inline(always)]
+pub const fn is_ascii(byte: u8) -> bool {
+    byte.is_ascii()
+}
@@ -0,0 +1,30 @@
+//! Complex numbers, with a real and an imaginary float part.
+//!
+//! Complex numbers are meant as an interchange format for scientific
+//! text formats, like Matlab, Octave or Julia, so they are parsed and
+//! written, but do not implement any arithmetic operations. They can
+//! be written as `a+bi`, with a configurable imaginary unit, or as an
+//! `(a,b)` tuple.
+
+#![cfg(feature = "complex")]
+#![doc(hidden)]
+
+/// A complex number, `re + im*i`.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+pub struct Complex<F> {
+    /// The real part.
+    pub re: F,
+    /// The imaginary part.
+    pub im: F,
+}
+
+impl<F> Complex<F> {
+    /// Create the complex number from the real and imaginary parts.
+    #[inline(always)]
+    pub const fn new(re: F, im: F) -> Self {
+        Self {
+            re,
+            im,
+        }
+    }
+}
@@ -4,8 +4,37 @@
 
 #[cfg(feature = "f16")]
 use crate::bf16::bf16;
+#[cfg(feature = "complex")]
+use crate::complex::Complex;
+#[cfg(feature = "custom-float")]
+use crate::custom_float::CustomFloat;
+#[cfg(feature = "decimal")]
+use crate::decimal::{decimal128, decimal32, decimal64};
+#[cfg(feature = "double-double")]
+use crate::double_double::DoubleDouble;
 #[cfg(feature = "f16")]
 use crate::f16::f16;
+#[cfg(feature = "f80")]
+use crate::f80::f80;
+#[cfg(feature = "f128")]
+use crate::f128::f128;
+#[cfg(feature = "saturating")]
+use core::num::Saturating;
+use core::num::{
+    NonZeroI128,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI8,
+    NonZeroIsize,
+    NonZeroU128,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU8,
+    NonZeroUsize,
+    Wrapping,
+};
 
 /// The size, in bytes, of formatted values.
 pub trait FormattedSize {
@@ -67,7 +96,6 @@ formatted_size_impl! {
     //f16 64 256 ;
     f32 64 256 ;
     f64 64 256 ;
-    //f128 128 512 ;
     //f256 256 1024 ;
 }
 
@@ -77,6 +105,46 @@ formatted_size_impl! {
     bf16 64 256 ;
 }
 
+#[cfg(feature = "custom-float")]
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> FormattedSize
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    #[cfg(feature = "power-of-two")]
+    const FORMATTED_SIZE: usize = 256;
+    #[cfg(not(feature = "power-of-two"))]
+    const FORMATTED_SIZE: usize = 64;
+    const FORMATTED_SIZE_DECIMAL: usize = 64;
+}
+
+#[cfg(feature = "f80")]
+formatted_size_impl! { f80 128 512 ; }
+
+#[cfg(feature = "f128")]
+formatted_size_impl! { f128 128 512 ; }
+
+// Double-doubles may need every digit from the high float to the smallest
+// denormal, or at most 633 digits in decimal, and 2100 in binary.
+#[cfg(feature = "double-double")]
+formatted_size_impl! { DoubleDouble 768 2304 ; }
+
+// Complex numbers write both parts, with a byte for the sign of the
+// imaginary part and room for an imaginary unit of at most 4 bytes,
+// which also fits the parentheses and comma of a tuple.
+#[cfg(feature = "complex")]
+impl<F: FormattedSize> FormattedSize for Complex<F> {
+    const FORMATTED_SIZE: usize = 2 * F::FORMATTED_SIZE + 5;
+    const FORMATTED_SIZE_DECIMAL: usize = 2 * F::FORMATTED_SIZE_DECIMAL + 5;
+}
+
+// Decimal floats are always written in decimal, so the radix does not
+// change the size.
+#[cfg(feature = "decimal")]
+formatted_size_impl! {
+    decimal32 64 64 ;
+    decimal64 64 64 ;
+    decimal128 64 64 ;
+}
+
 #[cfg(target_pointer_width = "16")]
 formatted_size_impl! { isize 6 32 ; }
 #[cfg(target_pointer_width = "16")]
@@ -92,6 +160,42 @@ formatted_size_impl! { isize 20 128 ; }
 #[cfg(target_pointer_width = "64")]
 formatted_size_impl! { usize 20 128 ; }
 
+/// Implement `FormattedSize` for a non-zero integer from the primitive.
+macro_rules! nonzero_formatted_size_impl {
+    ($($t:ident $inner:ident ; )*) => ($(
+        impl FormattedSize for $t {
+            const FORMATTED_SIZE: usize = $inner::FORMATTED_SIZE;
+            const FORMATTED_SIZE_DECIMAL: usize = $inner::FORMATTED_SIZE_DECIMAL;
+        }
+    )*);
+}
+
+nonzero_formatted_size_impl! {
+    NonZeroI8 i8 ;
+    NonZeroI16 i16 ;
+    NonZeroI32 i32 ;
+    NonZeroI64 i64 ;
+    NonZeroI128 i128 ;
+    NonZeroIsize isize ;
+    NonZeroU8 u8 ;
+    NonZeroU16 u16 ;
+    NonZeroU32 u32 ;
+    NonZeroU64 u64 ;
+    NonZeroU128 u128 ;
+    NonZeroUsize usize ;
+}
+
+impl<T: FormattedSize> FormattedSize for Wrapping<T> {
+    const FORMATTED_SIZE: usize = T::FORMATTED_SIZE;
+    const FORMATTED_SIZE_DECIMAL: usize = T::FORMATTED_SIZE_DECIMAL;
+}
+
+#[cfg(feature = "saturating")]
+impl<T: FormattedSize> FormattedSize for Saturating<T> {
+    const FORMATTED_SIZE: usize = T::FORMATTED_SIZE;
+    const FORMATTED_SIZE_DECIMAL: usize = T::FORMATTED_SIZE_DECIMAL;
+}
+
 /// Maximum number of bytes required to serialize any number to string.
 ///
 /// Note that this value may be insufficient if digit precision control,
@@ -0,0 +1,259 @@
+//! Narrow floats with a configurable number of exponent and mantissa bits.
+//!
+//! `CustomFloat` is meant as an interchange format for the floats used by
+//! machine-learning accelerators, such as FP8 and TF32: it is parsed and
+//! written exactly, but the arithmetic operations and the formatting traits
+//! convert the value to f64. Every value is exact as an f64, so only the
+//! result of an operation is rounded.
+//!
+//! The float has a sign bit, `EXPONENT` exponent bits, and `MANTISSA`
+//! explicit mantissa bits, with the IEEE-754 exponent bias. `SPECIAL`
+//! selects the encoding of the special values:
+//!
+//! - [`IEEE`]: the largest exponent is reserved for infinity and NaN,
+//!     like the IEEE-754 floats and FP8 E5M2.
+//! - [`FINITE`]: there is no infinity, and only the values with every
+//!     exponent and mantissa bit set are NaN, so the largest exponent is
+//!     used for finite values, like FP8 E4M3. Values above the largest
+//!     finite float, including infinity, saturate to the largest finite
+//!     float, which is written with its exact digits.
+//!
+//! `EXPONENT` must be from 2 to 11, `MANTISSA` must be at least 1, and
+//! the float must fit in 32 bits. Comparisons are exact, and use the
+//! IEEE-754 semantics.
+
+#![cfg(feature = "custom-float")]
+#![doc(hidden)]
+
+use crate::num::Float;
+use core::cmp::Ordering;
+use core::{fmt, ops};
+
+/// The largest exponent is reserved for infinity and NaN.
+pub const IEEE: u8 = 0;
+
+/// There is no infinity, and NaN has every exponent and mantissa bit set.
+pub const FINITE: u8 = 1;
+
+/// Float with `EXPONENT` exponent bits, `MANTISSA` mantissa bits, and
+/// the special values given by `SPECIAL`.
+#[derive(Default, Copy, Clone)]
+pub struct CustomFloat<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> {
+    /// Raw bitwise representation of the float, in the low bits.
+    bits: u32,
+}
+
+/// FP8 E4M3 float, with no infinity, as used for weights and activations.
+#[allow(non_camel_case_types)]
+pub type f8e4m3 = CustomFloat<4, 3, FINITE>;
+
+/// FP8 E5M2 float, with IEEE-754 special values, as used for gradients.
+#[allow(non_camel_case_types)]
+pub type f8e5m2 = CustomFloat<5, 2, IEEE>;
+
+/// TensorFloat-32, with the range of an f32 and the precision of an f16.
+#[allow(non_camel_case_types)]
+pub type tf32 = CustomFloat<8, 10, IEEE>;
+
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8>
+    CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    /// Mask for the bits of the float.
+    const BITS_MASK: u32 = u32::MAX >> (31 - EXPONENT - MANTISSA);
+
+    #[inline(always)]
+    pub const fn to_bits(self) -> u32 {
+        self.bits
+    }
+
+    /// Create the float from the low bits, ignoring any higher bits.
+    #[inline(always)]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self {
+            bits: bits & Self::BITS_MASK,
+        }
+    }
+
+    #[inline(always)]
+    pub fn as_f64(self) -> f64 {
+        custom_to_f64(self)
+    }
+
+    #[inline(always)]
+    pub fn from_f64(value: f64) -> Self {
+        f64_to_custom(value)
+    }
+
+    /// Get the bits ordered like the values, if the float is not NaN.
+    #[inline(always)]
+    fn ordered_bits(self) -> i64 {
+        let magnitude = (self.bits & !Self::SIGN_MASK) as i64;
+        if self.is_sign_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> PartialEq
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        !self.is_nan() && !other.is_nan() && self.ordered_bits() == other.ordered_bits()
+    }
+}
+
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> PartialOrd
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else {
+            Some(self.ordered_bits().cmp(&other.ordered_bits()))
+        }
+    }
+}
+
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> fmt::Debug
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_f64().fmt(formatter)
+    }
+}
+
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> fmt::Display
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_f64().fmt(formatter)
+    }
+}
+
+macro_rules! binary_op_impl {
+    ($($trait:ident $method:ident $assign_trait:ident $assign:ident $op:tt ;)*) => ($(
+        impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> ops::$trait
+            for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+        {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self::from_f64(self.as_f64() $op rhs.as_f64())
+            }
+        }
+
+        impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> ops::$assign_trait
+            for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+        {
+            #[inline(always)]
+            fn $assign(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    )*);
+}
+
+binary_op_impl! {
+    Add add AddAssign add_assign + ;
+    Div div DivAssign div_assign / ;
+    Mul mul MulAssign mul_assign * ;
+    Sub sub SubAssign sub_assign - ;
+    Rem rem RemAssign rem_assign % ;
+}
+
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> ops::Neg
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self::from_bits(self.bits ^ Self::SIGN_MASK)
+    }
+}
+
+// Every value is exactly representable as an f64, but the smallest
+// denormals may be denormal as an f64. Narrowing rounds to nearest,
+// with ties to even, and saturates if there is no infinity.
+
+fn custom_to_f64<F: Float<Unsigned = u32>>(x: F) -> f64 {
+    let sign = (x.is_sign_negative() as u64) << 63;
+    if x.is_nan() {
+        return f64::from_bits(sign | f64::NAN.to_bits());
+    } else if x.is_inf() {
+        return f64::from_bits(sign | f64::INFINITY_BITS);
+    }
+
+    let man = x.mantissa() as u64;
+    if man == 0 {
+        return f64::from_bits(sign);
+    }
+
+    // Normalize the significand so the hidden bit is set, unless the value is denormal.
+    let exp = x.exponent();
+    let shift = man.leading_zeros() as i32 - (63 - f64::MANTISSA_SIZE);
+    let biased = exp - shift - f64::DENORMAL_EXPONENT + 1;
+    if biased > 0 {
+        let man = (man << shift) & f64::MANTISSA_MASK;
+        f64::from_bits(sign | ((biased as u64) << f64::MANTISSA_SIZE) | man)
+    } else {
+        f64::from_bits(sign | (man << (exp - f64::DENORMAL_EXPONENT)))
+    }
+}
+
+fn f64_to_custom<F: Float<Unsigned = u32>>(value: f64) -> F {
+    let sign = match value.is_sign_negative() {
+        true => F::SIGN_MASK,
+        false => 0,
+    };
+    if value.is_nan() {
+        return F::from_bits(sign | F::NAN.to_bits());
+    } else if value.is_inf() {
+        return F::from_bits(sign | F::INFINITY_BITS);
+    }
+
+    let man = value.mantissa();
+    if man == 0 {
+        return F::from_bits(sign);
+    }
+
+    // Shift to the precision of the result, and round the truncated bits.
+    let exp = value.exponent();
+    let top = exp + 63 - man.leading_zeros() as i32;
+    let mut ulp = (top - F::MANTISSA_SIZE).max(F::DENORMAL_EXPONENT);
+    let shift = ulp - exp;
+    let mut result = if shift <= 0 {
+        man << -shift
+    } else if shift > 64 - man.leading_zeros() as i32 {
+        // The value is below half the smallest denormal.
+        return F::from_bits(sign);
+    } else {
+        let result = man >> shift;
+        let halfway = 1_u64 << (shift - 1);
+        let truncated = man & ((halfway << 1) - 1);
+        let is_above = truncated > halfway || (truncated == halfway && result & 1 != 0);
+        result + is_above as u64
+    };
+
+    // Rebias the exponent, where rounding may carry into the next exponent.
+    if result == (F::CARRY_MASK as u64) {
+        result >>= 1;
+        ulp += 1;
+    }
+    let biased = match result & F::HIDDEN_BIT_MASK as u64 {
+        0 => 0,
+        _ => (ulp - F::DENORMAL_EXPONENT + 1) as u64,
+    };
+    let bits = (biased << F::MANTISSA_SIZE) | (result & F::MANTISSA_MASK as u64);
+    match bits > F::MAX.to_bits() as u64 {
+        true => F::from_bits(sign | F::INFINITY_BITS),
+        false => F::from_bits(sign | bits as u32),
+    }
+}
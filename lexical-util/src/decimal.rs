@@ -0,0 +1,263 @@
+//! IEEE-754 decimal floating point implementation.
+//!
+//! The decimal32, decimal64 and decimal128 formats are meant as interchange
+//! formats, such as for the `Decimal128` type in MongoDB, so they are
+//! parsed and written, but do not implement any arithmetic operations.
+//! They use the binary integer decimal (BID) encoding.
+//!
+//! A finite decimal float is `(-1)^sign * coefficient * 10^exponent`,
+//! where the coefficient is an integer with at most `PRECISION` digits.
+//! The same value may have multiple representations, such as `1.5` and
+//! `1.50`, which is called the cohort of the value, and the exponent is
+//! called the quantum. Unlike binary floats, the quantum is significant,
+//! so it is preserved when parsing and writing.
+
+#![cfg(feature = "decimal")]
+#![doc(hidden)]
+
+use core::fmt;
+
+/// Shared implementation for the IEEE-754 decimal floats.
+///
+/// The raw bits are widened to `u128`, so the encoding can be shared
+/// for all the formats.
+pub trait DecimalFloat: Copy + Clone + Default + fmt::Debug + Send + Sync {
+    /// The number of bits in the encoding.
+    const BITS: u32;
+    /// The maximum number of significant digits in the coefficient.
+    const PRECISION: usize;
+    /// The number of bits in the biased exponent.
+    const EXPONENT_BITS: u32;
+    /// The bias of the exponent.
+    const EXPONENT_BIAS: i32;
+    /// The minimum exponent of the coefficient, or the quantum of subnormals.
+    const MIN_EXPONENT: i32 = -Self::EXPONENT_BIAS;
+    /// The maximum exponent of the coefficient.
+    const MAX_EXPONENT: i32 = 3 * (1 << (Self::EXPONENT_BITS - 2)) - 1 - Self::EXPONENT_BIAS;
+    /// The maximum value of the coefficient, or `10^PRECISION - 1`.
+    const MAX_COEFFICIENT: u128;
+
+    /// A quiet NaN.
+    const NAN: Self;
+    /// Positive infinity.
+    const INFINITY: Self;
+    /// Negative infinity.
+    const NEG_INFINITY: Self;
+
+    /// Get the raw bits, widened to `u128`.
+    fn to_raw_bits(self) -> u128;
+
+    /// Create the float from the raw bits, ignoring any higher bits.
+    fn from_raw_bits(bits: u128) -> Self;
+
+    // PROPERTIES
+
+    /// The mask for the sign bit.
+    #[inline(always)]
+    fn sign_mask() -> u128 {
+        1 << (Self::BITS - 1)
+    }
+
+    /// The number of bits of the coefficient stored after the exponent.
+    #[inline(always)]
+    fn coefficient_bits() -> u32 {
+        Self::BITS - 1 - Self::EXPONENT_BITS
+    }
+
+    /// Get the bits in the combination field after the sign bit.
+    #[inline(always)]
+    fn combination(self, count: u32) -> u32 {
+        let shift = Self::BITS - 1 - count;
+        ((self.to_raw_bits() >> shift) & ((1 << count) - 1)) as u32
+    }
+
+    /// Returns true if the float has a negative sign, including NaN and zero.
+    #[inline(always)]
+    fn is_sign_negative(self) -> bool {
+        self.to_raw_bits() & Self::sign_mask() != 0
+    }
+
+    /// Returns true if the float is a quiet or signaling NaN.
+    #[inline(always)]
+    fn is_nan(self) -> bool {
+        self.combination(5) == 0b11111
+    }
+
+    /// Returns true if the float is a signaling NaN.
+    #[inline(always)]
+    fn is_snan(self) -> bool {
+        self.combination(6) == 0b111111
+    }
+
+    /// Returns true if the float is positive or negative infinity.
+    #[inline(always)]
+    fn is_inf(self) -> bool {
+        self.combination(5) == 0b11110
+    }
+
+    /// Returns true if the float is not NaN or infinite.
+    #[inline(always)]
+    fn is_finite(self) -> bool {
+        self.combination(4) != 0b1111
+    }
+
+    /// Get the biased exponent and the coefficient, if the float is finite.
+    ///
+    /// Non-canonical coefficients, which are larger than the maximum
+    /// coefficient, are treated as 0.
+    #[inline]
+    fn decode(self) -> (i32, u128) {
+        debug_assert!(self.is_finite());
+        let bits = self.to_raw_bits();
+        let exponent_mask = (1 << Self::EXPONENT_BITS) - 1;
+        let (biased, coefficient) = if self.combination(2) != 0b11 {
+            let shift = Self::coefficient_bits();
+            ((bits >> shift) & exponent_mask, bits & ((1 << shift) - 1))
+        } else {
+            let shift = Self::coefficient_bits() - 2;
+            let coefficient = (0b100 << shift) | (bits & ((1 << shift) - 1));
+            ((bits >> shift) & exponent_mask, coefficient)
+        };
+        match coefficient > Self::MAX_COEFFICIENT {
+            true => (biased as i32, 0),
+            false => (biased as i32, coefficient),
+        }
+    }
+
+    /// Get the coefficient, which is 0 if the float is not finite.
+    #[inline]
+    fn coefficient(self) -> u128 {
+        match self.is_finite() {
+            true => self.decode().1,
+            false => 0,
+        }
+    }
+
+    /// Get the exponent of the coefficient, which is 0 if the float is not finite.
+    #[inline]
+    fn exponent(self) -> i32 {
+        match self.is_finite() {
+            true => self.decode().0 - Self::EXPONENT_BIAS,
+            false => 0,
+        }
+    }
+
+    /// Create a finite float from the sign, coefficient, and exponent.
+    ///
+    /// Returns `None` if the coefficient has more than `PRECISION` digits,
+    /// or if the exponent is out of range. The value is not rounded or
+    /// clamped, so the quantum is always preserved.
+    #[inline]
+    fn from_parts(is_negative: bool, coefficient: u128, exponent: i32) -> Option<Self> {
+        if coefficient > Self::MAX_COEFFICIENT
+            || exponent < Self::MIN_EXPONENT
+            || exponent > Self::MAX_EXPONENT
+        {
+            return None;
+        }
+
+        let biased = (exponent + Self::EXPONENT_BIAS) as u128;
+        let shift = Self::coefficient_bits();
+        let mut bits = if coefficient < 1 << shift {
+            (biased << shift) | coefficient
+        } else {
+            let shift = shift - 2;
+            let coefficient = coefficient & ((1 << shift) - 1);
+            (0b11 << (Self::BITS - 3)) | (biased << shift) | coefficient
+        };
+        if is_negative {
+            bits |= Self::sign_mask();
+        }
+        Some(Self::from_raw_bits(bits))
+    }
+
+    /// Get the member of the cohort with the largest exponent.
+    ///
+    /// This removes the trailing zeros from the coefficient, so `1.50` is
+    /// normalized to `1.5`, and `1200` to `1.2e3`. Zero is normalized to an
+    /// exponent of 0, and special values are returned unchanged.
+    #[inline]
+    fn normalize(self) -> Self {
+        if !self.is_finite() {
+            return self;
+        }
+        let is_negative = self.is_sign_negative();
+        let mut coefficient = self.coefficient();
+        let mut exponent = self.exponent();
+        if coefficient == 0 {
+            exponent = 0;
+        }
+        while coefficient != 0 && exponent < Self::MAX_EXPONENT {
+            let (quotient, remainder) = (coefficient / 10, coefficient % 10);
+            if remainder != 0 {
+                break;
+            }
+            coefficient = quotient;
+            exponent += 1;
+        }
+        // The coefficient and exponent are always in range.
+        Self::from_parts(is_negative, coefficient, exponent).unwrap_or(self)
+    }
+}
+
+/// Implement the decimal float type.
+macro_rules! decimal_impl {
+    ($(
+        $(#[$meta:meta])*
+        $t:ident $bits:ty, $width:literal, $precision:literal,
+        $exponent_bits:literal, $bias:literal ;
+    )*) => ($(
+        $(#[$meta])*
+        #[allow(non_camel_case_types)]
+        #[derive(Default, Copy, Clone, Debug)]
+        pub struct $t {
+            /// Raw bitwise representation of the float, in the BID encoding.
+            bits: $bits,
+        }
+
+        impl $t {
+            #[inline(always)]
+            pub const fn to_bits(self) -> $bits {
+                self.bits
+            }
+
+            #[inline(always)]
+            pub const fn from_bits(bits: $bits) -> Self {
+                Self {
+                    bits,
+                }
+            }
+        }
+
+        impl DecimalFloat for $t {
+            const BITS: u32 = $width;
+            const PRECISION: usize = $precision;
+            const EXPONENT_BITS: u32 = $exponent_bits;
+            const EXPONENT_BIAS: i32 = $bias;
+            const MAX_COEFFICIENT: u128 = 10u128.pow($precision) - 1;
+
+            const NAN: Self = Self::from_bits(0b11111 << ($width - 6));
+            const INFINITY: Self = Self::from_bits(0b11110 << ($width - 6));
+            const NEG_INFINITY: Self = Self::from_bits(0b111110 << ($width - 6));
+
+            #[inline(always)]
+            fn to_raw_bits(self) -> u128 {
+                self.bits as u128
+            }
+
+            #[inline(always)]
+            fn from_raw_bits(bits: u128) -> Self {
+                Self::from_bits(bits as $bits)
+            }
+        }
+    )*);
+}
+
+decimal_impl! {
+    /// IEEE-754 32-bit decimal floating point type, with 7 digits of precision.
+    decimal32 u32, 32, 7, 8, 101 ;
+    /// IEEE-754 64-bit decimal floating point type, with 16 digits of precision.
+    decimal64 u64, 64, 16, 10, 398 ;
+    /// IEEE-754 128-bit decimal floating point type, with 34 digits of precision.
+    decimal128 u128, 128, 34, 14, 6176 ;
+}
@@ -0,0 +1,73 @@
+//! Double-double floats, the unevaluated sum of two f64s.
+//!
+//! A double-double carries about 106 bits of precision as `hi + lo`,
+//! which is used for compensated summation and high-precision geometry.
+//! It is meant as an interchange format, so it is parsed and written,
+//! but does not implement any arithmetic operations.
+//!
+//! A double-double is normalized if `hi` is the sum rounded to the
+//! nearest f64, so `|lo|` is at most half the gap between the floats
+//! adjacent to `hi`. Parsing returns the normalized pair closest to the
+//! value: `hi` is the value rounded to the nearest f64, and `lo` is the
+//! exact residual, rounded to an f64.
+
+#![cfg(feature = "double-double")]
+#![doc(hidden)]
+
+/// The unevaluated sum of two f64s, `hi + lo`.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+pub struct DoubleDouble {
+    /// The high float, with the leading significant bits.
+    hi: f64,
+    /// The low float, with the trailing significant bits.
+    lo: f64,
+}
+
+impl DoubleDouble {
+    /// Create the double-double from the high and low floats, without normalizing.
+    #[inline(always)]
+    pub const fn new(hi: f64, lo: f64) -> Self {
+        Self {
+            hi,
+            lo,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn hi(self) -> f64 {
+        self.hi
+    }
+
+    #[inline(always)]
+    pub const fn lo(self) -> f64 {
+        self.lo
+    }
+
+    /// Get the sum, rounded to the nearest f64.
+    #[inline(always)]
+    pub fn as_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+
+    /// Normalize the pair, so `hi` is the sum rounded to the nearest f64.
+    ///
+    /// This is the exact two-sum algorithm, so the value does not change,
+    /// unless the sum is not finite.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let hi = self.hi + self.lo;
+        if !hi.is_finite() {
+            return Self::new(hi, 0.0);
+        }
+        let b = hi - self.hi;
+        let lo = (self.hi - (hi - b)) + (self.lo - b);
+        Self::new(hi, lo)
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    #[inline(always)]
+    fn from(value: f64) -> Self {
+        Self::new(value, 0.0)
+    }
+}
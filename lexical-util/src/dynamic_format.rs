@@ -0,0 +1,381 @@
+//! Number formats as values, selected at compile time or at runtime.
+//!
+//! The parsers and writers take the number format as a value implementing
+//! [`Format`], so the same code handles the compile-time `FORMAT` packed
+//! struct, wrapped in a [`NumberFormat`], and the runtime [`DynamicFormat`].
+//! With a [`NumberFormat`], every property of the format is a constant
+//! and the unused branches are optimized out.
+
+use crate::error::Error;
+#[cfg(feature = "format")]
+use crate::feature_format::format_error_impl;
+#[cfg(feature = "format")]
+use crate::feature_format::NumberFormat;
+use crate::format_builder::NumberFormatBuilder;
+use crate::format_flags as flags;
+use crate::grouping::DigitGrouping;
+#[cfg(not(feature = "format"))]
+use crate::not_feature_format::{format_error_impl, NumberFormat};
+use crate::result::Result;
+
+/// Get a flag from the packed struct.
+///
+/// Without the `format` feature, the flags are fixed to those of the
+/// standard format, like for [`NumberFormat`].
+macro_rules! format_flag {
+    ($($(#[$meta:meta])* $name:ident => $flag:ident ;)*) => ($(
+        $(#[$meta])*
+        #[inline(always)]
+        fn $name(&self) -> bool {
+            if cfg!(feature = "format") {
+                self.packed() & flags::$flag != 0
+            } else {
+                NumberFormat::<{ crate::format::STANDARD }>::$flag
+            }
+        }
+    )*);
+}
+
+/// Get a mask of flags from the packed struct.
+macro_rules! format_mask {
+    ($($(#[$meta:meta])* $name:ident => $mask:ident ;)*) => ($(
+        $(#[$meta])*
+        #[inline(always)]
+        fn $name(&self) -> u128 {
+            self.packed() & flags::$mask
+        }
+    )*);
+}
+
+/// Number format, as a compile-time or runtime value.
+///
+/// All properties of the format are computed from the packed struct,
+/// so implementations only need to provide [`packed`](Format::packed).
+pub trait Format: Copy {
+    /// Get the packed struct.
+    fn packed(&self) -> u128;
+
+    // VALIDATION
+
+    /// Determine if the number format is valid.
+    #[inline(always)]
+    fn is_valid(&self) -> bool {
+        self.error().is_success()
+    }
+
+    /// Get the error type from the format.
+    #[inline(always)]
+    fn error(&self) -> Error {
+        format_error_impl(self.packed())
+    }
+
+    // FLAGS
+
+    format_flag! {
+        /// Get if digits are required before the decimal point.
+        required_integer_digits => REQUIRED_INTEGER_DIGITS;
+        /// Get if digits are required after the decimal point.
+        required_fraction_digits => REQUIRED_FRACTION_DIGITS;
+        /// Get if digits are required after the exponent character.
+        required_exponent_digits => REQUIRED_EXPONENT_DIGITS;
+        /// Get if significant digits are required.
+        required_mantissa_digits => REQUIRED_MANTISSA_DIGITS;
+        /// Get if at least 1 digit in the number is required.
+        required_digits => REQUIRED_DIGITS;
+        /// Get if a positive sign before the mantissa is not allowed.
+        no_positive_mantissa_sign => NO_POSITIVE_MANTISSA_SIGN;
+        /// Get if a sign symbol before the mantissa is required.
+        required_mantissa_sign => REQUIRED_MANTISSA_SIGN;
+        /// Get if exponent notation is not allowed.
+        no_exponent_notation => NO_EXPONENT_NOTATION;
+        /// Get if a positive sign before the exponent is not allowed.
+        no_positive_exponent_sign => NO_POSITIVE_EXPONENT_SIGN;
+        /// Get if a sign symbol before the exponent is required.
+        required_exponent_sign => REQUIRED_EXPONENT_SIGN;
+        /// Get if an exponent without fraction is not allowed.
+        no_exponent_without_fraction => NO_EXPONENT_WITHOUT_FRACTION;
+        /// Get if special (non-finite) values are not allowed.
+        no_special => NO_SPECIAL;
+        /// Get if special (non-finite) values are case-sensitive.
+        case_sensitive_special => CASE_SENSITIVE_SPECIAL;
+        /// Get if leading zeros before an integer are not allowed.
+        no_integer_leading_zeros => NO_INTEGER_LEADING_ZEROS;
+        /// Get if leading zeros before a float are not allowed.
+        no_float_leading_zeros => NO_FLOAT_LEADING_ZEROS;
+        /// Get if exponent notation is required.
+        required_exponent_notation => REQUIRED_EXPONENT_NOTATION;
+        /// Get if exponent characters are case-sensitive.
+        case_sensitive_exponent => CASE_SENSITIVE_EXPONENT;
+        /// Get if base prefixes are case-sensitive.
+        case_sensitive_base_prefix => CASE_SENSITIVE_BASE_PREFIX;
+        /// Get if base suffixes are case-sensitive.
+        case_sensitive_base_suffix => CASE_SENSITIVE_BASE_SUFFIX;
+        /// Get if non-ASCII Unicode decimal digits are allowed.
+        unicode_digits => UNICODE_DIGITS;
+        /// Get if whitespace before the number is skipped.
+        leading_whitespace => LEADING_WHITESPACE;
+        /// Get if whitespace after the number is skipped.
+        trailing_whitespace => TRAILING_WHITESPACE;
+        /// Get if skipped whitespace includes all Unicode whitespace.
+        unicode_whitespace => UNICODE_WHITESPACE;
+        /// Get if digit separators are allowed between integer digits.
+        integer_internal_digit_separator => INTEGER_INTERNAL_DIGIT_SEPARATOR;
+        /// Get if digit separators are allowed between fraction digits.
+        fraction_internal_digit_separator => FRACTION_INTERNAL_DIGIT_SEPARATOR;
+        /// Get if digit separators are allowed between exponent digits.
+        exponent_internal_digit_separator => EXPONENT_INTERNAL_DIGIT_SEPARATOR;
+        /// Get if digit separators are allowed between digits.
+        internal_digit_separator => INTERNAL_DIGIT_SEPARATOR;
+        /// Get if a digit separator is allowed before any integer digits.
+        integer_leading_digit_separator => INTEGER_LEADING_DIGIT_SEPARATOR;
+        /// Get if a digit separator is allowed before any fraction digits.
+        fraction_leading_digit_separator => FRACTION_LEADING_DIGIT_SEPARATOR;
+        /// Get if a digit separator is allowed before any exponent digits.
+        exponent_leading_digit_separator => EXPONENT_LEADING_DIGIT_SEPARATOR;
+        /// Get if a digit separator is allowed before any digits.
+        leading_digit_separator => LEADING_DIGIT_SEPARATOR;
+        /// Get if a digit separator is allowed after any integer digits.
+        integer_trailing_digit_separator => INTEGER_TRAILING_DIGIT_SEPARATOR;
+        /// Get if a digit separator is allowed after any fraction digits.
+        fraction_trailing_digit_separator => FRACTION_TRAILING_DIGIT_SEPARATOR;
+        /// Get if a digit separator is allowed after any exponent digits.
+        exponent_trailing_digit_separator => EXPONENT_TRAILING_DIGIT_SEPARATOR;
+        /// Get if a digit separator is allowed after any digits.
+        trailing_digit_separator => TRAILING_DIGIT_SEPARATOR;
+        /// Get if multiple consecutive integer digit separators are allowed.
+        integer_consecutive_digit_separator => INTEGER_CONSECUTIVE_DIGIT_SEPARATOR;
+        /// Get if multiple consecutive fraction digit separators are allowed.
+        fraction_consecutive_digit_separator => FRACTION_CONSECUTIVE_DIGIT_SEPARATOR;
+        /// Get if multiple consecutive exponent digit separators are allowed.
+        exponent_consecutive_digit_separator => EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR;
+        /// Get if multiple consecutive digit separators are allowed.
+        consecutive_digit_separator => CONSECUTIVE_DIGIT_SEPARATOR;
+        /// Get if any digit separators are allowed in special (non-finite) values.
+        special_digit_separator => SPECIAL_DIGIT_SEPARATOR;
+    }
+
+    /// Get the grouping of the integer digits.
+    #[inline(always)]
+    fn digit_grouping(&self) -> DigitGrouping {
+        if cfg!(feature = "format") {
+            DigitGrouping::from_format(self.packed())
+        } else {
+            DigitGrouping::None
+        }
+    }
+
+    // CHARACTERS
+
+    /// Get the character for the digit separator.
+    #[inline(always)]
+    fn digit_separator(&self) -> u8 {
+        if cfg!(feature = "format") {
+            flags::digit_separator(self.packed())
+        } else {
+            0
+        }
+    }
+
+    /// Get the character for the base prefix.
+    #[inline(always)]
+    fn base_prefix(&self) -> u8 {
+        if cfg!(feature = "format") {
+            flags::base_prefix(self.packed())
+        } else {
+            0
+        }
+    }
+
+    /// Get the character for the base suffix.
+    #[inline(always)]
+    fn base_suffix(&self) -> u8 {
+        if cfg!(feature = "format") {
+            flags::base_suffix(self.packed())
+        } else {
+            0
+        }
+    }
+
+    // RADIX
+
+    /// Get the radix for the mantissa digits.
+    #[inline(always)]
+    fn mantissa_radix(&self) -> u32 {
+        flags::mantissa_radix(self.packed())
+    }
+
+    /// Get the radix for the significant digits.
+    #[inline(always)]
+    fn radix(&self) -> u32 {
+        self.mantissa_radix()
+    }
+
+    /// Get the base for the exponent.
+    #[inline(always)]
+    fn exponent_base(&self) -> u32 {
+        flags::exponent_base(self.packed())
+    }
+
+    /// Get the radix for the exponent digits.
+    #[inline(always)]
+    fn exponent_radix(&self) -> u32 {
+        flags::exponent_radix(self.packed())
+    }
+
+    // MASKS
+
+    format_mask! {
+        /// Get the flags from the number format.
+        flags => FLAG_MASK;
+        /// Get the interface flags from the number format.
+        interface_flags => INTERFACE_FLAG_MASK;
+        /// Get the digit separator flags from the number format.
+        digit_separator_flags => DIGIT_SEPARATOR_FLAG_MASK;
+        /// Get the exponent flags from the number format.
+        exponent_flags => EXPONENT_FLAG_MASK;
+        /// Get the integer digit separator flags from the number format.
+        integer_digit_separator_flags => INTEGER_DIGIT_SEPARATOR_FLAG_MASK;
+        /// Get the fraction digit separator flags from the number format.
+        fraction_digit_separator_flags => FRACTION_DIGIT_SEPARATOR_FLAG_MASK;
+        /// Get the exponent digit separator flags from the number format.
+        exponent_digit_separator_flags => EXPONENT_DIGIT_SEPARATOR_FLAG_MASK;
+    }
+}
+
+impl<const FORMAT: u128> Format for NumberFormat<FORMAT> {
+    #[inline(always)]
+    fn packed(&self) -> u128 {
+        FORMAT
+    }
+}
+
+/// Number format packed struct validated at runtime.
+///
+/// This is the runtime equivalent of the `FORMAT` packed struct, for
+/// number grammars that are not known at compile time, such as formats
+/// read from configuration files. Any valid format may be used, and
+/// parsing and writing with it behaves identically to using the packed
+/// struct as a `const FORMAT: u128` parameter, at some cost in speed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynamicFormat {
+    format: u128,
+}
+
+impl DynamicFormat {
+    // CONSTRUCTORS
+
+    /// Create a runtime format from the packed struct.
+    ///
+    /// Returns an error if the format is invalid.
+    pub fn new(format: u128) -> Result<Self> {
+        let error = format_error_impl(format);
+        if error.is_success() {
+            Ok(Self {
+                format: canonical_format(format),
+            })
+        } else {
+            Err(error)
+        }
+    }
+
+    // GETTERS
+
+    /// Get the packed struct.
+    ///
+    /// The exponent base and radix are only stored if they differ
+    /// from the mantissa radix.
+    #[inline(always)]
+    pub const fn packed(&self) -> u128 {
+        self.format
+    }
+
+    /// Get the radix for the mantissa digits.
+    #[inline(always)]
+    pub const fn mantissa_radix(&self) -> u32 {
+        flags::mantissa_radix(self.format)
+    }
+
+    /// Get the radix for the significant digits.
+    #[inline(always)]
+    pub const fn radix(&self) -> u32 {
+        self.mantissa_radix()
+    }
+
+    /// Get the base for the exponent.
+    #[inline(always)]
+    pub const fn exponent_base(&self) -> u32 {
+        flags::exponent_base(self.format)
+    }
+
+    /// Get the radix for the exponent digits.
+    #[inline(always)]
+    pub const fn exponent_radix(&self) -> u32 {
+        flags::exponent_radix(self.format)
+    }
+
+    /// Get the character for the digit separator.
+    #[inline(always)]
+    pub const fn digit_separator(&self) -> u8 {
+        flags::digit_separator(self.format)
+    }
+
+    /// Get the character for the base prefix.
+    #[inline(always)]
+    pub const fn base_prefix(&self) -> u8 {
+        flags::base_prefix(self.format)
+    }
+
+    /// Get the character for the base suffix.
+    #[inline(always)]
+    pub const fn base_suffix(&self) -> u8 {
+        flags::base_suffix(self.format)
+    }
+
+    /// Get the flags from the number format.
+    #[inline(always)]
+    pub const fn flags(&self) -> u128 {
+        self.format & flags::FLAG_MASK
+    }
+
+    // BUILDER
+
+    /// Get the number format builder from the format.
+    #[inline]
+    pub const fn rebuild(&self) -> NumberFormatBuilder {
+        NumberFormatBuilder::rebuild(self.format)
+    }
+}
+
+impl Format for DynamicFormat {
+    #[inline(always)]
+    fn packed(&self) -> u128 {
+        self.format
+    }
+}
+
+impl Default for DynamicFormat {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            format: canonical_format(crate::format::STANDARD),
+        }
+    }
+}
+
+/// Remove the redundant exponent base and radix from the packed struct.
+///
+/// An exponent base or radix of 0 defaults to the mantissa radix, so
+/// these are cleared if they are identical to the mantissa radix, giving
+/// a single representation for equivalent formats.
+#[inline]
+const fn canonical_format(format: u128) -> u128 {
+    let mut format = format;
+    let radix = flags::mantissa_radix(format);
+    if flags::exponent_base(format) == radix {
+        format &= !flags::EXPONENT_BASE;
+    }
+    if flags::exponent_radix(format) == radix {
+        format &= !flags::EXPONENT_RADIX;
+    }
+    format
+}
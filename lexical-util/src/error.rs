@@ -51,6 +51,12 @@ pub enum Error {
     InvalidPositiveSign(usize),
     /// Invalid negative sign for an unsigned type was found.
     InvalidNegativeSign(usize),
+    /// Number in exponent or fraction notation was not an integer.
+    NonIntegral(usize),
+    /// Found zero when parsing a non-zero integer type.
+    Zero(usize),
+    /// Integer digits did not form groups of the digit grouping.
+    InvalidDigitGroup(usize),
 
     // NUMBER FORMAT ERRORS
     /// Invalid radix for the mantissa (significant) digits.
@@ -73,6 +79,8 @@ pub enum Error {
     InvalidPunctuation,
     /// Optional exponent flags were set while disabling exponent notation.
     InvalidExponentFlags,
+    /// Unicode whitespace was set without leading or trailing whitespace.
+    InvalidWhitespaceFlags,
     /// Set no positive mantissa sign while requiring mantissa signs.
     InvalidMantissaSign,
     /// Set no positive exponent sign while requiring exponent signs.
@@ -85,6 +93,8 @@ pub enum Error {
     InvalidConsecutiveFractionDigitSeparator,
     /// Invalid consecutive exponent digit separator.
     InvalidConsecutiveExponentDigitSeparator,
+    /// Digit grouping was set without integer internal digit separators.
+    InvalidDigitGrouping,
     /// Invalid flags were set without the format feature.
     InvalidFlags,
 
@@ -103,6 +113,12 @@ pub enum Error {
     InfinityStringTooLong,
     /// Long infinity string is too short: it must be as long as short infinity.
     InfinityStringTooShort,
+    /// Invalid imaginary unit: must only contain letters, and not start with the exponent.
+    InvalidImaginaryUnit,
+    /// Imaginary unit is too long.
+    ImaginaryUnitTooLong,
+    /// Invalid alternative punctuation: must be a single character, and not overlap.
+    InvalidAlternativePunctuation,
     /// Invalid float parsing algorithm.
     InvalidFloatParseAlgorithm,
     /// Invalid radix for the significant digits.
@@ -160,6 +176,9 @@ impl Error {
             Self::MissingSign(index) => Some(index),
             Self::InvalidPositiveSign(index) => Some(index),
             Self::InvalidNegativeSign(index) => Some(index),
+            Self::NonIntegral(index) => Some(index),
+            Self::Zero(index) => Some(index),
+            Self::InvalidDigitGroup(index) => Some(index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => None,
@@ -172,12 +191,14 @@ impl Error {
             Self::InvalidBaseSuffix => None,
             Self::InvalidPunctuation => None,
             Self::InvalidExponentFlags => None,
+            Self::InvalidWhitespaceFlags => None,
             Self::InvalidMantissaSign => None,
             Self::InvalidExponentSign => None,
             Self::InvalidSpecial => None,
             Self::InvalidConsecutiveIntegerDigitSeparator => None,
             Self::InvalidConsecutiveFractionDigitSeparator => None,
             Self::InvalidConsecutiveExponentDigitSeparator => None,
+            Self::InvalidDigitGrouping => None,
             Self::InvalidFlags => None,
 
             // OPTION ERRORS
@@ -188,6 +209,9 @@ impl Error {
             Self::InvalidInfinityString => None,
             Self::InfinityStringTooLong => None,
             Self::InfinityStringTooShort => None,
+            Self::InvalidImaginaryUnit => None,
+            Self::ImaginaryUnitTooLong => None,
+            Self::InvalidAlternativePunctuation => None,
             Self::InvalidFloatParseAlgorithm => None,
             Self::InvalidRadix => None,
             Self::InvalidFloatPrecision => None,
@@ -199,6 +223,38 @@ impl Error {
         }
     }
 
+    /// Get a mutable reference to the index for the parsing error.
+    pub fn index_mut(&mut self) -> Option<&mut usize> {
+        match self {
+            // PARSE ERRORS
+            Self::Overflow(index) => Some(index),
+            Self::Underflow(index) => Some(index),
+            Self::InvalidDigit(index) => Some(index),
+            Self::Empty(index) => Some(index),
+            Self::EmptyMantissa(index) => Some(index),
+            Self::EmptyExponent(index) => Some(index),
+            Self::EmptyInteger(index) => Some(index),
+            Self::EmptyFraction(index) => Some(index),
+            Self::InvalidPositiveMantissaSign(index) => Some(index),
+            Self::MissingMantissaSign(index) => Some(index),
+            Self::InvalidExponent(index) => Some(index),
+            Self::InvalidPositiveExponentSign(index) => Some(index),
+            Self::MissingExponentSign(index) => Some(index),
+            Self::ExponentWithoutFraction(index) => Some(index),
+            Self::InvalidLeadingZeros(index) => Some(index),
+            Self::MissingExponent(index) => Some(index),
+            Self::MissingSign(index) => Some(index),
+            Self::InvalidPositiveSign(index) => Some(index),
+            Self::InvalidNegativeSign(index) => Some(index),
+            Self::NonIntegral(index) => Some(index),
+            Self::Zero(index) => Some(index),
+            Self::InvalidDigitGroup(index) => Some(index),
+
+            // NUMBER FORMAT, OPTION ERRORS, AND NOT AN ERROR
+            _ => None,
+        }
+    }
+
     is_error_type!(is_overflow, Overflow(_));
     is_error_type!(is_underflow, Underflow(_));
     is_error_type!(is_invalid_digit, InvalidDigit(_));
@@ -218,6 +274,9 @@ impl Error {
     is_error_type!(is_missing_sign, MissingSign(_));
     is_error_type!(is_invalid_positive_sign, InvalidPositiveSign(_));
     is_error_type!(is_invalid_negative_sign, InvalidNegativeSign(_));
+    is_error_type!(is_non_integral, NonIntegral(_));
+    is_error_type!(is_zero, Zero(_));
+    is_error_type!(is_invalid_digit_group, InvalidDigitGroup(_));
     is_error_type!(is_invalid_mantissa_radix, InvalidMantissaRadix);
     is_error_type!(is_invalid_exponent_base, InvalidExponentBase);
     is_error_type!(is_invalid_exponent_radix, InvalidExponentRadix);
@@ -228,6 +287,7 @@ impl Error {
     is_error_type!(is_invalid_base_suffix, InvalidBaseSuffix);
     is_error_type!(is_invalid_punctuation, InvalidPunctuation);
     is_error_type!(is_invalid_exponent_flags, InvalidExponentFlags);
+    is_error_type!(is_invalid_whitespace_flags, InvalidWhitespaceFlags);
     is_error_type!(is_invalid_mantissa_sign, InvalidMantissaSign);
     is_error_type!(is_invalid_exponent_sign, InvalidExponentSign);
     is_error_type!(is_invalid_special, InvalidSpecial);
@@ -243,6 +303,7 @@ impl Error {
         is_invalid_consecutive_exponent_digit_separator,
         InvalidConsecutiveExponentDigitSeparator
     );
+    is_error_type!(is_invalid_digit_grouping, InvalidDigitGrouping);
     is_error_type!(is_invalid_flags, InvalidFlags);
     is_error_type!(is_invalid_nan_string, InvalidNanString);
     is_error_type!(is_nan_string_too_long, NanStringTooLong);
@@ -251,6 +312,9 @@ impl Error {
     is_error_type!(is_invalid_infinity_string, InvalidInfinityString);
     is_error_type!(is_infinity_string_too_long, InfinityStringTooLong);
     is_error_type!(is_infinity_string_too_short, InfinityStringTooShort);
+    is_error_type!(is_invalid_imaginary_unit, InvalidImaginaryUnit);
+    is_error_type!(is_imaginary_unit_too_long, ImaginaryUnitTooLong);
+    is_error_type!(is_invalid_alternative_punctuation, InvalidAlternativePunctuation);
     is_error_type!(is_invalid_float_parse_algorithm, InvalidFloatParseAlgorithm);
     is_error_type!(is_invalid_radix, InvalidRadix);
     is_error_type!(is_invalid_float_precision, InvalidFloatPrecision);
@@ -303,6 +367,9 @@ impl fmt::Display for Error {
             Self::MissingSign(index) => write_parse_error!(formatter, "'missing required `+/-` sign for integer'", index),
             Self::InvalidPositiveSign(index) => write_parse_error!(formatter, "'invalid `+` sign for an integer was found'", index),
             Self::InvalidNegativeSign(index) => write_parse_error!(formatter, "'invalid `-` sign for an unsigned type was found'", index),
+            Self::NonIntegral(index) => write_parse_error!(formatter, "'number with a fraction found for an integer'", index),
+            Self::Zero(index) => write_parse_error!(formatter, "'zero found for a non-zero integer type'", index),
+            Self::InvalidDigitGroup(index) => write_parse_error!(formatter, "'integer digits do not match the digit grouping'", index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => format_message!(formatter, "'invalid radix for mantissa digits'"),
@@ -315,12 +382,14 @@ impl fmt::Display for Error {
             Self::InvalidBaseSuffix => format_message!(formatter, "'invalid base suffix character'"),
             Self::InvalidPunctuation => format_message!(formatter, "'invalid punctuation: multiple characters overlap'"),
             Self::InvalidExponentFlags => format_message!(formatter, "'exponent flags set while disabling exponent notation'"),
+            Self::InvalidWhitespaceFlags => format_message!(formatter, "'enabled Unicode whitespace without leading or trailing whitespace'"),
             Self::InvalidMantissaSign => format_message!(formatter, "'disabled the `+` sign while requiring a sign for significant digits'"),
             Self::InvalidExponentSign => format_message!(formatter, "'disabled the `+` sign while requiring a sign for exponent digits'"),
             Self::InvalidSpecial => format_message!(formatter, "'special flags set while disabling special floats'"),
             Self::InvalidConsecutiveIntegerDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the integer without setting a valid location'"),
             Self::InvalidConsecutiveFractionDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the fraction without setting a valid location'"),
             Self::InvalidConsecutiveExponentDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the exponent without setting a valid location'"),
+            Self::InvalidDigitGrouping => format_message!(formatter, "'enabled digit grouping without integer internal digit separators'"),
             Self::InvalidFlags => format_message!(formatter, "'invalid flags enabled without the format feature'"),
 
             // OPTION ERRORS
@@ -331,6 +400,9 @@ impl fmt::Display for Error {
             Self::InvalidInfinityString => options_message!(formatter, "'long infinity string must started with `i`'"),
             Self::InfinityStringTooLong => options_message!(formatter, "'long infinity string is too long'"),
             Self::InfinityStringTooShort => options_message!(formatter, "'long infinity string is too short'"),
+            Self::InvalidImaginaryUnit => options_message!(formatter, "'imaginary unit must only contain letters, and not start with the exponent'"),
+            Self::ImaginaryUnitTooLong => options_message!(formatter, "'imaginary unit is too long'"),
+            Self::InvalidAlternativePunctuation => options_message!(formatter, "'alternative punctuation must be a single character, and not overlap other punctuation'"),
             Self::InvalidFloatParseAlgorithm => options_message!(formatter, "'invalid combination of float parse algorithms'"),
             Self::InvalidRadix => options_message!(formatter, "'invalid radix for significant digits'"),
             Self::InvalidFloatPrecision => options_message!(formatter, "'invalid float precision: min digits is larger than max digits'"),
@@ -0,0 +1,264 @@
+//! Quadruple-precision IEEE-754 floating point implementation.
+//!
+//! f128 is meant as an interchange format: it is parsed and written
+//! exactly, but the arithmetic operations and the formatting traits
+//! convert the value to f64, so they have rounding error. Since there
+//! are no native operations using `f128`, this is of minimal concern.
+//!
+//! Comparisons are exact, and use the IEEE-754 semantics.
+
+#![cfg(feature = "f128")]
+#![doc(hidden)]
+
+use crate::num::Float;
+use core::cmp::Ordering;
+use core::{fmt, ops};
+
+/// Quadruple-precision IEEE-754 floating point type.
+#[allow(non_camel_case_types)]
+#[derive(Default, Copy, Clone)]
+pub struct f128 {
+    /// Raw bitwise representation of the float as a 128-bit type.
+    bits: u128,
+}
+
+unsafe impl Send for f128 {
+}
+unsafe impl Sync for f128 {
+}
+
+impl f128 {
+    #[inline(always)]
+    pub const fn to_bits(self) -> u128 {
+        self.bits
+    }
+
+    #[inline(always)]
+    pub const fn from_bits(bits: u128) -> Self {
+        Self {
+            bits,
+        }
+    }
+
+    #[inline(always)]
+    pub fn as_f64(self) -> f64 {
+        f128_to_f64(self)
+    }
+
+    #[inline(always)]
+    pub fn from_f64(value: f64) -> Self {
+        f64_to_f128(value)
+    }
+
+    /// Get the bits ordered like the values, if the float is not NaN.
+    #[inline(always)]
+    fn ordered_bits(self) -> i128 {
+        let magnitude = (self.bits & !f128::SIGN_MASK) as i128;
+        if self.is_sign_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl PartialEq for f128 {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        !self.is_nan() && !other.is_nan() && self.ordered_bits() == other.ordered_bits()
+    }
+}
+
+impl PartialOrd for f128 {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else {
+            Some(self.ordered_bits().cmp(&other.ordered_bits()))
+        }
+    }
+}
+
+impl fmt::Debug for f128 {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_f64().fmt(formatter)
+    }
+}
+
+impl fmt::Display for f128 {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_f64().fmt(formatter)
+    }
+}
+
+impl ops::Add for f128 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_f64(self.as_f64() + rhs.as_f64())
+    }
+}
+
+impl ops::Div for f128 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::from_f64(self.as_f64() / rhs.as_f64())
+    }
+}
+
+impl ops::Mul for f128 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_f64(self.as_f64() * rhs.as_f64())
+    }
+}
+
+impl ops::Sub for f128 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_f64(self.as_f64() - rhs.as_f64())
+    }
+}
+
+impl ops::Rem for f128 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self::from_f64(self.as_f64() % rhs.as_f64())
+    }
+}
+
+impl ops::Neg for f128 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self::from_bits(self.bits ^ (1 << 127))
+    }
+}
+
+impl ops::AddAssign for f128 {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::DivAssign for f128 {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl ops::MulAssign for f128 {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::SubAssign for f128 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl ops::RemAssign for f128 {
+    #[inline(always)]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+// Every f64 is exactly representable as an f128, including subnormals,
+// so widening only needs to rebias the exponent. Narrowing rounds to
+// nearest, with ties to even.
+
+fn f128_to_f64(quad: f128) -> f64 {
+    let sign = ((quad.bits & f128::SIGN_MASK) >> 64) as u64;
+    if quad.is_nan() {
+        let man = (quad.bits & f128::MANTISSA_MASK) >> (f128::MANTISSA_SIZE - f64::MANTISSA_SIZE);
+        return f64::from_bits(sign | f64::INFINITY_BITS | (1 << 51) | man as u64);
+    } else if quad.is_inf() {
+        return f64::from_bits(sign | f64::INFINITY_BITS);
+    }
+
+    // Get the value as `man * 2^exp`, and the exponent of the most-significant bit.
+    let man = quad.mantissa();
+    if man == 0 {
+        return f64::from_bits(sign);
+    }
+    let exp = quad.exponent();
+    let top = exp + 127 - man.leading_zeros() as i32;
+    if top >= f64::MAX_EXPONENT + f64::MANTISSA_SIZE {
+        return f64::from_bits(sign | f64::INFINITY_BITS);
+    }
+
+    // Shift to the precision of the result, and round the truncated bits.
+    let ulp = (top - f64::MANTISSA_SIZE).max(f64::DENORMAL_EXPONENT);
+    let shift = (ulp - exp) as u32;
+    if shift > 128 - man.leading_zeros() {
+        // The value is below half the smallest subnormal.
+        return f64::from_bits(sign);
+    }
+    let mut result = (man >> shift) as u64;
+    if shift != 0 {
+        let halfway = 1_u128 << (shift - 1);
+        let truncated = man & ((halfway << 1).wrapping_sub(1));
+        if truncated > halfway || (truncated == halfway && result & 1 != 0) {
+            result += 1;
+        }
+    }
+
+    // Rebias the exponent, where rounding may carry into the next exponent.
+    let mut ulp = ulp;
+    if result & f64::CARRY_MASK != 0 {
+        result >>= 1;
+        ulp += 1;
+    }
+    if ulp > f64::MAX_EXPONENT - 1 {
+        return f64::from_bits(sign | f64::INFINITY_BITS);
+    }
+    let biased = match result & f64::HIDDEN_BIT_MASK {
+        0 => 0,
+        _ => (ulp - f64::DENORMAL_EXPONENT + 1) as u64,
+    };
+    f64::from_bits(sign | (biased << f64::MANTISSA_SIZE) | (result & f64::MANTISSA_MASK))
+}
+
+fn f64_to_f128(value: f64) -> f128 {
+    let man_shift = f128::MANTISSA_SIZE - f64::MANTISSA_SIZE;
+    let sign = ((value.to_bits() & f64::SIGN_MASK) as u128) << 64;
+    if value.is_nan() {
+        let man = (value.to_bits() & f64::MANTISSA_MASK) as u128;
+        return f128::from_bits(sign | f128::INFINITY_BITS | (1 << 111) | (man << man_shift));
+    } else if value.is_inf() {
+        return f128::from_bits(sign | f128::INFINITY_BITS);
+    }
+
+    let man = value.mantissa();
+    if man == 0 {
+        return f128::from_bits(sign);
+    }
+
+    // Normalize the mantissa, including subnormals, so the hidden bit is set.
+    let shift = man.leading_zeros() - (63 - f64::MANTISSA_SIZE as u32);
+    let man = (man << shift) as u128;
+    let exp = value.exponent() - shift as i32 - man_shift;
+    let biased = (exp - f128::DENORMAL_EXPONENT + 1) as u128;
+    let man = (man << man_shift) & f128::MANTISSA_MASK;
+    f128::from_bits(sign | (biased << f128::MANTISSA_SIZE) | man)
+}
@@ -252,7 +252,7 @@ fn f32_to_f16(value: f32) -> f16 {
     let half_exp = unbiased_exp + f16_bias;
 
     // Check for exponent overflow, return +infinity
-    if unbiased_exp >= 0x1F {
+    if half_exp >= 0x1F {
         return f16::from_bits(half_sign as u16 | f16::INFINITY_BITS);
     }
 
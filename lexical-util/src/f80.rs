@@ -0,0 +1,295 @@
+//! x87 80-bit extended-precision floating point implementation.
+//!
+//! f80 is meant as an interchange format, for the `long double` type used
+//! by C on x86: it is parsed and written exactly, but the arithmetic
+//! operations and the formatting traits convert the value to f64, so they
+//! have rounding error. Since there are no native operations using `f80`,
+//! this is of minimal concern.
+//!
+//! Unlike the IEEE-754 formats, the integer bit of the significand is
+//! explicit, so the 80 bits are a sign bit, a 15-bit exponent, and a
+//! 64-bit significand. The significand of non-canonical encodings, such
+//! as pseudo-denormals, is used as-is, so they have the same value as on
+//! the x87 FPU. Comparisons are exact, and use the IEEE-754 semantics.
+
+#![cfg(feature = "f80")]
+#![doc(hidden)]
+
+use crate::num::Float;
+use core::cmp::Ordering;
+use core::{fmt, ops};
+
+/// x87 80-bit extended-precision floating point type.
+#[allow(non_camel_case_types)]
+#[derive(Default, Copy, Clone)]
+pub struct f80 {
+    /// Raw bitwise representation of the float, in the low 80 bits.
+    bits: u128,
+}
+
+unsafe impl Send for f80 {
+}
+unsafe impl Sync for f80 {
+}
+
+impl f80 {
+    /// Mask for the 80 bits of the float.
+    const BITS_MASK: u128 = (1 << 80) - 1;
+
+    #[inline(always)]
+    pub const fn to_bits(self) -> u128 {
+        self.bits
+    }
+
+    /// Create the float from the low 80 bits, ignoring any higher bits.
+    #[inline(always)]
+    pub const fn from_bits(bits: u128) -> Self {
+        Self {
+            bits: bits & Self::BITS_MASK,
+        }
+    }
+
+    /// Get the float as 10 little-endian bytes, as stored in memory on x86.
+    #[inline(always)]
+    pub fn to_le_bytes(self) -> [u8; 10] {
+        let mut bytes = [0; 10];
+        bytes.copy_from_slice(&self.bits.to_le_bytes()[..10]);
+        bytes
+    }
+
+    /// Create the float from 10 little-endian bytes, as stored in memory on x86.
+    #[inline(always)]
+    pub fn from_le_bytes(bytes: [u8; 10]) -> Self {
+        let mut buffer = [0; 16];
+        buffer[..10].copy_from_slice(&bytes);
+        Self::from_bits(u128::from_le_bytes(buffer))
+    }
+
+    #[inline(always)]
+    pub fn as_f64(self) -> f64 {
+        f80_to_f64(self)
+    }
+
+    #[inline(always)]
+    pub fn from_f64(value: f64) -> Self {
+        f64_to_f80(value)
+    }
+
+    /// Get the bits ordered like the values, if the float is not NaN.
+    #[inline(always)]
+    fn ordered_bits(self) -> i128 {
+        let mut magnitude = (self.bits & !f80::SIGN_MASK) as i128;
+        if self.is_denormal() && self.bits & f80::HIDDEN_BIT_MASK != 0 {
+            // Pseudo-denormals have the value of the smallest exponent.
+            magnitude += f80::CARRY_MASK as i128;
+        }
+        if self.is_sign_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl PartialEq for f80 {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        !self.is_nan() && !other.is_nan() && self.ordered_bits() == other.ordered_bits()
+    }
+}
+
+impl PartialOrd for f80 {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else {
+            Some(self.ordered_bits().cmp(&other.ordered_bits()))
+        }
+    }
+}
+
+impl fmt::Debug for f80 {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_f64().fmt(formatter)
+    }
+}
+
+impl fmt::Display for f80 {
+    #[inline(always)]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_f64().fmt(formatter)
+    }
+}
+
+impl ops::Add for f80 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_f64(self.as_f64() + rhs.as_f64())
+    }
+}
+
+impl ops::Div for f80 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::from_f64(self.as_f64() / rhs.as_f64())
+    }
+}
+
+impl ops::Mul for f80 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_f64(self.as_f64() * rhs.as_f64())
+    }
+}
+
+impl ops::Sub for f80 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_f64(self.as_f64() - rhs.as_f64())
+    }
+}
+
+impl ops::Rem for f80 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self::from_f64(self.as_f64() % rhs.as_f64())
+    }
+}
+
+impl ops::Neg for f80 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self::from_bits(self.bits ^ f80::SIGN_MASK)
+    }
+}
+
+impl ops::AddAssign for f80 {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::DivAssign for f80 {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl ops::MulAssign for f80 {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::SubAssign for f80 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl ops::RemAssign for f80 {
+    #[inline(always)]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+// Every f64 is exactly representable as a normal f80, so widening only
+// needs to normalize the significand and rebias the exponent. Narrowing
+// rounds to nearest, with ties to even.
+
+fn f80_to_f64(x: f80) -> f64 {
+    let sign = ((x.bits & f80::SIGN_MASK) >> 16) as u64;
+    if x.is_nan() {
+        let man = (x.bits & f80::MANTISSA_MASK) >> (f80::MANTISSA_SIZE - f64::MANTISSA_SIZE);
+        return f64::from_bits(sign | f64::INFINITY_BITS | (1 << 51) | man as u64);
+    } else if x.is_inf() {
+        return f64::from_bits(sign | f64::INFINITY_BITS);
+    }
+
+    // Get the value as `man * 2^exp`, and the exponent of the most-significant bit.
+    let man = x.mantissa();
+    if man == 0 {
+        return f64::from_bits(sign);
+    }
+    let exp = x.exponent();
+    let top = exp + 127 - man.leading_zeros() as i32;
+    if top >= f64::MAX_EXPONENT + f64::MANTISSA_SIZE {
+        return f64::from_bits(sign | f64::INFINITY_BITS);
+    }
+
+    // Shift to the precision of the result, and round the truncated bits.
+    // The significand may have leading zeros, so it may need to be shifted left.
+    let ulp = (top - f64::MANTISSA_SIZE).max(f64::DENORMAL_EXPONENT);
+    let mut result = if ulp <= exp {
+        (man << (exp - ulp)) as u64
+    } else {
+        let shift = (ulp - exp) as u32;
+        if shift > 128 - man.leading_zeros() {
+            // The value is below half the smallest subnormal.
+            return f64::from_bits(sign);
+        }
+        let mut result = (man >> shift) as u64;
+        let halfway = 1_u128 << (shift - 1);
+        let truncated = man & ((halfway << 1).wrapping_sub(1));
+        if truncated > halfway || (truncated == halfway && result & 1 != 0) {
+            result += 1;
+        }
+        result
+    };
+
+    // Rebias the exponent, where rounding may carry into the next exponent.
+    let mut ulp = ulp;
+    if result & f64::CARRY_MASK != 0 {
+        result >>= 1;
+        ulp += 1;
+    }
+    if ulp > f64::MAX_EXPONENT - 1 {
+        return f64::from_bits(sign | f64::INFINITY_BITS);
+    }
+    let biased = match result & f64::HIDDEN_BIT_MASK {
+        0 => 0,
+        _ => (ulp - f64::DENORMAL_EXPONENT + 1) as u64,
+    };
+    f64::from_bits(sign | (biased << f64::MANTISSA_SIZE) | (result & f64::MANTISSA_MASK))
+}
+
+fn f64_to_f80(value: f64) -> f80 {
+    let man_shift = f80::MANTISSA_SIZE - f64::MANTISSA_SIZE;
+    let sign = ((value.to_bits() & f64::SIGN_MASK) as u128) << 16;
+    if value.is_nan() {
+        let man = (value.to_bits() & f64::MANTISSA_MASK) as u128;
+        return f80::from_bits(sign | f80::INFINITY_BITS | (1 << 62) | (man << man_shift));
+    } else if value.is_inf() {
+        return f80::from_bits(sign | f80::INFINITY_BITS);
+    }
+
+    let man = value.mantissa();
+    if man == 0 {
+        return f80::from_bits(sign);
+    }
+
+    // Normalize the significand, including subnormals, so the integer bit is set.
+    let shift = man.leading_zeros();
+    let exp = value.exponent() - shift as i32;
+    let biased = (exp - f80::DENORMAL_EXPONENT + 1) as u128;
+    f80::from_bits(sign | (biased << 64) | ((man << shift) as u128))
+}
@@ -696,6 +696,7 @@
 use crate::error::Error;
 use crate::format_builder::NumberFormatBuilder;
 use crate::format_flags as flags;
+use crate::grouping::DigitGrouping;
 use core::num;
 
 use static_assertions::const_assert;
@@ -707,11 +708,61 @@ macro_rules! from_flag {
     }};
 }
 
+/// Get the error type from the packed struct.
+///
+/// This is shared by the compile-time and runtime number formats,
+/// so both validate formats identically.
+#[rustfmt::skip]
+#[allow(clippy::if_same_then_else)]
+pub(crate) const fn format_error_impl(format: u128) -> Error {
+    let mantissa_radix = flags::mantissa_radix(format);
+    let exponent_base = flags::exponent_base(format);
+    let exponent_radix = flags::exponent_radix(format);
+    if !flags::is_valid_radix(mantissa_radix) {
+        Error::InvalidMantissaRadix
+    } else if !flags::is_valid_radix(exponent_base) {
+        Error::InvalidExponentBase
+    } else if !flags::is_valid_radix(exponent_radix) {
+        Error::InvalidExponentRadix
+    } else if !flags::is_valid_digit_separator(format) {
+        Error::InvalidDigitSeparator
+    } else if !flags::is_valid_base_prefix(format) {
+        Error::InvalidBasePrefix
+    } else if !flags::is_valid_base_suffix(format) {
+        Error::InvalidBaseSuffix
+    } else if !flags::is_valid_punctuation(format) {
+        Error::InvalidPunctuation
+    } else if !flags::is_valid_exponent_flags(format) {
+        Error::InvalidExponentFlags
+    } else if !flags::is_valid_whitespace_flags(format) {
+        Error::InvalidWhitespaceFlags
+    } else if from_flag!(format, NO_POSITIVE_MANTISSA_SIGN) && from_flag!(format, REQUIRED_MANTISSA_SIGN) {
+        Error::InvalidMantissaSign
+    } else if from_flag!(format, NO_POSITIVE_EXPONENT_SIGN) && from_flag!(format, REQUIRED_EXPONENT_SIGN) {
+        Error::InvalidExponentSign
+    } else if from_flag!(format, NO_SPECIAL) && from_flag!(format, CASE_SENSITIVE_SPECIAL) {
+        Error::InvalidSpecial
+    } else if from_flag!(format, NO_SPECIAL) && from_flag!(format, SPECIAL_DIGIT_SEPARATOR) {
+        Error::InvalidSpecial
+    } else if format & flags::INTEGER_DIGIT_SEPARATOR_FLAG_MASK == flags::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR {
+        Error::InvalidConsecutiveIntegerDigitSeparator
+    } else if format & flags::FRACTION_DIGIT_SEPARATOR_FLAG_MASK == flags::FRACTION_CONSECUTIVE_DIGIT_SEPARATOR {
+        Error::InvalidConsecutiveFractionDigitSeparator
+    } else if format & flags::EXPONENT_DIGIT_SEPARATOR_FLAG_MASK == flags::EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR {
+        Error::InvalidConsecutiveExponentDigitSeparator
+    } else if format & flags::DIGIT_GROUPING != 0 && !from_flag!(format, INTEGER_INTERNAL_DIGIT_SEPARATOR) {
+        Error::InvalidDigitGrouping
+    } else {
+        Error::Success
+    }
+}
+
 /// Wrapper for the 128-bit packed struct.
 ///
 /// See `NumberFormatBuilder` for the `FORMAT` fields
 /// for the packed struct.
 #[doc(hidden)]
+#[derive(Copy, Clone, Debug, Default)]
 pub struct NumberFormat<const FORMAT: u128>;
 
 #[rustfmt::skip]
@@ -731,41 +782,9 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     }
 
     /// Get the error type from the format.
-    #[allow(clippy::if_same_then_else)]
+    #[inline(always)]
     pub const fn error(&self) -> Error {
-        if !flags::is_valid_radix(self.mantissa_radix()) {
-            Error::InvalidMantissaRadix
-        } else if !flags::is_valid_radix(self.exponent_base()) {
-            Error::InvalidExponentBase
-        } else if !flags::is_valid_radix(self.exponent_radix()) {
-            Error::InvalidExponentRadix
-        } else if !flags::is_valid_digit_separator(FORMAT) {
-            Error::InvalidDigitSeparator
-        } else if !flags::is_valid_base_prefix(FORMAT) {
-            Error::InvalidBasePrefix
-        } else if !flags::is_valid_base_suffix(FORMAT) {
-            Error::InvalidBaseSuffix
-        } else if !flags::is_valid_punctuation(FORMAT) {
-            Error::InvalidPunctuation
-        } else if !flags::is_valid_exponent_flags(FORMAT) {
-            Error::InvalidExponentFlags
-        } else if self.no_positive_mantissa_sign() && self.required_mantissa_sign() {
-            Error::InvalidMantissaSign
-        } else if self.no_positive_exponent_sign() && self.required_exponent_sign() {
-            Error::InvalidExponentSign
-        } else if self.no_special() && self.case_sensitive_special() {
-            Error::InvalidSpecial
-        } else if self.no_special() && self.special_digit_separator() {
-            Error::InvalidSpecial
-        } else if self.integer_digit_separator_flags() == flags::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR {
-            Error::InvalidConsecutiveIntegerDigitSeparator
-        } else if self.fraction_digit_separator_flags() == flags::FRACTION_CONSECUTIVE_DIGIT_SEPARATOR {
-            Error::InvalidConsecutiveFractionDigitSeparator
-        } else if self.exponent_digit_separator_flags() == flags::EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR {
-            Error::InvalidConsecutiveExponentDigitSeparator
-        } else {
-            Error::Success
-        }
+        format_error_impl(FORMAT)
     }
 
     // NON-DIGIT SEPARATOR FLAGS & MASKS
@@ -941,6 +960,42 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::CASE_SENSITIVE_BASE_SUFFIX
     }
 
+    /// If non-ASCII decimal digits are allowed.
+    pub const UNICODE_DIGITS: bool = from_flag!(FORMAT, UNICODE_DIGITS);
+
+    /// Get if non-ASCII decimal digits are allowed.
+    #[inline(always)]
+    pub const fn unicode_digits(&self) -> bool {
+        Self::UNICODE_DIGITS
+    }
+
+    /// If whitespace is allowed before the number.
+    pub const LEADING_WHITESPACE: bool = from_flag!(FORMAT, LEADING_WHITESPACE);
+
+    /// Get if whitespace is allowed before the number.
+    #[inline(always)]
+    pub const fn leading_whitespace(&self) -> bool {
+        Self::LEADING_WHITESPACE
+    }
+
+    /// If whitespace is allowed after the number.
+    pub const TRAILING_WHITESPACE: bool = from_flag!(FORMAT, TRAILING_WHITESPACE);
+
+    /// Get if whitespace is allowed after the number.
+    #[inline(always)]
+    pub const fn trailing_whitespace(&self) -> bool {
+        Self::TRAILING_WHITESPACE
+    }
+
+    /// If whitespace includes Unicode `White_Space`.
+    pub const UNICODE_WHITESPACE: bool = from_flag!(FORMAT, UNICODE_WHITESPACE);
+
+    /// Get if whitespace includes Unicode `White_Space`.
+    #[inline(always)]
+    pub const fn unicode_whitespace(&self) -> bool {
+        Self::UNICODE_WHITESPACE
+    }
+
     // DIGIT SEPARATOR FLAGS & MASKS
 
     // If digit separators are allowed between integer digits.
@@ -1096,6 +1151,15 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::SPECIAL_DIGIT_SEPARATOR
     }
 
+    /// The grouping of integer digits between digit separators.
+    pub const DIGIT_GROUPING: DigitGrouping = DigitGrouping::from_format(FORMAT);
+
+    /// Get the grouping of integer digits between digit separators.
+    #[inline(always)]
+    pub const fn digit_grouping(&self) -> DigitGrouping {
+        Self::DIGIT_GROUPING
+    }
+
     // CHARACTERS
 
     /// The digit separator character in the packed struct.
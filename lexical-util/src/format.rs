@@ -248,6 +248,10 @@
 //! - [CASE_SENSITIVE_EXPONENT](crate::format::CASE_SENSITIVE_EXPONENT)
 //! - [CASE_SENSITIVE_BASE_PREFIX](crate::format::CASE_SENSITIVE_BASE_PREFIX)
 //! - [CASE_SENSITIVE_BASE_SUFFIX](crate::format::CASE_SENSITIVE_BASE_SUFFIX)
+//! - [UNICODE_DIGITS](crate::format::UNICODE_DIGITS)
+//! - [LEADING_WHITESPACE](crate::format::LEADING_WHITESPACE)
+//! - [TRAILING_WHITESPACE](crate::format::TRAILING_WHITESPACE)
+//! - [UNICODE_WHITESPACE](crate::format::UNICODE_WHITESPACE)
 //!
 //! # Digit Separator Flags
 //!
@@ -271,6 +275,10 @@
 //! - [TRAILING_DIGIT_SEPARATOR](crate::format::TRAILING_DIGIT_SEPARATOR)
 //! - [CONSECUTIVE_DIGIT_SEPARATOR](crate::format::CONSECUTIVE_DIGIT_SEPARATOR)
 //! - [SPECIAL_DIGIT_SEPARATOR](crate::format::SPECIAL_DIGIT_SEPARATOR)
+//! - [DIGIT_GROUPING](crate::format::DIGIT_GROUPING)
+//! - [THOUSANDS_DIGIT_GROUPING](crate::format::THOUSANDS_DIGIT_GROUPING)
+//! - [INDIAN_DIGIT_GROUPING](crate::format::INDIAN_DIGIT_GROUPING)
+//! - [MYRIAD_DIGIT_GROUPING](crate::format::MYRIAD_DIGIT_GROUPING)
 //!
 //! # Character Shifts and Masks
 //!
@@ -313,7 +321,17 @@
 //! - [is_valid_base_suffix](is_valid_base_suffix)
 //! - [is_valid_punctuation](is_valid_punctuation)
 //! - [is_valid_radix](is_valid_radix)
+//!
+//! # Runtime Formats
+//!
+//! The parsers and writers take the number format as a value implementing
+//! the [Format](crate::format::Format) trait, so number formats may also
+//! be selected at runtime, and validated when created.
+//!
+//! - [Format](crate::format::Format)
+//! - [DynamicFormat](crate::format::DynamicFormat)
 
+pub use crate::dynamic_format::*;
 #[cfg(feature = "format")]
 pub use crate::feature_format::*;
 pub use crate::format_builder::*;
@@ -1,6 +1,9 @@
 //! Builder for the number format.
 
+use crate::dynamic_format::DynamicFormat;
 use crate::format_flags as flags;
+use crate::grouping::DigitGrouping;
+use crate::result::Result;
 use core::{mem, num};
 use static_assertions::const_assert;
 
@@ -73,6 +76,10 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `case_sensitive_exponent`                 - If exponent characters are case-sensitive.
 /// * `case_sensitive_base_prefix`              - If base prefixes are case-sensitive.
 /// * `case_sensitive_base_suffix`              - If base suffixes are case-sensitive.
+/// * `unicode_digits`                          - If non-ASCII decimal digits are allowed.
+/// * `leading_whitespace`                      - If whitespace is allowed before the number.
+/// * `trailing_whitespace`                     - If whitespace is allowed after the number.
+/// * `unicode_whitespace`                      - If whitespace includes Unicode `White_Space`.
 /// * `integer_internal_digit_separator`        - If digit separators are allowed between integer digits.
 /// * `fraction_internal_digit_separator`       - If digit separators are allowed between fraction digits.
 /// * `exponent_internal_digit_separator`       - If digit separators are allowed between exponent digits.
@@ -85,6 +92,7 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `integer_consecutive_digit_separator`     - If multiple consecutive integer digit separators are allowed.
 /// * `fraction_consecutive_digit_separator`    - If multiple consecutive fraction digit separators are allowed.
 /// * `special_digit_separator`                 - If any digit separators are allowed in special (non-finite) values.
+/// * `digit_grouping`                          - The grouping of integer digits between digit separators.
 ///
 /// # Write Integer Fields
 ///
@@ -101,10 +109,14 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `no_positive_mantissa_sign`
 /// * `required_mantissa_sign`
 /// * `no_integer_leading_zeros`
+/// * `leading_whitespace`
+/// * `trailing_whitespace`
+/// * `unicode_whitespace`
 /// * `integer_internal_digit_separator`
 /// * `integer_leading_digit_separator`
 /// * `integer_trailing_digit_separator`
 /// * `integer_consecutive_digit_separator`
+/// * `digit_grouping`
 ///
 /// # Write Float Fields
 ///
@@ -147,6 +159,10 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `case_sensitive_exponent`
 /// * `case_sensitive_base_prefix`
 /// * `case_sensitive_base_suffix`
+/// * `unicode_digits`
+/// * `leading_whitespace`
+/// * `trailing_whitespace`
+/// * `unicode_whitespace`
 /// * `integer_internal_digit_separator`
 /// * `fraction_internal_digit_separator`
 /// * `exponent_internal_digit_separator`
@@ -159,6 +175,7 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `integer_consecutive_digit_separator`
 /// * `fraction_consecutive_digit_separator`
 /// * `special_digit_separator`
+/// * `digit_grouping`
 pub struct NumberFormatBuilder {
     digit_separator: OptionU8,
     base_prefix: OptionU8,
@@ -184,6 +201,10 @@ pub struct NumberFormatBuilder {
     case_sensitive_exponent: bool,
     case_sensitive_base_prefix: bool,
     case_sensitive_base_suffix: bool,
+    unicode_digits: bool,
+    leading_whitespace: bool,
+    trailing_whitespace: bool,
+    unicode_whitespace: bool,
     integer_internal_digit_separator: bool,
     fraction_internal_digit_separator: bool,
     exponent_internal_digit_separator: bool,
@@ -197,6 +218,7 @@ pub struct NumberFormatBuilder {
     fraction_consecutive_digit_separator: bool,
     exponent_consecutive_digit_separator: bool,
     special_digit_separator: bool,
+    digit_grouping: DigitGrouping,
 }
 
 impl NumberFormatBuilder {
@@ -230,6 +252,10 @@ impl NumberFormatBuilder {
             case_sensitive_exponent: false,
             case_sensitive_base_prefix: false,
             case_sensitive_base_suffix: false,
+            unicode_digits: false,
+            leading_whitespace: false,
+            trailing_whitespace: false,
+            unicode_whitespace: false,
             integer_internal_digit_separator: false,
             fraction_internal_digit_separator: false,
             exponent_internal_digit_separator: false,
@@ -243,6 +269,7 @@ impl NumberFormatBuilder {
             fraction_consecutive_digit_separator: false,
             exponent_consecutive_digit_separator: false,
             special_digit_separator: false,
+            digit_grouping: DigitGrouping::None,
         }
     }
 
@@ -429,6 +456,30 @@ impl NumberFormatBuilder {
         self.case_sensitive_base_suffix
     }
 
+    /// Get if non-ASCII decimal digits are allowed.
+    #[inline(always)]
+    pub const fn get_unicode_digits(&self) -> bool {
+        self.unicode_digits
+    }
+
+    /// Get if whitespace is allowed before the number.
+    #[inline(always)]
+    pub const fn get_leading_whitespace(&self) -> bool {
+        self.leading_whitespace
+    }
+
+    /// Get if whitespace is allowed after the number.
+    #[inline(always)]
+    pub const fn get_trailing_whitespace(&self) -> bool {
+        self.trailing_whitespace
+    }
+
+    /// Get if whitespace includes Unicode `White_Space`.
+    #[inline(always)]
+    pub const fn get_unicode_whitespace(&self) -> bool {
+        self.unicode_whitespace
+    }
+
     /// Get if digit separators are allowed between integer digits.
     #[inline(always)]
     pub const fn get_integer_internal_digit_separator(&self) -> bool {
@@ -507,6 +558,12 @@ impl NumberFormatBuilder {
         self.special_digit_separator
     }
 
+    /// Get the grouping of integer digits between digit separators.
+    #[inline(always)]
+    pub const fn get_digit_grouping(&self) -> DigitGrouping {
+        self.digit_grouping
+    }
+
     // SETTERS
 
     /// Set the digit separator for the number format.
@@ -719,6 +776,38 @@ impl NumberFormatBuilder {
         self
     }
 
+    /// Set if non-ASCII decimal digits are allowed.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn unicode_digits(mut self, flag: bool) -> Self {
+        self.unicode_digits = flag;
+        self
+    }
+
+    /// Set if whitespace is allowed before the number.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn leading_whitespace(mut self, flag: bool) -> Self {
+        self.leading_whitespace = flag;
+        self
+    }
+
+    /// Set if whitespace is allowed after the number.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn trailing_whitespace(mut self, flag: bool) -> Self {
+        self.trailing_whitespace = flag;
+        self
+    }
+
+    /// Set if whitespace includes Unicode `White_Space`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn unicode_whitespace(mut self, flag: bool) -> Self {
+        self.unicode_whitespace = flag;
+        self
+    }
+
     /// Set if digit separators are allowed between integer digits.
     #[inline(always)]
     #[cfg(feature = "format")]
@@ -863,6 +952,16 @@ impl NumberFormatBuilder {
         self
     }
 
+    /// Set the grouping of integer digits between digit separators.
+    ///
+    /// This requires integer internal digit separators.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn digit_grouping(mut self, grouping: DigitGrouping) -> Self {
+        self.digit_grouping = grouping;
+        self
+    }
+
     /// Set all digit separator flag masks.
     #[inline(always)]
     #[cfg(feature = "format")]
@@ -938,6 +1037,10 @@ impl NumberFormatBuilder {
             self.case_sensitive_exponent, CASE_SENSITIVE_EXPONENT ;
             self.case_sensitive_base_prefix, CASE_SENSITIVE_BASE_PREFIX ;
             self.case_sensitive_base_suffix, CASE_SENSITIVE_BASE_SUFFIX ;
+            self.unicode_digits, UNICODE_DIGITS ;
+            self.leading_whitespace, LEADING_WHITESPACE ;
+            self.trailing_whitespace, TRAILING_WHITESPACE ;
+            self.unicode_whitespace, UNICODE_WHITESPACE ;
             self.integer_internal_digit_separator, INTEGER_INTERNAL_DIGIT_SEPARATOR ;
             self.fraction_internal_digit_separator, FRACTION_INTERNAL_DIGIT_SEPARATOR ;
             self.exponent_internal_digit_separator, EXPONENT_INTERNAL_DIGIT_SEPARATOR ;
@@ -952,6 +1055,7 @@ impl NumberFormatBuilder {
             self.exponent_consecutive_digit_separator, EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR ;
             self.special_digit_separator, SPECIAL_DIGIT_SEPARATOR ;
         );
+        format |= self.digit_grouping.flags();
         if format & flags::DIGIT_SEPARATOR_FLAG_MASK != 0 {
             format |=
                 (unwrap_or_zero(self.digit_separator) as u128) << flags::DIGIT_SEPARATOR_SHIFT;
@@ -993,6 +1097,10 @@ impl NumberFormatBuilder {
             case_sensitive_exponent: has_flag!(format, CASE_SENSITIVE_EXPONENT),
             case_sensitive_base_prefix: has_flag!(format, CASE_SENSITIVE_BASE_PREFIX),
             case_sensitive_base_suffix: has_flag!(format, CASE_SENSITIVE_BASE_SUFFIX),
+            unicode_digits: has_flag!(format, UNICODE_DIGITS),
+            leading_whitespace: has_flag!(format, LEADING_WHITESPACE),
+            trailing_whitespace: has_flag!(format, TRAILING_WHITESPACE),
+            unicode_whitespace: has_flag!(format, UNICODE_WHITESPACE),
             integer_internal_digit_separator: has_flag!(format, INTEGER_INTERNAL_DIGIT_SEPARATOR),
             fraction_internal_digit_separator: has_flag!(format, FRACTION_INTERNAL_DIGIT_SEPARATOR),
             exponent_internal_digit_separator: has_flag!(format, EXPONENT_INTERNAL_DIGIT_SEPARATOR),
@@ -1015,8 +1123,18 @@ impl NumberFormatBuilder {
                 EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR
             ),
             special_digit_separator: has_flag!(format, SPECIAL_DIGIT_SEPARATOR),
+            digit_grouping: DigitGrouping::from_format(format),
         }
     }
+
+    /// Create a validated, runtime number format from builder options.
+    ///
+    /// Unlike `build`, this validates the format, returning an error
+    /// if the format is invalid or cannot be used at runtime.
+    #[inline]
+    pub fn build_dynamic(&self) -> Result<DynamicFormat> {
+        DynamicFormat::new(self.build())
+    }
 }
 
 impl Default for NumberFormatBuilder {
@@ -25,12 +25,12 @@
 //!
 //! 16  17  18  19  20  21  22  23  24  25  26  27  28  29  30  31  32
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-//! |e/P|e/S|                                                       |
+//! |e/P|e/S|u/D|L/W|T/W|u/W|                                       |
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
 //!
 //! 32  33  34  35  36  37  38  39  40  41 42  43  44  45  46  47   48
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-//! |I/I|F/I|E/I|I/L|F/L|E/L|I/T|F/T|E/T|I/C|F/C|E/C|S/D|           |
+//! |I/I|F/I|E/I|I/L|F/L|E/L|I/T|F/T|E/T|I/C|F/C|E/C|S/D|  I/G  |   |
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
 //!
 //! 48  49  50  51  52  53  54  55  56  57  58  59  60  62  62  63  64
@@ -58,6 +58,10 @@
 //!         e/C = Case-sensitive exponent character.
 //!         e/P = Case-sensitive base prefix.
 //!         e/S = Case-sensitive base suffix.
+//!         u/D = Unicode decimal digits.
+//!         L/W = Leading whitespace.
+//!         T/W = Trailing whitespace.
+//!         u/W = Unicode whitespace.
 //!
 //!     Digit Separator Flags:
 //!         I/I = Integer internal digit separator.
@@ -73,6 +77,7 @@
 //!         F/C = Fraction consecutive digit separator.
 //!         E/C = Exponent consecutive digit separator.
 //!         S/D = Special (non-finite) digit separator.
+//!         I/G = Integer digit grouping.
 //! ```
 //!
 //! The upper 64-bits are designated for control characters and radixes,
@@ -337,6 +342,29 @@ pub const CASE_SENSITIVE_BASE_PREFIX: u128 = 1 << 16;
 /// Base suffixes are case-sensitive.
 pub const CASE_SENSITIVE_BASE_SUFFIX: u128 = 1 << 17;
 
+/// Non-ASCII decimal digits are allowed.
+///
+/// The Arabic-Indic, Extended Arabic-Indic, Devanagari and full-width
+/// digits are parsed from UTF-8 as their ASCII digits, however, all
+/// decimal digits in a number must be from the same script.
+pub const UNICODE_DIGITS: u128 = 1 << 18;
+
+/// Whitespace is allowed before the number.
+///
+/// Whitespace is the ASCII characters `\t`, `\n`, `\v`, `\f`, `\r`
+/// and space, and the skipped whitespace is included in the number of
+/// processed bytes.
+pub const LEADING_WHITESPACE: u128 = 1 << 19;
+
+/// Whitespace is allowed after the number.
+pub const TRAILING_WHITESPACE: u128 = 1 << 20;
+
+/// Leading and trailing whitespace includes Unicode `White_Space`.
+///
+/// Bytes are decoded as UTF-8, and other code units as code points.
+/// This requires leading or trailing whitespace.
+pub const UNICODE_WHITESPACE: u128 = 1 << 21;
+
 // Non-digit separator flags.
 const_assert!(REQUIRED_INTEGER_DIGITS == 1);
 check_subsequent_flags!(REQUIRED_INTEGER_DIGITS, REQUIRED_FRACTION_DIGITS);
@@ -357,6 +385,10 @@ check_subsequent_flags!(NO_FLOAT_LEADING_ZEROS, REQUIRED_EXPONENT_NOTATION);
 check_subsequent_flags!(REQUIRED_EXPONENT_NOTATION, CASE_SENSITIVE_EXPONENT);
 check_subsequent_flags!(CASE_SENSITIVE_EXPONENT, CASE_SENSITIVE_BASE_PREFIX);
 check_subsequent_flags!(CASE_SENSITIVE_BASE_PREFIX, CASE_SENSITIVE_BASE_SUFFIX);
+check_subsequent_flags!(CASE_SENSITIVE_BASE_SUFFIX, UNICODE_DIGITS);
+check_subsequent_flags!(UNICODE_DIGITS, LEADING_WHITESPACE);
+check_subsequent_flags!(LEADING_WHITESPACE, TRAILING_WHITESPACE);
+check_subsequent_flags!(TRAILING_WHITESPACE, UNICODE_WHITESPACE);
 
 // DIGIT SEPARATOR FLAGS & MASKS
 // -----------------------------
@@ -424,6 +456,24 @@ pub const CONSECUTIVE_DIGIT_SEPARATOR: u128 =
 /// Any digit separators are allowed in special (non-finite) values.
 pub const SPECIAL_DIGIT_SEPARATOR: u128 = 1 << 44;
 
+/// Shift to convert to and from the integer digit grouping.
+pub const DIGIT_GROUPING_SHIFT: i32 = 45;
+
+/// Mask to extract the integer digit grouping.
+///
+/// If set, integer digits between digit separators must form groups
+/// of a fixed size, and requires integer internal digit separators.
+pub const DIGIT_GROUPING: u128 = 0b11 << DIGIT_GROUPING_SHIFT;
+
+/// Integer digits are grouped by 3, such as `1,234,567`.
+pub const THOUSANDS_DIGIT_GROUPING: u128 = 1 << DIGIT_GROUPING_SHIFT;
+
+/// Integer digits are grouped by 3, then by 2, such as `12,34,567`.
+pub const INDIAN_DIGIT_GROUPING: u128 = 2 << DIGIT_GROUPING_SHIFT;
+
+/// Integer digits are grouped by 4, such as `123,4567`.
+pub const MYRIAD_DIGIT_GROUPING: u128 = 3 << DIGIT_GROUPING_SHIFT;
+
 // Digit separator flags.
 const_assert!(INTEGER_INTERNAL_DIGIT_SEPARATOR == 1 << 32);
 check_subsequent_flags!(INTEGER_INTERNAL_DIGIT_SEPARATOR, FRACTION_INTERNAL_DIGIT_SEPARATOR);
@@ -438,6 +488,7 @@ check_subsequent_flags!(EXPONENT_TRAILING_DIGIT_SEPARATOR, INTEGER_CONSECUTIVE_D
 check_subsequent_flags!(INTEGER_CONSECUTIVE_DIGIT_SEPARATOR, FRACTION_CONSECUTIVE_DIGIT_SEPARATOR);
 check_subsequent_flags!(FRACTION_CONSECUTIVE_DIGIT_SEPARATOR, EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR);
 check_subsequent_flags!(EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR, SPECIAL_DIGIT_SEPARATOR);
+check_subsequent_flags!(SPECIAL_DIGIT_SEPARATOR, THOUSANDS_DIGIT_GROUPING);
 
 // CONTROL CHARACTER & RADIX MASKS
 // -------------------------------
@@ -501,6 +552,7 @@ check_mask_shifts!(EXPONENT_RADIX, EXPONENT_RADIX_SHIFT);
 
 // Check masks don't overlap with neighboring flags.
 check_masks_and_flags!(DIGIT_SEPARATOR, SPECIAL_DIGIT_SEPARATOR);
+check_masks_and_flags!(DIGIT_SEPARATOR, DIGIT_GROUPING);
 
 // HIDDEN MASKS
 // ------------
@@ -523,11 +575,16 @@ pub const FLAG_MASK: u128 =
     CASE_SENSITIVE_EXPONENT |
     CASE_SENSITIVE_BASE_PREFIX |
     CASE_SENSITIVE_BASE_SUFFIX |
+    UNICODE_DIGITS |
+    LEADING_WHITESPACE |
+    TRAILING_WHITESPACE |
+    UNICODE_WHITESPACE |
     INTERNAL_DIGIT_SEPARATOR |
     LEADING_DIGIT_SEPARATOR |
     TRAILING_DIGIT_SEPARATOR |
     CONSECUTIVE_DIGIT_SEPARATOR |
-    SPECIAL_DIGIT_SEPARATOR;
+    SPECIAL_DIGIT_SEPARATOR |
+    DIGIT_GROUPING;
 
 /// Mask to extract the flag bits controlling interface parsing.
 ///
@@ -668,6 +725,13 @@ pub const fn is_valid_exponent_flags(format: u128) -> bool {
     format & NO_EXPONENT_NOTATION == 0 || format & REQUIRED_EXPONENT_NOTATION == 0
 }
 
+/// Determine if the provided whitespace flags are valid.
+#[inline]
+pub const fn is_valid_whitespace_flags(format: u128) -> bool {
+    // Unicode whitespace requires leading or trailing whitespace.
+    format & UNICODE_WHITESPACE == 0 || format & (LEADING_WHITESPACE | TRAILING_WHITESPACE) != 0
+}
+
 /// Determine if an optional control character is valid.
 #[inline]
 const fn is_valid_optional_control_radix(radix: u32, value: u8) -> bool {
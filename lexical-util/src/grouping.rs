@@ -0,0 +1,155 @@
+//! Digit grouping rules for the integer digits of a number.
+//!
+//! The digit separator flags only control where digit separators may
+//! appear, so a digit grouping in the number format also requires the
+//! integer digits between separators to form groups of specific sizes.
+//! Groups are sized from the last integer digit: every group has the
+//! size of the grouping, except for the last group, which may differ,
+//! and the first group, which may be shorter.
+//!
+//! Integer digits without any digit separators are not grouped, and
+//! are always valid. Leading, trailing and consecutive digit separators
+//! are validated by the digit separator flags, and consecutive digit
+//! separators split the digits like a single separator.
+
+#[cfg(feature = "parse")]
+use crate::digit::char_is_digit_const;
+#[cfg(feature = "parse")]
+use crate::error::Error;
+#[cfg(feature = "parse")]
+use crate::format::Format;
+use crate::format_flags as flags;
+#[cfg(feature = "parse")]
+use crate::result::Result;
+
+/// How the integer digits of a number are grouped by digit separators.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DigitGrouping {
+    /// Digit separators may separate any number of digits.
+    None,
+    /// Groups of 3 digits, such as `1,234,567`.
+    Thousands,
+    /// A last group of 3 digits, preceded by groups of 2 digits, such as `12,34,567`.
+    Indian,
+    /// Groups of 4 digits, such as `123,4567`.
+    Myriad,
+}
+
+impl DigitGrouping {
+    /// Get the digit grouping from the format packed struct.
+    #[inline(always)]
+    pub const fn from_format(format: u128) -> Self {
+        match format & flags::DIGIT_GROUPING {
+            flags::THOUSANDS_DIGIT_GROUPING => Self::Thousands,
+            flags::INDIAN_DIGIT_GROUPING => Self::Indian,
+            flags::MYRIAD_DIGIT_GROUPING => Self::Myriad,
+            _ => Self::None,
+        }
+    }
+
+    /// Get the flags for the format packed struct.
+    #[inline(always)]
+    pub const fn flags(self) -> u128 {
+        match self {
+            Self::None => 0,
+            Self::Thousands => flags::THOUSANDS_DIGIT_GROUPING,
+            Self::Indian => flags::INDIAN_DIGIT_GROUPING,
+            Self::Myriad => flags::MYRIAD_DIGIT_GROUPING,
+        }
+    }
+
+    /// Get the size of the groups, and of the last group.
+    #[inline(always)]
+    pub const fn group_sizes(self) -> (usize, usize) {
+        match self {
+            Self::None => (0, 0),
+            Self::Thousands => (3, 3),
+            Self::Indian => (2, 3),
+            Self::Myriad => (4, 4),
+        }
+    }
+}
+
+/// Check the groups of the integer digits at the start of a number.
+///
+/// The integer digits follow an optional sign and base prefix, and
+/// end at the first character that is not a digit or digit separator.
+/// Returns `Error::InvalidDigitGroup` with the index into `bytes`.
+#[cfg(feature = "parse")]
+pub fn check_grouping<F: Format>(bytes: &[u8], format: F) -> Result<()> {
+    let grouping = format.digit_grouping();
+    if grouping == DigitGrouping::None {
+        return Ok(());
+    }
+
+    let mut start = match bytes.first() {
+        Some(&b'+') | Some(&b'-') => 1,
+        _ => 0,
+    };
+    let base_prefix = format.base_prefix();
+    if base_prefix != 0 && bytes.get(start) == Some(&b'0') {
+        let is_prefix = match bytes.get(start + 1) {
+            Some(&c) if format.case_sensitive_base_prefix() => c == base_prefix,
+            Some(&c) => c.eq_ignore_ascii_case(&base_prefix),
+            None => false,
+        };
+        if is_prefix {
+            start += 2;
+        }
+    }
+
+    let separator = format.digit_separator();
+    let radix = format.mantissa_radix();
+    let length = bytes[start..]
+        .iter()
+        .take_while(|&&c| c == separator || char_is_digit_const(c, radix))
+        .count();
+    check_digit_grouping(&bytes[start..start + length], separator, grouping, start)
+}
+
+/// Check the groups of the integer digits.
+///
+/// `digits` are the integer digits of the number, with any digit
+/// separators, and `index` is the index of the first digit in the
+/// number. Returns `Error::InvalidDigitGroup` at the first digit past
+/// the size of a group, or at the end of a group that is too short.
+#[cfg(feature = "parse")]
+pub fn check_digit_grouping(
+    digits: &[u8],
+    separator: u8,
+    grouping: DigitGrouping,
+    index: usize,
+) -> Result<()> {
+    let (size, last_size) = grouping.group_sizes();
+    let start = digits.iter().take_while(|&&c| c == separator).count();
+    let end = digits.len() - digits[start..].iter().rev().take_while(|&&c| c == separator).count();
+    if grouping == DigitGrouping::None || !digits[start..end].contains(&separator) {
+        return Ok(());
+    }
+
+    let mut position = start;
+    let mut is_first = true;
+    while position < end {
+        let group = position;
+        while position < end && digits[position] != separator {
+            position += 1;
+        }
+        // The first group is never the last, since there is a separator.
+        let length = position - group;
+        let expected = if position == end {
+            last_size
+        } else {
+            size
+        };
+        if length > expected {
+            return Err(Error::InvalidDigitGroup(index + group + expected));
+        } else if length < expected && !is_first {
+            return Err(Error::InvalidDigitGroup(index + position));
+        }
+        is_first = false;
+        while position < end && digits[position] == separator {
+            position += 1;
+        }
+    }
+    Ok(())
+}
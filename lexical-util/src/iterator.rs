@@ -21,9 +21,6 @@ pub use crate::skip::{AsBytes, Bytes};
 /// This trait **should never** return `null` from `as_ptr`, or be
 /// implemented for non-contiguous data.
 pub trait BytesIter<'a>: Iterator<Item = &'a u8> {
-    /// Determine if each yielded value is adjacent in memory.
-    const IS_CONTIGUOUS: bool;
-
     /// Get a ptr to the current start of the iterator.
     fn as_ptr(&self) -> *const u8;
 
@@ -62,11 +59,8 @@ pub trait BytesIter<'a>: Iterator<Item = &'a u8> {
     /// but weaker variant of `is_consumed()`.
     fn is_done(&self) -> bool;
 
-    /// Determine if the iterator is contiguous.
-    #[inline]
-    fn is_contiguous(&self) -> bool {
-        Self::IS_CONTIGUOUS
-    }
+    /// Determine if each yielded value is adjacent in memory.
+    fn is_contiguous(&self) -> bool;
 
     /// Peek the next value of the iterator, without checking bounds.
     ///
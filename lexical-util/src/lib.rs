@@ -41,25 +41,45 @@
 #![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+// Need an allocator to narrow long numbers from code units.
+#[cfg(all(feature = "parse", not(feature = "std")))]
+extern crate alloc;
+
 pub mod algorithm;
 pub mod ascii;
+pub mod bignum;
 pub mod assert;
 pub mod bf16;
+pub mod code_unit;
+pub mod complex;
 pub mod constants;
+pub mod custom_float;
+pub mod decimal;
 pub mod digit;
 pub mod div128;
+pub mod double_double;
 pub mod error;
 pub mod extended_float;
 pub mod f16;
+pub mod f80;
+pub mod f128;
 pub mod format;
+pub mod grouping;
 pub mod iterator;
 pub mod mul;
 pub mod num;
 pub mod options;
+pub mod punctuation;
+pub mod rational;
 pub mod result;
 pub mod step;
+pub mod stream;
+pub mod unicode;
+pub mod whitespace;
+pub mod wide;
 
 mod api;
+mod dynamic_format;
 mod feature_format;
 mod format_builder;
 mod format_flags;
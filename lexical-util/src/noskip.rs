@@ -5,6 +5,7 @@
 
 #![cfg(all(feature = "parse", not(feature = "format")))]
 
+use crate::format::Format;
 use crate::iterator::BytesIter;
 use core::{mem, ptr};
 
@@ -14,13 +15,13 @@ use core::{mem, ptr};
 /// Trait to simplify creation of a `Bytes` object.
 pub trait AsBytes<'a> {
     /// Create `Bytes` from object.
-    fn bytes<const __: u128>(&'a self) -> Bytes<'a, __>;
+    fn bytes<F: Format>(&'a self, format: F) -> Bytes<'a, F>;
 }
 
 impl<'a> AsBytes<'a> for [u8] {
     #[inline]
-    fn bytes<const __: u128>(&'a self) -> Bytes<'a, __> {
-        Bytes::new(self)
+    fn bytes<F: Format>(&'a self, format: F) -> Bytes<'a, F> {
+        Bytes::new(self, format)
     }
 }
 
@@ -28,27 +29,35 @@ impl<'a> AsBytes<'a> for [u8] {
 // ------
 
 /// Slice iterator that stores the original length of the slice.
+///
+/// Without digit separators, the format does not change the iteration.
 #[derive(Clone)]
-pub struct Bytes<'a, const __: u128> {
+pub struct Bytes<'a, F: Format> {
     /// The raw slice for the iterator.
     slc: &'a [u8],
     /// Current index of the iterator in the slice.
     index: usize,
+    /// The number format, unused without digit separators.
+    format: F,
 }
 
-impl<'a, const __: u128> Bytes<'a, __> {
-    /// If each yielded value is adjacent in memory.
-    pub const IS_CONTIGUOUS: bool = true;
-
+impl<'a, F: Format> Bytes<'a, F> {
     /// Create new byte object.
     #[inline]
-    pub const fn new(slc: &'a [u8]) -> Self {
+    pub fn new(slc: &'a [u8], format: F) -> Self {
         Self {
             slc,
             index: 0,
+            format,
         }
     }
 
+    /// Get the number format of the iterator.
+    #[inline]
+    pub fn format(&self) -> F {
+        self.format
+    }
+
     /// Get a ptr to the current start of the iterator.
     #[inline]
     pub fn as_ptr(&self) -> *const u8 {
@@ -99,6 +108,12 @@ impl<'a, const __: u128> Bytes<'a, __> {
         self.index >= self.slc.len()
     }
 
+    /// Determine if each yielded value is adjacent in memory.
+    #[inline(always)]
+    pub const fn is_contiguous(&self) -> bool {
+        true
+    }
+
     /// Read a value of a difference type from the iterator.
     /// This advances the internal state of the iterator.
     ///
@@ -107,9 +122,8 @@ impl<'a, const __: u128> Bytes<'a, __> {
     /// Safe as long as the number of the buffer is contains as least as
     /// many bytes as the size of V.
     #[inline]
-    #[allow(clippy::assertions_on_constants)]
     pub unsafe fn read_unchecked<V>(&self) -> V {
-        debug_assert!(Self::IS_CONTIGUOUS);
+        debug_assert!(self.is_contiguous());
         debug_assert!(self.as_slice().len() >= mem::size_of::<V>());
 
         let slc = self.as_slice();
@@ -121,7 +135,7 @@ impl<'a, const __: u128> Bytes<'a, __> {
     /// This advances the internal state of the iterator.
     #[inline]
     pub fn read<V>(&self) -> Option<V> {
-        if Self::IS_CONTIGUOUS && self.as_slice().len() >= mem::size_of::<V>() {
+        if self.is_contiguous() && self.as_slice().len() >= mem::size_of::<V>() {
             // SAFETY: safe since we've guaranteed the buffer is greater than
             // the number of elements read.
             unsafe { Some(self.read_unchecked()) }
@@ -152,7 +166,7 @@ impl<'a, const __: u128> Bytes<'a, __> {
 
     /// Get iterator over integer digits.
     #[inline]
-    pub fn integer_iter<'b>(&'b mut self) -> BytesIterator<'a, 'b, __> {
+    pub fn integer_iter<'b>(&'b mut self) -> BytesIterator<'a, 'b, F> {
         BytesIterator {
             byte: self,
         }
@@ -160,7 +174,7 @@ impl<'a, const __: u128> Bytes<'a, __> {
 
     /// Get iterator over fraction digits.
     #[inline]
-    pub fn fraction_iter<'b>(&'b mut self) -> BytesIterator<'a, 'b, __> {
+    pub fn fraction_iter<'b>(&'b mut self) -> BytesIterator<'a, 'b, F> {
         BytesIterator {
             byte: self,
         }
@@ -168,7 +182,7 @@ impl<'a, const __: u128> Bytes<'a, __> {
 
     /// Get iterator over exponent digits.
     #[inline]
-    pub fn exponent_iter<'b>(&'b mut self) -> BytesIterator<'a, 'b, __> {
+    pub fn exponent_iter<'b>(&'b mut self) -> BytesIterator<'a, 'b, F> {
         BytesIterator {
             byte: self,
         }
@@ -176,7 +190,7 @@ impl<'a, const __: u128> Bytes<'a, __> {
 
     /// Get iterator over special floating point values.
     #[inline]
-    pub fn special_iter<'b>(&'b mut self) -> BytesIterator<'a, 'b, __> {
+    pub fn special_iter<'b>(&'b mut self) -> BytesIterator<'a, 'b, F> {
         BytesIterator {
             byte: self,
         }
@@ -189,9 +203,8 @@ impl<'a, const __: u128> Bytes<'a, __> {
     /// As long as the iterator is at least `N` elements, this
     /// is safe.
     #[inline]
-    #[allow(clippy::assertions_on_constants)]
     pub unsafe fn step_by_unchecked(&mut self, count: usize) {
-        debug_assert!(Self::IS_CONTIGUOUS);
+        debug_assert!(self.is_contiguous());
         debug_assert!(self.as_slice().len() >= count);
         self.index += count;
     }
@@ -202,9 +215,8 @@ impl<'a, const __: u128> Bytes<'a, __> {
     ///
     /// Safe as long as the iterator is not empty.
     #[inline]
-    #[allow(clippy::assertions_on_constants)]
     pub unsafe fn step_unchecked(&mut self) {
-        debug_assert!(Self::IS_CONTIGUOUS);
+        debug_assert!(self.is_contiguous());
         debug_assert!(!self.as_slice().is_empty());
         self.index += 1;
     }
@@ -214,14 +226,12 @@ impl<'a, const __: u128> Bytes<'a, __> {
 // ---------------
 
 /// Slice iterator that stores the original length of the slice.
-pub struct BytesIterator<'a: 'b, 'b, const __: u128> {
+pub struct BytesIterator<'a: 'b, 'b, F: Format> {
     /// The internal byte object for the noskip iterator.
-    byte: &'b mut Bytes<'a, __>,
+    byte: &'b mut Bytes<'a, F>,
 }
 
-impl<'a: 'b, 'b, const __: u128> BytesIter<'a> for BytesIterator<'a, 'b, __> {
-    const IS_CONTIGUOUS: bool = Bytes::<'a, __>::IS_CONTIGUOUS;
-
+impl<'a: 'b, 'b, F: Format> BytesIter<'a> for BytesIterator<'a, 'b, F> {
     #[inline]
     fn as_ptr(&self) -> *const u8 {
         self.byte.as_ptr()
@@ -264,6 +274,11 @@ impl<'a: 'b, 'b, const __: u128> BytesIter<'a> for BytesIterator<'a, 'b, __> {
         self.byte.is_done()
     }
 
+    #[inline(always)]
+    fn is_contiguous(&self) -> bool {
+        self.byte.is_contiguous()
+    }
+
     #[inline]
     unsafe fn peek_unchecked(&mut self) -> <Self as Iterator>::Item {
         // SAFETY: safe if `self.cursor() < self.length()`.
@@ -307,7 +322,7 @@ impl<'a: 'b, 'b, const __: u128> BytesIter<'a> for BytesIterator<'a, 'b, __> {
     }
 }
 
-impl<'a: 'b, 'b, const __: u128> Iterator for BytesIterator<'a, 'b, __> {
+impl<'a: 'b, 'b, F: Format> Iterator for BytesIterator<'a, 'b, F> {
     type Item = &'a u8;
 
     #[inline]
@@ -318,7 +333,7 @@ impl<'a: 'b, 'b, const __: u128> Iterator for BytesIterator<'a, 'b, __> {
     }
 }
 
-impl<'a: 'b, 'b, const __: u128> ExactSizeIterator for BytesIterator<'a, 'b, __> {
+impl<'a: 'b, 'b, F: Format> ExactSizeIterator for BytesIterator<'a, 'b, F> {
     #[inline]
     fn len(&self) -> usize {
         self.length() - self.cursor()
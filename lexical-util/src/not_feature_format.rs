@@ -7,6 +7,34 @@
 use crate::error::Error;
 use crate::format_builder::NumberFormatBuilder;
 use crate::format_flags as flags;
+use crate::grouping::DigitGrouping;
+
+/// Get the error type from the packed struct.
+///
+/// This is shared by the compile-time and runtime number formats,
+/// so both validate formats identically.
+pub(crate) const fn format_error_impl(format: u128) -> Error {
+    let valid_flags = flags::REQUIRED_EXPONENT_DIGITS | flags::REQUIRED_MANTISSA_DIGITS;
+    if !flags::is_valid_radix(flags::mantissa_radix(format)) {
+        Error::InvalidMantissaRadix
+    } else if !flags::is_valid_radix(flags::exponent_base(format)) {
+        Error::InvalidExponentBase
+    } else if !flags::is_valid_radix(flags::exponent_radix(format)) {
+        Error::InvalidExponentRadix
+    } else if !flags::is_valid_digit_separator(format) {
+        Error::InvalidDigitSeparator
+    } else if !flags::is_valid_base_prefix(format) {
+        Error::InvalidBasePrefix
+    } else if !flags::is_valid_base_suffix(format) {
+        Error::InvalidBaseSuffix
+    } else if !flags::is_valid_punctuation(format) {
+        Error::InvalidPunctuation
+    } else if format & flags::FLAG_MASK != valid_flags {
+        Error::InvalidFlags
+    } else {
+        Error::Success
+    }
+}
 
 /// Wrapper for the 128-bit packed struct.
 ///
@@ -30,32 +58,38 @@ use crate::format_flags as flags;
 ///     17. case_sensitive_exponent
 ///     18. case_sensitive_base_prefix
 ///     19. case_sensitive_base_suffix
-///     20. integer_internal_digit_separator
-///     21. fraction_internal_digit_separator
-///     22. exponent_internal_digit_separator
-///     23. internal_digit_separator
-///     24. integer_leading_digit_separator
-///     25. fraction_leading_digit_separator
-///     26. exponent_leading_digit_separator
-///     27. leading_digit_separator
-///     28. integer_trailing_digit_separator
-///     29. fraction_trailing_digit_separator
-///     30. exponent_trailing_digit_separator
-///     31. trailing_digit_separator
-///     32. integer_consecutive_digit_separator
-///     33. fraction_consecutive_digit_separator
-///     34. exponent_consecutive_digit_separator
-///     35. consecutive_digit_separator
-///     36. special_digit_separator
-///     37. digit_separator
-///     38. base_prefix
-///     39. base_suffix
-///     40. exponent_base
-///     41. exponent_radix
+///     20. unicode_digits
+///     21. leading_whitespace
+///     22. trailing_whitespace
+///     23. unicode_whitespace
+///     24. integer_internal_digit_separator
+///     25. fraction_internal_digit_separator
+///     26. exponent_internal_digit_separator
+///     27. internal_digit_separator
+///     28. integer_leading_digit_separator
+///     29. fraction_leading_digit_separator
+///     30. exponent_leading_digit_separator
+///     31. leading_digit_separator
+///     32. integer_trailing_digit_separator
+///     33. fraction_trailing_digit_separator
+///     34. exponent_trailing_digit_separator
+///     35. trailing_digit_separator
+///     36. integer_consecutive_digit_separator
+///     37. fraction_consecutive_digit_separator
+///     38. exponent_consecutive_digit_separator
+///     39. consecutive_digit_separator
+///     40. special_digit_separator
+///     41. digit_grouping
+///     42. digit_separator
+///     43. base_prefix
+///     44. base_suffix
+///     45. exponent_base
+///     46. exponent_radix
 ///
 /// See `NumberFormatBuilder` for the `FORMAT` fields
 /// for the packed struct.
 #[doc(hidden)]
+#[derive(Copy, Clone, Debug, Default)]
 pub struct NumberFormat<const FORMAT: u128>;
 
 impl<const FORMAT: u128> NumberFormat<FORMAT> {
@@ -74,27 +108,9 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     }
 
     /// Get the error type from the format.
+    #[inline(always)]
     pub const fn error(&self) -> Error {
-        let valid_flags = flags::REQUIRED_EXPONENT_DIGITS | flags::REQUIRED_MANTISSA_DIGITS;
-        if !flags::is_valid_radix(self.mantissa_radix()) {
-            Error::InvalidMantissaRadix
-        } else if !flags::is_valid_radix(self.exponent_base()) {
-            Error::InvalidExponentBase
-        } else if !flags::is_valid_radix(self.exponent_radix()) {
-            Error::InvalidExponentRadix
-        } else if !flags::is_valid_digit_separator(FORMAT) {
-            Error::InvalidDigitSeparator
-        } else if !flags::is_valid_base_prefix(FORMAT) {
-            Error::InvalidBasePrefix
-        } else if !flags::is_valid_base_suffix(FORMAT) {
-            Error::InvalidBaseSuffix
-        } else if !flags::is_valid_punctuation(FORMAT) {
-            Error::InvalidPunctuation
-        } else if self.flags() != valid_flags {
-            Error::InvalidFlags
-        } else {
-            Error::Success
-        }
+        format_error_impl(FORMAT)
     }
 
     // NON-DIGIT SEPARATOR FLAGS & MASKS
@@ -270,6 +286,42 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::CASE_SENSITIVE_BASE_SUFFIX
     }
 
+    /// If non-ASCII decimal digits are allowed.
+    pub const UNICODE_DIGITS: bool = false;
+
+    /// Get if non-ASCII decimal digits are allowed.
+    #[inline(always)]
+    pub const fn unicode_digits(&self) -> bool {
+        Self::UNICODE_DIGITS
+    }
+
+    /// If whitespace is allowed before the number.
+    pub const LEADING_WHITESPACE: bool = false;
+
+    /// Get if whitespace is allowed before the number.
+    #[inline(always)]
+    pub const fn leading_whitespace(&self) -> bool {
+        Self::LEADING_WHITESPACE
+    }
+
+    /// If whitespace is allowed after the number.
+    pub const TRAILING_WHITESPACE: bool = false;
+
+    /// Get if whitespace is allowed after the number.
+    #[inline(always)]
+    pub const fn trailing_whitespace(&self) -> bool {
+        Self::TRAILING_WHITESPACE
+    }
+
+    /// If whitespace includes Unicode `White_Space`.
+    pub const UNICODE_WHITESPACE: bool = false;
+
+    /// Get if whitespace includes Unicode `White_Space`.
+    #[inline(always)]
+    pub const fn unicode_whitespace(&self) -> bool {
+        Self::UNICODE_WHITESPACE
+    }
+
     // DIGIT SEPARATOR FLAGS & MASKS
 
     // If digit separators are allowed between integer digits.
@@ -425,6 +477,15 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::SPECIAL_DIGIT_SEPARATOR
     }
 
+    /// The grouping of integer digits between digit separators.
+    pub const DIGIT_GROUPING: DigitGrouping = DigitGrouping::None;
+
+    /// Get the grouping of integer digits between digit separators.
+    #[inline(always)]
+    pub const fn digit_grouping(&self) -> DigitGrouping {
+        Self::DIGIT_GROUPING
+    }
+
     // CHARACTERS
 
     /// The digit separator character in the packed struct.
@@ -6,8 +6,14 @@
 
 #[cfg(feature = "f16")]
 use crate::bf16::bf16;
+#[cfg(feature = "custom-float")]
+use crate::custom_float::{CustomFloat, FINITE};
 #[cfg(feature = "f16")]
 use crate::f16::f16;
+#[cfg(feature = "f80")]
+use crate::f80::f80;
+#[cfg(feature = "f128")]
+use crate::f128::f128;
 use core::{fmt, mem, ops};
 
 // AS PRIMITIVE
@@ -36,6 +42,12 @@ pub trait AsPrimitive: Copy + PartialEq + PartialOrd + Send + Sync + Sized {
 
     #[cfg(feature = "f16")]
     fn as_bf16(self) -> bf16;
+
+    #[cfg(feature = "f80")]
+    fn as_f80(self) -> f80;
+
+    #[cfg(feature = "f128")]
+    fn as_f128(self) -> f128;
 }
 
 macro_rules! as_primitive {
@@ -127,6 +139,18 @@ macro_rules! as_primitive {
             fn as_bf16(self) -> bf16 {
                 bf16::from_f32(self as f32)
             }
+
+            #[cfg(feature = "f80")]
+            #[inline(always)]
+            fn as_f80(self) -> f80 {
+                f80::from_f64(self as f64)
+            }
+
+            #[cfg(feature = "f128")]
+            #[inline(always)]
+            fn as_f128(self) -> f128 {
+                f128::from_f64(self as f64)
+            }
         }
     )*)
 }
@@ -221,6 +245,18 @@ macro_rules! half_as_primitive {
             fn as_bf16(self) -> bf16 {
                 bf16::from_f32(self.as_f32())
             }
+
+            #[cfg(feature = "f80")]
+            #[inline(always)]
+            fn as_f80(self) -> f80 {
+                f80::from_f64(self.as_f32() as f64)
+            }
+
+            #[cfg(feature = "f128")]
+            #[inline(always)]
+            fn as_f128(self) -> f128 {
+                f128::from_f64(self.as_f32() as f64)
+            }
         }
     )*)
 }
@@ -228,6 +264,312 @@ macro_rules! half_as_primitive {
 #[cfg(feature = "f16")]
 half_as_primitive! { f16 bf16 }
 
+#[cfg(feature = "f80")]
+impl AsPrimitive for f80 {
+    #[inline(always)]
+    fn as_u8(self) -> u8 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u16(self) -> u16 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u32(self) -> u32 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u64(self) -> u64 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u128(self) -> u128 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_usize(self) -> usize {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i8(self) -> i8 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i16(self) -> i16 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i32(self) -> i32 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i64(self) -> i64 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i128(self) -> i128 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_isize(self) -> isize {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_f32(self) -> f32 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_f64(self) -> f64 {
+        self.as_f64()
+    }
+
+    #[inline(always)]
+    fn from_u32(value: u32) -> Self {
+        Self::from_f64(value as _)
+    }
+
+    #[cfg(feature = "f16")]
+    #[inline(always)]
+    fn as_f16(self) -> f16 {
+        f16::from_f32(self.as_f64() as _)
+    }
+
+    #[cfg(feature = "f16")]
+    #[inline(always)]
+    fn as_bf16(self) -> bf16 {
+        bf16::from_f32(self.as_f64() as _)
+    }
+
+    #[inline(always)]
+    fn as_f80(self) -> f80 {
+        self
+    }
+
+    #[cfg(feature = "f128")]
+    #[inline(always)]
+    fn as_f128(self) -> f128 {
+        f128::from_f64(self.as_f64())
+    }
+}
+
+#[cfg(feature = "f128")]
+impl AsPrimitive for f128 {
+    #[inline(always)]
+    fn as_u8(self) -> u8 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u16(self) -> u16 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u32(self) -> u32 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u64(self) -> u64 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u128(self) -> u128 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_usize(self) -> usize {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i8(self) -> i8 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i16(self) -> i16 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i32(self) -> i32 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i64(self) -> i64 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i128(self) -> i128 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_isize(self) -> isize {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_f32(self) -> f32 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_f64(self) -> f64 {
+        self.as_f64()
+    }
+
+    #[inline(always)]
+    fn from_u32(value: u32) -> Self {
+        Self::from_f64(value as _)
+    }
+
+    #[cfg(feature = "f16")]
+    #[inline(always)]
+    fn as_f16(self) -> f16 {
+        f16::from_f32(self.as_f64() as _)
+    }
+
+    #[cfg(feature = "f16")]
+    #[inline(always)]
+    fn as_bf16(self) -> bf16 {
+        bf16::from_f32(self.as_f64() as _)
+    }
+
+    #[cfg(feature = "f80")]
+    #[inline(always)]
+    fn as_f80(self) -> f80 {
+        f80::from_f64(self.as_f64())
+    }
+
+    #[inline(always)]
+    fn as_f128(self) -> f128 {
+        self
+    }
+}
+
+#[cfg(feature = "custom-float")]
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> AsPrimitive
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    #[inline(always)]
+    fn as_u8(self) -> u8 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u16(self) -> u16 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u32(self) -> u32 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u64(self) -> u64 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_u128(self) -> u128 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_usize(self) -> usize {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i8(self) -> i8 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i16(self) -> i16 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i32(self) -> i32 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i64(self) -> i64 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_i128(self) -> i128 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_isize(self) -> isize {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_f32(self) -> f32 {
+        self.as_f64() as _
+    }
+
+    #[inline(always)]
+    fn as_f64(self) -> f64 {
+        self.as_f64()
+    }
+
+    #[inline(always)]
+    fn from_u32(value: u32) -> Self {
+        Self::from_f64(value as _)
+    }
+
+    #[cfg(feature = "f16")]
+    #[inline(always)]
+    fn as_f16(self) -> f16 {
+        f16::from_f32(self.as_f64() as _)
+    }
+
+    #[cfg(feature = "f16")]
+    #[inline(always)]
+    fn as_bf16(self) -> bf16 {
+        bf16::from_f32(self.as_f64() as _)
+    }
+
+    #[cfg(feature = "f80")]
+    #[inline(always)]
+    fn as_f80(self) -> f80 {
+        f80::from_f64(self.as_f64())
+    }
+
+    #[cfg(feature = "f128")]
+    #[inline(always)]
+    fn as_f128(self) -> f128 {
+        f128::from_f64(self.as_f64())
+    }
+}
+
 // AS CAST
 // -------
 
@@ -278,6 +620,22 @@ as_cast!(
     bf16, as_bf16 ;
 );
 
+#[cfg(feature = "f80")]
+as_cast!(f80, as_f80 ;);
+
+#[cfg(feature = "f128")]
+as_cast!(f128, as_f128 ;);
+
+#[cfg(feature = "custom-float")]
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> AsCast
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    #[inline]
+    fn as_cast<N: AsPrimitive>(n: N) -> Self {
+        Self::from_f64(n.as_f64())
+    }
+}
+
 // PRIMITIVE
 // ---------
 
@@ -295,6 +653,18 @@ primitive! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 }
 #[cfg(feature = "f16")]
 primitive! { f16 bf16 }
 
+#[cfg(feature = "f80")]
+primitive! { f80 }
+
+#[cfg(feature = "f128")]
+primitive! { f128 }
+
+#[cfg(feature = "custom-float")]
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> Primitive
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+}
+
 // NUMBER
 // ------
 
@@ -341,7 +711,6 @@ number_impl! {
     isize true ;
     f32 true ;
     f64 true ;
-    // f128 true
 }
 
 #[cfg(feature = "f16")]
@@ -350,6 +719,19 @@ number_impl! {
     bf16 true ;
 }
 
+#[cfg(feature = "f80")]
+number_impl! { f80 true ; }
+
+#[cfg(feature = "f128")]
+number_impl! { f128 true ; }
+
+#[cfg(feature = "custom-float")]
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> Number
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    const IS_SIGNED: bool = true;
+}
+
 // INTEGER
 // -------
 
@@ -808,39 +1190,36 @@ macro_rules! float_masks {
     };
 }
 
-//  Due to missing specifics or types for the following float types,
-//  `Float` is not yet fully implemented for:
-//      - f128
-
-#[cfg(feature = "f16")]
+#[cfg(any(feature = "f16", feature = "f128", feature = "custom-float"))]
 macro_rules! float_one {
     ($f:ident) => {
-        (($f::EXPONENT_BIAS - $f::MANTISSA_SIZE) as u16) << $f::MANTISSA_SIZE
+        (($f::EXPONENT_BIAS - $f::MANTISSA_SIZE) as <$f as Float>::Unsigned) << $f::MANTISSA_SIZE
     };
 }
 
-#[cfg(feature = "f16")]
+#[cfg(any(feature = "f16", feature = "f128", feature = "custom-float"))]
 macro_rules! float_two {
     ($f:ident) => {
-        (($f::EXPONENT_BIAS - $f::MANTISSA_SIZE + 1) as u16) << $f::MANTISSA_SIZE
+        (($f::EXPONENT_BIAS - $f::MANTISSA_SIZE + 1) as <$f as Float>::Unsigned)
+            << $f::MANTISSA_SIZE
     };
 }
 
-#[cfg(feature = "f16")]
+#[cfg(any(feature = "f16", feature = "f128", feature = "custom-float"))]
 macro_rules! float_max {
     ($f:ident) => {
         ($f::EXPONENT_MASK ^ $f::HIDDEN_BIT_MASK) | $f::MANTISSA_MASK
     };
 }
 
-#[cfg(feature = "f16")]
+#[cfg(any(feature = "f16", feature = "f128", feature = "custom-float"))]
 macro_rules! float_min {
     ($f:ident) => {
         $f::MAX.to_bits() | $f::SIGN_MASK
     };
 }
 
-#[cfg(feature = "f16")]
+#[cfg(any(feature = "f16", feature = "f128", feature = "custom-float"))]
 macro_rules! float_nan {
     ($f:ident) => {
         $f::EXPONENT_MASK | ($f::HIDDEN_BIT_MASK >> 1)
@@ -1075,23 +1454,235 @@ impl Float for f64 {
     }
 }
 
-// #[cfg(feature = "f128")]
-// impl Float for f128 {
-//     type Unsigned = u128;
-//     float_literals!(f128);
-//     float_masks!(
-//         float => Self,
-//         sign_mask => 0x80000000000000000000000000000000,
-//         exponent_mask => 0x7FFF0000000000000000000000000000,
-//         hidden_bit_mask => 0x00010000000000000000000000000000,
-//         mantissa_mask => 0x0000FFFFFFFFFFFFFFFFFFFFFFFFFFFF,
-//     );
-//     const EXPONENT_SIZE: i32 = 15;
-//     const MANTISSA_SIZE: i32 = 112;
-//     const EXPONENT_BIAS: i32 = 16383 + Self::MANTISSA_SIZE;
-//     const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
-//     const MAX_EXPONENT: i32 = 0x7FFF - Self::EXPONENT_BIAS;
-// }
+#[cfg(feature = "f80")]
+impl Float for f80 {
+    type Unsigned = u128;
+
+    // The integer bit is explicit, so the constants cannot be derived
+    // from the masks like the IEEE-754 floats.
+    const ZERO: Self = Self::from_bits(0);
+    const ONE: Self = Self::from_bits(0x3FFF8000000000000000);
+    const TWO: Self = Self::from_bits(0x40008000000000000000);
+    const MAX: Self = Self::from_bits(0x7FFEFFFFFFFFFFFFFFFF);
+    const MIN: Self = Self::from_bits(0xFFFEFFFFFFFFFFFFFFFF);
+    const INFINITY: Self = Self::from_bits(Self::INFINITY_BITS);
+    const NEG_INFINITY: Self = Self::from_bits(Self::NEGATIVE_INFINITY_BITS);
+    const NAN: Self = Self::from_bits(0x7FFFC000000000000000);
+    const BITS: usize = 80;
+
+    const SIGN_MASK: u128 = 0x80000000000000000000;
+    const EXPONENT_MASK: u128 = 0x7FFF0000000000000000;
+    const HIDDEN_BIT_MASK: u128 = 0x00008000000000000000;
+    const MANTISSA_MASK: u128 = 0x00007FFFFFFFFFFFFFFF;
+    const CARRY_MASK: u128 = 0x00010000000000000000;
+    const INFINITY_BITS: u128 = 0x7FFF8000000000000000;
+    const NEGATIVE_INFINITY_BITS: u128 = 0xFFFF8000000000000000;
+    const EXPONENT_SIZE: i32 = 15;
+    const MANTISSA_SIZE: i32 = 63;
+    const EXPONENT_BIAS: i32 = 16383 + Self::MANTISSA_SIZE;
+    const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
+    const MAX_EXPONENT: i32 = 0x7FFF - Self::EXPONENT_BIAS;
+
+    #[inline]
+    fn to_bits(self) -> u128 {
+        f80::to_bits(self)
+    }
+
+    #[inline]
+    fn from_bits(u: u128) -> f80 {
+        f80::from_bits(u)
+    }
+
+    #[inline]
+    fn ln(self) -> f80 {
+        f80::from_f64(self.as_f64().ln())
+    }
+
+    #[inline]
+    fn floor(self) -> f80 {
+        f80::from_f64(self.as_f64().floor())
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.to_bits() & Self::SIGN_MASK == 0
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        !self.is_sign_positive()
+    }
+
+    #[inline]
+    fn exponent(self) -> i32 {
+        if self.is_denormal() {
+            return Self::DENORMAL_EXPONENT;
+        }
+
+        let biased_e = ((self.to_bits() & Self::EXPONENT_MASK) >> 64) as i32;
+        biased_e - Self::EXPONENT_BIAS
+    }
+
+    #[inline]
+    fn mantissa(self) -> u128 {
+        // The integer bit is stored, so it is not implied by the exponent.
+        self.to_bits() & (Self::HIDDEN_BIT_MASK | Self::MANTISSA_MASK)
+    }
+}
+
+#[cfg(feature = "f128")]
+impl Float for f128 {
+    type Unsigned = u128;
+
+    const ZERO: Self = Self::from_bits(0);
+    const ONE: Self = Self::from_bits(float_one!(Self));
+    const TWO: Self = Self::from_bits(float_two!(Self));
+    const MAX: Self = Self::from_bits(float_max!(Self));
+    const MIN: Self = Self::from_bits(float_min!(Self));
+    const INFINITY: Self = Self::from_bits(Self::INFINITY_BITS);
+    const NEG_INFINITY: Self = Self::from_bits(Self::NEGATIVE_INFINITY_BITS);
+    const NAN: Self = Self::from_bits(float_nan!(Self));
+    const BITS: usize = mem::size_of::<Self>() * 8;
+
+    float_masks!(
+        float => Self,
+        sign_mask => 0x80000000000000000000000000000000,
+        exponent_mask => 0x7FFF0000000000000000000000000000,
+        hidden_bit_mask => 0x00010000000000000000000000000000,
+        mantissa_mask => 0x0000FFFFFFFFFFFFFFFFFFFFFFFFFFFF,
+    );
+    const EXPONENT_SIZE: i32 = 15;
+    const MANTISSA_SIZE: i32 = 112;
+    const EXPONENT_BIAS: i32 = 16383 + Self::MANTISSA_SIZE;
+    const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
+    const MAX_EXPONENT: i32 = 0x7FFF - Self::EXPONENT_BIAS;
+
+    #[inline]
+    fn to_bits(self) -> u128 {
+        f128::to_bits(self)
+    }
+
+    #[inline]
+    fn from_bits(u: u128) -> f128 {
+        f128::from_bits(u)
+    }
+
+    #[inline]
+    fn ln(self) -> f128 {
+        f128::from_f64(self.as_f64().ln())
+    }
+
+    #[inline]
+    fn floor(self) -> f128 {
+        f128::from_f64(self.as_f64().floor())
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.to_bits() & Self::SIGN_MASK == 0
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        !self.is_sign_positive()
+    }
+}
+
+#[cfg(feature = "custom-float")]
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> Float
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+    type Unsigned = u32;
+
+    // Without infinity, the largest exponent is finite except for NaN, and
+    // infinity is the largest finite float, so values above it saturate.
+    const ZERO: Self = Self::from_bits(0);
+    const ONE: Self = Self::from_bits(float_one!(Self));
+    const TWO: Self = Self::from_bits(float_two!(Self));
+    const MAX: Self = match SPECIAL {
+        FINITE => Self::from_bits(Self::EXPONENT_MASK | (Self::MANTISSA_MASK - 1)),
+        _ => Self::from_bits(float_max!(Self)),
+    };
+    const MIN: Self = Self::from_bits(float_min!(Self));
+    const INFINITY: Self = Self::from_bits(Self::INFINITY_BITS);
+    const NEG_INFINITY: Self = Self::from_bits(Self::NEGATIVE_INFINITY_BITS);
+    const NAN: Self = match SPECIAL {
+        FINITE => Self::from_bits(Self::EXPONENT_MASK | Self::MANTISSA_MASK),
+        _ => Self::from_bits(float_nan!(Self)),
+    };
+    const BITS: usize = (EXPONENT + MANTISSA + 1) as usize;
+
+    const SIGN_MASK: u32 = 1 << (EXPONENT + MANTISSA);
+    const EXPONENT_MASK: u32 = ((1 << EXPONENT) - 1) << MANTISSA;
+    const HIDDEN_BIT_MASK: u32 = 1 << MANTISSA;
+    const MANTISSA_MASK: u32 = Self::HIDDEN_BIT_MASK - 1;
+    const CARRY_MASK: u32 = Self::HIDDEN_BIT_MASK << 1;
+    const INFINITY_BITS: u32 = match SPECIAL {
+        FINITE => Self::MAX.to_bits(),
+        _ => Self::EXPONENT_MASK,
+    };
+    const NEGATIVE_INFINITY_BITS: u32 = Self::INFINITY_BITS | Self::SIGN_MASK;
+    const EXPONENT_SIZE: i32 = EXPONENT as i32;
+    const MANTISSA_SIZE: i32 = MANTISSA as i32;
+    const EXPONENT_BIAS: i32 = (1 << (EXPONENT - 1)) - 1 + Self::MANTISSA_SIZE;
+    const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
+    const MAX_EXPONENT: i32 = match SPECIAL {
+        FINITE => (1 << EXPONENT) - Self::EXPONENT_BIAS,
+        _ => (1 << EXPONENT) - 1 - Self::EXPONENT_BIAS,
+    };
+
+    #[inline]
+    fn to_bits(self) -> u32 {
+        CustomFloat::to_bits(self)
+    }
+
+    #[inline]
+    fn from_bits(u: u32) -> Self {
+        CustomFloat::from_bits(u)
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        Self::from_f64(self.as_f64().ln())
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        Self::from_f64(self.as_f64().floor())
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.to_bits() & Self::SIGN_MASK == 0
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        !self.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_special(self) -> bool {
+        match SPECIAL {
+            FINITE => self.is_nan(),
+            _ => self.to_bits() & Self::EXPONENT_MASK == Self::EXPONENT_MASK,
+        }
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        let bits = self.to_bits() & !Self::SIGN_MASK;
+        match SPECIAL {
+            FINITE => bits == Self::EXPONENT_MASK | Self::MANTISSA_MASK,
+            _ => bits > Self::EXPONENT_MASK,
+        }
+    }
+
+    #[inline]
+    fn is_inf(self) -> bool {
+        SPECIAL != FINITE && self.to_bits() & !Self::SIGN_MASK == Self::EXPONENT_MASK
+    }
+}
 
 // FLOAT HELPERS
 // -------------
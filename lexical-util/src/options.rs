@@ -2,6 +2,8 @@
 
 #[cfg(feature = "write")]
 use crate::constants::FormattedSize;
+#[cfg(feature = "write")]
+use crate::format::{Format, NumberFormat};
 
 // TRAITS
 // ------
@@ -13,7 +15,13 @@ pub trait WriteOptions: Default {
     fn is_valid(&self) -> bool;
 
     /// Get an upper bound on the buffer size.
-    fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize;
+    #[inline(always)]
+    fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
+        self.buffer_size_with_format::<T, _>(NumberFormat::<{ FORMAT }> {})
+    }
+
+    /// Get an upper bound on the buffer size, for a number format value.
+    fn buffer_size_with_format<T: FormattedSize, F: Format>(&self, format: F) -> usize;
 }
 
 /// Shared trait for all parser options.
@@ -31,6 +39,7 @@ pub trait ParseOptions: Default {
 //  ${X}_STRING - Applies to all string values for that language.
 //  ${X} - Applies to all values for that language.
 //  ${X}_(NAN|INF|INFINITY) - Applies to only a single special value.
+//  ${X}_IMAGINARY - Applies to only the imaginary unit of complex numbers.
 //  IF it's not defined, all values are the default.
 
 macro_rules! literal {
@@ -45,6 +54,7 @@ macro_rules! literal {
 literal!(RUST_LITERAL, None);
 // RUST_STRING
 literal!(PYTHON_LITERAL, None);
+literal!(PYTHON_LITERAL_IMAGINARY, b"j");
 // PYTHON_STRING
 literal!(CXX_LITERAL_NAN, b"NAN");
 literal!(CXX_LITERAL_INF, b"INFINITY");
@@ -81,6 +91,7 @@ literal!(KOTLIN_STRING_INF, b"Infinity");
 literal!(KOTLIN_STRING_INFINITY, b"Infinity");
 literal!(JULIA_LITERAL_INF, b"Inf");
 literal!(JULIA_LITERAL_INFINITY, b"Inf");
+literal!(JULIA_LITERAL_IMAGINARY, b"im");
 // JULIA_STRING
 literal!(CSHARP_LITERAL, None);
 literal!(CSHARP_STRING_INF, b"Infinity");
@@ -0,0 +1,280 @@
+//! Alternative punctuation for parsing numbers.
+//!
+//! The digit separator of the number format, and the decimal point and
+//! signs of the options, are single ASCII bytes. Real-world data may use
+//! other punctuation, such as a narrow no-break space to group digits,
+//! `٫` as a decimal point, or `−` as a minus sign, so a small set of
+//! alternatives may be accepted for each of them. Each alternative is a
+//! single character, of 1 to 4 bytes of UTF-8.
+//!
+//! Alternatives are replaced by the punctuation they stand for before
+//! parsing, so the rules of the number format, such as where digit
+//! separators are valid, apply to the alternatives the same way.
+//!
+//! The options are independent of the number format, so alternatives are
+//! checked against the digit separator of the format when parsing: the
+//! parsers return [`Error::InvalidAlternativePunctuation`] if an
+//! alternative for other punctuation is the digit separator.
+//!
+//! [`Error::InvalidAlternativePunctuation`]: crate::error::Error::InvalidAlternativePunctuation
+
+#![cfg(feature = "parse")]
+
+use crate::unicode::{code_point, is_unicode_digit};
+
+/// Maximum length of an alternative, in bytes.
+pub const MAX_PUNCTUATION_LENGTH: usize = 4;
+
+/// U+00A0, the no-break space, as UTF-8.
+pub const NO_BREAK_SPACE: &[u8] = "\u{00A0}".as_bytes();
+
+/// U+202F, the narrow no-break space, as UTF-8.
+pub const NARROW_NO_BREAK_SPACE: &[u8] = "\u{202F}".as_bytes();
+
+/// U+066B, the Arabic decimal separator, as UTF-8.
+pub const ARABIC_DECIMAL_SEPARATOR: &[u8] = "\u{066B}".as_bytes();
+
+/// U+2212, the minus sign, as UTF-8.
+pub const MINUS_SIGN: &[u8] = "\u{2212}".as_bytes();
+
+/// Alternatives for the digit separator, decimal point and signs.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "parse")] {
+/// use lexical_util::punctuation::{Punctuation, MINUS_SIGN, NARROW_NO_BREAK_SPACE};
+///
+/// const PUNCTUATION: Punctuation = Punctuation::new()
+///     .digit_separators(&[NARROW_NO_BREAK_SPACE, b"'"])
+///     .minus_signs(&[MINUS_SIGN]);
+/// assert!(PUNCTUATION.is_valid());
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Punctuation {
+    /// Alternatives for the digit separator of the number format.
+    digit_separators: &'static [&'static [u8]],
+    /// Alternatives for the decimal point.
+    decimal_points: &'static [&'static [u8]],
+    /// Alternatives for `-`.
+    minus_signs: &'static [&'static [u8]],
+    /// Alternatives for `+`.
+    plus_signs: &'static [&'static [u8]],
+}
+
+impl Punctuation {
+    /// Create punctuation without any alternatives.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            digit_separators: &[],
+            decimal_points: &[],
+            minus_signs: &[],
+            plus_signs: &[],
+        }
+    }
+
+    // GETTERS
+
+    /// Get the alternatives for the digit separator.
+    #[inline(always)]
+    pub const fn get_digit_separators(&self) -> &'static [&'static [u8]] {
+        self.digit_separators
+    }
+
+    /// Get the alternatives for the decimal point.
+    #[inline(always)]
+    pub const fn get_decimal_points(&self) -> &'static [&'static [u8]] {
+        self.decimal_points
+    }
+
+    /// Get the alternatives for `-`.
+    #[inline(always)]
+    pub const fn get_minus_signs(&self) -> &'static [&'static [u8]] {
+        self.minus_signs
+    }
+
+    /// Get the alternatives for `+`.
+    #[inline(always)]
+    pub const fn get_plus_signs(&self) -> &'static [&'static [u8]] {
+        self.plus_signs
+    }
+
+    /// Get if there are no alternatives.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.digit_separators.is_empty()
+            && self.decimal_points.is_empty()
+            && self.minus_signs.is_empty()
+            && self.plus_signs.is_empty()
+    }
+
+    /// Get if the byte is part of any alternative.
+    #[inline]
+    pub fn contains_byte(&self, c: u8) -> bool {
+        self.sets().iter().any(|set| set.iter().any(|alternative| alternative.contains(&c)))
+    }
+
+    // SETTERS
+
+    /// Set the alternatives for the digit separator.
+    ///
+    /// These are only used if the number format has a digit separator.
+    #[inline(always)]
+    pub const fn digit_separators(mut self, alternatives: &'static [&'static [u8]]) -> Self {
+        self.digit_separators = alternatives;
+        self
+    }
+
+    /// Set the alternatives for the decimal point.
+    #[inline(always)]
+    pub const fn decimal_points(mut self, alternatives: &'static [&'static [u8]]) -> Self {
+        self.decimal_points = alternatives;
+        self
+    }
+
+    /// Set the alternatives for `-`.
+    #[inline(always)]
+    pub const fn minus_signs(mut self, alternatives: &'static [&'static [u8]]) -> Self {
+        self.minus_signs = alternatives;
+        self
+    }
+
+    /// Set the alternatives for `+`.
+    #[inline(always)]
+    pub const fn plus_signs(mut self, alternatives: &'static [&'static [u8]]) -> Self {
+        self.plus_signs = alternatives;
+        self
+    }
+
+    // VALIDATION
+
+    /// Get the alternatives for each punctuation.
+    #[inline(always)]
+    const fn sets(&self) -> [&'static [&'static [u8]]; 4] {
+        [self.digit_separators, self.decimal_points, self.minus_signs, self.plus_signs]
+    }
+
+    /// Check if every alternative is valid and only stands for 1 punctuation.
+    ///
+    /// Alternatives must be a single UTF-8 character, which is not an ASCII
+    /// letter or digit, a decimal digit, or an ASCII control character.
+    #[inline]
+    pub const fn is_valid(&self) -> bool {
+        let sets = self.sets();
+        let mut set = 0;
+        while set < sets.len() {
+            let mut index = 0;
+            while index < sets[set].len() {
+                let alternative = sets[set][index];
+                if !is_valid_alternative(alternative) || self.find(alternative, set + 1) {
+                    return false;
+                }
+                index += 1;
+            }
+            set += 1;
+        }
+        true
+    }
+
+    /// Check if the alternatives are valid, and do not conflict with the punctuation.
+    ///
+    /// An alternative cannot be the digit separator, decimal point, exponent
+    /// or sign, unless it is an alternative for that punctuation. A digit
+    /// separator of `0` means the format has no digit separator.
+    #[inline]
+    pub const fn is_valid_for(&self, digit_separator: u8, decimal_point: u8, exponent: u8) -> bool {
+        if !self.is_valid() {
+            return false;
+        }
+        let sets = self.sets();
+        let punctuation = [digit_separator, decimal_point, b'-', b'+'];
+        let mut set = 0;
+        while set < sets.len() {
+            let mut index = 0;
+            while index < sets[set].len() {
+                let alternative = sets[set][index];
+                if alternative.len() == 1 {
+                    let c = alternative[0];
+                    let mut other = 0;
+                    while other < punctuation.len() {
+                        if other != set && c == punctuation[other] {
+                            return false;
+                        }
+                        other += 1;
+                    }
+                    if c == exponent {
+                        return false;
+                    }
+                }
+                index += 1;
+            }
+            set += 1;
+        }
+        true
+    }
+
+    /// Find if the alternative is in any set, starting from `start`.
+    #[inline]
+    const fn find(&self, alternative: &[u8], start: usize) -> bool {
+        let sets = self.sets();
+        let mut set = start;
+        while set < sets.len() {
+            let mut index = 0;
+            while index < sets[set].len() {
+                if is_equal(sets[set][index], alternative) {
+                    return true;
+                }
+                index += 1;
+            }
+            set += 1;
+        }
+        false
+    }
+}
+
+impl Default for Punctuation {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Typographic punctuation: no-break spaces, `'` and `_` as digit
+/// separators, `٫` as a decimal point and `−` as a minus sign.
+pub const TYPOGRAPHIC: Punctuation = Punctuation::new()
+    .digit_separators(&[NARROW_NO_BREAK_SPACE, NO_BREAK_SPACE, b"'", b"_"])
+    .decimal_points(&[ARABIC_DECIMAL_SEPARATOR])
+    .minus_signs(&[MINUS_SIGN]);
+
+/// Check if the bytes are a valid alternative.
+#[inline]
+const fn is_valid_alternative(bytes: &[u8]) -> bool {
+    if bytes.is_empty() || bytes.len() > MAX_PUNCTUATION_LENGTH {
+        return false;
+    } else if bytes.len() == 1 {
+        let c = bytes[0];
+        return (c.is_ascii_graphic() || c == b' ') && !c.is_ascii_alphanumeric();
+    }
+    match code_point(bytes) {
+        Some((point, length)) => length == bytes.len() && !is_unicode_digit(point),
+        None => false,
+    }
+}
+
+/// Check if 2 byte slices are equal.
+#[inline]
+const fn is_equal(x: &[u8], y: &[u8]) -> bool {
+    if x.len() != y.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < x.len() {
+        if x[index] != y[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
@@ -0,0 +1,372 @@
+//! Exact rational numbers, with arbitrary-precision numerators and denominators.
+//!
+//! Rationals are meant as an interchange format for symbolic math, so
+//! they are parsed and written exactly, but do not implement arithmetic
+//! operations. A rational is always in lowest terms, with a positive
+//! denominator, so equal values have the same representation. Floats
+//! are converted to their exact dyadic fraction, which can then be
+//! approximated with a bounded denominator.
+//!
+//! The numerator and denominator are stored as little-endian `u64` limbs,
+//! without any most-significant zero limbs, so zero has no limbs.
+
+#![cfg(feature = "rational")]
+#![doc(hidden)]
+
+use crate::bignum;
+use crate::num::{AsPrimitive, Float};
+use core::cmp;
+
+/// An exact rational number, `numerator / denominator`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    /// If the rational is negative.
+    is_negative: bool,
+    /// The magnitude of the numerator.
+    numerator: Vec<u64>,
+    /// The denominator, which is never 0.
+    denominator: Vec<u64>,
+}
+
+impl Rational {
+    /// Create the rational, reducing it to lowest terms.
+    ///
+    /// The sign of zero is ignored, so zero is never negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is 0.
+    pub fn new(is_negative: bool, numerator: Vec<u64>, denominator: Vec<u64>) -> Self {
+        let numerator = normalize(numerator);
+        let denominator = normalize(denominator);
+        assert!(!denominator.is_empty(), "attempt to divide by zero");
+        let gcd = gcd(&numerator, &denominator);
+        if gcd.len() == 1 && gcd[0] == 1 {
+            Self::from_lowest_terms(is_negative, numerator, denominator)
+        } else {
+            let (numerator, _) = divrem(&numerator, &gcd);
+            let (denominator, _) = divrem(&denominator, &gcd);
+            Self::from_lowest_terms(is_negative, numerator, denominator)
+        }
+    }
+
+    /// Create the rational from a numerator and denominator in lowest terms.
+    ///
+    /// This does not reduce the rational, so comparisons are incorrect if
+    /// the numerator and denominator have a common factor. Any
+    /// most-significant zero limbs are removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is 0.
+    pub fn from_lowest_terms(
+        is_negative: bool,
+        numerator: Vec<u64>,
+        denominator: Vec<u64>,
+    ) -> Self {
+        let numerator = normalize(numerator);
+        let denominator = normalize(denominator);
+        assert!(!denominator.is_empty(), "attempt to divide by zero");
+        match numerator.is_empty() {
+            true => Self::zero(),
+            false => Self {
+                is_negative,
+                numerator,
+                denominator,
+            },
+        }
+    }
+
+    /// Get the rational for 0.
+    #[inline]
+    pub fn zero() -> Self {
+        Self {
+            is_negative: false,
+            numerator: Vec::new(),
+            denominator: vec![1],
+        }
+    }
+
+    /// Create the rational for `significand * b0^e0 * b1^e1 * ...`, in lowest terms.
+    ///
+    /// This is the exact value of a number with digits in a radix and an
+    /// exponent, such as `1 * 10^-3` for `0.001`. It is reduced with the
+    /// prime factors of the bases, rather than the greatest common divisor,
+    /// so it is fast for large values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any base is less than 2.
+    pub fn from_powers(is_negative: bool, significand: Vec<u64>, powers: &[(u32, i64)]) -> Self {
+        let mut numerator = normalize(significand);
+        if numerator.is_empty() {
+            return Self::zero();
+        }
+
+        // Get the exponent of each prime factor of the bases.
+        let mut primes: Vec<(u64, i64)> = Vec::new();
+        for &(base, exponent) in powers.iter() {
+            assert!(base >= 2, "base must be at least 2");
+            let mut base = base as u64;
+            let mut prime = 2;
+            while base > 1 {
+                if prime * prime > base {
+                    prime = base;
+                }
+                let mut count = 0;
+                while base / prime * prime == base {
+                    base /= prime;
+                    count += 1;
+                }
+                if count != 0 {
+                    match primes.iter_mut().find(|x| x.0 == prime) {
+                        Some(x) => x.1 += count * exponent,
+                        None => primes.push((prime, count * exponent)),
+                    }
+                }
+                prime += 1;
+            }
+        }
+
+        // Remove any factors of the denominator from the significand.
+        let mut denominator = vec![1];
+        for &(prime, mut exponent) in primes.iter() {
+            while exponent < 0 {
+                let mut quotient = numerator.clone();
+                if bignum::divrem_small(&mut quotient, prime) != 0 {
+                    break;
+                }
+                numerator = normalize(quotient);
+                exponent += 1;
+            }
+            if exponent > 0 {
+                numerator = mul(&numerator, &pow(prime, exponent as u64));
+            } else if exponent < 0 {
+                denominator = mul(&denominator, &pow(prime, (-exponent) as u64));
+            }
+        }
+        Self::from_lowest_terms(is_negative, numerator, denominator)
+    }
+
+    /// Create the rational for the exact value of a float.
+    ///
+    /// The denominator of a finite float is always a power of 2. Returns
+    /// `None` if the float is NaN or infinite.
+    pub fn from_float<F: Float>(value: F) -> Option<Self> {
+        if value.is_special() {
+            return None;
+        }
+        let mantissa = value.mantissa().as_u128();
+        let significand = normalize(vec![mantissa as u64, (mantissa >> 64) as u64]);
+        let powers = [(2, value.exponent() as i64)];
+        Some(Self::from_powers(value.is_sign_negative(), significand, &powers))
+    }
+
+    /// Get if the rational is negative.
+    #[inline(always)]
+    pub fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Get if the rational is 0.
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_empty()
+    }
+
+    /// Get if the rational is an integer, that is, the denominator is 1.
+    #[inline(always)]
+    pub fn is_integer(&self) -> bool {
+        self.denominator == [1]
+    }
+
+    /// Get the magnitude of the numerator, as little-endian limbs.
+    #[inline(always)]
+    pub fn numerator(&self) -> &[u64] {
+        &self.numerator
+    }
+
+    /// Get the denominator, as little-endian limbs.
+    #[inline(always)]
+    pub fn denominator(&self) -> &[u64] {
+        &self.denominator
+    }
+
+    /// Get the closest rational with a denominator of at most `max_denominator`.
+    ///
+    /// This is the best rational approximation, found with the continued
+    /// fraction of the rational: any rational closer to the value has a
+    /// larger denominator. The rational is returned unchanged if the
+    /// denominator is already small enough.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_denominator` is 0.
+    pub fn limit_denominator(&self, max_denominator: u64) -> Self {
+        assert!(max_denominator != 0, "max_denominator must be at least 1");
+        if bignum::compare(&self.denominator, &[max_denominator]) != cmp::Ordering::Greater {
+            return self.clone();
+        }
+
+        // Find the last convergent `p1/q1` with a denominator of at most
+        // `max_denominator`, using the previous convergent `p0/q0`.
+        let (mut p0, mut q0, mut p1, mut q1) = (Vec::new(), 1u64, vec![1u64], 0u64);
+        let mut n = self.numerator.clone();
+        let mut d = self.denominator.clone();
+        loop {
+            let (a, r) = divrem(&n, &d);
+            let q2 = match q1 {
+                0 => q0,
+                _ => {
+                    // The denominator is `q0 + a * q1`, which must not exceed the maximum.
+                    let limit = (max_denominator - q0) / q1;
+                    if bignum::compare(&a, &[limit]) == cmp::Ordering::Greater {
+                        break;
+                    }
+                    q0 + a.first().copied().unwrap_or(0) * q1
+                },
+            };
+            let mut p2 = mul(&a, &p1);
+            add(&mut p2, &p0);
+            p0 = core::mem::replace(&mut p1, p2);
+            q0 = core::mem::replace(&mut q1, q2);
+            n = core::mem::replace(&mut d, r);
+        }
+
+        // The best approximation is either the convergent, or the
+        // semiconvergent with the largest denominator below the maximum.
+        let k = (max_denominator - q0) / q1;
+        let mut p2 = p1.clone();
+        mul_small(&mut p2, k);
+        add(&mut p2, &p0);
+        let q2 = q0 + k * q1;
+        let convergent = self.distance(&p1, q1);
+        let semiconvergent = self.distance(&p2, q2);
+        // Compare `|p1/q1 - x|` and `|p2/q2 - x|`, with a common denominator.
+        let lhs = mul(&convergent, &[q2]);
+        let rhs = mul(&semiconvergent, &[q1]);
+        match bignum::compare(&lhs, &rhs) {
+            cmp::Ordering::Greater => Self::new(self.is_negative, p2, vec![q2]),
+            _ => Self::new(self.is_negative, p1, vec![q1]),
+        }
+    }
+
+    /// Get `|p * d - n * q|`, for the magnitude of the rational `n / d`.
+    fn distance(&self, p: &[u64], q: u64) -> Vec<u64> {
+        let x = mul(p, &self.denominator);
+        let y = mul(&self.numerator, &[q]);
+        match bignum::compare(&x, &y) {
+            cmp::Ordering::Less => sub(&y, &x),
+            _ => sub(&x, &y),
+        }
+    }
+}
+
+// ARITHMETIC
+
+/// Remove the most-significant zero limbs.
+#[inline]
+fn normalize(mut x: Vec<u64>) -> Vec<u64> {
+    x.truncate(bignum::normalized_len(&x));
+    x
+}
+
+/// Add `y` to `x` in place.
+fn add(x: &mut Vec<u64>, y: &[u64]) {
+    let len = cmp::max(x.len(), y.len()) + 1;
+    x.resize(len, 0);
+    bignum::add_assign(x, y);
+    x.truncate(bignum::normalized_len(x));
+}
+
+/// Subtract `y` from `x`, which must not be smaller than `y`.
+fn sub(x: &[u64], y: &[u64]) -> Vec<u64> {
+    let mut result = x.to_vec();
+    bignum::sub_assign(&mut result, y);
+    normalize(result)
+}
+
+/// Multiply `x` by a small value in place.
+fn mul_small(x: &mut Vec<u64>, y: u64) {
+    let carry = bignum::mul_small(x, y, 0);
+    x.push(carry);
+    x.truncate(bignum::normalized_len(x));
+}
+
+/// Multiply `x` and `y`.
+fn mul(x: &[u64], y: &[u64]) -> Vec<u64> {
+    let x = &x[..bignum::normalized_len(x)];
+    let y = &y[..bignum::normalized_len(y)];
+    if x.is_empty() || y.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0; x.len() + y.len()];
+    let mut scratch = vec![0; bignum::mul_scratch_len(x.len(), y.len())];
+    bignum::mul(&mut result, x, y, &mut scratch);
+    normalize(result)
+}
+
+/// Raise a small value to a power.
+fn pow(base: u64, mut exponent: u64) -> Vec<u64> {
+    let mut result = vec![1];
+    let mut square = vec![base];
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = mul(&result, &square);
+        }
+        exponent >>= 1;
+        if exponent != 0 {
+            square = mul(&square, &square);
+        }
+    }
+    result
+}
+
+/// Divide `x` by `y`, which must not be 0, and return the quotient and remainder.
+///
+/// The quotient of [`bignum::divrem`] must fit in the limbs of the
+/// divisor, so this divides blocks with as many limbs as the divisor,
+/// from the most-significant block, carrying the remainder.
+fn divrem(x: &[u64], y: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    let y = &y[..bignum::normalized_len(y)];
+    let n = y.len();
+    if bignum::compare(x, y) == cmp::Ordering::Less {
+        return (Vec::new(), normalize(x.to_vec()));
+    } else if n == 1 {
+        let mut quotient = x.to_vec();
+        let rem = bignum::divrem_small(&mut quotient, y[0]);
+        return (normalize(quotient), normalize(vec![rem]));
+    }
+
+    let blocks = match x.len() % n {
+        0 => x.len() / n,
+        _ => x.len() / n + 1,
+    };
+    let mut quotient = vec![0; blocks * n];
+    let mut block = vec![0; 2 * n];
+    let mut scratch = vec![0; bignum::divrem_scratch_len(n)];
+    for index in (0..blocks).rev() {
+        // The high limbs hold the remainder of the previous block.
+        let start = index * n;
+        let end = cmp::min(start + n, x.len());
+        block.copy_within(..n, n);
+        for limb in block[..n].iter_mut() {
+            *limb = 0;
+        }
+        block[..end - start].copy_from_slice(&x[start..end]);
+        bignum::divrem(&mut block, y, &mut quotient[start..start + n], &mut scratch);
+    }
+    block.truncate(n);
+    (normalize(quotient), normalize(block))
+}
+
+/// Get the greatest common divisor of `x` and `y`, using Euclid's algorithm.
+fn gcd(x: &[u64], y: &[u64]) -> Vec<u64> {
+    let mut x = x.to_vec();
+    let mut y = y.to_vec();
+    while !y.is_empty() {
+        let (_, r) = divrem(&x, &y);
+        x = core::mem::replace(&mut y, r);
+    }
+    x
+}
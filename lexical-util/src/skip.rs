@@ -38,7 +38,7 @@
 #![cfg(all(feature = "format", feature = "parse"))]
 
 use crate::digit::char_is_digit_const;
-use crate::format::NumberFormat;
+use crate::format::Format;
 use crate::format_flags as flags;
 use crate::iterator::BytesIter;
 use core::{mem, ptr};
@@ -305,13 +305,13 @@ macro_rules! peek_iltc {
 /// Trait to simplify creation of a `Bytes` object.
 pub trait AsBytes<'a> {
     /// Create `Bytes` from object.
-    fn bytes<const FORMAT: u128>(&'a self) -> Bytes<'a, FORMAT>;
+    fn bytes<F: Format>(&'a self, format: F) -> Bytes<'a, F>;
 }
 
 impl<'a> AsBytes<'a> for [u8] {
     #[inline]
-    fn bytes<const FORMAT: u128>(&'a self) -> Bytes<'a, FORMAT> {
-        Bytes::new(self)
+    fn bytes<F: Format>(&'a self, format: F) -> Bytes<'a, F> {
+        Bytes::new(self, format)
     }
 }
 
@@ -326,13 +326,13 @@ impl<'a> AsBytes<'a> for [u8] {
 /// The format allows us to dictate the actual behavior of
 /// the iterator: in what contexts does it skip digit separators.
 ///
-/// `FORMAT` is required to tell us what the digit separator is, and where
+/// The format is required to tell us what the digit separator is, and where
 /// the digit separators are allowed, as well tell us the radix.
 /// The radix is required to allow us to differentiate digit from
 /// non-digit characters (see [DigitSeparators](/docs/DigitSeparators.md)
 /// for a detailed explanation on why).
 #[derive(Clone)]
-pub struct Bytes<'a, const FORMAT: u128> {
+pub struct Bytes<'a, F: Format> {
     /// The raw slice for the iterator.
     slc: &'a [u8],
     /// Current index of the iterator in the slice.
@@ -340,22 +340,28 @@ pub struct Bytes<'a, const FORMAT: u128> {
     /// The current count of values returned by the iterator.
     /// This is only used if the iterator is not contiguous.
     count: usize,
+    /// The number format, with the digit separator and where it is allowed.
+    format: F,
 }
 
-impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
-    /// If each yielded value is adjacent in memory.
-    pub const IS_CONTIGUOUS: bool = NumberFormat::<{ FORMAT }>::DIGIT_SEPARATOR == 0;
-
+impl<'a, F: Format> Bytes<'a, F> {
     /// Create new byte object.
     #[inline]
-    pub fn new(slc: &'a [u8]) -> Self {
+    pub fn new(slc: &'a [u8], format: F) -> Self {
         Self {
             slc,
             index: 0,
             count: 0,
+            format,
         }
     }
 
+    /// Get the number format of the iterator.
+    #[inline]
+    pub fn format(&self) -> F {
+        self.format
+    }
+
     /// Get a ptr to the current start of the iterator.
     #[inline]
     pub fn as_ptr(&self) -> *const u8 {
@@ -397,7 +403,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
     pub fn current_count(&self) -> usize {
         // If the buffer is contiguous, then we don't need to track the
         // number of values: the current index is enough.
-        if Self::IS_CONTIGUOUS {
+        if self.is_contiguous() {
             self.index
         } else {
             self.count
@@ -416,10 +422,10 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
         self.index >= self.slc.len()
     }
 
-    // Determine if the abstraction is contiguous.
+    /// Determine if each yielded value is adjacent in memory.
     #[inline]
     pub fn is_contiguous(&self) -> bool {
-        Self::IS_CONTIGUOUS
+        self.format.digit_separator() == 0
     }
 
     /// Read a value of a difference type from the iterator.
@@ -431,7 +437,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
     /// many bytes as the size of V.
     #[inline]
     pub unsafe fn read_unchecked<V>(&self) -> V {
-        debug_assert!(Self::IS_CONTIGUOUS);
+        debug_assert!(self.is_contiguous());
         debug_assert!(self.as_slice().len() >= mem::size_of::<V>());
 
         let slc = self.as_slice();
@@ -443,7 +449,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
     /// This advances the internal state of the iterator.
     #[inline]
     pub fn read<V>(&self) -> Option<V> {
-        if Self::IS_CONTIGUOUS && self.as_slice().len() >= mem::size_of::<V>() {
+        if self.is_contiguous() && self.as_slice().len() >= mem::size_of::<V>() {
             // SAFETY: safe since we've guaranteed the buffer is greater than
             // the number of elements read.
             unsafe { Some(self.read_unchecked()) }
@@ -478,7 +484,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
 
     /// Get iterator over integer digits.
     #[inline]
-    pub fn integer_iter<'b>(&'b mut self) -> IntegerBytesIterator<'a, 'b, FORMAT> {
+    pub fn integer_iter<'b>(&'b mut self) -> IntegerBytesIterator<'a, 'b, F> {
         IntegerBytesIterator {
             byte: self,
         }
@@ -486,7 +492,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
 
     /// Get iterator over fraction digits.
     #[inline]
-    pub fn fraction_iter<'b>(&'b mut self) -> FractionBytesIterator<'a, 'b, FORMAT> {
+    pub fn fraction_iter<'b>(&'b mut self) -> FractionBytesIterator<'a, 'b, F> {
         FractionBytesIterator {
             byte: self,
         }
@@ -494,7 +500,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
 
     /// Get iterator over exponent digits.
     #[inline]
-    pub fn exponent_iter<'b>(&'b mut self) -> ExponentBytesIterator<'a, 'b, FORMAT> {
+    pub fn exponent_iter<'b>(&'b mut self) -> ExponentBytesIterator<'a, 'b, F> {
         ExponentBytesIterator {
             byte: self,
         }
@@ -502,7 +508,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
 
     /// Get iterator over special floating point values.
     #[inline]
-    pub fn special_iter<'b>(&'b mut self) -> SpecialBytesIterator<'a, 'b, FORMAT> {
+    pub fn special_iter<'b>(&'b mut self) -> SpecialBytesIterator<'a, 'b, F> {
         SpecialBytesIterator {
             byte: self,
         }
@@ -516,7 +522,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
     /// is safe.
     #[inline]
     pub unsafe fn step_by_unchecked(&mut self, count: usize) {
-        if Self::IS_CONTIGUOUS {
+        if self.is_contiguous() {
             // Contiguous, can skip most of these checks.
             debug_assert!(self.as_slice().len() >= count);
         } else {
@@ -524,15 +530,14 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
             // if the value is in the range `[0, 1]`.
             // We also need to make sure the **current** value
             // isn't a digit separator.
-            let format = NumberFormat::<{ FORMAT }> {};
             debug_assert!(self.as_slice().len() >= count);
             debug_assert!(count == 0 || count == 1);
             debug_assert!(
-                count == 0 || self.slc.get(self.index) != Some(&format.digit_separator())
+                count == 0 || self.slc.get(self.index) != Some(&self.format.digit_separator())
             );
         }
         self.index += count;
-        if !Self::IS_CONTIGUOUS {
+        if !self.is_contiguous() {
             // Only increment the count if it's not contiguous, otherwise,
             // this is an unnecessary performance penalty.
             self.count += count;
@@ -559,22 +564,22 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
 macro_rules! skip_iterator {
     ($iterator:ident, $doc:literal) => {
         #[doc = $doc]
-        pub struct $iterator<'a: 'b, 'b, const FORMAT: u128> {
+        pub struct $iterator<'a: 'b, 'b, F: Format> {
             /// The internal byte object for the skip iterator.
-            byte: &'b mut Bytes<'a, FORMAT>,
+            byte: &'b mut Bytes<'a, F>,
         }
     };
 }
 
 macro_rules! is_digit_separator {
-    ($format:ident) => {
+    () => {
         /// Determine if the character is a digit separator.
-        pub const fn is_digit_separator(&self, value: u8) -> bool {
-            let format = NumberFormat::<{ $format }> {};
-            let digit_separator = format.digit_separator();
+        #[inline]
+        pub fn is_digit_separator(&self, value: u8) -> bool {
+            let digit_separator = self.byte.format.digit_separator();
             if digit_separator == 0 {
-                // Check at compile time if we have an invalid digit separator.
-                // b'\x00', or the NUL character, is this invalid value.
+                // b'\x00', or the NUL character, is the invalid value, which
+                // is checked at compile time for compile-time formats.
                 false
             } else {
                 value == digit_separator
@@ -586,13 +591,13 @@ macro_rules! is_digit_separator {
 /// Create impl block for skip iterator.
 macro_rules! skip_iterator_impl {
     ($iterator:ident, $radix_cb:ident) => {
-        impl<'a: 'b, 'b, const FORMAT: u128> $iterator<'a, 'b, FORMAT> {
-            is_digit_separator!(FORMAT);
+        impl<'a: 'b, 'b, F: Format> $iterator<'a, 'b, F> {
+            is_digit_separator!();
 
             /// Determine if the character is a digit.
-            pub const fn is_digit(&self, value: u8) -> bool {
-                let format = NumberFormat::<{ FORMAT }> {};
-                char_is_digit_const(value, format.$radix_cb())
+            #[inline]
+            pub fn is_digit(&self, value: u8) -> bool {
+                char_is_digit_const(value, self.byte.format.$radix_cb())
             }
         }
     };
@@ -601,7 +606,7 @@ macro_rules! skip_iterator_impl {
 /// Create impl Iterator block for skip iterator.
 macro_rules! skip_iterator_iterator_impl {
     ($iterator:ident) => {
-        impl<'a: 'b, 'b, const FORMAT: u128> Iterator for $iterator<'a, 'b, FORMAT> {
+        impl<'a: 'b, 'b, F: Format> Iterator for $iterator<'a, 'b, F> {
             type Item = &'a u8;
 
             #[inline]
@@ -610,7 +615,7 @@ macro_rules! skip_iterator_iterator_impl {
                 let value = self.peek()?;
                 // Increment the index so we know not to re-fetch it.
                 self.byte.index += 1;
-                if !Self::IS_CONTIGUOUS {
+                if !self.is_contiguous() {
                     // Only increment the count if it's not contiguous, otherwise,
                     // this is an unnecessary performance penalty.
                     self.byte.count += 1;
@@ -623,12 +628,7 @@ macro_rules! skip_iterator_iterator_impl {
 
 /// Create base methods for the ByteIter block of a skip iterator.
 macro_rules! skip_iterator_byteiter_base {
-    ($format:ident, $mask:ident) => {
-        // It's contiguous if we don't skip over any values.
-        // IE, the digit separator flags for the iterator over
-        // the digits doesn't skip any values.
-        const IS_CONTIGUOUS: bool = $format & flags::$mask == 0;
-
+    ($mask:ident) => {
         #[inline]
         fn as_ptr(&self) -> *const u8 {
             self.byte.as_ptr()
@@ -671,9 +671,12 @@ macro_rules! skip_iterator_byteiter_base {
             self.byte.is_done()
         }
 
+        // It's contiguous if we don't skip over any values.
+        // IE, the digit separator flags for the iterator over
+        // the digits doesn't skip any values.
         #[inline]
         fn is_contiguous(&self) -> bool {
-            Self::IS_CONTIGUOUS
+            self.byte.format.packed() & flags::$mask == 0
         }
 
         #[inline]
@@ -705,13 +708,13 @@ macro_rules! skip_iterator_byteiter_base {
 /// Create impl ByteIter block for skip iterator.
 macro_rules! skip_iterator_byteiter_impl {
     ($iterator:ident, $mask:ident, $i:ident, $l:ident, $t:ident, $c:ident) => {
-        impl<'a: 'b, 'b, const FORMAT: u128> BytesIter<'a> for $iterator<'a, 'b, FORMAT> {
-            skip_iterator_byteiter_base!(FORMAT, $mask);
+        impl<'a: 'b, 'b, F: Format> BytesIter<'a> for $iterator<'a, 'b, F> {
+            skip_iterator_byteiter_base!($mask);
 
             /// Peek the next value of the iterator, without consuming it.
             #[inline]
             fn peek(&mut self) -> Option<<Self as Iterator>::Item> {
-                let format = NumberFormat::<{ FORMAT }> {};
+                let format = self.byte.format;
                 const IL: u128 = flags::$i | flags::$l;
                 const IT: u128 = flags::$i | flags::$t;
                 const LT: u128 = flags::$l | flags::$t;
@@ -801,18 +804,17 @@ skip_iterator!(
 );
 skip_iterator_iterator_impl!(SpecialBytesIterator);
 
-impl<'a: 'b, 'b, const FORMAT: u128> SpecialBytesIterator<'a, 'b, FORMAT> {
-    is_digit_separator!(FORMAT);
+impl<'a: 'b, 'b, F: Format> SpecialBytesIterator<'a, 'b, F> {
+    is_digit_separator!();
 }
 
-impl<'a: 'b, 'b, const FORMAT: u128> BytesIter<'a> for SpecialBytesIterator<'a, 'b, FORMAT> {
-    skip_iterator_byteiter_base!(FORMAT, SPECIAL_DIGIT_SEPARATOR);
+impl<'a: 'b, 'b, F: Format> BytesIter<'a> for SpecialBytesIterator<'a, 'b, F> {
+    skip_iterator_byteiter_base!(SPECIAL_DIGIT_SEPARATOR);
 
     /// Peek the next value of the iterator, without consuming it.
     #[inline]
     fn peek(&mut self) -> Option<<Self as Iterator>::Item> {
-        let format = NumberFormat::<{ FORMAT }> {};
-        if format.special_digit_separator() {
+        if self.byte.format.special_digit_separator() {
             peek_iltc!(self)
         } else {
             peek_noskip!(self)
@@ -0,0 +1,219 @@
+//! Buffering for numbers split across chunks of input.
+//!
+//! Numeric parsers work over a single contiguous slice, which does not
+//! work when a number straddles the boundary between two buffers read
+//! from a socket or a file. The stream buffer only copies the bytes of
+//! a number that reach the end of a chunk, so the common case of a number
+//! fully contained in a chunk parses directly from the input.
+//!
+//! The parsers never read past a byte that cannot be part of a number,
+//! which is called a token byte here. Once a chunk contains a non-token
+//! byte after the start of the number, the number is complete and parsing
+//! the buffered bytes with that terminator gives the same result as
+//! parsing the entire, contiguous input. Buffered token bytes after the
+//! end of the number, such as the `+` in `12+`, are kept as the start of
+//! the next number.
+
+#![cfg(feature = "parse")]
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::result::Result;
+
+/// Progress of a resumable parser after feeding a chunk of input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Progress<T> {
+    /// The number may continue in the next chunk, so more input is required.
+    Incomplete,
+    /// The parsed value, the number of bytes processed since the start
+    /// of the number, including bytes from previous chunks, and the number
+    /// of bytes of the chunk that were used.
+    ///
+    /// The next number starts at the first unused byte of the chunk, after
+    /// any bytes that are still buffered.
+    Complete(T, usize, usize),
+}
+
+impl<T> Progress<T> {
+    /// Determine if more input is required to finish parsing.
+    #[inline]
+    pub const fn is_incomplete(&self) -> bool {
+        matches!(self, Self::Incomplete)
+    }
+
+    /// Determine if parsing has finished.
+    #[inline]
+    pub const fn is_complete(&self) -> bool {
+        matches!(self, Self::Complete(..))
+    }
+}
+
+/// Buffer for a number split across chunks.
+///
+/// Up to `SIZE` bytes are buffered inline, which includes the byte after
+/// the number. Longer numbers are buffered on the heap, so numbers of any
+/// length parse the same as the contiguous input.
+#[derive(Clone, Debug)]
+pub struct StreamBuffer<const SIZE: usize> {
+    /// Inline storage for the bytes from previous chunks.
+    data: [u8; SIZE],
+    /// Number of bytes in the inline storage.
+    length: usize,
+    /// Storage for the bytes from previous chunks, once they overflow the
+    /// inline storage. This is empty while the inline storage is used.
+    heap: Vec<u8>,
+}
+
+impl<const SIZE: usize> StreamBuffer<SIZE> {
+    /// Create an empty stream buffer.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            data: [0; SIZE],
+            length: 0,
+            heap: Vec::new(),
+        }
+    }
+
+    /// Get the number of bytes buffered from previous chunks.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Determine if no bytes are buffered from previous chunks.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Discard any buffered bytes.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.length = 0;
+        self.heap.clear();
+    }
+
+    /// Get the buffered bytes.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        if self.heap.is_empty() {
+            &self.data[..self.length]
+        } else {
+            &self.heap
+        }
+    }
+
+    /// Append bytes to the buffer, moving it to the heap if it is full.
+    #[inline]
+    fn extend(&mut self, bytes: &[u8]) {
+        let length = self.length + bytes.len();
+        if !self.heap.is_empty() {
+            self.heap.extend_from_slice(bytes);
+        } else if length <= SIZE {
+            self.data[self.length..length].copy_from_slice(bytes);
+            self.length = length;
+        } else {
+            self.heap.reserve(length);
+            self.heap.extend_from_slice(&self.data[..self.length]);
+            self.heap.extend_from_slice(bytes);
+            self.length = 0;
+        }
+    }
+
+    /// Keep the first `length` buffered bytes.
+    #[inline]
+    fn truncate(&mut self, length: usize) {
+        if self.heap.is_empty() {
+            self.length = length;
+        } else {
+            self.heap.truncate(length);
+        }
+    }
+
+    /// Remove the first `count` buffered bytes, keeping the rest.
+    #[inline]
+    fn consume(&mut self, count: usize) {
+        if self.heap.is_empty() {
+            self.data.copy_within(count..self.length, 0);
+            self.length -= count;
+        } else {
+            self.heap.drain(..count);
+        }
+    }
+
+    /// Feed a chunk of input, parsing the number once it is complete.
+    ///
+    /// `is_token` must return true for every byte that may be part of a
+    /// number, and `parse` is the partial parser for the number. After the
+    /// number completes, only the buffered bytes after it are kept, so the
+    /// same buffer can be used for the next number, starting with the
+    /// unused bytes of the chunk. If parsing fails, the buffer is cleared.
+    pub fn feed<T, Token, Parse>(
+        &mut self,
+        chunk: &[u8],
+        is_token: Token,
+        parse: Parse,
+    ) -> Result<Progress<T>>
+    where
+        Token: Fn(u8) -> bool,
+        Parse: FnOnce(&[u8]) -> Result<(T, usize)>,
+    {
+        let count = chunk.iter().take_while(|&&c| is_token(c)).count();
+        if count == chunk.len() {
+            // The number may continue into the next chunk.
+            self.extend(chunk);
+            return Ok(Progress::Incomplete);
+        }
+
+        // Include the terminator, so the parser sees the same bytes it
+        // would in the contiguous input.
+        let chunk = &chunk[..count + 1];
+        let buffered = self.len();
+        if buffered == 0 {
+            return parse(chunk).map(|(value, count)| Progress::Complete(value, count, count));
+        }
+        self.extend(chunk);
+        let result = parse(self.as_slice());
+        self.truncate(buffered);
+        match result {
+            Ok((value, count)) if count < buffered => {
+                // The number ended before the chunk: keep the rest of the
+                // buffered bytes for the next number.
+                self.consume(count);
+                Ok(Progress::Complete(value, count, 0))
+            },
+            Ok((value, count)) => {
+                self.clear();
+                Ok(Progress::Complete(value, count, count - buffered))
+            },
+            Err(error) => {
+                self.clear();
+                Err(error)
+            },
+        }
+    }
+
+    /// Parse the buffered bytes at the end of the input.
+    ///
+    /// Any buffered bytes after the number are kept for the next call.
+    pub fn finish<T, Parse>(&mut self, parse: Parse) -> Result<(T, usize)>
+    where
+        Parse: FnOnce(&[u8]) -> Result<(T, usize)>,
+    {
+        let result = parse(self.as_slice());
+        match result {
+            Ok((_, count)) => self.consume(count),
+            Err(_) => self.clear(),
+        }
+        result
+    }
+}
+
+impl<const SIZE: usize> Default for StreamBuffer<SIZE> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
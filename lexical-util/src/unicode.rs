@@ -0,0 +1,353 @@
+//! Parse numbers with non-ASCII decimal digits and alternative punctuation.
+//!
+//! The parsers only work over ASCII digits and punctuation, so the UTF-8
+//! digits of a number are replaced by their ASCII digits, and alternative
+//! punctuation by the punctuation it stands for, before parsing. The
+//! indexes of errors and the number of processed bytes are mapped back
+//! to the original bytes. Every decimal digit in a number must be from
+//! the same script, including ASCII, so mixed scripts are rejected at
+//! the first digit from a different script.
+//!
+//! Like [`narrow`](crate::code_unit::narrow), only the bytes of the
+//! number and the first byte after it are replaced, into a small buffer
+//! on the stack, or on the heap for long inputs. Numbers without bytes
+//! to replace are parsed directly.
+
+#![cfg(feature = "parse")]
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use crate::code_unit::{NARROW_SIZE, NON_ASCII};
+use crate::error::Error;
+use crate::punctuation::Punctuation;
+use crate::result::Result;
+
+/// The code points of the zero digit of each supported script.
+///
+/// These are the Arabic-Indic, Extended Arabic-Indic, Devanagari and
+/// full-width digits, and each script has 10 contiguous digits.
+pub const UNICODE_ZEROS: [u32; 4] = [0x0660, 0x06F0, 0x0966, 0xFF10];
+
+/// Script of ASCII digits, which is not in [`UNICODE_ZEROS`].
+const ASCII_SCRIPT: usize = UNICODE_ZEROS.len();
+
+/// Decode the multi-byte UTF-8 character at the start of the bytes.
+///
+/// Returns the code point and the number of bytes in the character, or
+/// `None` if the bytes do not start with a 2 to 4 byte character.
+#[inline]
+pub const fn code_point(bytes: &[u8]) -> Option<(u32, usize)> {
+    let length = match bytes.len() {
+        0 => return None,
+        _ => match bytes[0] {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => return None,
+        },
+    };
+    if bytes.len() < length {
+        return None;
+    }
+    let mut point = (bytes[0] & (0x7F >> length)) as u32;
+    let mut index = 1;
+    while index < length {
+        if bytes[index] & 0xC0 != 0x80 {
+            return None;
+        }
+        point = point << 6 | (bytes[index] & 0x3F) as u32;
+        index += 1;
+    }
+    Some((point, length))
+}
+
+/// Get the script of a non-ASCII decimal digit, as an index into [`UNICODE_ZEROS`].
+#[inline]
+const fn digit_script(point: u32) -> Option<usize> {
+    let mut script = 0;
+    while script < UNICODE_ZEROS.len() {
+        if point >= UNICODE_ZEROS[script] && point - UNICODE_ZEROS[script] < 10 {
+            return Some(script);
+        }
+        script += 1;
+    }
+    None
+}
+
+/// Get if the code point is a supported non-ASCII decimal digit.
+#[inline]
+pub const fn is_unicode_digit(point: u32) -> bool {
+    digit_script(point).is_some()
+}
+
+/// Get a non-ASCII digit at the start of the bytes.
+///
+/// Returns the value of the digit, the script, as an index into
+/// [`UNICODE_ZEROS`], and the number of bytes in the digit.
+#[inline]
+pub fn unicode_digit(bytes: &[u8]) -> Option<(u8, usize, usize)> {
+    let (point, count) = code_point(bytes)?;
+    let script = digit_script(point)?;
+    Some(((point - UNICODE_ZEROS[script]) as u8, script, count))
+}
+
+/// The non-ASCII digits and alternative punctuation to replace in a number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Replacements {
+    /// If non-ASCII decimal digits are replaced.
+    digits: bool,
+    /// Alternative punctuation to replace.
+    punctuation: Punctuation,
+    /// Digit separator of the number format, or `0` if there is none.
+    digit_separator: u8,
+    /// Decimal point of the options.
+    decimal_point: u8,
+    /// Exponent symbol of the options.
+    exponent: u8,
+}
+
+impl Replacements {
+    /// Create the replacements for a number format and options.
+    #[inline(always)]
+    pub const fn new(
+        digits: bool,
+        punctuation: Punctuation,
+        digit_separator: u8,
+        decimal_point: u8,
+        exponent: u8,
+    ) -> Self {
+        Self {
+            digits,
+            punctuation,
+            digit_separator,
+            decimal_point,
+            exponent,
+        }
+    }
+
+    /// Get if there is nothing to replace.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        !self.digits && self.punctuation.is_empty()
+    }
+
+    /// Check if the alternative punctuation does not overlap other punctuation.
+    #[inline(always)]
+    pub const fn is_valid(&self) -> bool {
+        self.punctuation.is_valid_for(self.digit_separator, self.decimal_point, self.exponent)
+    }
+
+    /// Get the alternative punctuation at the start of the bytes.
+    ///
+    /// Returns the punctuation it stands for, and the number of bytes in
+    /// the alternative.
+    #[inline]
+    fn alternative(&self, bytes: &[u8]) -> Option<(u8, usize)> {
+        let find = |alternatives: &[&[u8]], punctuation: u8| {
+            alternatives
+                .iter()
+                .find(|alternative| bytes.starts_with(alternative))
+                .map(|alternative| (punctuation, alternative.len()))
+        };
+        let mut found = None;
+        if self.digit_separator != 0 {
+            found = find(self.punctuation.get_digit_separators(), self.digit_separator);
+        }
+        found
+            .or_else(|| find(self.punctuation.get_decimal_points(), self.decimal_point))
+            .or_else(|| find(self.punctuation.get_minus_signs(), b'-'))
+            .or_else(|| find(self.punctuation.get_plus_signs(), b'+'))
+    }
+}
+
+/// Replace the non-ASCII digits and alternative punctuation of a number.
+///
+/// Writes the ASCII bytes to `buffer`, and the index of each byte in the
+/// original bytes to `indexes`, followed by the index past the last
+/// replaced byte. Returns the number of written bytes. `buffer` must be
+/// at least as long as `bytes`, and `indexes` 1 longer.
+fn replace<Token>(
+    bytes: &[u8],
+    replacements: &Replacements,
+    is_token: Token,
+    buffer: &mut [u8],
+    indexes: &mut [usize],
+) -> Result<usize>
+where
+    Token: Fn(u8) -> bool,
+{
+    debug_assert!(buffer.len() >= bytes.len() && indexes.len() > bytes.len());
+    let mut script = None;
+    let mut check_script = |digit_script: usize, index: usize| match script {
+        Some(script) if script != digit_script => Err(Error::InvalidDigit(index)),
+        _ => {
+            script = Some(digit_script);
+            Ok(())
+        },
+    };
+
+    let mut index = 0;
+    let mut count = 0;
+    while index < bytes.len() {
+        let c = bytes[index];
+        indexes[count] = index;
+        count += 1;
+        if let Some((punctuation, length)) = replacements.alternative(&bytes[index..]) {
+            buffer[count - 1] = punctuation;
+            index += length;
+        } else if c < 0x80 {
+            if c.is_ascii_digit() {
+                check_script(ASCII_SCRIPT, index)?;
+            }
+            buffer[count - 1] = c;
+            index += 1;
+            if !is_token(c) {
+                break;
+            }
+        } else if let Some((digit, digit_script, length)) =
+            unicode_digit(&bytes[index..]).filter(|_| replacements.digits)
+        {
+            check_script(digit_script, index)?;
+            buffer[count - 1] = b'0' + digit;
+            index += length;
+        } else {
+            buffer[count - 1] = NON_ASCII;
+            index += 1;
+            break;
+        }
+    }
+    indexes[count] = index;
+    Ok(count)
+}
+
+/// Map the index of an error back to the original bytes.
+#[inline(always)]
+fn map_error(mut error: Error, indexes: &[usize]) -> Error {
+    if let Some(index) = error.index_mut() {
+        *index = indexes[(*index).min(indexes.len() - 1)];
+    }
+    error
+}
+
+/// Replace the non-ASCII digits and punctuation into the buffers, and parse it.
+///
+/// Returns the result of the parser, with the indexes of errors and the
+/// processed bytes, if any, mapped back to the original bytes by `map`.
+#[inline(always)]
+fn parse_buffer<T, U, Token, Parse, Map>(
+    bytes: &[u8],
+    replacements: &Replacements,
+    is_token: Token,
+    parse: Parse,
+    map: Map,
+    buffer: &mut [u8],
+    indexes: &mut [usize],
+) -> Result<U>
+where
+    Token: Fn(u8) -> bool,
+    Parse: FnOnce(&[u8]) -> Result<T>,
+    Map: FnOnce(T, &[usize]) -> U,
+{
+    let count = replace(bytes, replacements, is_token, buffer, indexes)?;
+    let indexes = &indexes[..count + 1];
+    match parse(&buffer[..count]) {
+        Ok(value) => Ok(map(value, indexes)),
+        Err(error) => Err(map_error(error, indexes)),
+    }
+}
+
+/// Replace the non-ASCII digits and punctuation of a number, and parse it.
+#[inline]
+fn parse_with<T, U, Token, Parse, Map>(
+    bytes: &[u8],
+    replacements: &Replacements,
+    is_token: Token,
+    parse: Parse,
+    map: Map,
+) -> Result<U>
+where
+    Token: Fn(u8) -> bool,
+    Parse: FnOnce(&[u8]) -> Result<T>,
+    Map: FnOnce(T, &[usize]) -> U,
+{
+    // Each replaced byte is at least 1 original byte, so the buffer never
+    // needs more bytes than the input.
+    if bytes.len() <= NARROW_SIZE {
+        let mut buffer = [0u8; NARROW_SIZE];
+        let mut indexes = [0usize; NARROW_SIZE + 1];
+        return parse_buffer(bytes, replacements, is_token, parse, map, &mut buffer, &mut indexes);
+    }
+
+    let mut buffer = vec![0u8; bytes.len()];
+    let mut indexes = vec![0usize; bytes.len() + 1];
+    parse_buffer(bytes, replacements, is_token, parse, map, &mut buffer, &mut indexes)
+}
+
+/// Get if the number, up to the first byte after it, has bytes to replace.
+#[inline(always)]
+fn has_replacements<Token>(bytes: &[u8], replacements: &Replacements, is_token: &Token) -> bool
+where
+    Token: Fn(u8) -> bool,
+{
+    let mut index = 0;
+    while index < bytes.len() {
+        let c = bytes[index];
+        if c >= 0x80 || replacements.alternative(&bytes[index..]).is_some() {
+            return true;
+        } else if !is_token(c) {
+            return false;
+        }
+        index += 1;
+    }
+    false
+}
+
+/// Parse the number with replacements using a complete parser.
+///
+/// `is_token` must return true for every ASCII byte that may be part of
+/// a number, and `parse` is the parser for the ASCII bytes.
+#[inline]
+pub fn parse_unicode<T, Token, Parse>(
+    bytes: &[u8],
+    replacements: &Replacements,
+    is_token: Token,
+    parse: Parse,
+) -> Result<T>
+where
+    Token: Fn(u8) -> bool,
+    Parse: FnOnce(&[u8]) -> Result<T>,
+{
+    if !replacements.is_valid() {
+        return Err(Error::InvalidAlternativePunctuation);
+    } else if !has_replacements(bytes, replacements, &is_token) {
+        return parse(bytes);
+    }
+    parse_with(bytes, replacements, is_token, parse, |value, _| value)
+}
+
+/// Parse the number with replacements using a partial parser.
+///
+/// `is_token` must return true for every ASCII byte that may be part of
+/// a number, and `parse` is the parser for the ASCII bytes. The number of
+/// processed bytes is mapped back to the original bytes.
+#[inline]
+pub fn parse_partial_unicode<T, Token, Parse>(
+    bytes: &[u8],
+    replacements: &Replacements,
+    is_token: Token,
+    parse: Parse,
+) -> Result<(T, usize)>
+where
+    Token: Fn(u8) -> bool,
+    Parse: FnOnce(&[u8]) -> Result<(T, usize)>,
+{
+    if !replacements.is_valid() {
+        return Err(Error::InvalidAlternativePunctuation);
+    } else if !has_replacements(bytes, replacements, &is_token) {
+        return parse(bytes);
+    }
+    parse_with(bytes, replacements, is_token, parse, |(value, count), indexes| {
+        (value, indexes[count])
+    })
+}
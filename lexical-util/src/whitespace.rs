@@ -0,0 +1,186 @@
+//! Skip whitespace before and after a number.
+//!
+//! The parsers never accept whitespace, so any leading and trailing
+//! whitespace allowed by the format is skipped before parsing, and the
+//! indexes of errors and the number of processed code units are mapped
+//! back to the original input. Partial parsers include the skipped
+//! whitespace, before and after the number, in the processed count.
+//!
+//! Whitespace is either the ASCII whitespace accepted by C's `isspace`,
+//! or every character with the Unicode `White_Space` property. Bytes
+//! are decoded as UTF-8, and other code units as code points.
+
+#![cfg(feature = "parse")]
+
+use crate::code_unit::CodeUnit;
+use crate::error::Error;
+use crate::format::Format;
+use crate::result::Result;
+use crate::unicode::code_point;
+
+/// Determine if a code point is whitespace.
+///
+/// ASCII whitespace is `\t`, `\n`, `\v`, `\f`, `\r` and space. Unicode
+/// whitespace also includes every other `White_Space` character.
+#[inline]
+pub const fn is_whitespace(point: u32, unicode: bool) -> bool {
+    match point {
+        0x09..=0x0D | 0x20 => true,
+        0x85 | 0xA0 | 0x1680 | 0x2000..=0x200A | 0x2028 | 0x2029 | 0x202F | 0x205F | 0x3000 => {
+            unicode
+        },
+        _ => false,
+    }
+}
+
+/// Determine if a byte may be part of the whitespace allowed by the format.
+///
+/// Any non-ASCII byte may be part of Unicode whitespace.
+#[inline]
+pub fn is_whitespace_byte<F: Format>(c: u8, format: F) -> bool {
+    if !format.leading_whitespace() && !format.trailing_whitespace() {
+        return false;
+    }
+    is_whitespace(c as u32, false) || (c >= 0x80 && format.unicode_whitespace())
+}
+
+/// Get the number of bytes of whitespace at the start of the UTF-8 bytes.
+#[inline]
+fn leading_bytes(bytes: &[u8], unicode: bool) -> usize {
+    let mut index = 0;
+    while index < bytes.len() {
+        let c = bytes[index];
+        if c < 0x80 && is_whitespace(c as u32, unicode) {
+            index += 1;
+        } else if c < 0x80 || !unicode {
+            break;
+        } else {
+            match code_point(&bytes[index..]) {
+                Some((point, length)) if is_whitespace(point, unicode) => index += length,
+                _ => break,
+            }
+        }
+    }
+    index
+}
+
+/// Get the number of bytes of whitespace at the end of the UTF-8 bytes.
+#[inline]
+fn trailing_bytes(bytes: &[u8], unicode: bool) -> usize {
+    let mut end = bytes.len();
+    while end > 0 {
+        let c = bytes[end - 1];
+        let start = if c < 0x80 {
+            end - 1
+        } else if !unicode {
+            break;
+        } else {
+            // Find the first byte of the last character, of up to 4 bytes.
+            let min = end.saturating_sub(4);
+            let mut start = end - 1;
+            while start > min && bytes[start] & 0xC0 == 0x80 {
+                start -= 1;
+            }
+            start
+        };
+        let is_space = if c < 0x80 {
+            is_whitespace(c as u32, unicode)
+        } else {
+            match code_point(&bytes[start..end]) {
+                Some((point, length)) => start + length == end && is_whitespace(point, unicode),
+                None => false,
+            }
+        };
+        if !is_space {
+            break;
+        }
+        end = start;
+    }
+    bytes.len() - end
+}
+
+/// Get the number of code units of whitespace at the start of the units.
+#[inline]
+pub fn leading_whitespace<U: CodeUnit>(units: &[U], unicode: bool) -> usize {
+    if let Some(bytes) = U::as_bytes(units) {
+        return leading_bytes(bytes, unicode);
+    }
+    units.iter().take_while(|&&c| is_whitespace(c.to_code_point(), unicode)).count()
+}
+
+/// Get the number of code units of whitespace at the end of the units.
+#[inline]
+pub fn trailing_whitespace<U: CodeUnit>(units: &[U], unicode: bool) -> usize {
+    if let Some(bytes) = U::as_bytes(units) {
+        return trailing_bytes(bytes, unicode);
+    }
+    units.iter().rev().take_while(|&&c| is_whitespace(c.to_code_point(), unicode)).count()
+}
+
+/// Shift the index of an error by the skipped leading whitespace.
+#[inline(always)]
+fn shift_error(mut error: Error, count: usize) -> Error {
+    if let Some(index) = error.index_mut() {
+        *index += count;
+    }
+    error
+}
+
+/// Skip the whitespace allowed by the format, and parse the number using a complete parser.
+#[inline]
+pub fn parse_whitespace<F, U, T, Parse>(units: &[U], format: F, parse: Parse) -> Result<T>
+where
+    F: Format,
+    U: CodeUnit,
+    Parse: FnOnce(&[U]) -> Result<T>,
+{
+    if !format.leading_whitespace() && !format.trailing_whitespace() {
+        return parse(units);
+    }
+
+    let unicode = format.unicode_whitespace();
+    let start = if format.leading_whitespace() {
+        leading_whitespace(units, unicode)
+    } else {
+        0
+    };
+    let end = if format.trailing_whitespace() {
+        units.len() - trailing_whitespace(&units[start..], unicode)
+    } else {
+        units.len()
+    };
+    parse(&units[start..end]).map_err(|error| shift_error(error, start))
+}
+
+/// Skip the whitespace allowed by the format, and parse the number using a partial parser.
+///
+/// The number of processed code units includes the whitespace before
+/// and after the number.
+#[inline]
+pub fn parse_partial_whitespace<F, U, T, Parse>(
+    units: &[U],
+    format: F,
+    parse: Parse,
+) -> Result<(T, usize)>
+where
+    F: Format,
+    U: CodeUnit,
+    Parse: FnOnce(&[U]) -> Result<(T, usize)>,
+{
+    if !format.leading_whitespace() && !format.trailing_whitespace() {
+        return parse(units);
+    }
+
+    let unicode = format.unicode_whitespace();
+    let start = if format.leading_whitespace() {
+        leading_whitespace(units, unicode)
+    } else {
+        0
+    };
+    let (value, count) = parse(&units[start..]).map_err(|error| shift_error(error, start))?;
+    let mut count = start + count;
+    if format.trailing_whitespace() {
+        count += leading_whitespace(&units[count..], unicode);
+    }
+    Ok((value, count))
+}
@@ -0,0 +1,120 @@
+//! Trait for user-defined, fixed-width wide integers.
+//!
+//! The primitive integers are limited to 128 bits. Larger integers, such
+//! as 256- or 512-bit integers, may implement [`WideInteger`] to be parsed
+//! by `lexical-parse-integer` and written by `lexical-write-integer`.
+
+#![cfg(feature = "integers")]
+
+/// A fixed-width integer stored as 64-bit limbs.
+///
+/// The limbs are stored from least to most significant, and signed
+/// integers use two's complement, so the most-significant bit of the
+/// last limb is the sign bit. Every value of the type must have the
+/// same number of limbs, and the number of bits in the integer is
+/// `64 * limbs().len()`.
+///
+/// Only the limb accessors are required: the arithmetic has default
+/// implementations in terms of the limbs, which may be overridden with
+/// faster implementations.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::wide::WideInteger;
+///
+/// #[derive(Clone)]
+/// struct U256([u64; 4]);
+///
+/// impl WideInteger for U256 {
+///     const IS_SIGNED: bool = false;
+///     const ZERO: Self = U256([0; 4]);
+///
+///     fn limbs(&self) -> &[u64] {
+///         &self.0
+///     }
+///
+///     fn limbs_mut(&mut self) -> &mut [u64] {
+///         &mut self.0
+///     }
+/// }
+///
+/// let mut x = U256([u64::MAX, 0, 0, 0]);
+/// assert_eq!(x.mul_small(2, 1), 0);
+/// assert_eq!(x.limbs(), &[u64::MAX, 1, 0, 0]);
+/// assert_eq!(x.divrem_small(10), 1);
+/// ```
+pub trait WideInteger: Clone {
+    /// If the integer is signed.
+    const IS_SIGNED: bool;
+    /// The integer with a value of 0.
+    const ZERO: Self;
+
+    /// Get the limbs, from least to most significant.
+    fn limbs(&self) -> &[u64];
+
+    /// Get the mutable limbs, from least to most significant.
+    fn limbs_mut(&mut self) -> &mut [u64];
+
+    /// Get the number of bits in the integer.
+    #[inline]
+    fn bits(&self) -> usize {
+        64 * self.limbs().len()
+    }
+
+    /// Get if the value is 0.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.limbs().iter().all(|&x| x == 0)
+    }
+
+    /// Get if the value is negative.
+    #[inline]
+    fn is_negative(&self) -> bool {
+        match self.limbs().last() {
+            Some(&x) => Self::IS_SIGNED && x >> 63 == 1,
+            None => false,
+        }
+    }
+
+    /// Negate the value in place, using two's complement and wrapping on overflow.
+    #[inline]
+    fn wrapping_neg(&mut self) {
+        let mut carry = true;
+        for limb in self.limbs_mut() {
+            let (value, overflowed) = (!*limb).overflowing_add(carry as u64);
+            *limb = value;
+            carry = overflowed;
+        }
+    }
+
+    /// Multiply by a small value and add a carry in place, treating the value as unsigned.
+    ///
+    /// The result wraps on overflow, and the carry out of the
+    /// most-significant limb is returned, so the result overflowed
+    /// if the returned value is not 0.
+    #[inline]
+    fn mul_small(&mut self, y: u64, mut carry: u64) -> u64 {
+        for limb in self.limbs_mut() {
+            let full = (*limb as u128) * (y as u128) + (carry as u128);
+            *limb = full as u64;
+            carry = (full >> 64) as u64;
+        }
+        carry
+    }
+
+    /// Divide by a small value in place, treating the value as unsigned.
+    ///
+    /// Returns the remainder. The divisor must not be 0.
+    #[inline]
+    fn divrem_small(&mut self, y: u64) -> u64 {
+        debug_assert!(y != 0, "attempt to divide by zero");
+        let mut rem = 0_u64;
+        for limb in self.limbs_mut().iter_mut().rev() {
+            let full = ((rem as u128) << 64) | (*limb as u128);
+            *limb = (full / y as u128) as u64;
+            rem = (full % y as u128) as u64;
+        }
+        rem
+    }
+}
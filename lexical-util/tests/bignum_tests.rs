@@ -0,0 +1,156 @@
+#![cfg(feature = "integers")]
+
+use core::cmp::Ordering;
+use lexical_util::bignum::*;
+
+/// Generate pseudo-random limbs using xorshift.
+fn random_limbs(seed: &mut u64, len: usize) -> Vec<u64> {
+    (0..len)
+        .map(|_| {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            *seed
+        })
+        .collect()
+}
+
+/// Multiply using only small multiplications, as a reference.
+fn reference_mul(x: &[u64], y: &[u64]) -> Vec<u64> {
+    let mut out = vec![0; x.len() + y.len()];
+    for (index, &yi) in y.iter().enumerate() {
+        let mut partial = x.to_vec();
+        let carry = mul_small(&mut partial, yi, 0);
+        partial.push(carry);
+        add_assign(&mut out[index..], &partial);
+    }
+    out
+}
+
+fn mul_vec(x: &[u64], y: &[u64]) -> Vec<u64> {
+    let mut out = vec![0; x.len() + y.len()];
+    let mut scratch = vec![0; mul_scratch_len(x.len(), y.len())];
+    mul(&mut out, x, y, &mut scratch);
+    out
+}
+
+#[test]
+fn compare_test() {
+    assert_eq!(compare(&[1, 0, 0], &[1]), Ordering::Equal);
+    assert_eq!(compare(&[0, 1], &[u64::MAX]), Ordering::Greater);
+    assert_eq!(compare(&[u64::MAX, 1], &[0, 2]), Ordering::Less);
+    assert_eq!(normalized_len(&[1, 0, 0]), 1);
+    assert_eq!(normalized_len(&[0, 0]), 0);
+}
+
+#[test]
+fn add_sub_test() {
+    let mut x = [u64::MAX, u64::MAX, 0];
+    assert!(!add_assign(&mut x, &[1]));
+    assert_eq!(x, [0, 0, 1]);
+    assert!(add_assign(&mut x[1..], &[0, u64::MAX]));
+    assert_eq!(x, [0, 0, 0]);
+    assert!(sub_assign(&mut x, &[1]));
+    assert_eq!(x, [u64::MAX; 3]);
+    assert!(!sub_assign(&mut x, &[0, 0, 2]));
+    assert_eq!(x, [u64::MAX, u64::MAX, u64::MAX - 2]);
+}
+
+#[test]
+fn mul_test() {
+    let mut seed = 0x2545F4914F6CDD1D;
+    let lengths = [(3, 5), (32, 32), (33, 33), (100, 100), (150, 70), (40, 311), (257, 256)];
+    for &(x_len, y_len) in &lengths {
+        let x = random_limbs(&mut seed, x_len);
+        let y = random_limbs(&mut seed, y_len);
+        assert_eq!(mul_vec(&x, &y), reference_mul(&x, &y), "{}x{}", x_len, y_len);
+    }
+
+    // Maximal values stress the carries in Karatsuba multiplication.
+    let x = vec![u64::MAX; 130];
+    assert_eq!(mul_vec(&x, &x), reference_mul(&x, &x));
+}
+
+#[test]
+fn divrem_test() {
+    let mut seed = 0x9E3779B97F4A7C15;
+    for &n in &[1, 2, 5, 47, 48, 61, 96, 200, 333] {
+        let mut y = random_limbs(&mut seed, n);
+        for &shift in &[0, 1, 17, 63] {
+            y[n - 1] = (y[n - 1] >> shift).max(1);
+            let mut x = random_limbs(&mut seed, 2 * n);
+            // The quotient must fit in `n` limbs.
+            x[2 * n - 1] = y[n - 1] - 1;
+            let original = x.clone();
+            let mut q = vec![0; n];
+            let mut scratch = vec![0; divrem_scratch_len(n)];
+            divrem(&mut x, &y, &mut q, &mut scratch);
+            assert_eq!(compare(&x, &y), Ordering::Less);
+            let mut result = mul_vec(&q, &y);
+            assert!(!add_assign(&mut result, &x));
+            assert_eq!(compare(&result, &original), Ordering::Equal, "{} {}", n, shift);
+        }
+    }
+
+    // Values smaller than the divisor are the remainder.
+    let mut x = [5, 0];
+    let mut q = [7];
+    divrem(&mut x, &[6], &mut q, &mut []);
+    assert_eq!((x, q), ([5, 0], [0]));
+}
+
+#[test]
+fn divrem_normalized_test() {
+    let mut seed = 0x2545F4914F6CDD1D;
+    for &n in &[1, 2, 7] {
+        let mut y = random_limbs(&mut seed, n);
+        y[n - 1] |= 1 << 63;
+        let mut x = random_limbs(&mut seed, n + 2);
+        x[n + 1] = 0;
+        let original = x.clone();
+        let mut q = vec![0; 2];
+        divrem_normalized(&mut x, &y, &mut q);
+        assert_eq!(compare(&x[..n], &y), Ordering::Less);
+        let mut result = mul_vec(&q, &y);
+        assert!(!add_assign(&mut result, &x[..n]));
+        assert_eq!(compare(&result, &original), Ordering::Equal);
+    }
+}
+
+#[test]
+#[should_panic]
+fn divrem_normalized_panic_test() {
+    divrem_normalized(&mut [0, 0, 1], &[1, 1], &mut [0]);
+}
+
+#[test]
+fn shl_test() {
+    let mut dst = [u64::MAX; 4];
+    shl(&mut dst, &[1 << 63, 1], 65);
+    assert_eq!(dst, [0, 0, 3, 0]);
+    shl(&mut dst, &[1], 64 * 5);
+    assert_eq!(dst, [0; 4]);
+}
+
+#[test]
+fn powers_test() {
+    let (base, step) = max_power(10);
+    assert_eq!((base, step), (10_000_000_000_000_000_000, 19));
+    assert_eq!(max_power(2), (1 << 63, 63));
+    assert_eq!(max_power(36), (36_u64.pow(12), 12));
+
+    let mut table = vec![0; powers_len(7)];
+    let mut scratch = vec![0; mul_scratch_len(64, 64)];
+    powers(base, 7, &mut table, &mut scratch);
+    let mut expected = vec![0; 128];
+    expected[0] = base;
+    assert_eq!(power(&table, 0), &[base]);
+    for index in 1..=7 {
+        // Squaring doubles the exponent.
+        for _ in 0..1 << (index - 1) {
+            mul_small(&mut expected, base, 0);
+        }
+        let len = normalized_len(&expected);
+        assert_eq!(power(&table, index), &expected[..len]);
+    }
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::code_unit::{narrow, CodeUnit, NARROW_SIZE, NON_ASCII};
+use lexical_util::error::Error;
+use lexical_util::result::Result;
+
+fn collect(bytes: &[u8]) -> Result<(Vec<u8>, usize)> {
+    Ok((bytes.to_vec(), bytes.len()))
+}
+
+#[test]
+fn to_byte_test() {
+    assert_eq!(b'1'.to_byte(), b'1');
+    assert_eq!(0xE9u8.to_byte(), 0xE9);
+    assert_eq!(0x31u16.to_byte(), b'1');
+    assert_eq!(0x7Fu16.to_byte(), 0x7F);
+    assert_eq!(0x80u16.to_byte(), NON_ASCII);
+    assert_eq!(0x131u16.to_byte(), NON_ASCII);
+    assert_eq!(0x131u32.to_byte(), NON_ASCII);
+    assert_eq!('1'.to_byte(), b'1');
+    assert_eq!('é'.to_byte(), NON_ASCII);
+    assert_eq!('١'.to_byte(), NON_ASCII);
+}
+
+#[test]
+fn to_code_point_test() {
+    assert_eq!(b' '.to_code_point(), 0x20);
+    assert_eq!(0xA0u8.to_code_point(), u32::MAX);
+    assert_eq!(0x3000u16.to_code_point(), 0x3000);
+    assert_eq!(0xD800u16.to_code_point(), 0xD800);
+    assert_eq!(0x1F600u32.to_code_point(), 0x1F600);
+    assert_eq!('\u{2028}'.to_code_point(), 0x2028);
+}
+
+#[test]
+fn narrow_test() {
+    let is_digit = |c: u8| c.is_ascii_digit();
+    assert_eq!(narrow(b"12a45", is_digit, collect), Ok((b"12a45".to_vec(), 5)));
+
+    let units: Vec<u16> = "12a45".encode_utf16().collect();
+    assert_eq!(narrow(&units, is_digit, collect), Ok((b"12a".to_vec(), 3)));
+    let units: Vec<u16> = "12é45".encode_utf16().collect();
+    assert_eq!(narrow(&units, is_digit, collect), Ok((vec![b'1', b'2', NON_ASCII], 3)));
+    let units: Vec<char> = "1245".chars().collect();
+    assert_eq!(narrow(&units, is_digit, collect), Ok((b"1245".to_vec(), 4)));
+    let units: Vec<char> = "".chars().collect();
+    assert_eq!(narrow(&units, is_digit, collect), Ok((b"".to_vec(), 0)));
+
+    let units = vec!['1'; NARROW_SIZE + 10];
+    let result = narrow(&units, is_digit, collect);
+    assert_eq!(result, Ok((vec![b'1'; NARROW_SIZE + 10], NARROW_SIZE + 10)));
+}
+
+lexical_util::from_lexical!();
+
+/// Counts the leading digits, to check the default code unit parsers.
+#[derive(Debug, PartialEq)]
+struct Digits(usize);
+
+impl FromLexical for Digits {
+    fn from_lexical(bytes: &[u8]) -> Result<Self> {
+        let (value, count) = Self::from_lexical_partial(bytes)?;
+        if count == bytes.len() {
+            Ok(value)
+        } else {
+            Err(Error::InvalidDigit(count))
+        }
+    }
+
+    fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)> {
+        let count = bytes.iter().take_while(|c| c.is_ascii_digit()).count();
+        Ok((Digits(count), count))
+    }
+}
+
+#[test]
+fn default_units_test() {
+    let units: Vec<u16> = "12a45".encode_utf16().collect();
+    assert_eq!(Digits::from_lexical_units(&units), Err(Error::InvalidDigit(2)));
+    assert_eq!(Digits::from_lexical_partial_units(&units), Ok((Digits(2), 2)));
+    let units: Vec<char> = "12é45".chars().collect();
+    assert_eq!(Digits::from_lexical_units(&units), Err(Error::InvalidDigit(2)));
+    let units = vec!['1'; NARROW_SIZE + 10];
+    assert_eq!(Digits::from_lexical_units(&units), Ok(Digits(NARROW_SIZE + 10)));
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "custom-float")]
+
+use lexical_util::custom_float::{f8e4m3, f8e5m2, tf32, CustomFloat, FINITE};
+use lexical_util::num::Float;
+
+/// Get the exact value of the positive, finite float bits.
+fn to_f64<F: Float>(bits: u32) -> f64 {
+    let mantissa = bits as u64 & ((1 << F::MANTISSA_SIZE) - 1);
+    let biased = (bits >> F::MANTISSA_SIZE) as i32;
+    match biased {
+        0 => mantissa as f64 * 2f64.powi(F::DENORMAL_EXPONENT),
+        _ => (mantissa | (1 << F::MANTISSA_SIZE)) as f64 * 2f64.powi(biased - F::EXPONENT_BIAS),
+    }
+}
+
+#[test]
+fn constants_test() {
+    assert_eq!(f8e4m3::ONE.to_bits(), 0x38);
+    assert_eq!(f8e4m3::TWO.to_bits(), 0x40);
+    assert_eq!(f8e4m3::MAX.to_bits(), 0x7E);
+    assert_eq!(f8e4m3::MIN.to_bits(), 0xFE);
+    assert_eq!(f8e4m3::NAN.to_bits(), 0x7F);
+    assert_eq!(f8e4m3::INFINITY.to_bits(), 0x7E);
+    assert_eq!(f8e4m3::MAX.as_f64(), 448.0);
+
+    assert_eq!(f8e5m2::ONE.to_bits(), 0x3C);
+    assert_eq!(f8e5m2::MAX.to_bits(), 0x7B);
+    assert_eq!(f8e5m2::INFINITY.to_bits(), 0x7C);
+    assert_eq!(f8e5m2::MAX.as_f64(), 57344.0);
+
+    assert_eq!(tf32::ONE.to_bits(), 0x1FC00);
+    assert_eq!(tf32::MAX.as_f64(), f32::from_bits(0x7F7FE000) as f64);
+    assert_eq!(tf32::INFINITY.to_bits(), 0x3FC00);
+}
+
+#[test]
+fn special_test() {
+    assert!(f8e4m3::NAN.is_nan());
+    assert!(f8e4m3::from_bits(0xFF).is_nan());
+    assert!(!f8e4m3::from_bits(0x7E).is_nan());
+    assert!(!f8e4m3::from_bits(0x78).is_special());
+    assert!(!f8e4m3::INFINITY.is_inf());
+
+    assert!(f8e5m2::NAN.is_nan());
+    assert!(f8e5m2::from_bits(0x7D).is_nan());
+    assert!(f8e5m2::INFINITY.is_inf());
+    assert!(f8e5m2::NEG_INFINITY.is_inf());
+    assert!(!f8e5m2::from_bits(0x78).is_special());
+}
+
+#[test]
+fn as_f64_test() {
+    for bits in 0..0x7F {
+        assert_eq!(f8e4m3::from_bits(bits).as_f64(), to_f64::<f8e4m3>(bits));
+        assert_eq!(f8e4m3::from_bits(bits | 0x80).as_f64(), -to_f64::<f8e4m3>(bits));
+    }
+    for bits in 0..0x7C {
+        assert_eq!(f8e5m2::from_bits(bits).as_f64(), to_f64::<f8e5m2>(bits));
+    }
+    assert_eq!(tf32::from_bits(1).as_f64(), 2f64.powi(-136));
+    assert!(f8e5m2::INFINITY.as_f64().is_inf());
+    assert!(f8e4m3::NAN.as_f64().is_nan());
+
+    // Wider formats may be denormal as an f64.
+    type F = CustomFloat<11, 20, FINITE>;
+    assert_eq!(F::from_bits(1).as_f64(), f64::from_bits(1 << 32));
+    assert_eq!(F::from_bits(1 << 20).as_f64(), f64::from_bits(1 << 52));
+}
+
+#[test]
+fn from_f64_test() {
+    for bits in 0..0x7F {
+        assert_eq!(f8e4m3::from_f64(to_f64::<f8e4m3>(bits)).to_bits(), bits);
+        assert_eq!(f8e4m3::from_f64(-to_f64::<f8e4m3>(bits)).to_bits(), bits | 0x80);
+    }
+    for bits in 0..0x7C {
+        assert_eq!(f8e5m2::from_f64(to_f64::<f8e5m2>(bits)).to_bits(), bits);
+    }
+
+    // Ties round to even, including into the next exponent.
+    assert_eq!(f8e4m3::from_f64(1.0625).to_bits(), 0x38);
+    assert_eq!(f8e4m3::from_f64(1.1875).to_bits(), 0x3A);
+    assert_eq!(f8e4m3::from_f64(1.9375).to_bits(), 0x40);
+    assert_eq!(f8e4m3::from_f64(2f64.powi(-10)).to_bits(), 0);
+    assert_eq!(f8e4m3::from_f64(3.0 * 2f64.powi(-10)).to_bits(), 2);
+    assert_eq!(f8e4m3::from_f64(1e-300).to_bits(), 0);
+
+    // Formats without infinity saturate.
+    assert_eq!(f8e4m3::from_f64(464.0).to_bits(), 0x7E);
+    assert_eq!(f8e4m3::from_f64(f64::INFINITY).to_bits(), 0x7E);
+    assert_eq!(f8e4m3::from_f64(f64::NEG_INFINITY).to_bits(), 0xFE);
+    assert!(f8e4m3::from_f64(f64::NAN).is_nan());
+    assert_eq!(f8e5m2::from_f64(61440.0).to_bits(), 0x7C);
+    assert_eq!(f8e5m2::from_f64(61439.0).to_bits(), 0x7B);
+}
+
+#[test]
+fn compare_test() {
+    let min = f8e4m3::from_bits(1);
+    assert!(f8e4m3::ZERO < min);
+    assert!(-min < f8e4m3::ZERO);
+    assert_eq!(f8e4m3::ZERO, -f8e4m3::ZERO);
+    assert_ne!(f8e4m3::NAN, f8e4m3::NAN);
+    assert!(f8e4m3::MIN < f8e4m3::MAX);
+    assert!(f8e5m2::NEG_INFINITY < f8e5m2::MIN);
+    assert!(f8e5m2::MAX < f8e5m2::INFINITY);
+}
+
+#[test]
+fn math_tests() {
+    assert_eq!(f8e4m3::ONE + f8e4m3::ONE, f8e4m3::TWO);
+    assert_eq!(f8e4m3::ONE * f8e4m3::ONE, f8e4m3::ONE);
+    assert_eq!(f8e4m3::ONE / f8e4m3::ONE, f8e4m3::ONE);
+    assert_eq!(f8e4m3::ONE - f8e4m3::ONE, f8e4m3::ZERO);
+    assert_eq!(f8e4m3::ONE % f8e4m3::ONE, f8e4m3::ZERO);
+    assert_eq!(f8e4m3::MAX + f8e4m3::MAX, f8e4m3::MAX);
+    assert!((f8e5m2::MAX + f8e5m2::MAX).is_inf());
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "decimal")]
+
+use lexical_util::decimal::{decimal128, decimal32, decimal64, DecimalFloat};
+
+#[test]
+fn constants_test() {
+    assert_eq!(decimal32::MIN_EXPONENT, -101);
+    assert_eq!(decimal32::MAX_EXPONENT, 90);
+    assert_eq!(decimal32::MAX_COEFFICIENT, 9_999_999);
+    assert_eq!(decimal64::MIN_EXPONENT, -398);
+    assert_eq!(decimal64::MAX_EXPONENT, 369);
+    assert_eq!(decimal128::MIN_EXPONENT, -6176);
+    assert_eq!(decimal128::MAX_EXPONENT, 6111);
+    assert_eq!(decimal128::MAX_COEFFICIENT, 10u128.pow(34) - 1);
+}
+
+#[test]
+fn from_parts_test() {
+    let bits = |x: Option<decimal128>| x.unwrap().to_bits();
+    assert_eq!(decimal32::from_parts(false, 1, 0).unwrap().to_bits(), 0x32800001);
+    assert_eq!(decimal64::from_parts(false, 1, 0).unwrap().to_bits(), 0x31C0000000000001);
+    assert_eq!(bits(decimal128::from_parts(false, 1, 0)), 0x30400000000000000000000000000001);
+    assert_eq!(bits(decimal128::from_parts(true, 150, -2)), 0xB03C0000000000000000000000000096);
+    assert_eq!(bits(decimal128::from_parts(false, 1, -6176)), 1);
+
+    // The largest coefficients use the second form of the encoding.
+    assert_eq!(decimal32::from_parts(false, 9_999_999, 90).unwrap().to_bits(), 0x77F8967F);
+    let max = decimal128::MAX_COEFFICIENT;
+    assert_eq!(bits(decimal128::from_parts(false, max, 6111)), 0x5FFFED09BEAD87C0378D8E63FFFFFFFF);
+
+    // Out of range values are not rounded or clamped.
+    assert!(decimal32::from_parts(false, 10_000_000, 0).is_none());
+    assert!(decimal32::from_parts(false, 1, 91).is_none());
+    assert!(decimal32::from_parts(false, 1, -102).is_none());
+}
+
+#[test]
+fn decode_test() {
+    let value = decimal32::from_bits(0x77F8967F);
+    assert_eq!((value.coefficient(), value.exponent()), (9_999_999, 90));
+    let value = decimal64::from_bits(0xB180000000000096);
+    assert!(value.is_sign_negative());
+    assert_eq!((value.coefficient(), value.exponent()), (150, -2));
+
+    // Non-canonical coefficients are 0.
+    let value = decimal128::from_bits(0x6FFFED09BEAD87C0378D8E63FFFFFFFF);
+    assert_eq!((value.coefficient(), value.exponent()), (0, 2015));
+    let value = decimal128::from_bits(0x3041ED09BEAD87C0378D8E6400000000);
+    assert_eq!((value.coefficient(), value.exponent()), (0, 0));
+}
+
+#[test]
+fn special_test() {
+    assert!(decimal32::NAN.is_nan());
+    assert!(!decimal32::NAN.is_snan());
+    assert!(decimal64::from_bits(0x7E00000000000000).is_snan());
+    assert!(decimal128::INFINITY.is_inf());
+    assert!(decimal128::NEG_INFINITY.is_sign_negative());
+    assert!(!decimal128::INFINITY.is_finite());
+    assert_eq!(decimal32::INFINITY.to_bits(), 0x78000000);
+    assert_eq!(decimal32::NAN.to_bits(), 0x7C000000);
+    assert_eq!(decimal32::NAN.coefficient(), 0);
+    assert!(decimal32::from_bits(0).is_finite());
+}
+
+#[test]
+fn normalize_test() {
+    let normalize = |c, e| {
+        let value = decimal64::from_parts(false, c, e).unwrap().normalize();
+        (value.coefficient(), value.exponent())
+    };
+    assert_eq!(normalize(150, -2), (15, -1));
+    assert_eq!(normalize(1200, 0), (12, 2));
+    assert_eq!(normalize(0, -50), (0, 0));
+    assert_eq!(normalize(7, 3), (7, 3));
+    // The exponent cannot exceed the maximum exponent.
+    assert_eq!(normalize(1000, 368), (100, 369));
+    assert!(decimal64::NAN.normalize().is_nan());
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "double-double")]
+
+use lexical_util::double_double::DoubleDouble;
+
+#[test]
+fn normalize_test() {
+    let value = DoubleDouble::new(1.0, 2f64.powi(-60)).normalize();
+    assert_eq!(value, DoubleDouble::new(1.0, 2f64.powi(-60)));
+
+    // The sum is exact, so the low float is the rounding error.
+    let value = DoubleDouble::new(1.0, 1.0 + f64::EPSILON).normalize();
+    assert_eq!(value, DoubleDouble::new(2.0, f64::EPSILON));
+    let value = DoubleDouble::new(2f64.powi(-60), 1.0).normalize();
+    assert_eq!(value, DoubleDouble::new(1.0, 2f64.powi(-60)));
+    let value = DoubleDouble::new(1.0, -1.0).normalize();
+    assert_eq!(value, DoubleDouble::new(0.0, 0.0));
+
+    // Ties in the sum round to even.
+    let value = DoubleDouble::new(1.0 + f64::EPSILON, f64::EPSILON / 2.0).normalize();
+    assert_eq!(value, DoubleDouble::new(1.0 + 2.0 * f64::EPSILON, -f64::EPSILON / 2.0));
+
+    // Non-finite sums have a low float of 0.
+    let value = DoubleDouble::new(f64::MAX, f64::MAX).normalize();
+    assert_eq!(value, DoubleDouble::new(f64::INFINITY, 0.0));
+    assert!(DoubleDouble::new(f64::NAN, 1.0).normalize().hi().is_nan());
+}
+
+#[test]
+fn as_f64_test() {
+    assert_eq!(DoubleDouble::new(0.1, -5.551115123125783e-18).as_f64(), 0.1);
+    assert_eq!(DoubleDouble::new(1.0, f64::EPSILON / 2.0).as_f64(), 1.0);
+    assert_eq!(DoubleDouble::from(0.5), DoubleDouble::new(0.5, 0.0));
+    assert_eq!(DoubleDouble::default(), DoubleDouble::new(0.0, 0.0));
+}
@@ -0,0 +1,116 @@
+use lexical_util::error::Error;
+use lexical_util::format::{
+    DynamicFormat,
+    NumberFormatBuilder,
+    CASE_SENSITIVE_SPECIAL,
+    NO_SPECIAL,
+    STANDARD,
+};
+
+#[test]
+fn standard_test() {
+    let format = DynamicFormat::new(STANDARD).unwrap();
+    assert_eq!(format, DynamicFormat::default());
+    assert_eq!(format.radix(), 10);
+    assert_eq!(format.mantissa_radix(), 10);
+    assert_eq!(format.exponent_base(), 10);
+    assert_eq!(format.exponent_radix(), 10);
+    assert_eq!(format.digit_separator(), 0);
+    assert_eq!(NumberFormatBuilder::new().build_dynamic(), Ok(format));
+}
+
+#[test]
+fn canonical_test() {
+    // Explicit exponent base and radix identical to the mantissa radix
+    // are the same format.
+    let format = NumberFormatBuilder::decimal();
+    assert_ne!(format, STANDARD);
+    assert_eq!(DynamicFormat::new(format), DynamicFormat::new(STANDARD));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn radix_test() {
+    let format = DynamicFormat::new(NumberFormatBuilder::hexadecimal()).unwrap();
+    assert_eq!(format.radix(), 16);
+    assert_eq!(format.exponent_base(), 16);
+    assert_eq!(format.exponent_radix(), 16);
+
+    let format = NumberFormatBuilder::new().radix(3).build();
+    if cfg!(feature = "radix") {
+        assert_eq!(DynamicFormat::new(format).unwrap().radix(), 3);
+    } else {
+        assert_eq!(DynamicFormat::new(format), Err(Error::InvalidMantissaRadix));
+    }
+
+    let format = NumberFormatBuilder::new().radix(16).exponent_base(core::num::NonZeroU8::new(2));
+    let format = format.build_dynamic().unwrap();
+    assert_eq!(format.radix(), 16);
+    assert_eq!(format.exponent_base(), 2);
+    assert_eq!(format.exponent_radix(), 16);
+}
+
+#[test]
+fn invalid_test() {
+    let format = STANDARD | NO_SPECIAL | CASE_SENSITIVE_SPECIAL;
+    if cfg!(feature = "format") {
+        assert_eq!(DynamicFormat::new(format), Err(Error::InvalidSpecial));
+    } else {
+        assert_eq!(DynamicFormat::new(format), Err(Error::InvalidFlags));
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn predefined_test() {
+    use lexical_util::format;
+
+    let json = DynamicFormat::new(format::JSON).unwrap();
+    assert_eq!(json.packed(), format::JSON);
+    assert_eq!(DynamicFormat::new(format::YAML), Ok(json));
+
+    let rust = DynamicFormat::new(format::RUST_LITERAL).unwrap();
+    assert_eq!(rust.digit_separator(), b'_');
+
+    let format = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'!'))
+        .internal_digit_separator(true);
+    let format = format.build_dynamic().unwrap();
+    assert_eq!(format.digit_separator(), b'!');
+
+    #[cfg(feature = "power-of-two")]
+    {
+        let format = NumberFormatBuilder::new().base_prefix(core::num::NonZeroU8::new(b'x'));
+        assert_eq!(format.build_dynamic().unwrap().base_prefix(), b'x');
+    }
+}
+
+#[test]
+fn format_trait_test() {
+    use lexical_util::format::{Format, NumberFormat};
+
+    // Runtime formats must have the same properties as compile-time formats.
+    fn check<const FORMAT: u128>() {
+        let expected = NumberFormat::<{ FORMAT }> {};
+        let format = DynamicFormat::new(FORMAT).unwrap();
+        assert_eq!(Format::is_valid(&format), expected.is_valid());
+        assert_eq!(Format::required_digits(&format), expected.required_digits());
+        assert_eq!(Format::no_special(&format), expected.no_special());
+        assert_eq!(Format::internal_digit_separator(&format), expected.internal_digit_separator());
+        assert_eq!(Format::digit_grouping(&format), expected.digit_grouping());
+        assert_eq!(Format::digit_separator(&format), expected.digit_separator());
+        assert_eq!(Format::base_prefix(&format), expected.base_prefix());
+        assert_eq!(Format::exponent_base(&format), expected.exponent_base());
+        assert_eq!(Format::digit_separator_flags(&format), expected.digit_separator_flags());
+        assert_eq!(Format::required_digits(&expected), expected.required_digits());
+    }
+
+    check::<{ STANDARD }>();
+    #[cfg(feature = "format")]
+    {
+        use lexical_util::format;
+        check::<{ format::JSON }>();
+        check::<{ format::RUST_LITERAL }>();
+        check::<{ format::PYTHON_LITERAL }>();
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "f128")]
+
+use lexical_util::f128::f128;
+use lexical_util::num::Float;
+use proptest::prelude::*;
+use quickcheck::quickcheck;
+
+#[test]
+fn as_f64_test() {
+    assert_eq!(f128::from_bits(1).as_f64(), 0.0);
+    assert_eq!(f128::ZERO.as_f64(), 0.0f64);
+    assert_eq!(f128::ZERO.to_bits(), 0);
+    assert_eq!(f128::ONE.as_f64(), 1.0f64);
+    assert_eq!(f128::ONE.to_bits(), 0x3FFF << 112);
+    assert_eq!(f128::TWO.as_f64(), 2.0f64);
+    assert_eq!(f128::TWO.to_bits(), 0x4000 << 112);
+    assert_eq!(f128::from_bits(0x3FFE << 112).as_f64(), 0.5f64);
+    assert!(f128::NAN.as_f64().is_nan());
+    assert!(f128::INFINITY.as_f64().is_inf());
+    assert!(f128::NEG_INFINITY.as_f64().is_inf());
+
+    // Values outside the range of f64 round to 0 or infinity.
+    assert_eq!(f128::from_bits(0x43FF << 112).as_f64(), f64::INFINITY);
+    assert_eq!(f128::from_bits(0x3BCB << 112).as_f64(), 0.0);
+    assert_eq!(f128::from_bits(0x3BCC << 112).as_f64(), 0.0);
+    assert_eq!(f128::from_bits((0x3BCC << 112) | 1).as_f64(), 5e-324);
+    assert_eq!(f128::from_bits(0x3BCD << 112).as_f64(), 5e-324);
+
+    // Ties round to even.
+    let one_and_half_ulp = (0x3FFF << 112) | (1 << 59);
+    assert_eq!(f128::from_bits(one_and_half_ulp).as_f64(), 1.0);
+    let three_halves_ulp = (0x3FFF << 112) | (3 << 59);
+    assert_eq!(f128::from_bits(three_halves_ulp).as_f64(), 1.0000000000000004);
+    let max = f128::from_f64(f64::MAX).to_bits();
+    assert_eq!(f128::from_bits(max + (1 << 59)).as_f64(), f64::INFINITY);
+    assert_eq!(f128::from_bits(max + (1 << 59) - 1).as_f64(), f64::MAX);
+}
+
+#[test]
+fn from_f64_test() {
+    assert_eq!(f128::from_f64(0.0).to_bits(), 0);
+    assert_eq!(f128::from_f64(-0.0).to_bits(), 1 << 127);
+    assert_eq!(f128::from_f64(1.0).to_bits(), 0x3FFF << 112);
+    assert_eq!(f128::from_f64(5e-324).to_bits(), 0x3BCD << 112);
+    assert_eq!(f128::from_f64(f64::MAX).to_bits(), (0x43FE << 112) | (0xFFFFFFFFFFFFF << 60));
+    assert!(f128::from_f64(f64::NAN).is_nan());
+    assert!(f128::from_f64(f64::INFINITY).is_inf());
+    assert!(f128::from_f64(f64::NEG_INFINITY).is_inf());
+}
+
+#[test]
+fn compare_test() {
+    let min = f128::from_bits(1);
+    assert!(f128::ZERO < min);
+    assert!(-min < f128::ZERO);
+    assert!(f128::ONE < f128::from_bits(f128::ONE.to_bits() + 1));
+    assert_eq!(f128::ZERO, -f128::ZERO);
+    assert_ne!(f128::NAN, f128::NAN);
+    assert!(f128::NEG_INFINITY < -f128::ONE);
+    assert_eq!(f128::NAN.partial_cmp(&f128::ONE), None);
+}
+
+#[test]
+fn math_tests() {
+    assert_eq!(f128::ONE + f128::ONE, f128::TWO);
+    assert_eq!(f128::ONE * f128::ONE, f128::ONE);
+    assert_eq!(f128::ONE / f128::ONE, f128::ONE);
+    assert_eq!(f128::ONE - f128::ONE, f128::ZERO);
+    assert_eq!(f128::ONE % f128::ONE, f128::ZERO);
+}
+
+quickcheck! {
+    #[cfg_attr(miri, ignore)]
+    fn f64_roundtrip_quickcheck(x: u64) -> bool {
+        let f = f64::from_bits(x);
+        if f.is_nan() {
+            f128::from_f64(f).as_f64().is_nan()
+        } else {
+            f128::from_f64(f).as_f64().to_bits() == x
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_roundtrip_proptest(x in u64::MIN..u64::MAX) {
+        let f = f64::from_bits(x);
+        if f.is_nan() {
+            prop_assert!(f128::from_f64(f).as_f64().is_nan());
+        } else {
+            prop_assert_eq!(f128::from_f64(f).as_f64().to_bits(), x);
+        }
+    }
+}
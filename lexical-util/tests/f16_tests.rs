@@ -31,6 +31,13 @@ fn from_f32_test() {
     assert!(f16::from_f32(f32::NAN).is_nan());
     assert!(f16::from_f32(f32::INFINITY).is_inf());
     assert!(f16::from_f32(f32::NEG_INFINITY).is_inf());
+
+    // Floats above the largest finite half-precision float overflow to infinity.
+    assert_eq!(f16::from_f32(65504.0).to_bits(), 0x7BFF);
+    assert_eq!(f16::from_f32(65536.0), f16::INFINITY);
+    assert_eq!(f16::from_f32(1e5), f16::INFINITY);
+    assert_eq!(f16::from_f32(-1e5), f16::NEG_INFINITY);
+    assert_eq!(f16::from_f32(f32::MAX), f16::INFINITY);
 }
 
 #[test]
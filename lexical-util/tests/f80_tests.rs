@@ -0,0 +1,87 @@
+#![cfg(feature = "f80")]
+
+use lexical_util::f80::f80;
+use lexical_util::num::Float;
+use proptest::prelude::*;
+
+#[test]
+fn as_f64_test() {
+    assert_eq!(f80::ZERO.as_f64(), 0.0f64);
+    assert_eq!(f80::ONE.as_f64(), 1.0f64);
+    assert_eq!(f80::ONE.to_bits(), 0x3FFF8000000000000000);
+    assert_eq!(f80::TWO.as_f64(), 2.0f64);
+    assert_eq!(f80::from_bits(0xBFFE8000000000000000).as_f64(), -0.5f64);
+    assert!(f80::NAN.as_f64().is_nan());
+    assert!(f80::INFINITY.as_f64().is_inf());
+    assert!(f80::NEG_INFINITY.as_f64().is_inf());
+
+    // Values outside the range of f64 round to 0 or infinity.
+    assert_eq!(f80::MAX.as_f64(), f64::INFINITY);
+    assert_eq!(f80::from_bits(1).as_f64(), 0.0);
+    assert_eq!(f80::from_bits(0x3BCC8000000000000000).as_f64(), 0.0);
+    assert_eq!(f80::from_bits(0x3BCC8000000000000001).as_f64(), 5e-324);
+    assert_eq!(f80::from_bits(0x3BCD8000000000000000).as_f64(), 5e-324);
+
+    // Ties round to even.
+    assert_eq!(f80::from_bits(0x3FFF8000000000000400).as_f64(), 1.0);
+    assert_eq!(f80::from_bits(0x3FFF8000000000000C00).as_f64(), 1.0000000000000004);
+
+    // Non-canonical encodings use the explicit integer bit.
+    assert_eq!(f80::from_bits(0x3FFF4000000000000000).as_f64(), 0.5);
+    assert_eq!(f80::from_bits(0x00008000000000000000), f80::from_bits(0x00018000000000000000));
+}
+
+#[test]
+fn from_f64_test() {
+    assert_eq!(f80::from_f64(0.0).to_bits(), 0);
+    assert_eq!(f80::from_f64(-0.0).to_bits(), 1 << 79);
+    assert_eq!(f80::from_f64(1.0).to_bits(), 0x3FFF8000000000000000);
+    assert_eq!(f80::from_f64(5e-324).to_bits(), 0x3BCD8000000000000000);
+    assert_eq!(f80::from_f64(f64::MAX).to_bits(), 0x43FEFFFFFFFFFFFFF800);
+    assert!(f80::from_f64(f64::NAN).is_nan());
+    assert!(f80::from_f64(f64::INFINITY).is_inf());
+    assert_eq!(f80::from_f64(f64::NEG_INFINITY).to_bits(), 0xFFFF8000000000000000);
+}
+
+#[test]
+fn bytes_test() {
+    let bytes = [0x35, 0xC2, 0x68, 0x21, 0xA2, 0xDA, 0x0F, 0xC9, 0x00, 0x40];
+    let pi = f80::from_le_bytes(bytes);
+    assert_eq!(pi.to_bits(), 0x4000C90FDAA22168C235);
+    assert_eq!(pi.to_le_bytes(), bytes);
+    assert_eq!(f80::from_bits(u128::MAX).to_bits(), (1 << 80) - 1);
+}
+
+#[test]
+fn compare_test() {
+    let min = f80::from_bits(1);
+    assert!(f80::ZERO < min);
+    assert!(-min < f80::ZERO);
+    assert!(f80::ONE < f80::from_bits(f80::ONE.to_bits() + 1));
+    assert_eq!(f80::ZERO, -f80::ZERO);
+    assert_ne!(f80::NAN, f80::NAN);
+    assert!(f80::NEG_INFINITY < f80::MIN);
+    assert!(f80::MAX < f80::INFINITY);
+}
+
+#[test]
+fn math_tests() {
+    assert_eq!(f80::ONE + f80::ONE, f80::TWO);
+    assert_eq!(f80::ONE * f80::ONE, f80::ONE);
+    assert_eq!(f80::ONE / f80::ONE, f80::ONE);
+    assert_eq!(f80::ONE - f80::ONE, f80::ZERO);
+    assert_eq!(f80::ONE % f80::ONE, f80::ZERO);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_roundtrip_proptest(x in u64::MIN..u64::MAX) {
+        let f = f64::from_bits(x);
+        if f.is_nan() {
+            prop_assert!(f80::from_f64(f).as_f64().is_nan());
+        } else {
+            prop_assert_eq!(f80::from_f64(f).as_f64().to_bits(), x);
+        }
+    }
+}
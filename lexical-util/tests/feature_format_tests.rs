@@ -1,7 +1,9 @@
 #![cfg(feature = "format")]
 
 use core::num;
+use lexical_util::error::Error;
 use lexical_util::format;
+use lexical_util::grouping::DigitGrouping;
 
 #[test]
 fn ignore_test() {
@@ -29,6 +31,10 @@ fn ignore_test() {
     assert_eq!(fmt.case_sensitive_base_prefix(), false);
     #[cfg(feature = "power-of-two")]
     assert_eq!(fmt.case_sensitive_base_suffix(), false);
+    assert_eq!(fmt.unicode_digits(), false);
+    assert_eq!(fmt.leading_whitespace(), false);
+    assert_eq!(fmt.trailing_whitespace(), false);
+    assert_eq!(fmt.unicode_whitespace(), false);
     assert_eq!(fmt.integer_internal_digit_separator(), true);
     assert_eq!(fmt.fraction_internal_digit_separator(), true);
     assert_eq!(fmt.exponent_internal_digit_separator(), true);
@@ -103,6 +109,9 @@ fn flags_test() {
     test_flag!(case_sensitive_base_prefix, CASE_SENSITIVE_BASE_PREFIX);
     #[cfg(feature = "power-of-two")]
     test_flag!(case_sensitive_base_suffix, CASE_SENSITIVE_BASE_SUFFIX);
+    test_flag!(unicode_digits, UNICODE_DIGITS);
+    test_flag!(leading_whitespace, LEADING_WHITESPACE);
+    test_flag!(trailing_whitespace, TRAILING_WHITESPACE);
     test_flag!(integer_internal_digit_separator, INTEGER_INTERNAL_DIGIT_SEPARATOR);
     test_flag!(fraction_internal_digit_separator, FRACTION_INTERNAL_DIGIT_SEPARATOR);
     test_flag!(exponent_internal_digit_separator, EXPONENT_INTERNAL_DIGIT_SEPARATOR);
@@ -118,6 +127,50 @@ fn flags_test() {
     test_flag!(special_digit_separator, SPECIAL_DIGIT_SEPARATOR);
 }
 
+#[test]
+fn digit_grouping_test() {
+    const FORMAT: u128 = format::NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b','))
+        .integer_internal_digit_separator(true)
+        .digit_grouping(DigitGrouping::Indian)
+        .build();
+    let fmt = format::NumberFormat::<FORMAT> {};
+    assert_eq!(fmt.is_valid(), true);
+    assert_eq!(fmt.digit_grouping(), DigitGrouping::Indian);
+    assert_eq!(FORMAT & format::DIGIT_GROUPING, format::INDIAN_DIGIT_GROUPING);
+    assert_eq!(
+        format::NumberFormatBuilder::rebuild(FORMAT).get_digit_grouping(),
+        DigitGrouping::Indian
+    );
+
+    const INVALID: u128 = format::NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b','))
+        .fraction_internal_digit_separator(true)
+        .digit_grouping(DigitGrouping::Thousands)
+        .build();
+    let fmt = format::NumberFormat::<INVALID> {};
+    assert_eq!(fmt.is_valid(), false);
+    assert_eq!(fmt.error(), Error::InvalidDigitGrouping);
+}
+
+#[test]
+fn whitespace_flags_test() {
+    const FORMAT: u128 = format::NumberFormatBuilder::new()
+        .leading_whitespace(true)
+        .unicode_whitespace(true)
+        .build();
+    let fmt = format::NumberFormat::<FORMAT> {};
+    assert_eq!(fmt.is_valid(), true);
+    assert_eq!(fmt.leading_whitespace(), true);
+    assert_eq!(fmt.trailing_whitespace(), false);
+    assert_eq!(fmt.unicode_whitespace(), true);
+
+    // Unicode whitespace requires leading or trailing whitespace.
+    const INVALID: u128 = format::NumberFormatBuilder::new().unicode_whitespace(true).build();
+    let fmt = format::NumberFormat::<INVALID> {};
+    assert_eq!(fmt.error(), Error::InvalidWhitespaceFlags);
+}
+
 #[test]
 fn constants_test() {
     // Don't check the actual values: just check they're defined.
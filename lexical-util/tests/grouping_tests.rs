@@ -0,0 +1,98 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::{DynamicFormat, NumberFormat, NumberFormatBuilder};
+#[cfg(feature = "format")]
+use lexical_util::grouping::check_grouping;
+use lexical_util::grouping::{check_digit_grouping, DigitGrouping};
+
+fn check(digits: &[u8], grouping: DigitGrouping) -> Result<(), Error> {
+    check_digit_grouping(digits, b',', grouping, 0)
+}
+
+#[test]
+fn digit_grouping_test() {
+    for grouping in [
+        DigitGrouping::None,
+        DigitGrouping::Thousands,
+        DigitGrouping::Indian,
+        DigitGrouping::Myriad,
+    ] {
+        assert_eq!(DigitGrouping::from_format(grouping.flags()), grouping);
+    }
+    assert_eq!(DigitGrouping::Thousands.group_sizes(), (3, 3));
+    assert_eq!(DigitGrouping::Indian.group_sizes(), (2, 3));
+    assert_eq!(DigitGrouping::Myriad.group_sizes(), (4, 4));
+}
+
+#[test]
+fn check_thousands_test() {
+    let grouping = DigitGrouping::Thousands;
+    assert_eq!(check(b"1,234,567", grouping), Ok(()));
+    assert_eq!(check(b"123,456", grouping), Ok(()));
+    assert_eq!(check(b"1234567", grouping), Ok(()));
+    assert_eq!(check(b"", grouping), Ok(()));
+    assert_eq!(check(b"1,23,4567", grouping), Err(Error::InvalidDigitGroup(4)));
+    assert_eq!(check(b"12,34,567", grouping), Err(Error::InvalidDigitGroup(5)));
+    assert_eq!(check(b"1234,567", grouping), Err(Error::InvalidDigitGroup(3)));
+    assert_eq!(check(b"1,2345", grouping), Err(Error::InvalidDigitGroup(5)));
+    assert_eq!(check(b"1,23", grouping), Err(Error::InvalidDigitGroup(4)));
+
+    // Leading, trailing and consecutive separators don't form groups.
+    assert_eq!(check(b",1,234,", grouping), Ok(()));
+    assert_eq!(check(b"1,,234", grouping), Ok(()));
+    assert_eq!(check(b",,", grouping), Ok(()));
+
+    // Indexes are relative to the start of the number.
+    let result = check_digit_grouping(b"1,23,4567", b',', grouping, 1);
+    assert_eq!(result, Err(Error::InvalidDigitGroup(5)));
+}
+
+#[test]
+fn check_indian_test() {
+    let grouping = DigitGrouping::Indian;
+    assert_eq!(check(b"12,34,567", grouping), Ok(()));
+    assert_eq!(check(b"1,23,45,678", grouping), Ok(()));
+    assert_eq!(check(b"1,234", grouping), Ok(()));
+    assert_eq!(check(b"1234567", grouping), Ok(()));
+    assert_eq!(check(b"1,234,567", grouping), Err(Error::InvalidDigitGroup(4)));
+    assert_eq!(check(b"123,456", grouping), Err(Error::InvalidDigitGroup(2)));
+    assert_eq!(check(b"1,2,345", grouping), Err(Error::InvalidDigitGroup(3)));
+    assert_eq!(check(b"12,3456", grouping), Err(Error::InvalidDigitGroup(6)));
+}
+
+#[test]
+fn check_myriad_test() {
+    let grouping = DigitGrouping::Myriad;
+    assert_eq!(check(b"123,4567", grouping), Ok(()));
+    assert_eq!(check(b"1,2345,6789", grouping), Ok(()));
+    assert_eq!(check(b"1,234", grouping), Err(Error::InvalidDigitGroup(5)));
+    assert_eq!(check(b"12345,6789", grouping), Err(Error::InvalidDigitGroup(4)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn check_grouping_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b','))
+        .integer_internal_digit_separator(true)
+        .digit_grouping(DigitGrouping::Thousands)
+        .build();
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert_eq!(check_grouping(b"-1,234.5", format), Ok(()));
+    assert_eq!(check_grouping(b"1,234e5", format), Ok(()));
+    assert_eq!(check_grouping(b"inf", format), Ok(()));
+    assert_eq!(check_grouping(b"+12,34", format), Err(Error::InvalidDigitGroup(6)));
+    assert_eq!(check_grouping(b"1,2345.5", format), Err(Error::InvalidDigitGroup(5)));
+
+    // Without a digit grouping, any groups are valid.
+    const UNGROUPED: u128 =
+        NumberFormatBuilder::rebuild(FORMAT).digit_grouping(DigitGrouping::None).build();
+    assert_eq!(check_grouping(b"12,34", NumberFormat::<{ UNGROUPED }> {}), Ok(()));
+
+    // Runtime formats check the same groups.
+    let format = DynamicFormat::new(FORMAT).unwrap();
+    assert_eq!(check_grouping(b"-1,234.5", format), Ok(()));
+    assert_eq!(check_grouping(b"+12,34", format), Err(Error::InvalidDigitGroup(6)));
+}
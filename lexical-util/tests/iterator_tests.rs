@@ -5,17 +5,16 @@ use lexical_util::iterator::{AsBytes, Bytes, BytesIter};
 #[test]
 #[cfg(not(feature = "format"))]
 fn digits_iterator_test() {
-    use lexical_util::format::STANDARD;
-
-    type Digits<'a> = Bytes<'a, { STANDARD }>;
-    assert!(Digits::IS_CONTIGUOUS);
+    use lexical_util::format::{NumberFormat, STANDARD};
 
+    let format = NumberFormat::<{ STANDARD }> {};
     let digits = b"12345";
-    let mut byte1 = Digits::new(digits);
-    let mut byte2 = Digits::new(digits);
+    let mut byte1 = Bytes::new(digits, format);
+    let mut byte2 = Bytes::new(digits, format);
+    assert!(byte1.is_contiguous());
     assert!(byte1.integer_iter().eq(byte2.integer_iter()));
 
-    let mut byte = digits.bytes::<{ STANDARD }>();
+    let mut byte = digits.bytes(format);
     let mut iter = byte.integer_iter();
     assert_eq!(iter.as_slice(), &digits[..]);
     assert_eq!(iter.as_ptr(), digits.as_ptr());
@@ -37,14 +36,14 @@ fn digits_iterator_test() {
     assert_eq!(iter.peek(), None);
     assert_eq!(iter.next(), None);
 
-    let mut byte = digits.bytes::<{ STANDARD }>();
+    let mut byte = digits.bytes(format);
     let mut iter = byte.integer_iter();
     assert_eq!(iter.read::<u64>(), None);
     assert_eq!(iter.nth(4).unwrap(), &b'5');
     assert_eq!(iter.as_slice(), &digits[digits.len()..]);
     assert_eq!(iter.as_ptr(), digits[digits.len()..].as_ptr());
 
-    let mut byte = digits.bytes::<{ STANDARD }>();
+    let mut byte = digits.bytes(format);
     let mut iter = byte.integer_iter();
     assert_eq!(iter.peek(), Some(&b'1'));
     unsafe {
@@ -69,15 +68,14 @@ fn skip_iterator_test() {
         .build();
     const_assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
 
-    type Digits<'a> = Bytes<'a, { FORMAT }>;
-    assert!(!Digits::IS_CONTIGUOUS);
-
+    let format = NumberFormat::<{ FORMAT }> {};
     let digits = b"123_45";
-    let mut byte1 = Digits::new(digits);
-    let mut byte2 = Digits::new(digits);
+    let mut byte1 = Bytes::new(digits, format);
+    let mut byte2 = Bytes::new(digits, format);
+    assert!(!byte1.is_contiguous());
     assert!(byte1.integer_iter().eq(byte2.integer_iter()));
 
-    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut byte = digits.bytes(format);
     let mut iter = byte.integer_iter();
     assert_eq!(iter.as_slice(), &digits[..]);
     assert_eq!(iter.as_ptr(), digits.as_ptr());
@@ -93,7 +91,7 @@ fn skip_iterator_test() {
     assert_eq!(iter.cursor(), 2);
     assert_eq!(iter.current_count(), 2);
 
-    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut byte = digits.bytes(format);
     let mut iter = byte.integer_iter();
     assert_eq!(unsafe { iter.peek_unchecked() }, &b'1');
     assert_eq!(iter.peek(), Some(&b'1'));
@@ -108,7 +106,7 @@ fn skip_iterator_test() {
     assert_eq!(iter.next(), Some(&b'5'));
     assert_eq!(iter.next(), None);
 
-    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut byte = digits.bytes(format);
     let mut iter = byte.integer_iter();
     assert_eq!(iter.nth(4).unwrap(), &b'5');
     assert_eq!(iter.as_slice(), &digits[digits.len()..]);
@@ -1,6 +1,7 @@
 #![cfg(not(feature = "format"))]
 
 use lexical_util::format::{self, NumberFormat, STANDARD};
+use lexical_util::grouping::DigitGrouping;
 
 #[test]
 fn format_properties_test() {
@@ -33,6 +34,10 @@ fn format_properties_test() {
     assert_eq!(format.case_sensitive_exponent(), false);
     assert_eq!(format.case_sensitive_base_prefix(), false);
     assert_eq!(format.case_sensitive_base_suffix(), false);
+    assert_eq!(format.unicode_digits(), false);
+    assert_eq!(format.leading_whitespace(), false);
+    assert_eq!(format.trailing_whitespace(), false);
+    assert_eq!(format.unicode_whitespace(), false);
     assert_eq!(format.integer_internal_digit_separator(), false);
     assert_eq!(format.fraction_internal_digit_separator(), false);
     assert_eq!(format.exponent_internal_digit_separator(), false);
@@ -50,4 +55,5 @@ fn format_properties_test() {
     assert_eq!(format.exponent_consecutive_digit_separator(), false);
     assert_eq!(format.consecutive_digit_separator(), false);
     assert_eq!(format.special_digit_separator(), false);
+    assert_eq!(format.digit_grouping(), DigitGrouping::None);
 }
@@ -0,0 +1,58 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::punctuation::{
+    Punctuation,
+    ARABIC_DECIMAL_SEPARATOR,
+    MINUS_SIGN,
+    NARROW_NO_BREAK_SPACE,
+    NO_BREAK_SPACE,
+    TYPOGRAPHIC,
+};
+
+#[test]
+fn is_valid_test() {
+    assert!(Punctuation::new().is_valid());
+    assert!(Punctuation::new().is_empty());
+    assert!(TYPOGRAPHIC.is_valid());
+    assert!(!TYPOGRAPHIC.is_empty());
+    assert_eq!(TYPOGRAPHIC.get_decimal_points(), &[ARABIC_DECIMAL_SEPARATOR]);
+
+    let valid = |x: &'static [&'static [u8]]| Punctuation::new().digit_separators(x).is_valid();
+    assert!(valid(&[NARROW_NO_BREAK_SPACE, NO_BREAK_SPACE, b" ", b"'"]));
+    // U+1F4A9, which is 4 bytes.
+    assert!(valid(&[b"\xF0\x9F\x92\xA9"]));
+    assert!(!valid(&[b""]));
+    assert!(!valid(&[b"a"]));
+    assert!(!valid(&[b"1"]));
+    assert!(!valid(&[b"\t"]));
+    assert!(!valid(&[b"''"]));
+
+    // Decimal digits, and invalid or multiple UTF-8 characters.
+    assert!(!valid(&[b"\xD9\xA1"]));
+    assert!(!valid(&[b"\xEF\xBC\x90"]));
+    assert!(!valid(&[b"\xC2"]));
+    assert!(!valid(&[b"\xC3\xA9'"]));
+
+    // Alternatives may only stand for one punctuation.
+    let punctuation = Punctuation::new().minus_signs(&[MINUS_SIGN]).plus_signs(&[MINUS_SIGN]);
+    assert!(!punctuation.is_valid());
+    let punctuation = Punctuation::new().digit_separators(&[b"'", b"'"]);
+    assert!(punctuation.is_valid());
+}
+
+#[test]
+fn is_valid_for_test() {
+    assert!(TYPOGRAPHIC.is_valid_for(b'_', b'.', b'e'));
+    assert!(TYPOGRAPHIC.is_valid_for(b'\'', b'.', b'e'));
+    assert!(TYPOGRAPHIC.is_valid_for(0, b'.', b'e'));
+    assert!(!TYPOGRAPHIC.is_valid_for(b'_', b'\'', b'e'));
+
+    let punctuation = Punctuation::new().decimal_points(&[b","]);
+    assert!(punctuation.is_valid_for(b'_', b'.', b'e'));
+    assert!(!punctuation.is_valid_for(b',', b'.', b'e'));
+    assert!(!punctuation.is_valid_for(b'_', b'.', b','));
+    let punctuation = Punctuation::new().plus_signs(&[b"-"]);
+    assert!(!punctuation.is_valid_for(0, b'.', b'e'));
+    let punctuation = Punctuation::new().minus_signs(&[b"-"]);
+    assert!(punctuation.is_valid_for(0, b'.', b'e'));
+}
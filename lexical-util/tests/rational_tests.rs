@@ -0,0 +1,82 @@
+#![cfg(feature = "rational")]
+
+use lexical_util::rational::Rational;
+
+#[test]
+fn new_test() {
+    let value = Rational::new(true, vec![12], vec![18]);
+    assert_eq!(value, Rational::from_lowest_terms(true, vec![2], vec![3]));
+    assert!(value.is_negative());
+    assert!(!value.is_integer());
+
+    // Multi-limb values are reduced and normalized.
+    let value = Rational::new(false, vec![0, 6, 0], vec![0, 4]);
+    assert_eq!(value.numerator(), &[3]);
+    assert_eq!(value.denominator(), &[2]);
+
+    // Zero is always positive, with a denominator of 1.
+    let value = Rational::new(true, vec![0, 0], vec![7]);
+    assert_eq!(value, Rational::zero());
+    assert!(value.is_zero());
+    assert!(!value.is_negative());
+    assert!(value.is_integer());
+}
+
+#[test]
+#[should_panic]
+fn zero_denominator_test() {
+    Rational::new(false, vec![1], vec![0]);
+}
+
+#[test]
+fn from_powers_test() {
+    // 1e30
+    let value = Rational::from_powers(false, vec![1], &[(10, 30)]);
+    assert_eq!(value.numerator(), &[0x4674edea40000000, 0xc9f2c9cd0]);
+    assert_eq!(value.denominator(), &[1]);
+
+    // 15e-4 = 3/2000
+    let value = Rational::from_powers(true, vec![15], &[(10, -4)]);
+    assert_eq!(value, Rational::from_lowest_terms(true, vec![3], vec![2000]));
+
+    // 12 * 6^-2 * 2^3 = 8/3
+    let value = Rational::from_powers(false, vec![12], &[(6, -2), (2, 3)]);
+    assert_eq!(value, Rational::from_lowest_terms(false, vec![8], vec![3]));
+    assert_eq!(Rational::from_powers(false, vec![], &[(10, -5)]), Rational::zero());
+}
+
+#[test]
+fn from_float_test() {
+    let value = Rational::from_float(0.1f64).unwrap();
+    assert_eq!(value.numerator(), &[3602879701896397]);
+    assert_eq!(value.denominator(), &[36028797018963968]);
+    let value = Rational::from_float(-1.5f32).unwrap();
+    assert_eq!(value, Rational::from_lowest_terms(true, vec![3], vec![2]));
+    assert_eq!(Rational::from_float(-0.0f64), Some(Rational::zero()));
+
+    // The smallest denormal is 2^-1074.
+    let value = Rational::from_float(5e-324f64).unwrap();
+    assert_eq!(value.numerator(), &[1]);
+    assert_eq!(value.denominator().len(), 17);
+    assert_eq!(value.denominator()[16], 1 << 50);
+
+    assert_eq!(Rational::from_float(f64::NAN), None);
+    assert_eq!(Rational::from_float(f64::INFINITY), None);
+}
+
+#[test]
+fn limit_denominator_test() {
+    let pi = Rational::from_float(core::f64::consts::PI).unwrap();
+    let value = pi.limit_denominator(1000);
+    assert_eq!(value, Rational::from_lowest_terms(false, vec![355], vec![113]));
+    let value = pi.limit_denominator(1_000_000_000_000_000);
+    assert_eq!(value.numerator(), &[884279719003555]);
+    assert_eq!(value.denominator(), &[281474976710656]);
+
+    let value = Rational::from_float(-0.1f64).unwrap().limit_denominator(1000);
+    assert_eq!(value, Rational::from_lowest_terms(true, vec![1], vec![10]));
+    let value = Rational::from_float(0.1f64).unwrap().limit_denominator(1);
+    assert_eq!(value, Rational::zero());
+    let value = Rational::new(false, vec![3], vec![4]);
+    assert_eq!(value.limit_denominator(4), value);
+}
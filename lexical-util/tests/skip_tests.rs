@@ -1,15 +1,22 @@
 #![cfg(all(feature = "format", feature = "parse"))]
 
 use core::num;
-use lexical_util::format::{NumberFormat, NumberFormatBuilder};
+use lexical_util::format::{DynamicFormat, NumberFormat, NumberFormatBuilder};
 use lexical_util::iterator::AsBytes;
 use static_assertions::const_assert;
 
 fn skip_iter_eq<const FORMAT: u128>(input: &[u8], output: &[u8]) {
     // next is done in terms of peek, so we're safe here.
-    let mut input = input.bytes::<{ FORMAT }>();
-    let mut output = output.bytes::<{ FORMAT }>();
-    assert!(input.integer_iter().eq(output.integer_iter()));
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut input_bytes = input.bytes(format);
+    let mut output_bytes = output.bytes(format);
+    assert!(input_bytes.integer_iter().eq(output_bytes.integer_iter()));
+
+    // Runtime formats must skip the same digit separators.
+    let format = DynamicFormat::new(FORMAT).unwrap();
+    let mut input_bytes = input.bytes(format);
+    let mut output_bytes = output.bytes(format);
+    assert!(input_bytes.integer_iter().eq(output_bytes.integer_iter()));
 }
 
 #[test]
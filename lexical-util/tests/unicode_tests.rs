@@ -0,0 +1,106 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::code_unit::NON_ASCII;
+use lexical_util::error::Error;
+use lexical_util::punctuation::{Punctuation, MINUS_SIGN, NARROW_NO_BREAK_SPACE};
+use lexical_util::result::Result;
+use lexical_util::unicode::{parse_partial_unicode, parse_unicode, unicode_digit, Replacements};
+
+const DIGITS: Replacements = Replacements::new(true, Punctuation::new(), 0, b'.', b'e');
+
+fn collect(bytes: &[u8]) -> Result<(Vec<u8>, usize)> {
+    Ok((bytes.to_vec(), bytes.len()))
+}
+
+fn is_digit(c: u8) -> bool {
+    c.is_ascii_digit()
+}
+
+#[test]
+fn unicode_digit_test() {
+    assert_eq!(unicode_digit("٠".as_bytes()), Some((0, 0, 2)));
+    assert_eq!(unicode_digit("٩".as_bytes()), Some((9, 0, 2)));
+    assert_eq!(unicode_digit("۴".as_bytes()), Some((4, 1, 2)));
+    assert_eq!(unicode_digit("७".as_bytes()), Some((7, 2, 3)));
+    assert_eq!(unicode_digit("０１".as_bytes()), Some((0, 3, 3)));
+    assert_eq!(unicode_digit("９".as_bytes()), Some((9, 3, 3)));
+    assert_eq!(unicode_digit(b"1"), None);
+    assert_eq!(unicode_digit("é".as_bytes()), None);
+    assert_eq!(unicode_digit("ｚ".as_bytes()), None);
+    assert_eq!(unicode_digit(&"٩".as_bytes()[..1]), None);
+    assert_eq!(unicode_digit(&"７".as_bytes()[..2]), None);
+    assert_eq!(unicode_digit(b""), None);
+}
+
+#[test]
+fn parse_unicode_test() {
+    let parse = |x: &str| parse_unicode(x.as_bytes(), &DIGITS, is_digit, collect);
+    assert_eq!(parse("12a"), Ok((b"12a".to_vec(), 3)));
+    assert_eq!(parse("١٢٣"), Ok((b"123".to_vec(), 3)));
+    assert_eq!(parse("१२a٣"), Ok((b"12a".to_vec(), 3)));
+    assert_eq!(parse("１é"), Ok((vec![b'1', NON_ASCII], 2)));
+
+    // Mixed scripts are rejected at the byte index of the first digit.
+    assert_eq!(parse("١٢3"), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse("1٢"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse("۱١"), Err(Error::InvalidDigit(2)));
+
+    // Error indexes are mapped back to bytes.
+    let bytes = "١٢٣".as_bytes();
+    let error = |bytes: &[u8]| Err::<(), _>(Error::InvalidDigit(bytes.len() - 1));
+    assert_eq!(parse_unicode(bytes, &DIGITS, is_digit, error), Err(Error::InvalidDigit(4)));
+    let error = |bytes: &[u8]| Err::<(), _>(Error::Empty(bytes.len()));
+    assert_eq!(parse_unicode(bytes, &DIGITS, is_digit, error), Err(Error::Empty(6)));
+}
+
+#[test]
+fn parse_punctuation_test() {
+    const PUNCTUATION: Punctuation = Punctuation::new()
+        .digit_separators(&[NARROW_NO_BREAK_SPACE, b"'"])
+        .decimal_points(&[b","])
+        .minus_signs(&[MINUS_SIGN]);
+    let replacements = Replacements::new(false, PUNCTUATION, b'_', b'.', b'e');
+    let is_token = |c: u8| c.is_ascii_digit() || matches!(c, b'_' | b'.' | b'-');
+    let parse = |x: &str| parse_unicode(x.as_bytes(), &replacements, is_token, collect);
+    assert_eq!(parse("\u{2212}1\u{202F}000,5"), Ok((b"-1_000.5".to_vec(), 8)));
+    assert_eq!(parse("1'000"), Ok((b"1_000".to_vec(), 5)));
+    assert_eq!(parse("1_000.5"), Ok((b"1_000.5".to_vec(), 7)));
+
+    // Digits are only replaced if enabled.
+    assert_eq!(parse("١"), Ok((vec![NON_ASCII], 1)));
+
+    // Error indexes are mapped to the first byte of the alternative.
+    let error = |bytes: &[u8]| Err::<(), _>(Error::InvalidDigit(bytes.len() - 1));
+    let bytes = "1\u{202F}".as_bytes();
+    assert_eq!(parse_unicode(bytes, &replacements, is_token, error), Err(Error::InvalidDigit(1)));
+
+    // Without a digit separator, the alternatives are not replaced.
+    let replacements = Replacements::new(false, PUNCTUATION, 0, b'.', b'e');
+    let result = parse_unicode("1'0".as_bytes(), &replacements, is_token, collect);
+    assert_eq!(result, Ok((b"1'0".to_vec(), 3)));
+
+    // Alternatives cannot overlap the punctuation of the format.
+    let replacements = Replacements::new(false, PUNCTUATION, b',', b'.', b'e');
+    let result = parse_unicode(b"1", &replacements, is_token, collect);
+    assert_eq!(result, Err(Error::InvalidAlternativePunctuation));
+}
+
+#[test]
+fn parse_partial_unicode_test() {
+    let partial = |bytes: &[u8]| {
+        let count = bytes.iter().take_while(|&&c| is_digit(c)).count();
+        Ok((bytes.to_vec(), count))
+    };
+    assert_eq!(parse_partial_unicode(b"12a", &DIGITS, is_digit, partial), Ok((b"12a".to_vec(), 2)));
+    let bytes = "०१२ 3".as_bytes();
+    assert_eq!(parse_partial_unicode(bytes, &DIGITS, is_digit, partial), Ok((b"012 ".to_vec(), 9)));
+    let bytes = "１２３".as_bytes();
+    assert_eq!(parse_partial_unicode(bytes, &DIGITS, is_digit, partial), Ok((b"123".to_vec(), 9)));
+}
+
+#[test]
+fn parse_long_test() {
+    let string = "٥".repeat(1000);
+    let result = parse_unicode(string.as_bytes(), &DIGITS, is_digit, collect);
+    assert_eq!(result, Ok((vec![b'5'; 1000], 1000)));
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::{NumberFormat, NumberFormatBuilder};
+use lexical_util::whitespace::{is_whitespace, leading_whitespace, trailing_whitespace};
+#[cfg(feature = "format")]
+use lexical_util::whitespace::{parse_partial_whitespace, parse_whitespace};
+
+#[test]
+fn is_whitespace_test() {
+    for c in [b'\t', b'\n', b'\x0B', b'\x0C', b'\r', b' '] {
+        assert!(is_whitespace(c as u32, false));
+        assert!(is_whitespace(c as u32, true));
+    }
+    for point in [0x85, 0xA0, 0x1680, 0x2000, 0x200A, 0x2028, 0x2029, 0x202F, 0x205F, 0x3000] {
+        assert!(!is_whitespace(point, false));
+        assert!(is_whitespace(point, true));
+    }
+    for point in [0x08, 0x1C, b'1' as u32, 0x200B, 0xFEFF, u32::MAX] {
+        assert!(!is_whitespace(point, true));
+    }
+}
+
+#[test]
+fn leading_whitespace_test() {
+    assert_eq!(leading_whitespace(b" \t\n1 ", false), 3);
+    assert_eq!(leading_whitespace(b"1 ", false), 0);
+    assert_eq!(leading_whitespace(b"   ", false), 3);
+    assert_eq!(leading_whitespace("\u{3000} 1".as_bytes(), false), 0);
+    assert_eq!(leading_whitespace("\u{3000} 1".as_bytes(), true), 4);
+    assert_eq!(leading_whitespace("\u{A0}\u{2029}1".as_bytes(), true), 5);
+    assert_eq!(leading_whitespace(&"\u{3000}".as_bytes()[..2], true), 0);
+
+    let units: Vec<u16> = "\u{3000} 1".encode_utf16().collect();
+    assert_eq!(leading_whitespace(&units, false), 0);
+    assert_eq!(leading_whitespace(&units, true), 2);
+    let units: Vec<char> = " \u{85}1".chars().collect();
+    assert_eq!(leading_whitespace(&units, true), 2);
+}
+
+#[test]
+fn trailing_whitespace_test() {
+    assert_eq!(trailing_whitespace(b" 1\r\n", false), 2);
+    assert_eq!(trailing_whitespace(b" 1", false), 0);
+    assert_eq!(trailing_whitespace(b"", false), 0);
+    assert_eq!(trailing_whitespace("1 \u{202F}".as_bytes(), false), 0);
+    assert_eq!(trailing_whitespace("1 \u{202F}".as_bytes(), true), 4);
+    assert_eq!(trailing_whitespace("1\u{1680}\u{85}".as_bytes(), true), 5);
+    assert_eq!(trailing_whitespace("1é".as_bytes(), true), 0);
+    assert_eq!(trailing_whitespace(&"\u{3000}".as_bytes()[1..], true), 0);
+
+    let units: Vec<u32> = "1\u{2028}\t".chars().map(|c| c as u32).collect();
+    assert_eq!(trailing_whitespace(&units, true), 2);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_whitespace_test() {
+    const FORMAT: u128 =
+        NumberFormatBuilder::new().leading_whitespace(true).trailing_whitespace(true).build();
+    let format = NumberFormat::<FORMAT> {};
+    let collect = |bytes: &[u8]| Ok::<_, Error>(bytes.to_vec());
+    assert_eq!(parse_whitespace(b" 12\n", format, collect), Ok(b"12".to_vec()));
+    assert_eq!(parse_whitespace(b"1 2", format, collect), Ok(b"1 2".to_vec()));
+    assert_eq!(parse_whitespace(b"  ", format, collect), Ok(b"".to_vec()));
+
+    // Error indexes include the leading whitespace.
+    let error = |bytes: &[u8]| Err::<(), _>(Error::InvalidDigit(bytes.len()));
+    let result = parse_whitespace(b"  12 ", format, error);
+    assert_eq!(result, Err(Error::InvalidDigit(4)));
+
+    const LEADING: u128 = NumberFormatBuilder::new().leading_whitespace(true).build();
+    let format = NumberFormat::<LEADING> {};
+    assert_eq!(parse_whitespace(b" 12\n", format, collect), Ok(b"12\n".to_vec()));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_partial_whitespace_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .leading_whitespace(true)
+        .trailing_whitespace(true)
+        .unicode_whitespace(true)
+        .build();
+    let partial = |bytes: &[u8]| {
+        let count = bytes.iter().take_while(|c| c.is_ascii_digit()).count();
+        Ok::<_, Error>((bytes[..count].to_vec(), count))
+    };
+    let format = NumberFormat::<FORMAT> {};
+    let bytes = "\u{A0}12 \t3".as_bytes();
+    let result = parse_partial_whitespace(bytes, format, partial);
+    assert_eq!(result, Ok((b"12".to_vec(), 6)));
+    let result = parse_partial_whitespace(b"12", format, partial);
+    assert_eq!(result, Ok((b"12".to_vec(), 2)));
+
+    const LEADING: u128 = NumberFormatBuilder::new().leading_whitespace(true).build();
+    let format = NumberFormat::<LEADING> {};
+    let result = parse_partial_whitespace(b" 12 3", format, partial);
+    assert_eq!(result, Ok((b"12".to_vec(), 3)));
+}
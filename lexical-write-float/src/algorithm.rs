@@ -34,7 +34,7 @@ use crate::table::*;
 use lexical_util::bf16::bf16;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::format::{Format, NumberFormat, STANDARD};
 use lexical_util::num::{AsPrimitive, Float};
 use lexical_write_integer::decimal::DigitCount;
 use lexical_write_integer::write::WriteInteger;
@@ -46,9 +46,10 @@ use lexical_write_integer::write::WriteInteger;
 /// Safe as long as the float isn't special (NaN or Infinity), and `bytes`
 /// is large enough to hold the significant digits.
 #[inline]
-pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
+pub unsafe fn write_float<F: RawFloat, Fmt: Format>(
     float: F,
     bytes: &mut [u8],
+    format: Fmt,
     options: &Options,
 ) -> usize {
     debug_assert!(!float.is_special());
@@ -64,14 +65,14 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
     // in most cases.
 
     write_float!(
-        FORMAT,
+        format,
         sci_exp,
         options,
         write_float_scientific,
         write_float_positive_exponent,
         write_float_negative_exponent,
         generic => F,
-        args => bytes, fp, sci_exp, options,
+        args => bytes, fp, sci_exp, format, options,
     )
 }
 
@@ -81,15 +82,15 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
 ///
 /// Safe as long as `bytes` is large enough to hold the number of digits
 /// and the scientific notation's exponent digits.
-pub unsafe fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
+pub unsafe fn write_float_scientific<F: DragonboxFloat, Fmt: Format>(
     bytes: &mut [u8],
     fp: ExtendedFloat80,
     sci_exp: i32,
+    format: Fmt,
     options: &Options,
 ) -> usize {
     // Config options.
     debug_assert_eq!(count_factors(10, fp.mant), 0);
-    let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
     let decimal_point = options.decimal_point();
 
@@ -135,7 +136,7 @@ pub unsafe fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe since bytes must be large enough to store all digits.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent(bytes, &mut cursor, sci_exp, options.exponent(), format) };
 
     cursor
 }
@@ -147,10 +148,11 @@ pub unsafe fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
 ///
 /// Safe as long as `bytes` is large enough to hold the number of
 /// significant digits and the leading zeros.
-pub unsafe fn write_float_negative_exponent<F: DragonboxFloat, const FORMAT: u128>(
+pub unsafe fn write_float_negative_exponent<F: DragonboxFloat, Fmt: Format>(
     bytes: &mut [u8],
     fp: ExtendedFloat80,
     sci_exp: i32,
+    _: Fmt,
     options: &Options,
 ) -> usize {
     debug_assert!(sci_exp < 0);
@@ -238,10 +240,11 @@ pub unsafe fn write_float_negative_exponent<F: DragonboxFloat, const FORMAT: u12
 ///
 /// Safe as long as `bytes` is large enough to hold the number of
 /// significant digits and the (optional) trailing zeros.
-pub unsafe fn write_float_positive_exponent<F: DragonboxFloat, const FORMAT: u128>(
+pub unsafe fn write_float_positive_exponent<F: DragonboxFloat, Fmt: Format>(
     bytes: &mut [u8],
     fp: ExtendedFloat80,
     sci_exp: i32,
+    _: Fmt,
     options: &Options,
 ) -> usize {
     // Config options.
@@ -790,7 +793,7 @@ pub fn compute_right_closed_directed<F: RawFloat>(float: F, shorter: bool) -> Ex
 #[inline]
 pub unsafe fn write_digits_u32(bytes: &mut [u8], mantissa: u32) -> usize {
     debug_assert!(bytes.len() >= 10);
-    unsafe { mantissa.write_mantissa::<u32, { STANDARD }>(bytes) }
+    unsafe { mantissa.write_mantissa::<u32, _>(bytes, NumberFormat::<{ STANDARD }> {}) }
 }
 
 /// Write the significant digits, when the significant digits cannot fit in a
@@ -805,7 +808,7 @@ pub unsafe fn write_digits_u32(bytes: &mut [u8], mantissa: u32) -> usize {
 #[allow(clippy::branches_sharing_code)]
 pub unsafe fn write_digits_u64(bytes: &mut [u8], mantissa: u64) -> usize {
     debug_assert!(bytes.len() >= 20);
-    unsafe { mantissa.write_mantissa::<u64, { STANDARD }>(bytes) }
+    unsafe { mantissa.write_mantissa::<u64, _>(bytes, NumberFormat::<{ STANDARD }> {}) }
 }
 
 // EXTENDED
@@ -1297,7 +1300,8 @@ impl DragonboxFloat for f32 {
 
         let r = umul96_lower64(two_f, *pow5);
         let parity = (r >> (64 - beta)) & 1;
-        let is_integer = r >> (32 - beta);
+        // Only the 32 fractional bits below the parity bit are checked.
+        let is_integer = (r >> (32 - beta)) as u32;
         (parity != 0, is_integer == 0)
     }
 
@@ -1501,82 +1505,117 @@ impl DragonboxFloat for f64 {
     }
 }
 
+/// Get the 64-bit Dragonbox power-of-5 for the 16-bit floats.
+///
+/// The `f32` table does not cover the exponent range of `bf16`, which has
+/// fewer mantissa bits but the same exponent range, so this rounds up the
+/// upper 64 bits of the `f64` power, which gives the `f32` power.
+///
+/// # Safety
+///
+/// Safe as long as the exponent is within the valid power-of-5 range.
+#[cfg(feature = "f16")]
+#[inline(always)]
+unsafe fn dragonbox16_power(exponent: i32) -> u64 {
+    debug_assert!((SMALLEST_F64_POW5..=LARGEST_F64_POW5).contains(&exponent));
+    let index = (exponent - SMALLEST_F64_POW5) as usize;
+    // SAFETY: safe if the exponent is in the correct range.
+    let pow5 = unsafe { index_unchecked!(DRAGONBOX64_POWERS_OF_FIVE[index]) };
+    high(&pow5) + (low(&pow5) != 0) as u64
+}
+
+// The 16-bit floats use the same algorithm as `f32`, with the 32-bit
+// significant digits and 64-bit powers, which have more than enough
+// precision for the smaller mantissa.
 #[cfg(feature = "f16")]
-macro_rules! dragonbox_unimpl {
-    ($($t:ident)*) => ($(
+macro_rules! dragonbox16_impl {
+    ($($t:ident $digits:literal ; )*) => ($(
         impl DragonboxFloat for $t {
-            const KAPPA: u32 = 0;
-            const DECIMAL_DIGITS: usize = 0;
+            const KAPPA: u32 = 1;
+            const DECIMAL_DIGITS: usize = $digits;
+            // The small significands can be divisible by the power of 5,
+            // so the left endpoint can be an integer for any exponent.
+            const DIV_BY_5_THRESHOLD: i32 = i32::MAX;
 
             type Power = u64;
 
             #[inline(always)]
-            fn digit_count(_: u64) -> usize {
-                unimplemented!()
+            fn digit_count(mantissa: u64) -> usize {
+                f32::digit_count(mantissa)
+            }
+
+            #[inline(always)]
+            unsafe fn write_digits(bytes: &mut [u8], mantissa: u64) -> usize {
+                // SAFETY: safe is `bytes.len() >= 10`.
+                unsafe { f32::write_digits(bytes, mantissa) }
             }
 
             #[inline(always)]
-            unsafe fn write_digits(_: &mut [u8], _: u64) -> usize {
-                unimplemented!()
+            unsafe fn dragonbox_power(exponent: i32) -> Self::Power {
+                // SAFETY: safe if the exponent is in the correct range.
+                unsafe { dragonbox16_power(exponent) }
             }
 
             #[inline(always)]
-            unsafe fn dragonbox_power(_: i32) -> Self::Power {
-                unimplemented!()
+            fn compute_left_endpoint(pow5: &Self::Power, beta_minus_1: i32) -> u64 {
+                compute_left_endpoint_u64::<Self>(*pow5, beta_minus_1)
             }
 
             #[inline(always)]
-            fn compute_left_endpoint(_: &Self::Power, _: i32) -> u64 {
-                unimplemented!()
+            fn compute_right_endpoint(pow5: &Self::Power, beta_minus_1: i32) -> u64 {
+                compute_right_endpoint_u64::<Self>(*pow5, beta_minus_1)
             }
 
             #[inline(always)]
-            fn compute_right_endpoint(_: &Self::Power, _: i32) -> u64 {
-                unimplemented!()
+            fn compute_round_up(pow5: &Self::Power, beta_minus_1: i32) -> u64 {
+                compute_round_up_u64::<Self>(*pow5, beta_minus_1)
             }
 
             #[inline(always)]
-            fn compute_round_up(_: &Self::Power, _: i32) -> (u64, bool) {
-                unimplemented!()
+            fn compute_mul(u: u64, pow5: &Self::Power) -> (u64, bool) {
+                f32::compute_mul(u, pow5)
             }
 
             #[inline(always)]
-            fn compute_mul(_: u64, _: &Self::Power) -> (u64, bool) {
-                unimplemented!()
+            fn compute_mul_parity(two_f: u64, pow5: &Self::Power, beta: i32) -> (bool, bool) {
+                f32::compute_mul_parity(two_f, pow5, beta)
             }
 
             #[inline(always)]
-            fn compute_mul_parity(_: u64, _: &Self::Power, _: i32) -> (bool, bool) {
-                unimplemented!()
+            fn compute_delta(pow5: &Self::Power, beta: i32) -> u32 {
+                f32::compute_delta(pow5, beta)
             }
 
             #[inline(always)]
-            fn compute_delta(_: &Self::Power, _: i32) -> u32 {
-                unimplemented!()
+            fn process_trailing_zeros(mantissa: u64, exponent: i32) -> (u64, i32) {
+                f32::process_trailing_zeros(mantissa, exponent)
             }
 
             #[inline(always)]
-            fn process_trailing_zeros(_: u64, _: i32) -> (u64, i32) {
-                unimplemented!()
+            fn remove_trailing_zeros(mantissa: u64) -> (u64, i32) {
+                f32::remove_trailing_zeros(mantissa)
             }
 
             #[inline(always)]
-            fn remove_trailing_zeros(_: u64) -> (u64, i32) {
-                unimplemented!()
+            fn check_div_pow10(n: u32) -> (u32, bool) {
+                f32::check_div_pow10(n)
             }
 
             #[inline(always)]
-            fn check_div_pow10(_: u32) -> (u32, bool) {
-                unimplemented!()
+            fn div_pow10(n: u32) -> u32 {
+                f32::div_pow10(n)
             }
 
             #[inline(always)]
-            fn div_pow10(_: u32) -> u32 {
-                unimplemented!()
+            fn divide_by_pow10(n: u64, exp: u32, n_max: u64) -> u64 {
+                f32::divide_by_pow10(n, exp, n_max)
             }
         }
     )*);
 }
 
 #[cfg(feature = "f16")]
-dragonbox_unimpl! { bf16 f16 }
+dragonbox16_impl! {
+    bf16 4 ;
+    f16 5 ;
+}
@@ -2,43 +2,87 @@
 
 #![doc(hidden)]
 
+#[cfg(feature = "complex")]
+use crate::complex::{self, write_complex};
 use crate::options::Options;
+#[cfg(feature = "decimal")]
+use crate::write::WriteDecimalFloat;
+#[cfg(feature = "double-double")]
+use crate::write::WriteDoubleDouble;
+#[cfg(any(feature = "f80", feature = "f128", feature = "custom-float"))]
+use crate::write::WriteExactFloat;
 use crate::write::WriteFloat;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+#[cfg(feature = "complex")]
+use lexical_util::complex::Complex;
 use lexical_util::constants::FormattedSize;
+#[cfg(feature = "custom-float")]
+use lexical_util::custom_float::CustomFloat;
+#[cfg(feature = "decimal")]
+use lexical_util::decimal::{decimal128, decimal32, decimal64};
+#[cfg(feature = "double-double")]
+use lexical_util::double_double::DoubleDouble;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+#[cfg(feature = "f80")]
+use lexical_util::f80::f80;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
+use lexical_util::format::{is_valid_options_punctuation, Format, NumberFormat, STANDARD};
 use lexical_util::options::WriteOptions;
-use lexical_util::{to_lexical, to_lexical_with_options};
+use lexical_util::{to_lexical, to_lexical_with_format, to_lexical_with_options};
 
 /// Check if a buffer is sufficiently large.
 #[inline]
-fn check_buffer<T, const FORMAT: u128>(len: usize, options: &Options) -> bool
+fn check_buffer<T, F>(len: usize, format: F, options: &Options) -> bool
 where
     T: FormattedSize,
+    F: Format,
 {
-    let size = Options::buffer_size::<T, FORMAT>(options);
+    let size = options.buffer_size_with_format::<T, _>(format);
     len >= size
 }
 
+/// Check if a buffer is sufficiently large for a decimal float.
+///
+/// The digit precision options are not used for decimal floats, so the
+/// buffer must always hold the maximum formatted size.
+#[cfg(feature = "decimal")]
+#[inline]
+fn check_decimal_buffer<T: FormattedSize>(len: usize) -> bool {
+    len >= T::FORMATTED_SIZE_DECIMAL
+}
+
+/// Check if a buffer is sufficiently large for a complex number.
+#[cfg(feature = "complex")]
+#[inline]
+fn check_complex_buffer<F, Fmt>(len: usize, format: Fmt, options: &Options) -> bool
+where
+    F: FormattedSize,
+    Fmt: Format,
+{
+    len >= complex::buffer_size::<F, _>(format, options)
+}
+
 // API
 
 const DEFAULT_OPTIONS: Options = Options::new();
+const STANDARD_FORMAT: NumberFormat<STANDARD> = NumberFormat::<{ STANDARD }> {};
 
 // Implement ToLexical for numeric type.
 macro_rules! float_to_lexical {
-    ($($t:tt $(, #[$meta:meta])? ; )*) => ($(
-        impl ToLexical for $t {
+    (@impl [$($generics:tt)*] $t:ty $(, #[$meta:meta])?) => {
+        impl<$($generics)*> ToLexical for $t {
             $(#[$meta:meta])?
             unsafe fn to_lexical_unchecked<'a>(self, bytes: &'a mut [u8])
                 -> &'a mut [u8]
             {
-                debug_assert!(check_buffer::<Self, { STANDARD }>(bytes.len(), &DEFAULT_OPTIONS));
-                // SAFETY: safe if `check_buffer::<STANDARD>(bytes.len(), &options)` passes.
+                let len = bytes.len();
+                debug_assert!(check_buffer::<Self, _>(len, STANDARD_FORMAT, &DEFAULT_OPTIONS));
+                // SAFETY: safe if `check_buffer(bytes.len(), STANDARD_FORMAT, &options)` passes.
                 unsafe {
-                    let len = self.write_float::<{ STANDARD }>(bytes, &DEFAULT_OPTIONS);
+                    let len = self.write_float(bytes, STANDARD_FORMAT, &DEFAULT_OPTIONS);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
@@ -47,13 +91,13 @@ macro_rules! float_to_lexical {
             fn to_lexical<'a>(self, bytes: &'a mut [u8])
                 -> &'a mut [u8]
             {
-                assert!(check_buffer::<Self, { STANDARD }>(bytes.len(), &DEFAULT_OPTIONS));
-                // SAFETY: safe since `check_buffer::<STANDARD>(bytes.len(), &options)` passes.
+                assert!(check_buffer::<Self, _>(bytes.len(), STANDARD_FORMAT, &DEFAULT_OPTIONS));
+                // SAFETY: safe since `check_buffer(bytes.len(), STANDARD_FORMAT, &options)` passes.
                 unsafe { self.to_lexical_unchecked(bytes) }
             }
         }
 
-        impl ToLexicalWithOptions for $t {
+        impl<$($generics)*> ToLexicalWithOptions for $t {
             type Options = Options;
 
             $(#[$meta:meta])?
@@ -63,26 +107,135 @@ macro_rules! float_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                assert!(is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()));
-                debug_assert!(check_buffer::<Self, { FORMAT }>(bytes.len(), &options));
-                // SAFETY: safe if `check_buffer::<FORMAT>(bytes.len(), &options)` passes.
+                let format = NumberFormat::<{ FORMAT }> {};
+                // SAFETY: safe if `check_buffer(bytes.len(), format, &options)` passes.
+                unsafe { self.to_lexical_with_format_unchecked(bytes, format, options) }
+            }
+
+            $(#[$meta:meta])?
+            fn to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                self.to_lexical_with_format(bytes, NumberFormat::<{ FORMAT }> {}, options)
+            }
+        }
+
+        impl<$($generics)*> ToLexicalWithFormat for $t {
+            $(#[$meta:meta])?
+            unsafe fn to_lexical_with_format_unchecked<'a, Fmt: Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                assert!(format.is_valid());
+                let (exponent, decimal_point) = (options.exponent(), options.decimal_point());
+                assert!(is_valid_options_punctuation(format.packed(), exponent, decimal_point));
+                debug_assert!(check_buffer::<Self, _>(bytes.len(), format, &options));
+                // SAFETY: safe if `check_buffer(bytes.len(), format, &options)` passes.
                 unsafe {
-                    let len = self.write_float::<{ FORMAT }>(bytes, &options);
+                    let len = self.write_float(bytes, format, &options);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
 
             $(#[$meta:meta])?
+            fn to_lexical_with_format<'a, Fmt: Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                assert!(check_buffer::<Self, _>(bytes.len(), format, &options));
+                // SAFETY: safe since `check_buffer(bytes.len(), format, &options)` passes.
+                unsafe { self.to_lexical_with_format_unchecked(bytes, format, options) }
+            }
+        }
+    };
+    ($($t:tt $(, #[$meta:meta])? ; )*) => ($(
+        float_to_lexical!(@impl [] $t $(, #[$meta])?);
+    )*);
+}
+
+// Implement ToLexical for decimal float types.
+#[cfg(feature = "decimal")]
+macro_rules! decimal_to_lexical {
+    ($($t:tt ; )*) => ($(
+        impl ToLexical for $t {
+            unsafe fn to_lexical_unchecked(self, bytes: &mut [u8]) -> &mut [u8] {
+                debug_assert!(check_decimal_buffer::<Self>(bytes.len()));
+                // SAFETY: safe if `check_decimal_buffer(bytes.len())` passes.
+                unsafe {
+                    let len = self.write_float(bytes, STANDARD_FORMAT, &DEFAULT_OPTIONS);
+                    &mut index_unchecked_mut!(bytes[..len])
+                }
+            }
+
+            fn to_lexical(self, bytes: &mut [u8]) -> &mut [u8] {
+                assert!(check_decimal_buffer::<Self>(bytes.len()));
+                // SAFETY: safe since `check_decimal_buffer(bytes.len())` passes.
+                unsafe { self.to_lexical_unchecked(bytes) }
+            }
+        }
+
+        impl ToLexicalWithOptions for $t {
+            type Options = Options;
+
+            unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                // SAFETY: safe if `check_decimal_buffer(bytes.len())` passes.
+                unsafe { self.to_lexical_with_format_unchecked(bytes, format, options) }
+            }
+
             fn to_lexical_with_options<'a, const FORMAT: u128>(
                 self,
                 bytes: &'a mut [u8],
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                assert!(check_buffer::<Self, { FORMAT }>(bytes.len(), &options));
-                // SAFETY: safe since `check_buffer::<FORMAT>(bytes.len(), &options)` passes.
-                unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
+                self.to_lexical_with_format(bytes, NumberFormat::<{ FORMAT }> {}, options)
+            }
+        }
+
+        impl ToLexicalWithFormat for $t {
+            unsafe fn to_lexical_with_format_unchecked<'a, Fmt: Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                assert!(format.is_valid());
+                let (exponent, decimal_point) = (options.exponent(), options.decimal_point());
+                assert!(is_valid_options_punctuation(format.packed(), exponent, decimal_point));
+                debug_assert!(check_decimal_buffer::<Self>(bytes.len()));
+                // SAFETY: safe if `check_decimal_buffer(bytes.len())` passes.
+                unsafe {
+                    let len = self.write_float(bytes, format, &options);
+                    &mut index_unchecked_mut!(bytes[..len])
+                }
+            }
+
+            fn to_lexical_with_format<'a, Fmt: Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: Fmt,
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                assert!(check_decimal_buffer::<Self>(bytes.len()));
+                // SAFETY: safe since `check_decimal_buffer(bytes.len())` passes.
+                unsafe { self.to_lexical_with_format_unchecked(bytes, format, options) }
             }
         }
     )*)
@@ -90,6 +243,7 @@ macro_rules! float_to_lexical {
 
 to_lexical! {}
 to_lexical_with_options! {}
+to_lexical_with_format! {}
 float_to_lexical! {
     f32 ;
     f64 ;
@@ -99,3 +253,107 @@ float_to_lexical! {
     f16 ;
     bf16 ;
 }
+#[cfg(feature = "f80")]
+float_to_lexical! {
+    f80 ;
+}
+#[cfg(feature = "f128")]
+float_to_lexical! {
+    f128 ;
+}
+#[cfg(feature = "custom-float")]
+float_to_lexical! {
+    @impl [const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8]
+        CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+}
+#[cfg(feature = "double-double")]
+float_to_lexical! {
+    DoubleDouble ;
+}
+#[cfg(feature = "complex")]
+impl<F> ToLexical for Complex<F>
+where
+    F: ToLexicalWithFormat<Options = Options> + FormattedSize,
+{
+    unsafe fn to_lexical_unchecked(self, bytes: &mut [u8]) -> &mut [u8] {
+        debug_assert!(check_complex_buffer::<F, _>(bytes.len(), STANDARD_FORMAT, &DEFAULT_OPTIONS));
+        // SAFETY: safe if `check_complex_buffer(bytes.len(), STANDARD_FORMAT, &options)` passes.
+        unsafe {
+            let len = write_complex::<F, _>(self, bytes, STANDARD_FORMAT, &DEFAULT_OPTIONS);
+            &mut index_unchecked_mut!(bytes[..len])
+        }
+    }
+
+    fn to_lexical(self, bytes: &mut [u8]) -> &mut [u8] {
+        assert!(check_complex_buffer::<F, _>(bytes.len(), STANDARD_FORMAT, &DEFAULT_OPTIONS));
+        // SAFETY: safe since `check_complex_buffer(bytes.len(), STANDARD_FORMAT, &options)` passes.
+        unsafe { self.to_lexical_unchecked(bytes) }
+    }
+}
+
+#[cfg(feature = "complex")]
+impl<F> ToLexicalWithOptions for Complex<F>
+where
+    F: ToLexicalWithFormat<Options = Options> + FormattedSize,
+{
+    type Options = Options;
+
+    unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
+        self,
+        bytes: &'a mut [u8],
+        options: &Self::Options,
+    ) -> &'a mut [u8] {
+        let format = NumberFormat::<{ FORMAT }> {};
+        // SAFETY: safe if `check_complex_buffer(bytes.len(), format, &options)` passes.
+        unsafe { self.to_lexical_with_format_unchecked(bytes, format, options) }
+    }
+
+    fn to_lexical_with_options<'a, const FORMAT: u128>(
+        self,
+        bytes: &'a mut [u8],
+        options: &Self::Options,
+    ) -> &'a mut [u8] {
+        self.to_lexical_with_format(bytes, NumberFormat::<{ FORMAT }> {}, options)
+    }
+}
+
+#[cfg(feature = "complex")]
+impl<F> ToLexicalWithFormat for Complex<F>
+where
+    F: ToLexicalWithFormat<Options = Options> + FormattedSize,
+{
+    unsafe fn to_lexical_with_format_unchecked<'a, Fmt: Format>(
+        self,
+        bytes: &'a mut [u8],
+        format: Fmt,
+        options: &Self::Options,
+    ) -> &'a mut [u8] {
+        assert!(format.is_valid());
+        let (exponent, decimal_point) = (options.exponent(), options.decimal_point());
+        assert!(is_valid_options_punctuation(format.packed(), exponent, decimal_point));
+        debug_assert!(check_complex_buffer::<F, _>(bytes.len(), format, options));
+        // SAFETY: safe if `check_complex_buffer(bytes.len(), format, &options)` passes.
+        unsafe {
+            let len = write_complex::<F, _>(self, bytes, format, options);
+            &mut index_unchecked_mut!(bytes[..len])
+        }
+    }
+
+    fn to_lexical_with_format<'a, Fmt: Format>(
+        self,
+        bytes: &'a mut [u8],
+        format: Fmt,
+        options: &Self::Options,
+    ) -> &'a mut [u8] {
+        assert!(check_complex_buffer::<F, _>(bytes.len(), format, options));
+        // SAFETY: safe since `check_complex_buffer(bytes.len(), format, &options)` passes.
+        unsafe { self.to_lexical_with_format_unchecked(bytes, format, options) }
+    }
+}
+
+#[cfg(feature = "decimal")]
+decimal_to_lexical! {
+    decimal32 ;
+    decimal64 ;
+    decimal128 ;
+}
@@ -11,7 +11,7 @@ use crate::options::{Options, RoundMode};
 use crate::shared;
 use lexical_util::algorithm::rtrim_char_count;
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_util::num::{as_cast, Float, Integer, UnsignedInteger};
 use lexical_write_integer::write::WriteInteger;
 
@@ -30,10 +30,11 @@ use lexical_write_integer::write::WriteInteger;
 /// # Panics
 ///
 /// Panics if exponent notation is used, and the exponent base and
-/// mantissa radix are not the same in `FORMAT`.
-pub unsafe fn write_float<F: Float, const FORMAT: u128>(
+/// mantissa radix are not the same in `format`.
+pub unsafe fn write_float<F: Float, Fmt: Format>(
     float: F,
     bytes: &mut [u8],
+    format: Fmt,
     options: &Options,
 ) -> usize
 where
@@ -43,7 +44,6 @@ where
 
     // Assert no special cases remain, no negative numbers,
     // and a valid format.
-    let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
     debug_assert!(!float.is_special());
     debug_assert!(float >= F::ZERO);
@@ -85,14 +85,14 @@ where
     }
 
     write_float!(
-        FORMAT,
+        format,
         sci_exp,
         options,
         write_float_scientific,
         write_float_positive_exponent,
         write_float_negative_exponent,
         generic => _,
-        args => mantissa, exp, sci_exp, bytes, options,
+        args => mantissa, exp, sci_exp, bytes, format, options,
     )
 }
 
@@ -107,13 +107,14 @@ where
 ///
 /// The mantissa must be truncated and rounded, prior to calling this,
 /// based on the number of maximum digits. In addition, `exponent_base`
-/// and `mantissa_radix` in `FORMAT` must be identical.
+/// and `mantissa_radix` in `format` must be identical.
 #[inline]
-pub unsafe fn write_float_scientific<M, const FORMAT: u128>(
+pub unsafe fn write_float_scientific<M, Fmt: Format>(
     mantissa: M,
     exp: i32,
     sci_exp: i32,
     bytes: &mut [u8],
+    format: Fmt,
     options: &Options,
 ) -> usize
 where
@@ -124,7 +125,6 @@ where
     debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options
-    let format = NumberFormat::<{ FORMAT }> {};
     let bits_per_digit = fast_log2(format.mantissa_radix());
     let decimal_point = options.decimal_point();
 
@@ -138,7 +138,7 @@ where
 
     // SAFETY: safe since the buffer must be larger than `M::FORMATTED_SIZE`.
     let digit_count = unsafe {
-        let count = value.write_mantissa::<M, FORMAT>(&mut index_unchecked_mut!(bytes[1..]));
+        let count = value.write_mantissa::<M, _>(&mut index_unchecked_mut!(bytes[1..]), format);
         index_unchecked_mut!(bytes[0] = bytes[1]);
         index_unchecked_mut!(bytes[1]) = decimal_point;
         let zeros = rtrim_char_count(&index_unchecked!(bytes[2..count + 1]), b'0');
@@ -176,7 +176,7 @@ where
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit);
     // SAFETY: safe if the buffer is large enough to hold the maximum written float.
     unsafe {
-        shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent())
+        shared::write_exponent(bytes, &mut cursor, scaled_sci_exp, options.exponent(), format)
     };
 
     cursor
@@ -190,11 +190,12 @@ where
 /// Safe as long as `bytes` is large enough to hold the number of
 /// significant digits and the leading zeros.
 #[inline]
-pub unsafe fn write_float_negative_exponent<M, const FORMAT: u128>(
+pub unsafe fn write_float_negative_exponent<M, Fmt: Format>(
     mantissa: M,
     exp: i32,
     sci_exp: i32,
     bytes: &mut [u8],
+    format: Fmt,
     options: &Options,
 ) -> usize
 where
@@ -211,7 +212,6 @@ where
     debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options
-    let format = NumberFormat::<{ FORMAT }> {};
     let bits_per_digit = fast_log2(format.mantissa_radix());
     let decimal_point = options.decimal_point();
 
@@ -241,7 +241,8 @@ where
 
     // SAFETY: both are safe, if the buffer is large enough to hold the significant digits.
     let digit_count = unsafe {
-        let count = value.write_mantissa::<M, FORMAT>(&mut index_unchecked_mut!(bytes[cursor..]));
+        let digits = &mut index_unchecked_mut!(bytes[cursor..]);
+        let count = value.write_mantissa::<M, _>(digits, format);
         let zeros = rtrim_char_count(&index_unchecked!(bytes[cursor..cursor + count]), b'0');
         count - zeros
     };
@@ -274,11 +275,12 @@ where
 /// Safe as long as `bytes` is large enough to hold the number of
 /// significant digits and the (optional) trailing zeros.
 #[inline]
-pub unsafe fn write_float_positive_exponent<M, const FORMAT: u128>(
+pub unsafe fn write_float_positive_exponent<M, Fmt: Format>(
     mantissa: M,
     exp: i32,
     sci_exp: i32,
     bytes: &mut [u8],
+    format: Fmt,
     options: &Options,
 ) -> usize
 where
@@ -287,7 +289,6 @@ where
     debug_assert!(sci_exp >= 0 || mantissa == M::ZERO);
 
     // Config options
-    let format = NumberFormat::<{ FORMAT }> {};
     let bits_per_digit = fast_log2(format.mantissa_radix());
     let decimal_point = options.decimal_point();
 
@@ -298,7 +299,7 @@ where
 
     // SAFETY: safe since the buffer must be larger than `M::FORMATTED_SIZE`.
     let mut digit_count = unsafe {
-        let count = value.write_mantissa::<M, FORMAT>(bytes);
+        let count = value.write_mantissa::<M, _>(bytes, format);
         let zeros = rtrim_char_count(&index_unchecked!(bytes[..count]), b'0');
         count - zeros
     };
@@ -32,7 +32,7 @@ use lexical_util::bf16::bf16;
 use lexical_util::digit::digit_to_char_const;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_util::num::{AsPrimitive, Float};
 
 /// Compact float-to-string algorithm for decimal strings.
@@ -49,16 +49,16 @@ use lexical_util::num::{AsPrimitive, Float};
 ///
 /// Safe as long as the float isn't special (NaN or Infinity), and `bytes`
 /// is large enough to hold the significant digits.
-pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
+pub unsafe fn write_float<F: RawFloat, Fmt: Format>(
     float: F,
     bytes: &mut [u8],
+    format: Fmt,
     options: &Options,
 ) -> usize {
     // PRECONDITIONS
 
     // Assert no special cases remain, no negative numbers,
     // and a valid format.
-    let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
     debug_assert!(!float.is_special());
     debug_assert!(float >= F::ZERO);
@@ -83,13 +83,13 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
 
     let sci_exp = kappa + digit_count as i32 - 1 + carried as i32;
     write_float!(
-        FORMAT,
+        format,
         sci_exp,
         options,
         write_float_scientific,
         write_float_positive_exponent,
         write_float_negative_exponent,
-        args => bytes, &mut digits, digit_count, sci_exp, options,
+        args => bytes, &mut digits, digit_count, sci_exp, format, options,
     )
 }
 
@@ -100,18 +100,18 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
 /// Safe as long as `bytes` is large enough to hold the number of digits
 /// and the scientific notation's exponent digits.
 #[allow(clippy::comparison_chain)]
-pub unsafe fn write_float_scientific<const FORMAT: u128>(
+pub unsafe fn write_float_scientific<Fmt: Format>(
     bytes: &mut [u8],
     digits: &mut [u8],
     digit_count: usize,
     sci_exp: i32,
+    format: Fmt,
     options: &Options,
 ) -> usize {
     debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0 || digit_count == 1);
     debug_assert!(digit_count <= 20);
 
     // Config options
-    let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
     let decimal_point = options.decimal_point();
 
@@ -155,7 +155,7 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe since bytes must be large enough to store the largest float.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent(bytes, &mut cursor, sci_exp, options.exponent(), format) };
 
     cursor
 }
@@ -168,11 +168,12 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 /// Safe as long as `bytes` is large enough to hold the number of
 /// significant digits and the leading zeros.
 #[allow(clippy::comparison_chain)]
-pub unsafe fn write_float_negative_exponent<const FORMAT: u128>(
+pub unsafe fn write_float_negative_exponent<Fmt: Format>(
     bytes: &mut [u8],
     digits: &mut [u8],
     digit_count: usize,
     sci_exp: i32,
+    _: Fmt,
     options: &Options,
 ) -> usize {
     debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0);
@@ -226,11 +227,12 @@ pub unsafe fn write_float_negative_exponent<const FORMAT: u128>(
 ///
 /// Safe as long as `bytes` is large enough to hold the number of
 /// significant digits and the (optional) trailing zeros.
-pub unsafe fn write_float_positive_exponent<const FORMAT: u128>(
+pub unsafe fn write_float_positive_exponent<Fmt: Format>(
     bytes: &mut [u8],
     digits: &mut [u8],
     mut digit_count: usize,
     sci_exp: i32,
+    _: Fmt,
     options: &Options,
 ) -> usize {
     debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0 || digit_count == 1);
@@ -634,16 +636,4 @@ macro_rules! grisu_impl {
 grisu_impl! { f32 f64 }
 
 #[cfg(feature = "f16")]
-macro_rules! grisu_unimpl {
-    ($($t:ident)*) => ($(
-        impl GrisuFloat for $t {
-            #[inline(always)]
-            unsafe fn grisu_power(_: usize) -> u64 {
-                unimplemented!()
-            }
-        }
-    )*);
-}
-
-#[cfg(feature = "f16")]
-grisu_unimpl! { bf16 f16 }
+grisu_impl! { bf16 f16 }
@@ -0,0 +1,119 @@
+//! Write complex numbers on top of the float writers.
+//!
+//! Both parts are written with the same options, as `a+bi` with the
+//! imaginary unit, or as an `(a,b)` tuple if the imaginary unit is `None`.
+//! The sign between both parts is the sign of the imaginary part, so
+//! negative imaginary parts are written as `a-bi`, and special values
+//! as `a+NaNi` or `a-infi`.
+
+#![cfg(feature = "complex")]
+#![doc(hidden)]
+
+use crate::api::ToLexicalWithFormat;
+use crate::options::{Options, MAX_IMAGINARY_UNIT_LENGTH};
+use lexical_util::complex::Complex;
+use lexical_util::constants::FormattedSize;
+use lexical_util::format::Format;
+use lexical_util::options::WriteOptions;
+
+/// Get the number of bytes required to write a complex number.
+///
+/// Each part requires the buffer size of the float, or the formatted size
+/// for floats that ignore the digit precision options, such as decimal
+/// floats. The imaginary part is written after a byte for its sign, and
+/// is followed by the imaginary unit, which also fits the parentheses
+/// and comma of a tuple.
+#[inline]
+pub fn buffer_size<F: FormattedSize, Fmt: Format>(format: Fmt, options: &Options) -> usize {
+    let size = options.buffer_size_with_format::<F, _>(format).max(F::FORMATTED_SIZE_DECIMAL);
+    2 * size + 1 + MAX_IMAGINARY_UNIT_LENGTH
+}
+
+/// Write a float part of the complex number, and return the bytes written.
+///
+/// # Safety
+///
+/// Safe as long as the buffer can hold the part.
+#[inline(always)]
+unsafe fn write_part<F, Fmt: Format>(
+    value: F,
+    bytes: &mut [u8],
+    format: Fmt,
+    options: &Options,
+) -> usize
+where
+    F: ToLexicalWithFormat<Options = Options>,
+{
+    // SAFETY: safe if the buffer can hold the part.
+    unsafe { value.to_lexical_with_format_unchecked(bytes, format, options).len() }
+}
+
+/// Write a complex number, and return the bytes written.
+///
+/// # Safety
+///
+/// Safe as long as the buffer can hold [`buffer_size`] bytes.
+///
+/// # Panics
+///
+/// Panics if the number format is invalid, or if `options.nan_string`
+/// or `options.inf_string` is None and asked to serialize a NaN or
+/// Inf value.
+pub unsafe fn write_complex<F, Fmt: Format>(
+    value: Complex<F>,
+    bytes: &mut [u8],
+    format: Fmt,
+    options: &Options,
+) -> usize
+where
+    F: ToLexicalWithFormat<Options = Options>,
+{
+    let unit = match options.imaginary_unit() {
+        Some(unit) => unit,
+        // SAFETY: safe if the buffer can hold `buffer_size` bytes.
+        None => return unsafe { write_tuple::<F, _>(value, bytes, format, options) },
+    };
+
+    // Write the imaginary part after a byte for the sign, and shift
+    // it left if it already has a sign.
+    // SAFETY: safe if the buffer can hold `buffer_size` bytes.
+    let count = unsafe { write_part::<F, _>(value.re, bytes, format, options) };
+    let im = unsafe { write_part::<F, _>(value.im, &mut bytes[count + 1..], format, options) };
+    let count = match bytes[count + 1] {
+        b'+' | b'-' => {
+            bytes.copy_within(count + 1..count + 1 + im, count);
+            count + im
+        },
+        _ => {
+            bytes[count] = b'+';
+            count + 1 + im
+        },
+    };
+    bytes[count..count + unit.len()].copy_from_slice(unit);
+    count + unit.len()
+}
+
+/// Write a complex number as an `(a,b)` tuple, and return the bytes written.
+///
+/// # Safety
+///
+/// Safe as long as the buffer can hold [`buffer_size`] bytes.
+unsafe fn write_tuple<F, Fmt: Format>(
+    value: Complex<F>,
+    bytes: &mut [u8],
+    format: Fmt,
+    options: &Options,
+) -> usize
+where
+    F: ToLexicalWithFormat<Options = Options>,
+{
+    bytes[0] = b'(';
+    // SAFETY: safe if the buffer can hold `buffer_size` bytes.
+    let count = 1 + unsafe { write_part::<F, _>(value.re, &mut bytes[1..], format, options) };
+    bytes[count] = b',';
+    let count = count + 1;
+    let im = unsafe { write_part::<F, _>(value.im, &mut bytes[count..], format, options) };
+    let count = count + im;
+    bytes[count] = b')';
+    count + 1
+}
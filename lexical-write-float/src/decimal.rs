@@ -0,0 +1,116 @@
+//! Writing of IEEE-754 decimal floats.
+//!
+//! Decimal floats are written exactly, using the to-scientific-string
+//! rules from the General Decimal Arithmetic specification, so the
+//! quantum is preserved: `1.50` is written as `1.50`, and `1.5E+3` as
+//! `1.5e3`. A number is written without an exponent if the exponent
+//! of the coefficient is not positive, and the exponent in scientific
+//! notation is at least -6, otherwise scientific notation is used.
+//!
+//! To write the shortest form, normalize the float first, which removes
+//! the trailing zeros from the coefficient.
+//!
+//! The significant digit and exponent break options are not used, since
+//! they would change the quantum of the value. The decimal point, the
+//! exponent character, and the special strings are used.
+//!
+//! The coefficient and exponent of a decimal float are always decimal,
+//! so the mantissa radix and exponent base of the number format are not
+//! used either, and a non-decimal format writes the same digits as a
+//! decimal format.
+
+#![cfg(feature = "decimal")]
+#![doc(hidden)]
+
+use crate::options::Options;
+use crate::shared;
+use lexical_util::decimal::DecimalFloat;
+use lexical_util::format::Format;
+
+/// The maximum number of digits in the coefficient.
+const MAX_DIGITS: usize = 40;
+
+/// Write the digits of the coefficient, returning the number of digits.
+#[inline]
+fn write_coefficient(mut coefficient: u128, digits: &mut [u8; MAX_DIGITS]) -> usize {
+    let mut index = MAX_DIGITS;
+    loop {
+        index -= 1;
+        digits[index] = b'0' + (coefficient % 10) as u8;
+        coefficient /= 10;
+        if coefficient == 0 {
+            break;
+        }
+    }
+    digits.copy_within(index.., 0);
+    MAX_DIGITS - index
+}
+
+/// Write a finite decimal float to the buffer, without the sign.
+///
+/// # Safety
+///
+/// Safe as long as `bytes` is large enough to hold the significant digits,
+/// the leading zeros, and the exponent, which is always less than
+/// [`FORMATTED_SIZE_DECIMAL`].
+///
+/// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
+pub unsafe fn write_float<D: DecimalFloat, Fmt: Format>(
+    value: D,
+    bytes: &mut [u8],
+    format: Fmt,
+    options: &Options,
+) -> usize {
+    // PRECONDITIONS
+
+    // Assert no special cases remain, and a valid format.
+    assert!(format.is_valid());
+    debug_assert!(value.is_finite());
+
+    let decimal_point = options.decimal_point();
+    let mut digits = [0_u8; MAX_DIGITS];
+    let digit_count = write_coefficient(value.coefficient(), &mut digits);
+    let exponent = value.exponent();
+    let sci_exp = exponent + digit_count as i32 - 1;
+
+    if exponent == 0 {
+        // An integer, with no decimal point.
+        bytes[..digit_count].copy_from_slice(&digits[..digit_count]);
+        digit_count
+    } else if exponent < 0 && sci_exp >= -6 {
+        let fraction_count = exponent.unsigned_abs() as usize;
+        if fraction_count < digit_count {
+            // Insert the decimal point into the digits.
+            let integer_count = digit_count - fraction_count;
+            bytes[..integer_count].copy_from_slice(&digits[..integer_count]);
+            bytes[integer_count] = decimal_point;
+            bytes[integer_count + 1..digit_count + 1]
+                .copy_from_slice(&digits[integer_count..digit_count]);
+            digit_count + 1
+        } else {
+            // Write the leading zeros, then the digits.
+            let zeros = fraction_count - digit_count;
+            bytes[0] = b'0';
+            bytes[1] = decimal_point;
+            for digit in bytes[2..zeros + 2].iter_mut() {
+                *digit = b'0';
+            }
+            bytes[zeros + 2..zeros + 2 + digit_count].copy_from_slice(&digits[..digit_count]);
+            zeros + 2 + digit_count
+        }
+    } else {
+        // Scientific notation, with a decimal point if there are multiple digits.
+        bytes[0] = digits[0];
+        let mut cursor = if digit_count == 1 {
+            1
+        } else {
+            bytes[1] = decimal_point;
+            bytes[2..digit_count + 1].copy_from_slice(&digits[1..digit_count]);
+            digit_count + 1
+        };
+        // SAFETY: safe since bytes must be large enough to store the largest float.
+        let exponent_character = options.exponent();
+        unsafe { shared::write_exponent(bytes, &mut cursor, sci_exp, exponent_character, format) };
+        cursor
+    }
+}
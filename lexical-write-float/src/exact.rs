@@ -0,0 +1,698 @@
+//! Shortest round-trip writing of extended and quadruple-precision floats.
+//!
+//! The extended-float algorithms need guard bits beyond the significand,
+//! so floats with 64-bit or wider significands, such as f80 and f128, are
+//! written using big integers. This is the free-format algorithm from
+//! "Printing Floating-Point Numbers Quickly and Accurately", by Robert G.
+//! Burger and R. Kent Dybvig, available online at:
+//! <https://legacy.cs.indiana.edu/~dyb/pubs/FP-Printing-PLDI96.pdf>.
+//!
+//! The value and the half-way points to the adjacent floats are scaled to
+//! big integers, and digits are generated until the digits uniquely
+//! identify the float, so the shortest digits that round-trip are written
+//! for any radix. The digits are then formatted like the other algorithms.
+//!
+//! Double-doubles are written the same way, but the bounds are the closest
+//! of the halfway points for the high float, and for the low float offset
+//! by the high float, since the digits must round-trip to both floats.
+
+#![cfg(any(feature = "f80", feature = "f128", feature = "custom-float", feature = "double-double"))]
+#![doc(hidden)]
+
+use crate::options::{Options, RoundMode};
+use crate::shared;
+use core::cmp::Ordering;
+use lexical_util::algorithm::rtrim_char_count;
+use lexical_util::bignum;
+use lexical_util::digit::{char_to_valid_digit_const, digit_to_char_const};
+use lexical_util::format::Format;
+use lexical_util::num::{AsPrimitive, Float};
+
+/// The number of limbs to store the scaled value and boundaries.
+///
+/// The values have at most about `2 - DENORMAL_EXPONENT` bits, and are
+/// shifted by less than 64 bits and multiplied by the radix.
+const LIMBS: usize = 264;
+
+/// The maximum number of significant digits, for binary strings.
+const MAX_DIGITS: usize = 128;
+
+/// The maximum number of significant digits of a double-double, for binary strings.
+///
+/// The digits may span from the high float to half the smallest denormal.
+#[cfg(feature = "double-double")]
+const DOUBLE_DOUBLE_DIGITS: usize = 2112;
+
+// LIMBS
+
+/// A big integer with a fixed capacity, as little-endian `u64` limbs.
+///
+/// The limbs after the length are always 0.
+#[derive(Clone)]
+struct Limbs {
+    data: [u64; LIMBS],
+    len: usize,
+}
+
+impl Limbs {
+    /// Create a big integer from a 128-bit value.
+    #[inline]
+    fn from_u128(x: u128) -> Self {
+        let mut data = [0; LIMBS];
+        data[0] = x as u64;
+        data[1] = (x >> 64) as u64;
+        Self {
+            data,
+            len: bignum::normalized_len(&data[..2]),
+        }
+    }
+
+    /// Create a big integer from `2^exp`.
+    #[cfg(feature = "double-double")]
+    #[inline]
+    fn pow2(exp: i32) -> Self {
+        let mut x = Self::from_u128(1);
+        x.shl(exp as usize);
+        x
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[u64] {
+        &self.data[..self.len]
+    }
+
+    /// Get the number of bits in the value.
+    #[inline]
+    fn bit_len(&self) -> i64 {
+        match self.len {
+            0 => 0,
+            len => 64 * len as i64 - self.data[len - 1].leading_zeros() as i64,
+        }
+    }
+
+    /// Add a big integer to the value.
+    #[inline]
+    fn add(&mut self, y: &Self) {
+        self.len = self.len.max(y.len);
+        bignum::add_assign(&mut self.data[..self.len + 1], y.as_slice());
+        self.len = bignum::normalized_len(&self.data[..self.len + 1]);
+    }
+
+    /// Subtract a big integer from the value, which must not be larger than it.
+    #[cfg(feature = "double-double")]
+    #[inline]
+    fn sub(&mut self, y: &Self) {
+        bignum::sub_assign(&mut self.data[..self.len], y.as_slice());
+        self.len = bignum::normalized_len(self.as_slice());
+    }
+
+    /// Multiply by a small value.
+    #[inline]
+    fn mul_small(&mut self, y: u64) {
+        let carry = bignum::mul_small(&mut self.data[..self.len], y, 0);
+        if carry != 0 {
+            self.data[self.len] = carry;
+            self.len += 1;
+        }
+    }
+
+    /// Multiply by `radix^exp`.
+    fn mul_pow(&mut self, radix: u32, mut exp: i32) {
+        let (base, step) = bignum::max_power(radix);
+        while exp > 0 {
+            let power = match exp >= step as i32 {
+                true => base,
+                false => (radix as u64).pow(exp as u32),
+            };
+            self.mul_small(power);
+            exp -= step as i32;
+        }
+    }
+
+    /// Shift the value left by `shift` bits.
+    fn shl(&mut self, shift: usize) {
+        let src = self.data;
+        let end = (self.len + shift / 64 + 1).min(LIMBS);
+        self.data = [0; LIMBS];
+        bignum::shl(&mut self.data[..end], &src[..self.len], shift);
+        self.len = bignum::normalized_len(&self.data[..end]);
+    }
+
+    /// Compare `self + y` to `z`.
+    fn compare_sum(&self, y: &Self, z: &Self) -> Ordering {
+        let mut sum = self.clone();
+        sum.add(y);
+        bignum::compare(sum.as_slice(), z.as_slice())
+    }
+}
+
+// ALGORITHM
+
+/// Get the approximate `log2(radix)`, as a fixed-point number with 16 fractional bits.
+///
+/// The result is within a few units in the last place, which is
+/// sufficient to estimate the scientific exponent.
+fn log2_fixed(radix: u32) -> i64 {
+    let integer = 31 - radix.leading_zeros();
+    let mut x = (radix as u64) << (32 - integer);
+    let mut result = (integer as i64) << 16;
+    for bit in (0..16).rev() {
+        x = ((x as u128 * x as u128) >> 32) as u64;
+        if x >= 2 << 32 {
+            x >>= 1;
+            result |= 1 << bit;
+        }
+    }
+    result
+}
+
+/// Generate the shortest digits that round-trip to the float.
+///
+/// The float must be positive and finite. Returns the number of digits
+/// written, and the exponent of the first digit.
+pub fn shortest_digits<F: Float>(float: F, radix: u32, digits: &mut [u8]) -> (usize, i32) {
+    debug_assert!(float > F::ZERO && !float.is_special());
+
+    // Get the value and the distance to the halfway points to the adjacent
+    // floats, in units of `2^(exp - shift)`. The gap below is half the gap
+    // above at powers of 2, except for the smallest normal.
+    let mant = float.mantissa().as_u128();
+    let exp = float.exponent();
+    let is_even = mant & 1 == 0;
+    let is_asymmetric = mant == F::HIDDEN_BIT_MASK.as_u128() && exp > F::DENORMAL_EXPONENT;
+    let shift = is_asymmetric as usize + 1;
+    let mut r = Limbs::from_u128(mant);
+    let mut m_plus = Limbs::from_u128(1);
+    let mut m_minus = Limbs::from_u128(1);
+    let mut is_closed = (is_even, is_even);
+    r.shl(shift);
+    m_plus.shl(shift - 1);
+
+    // The largest float of a format without infinity is its infinity, and
+    // larger values saturate to it, so the digits are never above it. The
+    // exact digits are written if they terminate, which they always do in
+    // even radixes, and otherwise only if the float is an integer.
+    if float.to_bits() == F::INFINITY_BITS {
+        m_plus = Limbs::from_u128(0);
+        is_closed.1 = true;
+        if radix & 1 == 0 || exp + mant.trailing_zeros() as i32 >= 0 {
+            m_minus = Limbs::from_u128(0);
+            is_closed.0 = true;
+        }
+    }
+    let exp = exp - shift as i32;
+
+    generate_digits(r, m_minus, m_plus, exp, is_closed, radix, digits)
+}
+
+/// Generate the shortest digits within the bounds of a value.
+///
+/// The value and the distance to the lower and upper bounds are
+/// `r * 2^exp`, `m_minus * 2^exp` and `m_plus * 2^exp`, and the bounds
+/// round to the value if they are closed. Returns the number of digits
+/// written, and the exponent of the first digit.
+fn generate_digits(
+    mut r: Limbs,
+    mut m_minus: Limbs,
+    mut m_plus: Limbs,
+    exp: i32,
+    is_closed: (bool, bool),
+    radix: u32,
+    digits: &mut [u8],
+) -> (usize, i32) {
+    // Scale the values to `r / s`, `m_plus / s`, and `m_minus / s`.
+    let (is_low_closed, is_high_closed) = is_closed;
+    let bits = exp as i64 + r.bit_len();
+    let mut s = Limbs::from_u128(1);
+    if exp >= 0 {
+        r.shl(exp as usize);
+        m_plus.shl(exp as usize);
+        m_minus.shl(exp as usize);
+    } else {
+        s.shl(exp.unsigned_abs() as usize);
+    }
+
+    // Estimate the exponent `k` so `radix^(k - 1) <= value < radix^k`. The
+    // estimate is never above the exponent, and it is corrected below.
+    let mut k = ((bits - 1) << 16).div_euclid(log2_fixed(radix) + 4) as i32 - 1;
+    if k >= 0 {
+        s.mul_pow(radix, k);
+    } else {
+        r.mul_pow(radix, -k);
+        m_plus.mul_pow(radix, -k);
+        m_minus.mul_pow(radix, -k);
+    }
+
+    // The upper bound rounds to the value if it is closed.
+    let is_high = |r: &Limbs, m_plus: &Limbs, s: &Limbs| match r.compare_sum(m_plus, s) {
+        Ordering::Greater => true,
+        Ordering::Equal => is_high_closed,
+        Ordering::Less => false,
+    };
+    while is_high(&r, &m_plus, &s) {
+        s.mul_small(radix as u64);
+        k += 1;
+    }
+
+    // Normalize the divisor, so the digits can be divided out.
+    let norm = s.data[s.len - 1].leading_zeros() as usize;
+    r.shl(norm);
+    s.shl(norm);
+    m_plus.shl(norm);
+    m_minus.shl(norm);
+    let n = s.len;
+
+    let mut count = 0;
+    loop {
+        r.mul_small(radix as u64);
+        m_plus.mul_small(radix as u64);
+        m_minus.mul_small(radix as u64);
+        let mut quotient = [0_u64; 1];
+        bignum::divrem_normalized(&mut r.data[..n + 1], s.as_slice(), &mut quotient);
+        r.len = bignum::normalized_len(&r.data[..n]);
+        let digit = quotient[0] as u32;
+
+        // Stop if the digits, or the digits rounded up, are within the bounds.
+        let is_low = match bignum::compare(r.as_slice(), m_minus.as_slice()) {
+            Ordering::Less => true,
+            Ordering::Equal => is_low_closed,
+            Ordering::Greater => false,
+        };
+        let is_high = is_high(&r, &m_plus, &s);
+        let digit = match (is_low, is_high) {
+            (false, false) => {
+                digits[count] = digit_to_char_const(digit, radix);
+                count += 1;
+                continue;
+            },
+            (true, false) => digit,
+            (false, true) => digit + 1,
+            // Use the closest digit, with ties to even.
+            (true, true) => match r.compare_sum(&r, &s) {
+                Ordering::Less => digit,
+                Ordering::Greater => digit + 1,
+                Ordering::Equal => digit + (digit & 1),
+            },
+        };
+        digits[count] = digit_to_char_const(digit, radix);
+        count += 1;
+        break;
+    }
+
+    (count, k - 1)
+}
+
+/// Get the mantissa and exponent of the absolute float, if the mantissa is
+/// even, and if the gap below is half the gap above.
+#[cfg(feature = "double-double")]
+#[inline]
+fn decompose(float: f64) -> (u64, i32, bool, bool) {
+    let mant = float.mantissa();
+    let exp = float.exponent();
+    let is_asymmetric = mant == f64::HIDDEN_BIT_MASK && exp > f64::DENORMAL_EXPONENT;
+    (mant, exp, mant & 1 == 0, is_asymmetric)
+}
+
+/// Generate the shortest digits that round-trip to the double-double.
+///
+/// The double-double must be normalized, with a positive, finite high
+/// float. The digits round-trip if they round to the high float, and the
+/// difference from the high float rounds to the low float, so the bounds
+/// are the closest of the two. Returns the number of digits written, and
+/// the exponent of the first digit.
+#[cfg(feature = "double-double")]
+pub fn shortest_double_double_digits(
+    hi: f64,
+    lo: f64,
+    radix: u32,
+    digits: &mut [u8],
+) -> (usize, i32) {
+    debug_assert!(hi > 0.0 && hi.is_finite());
+
+    // Get the floats, and the distance to their halfway points, in units of
+    // `2^exp`. Zero is even, and the halfway points are half the smallest
+    // denormal, and the gap below the low float is toward zero.
+    let (hi_mant, hi_exp, hi_even, hi_asymmetric) = decompose(hi);
+    let (lo_mant, lo_exp, lo_even, lo_asymmetric) = decompose(lo);
+    let exp = hi_exp.min(lo_exp) - 2;
+    let mut hi_value = Limbs::from_u128(hi_mant as u128);
+    let mut lo_value = Limbs::from_u128(lo_mant as u128);
+    hi_value.shl((hi_exp - exp) as usize);
+    lo_value.shl((lo_exp - exp) as usize);
+    let hi_below = Limbs::pow2(hi_exp - exp - 1 - hi_asymmetric as i32);
+    let hi_above = Limbs::pow2(hi_exp - exp - 1);
+    let lo_zero = Limbs::pow2(lo_exp - exp - 1 - lo_asymmetric as i32);
+    let lo_away = Limbs::pow2(lo_exp - exp - 1);
+
+    // Offset the bounds of the high float by the low float, which is
+    // within them, since the double-double is normalized.
+    let mut r = hi_value;
+    let (mut hi_below, mut hi_above) = (hi_below, hi_above);
+    let (lo_below, lo_above) = if lo.is_sign_negative() {
+        r.sub(&lo_value);
+        hi_below.sub(&lo_value);
+        hi_above.add(&lo_value);
+        (lo_away, lo_zero)
+    } else {
+        r.add(&lo_value);
+        hi_below.add(&lo_value);
+        hi_above.sub(&lo_value);
+        (lo_zero, lo_away)
+    };
+
+    // Use the closest bounds, which are closed if both bounds are closed.
+    let closest = |hi_bound: Limbs, lo_bound: Limbs| {
+        match bignum::compare(hi_bound.as_slice(), lo_bound.as_slice()) {
+            Ordering::Less => (hi_bound, hi_even),
+            Ordering::Equal => (hi_bound, hi_even && lo_even),
+            Ordering::Greater => (lo_bound, lo_even),
+        }
+    };
+    let (m_minus, is_low_closed) = closest(hi_below, lo_below);
+    let (m_plus, is_high_closed) = closest(hi_above, lo_above);
+
+    generate_digits(r, m_minus, m_plus, exp, (is_low_closed, is_high_closed), radix, digits)
+}
+
+/// Exact float-to-string algorithm for wide floats, with any radix.
+///
+/// This assumes the float is:
+///     1). Non-special (NaN or Infinite).
+///     2). Non-negative.
+///
+/// # Safety
+///
+/// Safe as long as `bytes` is large enough to hold the number of
+/// significant digits, any (optional) leading or trailing zeros,
+/// and the scientific exponent.
+///
+/// # Panics
+///
+/// Panics if the exponent base and the mantissa radix are not the same.
+pub unsafe fn write_float<F: Float, Fmt: Format>(
+    float: F,
+    bytes: &mut [u8],
+    format: Fmt,
+    options: &Options,
+) -> usize {
+    // PRECONDITIONS
+
+    // Assert no special cases remain, no negative numbers,
+    // and a valid format.
+    assert!(format.is_valid());
+    assert!(format.mantissa_radix() == format.exponent_base());
+    debug_assert!(!float.is_special());
+    debug_assert!(float >= F::ZERO);
+
+    // Write our mantissa digits to a temporary buffer.
+    let radix = format.radix();
+    let mut digits = [0_u8; MAX_DIGITS];
+    let (digit_count, sci_exp) = if float == F::ZERO {
+        digits[0] = b'0';
+        (1, 0)
+    } else {
+        shortest_digits(float, radix, &mut digits)
+    };
+
+    // SAFETY: safe since `bytes` is large enough to hold the digits.
+    unsafe { write_digits(bytes, &mut digits, digit_count, sci_exp, format, options) }
+}
+
+/// Exact double-double-to-string algorithm, with any radix.
+///
+/// This assumes the double-double is:
+///     1). Normalized.
+///     2). Non-special (NaN or Infinite).
+///     3). Non-negative.
+///
+/// # Safety
+///
+/// Safe as long as `bytes` is large enough to hold the number of
+/// significant digits, any (optional) leading or trailing zeros,
+/// and the scientific exponent.
+///
+/// # Panics
+///
+/// Panics if the exponent base and the mantissa radix are not the same.
+#[cfg(feature = "double-double")]
+pub unsafe fn write_double_double<Fmt: Format>(
+    hi: f64,
+    lo: f64,
+    bytes: &mut [u8],
+    format: Fmt,
+    options: &Options,
+) -> usize {
+    // PRECONDITIONS
+
+    // Assert no special cases remain, no negative numbers,
+    // and a valid format.
+    assert!(format.is_valid());
+    assert!(format.mantissa_radix() == format.exponent_base());
+    debug_assert!(hi.is_finite());
+    debug_assert!(hi >= 0.0);
+
+    // Write our mantissa digits to a temporary buffer.
+    let radix = format.radix();
+    let mut digits = [0_u8; DOUBLE_DOUBLE_DIGITS];
+    let (digit_count, sci_exp) = if hi == 0.0 {
+        digits[0] = b'0';
+        (1, 0)
+    } else {
+        shortest_double_double_digits(hi, lo, radix, &mut digits)
+    };
+
+    // SAFETY: safe since `bytes` is large enough to hold the digits.
+    unsafe { write_digits(bytes, &mut digits, digit_count, sci_exp, format, options) }
+}
+
+/// Round and write the shortest digits.
+///
+/// # Safety
+///
+/// Safe as long as `digit_count <= digits.len()`, and `bytes` is large
+/// enough to hold the number of significant digits, any (optional)
+/// leading or trailing zeros, and the scientific exponent.
+unsafe fn write_digits<Fmt: Format>(
+    bytes: &mut [u8],
+    digits: &mut [u8],
+    digit_count: usize,
+    sci_exp: i32,
+    format: Fmt,
+    options: &Options,
+) -> usize {
+    let radix = format.radix();
+    // SAFETY: safe since `digit_count <= digits.len()`.
+    let (count, carried) = unsafe { truncate_and_round(digits, digit_count, radix, options) };
+    let digit_count = count - rtrim_char_count(&digits[1..count], b'0');
+    let sci_exp = sci_exp + carried as i32;
+
+    write_float!(
+        format,
+        sci_exp,
+        options,
+        write_float_scientific,
+        write_float_positive_exponent,
+        write_float_negative_exponent,
+        args => bytes, digits, digit_count, sci_exp, format, options,
+    )
+}
+
+/// Round the number of digits based on the maximum digits.
+///
+/// Returns the digit count, and if the digits carried to the next digit.
+///
+/// # Safety
+///
+/// Safe as long as `digit_count <= digits.len()`.
+unsafe fn truncate_and_round(
+    digits: &mut [u8],
+    digit_count: usize,
+    radix: u32,
+    options: &Options,
+) -> (usize, bool) {
+    if radix == 10 {
+        // SAFETY: safe since `digit_count <= digits.len()`.
+        return unsafe { shared::truncate_and_round_decimal(digits, digit_count, options) };
+    }
+
+    let max_digits = match options.max_significant_digits() {
+        Some(max_digits) if max_digits.get() < digit_count => max_digits.get(),
+        _ => return (digit_count, false),
+    };
+    if options.round_mode() == RoundMode::Truncate {
+        return (max_digits, false);
+    }
+
+    // Compare the truncated digits to the halfway point, which is
+    // `radix / 2` for even radixes, and repeats `radix / 2` forever
+    // for odd radixes, so the digits cannot be halfway.
+    let halfway = digit_to_char_const(radix / 2, radix);
+    let truncated = &digits[max_digits..digit_count];
+    let is_above = if radix % 2 == 1 {
+        matches!(truncated.iter().find(|&&c| c != halfway), Some(&c) if c > halfway)
+    } else {
+        let is_odd = char_to_valid_digit_const(digits[max_digits - 1], radix) % 2 == 1;
+        let rest = &truncated[1..];
+        truncated[0] > halfway
+            || (truncated[0] == halfway && (is_odd || rest.iter().any(|&c| c != b'0')))
+    };
+    if is_above {
+        // SAFETY: safe since `max_digits < digit_count <= digits.len()`.
+        unsafe { shared::round_up(digits, max_digits, radix) }
+    } else {
+        (max_digits, false)
+    }
+}
+
+/// Write float to string in scientific notation.
+///
+/// # Safety
+///
+/// Safe as long as `bytes` is large enough to hold the number of digits
+/// and the scientific notation's exponent digits.
+pub unsafe fn write_float_scientific<Fmt: Format>(
+    bytes: &mut [u8],
+    digits: &[u8],
+    digit_count: usize,
+    sci_exp: i32,
+    format: Fmt,
+    options: &Options,
+) -> usize {
+    // Config options
+    assert!(format.is_valid());
+    let decimal_point = options.decimal_point();
+
+    // Determine the exact number of digits to write.
+    let exact_count = shared::min_exact_digits(digit_count, options);
+
+    // Write our significant digits
+    bytes[0] = digits[0];
+    bytes[1] = decimal_point;
+    let mut cursor = if !format.no_exponent_without_fraction()
+        && digit_count == 1
+        && options.trim_floats()
+    {
+        // No more digits and need to trim floats.
+        1
+    } else if digit_count == 1 && exact_count == 1 {
+        // Write a single, trailing 0.
+        bytes[2] = b'0';
+        3
+    } else {
+        // Write our significant digits, and any trailing zeros.
+        bytes[2..digit_count + 1].copy_from_slice(&digits[1..digit_count]);
+        for digit in bytes[digit_count + 1..exact_count + 1].iter_mut() {
+            *digit = b'0';
+        }
+        exact_count + 1
+    };
+
+    // Now, write our scientific notation.
+    // SAFETY: safe since bytes must be large enough to store the largest float.
+    unsafe { shared::write_exponent(bytes, &mut cursor, sci_exp, options.exponent(), format) };
+
+    cursor
+}
+
+/// Write negative float to string without scientific notation.
+/// Has a negative exponent (shift right) and no scientific notation.
+///
+/// # Safety
+///
+/// Safe as long as `bytes` is large enough to hold the number of
+/// significant digits and the leading zeros.
+pub unsafe fn write_float_negative_exponent<Fmt: Format>(
+    bytes: &mut [u8],
+    digits: &[u8],
+    digit_count: usize,
+    sci_exp: i32,
+    _: Fmt,
+    options: &Options,
+) -> usize {
+    debug_assert!(sci_exp < 0);
+
+    // Write our 0 digits.
+    let zeros = sci_exp.wrapping_neg() as usize;
+    bytes[0] = b'0';
+    bytes[1] = options.decimal_point();
+    for digit in bytes[2..zeros + 1].iter_mut() {
+        *digit = b'0';
+    }
+    let mut cursor = zeros + 1;
+
+    // Write out significant digits, and any trailing zeros.
+    let exact_count = shared::min_exact_digits(digit_count, options);
+    bytes[cursor..cursor + digit_count].copy_from_slice(&digits[..digit_count]);
+    for digit in bytes[cursor + digit_count..cursor + exact_count].iter_mut() {
+        *digit = b'0';
+    }
+    cursor += exact_count;
+
+    cursor
+}
+
+/// Write positive float to string without scientific notation.
+/// Has a positive exponent (shift left) and no scientific notation.
+///
+/// # Safety
+///
+/// Safe as long as `bytes` is large enough to hold the number of
+/// significant digits and the (optional) trailing zeros.
+pub unsafe fn write_float_positive_exponent<Fmt: Format>(
+    bytes: &mut [u8],
+    digits: &[u8],
+    mut digit_count: usize,
+    sci_exp: i32,
+    _: Fmt,
+    options: &Options,
+) -> usize {
+    debug_assert!(sci_exp >= 0);
+
+    // Config options
+    let decimal_point = options.decimal_point();
+
+    // Now need to write our significant digits.
+    let leading_digits = sci_exp as usize + 1;
+    let mut cursor: usize;
+    let mut trimmed = false;
+    if leading_digits >= digit_count {
+        // We have more leading digits than digits we wrote: write the
+        // digits, and then the remaining zeros.
+        bytes[..digit_count].copy_from_slice(&digits[..digit_count]);
+        for digit in bytes[digit_count..leading_digits].iter_mut() {
+            *digit = b'0';
+        }
+        cursor = leading_digits;
+        digit_count = leading_digits;
+        // Only write decimal point if we're not trimming floats.
+        if !options.trim_floats() {
+            bytes[cursor] = decimal_point;
+            bytes[cursor + 1] = b'0';
+            cursor += 2;
+            digit_count += 1;
+        } else {
+            trimmed = true;
+        }
+    } else {
+        // Write the digits before and after the decimal point.
+        bytes[..leading_digits].copy_from_slice(&digits[..leading_digits]);
+        bytes[leading_digits] = decimal_point;
+        bytes[leading_digits + 1..digit_count + 1]
+            .copy_from_slice(&digits[leading_digits..digit_count]);
+        cursor = digit_count + 1;
+    }
+
+    // Determine the exact number of digits to write.
+    let exact_count = shared::min_exact_digits(digit_count, options);
+
+    // Check if we need to write more trailing digits.
+    if !trimmed && exact_count > digit_count {
+        let zeros = exact_count - digit_count;
+        for digit in bytes[cursor..cursor + zeros].iter_mut() {
+            *digit = b'0';
+        }
+        cursor += zeros;
+    }
+
+    cursor
+}
@@ -29,7 +29,7 @@ use crate::options::Options;
 use crate::shared;
 use lexical_util::algorithm::rtrim_char_count;
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_util::num::{Float, Integer};
 use lexical_write_integer::write::WriteInteger;
 
@@ -49,9 +49,10 @@ use lexical_write_integer::write::WriteInteger;
 /// Panics if the radix for the significant digits is not 16, if
 /// the exponent base is not 2, or if the radix for the exponent
 /// digits is not 10.
-pub unsafe fn write_float<F: Float, const FORMAT: u128>(
+pub unsafe fn write_float<F: Float, Fmt: Format>(
     float: F,
     bytes: &mut [u8],
+    format: Fmt,
     options: &Options,
 ) -> usize
 where
@@ -61,7 +62,6 @@ where
 
     // Assert no special cases remain, no negative numbers,
     // and a valid format.
-    let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
     debug_assert!(!float.is_special());
     debug_assert!(float >= F::ZERO);
@@ -107,14 +107,14 @@ where
     }
 
     write_float!(
-        FORMAT,
+        format,
         sci_exp,
         options,
         write_float_scientific,
         write_float_positive_exponent,
         write_float_negative_exponent,
         generic => _,
-        args => mantissa, exp, sci_exp, bytes, options,
+        args => mantissa, exp, sci_exp, bytes, format, options,
     )
 }
 
@@ -130,11 +130,12 @@ where
 /// The mantissa must be truncated and rounded, prior to calling this,
 /// based on the number of maximum digits.
 #[inline]
-pub unsafe fn write_float_scientific<M, const FORMAT: u128>(
+pub unsafe fn write_float_scientific<M, Fmt: Format>(
     mantissa: M,
     exp: i32,
     sci_exp: i32,
     bytes: &mut [u8],
+    format: Fmt,
     options: &Options,
 ) -> usize
 where
@@ -145,7 +146,6 @@ where
     debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options
-    let format = NumberFormat::<{ FORMAT }> {};
     let bits_per_digit = fast_log2(format.mantissa_radix());
     let bits_per_base = fast_log2(format.exponent_base());
     let decimal_point = options.decimal_point();
@@ -161,7 +161,7 @@ where
 
     // SAFETY: safe since the buffer must be larger than `M::FORMATTED_SIZE`.
     let digit_count = unsafe {
-        let count = value.write_mantissa::<M, FORMAT>(&mut index_unchecked_mut!(bytes[1..]));
+        let count = value.write_mantissa::<M, _>(&mut index_unchecked_mut!(bytes[1..]), format);
         index_unchecked_mut!(bytes[0] = bytes[1]);
         index_unchecked_mut!(bytes[1]) = decimal_point;
         let zeros = rtrim_char_count(&index_unchecked!(bytes[2..count + 1]), b'0');
@@ -199,7 +199,7 @@ where
     // SAFETY: safe if bytes is large enough to store all digits.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit, bits_per_base);
     unsafe {
-        shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent())
+        shared::write_exponent(bytes, &mut cursor, scaled_sci_exp, options.exponent(), format)
     };
 
     cursor
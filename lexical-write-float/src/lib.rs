@@ -76,10 +76,14 @@ mod shared;
 pub mod algorithm;
 pub mod binary;
 pub mod compact;
+pub mod complex;
+pub mod decimal;
+pub mod exact;
 pub mod float;
 pub mod hex;
 pub mod options;
 pub mod radix;
+pub mod rational;
 pub mod table;
 pub mod write;
 
@@ -88,13 +92,29 @@ mod table_dragonbox;
 mod table_grisu;
 
 // Re-exports
-pub use self::api::{ToLexical, ToLexicalWithOptions};
+pub use self::api::{ToLexical, ToLexicalWithFormat, ToLexicalWithOptions};
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder, RoundMode};
+#[cfg(feature = "rational")]
+pub use self::rational::{write_rational, write_rational_with_format};
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
+#[cfg(feature = "complex")]
+pub use lexical_util::complex::Complex;
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
+#[cfg(feature = "custom-float")]
+pub use lexical_util::custom_float::{self, f8e4m3, f8e5m2, tf32, CustomFloat};
+#[cfg(feature = "decimal")]
+pub use lexical_util::decimal::{decimal128, decimal32, decimal64, DecimalFloat};
+#[cfg(feature = "double-double")]
+pub use lexical_util::double_double::DoubleDouble;
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
+#[cfg(feature = "f80")]
+pub use lexical_util::f80::f80;
+#[cfg(feature = "f128")]
+pub use lexical_util::f128::f128;
 pub use lexical_util::format::{self, NumberFormatBuilder};
 pub use lexical_util::options::WriteOptions;
+#[cfg(feature = "rational")]
+pub use lexical_util::rational::Rational;
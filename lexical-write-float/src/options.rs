@@ -4,7 +4,7 @@ use core::{mem, num};
 use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
 use lexical_util::constants::FormattedSize;
 use lexical_util::error::Error;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_util::options::{self, WriteOptions};
 use lexical_util::result::Result;
 use static_assertions::const_assert;
@@ -32,6 +32,11 @@ pub enum RoundMode {
 const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 const_assert!(MAX_SPECIAL_STRING_LENGTH < f32::FORMATTED_SIZE_DECIMAL);
 
+/// Maximum length for the imaginary unit.
+///
+/// The formatted size of complex numbers allows for this many bytes.
+pub(crate) const MAX_IMAGINARY_UNIT_LENGTH: usize = 4;
+
 /// Builder for `Options`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OptionsBuilder {
@@ -65,6 +70,8 @@ pub struct OptionsBuilder {
     nan_string: Option<&'static [u8]>,
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+    /// Suffix for the imaginary part of complex numbers.
+    imaginary_unit: Option<&'static [u8]>,
 }
 
 impl OptionsBuilder {
@@ -83,6 +90,7 @@ impl OptionsBuilder {
             decimal_point: b'.',
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
+            imaginary_unit: Some(b"i"),
         }
     }
 
@@ -148,6 +156,12 @@ impl OptionsBuilder {
         self.inf_string
     }
 
+    /// Get the suffix for the imaginary part of complex numbers.
+    #[inline(always)]
+    pub const fn get_imaginary_unit(&self) -> Option<&'static [u8]> {
+        self.imaginary_unit
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -220,6 +234,15 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the suffix for the imaginary part of complex numbers.
+    ///
+    /// If `None`, complex numbers are written as an `(a,b)` tuple.
+    #[inline(always)]
+    pub const fn imaginary_unit(mut self, imaginary_unit: Option<&'static [u8]>) -> Self {
+        self.imaginary_unit = imaginary_unit;
+        self
+    }
+
     // BUILDERS
 
     /// Determine if `nan_str` is valid.
@@ -264,6 +287,27 @@ impl OptionsBuilder {
         }
     }
 
+    /// Determine if `imaginary_unit` is valid.
+    #[inline(always)]
+    #[allow(clippy::if_same_then_else, clippy::needless_bool)]
+    pub const fn imaginary_unit_is_valid(&self) -> bool {
+        if self.imaginary_unit.is_none() {
+            return true;
+        }
+
+        let unit = unwrap_str(self.imaginary_unit);
+        let length = unit.len();
+        if length == 0 || length > MAX_IMAGINARY_UNIT_LENGTH {
+            false
+        } else if !is_valid_letter_slice(unit) {
+            false
+        } else if is_exponent_letter(unit[0], self.exponent) {
+            false
+        } else {
+            true
+        }
+    }
+
     /// Check if the builder state is valid.
     #[inline(always)]
     #[allow(clippy::if_same_then_else, clippy::needless_bool)]
@@ -276,6 +320,8 @@ impl OptionsBuilder {
             false
         } else if !self.inf_str_is_valid() {
             false
+        } else if !self.imaginary_unit_is_valid() {
+            false
         } else {
             true
         }
@@ -285,9 +331,10 @@ impl OptionsBuilder {
     ///
     /// # Safety
     ///
-    /// Safe as long as `is_valid` is true. If `nan_string` or `inf_string`
-    /// are too long, writing special floats may lead to buffer overflows,
-    /// and therefore severe security vulnerabilities.
+    /// Safe as long as `is_valid` is true. If `nan_string`, `inf_string`
+    /// or `imaginary_unit` are too long, writing special floats or complex
+    /// numbers may lead to buffer overflows, and therefore severe security
+    /// vulnerabilities.
     #[inline(always)]
     pub const unsafe fn build_unchecked(&self) -> Options {
         Options {
@@ -301,6 +348,7 @@ impl OptionsBuilder {
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
+            imaginary_unit: self.imaginary_unit,
         }
     }
 
@@ -330,6 +378,17 @@ impl OptionsBuilder {
             }
         }
 
+        if self.imaginary_unit.is_some() {
+            let unit = unwrap_str(self.imaginary_unit);
+            if unit.is_empty() || !is_valid_letter_slice(unit) {
+                return Err(Error::InvalidImaginaryUnit);
+            } else if is_exponent_letter(unit[0], self.exponent) {
+                return Err(Error::InvalidImaginaryUnit);
+            } else if unit.len() > MAX_IMAGINARY_UNIT_LENGTH {
+                return Err(Error::ImaginaryUnitTooLong);
+            }
+        }
+
         let min_digits = unwrap_or_zero_usize(self.min_significant_digits);
         let max_digits = unwrap_or_max_usize(self.max_significant_digits);
         if max_digits < min_digits {
@@ -401,6 +460,8 @@ pub struct Options {
     nan_string: Option<&'static [u8]>,
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+    /// Suffix for the imaginary part of complex numbers.
+    imaginary_unit: Option<&'static [u8]>,
 }
 
 impl Options {
@@ -496,6 +557,12 @@ impl Options {
         self.inf_string
     }
 
+    /// Get the suffix for the imaginary part of complex numbers.
+    #[inline(always)]
+    pub const fn imaginary_unit(&self) -> Option<&'static [u8]> {
+        self.imaginary_unit
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -609,6 +676,20 @@ impl Options {
         self.inf_string = inf_string
     }
 
+    /// Set the suffix for the imaginary part of complex numbers.
+    /// Unsafe, use the builder API for option validation.
+    ///
+    /// # Safety
+    ///
+    /// Unsafe if `imaginary_unit.len() > MAX_IMAGINARY_UNIT_LENGTH`. This
+    /// might cause a complex number larger than the buffer length to be
+    /// written, causing a buffer overflow, potentially a severe security
+    /// vulnerability.
+    #[inline(always)]
+    pub unsafe fn set_imaginary_unit(&mut self, imaginary_unit: Option<&'static [u8]>) {
+        self.imaginary_unit = imaginary_unit
+    }
+
     // BUILDERS
 
     /// Get WriteFloatOptionsBuilder as a static function.
@@ -631,6 +712,7 @@ impl Options {
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
+            imaginary_unit: self.imaginary_unit,
         }
     }
 }
@@ -649,39 +731,68 @@ impl WriteOptions for Options {
     }
 
     #[inline(always)]
-    fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
-        let format = NumberFormat::<{ FORMAT }> {};
-
+    fn buffer_size_with_format<T: FormattedSize, Fmt: Format>(&self, format: Fmt) -> usize {
         // At least 2 for the decimal point and sign.
         let mut count: usize = 2;
 
+        // Wide floats, like f128, have more digits and larger exponents.
+        let is_wide = T::FORMATTED_SIZE_DECIMAL > f64::FORMATTED_SIZE_DECIMAL;
+
         // First need to calculate maximum number of digits from leading or
         // trailing zeros, IE, the exponent break.
         if !format.no_exponent_notation() {
             let min_exp = self.negative_exponent_break().map_or(-5, |x| x.get());
             let max_exp = self.positive_exponent_break().map_or(9, |x| x.get());
             let exp = min_exp.abs().max(max_exp) as usize;
-            if cfg!(feature = "power-of-two") && exp < 13 {
-                // 11 for the exponent digits in binary, 1 for the sign, 1 for the symbol
-                count += 13;
-            } else if exp < 5 {
-                // 3 for the exponent digits in decimal, 1 for the sign, 1 for the symbol
-                count += 5;
+            // 11 (15 if wide) exponent digits in binary, or 3 (4 if wide) in
+            // decimal, with 1 for the sign and 1 for the symbol.
+            let (binary, decimal) = if is_wide {
+                (17, 6)
+            } else {
+                (13, 5)
+            };
+            if cfg!(feature = "power-of-two") && exp < binary {
+                count += binary;
+            } else if exp < decimal {
+                count += decimal;
             } else {
                 // More leading or trailing zeros than the exponent digits.
                 count += exp;
             }
         } else if cfg!(feature = "power-of-two") {
-            // Min is 2^-1075.
-            count += 1075;
+            // Min is 2^-1075, or 2^-16494 if wide.
+            count += if is_wide {
+                16494
+            } else {
+                1075
+            };
         } else {
-            // Min is 10^-324.
-            count += 324;
+            // Min is 10^-324, or 10^-4966 if wide.
+            count += if is_wide {
+                4966
+            } else {
+                324
+            };
         }
 
         // Now add the number of significant digits.
         let radix = format.radix();
-        let formatted_digits = if radix == 10 {
+        let formatted_digits = if T::FORMATTED_SIZE_DECIMAL > 512 {
+            // Double-doubles may have digits down to the smallest denormal,
+            // so 633 significant digits in decimal, and 2100 in binary.
+            if radix == 10 {
+                640
+            } else {
+                2112
+            }
+        } else if is_wide {
+            // 36 significant digits for f128 in decimal, and 113 in binary.
+            if radix == 10 {
+                40
+            } else {
+                128
+            }
+        } else if radix == 10 {
             // Really should be 18, but add some extra to be cautious.
             28
         } else {
@@ -739,6 +850,12 @@ const fn unwrap_or_max_usize(option: OptionUsize) -> usize {
     }
 }
 
+/// Determine if a letter is the exponent character, ignoring case.
+#[inline(always)]
+const fn is_exponent_letter(c: u8, exponent: u8) -> bool {
+    c | 0x20 == exponent | 0x20
+}
+
 /// Unwrap `Option` as a const fn.
 #[inline(always)]
 const fn unwrap_str(option: Option<&'static [u8]>) -> &'static [u8] {
@@ -807,6 +924,7 @@ pub const PYTHON_LITERAL: Options = unsafe {
     Options::builder()
         .nan_string(options::PYTHON_LITERAL)
         .inf_string(options::PYTHON_LITERAL)
+        .imaginary_unit(options::PYTHON_LITERAL_IMAGINARY)
         .build_unchecked()
 };
 const_assert!(PYTHON_LITERAL.is_valid());
@@ -980,6 +1098,7 @@ const_assert!(KOTLIN_STRING.is_valid());
 pub const JULIA_LITERAL: Options = unsafe {
     Options::builder()
         .inf_string(options::JULIA_LITERAL_INF)
+        .imaginary_unit(options::JULIA_LITERAL_IMAGINARY)
         .build_unchecked()
 };
 const_assert!(JULIA_LITERAL.is_valid());
@@ -18,7 +18,7 @@ use core::mem;
 use lexical_util::algorithm::{ltrim_char_count, rtrim_char_count};
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 use lexical_util::digit::{char_to_digit_const, digit_to_char_const};
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_util::num::Float;
 use lexical_write_integer::write::WriteInteger;
 
@@ -41,9 +41,10 @@ use lexical_write_integer::write::WriteInteger;
 ///
 /// Panics if exponent notation is used.
 #[allow(clippy::collapsible_if)]
-pub unsafe fn write_float<F: Float, const FORMAT: u128>(
+pub unsafe fn write_float<F: Float, Fmt: Format>(
     float: F,
     bytes: &mut [u8],
+    format: Fmt,
     options: &Options,
 ) -> usize
 where
@@ -52,7 +53,6 @@ where
     // PRECONDITIONS
 
     // Assert no special cases remain, no negative numbers, and a valid format.
-    let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
     debug_assert!(!float.is_special());
     debug_assert!(float >= F::ZERO);
@@ -178,14 +178,14 @@ where
     let zero_count = ltrim_char_count(digits, b'0');
     let sci_exp: i32 = initial_cursor as i32 - integer_cursor as i32 - zero_count as i32 - 1;
     write_float!(
-        FORMAT,
+        format,
         sci_exp,
         options,
         write_float_scientific,
         write_float_nonscientific,
         write_float_nonscientific,
         args => sci_exp, &mut buffer, bytes, initial_cursor,
-                integer_cursor, fraction_cursor, options,
+                integer_cursor, fraction_cursor, format, options,
     )
 }
 
@@ -200,22 +200,23 @@ where
 ///
 /// The mantissa must be truncated and rounded, prior to calling this,
 /// based on the number of maximum digits. In addition, `exponent_base`
-/// and `mantissa_radix` in `FORMAT` must be identical.
+/// and `mantissa_radix` in `format` must be identical.
 #[inline]
-pub unsafe fn write_float_scientific<const FORMAT: u128>(
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn write_float_scientific<Fmt: Format>(
     sci_exp: i32,
     buffer: &mut [u8],
     bytes: &mut [u8],
     initial_cursor: usize,
     integer_cursor: usize,
     fraction_cursor: usize,
+    format: Fmt,
     options: &Options,
 ) -> usize {
     // PRECONDITIONS
     debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options.
-    let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
     let decimal_point = options.decimal_point();
 
@@ -281,7 +282,7 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe if bytes is large enough to store the largest float with the smallest radix.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent(bytes, &mut cursor, sci_exp, options.exponent(), format) };
 
     cursor
 }
@@ -293,20 +294,21 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 /// Safe as long as `bytes` is large enough to hold the number of
 /// significant digits and the leading zeros.
 #[inline]
-pub unsafe fn write_float_nonscientific<const FORMAT: u128>(
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn write_float_nonscientific<Fmt: Format>(
     _: i32,
     buffer: &mut [u8],
     bytes: &mut [u8],
     initial_cursor: usize,
     integer_cursor: usize,
     fraction_cursor: usize,
+    format: Fmt,
     options: &Options,
 ) -> usize {
     // PRECONDITIONS
     debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options.
-    let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
     let decimal_point = options.decimal_point();
 
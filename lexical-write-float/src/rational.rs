@@ -0,0 +1,48 @@
+//! Write exact rationals as `p/q` literals.
+//!
+//! The numerator and denominator are written in the mantissa radix of the
+//! number format, and integers are written without a denominator, so the
+//! output round-trips through the rational parser. Floats are converted
+//! to their exact dyadic fraction with [`Rational::from_float`], or to the
+//! best approximation with a bounded denominator with
+//! [`Rational::limit_denominator`].
+
+#![cfg(feature = "rational")]
+#![doc(hidden)]
+
+use lexical_util::format::{Format, NumberFormat};
+use lexical_util::rational::Rational;
+use lexical_write_integer::bignum::write_limbs_vec;
+
+/// Write a rational to a new buffer.
+///
+/// # Panics
+///
+/// Panics if the number format is not valid.
+#[inline]
+pub fn write_rational<const FORMAT: u128>(value: &Rational) -> Vec<u8> {
+    write_rational_with_format(value, NumberFormat::<{ FORMAT }> {})
+}
+
+/// Write a rational to a new buffer, for a number format value.
+///
+/// # Panics
+///
+/// Panics if the number format is not valid.
+pub fn write_rational_with_format<Fmt: Format>(value: &Rational, format: Fmt) -> Vec<u8> {
+    assert!(format.is_valid());
+
+    let radix = format.mantissa_radix();
+    let mut bytes = Vec::new();
+    if value.is_negative() {
+        bytes.push(b'-');
+    } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+        bytes.push(b'+');
+    }
+    bytes.extend_from_slice(&write_limbs_vec(value.numerator(), radix));
+    if !value.is_integer() {
+        bytes.push(b'/');
+        bytes.extend_from_slice(&write_limbs_vec(value.denominator(), radix));
+    }
+    bytes
+}
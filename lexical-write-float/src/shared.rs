@@ -2,7 +2,7 @@
 
 use crate::options::{Options, RoundMode};
 use lexical_util::digit::{char_to_valid_digit_const, digit_to_char_const};
-use lexical_util::format::NumberFormat;
+use lexical_util::format::Format;
 use lexical_write_integer::write::WriteInteger;
 
 /// Get the exact number of digits from a minimum bound.
@@ -126,12 +126,12 @@ pub unsafe fn truncate_and_round_decimal(
 /// Safe if `bytes` is large enough to hold the largest possible exponent,
 /// with an extra byte for the sign.
 #[cfg_attr(not(feature = "compact"), inline)]
-pub unsafe fn write_exponent_sign<const FORMAT: u128>(
+pub unsafe fn write_exponent_sign<Fmt: Format>(
     bytes: &mut [u8],
     cursor: &mut usize,
     exp: i32,
+    format: Fmt,
 ) -> u32 {
-    let format = NumberFormat::<{ FORMAT }> {};
     if exp < 0 {
         // SAFETY: safe if bytes is large enough to hold the output
         unsafe { index_unchecked_mut!(bytes[*cursor]) = b'-' };
@@ -154,17 +154,18 @@ pub unsafe fn write_exponent_sign<const FORMAT: u128>(
 /// Safe if the buffer can hold all the significant digits and the sign
 /// starting from cursor.
 #[cfg_attr(not(feature = "compact"), inline)]
-pub unsafe fn write_exponent<const FORMAT: u128>(
+pub unsafe fn write_exponent<Fmt: Format>(
     bytes: &mut [u8],
     cursor: &mut usize,
     exp: i32,
     exponent_character: u8,
+    format: Fmt,
 ) {
     *cursor += unsafe {
         index_unchecked_mut!(bytes[*cursor]) = exponent_character;
         *cursor += 1;
-        let positive_exp = write_exponent_sign::<FORMAT>(bytes, cursor, exp);
-        positive_exp.write_exponent::<u32, FORMAT>(&mut index_unchecked_mut!(bytes[*cursor..]))
+        let positive_exp = write_exponent_sign(bytes, cursor, exp, format);
+        positive_exp.write_exponent::<u32, _>(&mut index_unchecked_mut!(bytes[*cursor..]), format)
     };
 }
 
@@ -180,26 +181,23 @@ macro_rules! write_float {
         $(generic => $generic:tt,)?
         args => $($args:expr,)*
     ) => {{
-        use lexical_util::format::NumberFormat;
-
-        let format = NumberFormat::<{ $format }> {};
         let min_exp = $options.negative_exponent_break().map_or(-5, |x| x.get());
         let max_exp = $options.positive_exponent_break().map_or(9, |x| x.get());
 
         let outside_break = $sci_exp < min_exp || $sci_exp > max_exp;
-        let require_exponent = format.required_exponent_notation() || outside_break;
-        if !format.no_exponent_notation() && require_exponent {
+        let require_exponent = $format.required_exponent_notation() || outside_break;
+        if !$format.no_exponent_notation() && require_exponent {
             // Write digits in scientific notation.
             // SAFETY: safe as long as bytes is large enough to hold all the digits.
-            unsafe { $write_scientific::<$($generic,)? FORMAT>($($args,)*) }
+            unsafe { $write_scientific::<$($generic,)? _>($($args,)*) }
         } else if $sci_exp >= 0 {
             // Write positive exponent without scientific notation.
             // SAFETY: safe as long as bytes is large enough to hold all the digits.
-            unsafe { $write_positive::<$($generic,)? FORMAT>($($args,)*) }
+            unsafe { $write_positive::<$($generic,)? _>($($args,)*) }
         } else {
             // Write negative exponent without scientific notation.
             // SAFETY: safe as long as bytes is large enough to hold all the digits.
-            unsafe { $write_negative::<$($generic,)? FORMAT>($($args,)*) }
+            unsafe { $write_negative::<$($generic,)? _>($($args,)*) }
         }
     }};
 }
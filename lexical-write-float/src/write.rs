@@ -9,6 +9,10 @@ use crate::binary;
 /// Select the back-end.
 #[cfg(feature = "compact")]
 use crate::compact::write_float as write_float_decimal;
+#[cfg(feature = "decimal")]
+use crate::decimal;
+#[cfg(any(feature = "f80", feature = "f128", feature = "custom-float", feature = "double-double"))]
+use crate::exact;
 #[cfg(feature = "power-of-two")]
 use crate::hex;
 #[cfg(feature = "radix")]
@@ -19,9 +23,21 @@ use crate::options::Options;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::constants::FormattedSize;
+#[cfg(feature = "custom-float")]
+use lexical_util::custom_float::CustomFloat;
+#[cfg(feature = "decimal")]
+use lexical_util::decimal::{decimal128, decimal32, decimal64, DecimalFloat};
+#[cfg(feature = "double-double")]
+use lexical_util::double_double::DoubleDouble;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::NumberFormat;
+#[cfg(feature = "f80")]
+use lexical_util::f80::f80;
+#[cfg(feature = "f128")]
+use lexical_util::f128::f128;
+use lexical_util::format::Format;
+#[cfg(any(feature = "f80", feature = "f128", feature = "custom-float"))]
+use lexical_util::num::Float;
 use lexical_write_integer::write::WriteInteger;
 
 /// Write float trait.
@@ -50,12 +66,16 @@ pub trait WriteFloat: RawFloat {
     /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
     /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
     #[inline]
-    unsafe fn write_float<const FORMAT: u128>(self, bytes: &mut [u8], options: &Options) -> usize
+    unsafe fn write_float<Fmt: Format>(
+        self,
+        bytes: &mut [u8],
+        format: Fmt,
+        options: &Options,
+    ) -> usize
     where
         Self::Unsigned: FormattedSize + WriteInteger,
     {
         // Validate our format options.
-        let format = NumberFormat::<FORMAT> {};
         assert!(format.is_valid());
         // Avoid any false assumptions for 128-bit floats.
         assert!(Self::BITS <= 64);
@@ -91,11 +111,11 @@ pub trait WriteFloat: RawFloat {
                 let exponent_base = format.exponent_base();
                 count
                     + if radix == 10 {
-                        unsafe { write_float_decimal::<_, FORMAT>(float, bytes, options) }
+                        unsafe { write_float_decimal(float, bytes, format, options) }
                     } else if radix != exponent_base {
-                        unsafe { hex::write_float::<_, FORMAT>(float, bytes, options) }
+                        unsafe { hex::write_float(float, bytes, format, options) }
                     } else {
-                        unsafe { binary::write_float::<_, FORMAT>(float, bytes, options) }
+                        unsafe { binary::write_float(float, bytes, format, options) }
                     }
             }
 
@@ -106,20 +126,20 @@ pub trait WriteFloat: RawFloat {
                 let exponent_base = format.exponent_base();
                 count
                     + if radix == 10 {
-                        unsafe { write_float_decimal::<_, FORMAT>(float, bytes, options) }
+                        unsafe { write_float_decimal(float, bytes, format, options) }
                     } else if radix != exponent_base {
-                        unsafe { hex::write_float::<_, FORMAT>(float, bytes, options) }
+                        unsafe { hex::write_float(float, bytes, format, options) }
                     } else if matches!(radix, 2 | 4 | 8 | 16 | 32) {
-                        unsafe { binary::write_float::<_, FORMAT>(float, bytes, options) }
+                        unsafe { binary::write_float(float, bytes, format, options) }
                     } else {
-                        unsafe { radix::write_float::<_, FORMAT>(float, bytes, options) }
+                        unsafe { radix::write_float(float, bytes, format, options) }
                     }
             }
 
             #[cfg(not(feature = "power-of-two"))]
             {
                 // SAFETY: safe if the buffer can hold the significant digits
-                count + unsafe { write_float_decimal::<_, FORMAT>(float, bytes, options) }
+                count + unsafe { write_float_decimal(float, bytes, format, options) }
             }
         } else if self.is_nan() {
             // SAFETY: safe if the buffer is longer than the NaN string.
@@ -167,18 +187,204 @@ macro_rules! write_float_impl {
 write_float_impl! { f32 f64 }
 
 #[cfg(feature = "f16")]
-macro_rules! write_float_as_f32 {
-    ($($t:ty)*) => ($(
-        impl WriteFloat for $t {
-            #[inline]
-            unsafe fn write_float<const FORMAT: u128>(self, bytes: &mut [u8], options: &Options) -> usize
-            {
-                // SAFETY: safe if `bytes` is large enough to hold the written bytes.
-                unsafe { self.as_f32().write_float::<FORMAT>(bytes, options) }
-            }
-        }
-    )*)
+write_float_impl! { bf16 f16 }
+
+/// Write float trait for floats wider than 64 bits.
+///
+/// This writes the shortest digits that round-trip with big integers,
+/// rather than the extended-float algorithms, for any radix.
+#[cfg(any(feature = "f80", feature = "f128", feature = "custom-float"))]
+pub trait WriteExactFloat: Float {
+    /// Forward write float parameters to the exact backend.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as the buffer can hold the number of bytes given by
+    /// [`buffer_size`], which is larger than for [`WriteFloat`], since
+    /// wide floats have more significant digits and larger exponents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number format is invalid, or if the exponent base
+    /// does not equal the mantissa radix. It also panics if
+    /// `options.nan_string` or `options.inf_string` is None and asked
+    /// to serialize a NaN or Inf value.
+    ///
+    /// [`buffer_size`]: lexical_util::options::WriteOptions::buffer_size
+    #[inline]
+    unsafe fn write_float<Fmt: Format>(
+        self,
+        bytes: &mut [u8],
+        format: Fmt,
+        options: &Options,
+    ) -> usize {
+        // Validate our format options.
+        assert!(format.is_valid());
+
+        let (float, count, bytes) = if self < Self::ZERO {
+            bytes[0] = b'-';
+            (-self, 1, &mut bytes[1..])
+        } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+            bytes[0] = b'+';
+            (self, 1, &mut bytes[1..])
+        } else {
+            (self, 0, bytes)
+        };
+
+        // Handle special values.
+        let special = if !self.is_special() {
+            // SAFETY: safe if the buffer can hold the significant digits.
+            return count + unsafe { exact::write_float(float, bytes, format, options) };
+        } else if self.is_nan() {
+            // PANIC: cannot serialize NaN.
+            options.nan_string().expect("NaN explicitly disabled but asked to write NaN as string.")
+        } else {
+            // PANIC: cannot serialize inf.
+            options.inf_string().expect("Inf explicitly disabled but asked to write Inf as string.")
+        };
+        bytes[..special.len()].copy_from_slice(special);
+        count + special.len()
+    }
 }
 
-#[cfg(feature = "f16")]
-write_float_as_f32! { bf16 f16 }
+#[cfg(feature = "f80")]
+impl WriteExactFloat for f80 {}
+#[cfg(feature = "f128")]
+impl WriteExactFloat for f128 {}
+#[cfg(feature = "custom-float")]
+impl<const EXPONENT: u32, const MANTISSA: u32, const SPECIAL: u8> WriteExactFloat
+    for CustomFloat<EXPONENT, MANTISSA, SPECIAL>
+{
+}
+
+/// Write double-double trait.
+///
+/// This writes the shortest digits that round-trip to both the high
+/// and the low float, with big integers, for any radix.
+#[cfg(feature = "double-double")]
+pub trait WriteDoubleDouble {
+    /// Forward write float parameters to the exact backend.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as the buffer can hold the number of bytes given by
+    /// [`buffer_size`], which is larger than for [`WriteFloat`], since
+    /// the digits may extend to the smallest denormal float.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number format is invalid, or if the exponent base
+    /// does not equal the mantissa radix. It also panics if
+    /// `options.nan_string` or `options.inf_string` is None and asked
+    /// to serialize a NaN or Inf value.
+    ///
+    /// [`buffer_size`]: lexical_util::options::WriteOptions::buffer_size
+    unsafe fn write_float<Fmt: Format>(
+        self,
+        bytes: &mut [u8],
+        format: Fmt,
+        options: &Options,
+    ) -> usize;
+}
+
+#[cfg(feature = "double-double")]
+impl WriteDoubleDouble for DoubleDouble {
+    #[inline]
+    unsafe fn write_float<Fmt: Format>(
+        self,
+        bytes: &mut [u8],
+        format: Fmt,
+        options: &Options,
+    ) -> usize {
+        // Validate our format options.
+        assert!(format.is_valid());
+
+        // Normalize the pair, so the high float is the nearest float to the sum.
+        let value = self.normalize();
+        let (hi, lo, count, bytes) = if value.hi() < 0.0 {
+            bytes[0] = b'-';
+            (-value.hi(), -value.lo(), 1, &mut bytes[1..])
+        } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+            bytes[0] = b'+';
+            (value.hi(), value.lo(), 1, &mut bytes[1..])
+        } else {
+            (value.hi(), value.lo(), 0, bytes)
+        };
+
+        // Handle special values.
+        let special = if hi.is_finite() {
+            // SAFETY: safe if the buffer can hold the significant digits.
+            return count + unsafe { exact::write_double_double(hi, lo, bytes, format, options) };
+        } else if hi.is_nan() {
+            // PANIC: cannot serialize NaN.
+            options.nan_string().expect("NaN explicitly disabled but asked to write NaN as string.")
+        } else {
+            // PANIC: cannot serialize inf.
+            options.inf_string().expect("Inf explicitly disabled but asked to write Inf as string.")
+        };
+        bytes[..special.len()].copy_from_slice(special);
+        count + special.len()
+    }
+}
+
+/// Write decimal float trait.
+#[cfg(feature = "decimal")]
+pub trait WriteDecimalFloat: DecimalFloat {
+    /// Forward write float parameters to the decimal backend.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as the buffer can hold [`FORMATTED_SIZE_DECIMAL`]
+    /// elements. The digit precision options are not used, so they do
+    /// not change the number of digits written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number format is invalid, or if `options.nan_string`
+    /// or `options.inf_string` is None and asked to serialize a NaN or Inf
+    /// value. The radix and exponent base of the format are not used.
+    ///
+    /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
+    #[inline]
+    unsafe fn write_float<Fmt: Format>(
+        self,
+        bytes: &mut [u8],
+        format: Fmt,
+        options: &Options,
+    ) -> usize {
+        // Validate our format options.
+        assert!(format.is_valid());
+
+        // The sign of NaN is not written, like for binary floats.
+        let (count, bytes) = if self.is_sign_negative() && !self.is_nan() {
+            bytes[0] = b'-';
+            (1, &mut bytes[1..])
+        } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+            bytes[0] = b'+';
+            (1, &mut bytes[1..])
+        } else {
+            (0, bytes)
+        };
+
+        // Handle special values.
+        let special = if self.is_finite() {
+            // SAFETY: safe if the buffer can hold the significant digits.
+            return count + unsafe { decimal::write_float(self, bytes, format, options) };
+        } else if self.is_nan() {
+            // PANIC: cannot serialize NaN.
+            options.nan_string().expect("NaN explicitly disabled but asked to write NaN as string.")
+        } else {
+            // PANIC: cannot serialize inf.
+            options.inf_string().expect("Inf explicitly disabled but asked to write Inf as string.")
+        };
+        bytes[..special.len()].copy_from_slice(special);
+        count + special.len()
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl WriteDecimalFloat for decimal32 {}
+#[cfg(feature = "decimal")]
+impl WriteDecimalFloat for decimal64 {}
+#[cfg(feature = "decimal")]
+impl WriteDecimalFloat for decimal128 {}
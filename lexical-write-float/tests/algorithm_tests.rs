@@ -2,7 +2,7 @@
 
 use core::num;
 use lexical_util::constants::BUFFER_SIZE;
-use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{NumberFormat, NumberFormatBuilder};
 use lexical_util::num::Float;
 use lexical_write_float::algorithm::DragonboxFloat;
 use lexical_write_float::float::{ExtendedFloat80, RawFloat};
@@ -258,7 +258,8 @@ fn write_float_scientific(mant: u64, exp: i32, options: &Options, expected: &str
     let digit_count = f64::digit_count(fp.mant);
     let sci_exp = fp.exp + digit_count as i32 - 1;
     let count = unsafe {
-        algorithm::write_float_scientific::<f64, DECIMAL>(&mut buffer, fp, sci_exp, &options)
+        let format = NumberFormat::<{ DECIMAL }> {};
+        algorithm::write_float_scientific::<f64, _>(&mut buffer, fp, sci_exp, format, &options)
     };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
@@ -348,7 +349,14 @@ fn write_float_positive_exponent(mant: u64, exp: i32, options: &Options, expecte
     let digit_count = f64::digit_count(fp.mant);
     let sci_exp = fp.exp + digit_count as i32 - 1;
     let count = unsafe {
-        algorithm::write_float_positive_exponent::<f64, DECIMAL>(&mut buffer, fp, sci_exp, &options)
+        let format = NumberFormat::<{ DECIMAL }> {};
+        algorithm::write_float_positive_exponent::<f64, _>(
+            &mut buffer,
+            fp,
+            sci_exp,
+            format,
+            &options,
+        )
     };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
@@ -434,7 +442,14 @@ fn write_float_negative_exponent(mant: u64, exp: i32, options: &Options, expecte
     let digit_count = f64::digit_count(fp.mant);
     let sci_exp = fp.exp + digit_count as i32 - 1;
     let count = unsafe {
-        algorithm::write_float_negative_exponent::<f64, DECIMAL>(&mut buffer, fp, sci_exp, &options)
+        let format = NumberFormat::<{ DECIMAL }> {};
+        algorithm::write_float_negative_exponent::<f64, _>(
+            &mut buffer,
+            fp,
+            sci_exp,
+            format,
+            &options,
+        )
     };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
@@ -583,7 +598,8 @@ const F64_DATA: [f64; 33] = [
 
 fn write_float<T: RawFloat, const FORMAT: u128>(f: T, options: &Options, expected: &str) {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
-    let count = unsafe { algorithm::write_float::<_, FORMAT>(f, &mut buffer, options) };
+    let format = NumberFormat::<{ FORMAT }> {};
+    let count = unsafe { algorithm::write_float(f, &mut buffer, format, options) };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -601,6 +617,10 @@ fn f32_test() {
     write_float::<_, DECIMAL>(1.2345678901234567890e3f32, &options, "1234.5679");
     write_float::<_, DECIMAL>(2.3786281e+38f32, &options, "2.3786281e38");
 
+    // The integer check for the left endpoint only uses the fractional bits.
+    write_float::<_, DECIMAL>(f32::from_bits(0x4E00001E), &options, "536872800");
+    write_float::<_, DECIMAL>(f32::from_bits(0x4E000050), &options, "536876000");
+
     let options = Options::new();
     write_float::<_, DECIMAL>(2.3786281e+38f32, &options, "2.3786281e38");
 }
@@ -642,7 +662,8 @@ fn f32_roundtrip_test() {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
     let options = Options::builder().build().unwrap();
     for &float in F32_DATA.iter() {
-        let count = unsafe { algorithm::write_float::<_, DECIMAL>(float, &mut buffer, &options) };
+        let format = NumberFormat::<{ DECIMAL }> {};
+        let count = unsafe { algorithm::write_float(float, &mut buffer, format, &options) };
         let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
         let roundtrip = actual.parse::<f32>();
         assert_eq!(roundtrip, Ok(float));
@@ -719,7 +740,8 @@ fn f64_roundtrip_test() {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
     let options = Options::builder().build().unwrap();
     for &float in F64_DATA.iter() {
-        let count = unsafe { algorithm::write_float::<_, DECIMAL>(float, &mut buffer, &options) };
+        let format = NumberFormat::<{ DECIMAL }> {};
+        let count = unsafe { algorithm::write_float(float, &mut buffer, format, &options) };
         let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
         let roundtrip = actual.parse::<f64>();
         assert_eq!(roundtrip, Ok(float));
@@ -757,7 +779,8 @@ quickcheck! {
         if f.is_special() {
             true
         } else {
-            let count = unsafe { algorithm::write_float::<_, DECIMAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ DECIMAL }> {};
+            let count = unsafe { algorithm::write_float(f, &mut buffer, format, &options) };
             let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
             let roundtrip = actual.parse::<f32>();
             roundtrip == Ok(f)
@@ -772,7 +795,8 @@ quickcheck! {
         if f.is_special() {
             true
         } else {
-            let count = unsafe { algorithm::write_float::<_, DECIMAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ DECIMAL }> {};
+            let count = unsafe { algorithm::write_float(f, &mut buffer, format, &options) };
             let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
             let roundtrip = actual.parse::<f64>();
             roundtrip == Ok(f)
@@ -788,7 +812,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         let f = f.abs();
         if !f.is_special() {
-            let count = unsafe { algorithm::write_float::<_, DECIMAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ DECIMAL }> {};
+            let count = unsafe { algorithm::write_float(f, &mut buffer, format, &options) };
             let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
             let roundtrip = actual.parse::<f32>();
             prop_assert_eq!(roundtrip, Ok(f))
@@ -802,7 +827,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         let f = f.abs();
         if !f.is_special() {
-            let count = unsafe { algorithm::write_float::<_, DECIMAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ DECIMAL }> {};
+            let count = unsafe { algorithm::write_float(f, &mut buffer, format, &options) };
             let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
             let roundtrip = actual.parse::<f64>();
             prop_assert_eq!(roundtrip, Ok(f))
@@ -4,7 +4,7 @@ mod parse_radix;
 
 use core::num;
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
-use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{NumberFormat, NumberFormatBuilder};
 use lexical_util::num::{Float, Integer};
 use lexical_write_float::options::RoundMode;
 use lexical_write_float::{binary, Options};
@@ -177,7 +177,8 @@ where
     }
 
     let count = unsafe {
-        binary::write_float_scientific::<_, FORMAT>(mantissa, exp, sci_exp, &mut buffer, options)
+        let format = NumberFormat::<{ FORMAT }> {};
+        binary::write_float_scientific(mantissa, exp, sci_exp, &mut buffer, format, options)
     };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
@@ -464,11 +465,12 @@ fn write_float_negative_exponent<T: Float, const FORMAT: u128>(
     let sci_exp = exp + mantissa_bits - 1;
 
     let count = unsafe {
-        binary::write_float_negative_exponent::<_, FORMAT>(
+        binary::write_float_negative_exponent(
             mantissa,
             exp,
             sci_exp,
             &mut buffer,
+            NumberFormat::<{ FORMAT }> {},
             options,
         )
     };
@@ -707,11 +709,12 @@ fn write_float_positive_exponent<T: Float, const FORMAT: u128>(
     }
 
     let count = unsafe {
-        binary::write_float_positive_exponent::<_, FORMAT>(
+        binary::write_float_positive_exponent(
             mantissa,
             exp,
             sci_exp,
             &mut buffer,
+            NumberFormat::<{ FORMAT }> {},
             options,
         )
     };
@@ -951,7 +954,8 @@ where
     <T as Float>::Unsigned: WriteInteger + FormattedSize,
 {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
-    let count = unsafe { binary::write_float::<_, FORMAT>(f, &mut buffer, options) };
+    let format = NumberFormat::<{ FORMAT }> {};
+    let count = unsafe { binary::write_float(f, &mut buffer, format, options) };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -1098,7 +1102,8 @@ quickcheck! {
             true
         } else {
             let f = f.abs();
-            let count = unsafe { binary::write_float::<_, BINARY>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BINARY }> {};
+            let count = unsafe { binary::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 2, b'e');
             roundtrip == f
         }
@@ -1112,7 +1117,8 @@ quickcheck! {
             true
         } else {
             let f = f.abs();
-            let count = unsafe { binary::write_float::<_, OCTAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ OCTAL }> {};
+            let count = unsafe { binary::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 8, b'e');
             roundtrip == f
         }
@@ -1126,7 +1132,8 @@ quickcheck! {
             true
         } else {
             let f = f.abs();
-            let count = unsafe { binary::write_float::<_, BINARY>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BINARY }> {};
+            let count = unsafe { binary::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 2, b'e');
             roundtrip == f
         }
@@ -1140,7 +1147,8 @@ quickcheck! {
             true
         } else {
             let f = f.abs();
-            let count = unsafe { binary::write_float::<_, OCTAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ OCTAL }> {};
+            let count = unsafe { binary::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 8, b'e');
             roundtrip == f
         }
@@ -1155,7 +1163,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         if !f.is_special() {
             let f = f.abs();
-            let count = unsafe { binary::write_float::<_, BINARY>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BINARY }> {};
+            let count = unsafe { binary::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 2, b'e');
             prop_assert_eq!(roundtrip, f)
         }
@@ -1168,7 +1177,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         if !f.is_special() {
             let f = f.abs();
-            let count = unsafe { binary::write_float::<_, OCTAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ OCTAL }> {};
+            let count = unsafe { binary::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 8, b'e');
             prop_assert_eq!(roundtrip, f)
         }
@@ -1181,7 +1191,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         if !f.is_special() {
             let f = f.abs();
-            let count = unsafe { binary::write_float::<_, BINARY>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BINARY }> {};
+            let count = unsafe { binary::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 2, b'e');
             prop_assert_eq!(roundtrip, f)
         }
@@ -1194,7 +1205,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         if !f.is_special() {
             let f = f.abs();
-            let count = unsafe { binary::write_float::<_, OCTAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ OCTAL }> {};
+            let count = unsafe { binary::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 8, b'e');
             prop_assert_eq!(roundtrip, f)
         }
@@ -2,7 +2,7 @@
 
 use core::num;
 use lexical_util::constants::BUFFER_SIZE;
-use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{NumberFormat, NumberFormatBuilder};
 use lexical_util::num::Float;
 use lexical_write_float::float::{ExtendedFloat80, RawFloat};
 use lexical_write_float::{compact, Options, RoundMode};
@@ -354,7 +354,8 @@ fn grisu_test() {
 
 fn write_float<T: RawFloat, const FORMAT: u128>(f: T, options: &Options, expected: &str) {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
-    let count = unsafe { compact::write_float::<_, FORMAT>(f, &mut buffer, options) };
+    let format = NumberFormat::<{ FORMAT }> {};
+    let count = unsafe { compact::write_float(f, &mut buffer, format, options) };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -638,7 +639,8 @@ fn f32_roundtrip_test() {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
     let options = Options::builder().build().unwrap();
     for &float in F32_DATA.iter() {
-        let count = unsafe { compact::write_float::<_, DECIMAL>(float, &mut buffer, &options) };
+        let format = NumberFormat::<{ DECIMAL }> {};
+        let count = unsafe { compact::write_float(float, &mut buffer, format, &options) };
         let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
         let roundtrip = actual.parse::<f32>();
         assert_eq!(roundtrip, Ok(float));
@@ -649,7 +651,8 @@ fn write_float_scientific(digits: &mut [u8], k: i32, options: &Options, expected
     let mut buffer = [b'\x00'; BUFFER_SIZE];
     let ndigits = digits.len();
     let count = unsafe {
-        compact::write_float_scientific::<DECIMAL>(&mut buffer, digits, ndigits, k, &options)
+        let format = NumberFormat::<{ DECIMAL }> {};
+        compact::write_float_scientific(&mut buffer, digits, ndigits, k, format, &options)
     };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
@@ -684,7 +687,8 @@ fn write_float_positive_exponent(digits: &mut [u8], k: i32, options: &Options, e
     let mut buffer = [b'\x00'; 512];
     let ndigits = digits.len();
     let count = unsafe {
-        compact::write_float_positive_exponent::<DECIMAL>(&mut buffer, digits, ndigits, k, &options)
+        let format = NumberFormat::<{ DECIMAL }> {};
+        compact::write_float_positive_exponent(&mut buffer, digits, ndigits, k, format, &options)
     };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
@@ -711,7 +715,8 @@ fn write_float_negative_exponent(digits: &mut [u8], k: i32, options: &Options, e
     let mut buffer = [b'\x00'; 512];
     let ndigits = digits.len();
     let count = unsafe {
-        compact::write_float_negative_exponent::<DECIMAL>(&mut buffer, digits, ndigits, k, &options)
+        let format = NumberFormat::<{ DECIMAL }> {};
+        compact::write_float_negative_exponent(&mut buffer, digits, ndigits, k, format, &options)
     };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
@@ -752,7 +757,8 @@ fn f64_roundtrip_test() {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
     let options = Options::builder().build().unwrap();
     for &float in F64_DATA.iter() {
-        let count = unsafe { compact::write_float::<_, DECIMAL>(float, &mut buffer, &options) };
+        let format = NumberFormat::<{ DECIMAL }> {};
+        let count = unsafe { compact::write_float(float, &mut buffer, format, &options) };
         let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
         let roundtrip = actual.parse::<f64>();
         assert_eq!(roundtrip, Ok(float));
@@ -768,7 +774,8 @@ quickcheck! {
         if f.is_special() {
             true
         } else {
-            let count = unsafe { compact::write_float::<_, DECIMAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ DECIMAL }> {};
+            let count = unsafe { compact::write_float(f, &mut buffer, format, &options) };
             let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
             let roundtrip = actual.parse::<f32>();
             roundtrip == Ok(f)
@@ -783,7 +790,8 @@ quickcheck! {
         if f.is_special() {
             true
         } else {
-            let count = unsafe { compact::write_float::<_, DECIMAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ DECIMAL }> {};
+            let count = unsafe { compact::write_float(f, &mut buffer, format, &options) };
             let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
             let roundtrip = actual.parse::<f64>();
             roundtrip == Ok(f)
@@ -799,7 +807,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         let f = f.abs();
         if !f.is_special() {
-            let count = unsafe { compact::write_float::<_, DECIMAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ DECIMAL }> {};
+            let count = unsafe { compact::write_float(f, &mut buffer, format, &options) };
             let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
             let roundtrip = actual.parse::<f32>();
             prop_assert_eq!(roundtrip, Ok(f))
@@ -813,7 +822,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         let f = f.abs();
         if !f.is_special() {
-            let count = unsafe { compact::write_float::<_, DECIMAL>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ DECIMAL }> {};
+            let count = unsafe { compact::write_float(f, &mut buffer, format, &options) };
             let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
             let roundtrip = actual.parse::<f64>();
             prop_assert_eq!(roundtrip, Ok(f))
@@ -0,0 +1,109 @@
+#![cfg(feature = "complex")]
+
+use core::num;
+
+use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_write_float::{
+    options,
+    Complex,
+    FormattedSize,
+    Options,
+    ToLexical,
+    ToLexicalWithOptions,
+};
+
+const FORMAT: u128 = STANDARD;
+
+fn write<F>(value: Complex<F>) -> String
+where
+    Complex<F>: FormattedSize + ToLexical,
+{
+    let mut buffer = vec![b'\x00'; Complex::<F>::FORMATTED_SIZE_DECIMAL];
+    String::from_utf8(value.to_lexical(&mut buffer).to_vec()).unwrap()
+}
+
+fn write_with_options(value: Complex<f64>, options: &Options) -> String {
+    let mut buffer = [b'\x00'; 512];
+    let bytes = value.to_lexical_with_options::<FORMAT>(&mut buffer, options);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn write_test() {
+    assert_eq!(write(Complex::new(1.5, 2e-3)), "1.5+0.002i");
+    assert_eq!(write(Complex::new(1.5, -2e-3)), "1.5-0.002i");
+    assert_eq!(write(Complex::new(-1.0, -2.0)), "-1.0-2.0i");
+    assert_eq!(write(Complex::new(0.0, 0.0)), "0.0+0.0i");
+    assert_eq!(write(Complex::new(0.0, -0.0)), "0.0+0.0i");
+    assert_eq!(write(Complex::new(1e100, -1e-100)), "1.0e100-1.0e-100i");
+    assert_eq!(write(Complex::new(0.1f32, 0.2f32)), "0.1+0.2i");
+}
+
+#[test]
+fn write_special_test() {
+    assert_eq!(write(Complex::new(1.0, f64::INFINITY)), "1.0+infi");
+    assert_eq!(write(Complex::new(1.0, f64::NEG_INFINITY)), "1.0-infi");
+    assert_eq!(write(Complex::new(f64::NAN, f64::NAN)), "NaN+NaNi");
+    assert_eq!(write(Complex::new(f64::NEG_INFINITY, -f64::NAN)), "-inf+NaNi");
+}
+
+#[test]
+fn write_imaginary_unit_test() {
+    let options = Options::builder().imaginary_unit(Some(b"im")).build().unwrap();
+    assert_eq!(write_with_options(Complex::new(1.0, -2.0), &options), "1.0-2.0im");
+    assert_eq!(write_with_options(Complex::new(1.0, -2.0), &options::PYTHON_LITERAL), "1.0-2.0j");
+    assert_eq!(
+        write_with_options(Complex::new(1.0, f64::NEG_INFINITY), &options::JULIA_LITERAL),
+        "1.0-Infim"
+    );
+
+    // Without an imaginary unit, complex numbers are written as tuples.
+    let options = Options::builder().imaginary_unit(None).build().unwrap();
+    assert_eq!(write_with_options(Complex::new(1.0, -2.0), &options), "(1.0,-2.0)");
+    assert_eq!(write_with_options(Complex::new(-1e-10, f64::NAN), &options), "(-1.0e-10,NaN)");
+}
+
+#[test]
+fn write_options_test() {
+    // Both parts use the same float options.
+    let options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(3))
+        .trim_floats(true)
+        .build()
+        .unwrap();
+    assert_eq!(write_with_options(Complex::new(1.0, -core::f64::consts::PI), &options), "1-3.14i");
+}
+
+#[test]
+fn write_buffer_size_test() {
+    // The longest output fits in exactly the buffer size: both parts have
+    // the most digits, a sign and the longest exponent, with a 4-byte unit.
+    let options = Options::builder()
+        .min_significant_digits(num::NonZeroUsize::new(200))
+        .imaginary_unit(Some(b"imag"))
+        .build()
+        .unwrap();
+    let format = NumberFormat::<{ FORMAT }> {};
+    let size = lexical_write_float::complex::buffer_size::<f64, _>(format, &options);
+    let mut buffer = vec![b'\x00'; size];
+    let value = Complex::new(-f64::MIN_POSITIVE, -f64::MIN_POSITIVE);
+    let bytes = value.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert!(bytes.starts_with(b"-2.2250738585072014") && bytes.ends_with(b"e-308imag"));
+    let value = Complex::new(-f64::MIN_POSITIVE, f64::MAX);
+    let bytes = value.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert!(bytes.contains(&b'+') && bytes.ends_with(b"e308imag"));
+
+    let options = options.rebuild().imaginary_unit(None).build().unwrap();
+    let size = lexical_write_float::complex::buffer_size::<f64, _>(format, &options);
+    let mut buffer = vec![b'\x00'; size];
+    let value = Complex::new(-f64::MIN_POSITIVE, -f64::MIN_POSITIVE);
+    let bytes = value.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert!(bytes.starts_with(b"(-2.2250738585072014") && bytes.ends_with(b"e-308)"));
+}
+
+#[test]
+#[should_panic]
+fn write_buffer_test() {
+    let mut buffer = [b'\x00'; 64];
+    Complex::new(1.0, 2.0).to_lexical(&mut buffer);
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "custom-float")]
+
+use lexical_util::num::Float;
+use lexical_write_float::{f8e4m3, f8e5m2, tf32, FormattedSize, ToLexical};
+
+fn write<F: FormattedSize + ToLexical>(float: F) -> String {
+    let mut buffer = vec![b'\x00'; F::FORMATTED_SIZE_DECIMAL];
+    String::from_utf8(float.to_lexical(&mut buffer).to_vec()).unwrap()
+}
+
+/// Check the floats are written with the shortest digits that round-trip.
+///
+/// The digits are parsed as an `f64`, which is exact for the short digits
+/// of the narrow floats, and then rounded to the float.
+fn check_shortest<F>(bits: impl Iterator<Item = u32>)
+where
+    F: Float<Unsigned = u32> + FormattedSize + ToLexical,
+{
+    // Values that round above the largest float do not round-trip, even
+    // if the float saturates to the largest float.
+    let max = F::MAX.as_f64();
+    let limit = max + (max - F::from_bits(F::MAX.to_bits() - 1).as_f64()) / 2.0;
+    let to_bits = |string: &str| {
+        let value = string.parse::<f64>().unwrap();
+        match value.abs() > limit {
+            true => None,
+            false => Some(F::as_cast(value).to_bits()),
+        }
+    };
+    for bits in bits {
+        let float = F::from_bits(bits);
+        let actual = write(float);
+        if float.is_nan() {
+            assert_eq!(actual, "NaN");
+            continue;
+        } else if float.is_inf() {
+            assert_eq!(actual.trim_start_matches('-'), "inf");
+            continue;
+        } else if float == F::ZERO {
+            assert_eq!(actual, "0.0");
+            continue;
+        }
+        assert_eq!(to_bits(&actual), Some(bits), "{} does not round-trip", actual);
+
+        // The largest float of a format without infinity is written exactly.
+        if F::MAX.to_bits() == F::INFINITY_BITS && float.as_f64().abs() == max {
+            assert_eq!(actual.trim_start_matches('-').parse::<f64>(), Ok(max));
+            continue;
+        }
+
+        // No decimal with fewer significant digits rounds to the float.
+        let significand = actual.split('e').next().unwrap().replace(['-', '.'].as_ref(), "");
+        let count = significand.trim_start_matches('0').trim_end_matches('0').len();
+        for precision in 1..count {
+            let nearest = format!("{:.*e}", precision - 1, float.as_f64());
+            let index = nearest.find('e').unwrap();
+            let digits = nearest[..index].replace('.', "").parse::<i64>().unwrap();
+            let exponent = nearest[index + 1..].parse::<i32>().unwrap() - precision as i32 + 1;
+            for candidate in [digits - 1, digits, digits + 1].iter() {
+                let string = format!("{}e{}", candidate, exponent);
+                assert_ne!(to_bits(&string), Some(bits), "{} is not shortest", actual);
+            }
+        }
+    }
+}
+
+#[test]
+fn f8e4m3_write_test() {
+    assert_eq!(write(f8e4m3::from_bits(0x00)), "0.0");
+    assert_eq!(write(f8e4m3::from_bits(0x80)), "0.0");
+    assert_eq!(write(f8e4m3::from_bits(0x38)), "1.0");
+    assert_eq!(write(f8e4m3::from_bits(0x1D)), "0.1");
+    assert_eq!(write(f8e4m3::from_bits(0xC6)), "-3.5");
+    assert_eq!(write(f8e4m3::from_bits(0x01)), "0.002");
+    assert_eq!(write(f8e4m3::from_bits(0x7E)), "448.0");
+    assert_eq!(write(f8e4m3::from_bits(0xFE)), "-448.0");
+    assert_eq!(write(f8e4m3::from_bits(0x7F)), "NaN");
+    assert_eq!(write(f8e4m3::from_bits(0xFF)), "NaN");
+}
+
+#[test]
+fn f8e5m2_write_test() {
+    assert_eq!(write(f8e5m2::from_bits(0x3C)), "1.0");
+    assert_eq!(write(f8e5m2::from_bits(0x2E)), "0.1");
+    assert_eq!(write(f8e5m2::from_bits(0x01)), "0.00002");
+    assert_eq!(write(f8e5m2::from_bits(0x7B)), "60000.0");
+    assert_eq!(write(f8e5m2::from_bits(0x7C)), "inf");
+    assert_eq!(write(f8e5m2::from_bits(0xFC)), "-inf");
+    assert_eq!(write(f8e5m2::from_bits(0x7E)), "NaN");
+}
+
+#[test]
+fn tf32_write_test() {
+    assert_eq!(write(tf32::from_bits(0x1FC00)), "1.0");
+    assert_eq!(write(tf32::from_bits(0x20248)), "3.14");
+    assert_eq!(write(tf32::from_bits(0x3FBFF)), "3.401e38");
+    assert_eq!(write(tf32::from_bits(0x00001)), "1.0e-41");
+    assert_eq!(write(tf32::from_bits(0x3FC00)), "inf");
+}
+
+#[test]
+fn f8e4m3_shortest_test() {
+    check_shortest::<f8e4m3>(0..0x100);
+}
+
+#[test]
+fn f8e5m2_shortest_test() {
+    check_shortest::<f8e5m2>(0..0x100);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn tf32_shortest_test() {
+    check_shortest::<tf32>((0..0x80000).step_by(7));
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "decimal")]
+
+use core::num;
+use lexical_util::format::STANDARD;
+use lexical_write_float::{
+    decimal128,
+    decimal32,
+    decimal64,
+    DecimalFloat,
+    FormattedSize,
+    Options,
+    ToLexical,
+    ToLexicalWithOptions,
+};
+
+fn write<D: DecimalFloat + ToLexicalWithOptions<Options = Options>>(
+    value: D,
+    options: &Options,
+) -> String {
+    let mut buffer = [b'\x00'; decimal128::FORMATTED_SIZE_DECIMAL];
+    let bytes = value.to_lexical_with_options::<{ STANDARD }>(&mut buffer, options);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+fn write_parts(is_negative: bool, coefficient: u128, exponent: i32) -> String {
+    let value = decimal128::from_parts(is_negative, coefficient, exponent).unwrap();
+    write(value, &Options::new())
+}
+
+#[test]
+fn write_quantum_test() {
+    assert_eq!(write_parts(false, 0, 0), "0");
+    assert_eq!(write_parts(true, 0, 0), "-0");
+    assert_eq!(write_parts(false, 1, 0), "1");
+    assert_eq!(write_parts(false, 150, -2), "1.50");
+    assert_eq!(write_parts(true, 15, -1), "-1.5");
+    assert_eq!(write_parts(false, 1200, 0), "1200");
+    assert_eq!(write_parts(false, 12, 2), "1.2e3");
+    assert_eq!(write_parts(false, 1, 3), "1e3");
+    assert_eq!(write_parts(false, 0, 2), "0e2");
+    assert_eq!(write_parts(false, 0, -3), "0.000");
+    assert_eq!(write_parts(false, 150, -5), "0.00150");
+
+    // Plain notation is used while the exponent is at least -6.
+    assert_eq!(write_parts(false, 1, -6), "0.000001");
+    assert_eq!(write_parts(false, 1, -7), "1e-7");
+    assert_eq!(write_parts(false, 10, -8), "1.0e-7");
+    assert_eq!(write_parts(false, 0, -7), "0e-7");
+}
+
+#[test]
+fn write_extremes_test() {
+    let max = decimal128::MAX_COEFFICIENT;
+    assert_eq!(write_parts(false, max, 6111), "9.999999999999999999999999999999999e6144");
+    assert_eq!(write_parts(true, max, -6176), "-9.999999999999999999999999999999999e-6143");
+    assert_eq!(write_parts(false, 1, -6176), "1e-6176");
+    assert_eq!(write(decimal32::from_bits(0x77F8967F), &Options::new()), "9.999999e96");
+    assert_eq!(write(decimal64::from_bits(0x31A0000000000001), &Options::new()), "0.1");
+}
+
+#[test]
+fn special_test() {
+    let mut buffer = [b'\x00'; decimal32::FORMATTED_SIZE_DECIMAL];
+    assert_eq!(decimal32::NAN.to_lexical(&mut buffer), b"NaN");
+    assert_eq!(decimal64::INFINITY.to_lexical(&mut buffer), b"inf");
+    assert_eq!(decimal128::NEG_INFINITY.to_lexical(&mut buffer), b"-inf");
+}
+
+#[test]
+fn normalize_test() {
+    let value = decimal64::from_parts(false, 150, -2).unwrap();
+    assert_eq!(write(value.normalize(), &Options::new()), "1.5");
+    let value = decimal64::from_parts(true, 1200, 0).unwrap();
+    assert_eq!(write(value.normalize(), &Options::new()), "-1.2e3");
+    let value = decimal64::from_parts(false, 0, -5).unwrap();
+    assert_eq!(write(value.normalize(), &Options::new()), "0");
+}
+
+#[test]
+fn options_test() {
+    // The digit options would change the quantum, so they are not used.
+    let value = decimal64::from_parts(false, 31416, -4).unwrap();
+    let options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(2))
+        .decimal_point(b',')
+        .exponent(b'E')
+        .build()
+        .unwrap();
+    assert_eq!(write(value, &options), "3,1416");
+    let value = decimal64::from_parts(false, 31416, 10).unwrap();
+    assert_eq!(write(value, &options), "3,1416E14");
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn non_decimal_format_test() {
+    // Decimal floats are always written in decimal.
+    const BINARY: u128 = lexical_util::format::NumberFormatBuilder::binary();
+    let value = decimal64::from_parts(true, 150, -2).unwrap();
+    let mut buffer = [b'\x00'; decimal64::FORMATTED_SIZE_DECIMAL];
+    let bytes = value.to_lexical_with_options::<{ BINARY }>(&mut buffer, &Options::new());
+    assert_eq!(bytes, b"-1.50");
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "double-double")]
+
+use lexical_write_float::{DoubleDouble, FormattedSize, ToLexical};
+
+fn write(hi: f64, lo: f64) -> String {
+    let mut buffer = vec![b'\x00'; DoubleDouble::FORMATTED_SIZE_DECIMAL];
+    let value = DoubleDouble::new(hi, lo);
+    String::from_utf8(value.to_lexical(&mut buffer).to_vec()).unwrap()
+}
+
+#[test]
+fn write_test() {
+    assert_eq!(write(0.0, 0.0), "0.0");
+    assert_eq!(write(-0.0, 0.0), "0.0");
+    assert_eq!(write(1.0, 0.0), "1.0");
+    assert_eq!(write(0.1, -5.551115123125783e-18), "0.1");
+    assert_eq!(write(-0.1, 5.551115123125783e-18), "-0.1");
+    assert_eq!(write(1.0, 1e-20), "1.00000000000000000001");
+    assert_eq!(write(1e300, -5.250476025520442e283), "1.0e300");
+    assert_eq!(write(1.7976931348623157e308, -8.145274237317043e290), "1.7976931348623157e308");
+    assert_eq!(write(core::f64::consts::PI, 1.2246467991473532e-16), "3.1415926535897932384626433832795");
+    assert_eq!(write(f64::from_bits(1), 0.0), "5.0e-324");
+}
+
+#[test]
+fn write_exact_test() {
+    // The low float of 0 must round-trip, so the digits are exact.
+    assert_eq!(write(0.1, 0.0), "0.1000000000000000055511151231257827021181583404541015625");
+    assert_eq!(write(1.0, f64::EPSILON / 2.0), "1.00000000000000011102230246251565");
+
+    // The digits extend to the smallest denormal.
+    let mut expected = "1.".to_string();
+    expected.push_str(&"0".repeat(323));
+    expected.push('5');
+    assert_eq!(write(1.0, f64::from_bits(1)), expected);
+    let actual = write(2f64.powi(1023), f64::from_bits(1));
+    assert_eq!(actual.len(), 637);
+    assert!(actual.ends_with("0005e307"));
+}
+
+#[test]
+fn write_normalize_test() {
+    // The pair is normalized before writing.
+    assert_eq!(write(1.0, 0.5), "1.5");
+    assert_eq!(write(0.5, 1.0), "1.5");
+    assert_eq!(write(1.0, -1.0), "0.0");
+    assert_eq!(write(-5.551115123125783e-18, 0.1), "0.1");
+}
+
+#[test]
+fn write_special_test() {
+    assert_eq!(write(f64::NAN, 0.0), "NaN");
+    assert_eq!(write(f64::INFINITY, 0.0), "inf");
+    assert_eq!(write(f64::NEG_INFINITY, 1.0), "-inf");
+    assert_eq!(write(f64::MAX, f64::MAX), "inf");
+}
+
+#[cfg(feature = "power-of-two")]
+#[test]
+fn write_binary_test() {
+    use lexical_util::format::NumberFormatBuilder;
+    use lexical_write_float::{Options, ToLexicalWithOptions};
+
+    const BINARY: u128 = NumberFormatBuilder::binary();
+    let options = Options::new();
+    let mut buffer = vec![b'\x00'; DoubleDouble::FORMATTED_SIZE];
+    let mut write = |hi: f64, lo: f64| {
+        let value = DoubleDouble::new(hi, lo);
+        let bytes = value.to_lexical_with_options::<BINARY>(&mut buffer, &options);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    assert_eq!(write(1.0, 0.0), "1.0");
+    assert_eq!(write(1.5, 2f64.powi(-60)), format!("1.1{}1", "0".repeat(58)));
+    let actual = write(2f64.powi(1023), f64::from_bits(1));
+    assert_eq!(actual.len(), 2110);
+    assert!(actual.ends_with("01e1111111111"));
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "f128")]
+
+use core::num;
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+use lexical_write_float::{f128, FormattedSize, Options, ToLexical, ToLexicalWithOptions};
+use proptest::prelude::*;
+
+fn write(f: f128, options: &Options) -> String {
+    let mut buffer = [b'\x00'; f128::FORMATTED_SIZE_DECIMAL];
+    let bytes = f.to_lexical_with_options::<{ STANDARD }>(&mut buffer, options);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+fn write_bits(bits: u128) -> String {
+    write(f128::from_bits(bits), &Options::new())
+}
+
+#[test]
+fn write_shortest_test() {
+    assert_eq!(write_bits(0), "0.0");
+    assert_eq!(write_bits(1 << 127), "0.0");
+    assert_eq!(write_bits(0x3FFF << 112), "1.0");
+    assert_eq!(write_bits((1 << 127) | (0x3FFF << 112)), "-1.0");
+    assert_eq!(write_bits(0x4000 << 112), "2.0");
+    assert_eq!(write_bits(0x3FFB999999999999999999999999999A), "0.1");
+    let below = "0.09999999999999999999999999999999999";
+    assert_eq!(write_bits(0x3FFB9999999999999999999999999999), below);
+    assert_eq!(write_bits(0x7FFEAE596552B8FDED99D037E3D04B75), "1.0e4932");
+    let pi = "3.1415926535897932384626433832795028";
+    assert_eq!(write_bits(0x4000921FB54442D18469898CC51701B8), pi);
+    assert_eq!(write_bits(0x405F8EE90FF6C373E0EE4E3F0AD20000), "1.2345678901234567890123456789e29");
+
+    // Extremes and the boundaries of the subnormals.
+    let max = 0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF;
+    assert_eq!(write_bits(max), "1.189731495357231765085759326628007e4932");
+    assert_eq!(write_bits(1), "6.0e-4966");
+    assert_eq!(write_bits(1 << 112), "3.3621031431120935062626778173217526e-4932");
+    assert_eq!(write_bits((1 << 112) - 1), "3.362103143112093506262677817321752e-4932");
+}
+
+#[test]
+fn special_test() {
+    let mut buffer = [b'\x00'; f128::FORMATTED_SIZE_DECIMAL];
+    assert_eq!(f128::NAN.to_lexical(&mut buffer), b"NaN");
+    assert_eq!(f128::INFINITY.to_lexical(&mut buffer), b"inf");
+    assert_eq!(f128::NEG_INFINITY.to_lexical(&mut buffer), b"-inf");
+}
+
+#[test]
+fn options_test() {
+    let pi = f128::from_bits(0x4000921FB54442D18469898CC51701B8);
+    let options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(5))
+        .build()
+        .unwrap();
+    assert_eq!(write(pi, &options), "3.1416");
+    let options = Options::builder()
+        .min_significant_digits(num::NonZeroUsize::new(40))
+        .build()
+        .unwrap();
+    assert_eq!(write(pi, &options), "3.141592653589793238462643383279502800000");
+    let options = Options::builder().trim_floats(true).build().unwrap();
+    assert_eq!(write(f128::from_bits(0x3FFF << 112), &options), "1");
+
+    // Large values can be written without exponent notation.
+    let large = f128::from_bits(0x405F8EE90FF6C373E0EE4E3F0AD20000);
+    let options =
+        Options::builder().positive_exponent_break(num::NonZeroI32::new(30)).build().unwrap();
+    assert_eq!(write(large, &options), "123456789012345678901234567890.0");
+    let options =
+        Options::builder().negative_exponent_break(num::NonZeroI32::new(-1)).build().unwrap();
+    assert_eq!(write(f128::from_f64(0.5), &options), "0.5");
+    assert_eq!(write(f128::from_f64(0.0625), &options), "6.25e-2");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn no_exponent_test() {
+    use lexical_util::format::NumberFormatBuilder;
+    use lexical_util::options::WriteOptions;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().no_exponent_notation(true).build();
+    let options = Options::new();
+    let mut buffer = vec![b'\x00'; options.buffer_size::<f128, FORMAT>()];
+    let min = f128::from_bits(1);
+    let bytes = min.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(bytes.len(), 4968);
+    assert!(bytes.ends_with(b"06"));
+    let max = f128::from_bits(0x7FFEFFFFFFFFFFFFFFFFFFFFFFFFFFFF);
+    let bytes = max.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(bytes.len(), 4935);
+    assert!(bytes.starts_with(b"1189731495357231765085759326628007000"));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+    use lexical_util::options::WriteOptions;
+
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+    let options = Options::builder().exponent(b'^').build().unwrap();
+    let mut buffer = vec![b'\x00'; options.buffer_size::<f128, BINARY>()];
+    let mut write = |bits: u128, radix: u32| {
+        let f = f128::from_bits(bits);
+        let bytes = match radix {
+            2 => f.to_lexical_with_options::<BINARY>(&mut buffer, &options),
+            _ => f.to_lexical_with_options::<BASE36>(&mut buffer, &options),
+        };
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    assert_eq!(write((0x3FFF << 112) | (1 << 111), 2), "1.1");
+    assert_eq!(write(1, 2), "1.0^-100000001101110");
+    assert_eq!(write(0x40093480000000000000000000000000, 36), "YA.0");
+    assert_eq!(write(0x3FFE << 112, 36), "0.I");
+    assert_eq!(write(1, 36), "9.0^-2GN");
+
+    let options = options.rebuild().max_significant_digits(num::NonZeroUsize::new(4)).build();
+    let options = options.unwrap();
+    let tenth = f128::from_bits(0x3FFB999999999999999999999999999A);
+    let bytes = tenth.to_lexical_with_options::<BINARY>(&mut buffer, &options);
+    assert_eq!(bytes, b"0.0001101");
+    let bytes = tenth.to_lexical_with_options::<BASE36>(&mut buffer, &options);
+    assert_eq!(bytes, b"0.3LLM");
+}
+
+proptest! {
+    #[test]
+    #[cfg(feature = "power-of-two")]
+    #[cfg_attr(miri, ignore)]
+    fn f64_binary_proptest(f in f64::MIN..f64::MAX, max_digits in 1usize..60) {
+        // Binary digits are exact, so they match the f64 digits for any precision.
+        use lexical_util::format::NumberFormatBuilder;
+        use lexical_write_float::RoundMode;
+
+        const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+        let options = Options::builder()
+            .exponent(b'^')
+            .max_significant_digits(num::NonZeroUsize::new(max_digits))
+            .round_mode(RoundMode::Truncate)
+            .build()
+            .unwrap();
+        let mut buffer = [b'\x00'; 512];
+        let expected = f.to_lexical_with_options::<BINARY>(&mut buffer, &options).to_vec();
+        let actual = f128::from_f64(f).to_lexical_with_options::<BINARY>(&mut buffer, &options);
+        prop_assert_eq!(actual, &expected[..]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_proptest(f in f64::MIN..f64::MAX) {
+        // The digits are within half an f128 ulp, so they round to the f64.
+        let actual = write(f128::from_f64(f), &Options::new());
+        prop_assert_eq!(actual.parse::<f64>(), Ok(f));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f128_proptest(bits in u128::MIN..u128::MAX) {
+        let f = f128::from_bits(bits);
+        let actual = write(f, &Options::new());
+        if f.is_nan() {
+            prop_assert_eq!(actual, "NaN");
+        } else if f.is_inf() {
+            prop_assert!(actual.ends_with("inf"));
+        } else {
+            // At most 36 significant digits are required to round-trip.
+            let digits = actual.split('e').next().unwrap().replace('.', "");
+            let count = digits.trim_start_matches(['-', '0'].as_ref()).len();
+            prop_assert!(count <= 36, "{}", actual);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+#![cfg(feature = "f16")]
+
+use fraction::BigUint;
+use lexical_util::bf16::bf16;
+use lexical_util::f16::f16;
+use lexical_util::num::Float;
+use lexical_write_float::{FormattedSize, ToLexical};
+
+// Exact values are scaled by `2^SCALE_2 * 5^SCALE_5`, so every binary
+// value and every decimal string for the 16-bit floats is an integer.
+const SCALE_2: i32 = 160;
+const SCALE_5: i32 = 60;
+
+/// Get the exact value of the float bits as `(mantissa, exponent)`.
+fn to_parts<F: Float>(bits: u16) -> (u64, i32) {
+    let mantissa = bits as u64 & ((1 << F::MANTISSA_SIZE) - 1);
+    let biased = (bits >> F::MANTISSA_SIZE) as i32;
+    if biased == 0 {
+        (mantissa, F::DENORMAL_EXPONENT)
+    } else {
+        (mantissa | (1 << F::MANTISSA_SIZE), biased - F::EXPONENT_BIAS)
+    }
+}
+
+/// Scale `mantissa * 2^exp2 * 5^exp5` to an exact integer.
+fn scaled(mantissa: u64, exp2: i32, exp5: i32) -> BigUint {
+    let mut value = BigUint::from(mantissa) << (exp2 + SCALE_2) as usize;
+    for _ in 0..exp5 + SCALE_5 {
+        value = value * BigUint::from(5u32);
+    }
+    value
+}
+
+/// Scale the halfway point between two binary values.
+fn halfway(x: (u64, i32), y: (u64, i32)) -> BigUint {
+    scaled(x.0, x.1 - 1, 0) + scaled(y.0, y.1 - 1, 0)
+}
+
+/// Parse a decimal string into the significant digits and the exponent.
+fn parse_decimal(string: &str) -> (u64, i32) {
+    let (significand, exponent) = match string.find('e') {
+        Some(index) => (&string[..index], string[index + 1..].parse::<i32>().unwrap()),
+        None => (string, 0),
+    };
+    let (integer, fraction) = match significand.find('.') {
+        Some(index) => (&significand[..index], &significand[index + 1..]),
+        None => (significand, ""),
+    };
+    let digits = format!("{}{}", integer, fraction);
+    (digits.parse::<u64>().unwrap(), exponent - fraction.len() as i32)
+}
+
+/// Get the number of significant digits, ignoring trailing zeros.
+fn digit_count(mut mantissa: u64, mut exponent: i32) -> (usize, u64, i32) {
+    while mantissa != 0 {
+        let (quotient, remainder) = (mantissa / 10, mantissa % 10);
+        if remainder != 0 {
+            break;
+        }
+        mantissa = quotient;
+        exponent += 1;
+    }
+    (mantissa.to_string().len(), mantissa, exponent)
+}
+
+/// Check a positive, finite float is written with the shortest digits.
+///
+/// The written digits must round to the float, and no decimal with fewer
+/// significant digits can round to the float. If another decimal with the
+/// same number of digits is closer to the float and rounds to it, the
+/// written digits are not correctly rounded.
+fn check_shortest<F: Float>(actual: &str, bits: u16) {
+    let parts = to_parts::<F>;
+    let value = parts(bits);
+    let is_even = value.0 & 1 == 0;
+    let lower = halfway(parts(bits - 1), value);
+    let upper = halfway(value, parts(bits + 1));
+    let exact = scaled(value.0, value.1, 0);
+    let round_trips = |digits: u64, exponent: i32, is_closed: bool| {
+        let decimal = scaled(digits, exponent, exponent);
+        match is_closed {
+            true => lower <= decimal && decimal <= upper,
+            false => lower < decimal && decimal < upper,
+        }
+    };
+
+    let (digits, exponent) = parse_decimal(actual);
+    let (count, digits, exponent) = digit_count(digits, exponent);
+    let is_valid = round_trips(digits, exponent, is_even);
+    assert!(is_valid, "{} does not round-trip for {:#X}", actual, bits);
+
+    // Grisu, used for compact builds, excludes the halfway points, so it may
+    // not find shorter digits that are exactly halfway to the next float.
+    let is_closed = is_even && !cfg!(feature = "compact");
+
+    // The f64 value is exact, and is formatted with correct rounding.
+    let float = value.0 as f64 * 2f64.powi(value.1);
+    let nearest = |precision: usize| parse_decimal(&format!("{:.*e}", precision - 1, float));
+
+    // Check the nearest decimals with fewer digits on both sides of the float.
+    for precision in 1..count {
+        let (nearest, exponent) = nearest(precision);
+        for candidate in [nearest - 1, nearest, nearest + 1].iter() {
+            let is_shorter = round_trips(*candidate, exponent, is_closed);
+            assert!(!is_shorter, "{} is not shortest for {:#X}", actual, bits);
+        }
+    }
+
+    // Check the nearest decimal with the same number of digits and exponent.
+    let (nearest, nearest_exponent) = nearest(count);
+    if nearest_exponent == exponent && round_trips(nearest, nearest_exponent, is_closed) {
+        let distance = |z: BigUint| match z > exact {
+            true => z - &exact,
+            false => &exact - z,
+        };
+        let x = distance(scaled(digits, exponent, exponent));
+        let y = distance(scaled(nearest, nearest_exponent, nearest_exponent));
+        assert!(x <= y, "{} is not nearest for {:#X}", actual, bits);
+    }
+}
+
+#[test]
+fn f16_shortest_test() {
+    let mut buffer = [b'\x00'; f16::FORMATTED_SIZE_DECIMAL];
+    let mut write = |bits| {
+        let bytes = f16::from_bits(bits).to_lexical(&mut buffer);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    assert_eq!(write(0x0000), "0.0");
+    assert_eq!(write(0x3C00), "1.0");
+    assert_eq!(write(0x2E66), "0.1");
+    assert_eq!(write(0x2E65), "0.0999");
+    assert_eq!(write(0x4248), "3.14");
+    assert_eq!(write(0x7BFF), "65500.0");
+    assert_eq!(write(0x0001), "6.0e-8");
+    assert_eq!(write(0x0400), "0.00006104");
+    assert_eq!(write(0x7C00), "inf");
+}
+
+#[test]
+fn bf16_shortest_test() {
+    let mut buffer = [b'\x00'; bf16::FORMATTED_SIZE_DECIMAL];
+    let mut write = |bits| {
+        let bytes = bf16::from_bits(bits).to_lexical(&mut buffer);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    assert_eq!(write(0x0000), "0.0");
+    assert_eq!(write(0x3F80), "1.0");
+    assert_eq!(write(0x3DCD), "0.1");
+    assert_eq!(write(0x4049), "3.14");
+    assert_eq!(write(0x7F7F), "3.39e38");
+    assert_eq!(write(0x0001), "1.0e-40");
+    assert_eq!(write(0x0080), "1.18e-38");
+    assert_eq!(write(0xFF80), "-inf");
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn f16_exhaustive_test() {
+    let mut buffer = [b'\x00'; f16::FORMATTED_SIZE_DECIMAL];
+    for bits in 0..=u16::MAX {
+        let float = f16::from_bits(bits);
+        let actual = std::str::from_utf8(float.to_lexical(&mut buffer)).unwrap();
+        let positive = bits & 0x7FFF;
+        if float.is_nan() {
+            assert_eq!(actual, "NaN");
+        } else if float.is_inf() {
+            assert_eq!(actual.trim_start_matches('-'), "inf");
+        } else if positive == 0 {
+            assert_eq!(actual.trim_start_matches('-'), "0.0");
+        } else {
+            assert_eq!(actual.starts_with('-'), bits != positive);
+            let actual = actual.trim_start_matches('-');
+            check_shortest::<f16>(actual, positive);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn bf16_exhaustive_test() {
+    let mut buffer = [b'\x00'; bf16::FORMATTED_SIZE_DECIMAL];
+    for bits in 0..=u16::MAX {
+        let float = bf16::from_bits(bits);
+        let actual = std::str::from_utf8(float.to_lexical(&mut buffer)).unwrap();
+        let positive = bits & 0x7FFF;
+        if float.is_nan() {
+            assert_eq!(actual, "NaN");
+        } else if float.is_inf() {
+            assert_eq!(actual.trim_start_matches('-'), "inf");
+        } else if positive == 0 {
+            assert_eq!(actual.trim_start_matches('-'), "0.0");
+        } else {
+            assert_eq!(actual.starts_with('-'), bits != positive);
+            let actual = actual.trim_start_matches('-');
+            check_shortest::<bf16>(actual, positive);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "f80")]
+
+use core::num;
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+use lexical_write_float::{f80, FormattedSize, Options, ToLexical, ToLexicalWithOptions};
+use proptest::prelude::*;
+
+fn write(f: f80, options: &Options) -> String {
+    let mut buffer = [b'\x00'; f80::FORMATTED_SIZE_DECIMAL];
+    let bytes = f.to_lexical_with_options::<{ STANDARD }>(&mut buffer, options);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+fn write_bits(bits: u128) -> String {
+    write(f80::from_bits(bits), &Options::new())
+}
+
+#[test]
+fn write_shortest_test() {
+    assert_eq!(write_bits(0), "0.0");
+    assert_eq!(write_bits(0x3FFF8000000000000000), "1.0");
+    assert_eq!(write_bits(0xBFFF8000000000000000), "-1.0");
+    assert_eq!(write_bits(0x3FFBCCCCCCCCCCCCCCCD), "0.1");
+    assert_eq!(write_bits(0x3FFBCCCCCCCCCCCCCCCC), "0.099999999999999999995");
+    assert_eq!(write_bits(0x4000C90FDAA22168C235), "3.1415926535897932385");
+    assert_eq!(write_bits(0x405FC77487FB61B9F077), "1.234567890123456789e29");
+    assert_eq!(write_bits(0x7FFED72CB2A95C7EF6CD), "1.0e4932");
+
+    // Extremes and the boundaries of the subnormals.
+    assert_eq!(write_bits(0x7FFEFFFFFFFFFFFFFFFF), "1.189731495357231765e4932");
+    assert_eq!(write_bits(1), "4.0e-4951");
+    assert_eq!(write_bits(0x00018000000000000000), "3.3621031431120935063e-4932");
+    assert_eq!(write_bits(0x00007FFFFFFFFFFFFFFF), "3.362103143112093506e-4932");
+}
+
+#[test]
+fn write_noncanonical_test() {
+    // Pseudo-denormals and unnormals are written using the explicit integer bit.
+    assert_eq!(write_bits(0x00008000000000000000), "3.3621031431120935063e-4932");
+    assert_eq!(write_bits(0x3FFF4000000000000000), "0.5");
+}
+
+#[test]
+fn special_test() {
+    let mut buffer = [b'\x00'; f80::FORMATTED_SIZE_DECIMAL];
+    assert_eq!(f80::NAN.to_lexical(&mut buffer), b"NaN");
+    assert_eq!(f80::INFINITY.to_lexical(&mut buffer), b"inf");
+    assert_eq!(f80::NEG_INFINITY.to_lexical(&mut buffer), b"-inf");
+}
+
+#[test]
+fn options_test() {
+    let pi = f80::from_bits(0x4000C90FDAA22168C235);
+    let options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(5))
+        .build()
+        .unwrap();
+    assert_eq!(write(pi, &options), "3.1416");
+    let options = Options::builder()
+        .min_significant_digits(num::NonZeroUsize::new(25))
+        .build()
+        .unwrap();
+    assert_eq!(write(pi, &options), "3.141592653589793238500000");
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_proptest(f in f64::MIN..f64::MAX) {
+        // The digits are within half an f80 ulp, so they round to the f64.
+        let actual = write(f80::from_f64(f), &Options::new());
+        prop_assert_eq!(actual.parse::<f64>(), Ok(f));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f80_proptest(bits in 0u128..(1 << 80)) {
+        let f = f80::from_bits(bits);
+        let actual = write(f, &Options::new());
+        if f.is_nan() {
+            prop_assert_eq!(actual, "NaN");
+        } else if f.is_inf() {
+            prop_assert!(actual.ends_with("inf"));
+        } else {
+            // At most 21 significant digits are required to round-trip.
+            let digits = actual.split('e').next().unwrap().replace('.', "");
+            let count = digits.trim_start_matches(['-', '0'].as_ref()).len();
+            prop_assert!(count <= 21, "{}", actual);
+        }
+    }
+}
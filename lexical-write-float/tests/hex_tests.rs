@@ -2,7 +2,7 @@
 
 use core::num;
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
-use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{NumberFormat, NumberFormatBuilder};
 use lexical_util::num::{Float, Integer};
 use lexical_write_float::{binary, hex, Options};
 use lexical_write_integer::write::WriteInteger;
@@ -50,7 +50,8 @@ where
     }
 
     let count = unsafe {
-        hex::write_float_scientific::<_, FORMAT>(mantissa, exp, sci_exp, &mut buffer, options)
+        let format = NumberFormat::<{ FORMAT }> {};
+        hex::write_float_scientific(mantissa, exp, sci_exp, &mut buffer, format, options)
     };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
@@ -388,7 +389,7 @@ where
     <T as Float>::Unsigned: WriteInteger + FormattedSize,
 {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
-    let count = unsafe { hex::write_float::<_, FORMAT>(f, &mut buffer, options) };
+    let count = unsafe { hex::write_float(f, &mut buffer, NumberFormat::<{ FORMAT }> {}, options) };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -61,6 +61,28 @@ fn invalid_inf_test() {
     assert!(builder.is_valid());
 }
 
+#[test]
+fn invalid_imaginary_unit_test() {
+    let mut builder = OptionsBuilder::default();
+    assert_eq!(builder.get_imaginary_unit(), Some("i".as_bytes()));
+    builder = builder.imaginary_unit(Some(b"imag0"));
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_unit(Some(b"i0"));
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_unit(Some(b"E"));
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_unit(Some(b""));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.imaginary_unit(Some(b"im"));
+    assert!(builder.is_valid());
+    builder = builder.imaginary_unit(Some(b"j"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+    builder = builder.imaginary_unit(None);
+    assert!(builder.is_valid());
+}
+
 #[test]
 fn builder_test() {
     let mut builder = OptionsBuilder::default();
@@ -5,7 +5,7 @@ mod parse_radix;
 use approx::{assert_relative_eq, relative_eq};
 use core::num;
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
-use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{NumberFormat, NumberFormatBuilder};
 use lexical_util::num::Float;
 use lexical_write_float::options::RoundMode;
 use lexical_write_float::{radix, Options};
@@ -92,7 +92,8 @@ where
     <T as Float>::Unsigned: WriteInteger + FormattedSize,
 {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
-    let count = unsafe { radix::write_float::<_, FORMAT>(f, &mut buffer, options) };
+    let format = NumberFormat::<{ FORMAT }> {};
+    let count = unsafe { radix::write_float(f, &mut buffer, format, options) };
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -240,7 +241,8 @@ macro_rules! test_radix {
         } else {
             $options.clone()
         };
-        let count = unsafe { radix::write_float::<_, FORMAT>($f, &mut $buffer, &options) };
+        let format = NumberFormat::<{ FORMAT }> {};
+        let count = unsafe { radix::write_float($f, &mut $buffer, format, &options) };
         let roundtrip = $parse(&$buffer[..count], $radix, options.exponent());
         assert_relative_eq!($f, roundtrip, epsilon = 1e-6, max_relative = 3e-6);
     }};
@@ -305,12 +307,14 @@ fn base21_test() {
     let mut buffer = [b'\x00'; 512];
     let options = Options::builder().exponent(b'^').build().unwrap();
     let f = 2879632400000000000000000.0f32;
-    let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+    let format = NumberFormat::<{ BASE21 }> {};
+    let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
     let roundtrip = parse_f32(&buffer[..count], 21, b'^');
     assert_relative_eq!(f, roundtrip, epsilon = 1e-5, max_relative = 1e-5);
 
     let f = 48205284000000000000000000000000000000.0f32;
-    let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+    let format = NumberFormat::<{ BASE21 }> {};
+    let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
     let roundtrip = parse_f32(&buffer[..count], 21, b'^');
     assert_relative_eq!(f, roundtrip, epsilon = 1e-5, max_relative = 1e-5);
 
@@ -320,17 +324,20 @@ fn base21_test() {
         .build()
         .unwrap();
     let f = 105861640000000000000000000000000000000.0f32;
-    let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+    let format = NumberFormat::<{ BASE21 }> {};
+    let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
     let roundtrip = parse_f32(&buffer[..count], 21, b'^');
     assert_relative_eq!(f, roundtrip, epsilon = 1e-1, max_relative = 1e-1);
 
     let f = 63900220000000000000000000000000000000.0f32;
-    let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+    let format = NumberFormat::<{ BASE21 }> {};
+    let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
     let roundtrip = parse_f32(&buffer[..count], 21, b'^');
     assert_relative_eq!(f, roundtrip, epsilon = 1e-1, max_relative = 1e-1);
 
     let f = 48205284000000000000000000000000000000.0f32;
-    let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+    let format = NumberFormat::<{ BASE21 }> {};
+    let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
     assert_eq!(b"4.C44^17", &buffer[..count]);
 
     let options = Options::builder()
@@ -340,7 +347,8 @@ fn base21_test() {
         .build()
         .unwrap();
     let f = 48205284000000000000000000000000000000.0f32;
-    let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+    let format = NumberFormat::<{ BASE21 }> {};
+    let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
     assert_eq!(b"4C440700000000000000000000000.0", &buffer[..count]);
 }
 
@@ -370,7 +378,8 @@ quickcheck! {
             true
         } else {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 3, b'e');
             relative_eq!(f, roundtrip, epsilon=1e-6, max_relative=1e-6)
         }
@@ -384,7 +393,8 @@ quickcheck! {
             true
         } else {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 5, b'e');
             relative_eq!(f, roundtrip, epsilon=1e-6, max_relative=1e-6)
         }
@@ -398,7 +408,8 @@ quickcheck! {
             true
         } else {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 21, b'^');
             relative_eq!(f, roundtrip, epsilon=1e-5, max_relative=1e-5)
         }
@@ -412,7 +423,8 @@ quickcheck! {
             true
         } else {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 3, b'e');
             relative_eq!(f, roundtrip, epsilon=1e-6, max_relative=1e-6)
         }
@@ -426,7 +438,8 @@ quickcheck! {
             true
         } else {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 5, b'e');
             relative_eq!(f, roundtrip, epsilon=1e-6, max_relative=1e-6)
         }
@@ -440,7 +453,8 @@ quickcheck! {
             true
         } else {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 21, b'^');
             relative_eq!(f, roundtrip, epsilon=1e-6, max_relative=1e-6)
         }
@@ -455,7 +469,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 3, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-6, max_relative=1e-6);
             prop_assert!(equal)
@@ -469,7 +484,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 5, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-6, max_relative=1e-6);
             prop_assert!(equal)
@@ -483,7 +499,8 @@ proptest! {
         let options = Options::builder().exponent(b'^').build().unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 21, b'^');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-5, max_relative=1e-5);
             prop_assert!(equal)
@@ -500,7 +517,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 3, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -517,7 +535,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 5, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -535,7 +554,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 21, b'^');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -552,7 +572,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 3, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -569,7 +590,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 5, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -587,7 +609,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 21, b'^');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -606,7 +629,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 3, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -625,7 +649,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 5, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -645,7 +670,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 21, b'^');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -664,7 +690,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 3, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -683,7 +710,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 5, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -703,7 +731,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f32 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f32(&buffer[..count], 21, b'^');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -717,7 +746,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 3, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-6, max_relative=1e-6);
             prop_assert!(equal)
@@ -731,7 +761,8 @@ proptest! {
         let options = Options::builder().build().unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 5, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-6, max_relative=1e-6);
             prop_assert!(equal)
@@ -745,7 +776,8 @@ proptest! {
         let options = Options::builder().exponent(b'^').build().unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 21, b'^');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-6, max_relative=1e-6);
             prop_assert!(equal)
@@ -762,7 +794,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 3, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -779,7 +812,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 5, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -797,7 +831,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 21, b'^');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -814,7 +849,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 3, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -831,7 +867,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 5, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -849,7 +886,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 21, b'^');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -868,7 +906,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 3, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -887,7 +926,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 5, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -907,7 +947,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 21, b'^');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -926,7 +967,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE3>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE3 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 3, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -945,7 +987,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE5>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE5 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 5, b'e');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -965,7 +1008,8 @@ proptest! {
             .unwrap();
         if !(is_overflow!(@f64 f)) {
             let f = f.abs();
-            let count = unsafe { radix::write_float::<_, BASE21>(f, &mut buffer, &options) };
+            let format = NumberFormat::<{ BASE21 }> {};
+            let count = unsafe { radix::write_float(f, &mut buffer, format, &options) };
             let roundtrip = parse_f64(&buffer[..count], 21, b'^');
             let equal = relative_eq!(f, roundtrip, epsilon=1e-1, max_relative=1e-1);
             prop_assert!(equal)
@@ -0,0 +1,48 @@
+#![cfg(feature = "rational")]
+
+use lexical_util::format::STANDARD;
+use lexical_write_float::{write_rational, Rational};
+
+fn write(value: &Rational) -> String {
+    String::from_utf8(write_rational::<STANDARD>(value)).unwrap()
+}
+
+#[test]
+fn write_test() {
+    assert_eq!(write(&Rational::new(false, vec![6], vec![8])), "3/4");
+    assert_eq!(write(&Rational::new(true, vec![3], vec![4])), "-3/4");
+    assert_eq!(write(&Rational::new(true, vec![10], vec![5])), "-2");
+    assert_eq!(write(&Rational::zero()), "0");
+    assert_eq!(write(&Rational::new(false, vec![0, 2], vec![3])), "36893488147419103232/3");
+}
+
+#[test]
+fn write_float_test() {
+    let value = Rational::from_float(0.1f64).unwrap();
+    assert_eq!(write(&value), "3602879701896397/36028797018963968");
+    assert_eq!(write(&value.limit_denominator(1000)), "1/10");
+    let value = Rational::from_float(-core::f64::consts::PI).unwrap();
+    assert_eq!(write(&value), "-884279719003555/281474976710656");
+    assert_eq!(write(&value.limit_denominator(1000)), "-355/113");
+    assert_eq!(write(&Rational::from_float(1e20f64).unwrap()), "100000000000000000000");
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn write_radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BINARY: u128 = NumberFormatBuilder::binary();
+    let value = Rational::from_float(0.375f64).unwrap();
+    assert_eq!(write_rational::<BINARY>(&value), b"11/1000");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn write_sign_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    assert_eq!(write_rational::<FORMAT>(&Rational::new(false, vec![1], vec![2])), b"+1/2");
+    assert_eq!(write_rational::<FORMAT>(&Rational::new(true, vec![1], vec![2])), b"-1/2");
+}
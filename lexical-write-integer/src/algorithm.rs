@@ -13,7 +13,6 @@
 use lexical_util::assert::debug_assert_radix;
 use lexical_util::digit::digit_to_char;
 use lexical_util::div128::u128_divrem;
-use lexical_util::format::{radix_from_flags, NumberFormat};
 use lexical_util::num::{AsCast, UnsignedInteger};
 use lexical_util::step::u64_step;
 
@@ -183,20 +182,20 @@ where
 /// Safe as long as the buffer is large enough to hold as many digits
 /// that can be in the largest value of `T`, in radix `N`.
 #[inline]
-pub unsafe fn algorithm_u128<const FORMAT: u128, const MASK: u128, const SHIFT: i32>(
+pub unsafe fn algorithm_u128(
     value: u128,
+    radix: u32,
     table: &[u8],
     buffer: &mut [u8],
 ) -> usize {
     //  NOTE:
-    //      Use the const version of radix for u64_step and u128_divrem
-    //      to ensure they're evaluated at compile time.
-    assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
+    //      Once inlined, a constant radix lets u64_step and u128_divrem
+    //      be evaluated at compile time.
+    debug_assert_radix(radix);
 
     // Quick approximations to make the algorithm **a lot** faster.
     // If the value can be represented in a 64-bit integer, we can
     // do this as a native integer.
-    let radix = radix_from_flags(FORMAT, MASK, SHIFT);
     if value <= u64::MAX as _ {
         // SAFETY: safe if the buffer is large enough to hold the significant digits.
         return unsafe { algorithm(value as u64, radix, table, buffer) };
@@ -215,8 +214,8 @@ pub unsafe fn algorithm_u128<const FORMAT: u128, const MASK: u128, const SHIFT:
     // To deal with internal 0 values or values with internal 0 digits set,
     // we store the starting index, and if not all digits are written,
     // we just skip down `digits` digits for the next value.
-    let step = u64_step(radix_from_flags(FORMAT, MASK, SHIFT));
-    let (value, low) = u128_divrem(value, radix_from_flags(FORMAT, MASK, SHIFT));
+    let step = u64_step(radix);
+    let (value, low) = u128_divrem(value, radix);
     let mut index = buffer.len();
     index = unsafe { write_step_digits(low, radix, table, buffer, index, step) };
     if value <= u64::MAX as _ {
@@ -224,7 +223,7 @@ pub unsafe fn algorithm_u128<const FORMAT: u128, const MASK: u128, const SHIFT:
     }
 
     // Value has to be greater than 1.8e38
-    let (value, mid) = u128_divrem(value, radix_from_flags(FORMAT, MASK, SHIFT));
+    let (value, mid) = u128_divrem(value, radix);
     index = unsafe { write_step_digits(mid, radix, table, buffer, index, step) };
     if index != 0 {
         index = unsafe { write_digits(value as u64, radix, table, buffer, index) };
@@ -5,9 +5,11 @@
 use crate::options::Options;
 use crate::write::WriteInteger;
 use lexical_util::assert::{assert_buffer, debug_assert_buffer};
-use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::format::{Format, NumberFormat, STANDARD};
 use lexical_util::num::SignedInteger;
-use lexical_util::{to_lexical, to_lexical_with_options};
+use lexical_util::{to_lexical, to_lexical_with_format, to_lexical_with_options};
+
+const STANDARD_FORMAT: NumberFormat<STANDARD> = NumberFormat::<{ STANDARD }> {};
 
 // UNSIGNED
 
@@ -18,22 +20,21 @@ use lexical_util::{to_lexical, to_lexical_with_options};
 /// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
 /// (or `FORMATTED_SIZE_DECIMAL` for decimal).
 #[inline]
-unsafe fn unsigned<Narrow, Wide, const FORMAT: u128>(value: Narrow, buffer: &mut [u8]) -> usize
+unsafe fn unsigned<Narrow, Wide, F: Format>(value: Narrow, buffer: &mut [u8], format: F) -> usize
 where
     Narrow: WriteInteger,
     Wide: WriteInteger,
 {
-    let format = NumberFormat::<FORMAT> {};
     if cfg!(feature = "format") && format.required_mantissa_sign() {
         // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
         unsafe {
             index_unchecked_mut!(buffer[0]) = b'+';
             let buffer = &mut index_unchecked_mut!(buffer[1..]);
-            value.write_mantissa::<Wide, FORMAT>(buffer) + 1
+            value.write_mantissa::<Wide, _>(buffer, format) + 1
         }
     } else {
         // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
-        unsafe { value.write_mantissa::<Wide, FORMAT>(buffer) }
+        unsafe { value.write_mantissa::<Wide, _>(buffer, format) }
     }
 }
 
@@ -46,16 +47,16 @@ where
 /// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
 /// (or `FORMATTED_SIZE_DECIMAL` for decimal).
 #[inline]
-unsafe fn signed<Narrow, Wide, Unsigned, const FORMAT: u128>(
+unsafe fn signed<Narrow, Wide, Unsigned, F: Format>(
     value: Narrow,
     buffer: &mut [u8],
+    format: F,
 ) -> usize
 where
     Narrow: SignedInteger,
     Wide: SignedInteger,
     Unsigned: WriteInteger,
 {
-    let format = NumberFormat::<FORMAT> {};
     if value < Narrow::ZERO {
         // Need to cast the value to the same size as unsigned type, since if
         // the value is **exactly** `Narrow::MIN`, and it it is then cast
@@ -67,7 +68,7 @@ where
         unsafe {
             index_unchecked_mut!(buffer[0]) = b'-';
             let buffer = &mut index_unchecked_mut!(buffer[1..]);
-            unsigned.write_mantissa::<Unsigned, FORMAT>(buffer) + 1
+            unsigned.write_mantissa::<Unsigned, _>(buffer, format) + 1
         }
     } else if cfg!(feature = "format") && format.required_mantissa_sign() {
         let unsigned = Unsigned::as_cast(value);
@@ -75,12 +76,12 @@ where
         unsafe {
             index_unchecked_mut!(buffer[0]) = b'+';
             let buffer = &mut index_unchecked_mut!(buffer[1..]);
-            unsigned.write_mantissa::<Unsigned, FORMAT>(buffer) + 1
+            unsigned.write_mantissa::<Unsigned, _>(buffer, format) + 1
         }
     } else {
         let unsigned = Unsigned::as_cast(value);
         // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
-        unsafe { unsigned.write_mantissa::<Unsigned, FORMAT>(buffer) }
+        unsafe { unsigned.write_mantissa::<Unsigned, _>(buffer, format) }
     }
 }
 
@@ -97,7 +98,7 @@ macro_rules! unsigned_to_lexical {
                 debug_assert_buffer::<$narrow>(10, bytes.len());
                 // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
                 unsafe {
-                    let len = unsigned::<$narrow, $wide, { STANDARD }>(self, bytes);
+                    let len = unsigned::<$narrow, $wide, _>(self, bytes, STANDARD_FORMAT);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
@@ -119,29 +120,55 @@ macro_rules! unsigned_to_lexical {
             unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
                 self,
                 bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE`.
+                unsafe { self.to_lexical_with_format_unchecked(bytes, format, options) }
+            }
+
+            $(#[$meta:meta])?
+            fn to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                self.to_lexical_with_format(bytes, NumberFormat::<{ FORMAT }> {}, options)
+            }
+        }
+
+        impl ToLexicalWithFormat for $narrow {
+            $(#[$meta:meta])?
+            unsafe fn to_lexical_with_format_unchecked<'a, F: Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: F,
                 _: &Self::Options,
             ) -> &'a mut [u8]
             {
-                debug_assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
-                assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
+                debug_assert_buffer::<$narrow>(format.radix(), bytes.len());
+                assert!(format.is_valid());
                 // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE`.
                 unsafe {
-                    let len = unsigned::<$narrow, $wide, FORMAT>(self, bytes);
+                    let len = unsigned::<$narrow, $wide, _>(self, bytes, format);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
 
             $(#[$meta:meta])?
-            fn to_lexical_with_options<'a, const FORMAT: u128>(
+            fn to_lexical_with_format<'a, F: Format>(
                 self,
                 bytes: &'a mut [u8],
+                format: F,
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
-                assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
+                assert_buffer::<$narrow>(format.radix(), bytes.len());
+                assert!(format.is_valid());
                 // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE`.
-                unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
+                unsafe { self.to_lexical_with_format_unchecked(bytes, format, options) }
             }
         }
     )*)
@@ -149,6 +176,7 @@ macro_rules! unsigned_to_lexical {
 
 to_lexical! {}
 to_lexical_with_options! {}
+to_lexical_with_format! {}
 unsigned_to_lexical! {
     u8 u32 ;
     u16 u32 ;
@@ -174,7 +202,7 @@ macro_rules! signed_to_lexical {
                 debug_assert_buffer::<$narrow>(10, bytes.len());
                 // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
                 unsafe {
-                    let len = signed::<$narrow, $wide, $unsigned, { STANDARD }>(self, bytes);
+                    let len = signed::<$narrow, $wide, $unsigned, _>(self, bytes, STANDARD_FORMAT);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
@@ -196,29 +224,55 @@ macro_rules! signed_to_lexical {
             unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
                 self,
                 bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE`.
+                unsafe { self.to_lexical_with_format_unchecked(bytes, format, options) }
+            }
+
+            $(#[$meta:meta])?
+            fn to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                self.to_lexical_with_format(bytes, NumberFormat::<{ FORMAT }> {}, options)
+            }
+        }
+
+        impl ToLexicalWithFormat for $narrow {
+            $(#[$meta:meta])?
+            unsafe fn to_lexical_with_format_unchecked<'a, F: Format>(
+                self,
+                bytes: &'a mut [u8],
+                format: F,
                 _: &Self::Options,
             ) -> &'a mut [u8]
             {
-                debug_assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
-                assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
+                debug_assert_buffer::<$narrow>(format.radix(), bytes.len());
+                assert!(format.is_valid());
                 // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE`.
                 unsafe {
-                    let len = signed::<$narrow, $wide, $unsigned, FORMAT>(self, bytes);
+                    let len = signed::<$narrow, $wide, $unsigned, _>(self, bytes, format);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
 
             $(#[$meta:meta])?
-            fn to_lexical_with_options<'a, const FORMAT: u128>(
+            fn to_lexical_with_format<'a, F: Format>(
                 self,
                 bytes: &'a mut [u8],
+                format: F,
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
-                assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
+                assert_buffer::<$narrow>(format.radix(), bytes.len());
+                assert!(format.is_valid());
                 // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE`.
-                unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
+                unsafe { self.to_lexical_with_format_unchecked(bytes, format, options) }
             }
         }
     )*)
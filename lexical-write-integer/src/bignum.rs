@@ -0,0 +1,261 @@
+//! Write little-endian `u64` limbs as very long digit strings.
+//!
+//! These writers convert unsigned values of any length, stored as limbs
+//! from least to most significant, to a string of digits in the radix,
+//! without a sign or any other number syntax.
+//!
+//! All buffers are provided by the caller, so no allocation is required:
+//! [`digits_len`] and [`scratch_len`] give the size of the buffers for a
+//! given number of limbs. Power-of-two radixes are written in linear time,
+//! and other radixes use a subquadratic divide-and-conquer algorithm for
+//! large inputs, which divides the value by a precomputed power of the
+//! radix and writes the quotient and remainder recursively.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_write_integer::bignum::{digits_len, scratch_len, write_limbs};
+//!
+//! # pub fn main() {
+//! let limbs = [0, 0, 1];
+//! let mut buffer = vec![0u8; digits_len(limbs.len(), 10)];
+//! let mut scratch = vec![0u64; scratch_len(limbs.len(), 10)];
+//! let digits = write_limbs(&limbs, 10, &mut buffer, &mut scratch);
+//! assert_eq!(digits, b"340282366920938463463374607431768211456");
+//! # }
+//! ```
+
+use lexical_util::bignum;
+use lexical_util::digit::digit_to_char_const;
+use lexical_util::format::is_valid_radix;
+
+/// The number of limbs below which the schoolbook algorithm is used.
+const BASE_LIMBS: usize = 32;
+
+/// Get the index of the smallest power `base^(2^t)` whose square is larger than any value
+/// with `limbs` limbs.
+///
+/// The power has at least `log2(base) * 2^t` bits.
+#[inline]
+const fn top_level(limbs: usize, radix: u32) -> usize {
+    let (base, _) = bignum::max_power(radix);
+    let bits = 63 - base.leading_zeros() as usize;
+    let mut level = 0;
+    while (bits << (level + 1)) < 64 * limbs {
+        level += 1;
+    }
+    level
+}
+
+/// Get the number of bytes required to write a value with the given number of limbs.
+///
+/// This is an upper bound, and the value may require fewer digits. The
+/// radix must be valid.
+#[inline]
+pub const fn digits_len(limbs: usize, radix: u32) -> usize {
+    let digits = if radix.is_power_of_two() {
+        let log2 = radix.trailing_zeros() as usize;
+        match 64 * limbs % log2 {
+            0 => 64 * limbs / log2,
+            _ => 64 * limbs / log2 + 1,
+        }
+    } else {
+        let (_, step) = bignum::max_power(radix);
+        limbs * (step + 1)
+    };
+    if digits == 0 {
+        1
+    } else {
+        digits
+    }
+}
+
+/// Get the number of scratch limbs required to write a value with the given number of limbs.
+///
+/// This is an upper bound, which increases with the number of limbs. The
+/// radix must be valid.
+pub const fn scratch_len(limbs: usize, radix: u32) -> usize {
+    if radix.is_power_of_two() {
+        return 0;
+    } else if limbs <= BASE_LIMBS {
+        return limbs;
+    }
+
+    // Value, powers table, and the scratch to create the powers or recurse.
+    let level = top_level(limbs, radix);
+    let mut recurse = 0;
+    let mut j = 0;
+    while j <= level {
+        if (1 << (j + 1)) > BASE_LIMBS {
+            let div = bignum::divrem_scratch_len(1 << j);
+            let inner = if recurse > div {
+                recurse
+            } else {
+                div
+            };
+            recurse = (1 << j) + inner;
+        }
+        j += 1;
+    }
+    let square = bignum::mul_scratch_len(1 << level, 1 << level);
+    let inner = if recurse > square {
+        recurse
+    } else {
+        square
+    };
+    limbs + bignum::powers_len(level) + inner
+}
+
+/// Write the value using the schoolbook algorithm, and return the number of digits.
+///
+/// If `width` is provided, exactly `width` digits are written, padded
+/// with leading zeros. Otherwise, the digits are written without leading
+/// zeros, so 0 writes no digits. The value is overwritten.
+fn write_base(x: &mut [u64], radix: u32, width: Option<usize>, out: &mut [u8]) -> usize {
+    let (base, step) = bignum::max_power(radix);
+    let mut len = bignum::normalized_len(x);
+    let mut index = width.unwrap_or(out.len());
+    let end = index;
+
+    // Write the digits from the end, in chunks that fit in a `u64`.
+    while len != 0 {
+        let mut chunk = bignum::divrem_small(&mut x[..len], base);
+        len = bignum::normalized_len(&x[..len]);
+        let mut digits = 0;
+        while digits < step && (len != 0 || chunk != 0) {
+            index -= 1;
+            out[index] = digit_to_char_const((chunk % radix as u64) as u32, radix);
+            chunk /= radix as u64;
+            digits += 1;
+        }
+    }
+
+    match width {
+        Some(width) => {
+            for digit in out[..index].iter_mut() {
+                *digit = b'0';
+            }
+            width
+        },
+        None => {
+            out.copy_within(index..end, 0);
+            end - index
+        },
+    }
+}
+
+/// Write a value less than `base^(2^(level + 1))`, and return the number of digits.
+///
+/// The value is divided by `base^(2^level)`, and the quotient and the
+/// remainder, padded to `step * 2^level` digits, are written recursively.
+/// If `padded`, exactly `step * 2^(level + 1)` digits are written. The
+/// value is overwritten.
+fn write_level(
+    x: &mut [u64],
+    radix: u32,
+    level: usize,
+    padded: bool,
+    out: &mut [u8],
+    powers: &[u64],
+    scratch: &mut [u64],
+) -> usize {
+    let size = 1 << level;
+    let (_, step) = bignum::max_power(radix);
+    if 2 * size <= BASE_LIMBS {
+        let width = if padded {
+            Some(2 * size * step)
+        } else {
+            None
+        };
+        return write_base(x, radix, width, out);
+    }
+
+    let power = bignum::power(powers, level);
+    if !padded && bignum::compare(x, power) == core::cmp::Ordering::Less {
+        return write_level(x, radix, level - 1, false, out, powers, scratch);
+    }
+    let (q, scratch) = scratch.split_at_mut(size);
+    bignum::divrem(x, power, q, scratch);
+    let count = write_level(q, radix, level - 1, padded, out, powers, scratch);
+    count + write_level(x, radix, level - 1, true, &mut out[count..], powers, scratch)
+}
+
+/// Write digits in a power-of-two radix by extracting the bits of each digit.
+fn write_power_of_two(limbs: &[u64], radix: u32, bytes: &mut [u8]) -> usize {
+    let log2 = radix.trailing_zeros() as usize;
+    let mask = radix as u64 - 1;
+    let bits = 64 * limbs.len() - limbs[limbs.len() - 1].leading_zeros() as usize;
+    let count = match bits % log2 {
+        0 => bits / log2,
+        _ => bits / log2 + 1,
+    };
+    for (index, byte) in bytes[..count].iter_mut().rev().enumerate() {
+        let bit = index * log2;
+        let shift = bit % 64;
+        let mut digit = limbs[bit / 64] >> shift;
+        if shift + log2 > 64 && bit / 64 + 1 < limbs.len() {
+            digit |= limbs[bit / 64 + 1] << (64 - shift);
+        }
+        *byte = digit_to_char_const((digit & mask) as u32, radix);
+    }
+    count
+}
+
+/// Write little-endian `u64` limbs as a string of digits.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The
+/// digits do not have leading zeros, and 0 is written as `0`. `bytes`
+/// must be large enough to store the digits, which is guaranteed if it
+/// has at least [`digits_len`] bytes, and `scratch` must have at least
+/// [`scratch_len`] limbs.
+///
+/// # Panics
+///
+/// Panics if the radix is not valid for the enabled features, or if
+/// either buffer is too small.
+pub fn write_limbs<'a>(
+    limbs: &[u64],
+    radix: u32,
+    bytes: &'a mut [u8],
+    scratch: &mut [u64],
+) -> &'a mut [u8] {
+    assert!(is_valid_radix(radix));
+    let limbs = &limbs[..bignum::normalized_len(limbs)];
+    let count = if limbs.is_empty() {
+        bytes[0] = b'0';
+        1
+    } else if radix.is_power_of_two() {
+        write_power_of_two(limbs, radix, bytes)
+    } else if limbs.len() <= BASE_LIMBS {
+        let (x, _) = scratch.split_at_mut(limbs.len());
+        x.copy_from_slice(limbs);
+        write_base(x, radix, None, bytes)
+    } else {
+        let level = top_level(limbs.len(), radix);
+        let (x, scratch) = scratch.split_at_mut(limbs.len());
+        let (powers, scratch) = scratch.split_at_mut(bignum::powers_len(level));
+        let (base, _) = bignum::max_power(radix);
+        bignum::powers(base, level, powers, scratch);
+        x.copy_from_slice(limbs);
+        write_level(x, radix, level, false, bytes, powers, scratch)
+    };
+    &mut bytes[..count]
+}
+
+/// Write little-endian `u64` limbs as a string of digits, allocating the buffers.
+///
+/// See [`write_limbs`] for the format of the digits.
+///
+/// # Panics
+///
+/// Panics if the radix is not valid for the enabled features.
+#[cfg(feature = "std")]
+pub fn write_limbs_vec(limbs: &[u64], radix: u32) -> Vec<u8> {
+    assert!(is_valid_radix(radix));
+    let mut bytes = vec![0; digits_len(limbs.len(), radix)];
+    let mut scratch = vec![0; scratch_len(limbs.len(), radix)];
+    let count = write_limbs(limbs, radix, &mut bytes, &mut scratch).len();
+    bytes.truncate(count);
+    bytes
+}
@@ -15,7 +15,6 @@
 
 use crate::algorithm::{algorithm, algorithm_u128};
 use crate::table::DIGIT_TO_BASE10_SQUARED;
-use lexical_util::format::{RADIX, RADIX_SHIFT, STANDARD};
 use lexical_util::num::UnsignedInteger;
 
 /// Fast integral log2.
@@ -279,11 +278,7 @@ impl Decimal for u128 {
         let count = self.digit_count();
         debug_assert!(count <= buffer.len());
         unsafe {
-            algorithm_u128::<{ STANDARD }, { RADIX }, { RADIX_SHIFT }>(
-                self,
-                &DIGIT_TO_BASE10_SQUARED,
-                &mut buffer[..count],
-            );
+            algorithm_u128(self, 10, &DIGIT_TO_BASE10_SQUARED, &mut buffer[..count]);
             count
         }
     }
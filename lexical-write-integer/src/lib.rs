@@ -52,11 +52,13 @@
 mod index;
 
 pub mod algorithm;
+pub mod bignum;
 pub mod compact;
 pub mod decimal;
 pub mod options;
 pub mod radix;
 pub mod table;
+pub mod wide;
 pub mod write;
 
 mod api;
@@ -65,7 +67,7 @@ mod table_decimal;
 mod table_radix;
 
 // Re-exports
-pub use self::api::{ToLexical, ToLexicalWithOptions};
+pub use self::api::{ToLexical, ToLexicalWithFormat, ToLexicalWithOptions};
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder};
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
@@ -3,6 +3,7 @@
 //! This is a dummy implementation, since writing integers never have options.
 
 use lexical_util::constants::FormattedSize;
+use lexical_util::format::Format;
 use lexical_util::options::WriteOptions;
 use lexical_util::result::Result;
 use static_assertions::const_assert;
@@ -109,7 +110,7 @@ impl WriteOptions for Options {
     }
 
     #[inline(always)]
-    fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
+    fn buffer_size_with_format<T: FormattedSize, F: Format>(&self, _: F) -> usize {
         T::FORMATTED_SIZE
     }
 }
@@ -0,0 +1,113 @@
+//! Write user-defined, fixed-width wide integers.
+//!
+//! These writers support any type implementing [`WideInteger`], such as
+//! 256- or 512-bit integers, in any radix and with the same sign rules
+//! as the primitive writers.
+//!
+//! Wide integers are defined outside of lexical, so they cannot implement
+//! [`ToLexical`] in lexical, however, they may implement it using these
+//! writers.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_write_integer::format::STANDARD;
+//! use lexical_write_integer::wide::{buffer_size, write, WideInteger};
+//!
+//! #[derive(Clone)]
+//! struct U256([u64; 4]);
+//!
+//! impl WideInteger for U256 {
+//!     const IS_SIGNED: bool = false;
+//!     const ZERO: Self = U256([0; 4]);
+//!
+//!     fn limbs(&self) -> &[u64] {
+//!         &self.0
+//!     }
+//!
+//!     fn limbs_mut(&mut self) -> &mut [u64] {
+//!         &mut self.0
+//!     }
+//! }
+//!
+//! # pub fn main() {
+//! let mut buffer = [0u8; 128];
+//! assert!(buffer.len() >= buffer_size::<U256, STANDARD>());
+//! let value = U256([0, 1, 0, 0]);
+//! assert_eq!(write::<_, STANDARD>(&value, &mut buffer), b"18446744073709551616");
+//! # }
+//! ```
+//!
+//! [`ToLexical`]: crate::ToLexical
+
+use lexical_util::bignum::max_power;
+use lexical_util::digit::digit_to_char_const;
+use lexical_util::format::NumberFormat;
+#[doc(inline)]
+pub use lexical_util::wide::WideInteger;
+
+/// Get the number of bytes required to write any value of the type.
+///
+/// This includes the sign, and is an upper bound: each 64-bit limb
+/// requires at most 1 more digit than fits in a `u64`, and integers
+/// without limbs are written as `0`.
+#[inline]
+pub fn buffer_size<T: WideInteger, const FORMAT: u128>() -> usize {
+    let radix = NumberFormat::<{ FORMAT }>::RADIX;
+    let (_, step) = max_power(radix);
+    T::ZERO.limbs().len().max(1) * (step + 1) + 1
+}
+
+/// Write a wide integer to the buffer.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. Unlike
+/// the primitive writers, this takes no options, since there are no
+/// options for writing integers.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than [`buffer_size`], or if the
+/// format is not valid.
+pub fn write<'a, T, const FORMAT: u128>(value: &T, bytes: &'a mut [u8]) -> &'a mut [u8]
+where
+    T: WideInteger,
+{
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.is_valid());
+    assert!(bytes.len() >= buffer_size::<T, FORMAT>());
+
+    let mut value = value.clone();
+    let mut index = 0;
+    if value.is_negative() {
+        value.wrapping_neg();
+        bytes[0] = b'-';
+        index = 1;
+    } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+        bytes[0] = b'+';
+        index = 1;
+    }
+
+    // Write the digits to the end of the buffer, in chunks that fit in a `u64`.
+    let radix = NumberFormat::<{ FORMAT }>::RADIX;
+    let (power, step) = max_power(radix);
+    let mut start = bytes.len();
+    loop {
+        let mut chunk = value.divrem_small(power);
+        let is_last = value.is_zero();
+        let mut digits = 0;
+        while digits < step && (!is_last || chunk != 0 || digits == 0) {
+            start -= 1;
+            bytes[start] = digit_to_char_const((chunk % radix as u64) as u32, radix);
+            chunk /= radix as u64;
+            digits += 1;
+        }
+        if is_last {
+            break;
+        }
+    }
+
+    let count = bytes.len() - start;
+    bytes.copy_within(start.., index);
+    &mut bytes[..index + count]
+}
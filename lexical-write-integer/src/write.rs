@@ -9,7 +9,7 @@ use crate::compact::Compact;
 use crate::decimal::Decimal;
 #[cfg(all(not(feature = "compact"), feature = "power-of-two"))]
 use crate::radix::Radix;
-use lexical_util::format;
+use lexical_util::format::Format;
 
 /// Define the implementation to write significant digits.
 macro_rules! write_mantissa {
@@ -21,12 +21,12 @@ macro_rules! write_mantissa {
         /// Safe as long as the buffer can hold `FORMATTED_SIZE` elements.
         #[doc(hidden)]
         #[inline(always)]
-        unsafe fn write_mantissa<U, const FORMAT: u128>(self, buffer: &mut [u8]) -> usize
+        unsafe fn write_mantissa<U, F: Format>(self, buffer: &mut [u8], format: F) -> usize
         where
             U: $($t)+,
         {
             // SAFETY: safe as long as the buffer can hold `FORMATTED_SIZE` elements.
-            unsafe { self.write_integer::<U, FORMAT, { format::RADIX }, { format::RADIX_SHIFT }>(buffer) }
+            unsafe { self.write_integer::<U>(format.radix(), buffer) }
         }
     )
 }
@@ -41,12 +41,12 @@ macro_rules! write_exponent {
         /// Safe as long as the buffer can hold `FORMATTED_SIZE` elements.
         #[doc(hidden)]
         #[inline(always)]
-        unsafe fn write_exponent<U, const FORMAT: u128>(self, buffer: &mut [u8]) -> usize
+        unsafe fn write_exponent<U, F: Format>(self, buffer: &mut [u8], format: F) -> usize
         where
             U: $($t)+,
         {
             // SAFETY: safe as long as the buffer can hold `FORMATTED_SIZE` elements.
-            unsafe { self.write_integer::<U, FORMAT, { format::EXPONENT_RADIX }, { format::EXPONENT_RADIX_SHIFT }>(buffer) }
+            unsafe { self.write_integer::<U>(format.exponent_radix(), buffer) }
         }
     )
 }
@@ -67,15 +67,11 @@ pub trait WriteInteger: Compact {
     ///
     /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
     /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
-    unsafe fn write_integer<U, const FORMAT: u128, const MASK: u128, const SHIFT: i32>(
-        self,
-        buffer: &mut [u8],
-    ) -> usize
+    unsafe fn write_integer<U>(self, radix: u32, buffer: &mut [u8]) -> usize
     where
         U: Compact,
     {
         let value = U::as_cast(self);
-        let radix = format::radix_from_flags(FORMAT, MASK, SHIFT);
         unsafe { value.compact(radix, buffer) }
     }
 
@@ -98,10 +94,7 @@ pub trait WriteInteger: Decimal {
     ///
     /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
     #[inline]
-    unsafe fn write_integer<U, const __: u128, const ___: u128, const ____: i32>(
-        self,
-        buffer: &mut [u8],
-    ) -> usize
+    unsafe fn write_integer<U>(self, _: u32, buffer: &mut [u8]) -> usize
     where
         U: Decimal,
     {
@@ -130,18 +123,15 @@ pub trait WriteInteger: Decimal + Radix {
     /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
     /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
     #[inline]
-    unsafe fn write_integer<U, const FORMAT: u128, const MASK: u128, const SHIFT: i32>(
-        self,
-        buffer: &mut [u8],
-    ) -> usize
+    unsafe fn write_integer<U>(self, radix: u32, buffer: &mut [u8]) -> usize
     where
         U: Decimal + Radix,
     {
         let value = U::as_cast(self);
-        if format::radix_from_flags(FORMAT, MASK, SHIFT) == 10 {
+        if radix == 10 {
             unsafe { value.decimal(buffer) }
         } else {
-            unsafe { value.radix::<FORMAT, MASK, SHIFT>(buffer) }
+            unsafe { value.radix(radix, buffer) }
         }
     }
 
@@ -6,9 +6,13 @@ use core::str::{from_utf8_unchecked, FromStr};
 use lexical_util::constants::FormattedSize;
 #[cfg(feature = "radix")]
 use lexical_util::constants::BUFFER_SIZE;
+#[cfg(all(feature = "format", feature = "radix"))]
+use lexical_util::format::DynamicFormat;
 #[cfg(feature = "format")]
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::format::STANDARD;
+#[cfg(feature = "format")]
+use lexical_write_integer::ToLexicalWithFormat;
 use lexical_write_integer::{Options, ToLexical, ToLexicalWithOptions};
 use proptest::prelude::*;
 use quickcheck::quickcheck;
@@ -42,6 +46,25 @@ fn mandatory_sign_test() {
     assert_eq!(b"+1", 1i8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn to_lexical_with_format_test() {
+    let mut buffer = [b'\x00'; 16];
+    let options = Options::new();
+    let format = NumberFormatBuilder::new().required_mantissa_sign(true).build_dynamic().unwrap();
+    assert_eq!(b"+0", 0i8.to_lexical_with_format(&mut buffer, format, &options));
+    assert_eq!(b"-1", (-1i8).to_lexical_with_format(&mut buffer, format, &options));
+    assert_eq!(b"+255", 255u8.to_lexical_with_format(&mut buffer, format, &options));
+
+    #[cfg(feature = "radix")]
+    {
+        let mut buffer = [b'\x00'; BUFFER_SIZE];
+        let format = DynamicFormat::new(NumberFormatBuilder::from_radix(12)).unwrap();
+        assert_eq!(b"A8", 128u8.to_lexical_with_format(&mut buffer, format, &options));
+        assert_eq!(b"-A8", (-128i16).to_lexical_with_format(&mut buffer, format, &options));
+    }
+}
+
 #[test]
 fn u8_test() {
     let mut buffer = [b'\x00'; 16];
@@ -0,0 +1,142 @@
+#![cfg(feature = "std")]
+
+use lexical_util::bignum::{divrem_small, max_power, normalized_len};
+use lexical_util::digit::digit_to_char_const;
+use lexical_write_integer::bignum::{digits_len, scratch_len, write_limbs, write_limbs_vec};
+use proptest::prelude::*;
+
+/// Generate pseudo-random limbs using xorshift.
+fn random_limbs(seed: &mut u64, len: usize) -> Vec<u64> {
+    (0..len)
+        .map(|_| {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            *seed
+        })
+        .collect()
+}
+
+/// Write the limbs using only small divisions, as a reference.
+fn reference_write(limbs: &[u64], radix: u32) -> String {
+    let (base, step) = max_power(radix);
+    let mut limbs = limbs.to_vec();
+    let mut digits = vec![];
+    while normalized_len(&limbs) != 0 {
+        let mut chunk = divrem_small(&mut limbs, base);
+        for _ in 0..step {
+            digits.push(digit_to_char_const((chunk % radix as u64) as u32, radix));
+            chunk /= radix as u64;
+        }
+    }
+    while digits.len() > 1 && digits.last() == Some(&b'0') {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        digits.push(b'0');
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+fn to_string(limbs: &[u64], radix: u32) -> String {
+    String::from_utf8(write_limbs_vec(limbs, radix)).unwrap()
+}
+
+#[test]
+fn write_limbs_test() {
+    assert_eq!(to_string(&[], 10), "0");
+    assert_eq!(to_string(&[0, 0], 10), "0");
+    assert_eq!(to_string(&[1, 0], 10), "1");
+    assert_eq!(to_string(&[0, 1], 10), "18446744073709551616");
+    assert_eq!(to_string(&[u64::MAX, u64::MAX], 10), u128::MAX.to_string());
+}
+
+#[test]
+fn buffer_test() {
+    assert_eq!(digits_len(0, 10), 1);
+    assert_eq!(digits_len(2, 10), 40);
+    assert_eq!(scratch_len(2, 10), 2);
+
+    let limbs = [u64::MAX, u64::MAX, 0, 0];
+    let mut buffer = [0u8; 39];
+    let mut scratch = [0u64; 2];
+    let digits = write_limbs(&limbs, 10, &mut buffer, &mut scratch);
+    assert_eq!(digits, u128::MAX.to_string().as_bytes());
+}
+
+#[test]
+#[should_panic]
+fn invalid_radix_test() {
+    write_limbs_vec(&[1], 37);
+}
+
+#[test]
+fn large_test() {
+    // Large values use the divide-and-conquer algorithm.
+    let mut seed = 0x2545F4914F6CDD1D;
+    for &len in &[31, 32, 33, 64, 65, 100, 257, 1000, 2049] {
+        let limbs = random_limbs(&mut seed, len);
+        assert_eq!(to_string(&limbs, 10), reference_write(&limbs, 10), "{}", len);
+    }
+
+    // Powers of the radix have many zero digits.
+    let mut limbs = vec![0; 1200];
+    limbs[0] = 1;
+    for _ in 0..20000 {
+        lexical_util::bignum::mul_small(&mut limbs, 10, 0);
+    }
+    assert_eq!(to_string(&limbs, 10), format!("1{}", "0".repeat(20000)));
+    let mut limbs = vec![0; 600];
+    limbs[599] = 1;
+    assert_eq!(to_string(&limbs, 10), reference_write(&limbs, 10));
+    let limbs = vec![u64::MAX; 700];
+    assert_eq!(to_string(&limbs, 10), reference_write(&limbs, 10));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn power_of_two_test() {
+    let mut seed = 0x9E3779B97F4A7C15;
+    let limbs = random_limbs(&mut seed, 300);
+    for &radix in &[2, 4, 8, 16, 32] {
+        assert_eq!(to_string(&limbs, radix), reference_write(&limbs, radix));
+    }
+    assert_eq!(to_string(&[0, 1], 16), format!("1{}", "0".repeat(16)));
+    assert_eq!(to_string(&[u64::MAX], 32), format!("F{}", "V".repeat(12)));
+    assert_eq!(digits_len(1, 32), 13);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix_test() {
+    let mut seed = 0xD1B54A32D192ED03;
+    for &radix in &[3, 7, 12, 31, 36] {
+        for &len in &[3, 40, 200, 700] {
+            let limbs = random_limbs(&mut seed, len);
+            let digits = to_string(&limbs, radix);
+            assert_eq!(digits, reference_write(&limbs, radix));
+            assert!(digits.len() <= digits_len(len, radix));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn u128_proptest(x in u128::MIN..u128::MAX) {
+        let limbs = [x as u64, (x >> 64) as u64];
+        prop_assert_eq!(to_string(&limbs, 10), x.to_string());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn large_proptest(seed in 1u64..u64::MAX, len in 1usize..300, zeros in 0usize..300) {
+        let mut seed = seed;
+        let mut limbs = random_limbs(&mut seed, len);
+        for limb in limbs.iter_mut().take(zeros) {
+            *limb = 0;
+        }
+        prop_assert_eq!(to_string(&limbs, 10), reference_write(&limbs, 10));
+    }
+}
@@ -5,6 +5,7 @@ mod util;
 
 use core::str::from_utf8_unchecked;
 use lexical_util::constants::BUFFER_SIZE;
+use lexical_util::format::{DynamicFormat, NumberFormat};
 use lexical_write_integer::write::WriteInteger;
 use proptest::prelude::*;
 use util::from_radix;
@@ -16,7 +17,7 @@ fn u128toa_test() {
     let mut buffer = [b'\x00'; 128];
     unsafe {
         let value = 136551478823710021067381144334863695872u128;
-        let count = value.write_mantissa::<u128, FORMAT>(&mut buffer);
+        let count = value.write_mantissa::<u128, _>(&mut buffer, NumberFormat::<{ FORMAT }> {});
         let y = u128::from_str_radix(from_utf8_unchecked(&buffer[..count]), 12);
         assert_eq!(y, Ok(value));
     }
@@ -26,7 +27,7 @@ fn u128toa_test() {
 fn write_integer<T: WriteInteger, const FORMAT: u128>(x: T, actual: &[u8]) {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
     unsafe {
-        let count = x.write_mantissa::<T, FORMAT>(&mut buffer);
+        let count = x.write_mantissa::<T, _>(&mut buffer, NumberFormat::<{ FORMAT }> {});
         assert_eq!(actual.len(), count);
         assert_eq!(actual, &buffer[..count])
     }
@@ -90,49 +91,12 @@ fn radix_test() {
     write_integer::<u32, { from_radix(36) }>(37u32, b"11");
 }
 
-// We need to trick the algorithm into thinking we're using a const.
-// Useful for proptests, useless everywhere else.
+// The radix is only known at runtime, so use a runtime format.
 macro_rules! to_radix {
-    ($t:ident, $x:ident, $radix:ident, $buffer:ident) => {
-        match $radix {
-            2 => $x.write_mantissa::<$t, { from_radix(2) }>(&mut $buffer),
-            3 => $x.write_mantissa::<$t, { from_radix(3) }>(&mut $buffer),
-            4 => $x.write_mantissa::<$t, { from_radix(4) }>(&mut $buffer),
-            5 => $x.write_mantissa::<$t, { from_radix(5) }>(&mut $buffer),
-            6 => $x.write_mantissa::<$t, { from_radix(6) }>(&mut $buffer),
-            7 => $x.write_mantissa::<$t, { from_radix(7) }>(&mut $buffer),
-            8 => $x.write_mantissa::<$t, { from_radix(8) }>(&mut $buffer),
-            9 => $x.write_mantissa::<$t, { from_radix(9) }>(&mut $buffer),
-            10 => $x.write_mantissa::<$t, { from_radix(10) }>(&mut $buffer),
-            11 => $x.write_mantissa::<$t, { from_radix(11) }>(&mut $buffer),
-            12 => $x.write_mantissa::<$t, { from_radix(12) }>(&mut $buffer),
-            13 => $x.write_mantissa::<$t, { from_radix(13) }>(&mut $buffer),
-            14 => $x.write_mantissa::<$t, { from_radix(14) }>(&mut $buffer),
-            15 => $x.write_mantissa::<$t, { from_radix(15) }>(&mut $buffer),
-            16 => $x.write_mantissa::<$t, { from_radix(16) }>(&mut $buffer),
-            17 => $x.write_mantissa::<$t, { from_radix(17) }>(&mut $buffer),
-            18 => $x.write_mantissa::<$t, { from_radix(18) }>(&mut $buffer),
-            19 => $x.write_mantissa::<$t, { from_radix(19) }>(&mut $buffer),
-            20 => $x.write_mantissa::<$t, { from_radix(20) }>(&mut $buffer),
-            21 => $x.write_mantissa::<$t, { from_radix(21) }>(&mut $buffer),
-            22 => $x.write_mantissa::<$t, { from_radix(22) }>(&mut $buffer),
-            23 => $x.write_mantissa::<$t, { from_radix(23) }>(&mut $buffer),
-            24 => $x.write_mantissa::<$t, { from_radix(24) }>(&mut $buffer),
-            25 => $x.write_mantissa::<$t, { from_radix(25) }>(&mut $buffer),
-            26 => $x.write_mantissa::<$t, { from_radix(26) }>(&mut $buffer),
-            27 => $x.write_mantissa::<$t, { from_radix(27) }>(&mut $buffer),
-            28 => $x.write_mantissa::<$t, { from_radix(28) }>(&mut $buffer),
-            29 => $x.write_mantissa::<$t, { from_radix(29) }>(&mut $buffer),
-            30 => $x.write_mantissa::<$t, { from_radix(30) }>(&mut $buffer),
-            31 => $x.write_mantissa::<$t, { from_radix(31) }>(&mut $buffer),
-            32 => $x.write_mantissa::<$t, { from_radix(32) }>(&mut $buffer),
-            33 => $x.write_mantissa::<$t, { from_radix(33) }>(&mut $buffer),
-            34 => $x.write_mantissa::<$t, { from_radix(34) }>(&mut $buffer),
-            35 => $x.write_mantissa::<$t, { from_radix(35) }>(&mut $buffer),
-            36 => $x.write_mantissa::<$t, { from_radix(36) }>(&mut $buffer),
-            _ => unimplemented!(),
-        }
-    };
+    ($t:ident, $x:ident, $radix:ident, $buffer:ident) => {{
+        let format = DynamicFormat::new(from_radix($radix as u8)).unwrap();
+        $x.write_mantissa::<$t, _>(&mut $buffer, format)
+    }};
 }
 
 fn u32toa_mockup(x: u32, radix: u32) -> Result<(), TestCaseError> {
@@ -0,0 +1,172 @@
+#[cfg(feature = "power-of-two")]
+mod util;
+
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use lexical_write_integer::wide::{buffer_size, write, WideInteger};
+#[cfg(feature = "power-of-two")]
+use lexical_write_integer::{Options, ToLexicalWithOptions, BUFFER_SIZE};
+use proptest::prelude::*;
+#[cfg(feature = "power-of-two")]
+use util::from_radix;
+
+macro_rules! wide_integer {
+    ($($t:ident $limbs:literal $is_signed:literal ;)*) => ($(
+        #[derive(Clone, Debug, PartialEq)]
+        struct $t([u64; $limbs]);
+
+        impl WideInteger for $t {
+            const IS_SIGNED: bool = $is_signed;
+            const ZERO: Self = $t([0; $limbs]);
+
+            fn limbs(&self) -> &[u64] {
+                &self.0
+            }
+
+            fn limbs_mut(&mut self) -> &mut [u64] {
+                &mut self.0
+            }
+        }
+    )*);
+}
+
+wide_integer! {
+    U128 2 false ;
+    I128 2 true ;
+    U256 4 false ;
+    I256 4 true ;
+    U0 0 false ;
+}
+
+fn to_string<T: WideInteger, const FORMAT: u128>(value: T) -> String {
+    let mut buffer = [b'0'; 600];
+    let bytes = write::<T, FORMAT>(&value, &mut buffer);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn u256_test() {
+    assert_eq!(to_string::<_, STANDARD>(U256([0; 4])), "0");
+    assert_eq!(to_string::<_, STANDARD>(U256([1, 0, 0, 0])), "1");
+    assert_eq!(to_string::<_, STANDARD>(U256([0, 1, 0, 0])), "18446744073709551616");
+    let value = U256([10000000000000000000, 0, 0, 0]);
+    assert_eq!(to_string::<_, STANDARD>(value), "10000000000000000000");
+    assert_eq!(
+        to_string::<_, STANDARD>(U256([u64::MAX; 4])),
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+    );
+}
+
+#[test]
+fn i256_test() {
+    assert_eq!(to_string::<_, STANDARD>(I256([u64::MAX; 4])), "-1");
+    let value = I256([0, u64::MAX, u64::MAX, u64::MAX]);
+    assert_eq!(to_string::<_, STANDARD>(value), "-18446744073709551616");
+    assert_eq!(
+        to_string::<_, STANDARD>(I256([0, 0, 0, 1 << 63])),
+        "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+    );
+    assert_eq!(
+        to_string::<_, STANDARD>(I256([u64::MAX, u64::MAX, u64::MAX, i64::MAX as u64])),
+        "57896044618658097711785492504343953926634992332820282019728792003956564819967"
+    );
+}
+
+#[test]
+fn buffer_size_test() {
+    assert_eq!(buffer_size::<U256, STANDARD>(), 81);
+    let mut buffer = [b'0'; 81];
+    let value = I256([0, 0, 0, 1 << 63]);
+    assert_eq!(write::<_, STANDARD>(&value, &mut buffer).len(), 78);
+
+    // Integers without limbs are written as 0.
+    assert_eq!(buffer_size::<U0, STANDARD>(), 21);
+    assert_eq!(to_string::<_, STANDARD>(U0([])), "0");
+}
+
+#[test]
+#[should_panic]
+fn small_buffer_test() {
+    let mut buffer = [b'0'; 80];
+    write::<_, STANDARD>(&U256([1, 0, 0, 0]), &mut buffer);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn radix_test() {
+    const HEX: u128 = from_radix(16);
+    assert_eq!(to_string::<_, HEX>(U256([0, 0, 0, 1])), format!("1{}", "0".repeat(48)));
+    assert_eq!(to_string::<_, HEX>(U256([u64::MAX; 4])), "F".repeat(64));
+    let value = I256([u64::MAX - 9, u64::MAX, u64::MAX, u64::MAX]);
+    assert_eq!(to_string::<_, HEX>(value), "-A");
+
+    const BINARY: u128 = from_radix(2);
+    assert_eq!(buffer_size::<U256, BINARY>(), 257);
+    let expected = format!("1{}", "0".repeat(255));
+    assert_eq!(to_string::<_, BINARY>(U256([0, 0, 0, 1 << 63])), expected);
+    assert_eq!(to_string::<_, BINARY>(I256([0, 0, 0, 1 << 63])), format!("-{}", expected));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    assert_eq!(to_string::<_, FORMAT>(U256([12, 0, 0, 0])), "+12");
+    assert_eq!(to_string::<_, FORMAT>(I256([u64::MAX; 4])), "-1");
+}
+
+#[cfg(feature = "power-of-two")]
+fn to_string_primitive<T: ToLexicalWithOptions<Options = Options>, const FORMAT: u128>(
+    value: T,
+) -> String {
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    let bytes = value.to_lexical_with_options::<FORMAT>(&mut buffer, &Options::new());
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn u128_proptest(x in u128::MIN..u128::MAX) {
+        // A 2-limb wide integer must match the primitive integer.
+        let value = U128([x as u64, (x >> 64) as u64]);
+        prop_assert_eq!(to_string::<_, STANDARD>(value), x.to_string());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i128_proptest(x in i128::MIN..i128::MAX) {
+        let value = I128([x as u64, (x >> 64) as u64]);
+        prop_assert_eq!(to_string::<_, STANDARD>(value), x.to_string());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(feature = "power-of-two")]
+    fn i128_binary_proptest(x in i128::MIN..i128::MAX) {
+        const BINARY: u128 = from_radix(2);
+        let value = I128([x as u64, (x >> 64) as u64]);
+        prop_assert_eq!(to_string::<_, BINARY>(value), to_string_primitive::<_, BINARY>(x));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(feature = "radix")]
+    fn u128_radix_proptest(x in u128::MIN..u128::MAX, index in 0usize..4) {
+        let value = U128([x as u64, (x >> 64) as u64]);
+        macro_rules! check {
+            ($radix:literal) => {{
+                const FORMAT: u128 = from_radix($radix);
+                (to_string::<_, FORMAT>(value), to_string_primitive::<_, FORMAT>(x))
+            }};
+        }
+        let (result, expected) = match index {
+            0 => check!(3),
+            1 => check!(7),
+            2 => check!(12),
+            _ => check!(36),
+        };
+        prop_assert_eq!(result, expected);
+    }
+}
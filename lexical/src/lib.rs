@@ -153,6 +153,14 @@
 //! safe feature enabled and disabled, with the tests verified by Miri
 //! and Valgrind.
 //!
+//! ### saturating
+//!
+//! Enable conversions to and from [`Saturating`] integers. `Saturating`
+//! was stabilized in Rust 1.74.0, so this requires a newer compiler than
+//! the minimum supported version.
+//!
+//! [`Saturating`]: core::num::Saturating
+//!
 //! # Configuration API
 //!
 //! Lexical provides two main levels of configuration:
@@ -284,16 +292,31 @@ use std::string::String;
 use std::vec::Vec;
 
 pub use lexical_core::format::{self, format_error, format_is_valid, NumberFormatBuilder};
+pub use lexical_core::DigitGrouping;
 #[cfg(feature = "parse")]
 pub use lexical_core::Error;
 #[cfg(feature = "parse")]
 pub use lexical_core::ParseOptions;
 #[cfg(feature = "parse")]
+pub use lexical_core::{punctuation, Punctuation};
+#[cfg(feature = "parse")]
 pub use lexical_core::Result;
 #[cfg(feature = "write")]
 pub use lexical_core::WriteOptions;
 #[cfg(feature = "f16")]
 pub use lexical_core::{bf16, f16};
+#[cfg(feature = "custom-float")]
+pub use lexical_core::{custom_float, f8e4m3, f8e5m2, tf32, CustomFloat};
+#[cfg(feature = "decimal")]
+pub use lexical_core::{decimal128, decimal32, decimal64, DecimalFloat};
+#[cfg(feature = "double-double")]
+pub use lexical_core::DoubleDouble;
+#[cfg(feature = "complex")]
+pub use lexical_core::Complex;
+#[cfg(feature = "rational")]
+pub use lexical_core::Rational;
+#[cfg(all(feature = "write-floats", feature = "rational"))]
+pub use lexical_core::write_rational;
 #[cfg(feature = "parse-floats")]
 pub use lexical_core::{parse_float_options, ParseFloatOptions, ParseFloatOptionsBuilder};
 #[cfg(feature = "parse-integers")]